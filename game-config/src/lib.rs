@@ -39,6 +39,8 @@ impl fmt::Display for DoomArgError {
 pub struct GameOptions {
     pub iwad: String,
     pub pwad: Vec<String>,
+    /// DEHACKED/BEX patch files, applied after any `DEHACKED` lumps.
+    pub deh: Vec<String>,
     pub no_monsters: bool,
     pub respawn_parm: bool,
     pub fast_parm: bool,
@@ -54,7 +56,26 @@ pub struct GameOptions {
     pub autostart: bool,
     /// Play this demo lump immediately and exit when done.
     pub demo: Option<String>,
+    /// Record live play to this demo file (`.lmp` is appended if missing).
+    pub record: Option<String>,
+    /// Record with full 16 bit turning (Doom 1.91 longtics demo format).
+    pub longtics: bool,
+    /// Set while a peer-to-peer netgame is running.
     pub netgame: bool,
+    /// Peer-to-peer netgame to join at startup.
+    pub net: Option<NetSettings>,
+}
+
+/// The nodes of a peer-to-peer netgame, one per player.
+#[derive(Debug, Clone)]
+pub struct NetSettings {
+    /// This node's player number, 0-based.
+    pub player: usize,
+    /// UDP port to listen on.
+    pub port: u16,
+    /// Addresses of the other players' nodes, in player order skipping this
+    /// one.
+    pub peers: Vec<String>,
 }
 
 impl Default for GameOptions {
@@ -62,6 +83,7 @@ impl Default for GameOptions {
         Self {
             iwad: "doom.wad".to_owned(),
             pwad: Default::default(),
+            deh: Default::default(),
             no_monsters: Default::default(),
             respawn_parm: Default::default(),
             fast_parm: Default::default(),
@@ -76,7 +98,10 @@ impl Default for GameOptions {
             hi_res: true,
             verbose: log::LevelFilter::Info,
             demo: None,
+            record: None,
+            longtics: false,
             netgame: false,
+            net: None,
         }
     }
 }
@@ -98,7 +98,7 @@ pub const TIC_CMD_BUTTONS: ButtonCode = ButtonCode {
 /// Mainly movements/button commands per game-exe tick,
 /// plus a checksum for internal state consistency.
 // G_BuildTiccmd
-#[derive(Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct TicCmd {
     /// *2048 for move
     pub forwardmove: i8,
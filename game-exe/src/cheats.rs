@@ -1,7 +1,7 @@
 //! Game cheats. These are what players type in, e.g, `iddqd`
 
 use game_config::{GameMission, GameMode, Skill, WeaponType};
-use gameplay::{PlayerCheat, PowerType, english};
+use gameplay::{PlayerCheat, PowerType, cheat_codes, english, misc_info};
 use gamestate::Game;
 use gamestate_traits::{GameTraits as _, KeyCode};
 use log::debug;
@@ -37,25 +37,21 @@ pub struct Cheats {
 }
 
 impl Cheats {
-    pub const fn new() -> Self {
+    /// Sequences come from `gameplay::cheat_codes`, so DEHACKED must be
+    /// applied first.
+    pub fn new() -> Self {
+        let codes = cheat_codes();
         Self {
-            god: Cheat::new("iddqd", 0),
-            mus: Cheat::new("idmus", 2),
-            ammo: Cheat::new("idkfa", 0),
-            ammonokey: Cheat::new("idfa", 0),
-            noclip: Cheat::new("idspispopd", 0),
-            commercial_noclip: Cheat::new("idclip", 0),
-            powerup: [
-                Cheat::new("idbeholdv", 0),
-                Cheat::new("idbeholds", 0),
-                Cheat::new("idbeholdi", 0),
-                Cheat::new("idbeholdr", 0),
-                Cheat::new("idbeholda", 0),
-                Cheat::new("idbeholdl", 0),
-            ],
-            choppers: Cheat::new("idchoppers", 0),
-            clev: Cheat::new("idclev", 2),
-            mypos: Cheat::new("idmypos", 0),
+            god: Cheat::new(codes.god, 0),
+            mus: Cheat::new(codes.mus, 2),
+            ammo: Cheat::new(codes.ammo, 0),
+            ammonokey: Cheat::new(codes.ammo_no_key, 0),
+            noclip: Cheat::new(codes.noclip, 0),
+            commercial_noclip: Cheat::new(codes.commercial_noclip, 0),
+            powerup: codes.powerup.map(|seq| Cheat::new(seq, 0)),
+            choppers: Cheat::new(codes.choppers, 0),
+            clev: Cheat::new(codes.clev, 2),
+            mypos: Cheat::new(codes.mypos, 0),
         }
     }
 
@@ -71,18 +67,19 @@ impl Cheats {
                 player.status.cheats.toggle(PlayerCheat::Godmode);
 
                 if player.status.cheats.contains(PlayerCheat::Godmode) {
+                    let health = misc_info().god_mode_health;
                     if let Some(mobj) = player.mobj_mut() {
-                        mobj.health = 100;
+                        mobj.health = health;
                     }
-                    player.status.health = 100;
+                    player.status.health = health;
                     player.message = Some(english::STSTR_DQDON);
                 } else {
                     player.message = Some(english::STSTR_DQDOFF);
                 }
             } else if self.ammonokey.check(key) {
                 let player = &mut game.players[game.consoleplayer];
-                player.status.armorpoints = 200;
-                player.status.armortype = 2;
+                player.status.armorpoints = misc_info().idfa_armor;
+                player.status.armortype = misc_info().idfa_armor_class;
 
                 for w in &mut player.status.weaponowned {
                     *w = true;
@@ -93,8 +90,8 @@ impl Cheats {
                 player.message = Some(english::STSTR_FAADDED);
             } else if self.ammo.check(key) {
                 let player = &mut game.players[game.consoleplayer];
-                player.status.armorpoints = 200;
-                player.status.armortype = 2;
+                player.status.armorpoints = misc_info().idkfa_armor;
+                player.status.armortype = misc_info().idkfa_armor_class;
 
                 for w in &mut player.status.weaponowned {
                     *w = true;
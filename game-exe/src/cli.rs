@@ -1,5 +1,6 @@
 use argh::FromArgs;
-use game_config::{GameOptions, Skill};
+use game_config::{GameOptions, NetSettings, Skill};
+use gamestate::DOOMPORT;
 #[cfg(feature = "software3d")]
 use software3d::{DebugColourMode, DebugDrawOptions, DebugOverlay};
 
@@ -46,9 +47,12 @@ pub struct CLIOptions {
     /// path to game-exe WAD
     #[argh(option, default = "Default::default()", short = 'i')]
     pub iwad: String,
-    /// path to patch WAD
+    /// path to patch WAD or PK3
     #[argh(option, short = 'p')]
     pub pwad: Vec<String>,
+    /// path to DEHACKED/BEX patch, applied after any in the WADs
+    #[argh(option)]
+    pub deh: Vec<String>,
     /// resolution width in pixels
     #[argh(option, default = "0", short = 'w')]
     pub width: u32,
@@ -78,8 +82,24 @@ pub struct CLIOptions {
     /// leak checks
     #[argh(switch)]
     pub dev_parm: bool,
-    //     help = "Start a deathmatch game-exe: 1 = classic, 2 = Start a deathmatch 2.0 game-exe.
-    // Weapons do not stay in place and all items respawn after 30 seconds" pub deathmatch: u8,
+    /// start a deathmatch netgame: weapons stay in place and keys are given
+    #[argh(switch)]
+    pub deathmatch: bool,
+    /// start a deathmatch 2.0 netgame: weapons do not stay in place and all
+    /// items respawn after 30 seconds
+    #[argh(switch)]
+    pub altdeath: bool,
+    /// join a netgame as this player, 1-4. Needs a --net-peer for each other
+    /// player
+    #[argh(option)]
+    pub net_player: Option<usize>,
+    /// UDP port to listen on in a netgame (default 5029)
+    #[argh(option, default = "DOOMPORT")]
+    pub net_port: u16,
+    /// address of another player's node, e.g. 127.0.0.1:5030. Given once per
+    /// other player, in player order
+    #[argh(option)]
+    pub net_peer: Vec<String>,
     // pub autostart: bool,
     /// set the game-exe skill, 0-4 (0: easiest, 4: hardest)
     #[argh(option, short = 's')]
@@ -110,6 +130,13 @@ pub struct CLIOptions {
     /// demo2)
     #[argh(option)]
     pub demo: Option<String>,
+    /// record a demo of live play to this file (".lmp" appended if missing).
+    /// Starts a new game immediately using the skill/episode/map options
+    #[argh(option)]
+    pub record: Option<String>,
+    /// record with full 16 bit turning resolution (Doom 1.91 longtics format)
+    #[argh(switch)]
+    pub longtics: bool,
     /// enable frame interpolation for smooth rendering between tics
     /// (true/false)
     #[argh(option)]
@@ -134,6 +161,10 @@ pub struct CLIOptions {
     /// demo playback in CI and deterministic demo-trace recording.
     #[argh(switch)]
     pub headless: bool,
+    /// compare two DEMO_TRACE (or DEMO_TRACE_DUMP) files and report the first
+    /// tic where they diverge, then exit. Give the option twice
+    #[argh(option)]
+    pub trace_diff: Vec<String>,
 }
 
 #[cfg(feature = "software3d")]
@@ -163,24 +194,41 @@ impl CLIOptions {
 
 impl From<CLIOptions> for GameOptions {
     fn from(g: CLIOptions) -> Self {
+        let net = g.net_player.map(|player| NetSettings {
+            player: player.saturating_sub(1),
+            port: g.net_port,
+            peers: g.net_peer.clone(),
+        });
+        // Deathmatch is only played over the net
+        let deathmatch = if net.is_none() {
+            0
+        } else if g.altdeath {
+            2
+        } else {
+            u8::from(g.deathmatch)
+        };
         Self {
             iwad: g.iwad,
             pwad: g.pwad,
+            deh: g.deh,
             no_monsters: g.no_monsters,
             dev_parm: g.dev_parm,
             skill: g.skill.unwrap_or_default(),
             episode: g.episode.unwrap_or_default(),
             map: g.map.unwrap_or_default(),
-            warp: g.map.is_some() || g.episode.is_some(),
+            warp: g.map.is_some() || g.episode.is_some() || g.record.is_some() || net.is_some(),
             hi_res: g.hi_res.unwrap_or(true),
             verbose: g.verbose.unwrap_or(log::LevelFilter::Warn),
             respawn_parm: false,
             respawn_monsters: false,
             fast_parm: false,
-            deathmatch: 0,
+            deathmatch,
             autostart: false,
             demo: g.demo,
+            record: g.record,
+            longtics: g.longtics,
             netgame: false,
+            net,
         }
     }
 }
@@ -350,6 +350,9 @@ pub struct UserConfig {
     pub sfx_vol: i32,
     pub mus_vol: i32,
     pub music_type: MusicType,
+    /// Doom 1.2 random sfx pitch variation; off plays 1.666 and later's flat pitch.
+    #[nserde(default)]
+    pub pitch_shift: bool,
     pub gus_mem_size: GusMemSize,
     pub input: InputConfig,
     pub frame_interpolation: bool,
@@ -467,6 +470,7 @@ impl UserConfig {
             MusicType::OPL3 => 1,
             MusicType::GUS => 2,
         };
+        a[ConfigKey::PitchShift as usize] = self.pitch_shift as i32;
         a[ConfigKey::WindowMode as usize] = match self.window_mode {
             WindowMode::Windowed => 0,
             WindowMode::Borderless => 1,
@@ -507,6 +511,7 @@ impl UserConfig {
             2 => MusicType::GUS,
             _ => MusicType::OPL2,
         };
+        self.pitch_shift = vals[ConfigKey::PitchShift as usize] != 0;
         self.window_mode = match vals[ConfigKey::WindowMode as usize] {
             1 => WindowMode::Borderless,
             2 => WindowMode::Exclusive,
@@ -10,7 +10,7 @@
 //! - `Intermission`
 //! - `Messages`
 //! - `Finale`
-//! - others may be added such as automap
+//! - `Automap`
 //!
 //! Note that the sound system runs on its own thread.
 //!
@@ -20,10 +20,11 @@
 
 use std::ptr;
 
+use game_config::tic_cmd::TicCmd;
 use gameplay::{MapObjFlag, Player};
-use gamestate::Game;
 use gamestate::subsystems::GameSubsystem;
-use gamestate_traits::{GameState, KeyCode, SubsystemTrait};
+use gamestate::{BACKUPTICS, Game};
+use gamestate_traits::{GameState, Input, Modifiers, SubsystemTrait};
 use hud_util::{draw_patch, draw_text_line, fullscreen_scale, hud_scale, measure_text_line};
 use input::InputState;
 use log::error;
@@ -88,7 +89,7 @@ fn build_render_view(player: &Player, frac: f32, game_tic: u32) -> Option<Render
         extralight: player.extralight,
         is_shadow: mobj.flags.contains(MapObjFlag::Shadow),
         psprites,
-        sector_lightlevel: mobj.subsector.sector.lightlevel,
+        sector_lightlevel: mobj.subsector.sector.sprite_lightlevel(),
         player_mobj_id: ptr::from_ref(mobj) as usize,
         frac,
         frac_fp,
@@ -146,14 +147,14 @@ pub(crate) fn load_voxels(
         pic_data::VoxelManager::load_from_pk3(
             path,
             game_mode,
-            &gameplay::SPRNAMES,
+            gameplay::sprite_names(),
             &doom_palette,
             pwad_overrides,
         )
     } else if path.is_dir() {
         pic_data::VoxelManager::load_from_directory(
             path,
-            &gameplay::SPRNAMES,
+            gameplay::sprite_names(),
             &doom_palette,
             pwad_overrides,
         )
@@ -169,9 +170,12 @@ pub(crate) fn load_voxels(
     Some(Arc::new(mgr))
 }
 
-/// Handle key-down for menu/cheat consumption. Returns true if consumed.
+/// Handle a key-down, mouse button press, wheel step or gamepad button press
+/// for menu/cheat consumption, `mods` being the modifier keys held. Returns
+/// true if consumed.
 pub(crate) fn input_responder(
-    sc: KeyCode,
+    input: Input,
+    mods: Modifiers,
     game: &mut Game,
     menu: &mut impl SubsystemTrait,
     machinations: &mut GameSubsystem<
@@ -179,9 +183,24 @@ pub(crate) fn input_responder(
         impl SubsystemTrait,
         impl SubsystemTrait,
         impl SubsystemTrait,
+        impl SubsystemTrait,
     >,
     cheats: &mut Cheats,
 ) -> bool {
+    game.held_mods = mods;
+    let Input::Key(sc) = input else {
+        if menu.device_responder(input, game) {
+            return true;
+        }
+        if game.level.is_some() {
+            return game.gamestate == GameState::Level
+                && machinations.automap.device_responder(input, game);
+        }
+        return game.gamestate == GameState::Finale
+            && !game.is_netgame()
+            && machinations.finale.device_responder(input, game);
+    };
+
     if game.level.is_some() {
         cheats.check_input(sc, game);
     }
@@ -194,7 +213,13 @@ pub(crate) fn input_responder(
         return true;
     }
 
-    if game.level.is_none() {
+    if game.level.is_some() {
+        if game.gamestate == GameState::Level && machinations.automap.responder(sc, game) {
+            return true;
+        }
+    } else if !game.is_netgame() {
+        // A netgame skips these with the player commands, so that every node
+        // skips together
         #[allow(clippy::collapsible_match)] // can't do this with &mut self methods
         match game.gamestate {
             GameState::Intermission => {
@@ -225,8 +250,20 @@ pub(crate) fn run_game_tic(
         impl SubsystemTrait,
         impl SubsystemTrait,
         impl SubsystemTrait,
+        impl SubsystemTrait,
     >,
 ) {
+    // Key releases don't pass through `input_responder`
+    game.held_mods = input.events.mods();
+    if !game.demo.playback {
+        input
+            .events
+            .feedback_damage(game.players[game.consoleplayer].status.damagecount);
+    }
+    if game.is_netgame() {
+        run_net_tics(game, input, menu, machinations);
+        return;
+    }
     if game.demo.advance {
         game.do_advance_demo();
     }
@@ -245,9 +282,58 @@ pub(crate) fn run_game_tic(
     game.game_tic += 1;
 
     let console_player = game.consoleplayer;
-    if game.gamestate == game.wipe_game_state {
-        let cmd = input.events.build_tic_cmd(&input.config);
-        game.netcmds[console_player][0] = cmd;
+    let buf = game.game_tic as usize % BACKUPTICS;
+    game.netcmds[console_player][buf] = if game.gamestate == game.wipe_game_state {
+        let player = &game.players[console_player];
+        let mode = game.game_type.mode;
+        input
+            .events
+            .build_tic_cmd(&game.bindings, |dir| player.cycle_weapon(dir, mode))
+    } else {
+        // Hold the last command through a wipe
+        game.netcmds[console_player][(buf + BACKUPTICS - 1) % BACKUPTICS]
+    };
+}
+
+/// Netgame version of `run_game_tic`: make a command for the next tic and
+/// swap commands with the other players, then run every tic that all of
+/// their commands are in for. The game doesn't stop for the menu or a wipe,
+/// the console player just stands still.
+///
+/// Doom function name `TryRunTics`
+fn run_net_tics(
+    game: &mut Game,
+    input: &mut InputState,
+    menu: &mut impl SubsystemTrait,
+    machinations: &mut GameSubsystem<
+        impl SubsystemTrait,
+        impl SubsystemTrait,
+        impl SubsystemTrait,
+        impl SubsystemTrait,
+        impl SubsystemTrait,
+    >,
+) {
+    let menu_active = menu.ticker(game);
+    let player = &game.players[game.consoleplayer];
+    let mode = game.game_type.mode;
+    let cmd = input
+        .events
+        .build_tic_cmd(&game.bindings, |dir| player.cycle_weapon(dir, mode));
+    let cmd = if menu_active || game.gamestate != game.wipe_game_state {
+        TicCmd::new()
+    } else {
+        cmd
+    };
+    game.net_update(cmd);
+
+    game.frozen = true;
+    while game.running() && game.net_tic_ready() {
+        game.ticker(machinations);
+        game.game_tic += 1;
+        game.frozen = game.paused;
+    }
+    if game.frozen {
+        machinations.hud_msgs.ticker(game);
     }
 }
 
@@ -289,6 +375,7 @@ pub(crate) fn d_display<P: PixelFmt>(
         impl SubsystemTrait,
         impl SubsystemTrait,
         impl SubsystemTrait,
+        impl SubsystemTrait,
     >,
     game: &mut Game,
     frac: f32,
@@ -312,12 +399,18 @@ pub(crate) fn d_display<P: PixelFmt>(
 
     match game.gamestate {
         GameState::Level => {
-            if let Some(level) = game.level.as_mut()
+            if game.automap {
+                machines.automap.draw(&mut screen.ui_frame());
+            } else if let Some(level) = game.level.as_mut()
                 && game.players_in_game[game.consoleplayer]
             {
                 level.level_data.apply_render_interpolation(frac);
                 let player = &game.players[game.consoleplayer];
                 if let Some(view) = build_render_view(player, frac, game.game_tic) {
+                    let colourmap_set = level
+                        .level_data
+                        .apply_transfer_heights(view.x, view.y, view.viewz);
+                    game.pic_data.set_colourmap_set(colourmap_set);
                     game.pic_data.set_player_palette(
                         player.status.damagecount,
                         player.status.bonuscount,
@@ -333,6 +426,7 @@ pub(crate) fn d_display<P: PixelFmt>(
                         screen.set_screen_effects(build_screen_effects(player, bleed_enabled));
                     }
                     screen.render_player_view(&view, &level.level_data, &mut game.pic_data);
+                    level.level_data.restore_transfer_heights();
                 } else {
                     error!("Active console player has no MapObject, can't render player view");
                 }
@@ -1,9 +1,9 @@
 //! SDL2 poll-based game loop. Never returns until `game.running` is false.
 
-use doom_ui::{Finale, GameMenu, Intermission, Messages, Statusbar};
+use doom_ui::{Automap, Finale, GameMenu, Intermission, Messages, Statusbar};
 use gamestate::Game;
 use gamestate::subsystems::GameSubsystem;
-use gamestate_traits::{ConfigTraits as _, KeyCode, SubsystemTrait};
+use gamestate_traits::{ConfigTraits as _, Input, Modifiers, SubsystemTrait};
 use log::info;
 use pic_data::PixelFmt;
 use render_backend::{ActiveBackend, RenderKind, RenderStack, RenderType};
@@ -111,12 +111,13 @@ pub fn d_doom_loop_sdl2<P>(
         intermission: Intermission::new(game.game_type.mode, &game.wad_data, &game.umapinfo),
         hud_msgs: Messages::new(&game.wad_data),
         finale: Finale::new(&game.wad_data),
+        automap: Automap::new(&game.wad_data),
     };
     info!("Loaded subsystems");
 
     if let Some(name) = options.demo.clone() {
         game.start_demo(name);
-    } else if options.episode.is_none() && options.map.is_none() {
+    } else if !game.options.warp {
         game.start_title();
     }
     info!("Started title sequence");
@@ -156,6 +157,7 @@ pub fn d_doom_loop_sdl2<P>(
     let mut menu = GameMenu::new(
         game.game_type.mode,
         &game.wad_data,
+        &game.umapinfo,
         render_backend.buffer_size().width(),
     );
     menu.init(&game);
@@ -187,6 +189,7 @@ pub fn d_doom_loop_sdl2<P>(
             menu = GameMenu::new(
                 game.game_type.mode,
                 &game.wad_data,
+                &game.umapinfo,
                 render_backend.buffer_size().width(),
             );
             menu.init(&game);
@@ -222,6 +225,7 @@ pub fn d_doom_loop_sdl2<P>(
         if game.is_config_dirty() {
             let old = user_config.clone();
             user_config.apply_config_array(&game.config_snapshot());
+            user_config.input.set_bindings(&game.bindings);
             let (w, h) = render_backend.window_size();
             user_config.width = w;
             user_config.height = h;
@@ -309,6 +313,7 @@ pub fn d_doom_loop_sdl2<P>(
                 menu = GameMenu::new(
                     game.game_type.mode,
                     &game.wad_data,
+                    &game.umapinfo,
                     render_backend.buffer_size().width(),
                 );
                 menu.init(&game);
@@ -335,6 +340,7 @@ fn try_run_tics_sdl2(
         impl SubsystemTrait,
         impl SubsystemTrait,
         impl SubsystemTrait,
+        impl SubsystemTrait,
     >,
     cheats: &mut Cheats,
     timestep: &mut TimeStep,
@@ -342,7 +348,9 @@ fn try_run_tics_sdl2(
     let mut action_return = None;
     let mut resized = false;
     {
-        let input_callback = |sc: KeyCode| input_responder(sc, game, menu, machinations, cheats);
+        let input_callback = |input: Input, mods: Modifiers| {
+            input_responder(input, mods, game, menu, machinations, cheats)
+        };
         let event_callback = |_: input::RawEvent| {
             resized = true;
         };
@@ -8,11 +8,11 @@ use std::time::{Duration, Instant};
 // frame-interval conversion
 const NANOS_PER_MILLIHERTZ: u64 = 1_000_000_000_000;
 
-use doom_ui::{Finale, GameMenu, Intermission, Messages, Statusbar};
+use doom_ui::{Automap, Finale, GameMenu, Intermission, Messages, Statusbar};
 use gamestate::Game;
 use gamestate::subsystems::GameSubsystem;
-use gamestate_traits::{ConfigTraits as _, SubsystemTrait as _};
-use input::InputState;
+use gamestate_traits::{ConfigTraits as _, Input, SubsystemTrait as _};
+use input::{GilrsPad, InputState};
 use log::{info, warn};
 use render_backend::{ActiveBackend, RenderStack};
 
@@ -63,6 +63,7 @@ fn new_backend(
 pub struct DoomApp {
     game: Game,
     input: InputState,
+    pad: GilrsPad,
     cheats: Cheats,
     timestep: TimeStep,
     /// "FPS N", refreshed once per second; drawn when ShowFps is on.
@@ -70,7 +71,7 @@ pub struct DoomApp {
     // winit drives softbuffer/pixels, both u32-only. (565 is sdl2-only.)
     render_backend: Option<RenderStack<u32>>,
     menu: Option<GameMenu>,
-    machines: GameSubsystem<Intermission, Statusbar, Messages, Finale>,
+    machines: GameSubsystem<Intermission, Statusbar, Messages, Finale, Automap>,
     options: CLIOptions,
     window: Option<Arc<Window>>,
     /// Frame interval for vsync pacing. `None` = uncapped.
@@ -96,6 +97,7 @@ impl DoomApp {
             intermission: Intermission::new(game.game_type.mode, &game.wad_data, &game.umapinfo),
             hud_msgs: Messages::new(&game.wad_data),
             finale: Finale::new(&game.wad_data),
+            automap: Automap::new(&game.wad_data),
         };
         let voxel_manager = load_voxels(
             &options,
@@ -120,6 +122,7 @@ impl DoomApp {
         Self {
             game,
             input,
+            pad: GilrsPad::new(),
             cheats: Cheats::new(),
             timestep: TimeStep::new(),
             fps_text: String::new(),
@@ -135,6 +138,20 @@ impl DoomApp {
             post,
         }
     }
+
+    /// Offer a mouse button, wheel step or gamepad press to the menu. True if
+    /// taken.
+    fn device_responder(&mut self, input: Input) -> bool {
+        let menu = self.menu.as_mut().expect("menu not initialized");
+        input_responder(
+            input,
+            self.input.events.mods(),
+            &mut self.game,
+            menu,
+            &mut self.machines,
+            &mut self.cheats,
+        )
+    }
 }
 
 impl ApplicationHandler for DoomApp {
@@ -293,13 +310,14 @@ impl ApplicationHandler for DoomApp {
         let mut menu = GameMenu::new(
             self.game.game_type.mode,
             &self.game.wad_data,
+            &self.game.umapinfo,
             render_backend.buffer_size().width(),
         );
         menu.init(&self.game);
 
         if let Some(name) = self.options.demo.clone() {
             self.game.start_demo(name);
-        } else if self.options.episode.is_none() && self.options.map.is_none() {
+        } else if !self.game.options.warp {
             self.game.start_title();
         }
         info!("Started title sequence");
@@ -330,7 +348,8 @@ impl ApplicationHandler for DoomApp {
                         ElementState::Pressed if !event.repeat => {
                             let menu = self.menu.as_mut().expect("menu not initialized");
                             let consumed = input_responder(
-                                kc,
+                                Input::Key(kc),
+                                self.input.events.mods(),
                                 &mut self.game,
                                 menu,
                                 &mut self.machines,
@@ -356,11 +375,25 @@ impl ApplicationHandler for DoomApp {
             } => {
                 if let Some(mb) = input::winit_mousebutton_to_mousebtn(button) {
                     match state {
-                        ElementState::Pressed => self.input.events.set_mb(mb),
+                        ElementState::Pressed => {
+                            if !self.device_responder(Input::Mouse(mb)) {
+                                self.input.events.set_mb(mb);
+                            }
+                        }
                         ElementState::Released => self.input.events.unset_mb(mb),
                     }
                 }
             }
+            WindowEvent::MouseWheel {
+                delta,
+                ..
+            } => {
+                if let Some(step) = input::winit_wheel_to_input(delta)
+                    && !self.device_responder(step)
+                {
+                    self.input.events.add_impulse(step);
+                }
+            }
             WindowEvent::Resized(new_size) => {
                 // The backend syncs the live window size every present, so a resize
                 // needs a Screen rebuild ONLY when the engine BUFFER size changes
@@ -405,6 +438,7 @@ impl ApplicationHandler for DoomApp {
                     let mut menu = GameMenu::new(
                         self.game.game_type.mode,
                         &self.game.wad_data,
+                        &self.game.umapinfo,
                         rt.buffer_size().width(),
                     );
                     menu.init(&self.game);
@@ -421,6 +455,12 @@ impl ApplicationHandler for DoomApp {
                     return;
                 }
 
+                for b in self.pad.poll(&mut self.input.events) {
+                    if self.device_responder(Input::Pad(b)) {
+                        self.input.events.unset_pad_button(b);
+                    }
+                }
+
                 {
                     let rt = self
                         .render_backend
@@ -455,6 +495,7 @@ impl ApplicationHandler for DoomApp {
                     let old = self.user_config.clone();
                     self.user_config
                         .apply_config_array(&self.game.config_snapshot());
+                    self.user_config.input.set_bindings(&self.game.bindings);
                     if let Some(rt) = self.render_backend.as_ref() {
                         let (w, h) = rt.window_size();
                         self.user_config.width = w;
@@ -548,6 +589,7 @@ impl ApplicationHandler for DoomApp {
                         let mut new_menu = GameMenu::new(
                             self.game.game_type.mode,
                             &self.game.wad_data,
+                            &self.game.umapinfo,
                             self.render_backend.as_ref().unwrap().buffer_size().width(),
                         );
                         new_menu.init(&self.game);
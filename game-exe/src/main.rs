@@ -21,7 +21,7 @@ use simplelog::TermLogger;
 use std::error::Error;
 use std::path::PathBuf;
 
-use gamestate::{Game, prepare_wad};
+use gamestate::{CONNECT_TIMEOUT, Game, NetGame, prepare_wad};
 
 use crate::config::UserConfig;
 #[cfg(feature = "display-sdl2")]
@@ -59,6 +59,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         simplelog::ColorChoice::Auto,
     )?;
 
+    if !options.trace_diff.is_empty() {
+        return trace_diff(&options.trace_diff);
+    }
+
     let mut user_config = UserConfig::load();
     user_config.sync_cli(&mut options);
     user_config.write();
@@ -114,7 +118,15 @@ where
         info!("Found display {:?}", video_ctx.display_name(n)?);
     }
 
-    let input = input::InputSdl2::new(sdl_ctx.event_pump()?, (&user_config.input).into());
+    let pads = sdl_ctx
+        .game_controller()
+        .inspect_err(|e| warn!("No game controller support: {e}"))
+        .ok();
+    let mut input = input::InputSdl2::new(sdl_ctx.event_pump()?, pads);
+    input
+        .state
+        .events
+        .set_pad_config(user_config.input.pad.clone());
 
     sdl_ctx.mouse().show_cursor(false);
     sdl_ctx.mouse().set_relative_mouse_mode(true);
@@ -127,9 +139,11 @@ where
         snd_thread,
         user_config.to_config_array(),
     );
+    game.bindings = (&user_config.input).into();
     game.pic_data.set_crt_gamma(user_config.crt_gamma);
     game.pic_data
         .set_palette_fade(options.palette_fade.unwrap_or_default().into());
+    join_net_game(&mut game)?;
     // The loop owns window/backend creation; `sdl_ctx` stays alive here (the
     // `event_pump` moved into `input` is owned, not borrowing it) until the loop
     // returns.
@@ -148,7 +162,10 @@ fn run_winit(
     options: CLIOptions,
 ) -> Result<(), Box<dyn Error>> {
     let (snd_tx, snd_thread) = init_sound_no_sdl(&wad, user_config);
-    let input_state = input::InputState::new((&user_config.input).into());
+    let mut input_state = input::InputState::default();
+    input_state
+        .events
+        .set_pad_config(user_config.input.pad.clone());
     let event_loop = EventLoop::new().expect("failed to create winit event loop");
 
     let mut game = Game::new(
@@ -158,9 +175,11 @@ fn run_winit(
         snd_thread,
         user_config.to_config_array(),
     );
+    game.bindings = (&user_config.input).into();
     game.pic_data.set_crt_gamma(user_config.crt_gamma);
     game.pic_data
         .set_palette_fade(options.palette_fade.unwrap_or_default().into());
+    join_net_game(&mut game)?;
 
     // Headless mode: tick the game with no window/render, for deterministic
     // demo playback (CI regression checks, demo-trace recording). The winit
@@ -185,7 +204,7 @@ fn run_winit(
 ))]
 fn run_headless(mut game: Game, mut input: input::InputState, options: CLIOptions) {
     use crate::d_main::run_game_tic;
-    use doom_ui::{Finale, GameMenu, Intermission, Messages, Statusbar};
+    use doom_ui::{Automap, Finale, GameMenu, Intermission, Messages, Statusbar};
     use gamestate::subsystems::GameSubsystem;
     use gamestate_traits::SubsystemTrait as _;
 
@@ -196,8 +215,14 @@ fn run_headless(mut game: Game, mut input: input::InputState, options: CLIOption
         intermission: Intermission::new(game.game_type.mode, &game.wad_data, &game.umapinfo),
         hud_msgs: Messages::new(&game.wad_data),
         finale: Finale::new(&game.wad_data),
+        automap: Automap::new(&game.wad_data),
     };
-    let mut menu = GameMenu::new(game.game_type.mode, &game.wad_data, HEADLESS_WIDTH);
+    let mut menu = GameMenu::new(
+        game.game_type.mode,
+        &game.wad_data,
+        &game.umapinfo,
+        HEADLESS_WIDTH,
+    );
     menu.init(&game);
 
     if let Some(name) = options.demo.clone() {
@@ -214,6 +239,10 @@ fn run_headless(mut game: Game, mut input: input::InputState, options: CLIOption
 
     while game.running() && tics < max_tics {
         run_game_tic(&mut game, &mut input, &mut menu, &mut machines);
+        if game.is_netgame() && game.frozen {
+            // Waiting on the other players
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
         // No render path consumes screen wipes, so clear the wipe state each
         // tic (a real wipe is purely visual). Without this, gameplay stays
         // frozen behind a pending ForceWipe and tics never advance.
@@ -222,6 +251,38 @@ fn run_headless(mut game: Game, mut input: input::InputState, options: CLIOption
     }
 }
 
+/// Print where two demo traces first diverge. Errors if they do, so scripts
+/// can bisect on the exit status.
+fn trace_diff(paths: &[String]) -> Result<(), Box<dyn Error>> {
+    let [a, b] = paths else {
+        return Err("--trace-diff takes exactly two files".into());
+    };
+    let divergence = gameplay::demo_trace::first_divergence(
+        &std::fs::read_to_string(a)?,
+        &std::fs::read_to_string(b)?,
+    )?;
+    if let Some(d) = divergence {
+        print!("{d}");
+        return Err("traces diverge".into());
+    }
+    println!("traces match");
+    Ok(())
+}
+
+/// Connect to the other players of the netgame given on the command line, if
+/// any, before the first tic runs.
+fn join_net_game(game: &mut Game) -> Result<(), Box<dyn Error>> {
+    if let Some(settings) = game.options.net.clone() {
+        log::info!(
+            "Waiting for {} other player(s) to join...",
+            settings.peers.len()
+        );
+        let net = NetGame::connect(&settings, &mut game.options, CONNECT_TIMEOUT)?;
+        game.start_net_game(net);
+    }
+    Ok(())
+}
+
 /// Initialise the sound server (SDL2 display path).
 #[cfg(feature = "display-sdl2")]
 fn init_sound(
@@ -279,5 +340,8 @@ fn init_sound_rodio(
     if let Err(e) = tx.send(SoundAction::MusicVolume(config.mus_vol)) {
         warn!("Failed to send initial music volume: {e}");
     }
+    if let Err(e) = tx.send(SoundAction::SfxPitchShift(config.pitch_shift)) {
+        warn!("Failed to send initial sfx pitch shift: {e}");
+    }
     (tx, thread)
 }
@@ -0,0 +1,800 @@
+//! Apply DEHACKED/BEX patches to copies of the info tables.
+//!
+//! Patches are applied in order onto `InfoTables::default()` and the result
+//! is installed once, before the first level loads. Field names follow
+//! Chocolate Doom's `deh_*.c` mappings.
+
+use log::{debug, info, warn};
+use sound_common::{
+    MusTrack, SfxInfoBase, SfxName, install_music_names, install_sfx_info, sfx_info,
+};
+use wad::dehacked::{DehBlock, DehPatch, parse_int};
+
+use crate::doom_def::{AmmoType, WeaponFlag};
+use crate::info::{ActionId, MapObjInfo, STATES, SpriteNum, StateData, StateNum};
+use crate::lang::{StringTable, install_strings};
+use crate::tables::{InfoTables, install_tables};
+use crate::thing::{MapObjFlag, MapObjFlag2};
+
+const NUM_WEAPONS: usize = 9;
+const NUM_AMMO: usize = 4;
+/// `Monsters Infight` values understood by vanilla DeHackEd.
+const INFIGHT_OFF: i32 = 202;
+const INFIGHT_ON: i32 = 221;
+/// `Doom version` declared by MBF21 patches.
+const MBF21_VERSION: i32 = 2021;
+/// Codepointer arguments a frame can carry.
+const NUM_ARGS: usize = 8;
+
+/// Everything a set of patches can touch, staged before install.
+struct Staged {
+    tables: InfoTables,
+    sfx: Vec<SfxInfoBase>,
+    music: Vec<String>,
+    strings: StringTable,
+    /// Per frame, a bit for each `Args` field a patch set explicitly.
+    explicit_args: Vec<u8>,
+}
+
+/// Patch the info tables, sfx and music names, and strings with `patches` in
+/// order, then install the result. Must run before anything reads the
+/// tables; a late call is logged and ignored.
+pub fn apply_dehacked(patches: &[DehPatch]) {
+    if patches.is_empty() {
+        return;
+    }
+
+    let mut staged = Staged {
+        tables: InfoTables::default(),
+        sfx: sfx_info().to_vec(),
+        music: (0..MusTrack::NumMus as u8)
+            .filter_map(|i| MusTrack::try_from(i).ok())
+            .map(MusTrack::base_name)
+            .collect(),
+        strings: StringTable::default(),
+        explicit_args: vec![0; StateNum::Count as usize],
+    };
+    for patch in patches {
+        apply_patch(&mut staged, patch);
+    }
+    apply_default_args(&mut staged);
+
+    let tables = install_tables(staged.tables);
+    let sfx = install_sfx_info(staged.sfx);
+    let music = install_music_names(staged.music);
+    let strings = install_strings(staged.strings);
+    if tables && sfx && music && strings {
+        info!("Applied {} DEHACKED patch(es)", patches.len());
+    } else {
+        warn!("DEHACKED applied after the info tables were in use, some changes are lost");
+    }
+}
+
+fn apply_patch(staged: &mut Staged, patch: &DehPatch) {
+    let tables = &mut staged.tables;
+    if patch.doom_version == Some(MBF21_VERSION) {
+        tables.mbf21 = true;
+    }
+    for block in &patch.things {
+        apply_thing(tables, block);
+    }
+    for block in &patch.frames {
+        apply_frame(tables, &mut staged.explicit_args, block);
+    }
+    for pointer in &patch.pointers {
+        match (
+            tables.states.get_mut(pointer.frame),
+            STATES.get(pointer.codep_frame),
+        ) {
+            (Some(state), Some(codep)) => state.action = codep.action,
+            _ => warn!(
+                "DEHACKED: Pointer frame {} or codep frame {} out of range",
+                pointer.frame, pointer.codep_frame
+            ),
+        }
+    }
+    for (frame, name) in &patch.code_pointers {
+        match (tables.states.get_mut(*frame), action_by_name(name)) {
+            (Some(state), Some(action)) => state.action = action,
+            (None, _) => warn!("DEHACKED: [CODEPTR] frame {frame} out of range"),
+            (_, None) => warn!("DEHACKED: [CODEPTR] unknown action {name}"),
+        }
+    }
+    for block in &patch.weapons {
+        apply_weapon(tables, block);
+    }
+    for block in &patch.ammo {
+        apply_ammo(tables, block);
+    }
+    for block in &patch.sounds {
+        apply_sound(&mut staged.sfx, block);
+    }
+    for (key, value) in &patch.misc {
+        apply_misc(tables, key, value);
+    }
+    for (key, value) in &patch.cheats {
+        apply_cheat(tables, key, value);
+    }
+    for (original, replacement) in &patch.texts {
+        apply_text(staged, original, replacement);
+    }
+    for (mnemonic, value) in &patch.strings {
+        staged.strings.replace_named(mnemonic, value);
+    }
+    for par in &patch.pars {
+        staged.tables.pars.push((par.episode, par.map, par.seconds));
+    }
+}
+
+/// `Thing N` is 1-based.
+fn apply_thing(tables: &mut InfoTables, block: &DehBlock) {
+    let Some(info) = block
+        .index
+        .checked_sub(1)
+        .and_then(|i| tables.mobj_info.get_mut(i))
+    else {
+        warn!("DEHACKED: Thing {} out of range", block.index);
+        return;
+    };
+    for (key, value) in &block.fields {
+        if key == "bits" {
+            match parse_bits(value, flag_by_name) {
+                Some(bits) => info.flags = MapObjFlag::from_bits_retain(bits),
+                None => warn!("DEHACKED: Thing {} bad Bits `{value}`", block.index),
+            }
+            continue;
+        }
+        if key == "mbf21 bits" {
+            match parse_bits(value, flag2_by_name) {
+                Some(bits) => info.flags2 = MapObjFlag2::from_bits_retain(bits),
+                None => warn!("DEHACKED: Thing {} bad MBF21 Bits `{value}`", block.index),
+            }
+            continue;
+        }
+        let Some(n) = parse_int(value) else {
+            warn!(
+                "DEHACKED: Thing {} {key} = `{value}` is not a number",
+                block.index
+            );
+            continue;
+        };
+        if !set_thing_field(info, key, n) {
+            debug!("DEHACKED: Thing {} ignoring `{key}`", block.index);
+        }
+    }
+}
+
+fn set_thing_field(info: &mut MapObjInfo, key: &str, n: i32) -> bool {
+    match key {
+        "id #" => info.doomednum = n,
+        "initial frame" => set_state(&mut info.spawnstate, n),
+        "hit points" => info.spawnhealth = n,
+        "first moving frame" => set_state(&mut info.seestate, n),
+        "alert sound" => set_sound(&mut info.seesound, n),
+        "reaction time" => info.reactiontime = n,
+        "attack sound" => set_sound(&mut info.attacksound, n),
+        "injury frame" => set_state(&mut info.painstate, n),
+        "pain chance" => info.painchance = n,
+        "pain sound" => set_sound(&mut info.painsound, n),
+        "close attack frame" => set_state(&mut info.meleestate, n),
+        "far attack frame" => set_state(&mut info.missilestate, n),
+        "death frame" => set_state(&mut info.deathstate, n),
+        "exploding frame" => set_state(&mut info.xdeathstate, n),
+        "death sound" => set_sound(&mut info.deathsound, n),
+        "speed" => info.speed = n,
+        "width" => info.radius = n as f32 / 65536.0,
+        "height" => info.height = n as f32 / 65536.0,
+        "mass" => info.mass = n,
+        "missile damage" => info.damage = n,
+        "action sound" => set_sound(&mut info.activesound, n),
+        "respawn frame" => set_state(&mut info.raisestate, n),
+        // MBF21 groups: 0 in a patch is a real group, 0 here means none
+        "infighting group" => info.infighting_group = n.max(-1) + 1,
+        "projectile group" => info.projectile_group = if n < 0 { -1 } else { n + 2 },
+        "splash group" => info.splash_group = n.max(-1) + 1,
+        "rip sound" => set_sound(&mut info.ripsound, n),
+        "fast speed" => info.fastspeed = n,
+        "melee range" => info.meleerange = n / 65536,
+        _ => return false,
+    }
+    true
+}
+
+fn apply_frame(tables: &mut InfoTables, explicit_args: &mut [u8], block: &DehBlock) {
+    let Some(state) = tables.states.get_mut(block.index) else {
+        warn!("DEHACKED: Frame {} out of range", block.index);
+        return;
+    };
+    for (key, value) in &block.fields {
+        let Some(n) = parse_int(value) else {
+            warn!(
+                "DEHACKED: Frame {} {key} = `{value}` is not a number",
+                block.index
+            );
+            continue;
+        };
+        if let Some(arg) = key
+            .strip_prefix("args")
+            .and_then(|i| i.parse::<usize>().ok())
+            .filter(|i| (1..=NUM_ARGS).contains(i))
+        {
+            state.args[arg - 1] = n;
+            explicit_args[block.index] |= 1 << (arg - 1);
+            continue;
+        }
+        if !set_frame_field(state, key, n) {
+            debug!("DEHACKED: Frame {} ignoring `{key}`", block.index);
+        }
+    }
+}
+
+fn set_frame_field(state: &mut StateData, key: &str, n: i32) -> bool {
+    match key {
+        "sprite number" => match SpriteNum::try_from(n as u16) {
+            Ok(sprite) => state.sprite = sprite,
+            Err(_) => warn!("DEHACKED: sprite {n} out of range"),
+        },
+        "sprite subnumber" => state.frame = n as u32,
+        "duration" => state.tics = n,
+        "next frame" => set_state(&mut state.next_state, n),
+        "unknown 1" => state.misc1 = n,
+        "unknown 2" => state.misc2 = n,
+        _ => return false,
+    }
+    true
+}
+
+fn apply_weapon(tables: &mut InfoTables, block: &DehBlock) {
+    let Some(weapon) = tables.weapon_info.get_mut(block.index) else {
+        warn!(
+            "DEHACKED: Weapon {} out of range (0..{NUM_WEAPONS})",
+            block.index
+        );
+        return;
+    };
+    for (key, value) in &block.fields {
+        if key == "mbf21 bits" {
+            match parse_bits(value, weapon_flag_by_name) {
+                Some(bits) => weapon.flags = WeaponFlag::from_bits_retain(bits),
+                None => warn!("DEHACKED: Weapon {} bad MBF21 Bits `{value}`", block.index),
+            }
+            continue;
+        }
+        let Some(n) = parse_int(value) else {
+            warn!(
+                "DEHACKED: Weapon {} {key} = `{value}` is not a number",
+                block.index
+            );
+            continue;
+        };
+        match key.as_str() {
+            "ammo type" => weapon.ammo = AmmoType::from(n.max(0) as usize),
+            // DeHackEd's names for these two are swapped relative to their use
+            "deselect frame" => set_state(&mut weapon.upstate, n),
+            "select frame" => set_state(&mut weapon.downstate, n),
+            "bobbing frame" => set_state(&mut weapon.readystate, n),
+            "shooting frame" => set_state(&mut weapon.atkstate, n),
+            "firing frame" => set_state(&mut weapon.flashstate, n),
+            "ammo per shot" => weapon.ammo_per_shot = n.max(0) as u32,
+            _ => debug!("DEHACKED: Weapon {} ignoring `{key}`", block.index),
+        }
+    }
+}
+
+fn apply_ammo(tables: &mut InfoTables, block: &DehBlock) {
+    if block.index >= NUM_AMMO {
+        warn!(
+            "DEHACKED: Ammo {} out of range (0..{NUM_AMMO})",
+            block.index
+        );
+        return;
+    }
+    for (key, value) in &block.fields {
+        let Some(n) = parse_int(value) else {
+            warn!(
+                "DEHACKED: Ammo {} {key} = `{value}` is not a number",
+                block.index
+            );
+            continue;
+        };
+        match key.as_str() {
+            "max ammo" => tables.max_ammo[block.index] = n.max(0) as u32,
+            "per ammo" => tables.clip_ammo[block.index] = n.max(0) as u32,
+            _ => debug!("DEHACKED: Ammo {} ignoring `{key}`", block.index),
+        }
+    }
+}
+
+/// Only the priority matters to the mixer; the rest were DOS pointers.
+fn apply_sound(sfx: &mut [SfxInfoBase], block: &DehBlock) {
+    let Some(info) = sfx.get_mut(block.index) else {
+        warn!("DEHACKED: Sound {} out of range", block.index);
+        return;
+    };
+    if let Some(priority) = block.get("value").and_then(parse_int) {
+        info.priority = priority;
+    }
+}
+
+fn apply_misc(tables: &mut InfoTables, key: &str, value: &str) {
+    let Some(n) = parse_int(value) else {
+        warn!("DEHACKED: Misc {key} = `{value}` is not a number");
+        return;
+    };
+    let misc = &mut tables.misc;
+    match key {
+        "initial health" => misc.initial_health = n,
+        "initial bullets" => misc.initial_bullets = n.max(0) as u32,
+        "max health" => misc.max_health = n,
+        "max armor" => misc.max_armor = n,
+        "green armor class" => misc.green_armor_class = n,
+        "blue armor class" => misc.blue_armor_class = n,
+        "max soulsphere" => misc.max_soulsphere = n,
+        "soulsphere health" => misc.soulsphere_health = n,
+        "megasphere health" => misc.megasphere_health = n,
+        "god mode health" => misc.god_mode_health = n,
+        "idfa armor" => misc.idfa_armor = n,
+        "idfa armor class" => misc.idfa_armor_class = n,
+        "idkfa armor" => misc.idkfa_armor = n,
+        "idkfa armor class" => misc.idkfa_armor_class = n,
+        "bfg cells/shot" => tables.weapon_info[6].ammo_per_shot = n.max(0) as u32,
+        "monsters infight" => match n {
+            INFIGHT_OFF => misc.species_infighting = false,
+            INFIGHT_ON => misc.species_infighting = true,
+            _ => warn!("DEHACKED: Monsters Infight = {n} is not {INFIGHT_OFF} or {INFIGHT_ON}"),
+        },
+        _ => debug!("DEHACKED: Misc ignoring `{key}`"),
+    }
+}
+
+fn apply_cheat(tables: &mut InfoTables, key: &str, value: &str) {
+    // Vanilla patches pad with 0xFF terminators, lossy-decoded as U+FFFD
+    let sequence = value
+        .trim_end_matches(|c: char| c == '\u{FFFD}' || c.is_whitespace())
+        .to_ascii_lowercase();
+    if sequence.is_empty() {
+        return;
+    }
+    let sequence: &'static str = Box::leak(sequence.into_boxed_str());
+    let cheats = &mut tables.cheats;
+    match key {
+        "change music" => cheats.mus = sequence,
+        "chainsaw" => cheats.choppers = sequence,
+        "god mode" => cheats.god = sequence,
+        "ammo & keys" => cheats.ammo = sequence,
+        "ammo" => cheats.ammo_no_key = sequence,
+        "no clipping 1" => cheats.noclip = sequence,
+        "no clipping 2" => cheats.commercial_noclip = sequence,
+        "invincibility" => cheats.powerup[0] = sequence,
+        "berserk" => cheats.powerup[1] = sequence,
+        "invisibility" => cheats.powerup[2] = sequence,
+        "radiation suit" => cheats.powerup[3] = sequence,
+        "auto-map" => cheats.powerup[4] = sequence,
+        "lite-amp goggles" => cheats.powerup[5] = sequence,
+        "level warp" => cheats.clev = sequence,
+        "player position" => cheats.mypos = sequence,
+        _ => debug!("DEHACKED: Cheat ignoring `{key}`"),
+    }
+}
+
+/// `Text` replaces whatever string in the executable matched: a sprite
+/// prefix, a sound or music name, or message text.
+fn apply_text(staged: &mut Staged, original: &str, replacement: &str) {
+    if let Some(name) = staged
+        .tables
+        .sprite_names
+        .iter_mut()
+        .find(|n| n.eq_ignore_ascii_case(original))
+    {
+        *name = Box::leak(replacement.to_ascii_uppercase().into_boxed_str());
+        return;
+    }
+    if let Some(sfx) = staged
+        .sfx
+        .iter_mut()
+        .skip(1)
+        .find(|s| s.name.eq_ignore_ascii_case(original))
+    {
+        sfx.name = Box::leak(replacement.to_ascii_lowercase().into_boxed_str());
+        return;
+    }
+    if let Some(music) = staged
+        .music
+        .iter_mut()
+        .find(|m| m.eq_ignore_ascii_case(original))
+    {
+        *music = replacement.to_ascii_lowercase();
+        return;
+    }
+    staged.strings.replace_text(original, replacement);
+}
+
+fn set_state(field: &mut StateNum, n: i32) {
+    match u16::try_from(n) {
+        Ok(n) if n < StateNum::Count as u16 => *field = StateNum::from(n),
+        _ => warn!("DEHACKED: frame {n} out of range"),
+    }
+}
+
+fn set_sound(field: &mut SfxName, n: i32) {
+    match u8::try_from(n).ok().and_then(|n| SfxName::try_from(n).ok()) {
+        Some(sfx) => *field = sfx,
+        None => warn!("DEHACKED: sound {n} out of range"),
+    }
+}
+
+/// MBF21 defaults for codepointer arguments a patch left unset. Applied once
+/// every patch is in, as the pointer may be assigned after the frame.
+fn apply_default_args(staged: &mut Staged) {
+    for (state, explicit) in staged.tables.states.iter_mut().zip(&staged.explicit_args) {
+        let defaults: &[i32] = match state.action {
+            ActionId::AMonsterbulletattack => &[0, 0, 1, 3, 5],
+            ActionId::AMonstermeleeattack => &[3, 8, 0, 0],
+            ActionId::AFindtracer => &[0, 10],
+            ActionId::PWeaponbulletattack => &[0, 0, 1, 5, 3],
+            ActionId::PWeaponmeleeattack => &[2, 10, 65536, 0, 0],
+            _ => continue,
+        };
+        for (i, default) in defaults.iter().enumerate() {
+            if explicit & (1 << i) == 0 {
+                state.args[i] = *default;
+            }
+        }
+    }
+}
+
+/// `Bits` is a number or mnemonics joined by `+`, `|`, `,` or spaces.
+fn parse_bits(value: &str, by_name: fn(&str) -> Option<u32>) -> Option<u32> {
+    if let Some(n) = parse_int(value) {
+        return Some(n as u32);
+    }
+    value
+        .split(|c: char| c == '+' || c == '|' || c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .try_fold(0u32, |bits, name| {
+            let flag = parse_int(name)
+                .map(|n| n as u32)
+                .or_else(|| by_name(name))?;
+            Some(bits | flag)
+        })
+}
+
+fn flag_by_name(name: &str) -> Option<u32> {
+    let bits = match name.to_ascii_uppercase().as_str() {
+        "SPECIAL" => MapObjFlag::Special.bits(),
+        "SOLID" => MapObjFlag::Solid.bits(),
+        "SHOOTABLE" => MapObjFlag::Shootable.bits(),
+        "NOSECTOR" => MapObjFlag::Nosector.bits(),
+        "NOBLOCKMAP" => MapObjFlag::Noblockmap.bits(),
+        "AMBUSH" => MapObjFlag::Ambush.bits(),
+        "JUSTHIT" => MapObjFlag::Justhit.bits(),
+        "JUSTATTACKED" => MapObjFlag::Justattacked.bits(),
+        "SPAWNCEILING" => MapObjFlag::Spawnceiling.bits(),
+        "NOGRAVITY" => MapObjFlag::Nogravity.bits(),
+        "DROPOFF" => MapObjFlag::Dropoff.bits(),
+        "PICKUP" => MapObjFlag::Pickup.bits(),
+        "NOCLIP" => MapObjFlag::Noclip.bits(),
+        "SLIDE" => MapObjFlag::Slide.bits(),
+        "FLOAT" => MapObjFlag::Float.bits(),
+        "TELEPORT" => MapObjFlag::Teleport.bits(),
+        "MISSILE" => MapObjFlag::Missile.bits(),
+        "DROPPED" => MapObjFlag::Dropped.bits(),
+        "SHADOW" => MapObjFlag::Shadow.bits(),
+        "NOBLOOD" => MapObjFlag::Noblood.bits(),
+        "CORPSE" => MapObjFlag::Corpse.bits(),
+        "INFLOAT" => MapObjFlag::Infloat.bits(),
+        "COUNTKILL" => MapObjFlag::Countkill.bits(),
+        "COUNTITEM" => MapObjFlag::Countitem.bits(),
+        "SKULLFLY" => MapObjFlag::Skullfly.bits(),
+        "NOTDMATCH" => MapObjFlag::Notdmatch.bits(),
+        "TRANSLATION1" => 0x400_0000,
+        "TRANSLATION2" => 0x800_0000,
+        // Boom/MBF bits, kept so later extensions can read them
+        "TOUCHY" => 0x1000_0000,
+        "BOUNCES" => 0x2000_0000,
+        "FRIEND" => 0x4000_0000,
+        "TRANSLUCENT" => MapObjFlag::Translucent.bits(),
+        _ => return None,
+    };
+    Some(bits)
+}
+
+fn flag2_by_name(name: &str) -> Option<u32> {
+    let flag = match name.to_ascii_uppercase().as_str() {
+        "LOGRAV" => MapObjFlag2::Lograv,
+        "SHORTMRANGE" => MapObjFlag2::Shortmrange,
+        "DMGIGNORED" => MapObjFlag2::Dmgignored,
+        "NORADIUSDMG" => MapObjFlag2::Noradiusdmg,
+        "FORCERADIUSDMG" => MapObjFlag2::Forceradiusdmg,
+        "HIGHERMPROB" => MapObjFlag2::Highermprob,
+        "RANGEHALF" => MapObjFlag2::Rangehalf,
+        "NOTHRESHOLD" => MapObjFlag2::Nothreshold,
+        "LONGMELEE" => MapObjFlag2::Longmelee,
+        "BOSS" => MapObjFlag2::Boss,
+        "MAP07BOSS1" => MapObjFlag2::Map07boss1,
+        "MAP07BOSS2" => MapObjFlag2::Map07boss2,
+        "E1M8BOSS" => MapObjFlag2::E1m8boss,
+        "E2M8BOSS" => MapObjFlag2::E2m8boss,
+        "E3M8BOSS" => MapObjFlag2::E3m8boss,
+        "E4M6BOSS" => MapObjFlag2::E4m6boss,
+        "E4M8BOSS" => MapObjFlag2::E4m8boss,
+        "RIP" => MapObjFlag2::Rip,
+        "FULLVOLSOUNDS" => MapObjFlag2::Fullvolsounds,
+        _ => return None,
+    };
+    Some(flag.bits())
+}
+
+fn weapon_flag_by_name(name: &str) -> Option<u32> {
+    let flag = match name.to_ascii_uppercase().as_str() {
+        "NOTHRUST" => WeaponFlag::Nothrust,
+        "SILENT" => WeaponFlag::Silent,
+        "NOAUTOFIRE" => WeaponFlag::Noautofire,
+        "FLEEMELEE" => WeaponFlag::Fleemelee,
+        "AUTOSWITCHFROM" => WeaponFlag::Autoswitchfrom,
+        "NOAUTOSWITCHTO" => WeaponFlag::Noautoswitchto,
+        _ => return None,
+    };
+    Some(flag.bits())
+}
+
+/// Action by BEX `[CODEPTR]` name, with or without the `A_` prefix.
+fn action_by_name(name: &str) -> Option<ActionId> {
+    let lower = name.trim().to_ascii_lowercase();
+    let name = lower.strip_prefix("a_").unwrap_or(&lower);
+    let action = match name {
+        "null" | "nothing" => ActionId::None,
+        "bfgspray" => ActionId::ABfgspray,
+        "explode" => ActionId::AExplode,
+        "pain" => ActionId::APain,
+        "playerscream" => ActionId::APlayerscream,
+        "fall" => ActionId::AFall,
+        "xscream" => ActionId::AXscream,
+        "look" => ActionId::ALook,
+        "chase" => ActionId::AChase,
+        "facetarget" => ActionId::AFacetarget,
+        "posattack" => ActionId::APosattack,
+        "scream" => ActionId::AScream,
+        "sposattack" => ActionId::ASposattack,
+        "vilechase" => ActionId::AVilechase,
+        "vilestart" => ActionId::AVilestart,
+        "viletarget" => ActionId::AViletarget,
+        "vileattack" => ActionId::AVileattack,
+        "startfire" => ActionId::AStartfire,
+        "fire" => ActionId::AFire,
+        "firecrackle" => ActionId::AFirecrackle,
+        "tracer" => ActionId::ATracer,
+        "skelwhoosh" => ActionId::ASkelwhoosh,
+        "skelfist" => ActionId::ASkelfist,
+        "skelmissile" => ActionId::ASkelmissile,
+        "fatraise" => ActionId::AFatraise,
+        "fatattack1" => ActionId::AFatattack1,
+        "fatattack2" => ActionId::AFatattack2,
+        "fatattack3" => ActionId::AFatattack3,
+        "bossdeath" => ActionId::ABossdeath,
+        "cposattack" => ActionId::ACposattack,
+        "cposrefire" => ActionId::ACposrefire,
+        "troopattack" => ActionId::ATroopattack,
+        "sargattack" => ActionId::ASargattack,
+        "headattack" => ActionId::AHeadattack,
+        "bruisattack" => ActionId::ABruisattack,
+        "skullattack" => ActionId::ASkullattack,
+        "metal" => ActionId::AMetal,
+        "spidrefire" => ActionId::ASpidrefire,
+        "babymetal" => ActionId::ABabymetal,
+        "bspiattack" => ActionId::ABspiattack,
+        "hoof" => ActionId::AHoof,
+        "cyberattack" => ActionId::ACyberattack,
+        "painattack" => ActionId::APainattack,
+        "paindie" => ActionId::APaindie,
+        "keendie" => ActionId::AKeendie,
+        "brainpain" => ActionId::ABrainpain,
+        "brainscream" => ActionId::ABrainscream,
+        "braindie" => ActionId::ABraindie,
+        "brainawake" => ActionId::ABrainawake,
+        "brainspit" => ActionId::ABrainspit,
+        "spawnsound" => ActionId::ASpawnsound,
+        "spawnfly" => ActionId::ASpawnfly,
+        "brainexplode" => ActionId::ABrainexplode,
+        "spawnobject" => ActionId::ASpawnobject,
+        "monsterprojectile" => ActionId::AMonsterprojectile,
+        "monsterbulletattack" => ActionId::AMonsterbulletattack,
+        "monstermeleeattack" => ActionId::AMonstermeleeattack,
+        "radiusdamage" => ActionId::ARadiusdamage,
+        "noisealert" => ActionId::ANoisealert,
+        "healchase" => ActionId::AHealchase,
+        "seektracer" => ActionId::ASeektracer,
+        "findtracer" => ActionId::AFindtracer,
+        "cleartracer" => ActionId::ACleartracer,
+        "jumpifhealthbelow" => ActionId::AJumpifhealthbelow,
+        "jumpiftargetinsight" => ActionId::AJumpiftargetinsight,
+        "jumpiftargetcloser" => ActionId::AJumpiftargetcloser,
+        "jumpiftracerinsight" => ActionId::AJumpiftracerinsight,
+        "jumpiftracercloser" => ActionId::AJumpiftracercloser,
+        "jumpifflagsset" => ActionId::AJumpifflagsset,
+        "addflags" => ActionId::AAddflags,
+        "removeflags" => ActionId::ARemoveflags,
+        "light0" => ActionId::PLight0,
+        "weaponready" => ActionId::PWeaponready,
+        "lower" => ActionId::PLower,
+        "raise" => ActionId::PRaise,
+        "punch" => ActionId::PPunch,
+        "refire" => ActionId::PRefire,
+        "firepistol" => ActionId::PFirepistol,
+        "light1" => ActionId::PLight1,
+        "fireshotgun" => ActionId::PFireshotgun,
+        "light2" => ActionId::PLight2,
+        "fireshotgun2" => ActionId::PFireshotgun2,
+        "checkreload" => ActionId::PCheckreload,
+        "openshotgun2" => ActionId::POpenshotgun2,
+        "loadshotgun2" => ActionId::PLoadshotgun2,
+        "closeshotgun2" => ActionId::PCloseshotgun2,
+        "firecgun" => ActionId::PFirecgun,
+        "gunflash" => ActionId::PGunflash,
+        "firemissile" => ActionId::PFiremissile,
+        "saw" => ActionId::PSaw,
+        "fireplasma" => ActionId::PFireplasma,
+        "bfgsound" => ActionId::PBfgsound,
+        "firebfg" => ActionId::PFirebfg,
+        "weaponprojectile" => ActionId::PWeaponprojectile,
+        "weaponbulletattack" => ActionId::PWeaponbulletattack,
+        "weaponmeleeattack" => ActionId::PWeaponmeleeattack,
+        "weaponsound" => ActionId::PWeaponsound,
+        "weaponalert" => ActionId::PWeaponalert,
+        "weaponjump" => ActionId::PWeaponjump,
+        "consumeammo" => ActionId::PConsumeammo,
+        "checkammo" => ActionId::PCheckammo,
+        "refireto" => ActionId::PRefireto,
+        "gunflashto" => ActionId::PGunflashto,
+        _ => return None,
+    };
+    Some(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::info::{MOBJINFO, MapObjKind};
+
+    fn staged() -> Staged {
+        Staged {
+            tables: InfoTables::default(),
+            sfx: sound_common::SFX_INFO_BASE.to_vec(),
+            music: vec!["e1m1".to_owned()],
+            strings: StringTable::default(),
+            explicit_args: vec![0; StateNum::Count as usize],
+        }
+    }
+
+    fn patched(text: &str) -> Staged {
+        let mut staged = staged();
+        apply_patch(&mut staged, &wad::dehacked::parse(text).unwrap());
+        staged
+    }
+
+    #[test]
+    fn thing_fields_patch_mobjinfo() {
+        let staged = patched(
+            "Thing 2 (Zombieman)\nHit points = 60\nWidth = 1310720\nBits = SOLID+SHOOTABLE\nInitial frame = 174\n",
+        );
+        let info = &staged.tables.mobj_info[MapObjKind::MT_POSSESSED as usize];
+        assert_eq!(info.spawnhealth, 60);
+        assert!((info.radius - 20.0).abs() < f32::EPSILON);
+        assert_eq!(info.flags, MapObjFlag::Solid | MapObjFlag::Shootable);
+        assert_eq!(info.spawnstate, StateNum::from(174));
+        // Untouched entries keep their defaults
+        assert_eq!(
+            staged.tables.mobj_info[MapObjKind::MT_SHOTGUY as usize].spawnhealth,
+            MOBJINFO[MapObjKind::MT_SHOTGUY as usize].spawnhealth
+        );
+    }
+
+    #[test]
+    fn frame_and_pointer_patch_states() {
+        let staged = patched(
+            "Frame 1\nDuration = 7\nNext frame = 2\n\nPointer 0 (Frame 1)\nCodep Frame = 176\n\n[CODEPTR]\nFRAME 2 = Explode\n",
+        );
+        let state = &staged.tables.states[1];
+        assert_eq!(state.tics, 7);
+        assert_eq!(state.next_state, StateNum::PUNCH);
+        assert_eq!(state.action, STATES[176].action);
+        assert_eq!(staged.tables.states[2].action, ActionId::AExplode);
+    }
+
+    #[test]
+    fn weapon_ammo_and_misc() {
+        let staged = patched(
+            "Weapon 1 (Pistol)\nAmmo type = 1\nDeselect frame = 20\n\nAmmo 1 (Shells)\nMax ammo = 99\nPer ammo = 8\n\nMisc 0\nInitial Health = 150\nBFG Cells/Shot = 30\nMonsters Infight = 221\n",
+        );
+        let pistol = &staged.tables.weapon_info[1];
+        assert_eq!(pistol.ammo, AmmoType::Shell);
+        assert_eq!(pistol.upstate, StateNum::SGUNUP);
+        assert_eq!(staged.tables.max_ammo[1], 99);
+        assert_eq!(staged.tables.clip_ammo[1], 8);
+        assert_eq!(staged.tables.misc.initial_health, 150);
+        assert_eq!(staged.tables.weapon_info[6].ammo_per_shot, 30);
+        assert!(staged.tables.misc.species_infighting);
+    }
+
+    #[test]
+    fn text_renames_sprites_sounds_and_music() {
+        let staged = patched("Text 4 4\nTROOBOSS\nText 6 6\npistolshotgn\nText 4 4\ne1m1e1m2\n");
+        assert_eq!(staged.tables.sprite_names[SpriteNum::TROO as usize], "BOSS");
+        assert_eq!(staged.sfx[SfxName::Pistol as usize].name, "shotgn");
+        assert_eq!(staged.music[0], "e1m2");
+    }
+
+    #[test]
+    fn cheat_and_sound_blocks() {
+        let staged = patched("Cheat 0\nGod mode = idgod\n\nSound 1\nValue = 10\n");
+        assert_eq!(staged.tables.cheats.god, "idgod");
+        assert_eq!(staged.sfx[1].priority, 10);
+    }
+
+    #[test]
+    fn out_of_range_entries_are_ignored() {
+        let staged = patched("Thing 0\nHit points = 1\n\nFrame 99999\nDuration = 1\n");
+        assert_eq!(
+            staged.tables.mobj_info[0].spawnhealth,
+            MOBJINFO[0].spawnhealth
+        );
+    }
+
+    #[test]
+    fn codeptr_names() {
+        assert_eq!(action_by_name("A_Chase"), Some(ActionId::AChase));
+        assert_eq!(action_by_name("WeaponReady"), Some(ActionId::PWeaponready));
+        assert_eq!(action_by_name("NULL"), Some(ActionId::None));
+        assert_eq!(action_by_name("A_Nope"), None);
+        assert_eq!(
+            action_by_name("A_MonsterProjectile"),
+            Some(ActionId::AMonsterprojectile)
+        );
+        assert_eq!(action_by_name("A_GunFlashTo"), Some(ActionId::PGunflashto));
+    }
+
+    #[test]
+    fn mbf21_thing_and_weapon_fields() {
+        let staged = patched(
+            "Doom version = 2021
+
+Thing 2
+MBF21 Bits = LOGRAV+BOSS
+Projectile group = 0
+Infighting group = 1
+Splash group = -1
+Melee range = 4194304
+
+Weapon 1
+MBF21 Bits = SILENT
+Ammo per shot = 3
+",
+        );
+        assert!(staged.tables.mbf21);
+        let info = &staged.tables.mobj_info[MapObjKind::MT_POSSESSED as usize];
+        assert_eq!(info.flags2, MapObjFlag2::Lograv | MapObjFlag2::Boss);
+        assert_eq!(info.projectile_group, 2);
+        assert_eq!(info.infighting_group, 2);
+        assert_eq!(info.splash_group, 0);
+        assert_eq!(info.meleerange, 64);
+        let pistol = &staged.tables.weapon_info[1];
+        assert_eq!(pistol.flags, WeaponFlag::Silent);
+        assert_eq!(pistol.ammo_per_shot, 3);
+    }
+
+    #[test]
+    fn mbf21_default_args_fill_unset_fields() {
+        let mut staged = patched(
+            "Frame 1
+Args2 = 7
+
+[CODEPTR]
+FRAME 1 = A_MonsterBulletAttack
+FRAME 2 = A_SpawnObject
+",
+        );
+        apply_default_args(&mut staged);
+        assert_eq!(staged.tables.states[1].args, [0, 7, 1, 3, 5, 0, 0, 0]);
+        assert_eq!(staged.tables.states[2].args, [0; 8]);
+    }
+}
@@ -1,5 +1,4 @@
-//! TEMP demo-determinism trace. Throwaway debug instrument, gated by env vars.
-//! Remove before commit.
+//! Demo-determinism trace, gated by env vars.
 //!
 //! `DEMO_TRACE=<path>`  -> one text line per tic:
 //!     `tic rndindex prndindex n_things sector_hash thing_hash full_hash`
@@ -9,17 +8,21 @@
 //!
 //! Hashes are FNV-1a over raw integer bits only (FixedT::raw, Angle bam, enum
 //! discriminants) — exact and deterministic, never float-rounded.
+//!
+//! [`first_divergence`] compares two traces or two dumps of the same demo, as
+//! recorded by different builds (e.g. `fixed64` against 32-bit `FixedT`), and
+//! reports the first tic where they disagree. `room4doom --trace-diff` prints
+//! that report.
 
 use std::cell::RefCell;
-use std::fmt::Write as _;
+use std::fmt::{self, Write as _};
 use std::fs::{File, OpenOptions};
 use std::io::Write as _;
-use std::ptr::addr_of;
 
 use math::{get_prndindex, get_rndindex};
 
 use crate::LevelState;
-use crate::info::STATES;
+use crate::tables::state_index;
 use crate::thinker::{Thinker, ThinkerData};
 
 const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
@@ -61,12 +64,6 @@ fn open_append(path: &str) -> Option<File> {
     OpenOptions::new().create(true).append(true).open(path).ok()
 }
 
-fn state_index(state: &'static crate::info::StateData) -> u64 {
-    let base = addr_of!(STATES).cast::<crate::info::StateData>() as usize;
-    let ptr = std::ptr::from_ref(state) as usize;
-    ((ptr - base) / size_of::<crate::info::StateData>()) as u64
-}
-
 /// Feed a thing's determinism-relevant state into both the running hash and
 /// (optionally) the human-readable dump string.
 fn hash_thing(h: &mut Fnv, idx: usize, m: &crate::thing::MapObject, dump: Option<&mut String>) {
@@ -79,7 +76,7 @@ fn hash_thing(h: &mut Fnv, idx: usize, m: &crate::thing::MapObject, dump: Option
     h.w_i64(m.momz.raw() as i64);
     h.w_i64(m.health as i64);
     h.w_i64(m.tics as i64);
-    h.w_u64(state_index(m.state));
+    h.w_u64(state_index(m.state) as u64);
     h.w_u64(m.flags.bits() as u64);
     h.w_u64(m.kind as u64);
     h.w_u64(m.movedir as u64);
@@ -152,30 +149,14 @@ pub fn trace_tic(level: &LevelState) {
     let rnd = get_rndindex() as u64;
     let prnd = get_prndindex() as u64;
 
-    let mut sector_h = Fnv::new();
-    for s in level.level_data.sectors.iter() {
-        sector_h.w_i64(s.floorheight.raw() as i64);
-        sector_h.w_i64(s.ceilingheight.raw() as i64);
-        sector_h.w_u64(s.lightlevel as u64);
-        sector_h.w_u64(s.special as u64);
-    }
-
     TRACE.with(|t| {
         let mut guard = t.borrow_mut();
         let st = guard.as_mut().unwrap();
         let want_dump = st.dump.is_some() && tic >= st.from && tic <= st.to;
 
-        let mut thing_h = Fnv::new();
         let mut dump_buf = if want_dump { Some(String::new()) } else { None };
-        let mut count: u64 = 0;
-        let mut idx = 0usize;
-        level.thinkers.for_each(|th: &Thinker| {
-            hash_thinker(&mut thing_h, idx, th, dump_buf.as_mut());
-            if matches!(th.data(), ThinkerData::MapObject(_)) {
-                count += 1;
-            }
-            idx += 1;
-        });
+        let sector_h = hash_sectors(level, dump_buf.as_mut());
+        let (thing_h, count) = hash_thinkers(level, dump_buf.as_mut());
 
         let mut full = Fnv::new();
         full.w_u64(rnd);
@@ -199,8 +180,60 @@ pub fn trace_tic(level: &LevelState) {
     });
 }
 
+fn hash_sectors(level: &LevelState, mut dump: Option<&mut String>) -> Fnv {
+    let mut h = Fnv::new();
+    for (idx, s) in level.level_data.sectors.iter().enumerate() {
+        h.w_i64(s.floorheight.raw() as i64);
+        h.w_i64(s.ceilingheight.raw() as i64);
+        h.w_u64(s.lightlevel as u64);
+        h.w_u64(s.special as u64);
+        if let Some(d) = dump.as_deref_mut() {
+            let _ = writeln!(
+                d,
+                "  S{idx} floor={} ceil={} light={} special={}",
+                s.floorheight.raw(),
+                s.ceilingheight.raw(),
+                s.lightlevel,
+                s.special
+            );
+        }
+    }
+    h
+}
+
+/// Hash every thinker, returning the hash and the number of map objects.
+fn hash_thinkers(level: &LevelState, mut dump: Option<&mut String>) -> (Fnv, u64) {
+    let mut h = Fnv::new();
+    let mut count: u64 = 0;
+    let mut idx = 0usize;
+    level.thinkers.for_each(|th: &Thinker| {
+        hash_thinker(&mut h, idx, th, dump.as_deref_mut());
+        if matches!(th.data(), ThinkerData::MapObject(_)) {
+            count += 1;
+        }
+        idx += 1;
+    });
+    (h, count)
+}
+
+/// Hash of the gameplay state: the `p_random` index plus every sector and
+/// thinker.
+///
+/// Unlike the trace's full hash this leaves out the `m_random` index, which
+/// menus, wipes and the status bar also draw from, so it only differs between
+/// two games whose simulation has diverged. Netgames compare it in their
+/// consistency checks.
+pub fn state_hash(level: &LevelState) -> u64 {
+    let mut h = Fnv::new();
+    h.w_u64(get_prndindex() as u64);
+    h.w_u64(hash_sectors(level, None).done());
+    h.w_u64(hash_thinkers(level, None).0.done());
+    h.done()
+}
+
 /// Hash a thinker's state by variant. Movers feed their height/direction/count
-/// fields; map objects defer to `hash_thing`.
+/// fields, and the friction, pusher and scroller effects the fields that move
+/// things; map objects defer to `hash_thing`.
 fn hash_thinker(h: &mut Fnv, idx: usize, th: &Thinker, mut dump: Option<&mut String>) {
     match th.data() {
         ThinkerData::MapObject(m) => hash_thing(h, idx, m, dump.as_deref_mut()),
@@ -274,6 +307,62 @@ fn hash_thinker(h: &mut Fnv, idx: usize, th: &Thinker, mut dump: Option<&mut Str
                 );
             }
         }
+        ThinkerData::Friction(f) => {
+            h.w_u64(5);
+            h.w_u64(f.sector.num as u64);
+            h.w_i64(f.friction.raw() as i64);
+            h.w_i64(f.movefactor as i64);
+            if let Some(s) = dump {
+                let _ = writeln!(
+                    s,
+                    "  Fr{idx} friction sector={} friction={} move={}",
+                    f.sector.num,
+                    f.friction.raw(),
+                    f.movefactor
+                );
+            }
+        }
+        ThinkerData::Pusher(p) => {
+            h.w_u64(6);
+            h.w_u64(p.sector.num as u64);
+            h.w_u64(p.kind as u64);
+            h.w_i64(p.x_mag as i64);
+            h.w_i64(p.y_mag as i64);
+            h.w_i64(p.magnitude as i64);
+            h.w_i64(p.radius as i64);
+            h.w_i64(p.x as i64);
+            h.w_i64(p.y as i64);
+            if let Some(s) = dump {
+                let _ = writeln!(
+                    s,
+                    "  Pu{idx} push sector={} kind={:?} xmag={} ymag={} mag={} radius={} x={} y={}",
+                    p.sector.num, p.kind, p.x_mag, p.y_mag, p.magnitude, p.radius, p.x, p.y
+                );
+            }
+        }
+        ThinkerData::Scroller(sc) => {
+            h.w_u64(7);
+            h.w_u64(sc.kind as u64);
+            h.w_u64(sc.affectee as u64);
+            h.w_i64(sc.dx.raw() as i64);
+            h.w_i64(sc.dy.raw() as i64);
+            h.w_i64(sc.last_height.raw() as i64);
+            h.w_i64(sc.vdx.raw() as i64);
+            h.w_i64(sc.vdy.raw() as i64);
+            if let Some(s) = dump {
+                let _ = writeln!(
+                    s,
+                    "  Sc{idx} scroll kind={:?} affectee={} dx={} dy={} last={} vdx={} vdy={}",
+                    sc.kind,
+                    sc.affectee,
+                    sc.dx.raw(),
+                    sc.dy.raw(),
+                    sc.last_height.raw(),
+                    sc.vdx.raw(),
+                    sc.vdy.raw()
+                );
+            }
+        }
         ThinkerData::LightFlash(_)
         | ThinkerData::StrobeFlash(_)
         | ThinkerData::FireFlicker(_)
@@ -283,3 +372,309 @@ fn hash_thinker(h: &mut Fnv, idx: usize, th: &Thinker, mut dump: Option<&mut Str
         | ThinkerData::Free => {}
     }
 }
+
+/// One tic of a trace or dump: a `tic` entry holding the summary, then (dumps
+/// only) an entry per sector and thinker, each with its `key=value` fields.
+#[derive(Debug)]
+struct Record {
+    tic: u32,
+    entries: Vec<(String, Vec<(String, String)>)>,
+}
+
+/// A field that differs between the two runs. `field` is empty when the whole
+/// entry exists in only one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub entry: String,
+    pub field: String,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+/// The first tic at which two traces disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Index of the tic record, counting from the start of the trace. Unlike
+    /// `tic` this does not restart with each level.
+    pub record: usize,
+    /// `level_time` of the diverging tic
+    pub tic: u32,
+    pub diffs: Vec<FieldDiff>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "first divergence at tic {} (record {})",
+            self.tic, self.record
+        )?;
+        for d in &self.diffs {
+            let a = d.a.as_deref().unwrap_or("-");
+            let b = d.b.as_deref().unwrap_or("-");
+            if d.field.is_empty() {
+                writeln!(f, "  {}: {a} | {b}", d.entry)?;
+            } else {
+                writeln!(f, "  {}.{}: {a} | {b}", d.entry, d.field)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compare two `DEMO_TRACE` files, or two `DEMO_TRACE_DUMP` files, of the same
+/// demo and find the first tic where they differ.
+///
+/// Trace lines compare the `p_random` index, thing count and the sector and
+/// thinker hashes; `m_random` and the combined hash are skipped as they are
+/// not demo-deterministic. Dumps compare every sector and thinker field, which
+/// pinpoints the offending `MapObject`. Records are matched in order, so both
+/// runs must start tracing at the same tic.
+pub fn first_divergence(a: &str, b: &str) -> Result<Option<Divergence>, String> {
+    let a = parse_records(a).map_err(|e| format!("first trace: {e}"))?;
+    let b = parse_records(b).map_err(|e| format!("second trace: {e}"))?;
+
+    for (record, (ra, rb)) in a.iter().zip(&b).enumerate() {
+        let mut diffs = Vec::new();
+        if ra.tic != rb.tic {
+            diffs.push(FieldDiff {
+                entry: "tic".to_owned(),
+                field: "tic".to_owned(),
+                a: Some(ra.tic.to_string()),
+                b: Some(rb.tic.to_string()),
+            });
+        }
+        diff_entries(ra, rb, &mut diffs);
+        if !diffs.is_empty() {
+            return Ok(Some(Divergence {
+                record,
+                tic: ra.tic,
+                diffs,
+            }));
+        }
+    }
+
+    if a.len() == b.len() {
+        return Ok(None);
+    }
+    let record = a.len().min(b.len());
+    let tic = a.get(record).or_else(|| b.get(record)).map_or(0, |r| r.tic);
+    Ok(Some(Divergence {
+        record,
+        tic,
+        diffs: vec![FieldDiff {
+            entry: "trace".to_owned(),
+            field: "records".to_owned(),
+            a: Some(a.len().to_string()),
+            b: Some(b.len().to_string()),
+        }],
+    }))
+}
+
+fn diff_entries(a: &Record, b: &Record, diffs: &mut Vec<FieldDiff>) {
+    let joined = |fields: &[(String, String)]| {
+        fields
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    for (id, fa) in &a.entries {
+        let Some((_, fb)) = b.entries.iter().find(|(other, _)| other == id) else {
+            diffs.push(FieldDiff {
+                entry: id.clone(),
+                field: String::new(),
+                a: Some(joined(fa)),
+                b: None,
+            });
+            continue;
+        };
+        for (key, va) in fa {
+            let vb = fb.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+            if vb != Some(va) {
+                diffs.push(FieldDiff {
+                    entry: id.clone(),
+                    field: key.clone(),
+                    a: Some(va.clone()),
+                    b: vb.cloned(),
+                });
+            }
+        }
+        for (key, vb) in fb {
+            if !fa.iter().any(|(k, _)| k == key) {
+                diffs.push(FieldDiff {
+                    entry: id.clone(),
+                    field: key.clone(),
+                    a: None,
+                    b: Some(vb.clone()),
+                });
+            }
+        }
+    }
+    for (id, fb) in &b.entries {
+        if !a.entries.iter().any(|(other, _)| other == id) {
+            diffs.push(FieldDiff {
+                entry: id.clone(),
+                field: String::new(),
+                a: None,
+                b: Some(joined(fb)),
+            });
+        }
+    }
+}
+
+/// Split a trace or dump into per-tic records.
+fn parse_records(text: &str) -> Result<Vec<Record>, String> {
+    let mut records: Vec<Record> = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let bad = || format!("line {}: can't parse {line:?}", n + 1);
+        if let Some(header) = line
+            .strip_prefix("=== tic ")
+            .and_then(|l| l.strip_suffix(" ==="))
+        {
+            let mut tokens = header.split_whitespace();
+            let tic = tokens.next().and_then(|t| t.parse().ok()).ok_or_else(bad)?;
+            let fields = tokens
+                .map(|t| t.split_once('=').ok_or_else(bad))
+                .filter(|kv| !matches!(kv, Ok(("rnd", _))))
+                .map(|kv| kv.map(|(k, v)| (k.to_owned(), v.to_owned())))
+                .collect::<Result<_, _>>()?;
+            records.push(Record {
+                tic,
+                entries: vec![("tic".to_owned(), fields)],
+            });
+        } else if line.starts_with(' ') {
+            let mut tokens = line.split_whitespace();
+            let id = tokens.next().ok_or_else(bad)?.to_owned();
+            // Bare words, such as a mover's kind, only label the entry
+            let fields = tokens
+                .filter_map(|t| t.split_once('='))
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect();
+            records
+                .last_mut()
+                .ok_or_else(bad)?
+                .entries
+                .push((id, fields));
+        } else if !line.trim().is_empty() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let &[tic, _rnd, prnd, things, sectors, thinkers, _full] = tokens.as_slice() else {
+                return Err(bad());
+            };
+            let fields = [
+                ("prnd", prnd),
+                ("things", things),
+                ("sectors", sectors),
+                ("thinkers", thinkers),
+            ];
+            records.push(Record {
+                tic: tic.parse().map_err(|_| bad())?,
+                entries: vec![(
+                    "tic".to_owned(),
+                    fields
+                        .iter()
+                        .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+                        .collect(),
+                )],
+            });
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACE: &str = "\
+0 0 0 10 00000000000000aa 00000000000000bb 00000000000000cc
+1 3 2 10 00000000000000aa 00000000000000bb 00000000000000cd
+2 5 4 10 00000000000000aa 00000000000000be 00000000000000ce
+";
+
+    const DUMP: &str = "\
+=== tic 7 rnd=1 prnd=9 things=2 ===
+  S0 floor=0 ceil=8388608 light=160 special=0
+  T0 kind=Player x=100 y=200 z=0 ang=0 mom=(0,0,0) hp=100 tics=-1 st=149 dir=NoDir mc=0 rt=0 thr=0 flags=0x2000 frame=0
+  T1 kind=Shotguy x=300 y=400 z=0 ang=0 mom=(0,0,0) hp=30 tics=10 st=216 dir=East mc=3 rt=8 thr=0 flags=0x400006 frame=0
+  D2 door top=8388608 speed=131072 dir=1 wait=150 cd=0
+  P3 plat speed=65536 low=0 high=8388608 count=0 status=Down
+";
+
+    #[test]
+    fn identical_traces_match() {
+        assert_eq!(first_divergence(TRACE, TRACE), Ok(None));
+        assert_eq!(first_divergence(DUMP, DUMP), Ok(None));
+    }
+
+    #[test]
+    fn m_random_is_ignored() {
+        let other = TRACE.replace("1 3 2", "1 4 2").replace("cd\n", "ff\n");
+        assert_eq!(first_divergence(TRACE, &other), Ok(None));
+    }
+
+    #[test]
+    fn first_hash_mismatch_is_reported() {
+        let other = TRACE.replace("00bb 00000000000000cd", "00bf 00000000000000cd");
+        let div = first_divergence(TRACE, &other).unwrap().unwrap();
+        assert_eq!(div.record, 1);
+        assert_eq!(div.tic, 1);
+        assert_eq!(
+            div.diffs,
+            vec![FieldDiff {
+                entry: "tic".to_owned(),
+                field: "thinkers".to_owned(),
+                a: Some("00000000000000bb".to_owned()),
+                b: Some("00000000000000bf".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn shorter_trace_diverges_where_it_ends() {
+        let short: String = TRACE.lines().take(2).map(|l| format!("{l}\n")).collect();
+        let div = first_divergence(TRACE, &short).unwrap().unwrap();
+        assert_eq!(div.record, 2);
+        assert_eq!(div.tic, 2);
+        assert_eq!(div.diffs[0].entry, "trace");
+    }
+
+    #[test]
+    fn dump_reports_thing_fields() {
+        let other = DUMP
+            .replace("x=300", "x=301")
+            .replace("rnd=1", "rnd=5")
+            .replace(" T1 ", " T2 ");
+        let div = first_divergence(DUMP, &other).unwrap().unwrap();
+        assert_eq!(div.tic, 7);
+        assert_eq!(div.diffs.len(), 2);
+        assert_eq!(div.diffs[0].entry, "T1");
+        assert_eq!(div.diffs[0].b, None);
+        assert_eq!(div.diffs[1].entry, "T2");
+        assert!(div.diffs[1].b.as_ref().unwrap().contains("x=301"));
+
+        let other = DUMP.replace("hp=30", "hp=25");
+        let div = first_divergence(DUMP, &other).unwrap().unwrap();
+        assert_eq!(
+            div.to_string(),
+            "first divergence at tic 7 (record 0)\n  T1.hp: 30 | 25\n"
+        );
+    }
+
+    #[test]
+    fn dump_reports_mover_fields() {
+        let other = DUMP.replace("dir=1 wait", "dir=-1 wait");
+        let div = first_divergence(DUMP, &other).unwrap().unwrap();
+        assert_eq!(
+            div.to_string(),
+            "first divergence at tic 7 (record 0)\n  D2.dir: 1 | -1\n"
+        );
+    }
+
+    #[test]
+    fn garbage_is_an_error() {
+        assert!(first_divergence("1 2 3\n", TRACE).is_err());
+        assert!(first_divergence(TRACE, "  T0 x=1\n").is_err());
+    }
+}
@@ -1,6 +1,9 @@
+use bitflags::bitflags;
+
 use crate::info::StateNum;
 use crate::player_sprite::*;
 use crate::thing::enemy::*;
+use crate::thing::mbf21::*;
 use crate::{MapObject, Player, PspDef};
 
 /// Do not know where this is set
@@ -27,6 +30,8 @@ pub static DOOM_VERSION: u8 = 109;
 pub const MAXPLAYERS: usize = 4;
 pub const MAX_DEATHMATCH_STARTS: usize = 10;
 pub const MAX_RESPAWNS: usize = 128;
+/// Player corpses kept around in a netgame before the oldest is removed
+pub const BODYQUESIZE: usize = 32;
 
 #[derive(Debug, Copy, Clone)]
 pub enum GameAction {
@@ -124,7 +129,27 @@ pub enum PowerDuration {
     IronFeet = (60 * TICRATE) as isize,
 }
 
+bitflags! {
+    /// MBF21 weapon flags.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct WeaponFlag: u32 {
+        /// Hits don't push the target (chainsaw).
+        const Nothrust = 0x1;
+        /// Firing doesn't wake monsters.
+        const Silent = 0x2;
+        /// Holding fire doesn't refire (rocket launcher, BFG).
+        const Noautofire = 0x4;
+        /// Monsters back away from a player holding it (fist, chainsaw).
+        const Fleemelee = 0x8;
+        /// Switched away from when ammo for a better weapon is picked up.
+        const Autoswitchfrom = 0x10;
+        /// Never switched to by an ammo pickup.
+        const Noautoswitchto = 0x20;
+    }
+}
+
 /// Definition for player sprites (HUD weapon) actions
+#[derive(Debug, Clone, Copy)]
 pub struct WeaponInfo {
     /// Amto type required
     pub ammo: AmmoType,
@@ -138,6 +163,9 @@ pub struct WeaponInfo {
     pub atkstate: StateNum,
     /// Muzzle flashes
     pub flashstate: StateNum,
+    /// Ammo used by one shot
+    pub ammo_per_shot: u32,
+    pub flags: WeaponFlag,
 }
 
 pub const WEAPON_INFO: [WeaponInfo; 9] = [
@@ -149,6 +177,12 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::PUNCH,
         atkstate: StateNum::PUNCH1,
         flashstate: StateNum::None,
+        ammo_per_shot: 0,
+        flags: WeaponFlag::from_bits_truncate(
+            WeaponFlag::Fleemelee.bits()
+                | WeaponFlag::Autoswitchfrom.bits()
+                | WeaponFlag::Noautoswitchto.bits(),
+        ),
     },
     // pistol
     WeaponInfo {
@@ -158,6 +192,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::PISTOL,
         atkstate: StateNum::PISTOL1,
         flashstate: StateNum::PISTOLFLASH,
+        ammo_per_shot: 1,
+        flags: WeaponFlag::Autoswitchfrom,
     },
     // shotgun
     WeaponInfo {
@@ -167,6 +203,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::SGUN,
         atkstate: StateNum::SGUN1,
         flashstate: StateNum::SGUNFLASH1,
+        ammo_per_shot: 1,
+        flags: WeaponFlag::empty(),
     },
     // chaingun
     WeaponInfo {
@@ -176,6 +214,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::CHAIN,
         atkstate: StateNum::CHAIN1,
         flashstate: StateNum::CHAINFLASH1,
+        ammo_per_shot: 1,
+        flags: WeaponFlag::empty(),
     },
     // missile
     WeaponInfo {
@@ -185,6 +225,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::MISSILE,
         atkstate: StateNum::MISSILE1,
         flashstate: StateNum::MISSILEFLASH1,
+        ammo_per_shot: 1,
+        flags: WeaponFlag::Noautofire,
     },
     // plasma
     WeaponInfo {
@@ -194,6 +236,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::PLASMA,
         atkstate: StateNum::PLASMA1,
         flashstate: StateNum::PLASMAFLASH1,
+        ammo_per_shot: 1,
+        flags: WeaponFlag::empty(),
     },
     // Big Fucking Gun
     WeaponInfo {
@@ -203,6 +247,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::BFG,
         atkstate: StateNum::BFG1,
         flashstate: StateNum::BFGFLASH1,
+        ammo_per_shot: BFGCELLS,
+        flags: WeaponFlag::Noautofire,
     },
     // chainsaw
     WeaponInfo {
@@ -212,6 +258,12 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::SAW,
         atkstate: StateNum::SAW1,
         flashstate: StateNum::None,
+        ammo_per_shot: 0,
+        flags: WeaponFlag::from_bits_truncate(
+            WeaponFlag::Nothrust.bits()
+                | WeaponFlag::Fleemelee.bits()
+                | WeaponFlag::Noautoswitchto.bits(),
+        ),
     },
     // shotgun
     WeaponInfo {
@@ -221,6 +273,8 @@ pub const WEAPON_INFO: [WeaponInfo; 9] = [
         readystate: StateNum::DSGUN,
         atkstate: StateNum::DSGUN1,
         flashstate: StateNum::DSGUNFLASH1,
+        ammo_per_shot: 2,
+        flags: WeaponFlag::empty(),
     },
 ];
 
@@ -283,6 +337,24 @@ impl ActionId {
             Self::ASpawnsound => Some(a_spawnsound),
             Self::ASpawnfly => Some(a_spawnfly),
             Self::ABrainexplode => Some(a_brainexplode),
+            Self::ASpawnobject => Some(a_spawnobject),
+            Self::AMonsterprojectile => Some(a_monsterprojectile),
+            Self::AMonsterbulletattack => Some(a_monsterbulletattack),
+            Self::AMonstermeleeattack => Some(a_monstermeleeattack),
+            Self::ARadiusdamage => Some(a_radiusdamage),
+            Self::ANoisealert => Some(a_noisealert),
+            Self::AHealchase => Some(a_healchase),
+            Self::ASeektracer => Some(a_seektracer),
+            Self::AFindtracer => Some(a_findtracer),
+            Self::ACleartracer => Some(a_cleartracer),
+            Self::AJumpifhealthbelow => Some(a_jumpifhealthbelow),
+            Self::AJumpiftargetinsight => Some(a_jumpiftargetinsight),
+            Self::AJumpiftargetcloser => Some(a_jumpiftargetcloser),
+            Self::AJumpiftracerinsight => Some(a_jumpiftracerinsight),
+            Self::AJumpiftracercloser => Some(a_jumpiftracercloser),
+            Self::AJumpifflagsset => Some(a_jumpifflagsset),
+            Self::AAddflags => Some(a_addflags),
+            Self::ARemoveflags => Some(a_removeflags),
             _ => None,
         }
     }
@@ -313,6 +385,16 @@ impl ActionId {
             Self::PFireplasma => Some(a_fireplasma),
             Self::PBfgsound => Some(a_bfgsound),
             Self::PFirebfg => Some(a_firebfg),
+            Self::PWeaponprojectile => Some(a_weaponprojectile),
+            Self::PWeaponbulletattack => Some(a_weaponbulletattack),
+            Self::PWeaponmeleeattack => Some(a_weaponmeleeattack),
+            Self::PWeaponsound => Some(a_weaponsound),
+            Self::PWeaponalert => Some(a_weaponalert),
+            Self::PWeaponjump => Some(a_weaponjump),
+            Self::PConsumeammo => Some(a_consumeammo),
+            Self::PCheckammo => Some(a_checkammo),
+            Self::PRefireto => Some(a_refireto),
+            Self::PGunflashto => Some(a_gunflashto),
             _ => None,
         }
     }
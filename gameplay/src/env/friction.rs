@@ -0,0 +1,170 @@
+//! BOOM variable friction: icy and muddy floors.
+//!
+//! Linedef 223 sets the friction of every sector sharing its tag, scaled by the
+//! length of the line. The effect only applies while the sector's friction bit
+//! (bit 8 of the special) is set, so a later sector-type change can switch it
+//! off.
+//!
+//! Doom source name `p_spec` (BOOM additions)
+
+use std::ptr::{self, null_mut};
+
+use crate::SectorExt as _;
+use crate::level::LevelState;
+use crate::thing::{FRICTION, MapObjFlag, MapObject};
+use crate::thinker::{Think, Thinker, ThinkerData};
+use level::MapPtr;
+use level::map_defs::Sector;
+use log::debug;
+use math::{FixedT, p_aprox_distance};
+
+/// BOOM generalized sector bit enabling friction
+pub const FRICTION_MASK: u32 = 0x100;
+/// Linedef special that transfers friction to tagged sectors
+const FRICTION_LINE: u32 = 223;
+
+pub struct Friction {
+    pub thinker: *mut Thinker,
+    pub sector: MapPtr<Sector>,
+    /// Momentum multiplier per tic. Higher is slipperier.
+    pub friction: FixedT,
+    /// Player thrust multiplier. Scaled up with momentum on mud.
+    pub movefactor: i32,
+}
+
+impl Friction {
+    /// Friction and movefactor for a 223 line of `length` map units. Lines
+    /// up to 100 units give mud, longer give ice. The movefactor is kept to
+    /// at least 32 as in MBF.
+    pub fn values_for_length(length: i32) -> (FixedT, i32) {
+        let friction = ((0x1EB8 * length) / 0x80 + 0xD000).clamp(0, 0x10000);
+        let movefactor = if friction > FRICTION {
+            ((0x10092 - friction) * 0x70) / 0x158
+        } else {
+            ((friction - 0xDB34) * 0xA) / 0x80
+        };
+        // Short lines would reverse the thrust
+        let movefactor = movefactor.max(32);
+        (FixedT::from_fixed(friction), movefactor)
+    }
+
+    /// Doom function name `Add_Friction`
+    pub fn spawn(sector: &mut Sector, friction: FixedT, movefactor: i32, level: &mut LevelState) {
+        let friction = Self {
+            thinker: null_mut(),
+            sector: MapPtr::new(sector),
+            friction,
+            movefactor,
+        };
+
+        let thinker = MapObject::create_thinker(ThinkerData::Friction(friction), Self::think);
+
+        if let Some(ptr) = level.thinkers.push::<Self>(thinker) {
+            ptr.set_obj_thinker_ptr();
+        }
+    }
+}
+
+impl Think for Friction {
+    fn think(object: &mut Thinker, _level: &mut LevelState) -> bool {
+        let friction = object.friction_mut();
+        #[cfg(feature = "null_check")]
+        assert!(!friction.thinker.is_null(), "friction thinker was null");
+
+        // The sector type may have been changed under us
+        if friction.sector.special & FRICTION_MASK == 0 {
+            return false;
+        }
+
+        let value = friction.friction;
+        let movefactor = friction.movefactor;
+        let orig = FixedT::from_fixed(FRICTION);
+        friction.sector.run_mut_func_on_thinglist(|thing| {
+            if thing.player.is_some()
+                && !thing
+                    .flags
+                    .intersects(MapObjFlag::Nogravity | MapObjFlag::Noclip)
+                && thing.z <= thing.subsector.sector.floor_z(thing.x, thing.y)
+                // Straddling two frictions: mud wins over ice
+                && (thing.friction == orig || value < thing.friction)
+            {
+                thing.friction = value;
+                thing.movefactor = movefactor;
+            }
+            true
+        });
+
+        false
+    }
+
+    fn set_thinker_ptr(&mut self, ptr: *mut Thinker) {
+        self.thinker = ptr;
+    }
+
+    fn thinker_mut(&mut self) -> &mut Thinker {
+        #[cfg(feature = "null_check")]
+        assert!(!self.thinker.is_null(), "friction thinker was null");
+        unsafe { Thinker::from_erased(self.thinker) }
+    }
+}
+
+/// Doom function name `P_SpawnFriction`
+pub fn spawn_friction(level: &mut LevelState) {
+    let level_iter = unsafe { &mut *ptr::from_mut(level) };
+    for line in level_iter
+        .level_data
+        .linedefs
+        .iter()
+        .filter(|l| l.special == FRICTION_LINE)
+    {
+        let length = p_aprox_distance(
+            FixedT::from_fixed(line.delta_fp[0]),
+            FixedT::from_fixed(line.delta_fp[1]),
+        )
+        .to_fixed_raw()
+            >> 16;
+        let (friction, movefactor) = Friction::values_for_length(length);
+        debug!("line-special #223: friction {friction:?}, movefactor {movefactor}");
+
+        for sector in level_iter
+            .level_data
+            .sectors
+            .iter_mut()
+            .filter(|s| s.tag == line.tag)
+        {
+            Friction::spawn(sector, friction, movefactor, level);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Friction;
+    use crate::thing::FRICTION;
+
+    #[test]
+    fn line_length_sets_ice_and_mud() {
+        // 100 units is just under vanilla friction, so counts as mud
+        let (friction, movefactor) = Friction::values_for_length(100);
+        assert_eq!(friction.to_fixed_raw(), 0xE7FF);
+        assert_eq!(movefactor, 255);
+
+        let (ice, ice_factor) = Friction::values_for_length(200);
+        assert_eq!(ice.to_fixed_raw(), 0xFFFF);
+        assert_eq!(ice_factor, 47);
+
+        let (mud, mud_factor) = Friction::values_for_length(50);
+        assert!(mud.to_fixed_raw() < FRICTION);
+        assert_eq!(mud_factor, 32);
+
+        let (stuck, stuck_factor) = Friction::values_for_length(0);
+        assert_eq!(stuck.to_fixed_raw(), 0xD000);
+        assert_eq!(stuck_factor, 32);
+    }
+
+    #[test]
+    fn friction_is_clamped() {
+        let (friction, _) = Friction::values_for_length(i16::MAX as i32);
+        assert_eq!(friction.to_fixed_raw(), 0x10000);
+    }
+}
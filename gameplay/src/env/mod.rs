@@ -4,9 +4,12 @@
 pub(crate) mod ceiling;
 pub(crate) mod doors;
 pub(crate) mod floor;
+pub(crate) mod friction;
 pub(crate) mod generalized;
 pub(crate) mod lights;
 pub(crate) mod platforms;
+pub(crate) mod pusher;
+pub(crate) mod scroller;
 pub(crate) mod specials;
 pub(crate) mod switch;
 pub(crate) mod teleport;
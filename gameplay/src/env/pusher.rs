@@ -0,0 +1,339 @@
+//! BOOM push/pull effects: wind, current and point sources.
+//!
+//! Linedefs 224 (wind) and 225 (current) push players standing in the tagged
+//! sectors in the direction and strength of the line. Linedef 226 turns an
+//! `MT_PUSH` or `MT_PULL` thing inside each tagged sector into a point source
+//! whose force falls off with distance. All of them only act while the
+//! sector's push bit (bit 9 of the special) is set. In deep water sectors
+//! (linedef 242) wind only blows above the surface and current only flows
+//! below it.
+//!
+//! Doom source name `p_spec` (BOOM additions)
+
+use std::ptr::{self, null_mut};
+
+use crate::SectorExt as _;
+use crate::info::MapObjKind;
+use crate::level::LevelState;
+use crate::thing::{MapObjFlag, MapObject};
+use crate::thinker::{Think, Thinker, ThinkerData};
+use level::MapPtr;
+use level::map_defs::Sector;
+use log::debug;
+use math::{ANG180, FixedT, p_aprox_distance, r_point_to_angle};
+
+/// BOOM generalized sector bit enabling pushers
+pub const PUSH_MASK: u32 = 0x200;
+/// Scales the line length down to a force
+const PUSH_FACTOR: i32 = 7;
+/// OG Doom MAPBLOCKSHIFT = FRACBITS + 7 = 23
+const MAPBLOCKSHIFT: i32 = 23;
+/// OG Doom MAXRADIUS in 16.16 fixed-point = 32 << 16
+const MAXRADIUS_FIXED: i32 = 32 << 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushKind {
+    /// Point source, `MT_PUSH` or `MT_PULL`
+    Point,
+    /// Full force in the air, half on the ground
+    Wind,
+    /// Full force on the ground only
+    Current,
+}
+
+impl TryFrom<u8> for PushKind {
+    type Error = u8;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(Self::Point),
+            1 => Ok(Self::Wind),
+            2 => Ok(Self::Current),
+            _ => Err(v),
+        }
+    }
+}
+
+pub struct Pusher {
+    pub thinker: *mut Thinker,
+    pub sector: MapPtr<Sector>,
+    pub kind: PushKind,
+    /// The `MT_PUSH`/`MT_PULL` thing for point sources
+    pub source: Option<*mut Thinker>,
+    /// Force components in map units
+    pub x_mag: i32,
+    pub y_mag: i32,
+    pub magnitude: i32,
+    /// Raw 16.16 distance at which a point source has no effect
+    pub radius: i32,
+    /// Raw 16.16 position of a point source
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pusher {
+    /// Doom function name `Add_Pusher`
+    pub fn spawn(
+        sector: &mut Sector,
+        kind: PushKind,
+        dx: i32,
+        dy: i32,
+        source: Option<*mut Thinker>,
+        level: &mut LevelState,
+    ) {
+        let pusher = Self::new(MapPtr::new(sector), kind, dx >> 16, dy >> 16, source);
+        let thinker = MapObject::create_thinker(ThinkerData::Pusher(pusher), Self::think);
+
+        if let Some(ptr) = level.thinkers.push::<Self>(thinker) {
+            ptr.set_obj_thinker_ptr();
+        }
+    }
+
+    /// Build a pusher from its magnitudes in map units, deriving the falloff
+    /// radius and origin from `source` if it is a point source.
+    pub(crate) fn new(
+        sector: MapPtr<Sector>,
+        kind: PushKind,
+        x_mag: i32,
+        y_mag: i32,
+        source: Option<*mut Thinker>,
+    ) -> Self {
+        let magnitude =
+            p_aprox_distance(FixedT::from_fixed(x_mag), FixedT::from_fixed(y_mag)).to_fixed_raw();
+        let (radius, x, y) = if let Some(source) = source {
+            let source = unsafe { (*source).mobj() };
+            (
+                magnitude << 17,
+                source.x.to_fixed_raw(),
+                source.y.to_fixed_raw(),
+            )
+        } else {
+            (0, 0, 0)
+        };
+        Self {
+            thinker: null_mut(),
+            sector,
+            kind,
+            source,
+            x_mag,
+            y_mag,
+            magnitude,
+            radius,
+            x,
+            y,
+        }
+    }
+
+    /// Doom function name `PIT_PushThing`
+    fn push_thing(&self, thing: &mut MapObject) {
+        if thing.player.is_none()
+            || thing
+                .flags
+                .intersects(MapObjFlag::Nogravity | MapObjFlag::Noclip)
+        {
+            return;
+        }
+        let Some(source) = self.source else {
+            return;
+        };
+        let source = unsafe { (*source).mobj() };
+
+        let dist = p_aprox_distance(
+            thing.x - FixedT::from_fixed(self.x),
+            thing.y - FixedT::from_fixed(self.y),
+        )
+        .to_fixed_raw();
+        let speed = (self.magnitude - ((dist >> 16) >> 1)) << (16 - PUSH_FACTOR - 1);
+
+        // Outside the effective radius, or no line of sight to the source
+        if speed <= 0 || !thing.check_sight_target(source) {
+            return;
+        }
+
+        let mut angle = r_point_to_angle(
+            FixedT::from_fixed(self.x) - thing.x,
+            FixedT::from_fixed(self.y) - thing.y,
+        );
+        if source.kind == MapObjKind::MT_PUSH {
+            angle = angle.wrapping_add(ANG180);
+        }
+        let speed = FixedT::from_fixed(speed);
+        thing.momx += speed.fixed_mul(FixedT::cos_bam(angle));
+        thing.momy += speed.fixed_mul(FixedT::sin_bam(angle));
+    }
+}
+
+impl Think for Pusher {
+    fn think(object: &mut Thinker, level: &mut LevelState) -> bool {
+        let pusher = object.pusher_mut();
+        #[cfg(feature = "null_check")]
+        assert!(!pusher.thinker.is_null(), "pusher thinker was null");
+
+        // The sector type may have been changed under us
+        if pusher.sector.special & PUSH_MASK == 0 {
+            return false;
+        }
+
+        if pusher.kind == PushKind::Point {
+            // Point sources cross sectors, so search the blockmap
+            let bm = level.level_data.blockmap();
+            let orgx = bm.x_origin;
+            let orgy = bm.y_origin;
+            let bmw = bm.columns;
+            let bmh = bm.rows;
+
+            let xl = (pusher.x - pusher.radius - orgx - MAXRADIUS_FIXED) >> MAPBLOCKSHIFT;
+            let xh = (pusher.x + pusher.radius - orgx + MAXRADIUS_FIXED) >> MAPBLOCKSHIFT;
+            let yl = (pusher.y - pusher.radius - orgy - MAXRADIUS_FIXED) >> MAPBLOCKSHIFT;
+            let yh = (pusher.y + pusher.radius - orgy + MAXRADIUS_FIXED) >> MAPBLOCKSHIFT;
+
+            for bx in xl..=xh {
+                for by in yl..=yh {
+                    if bx < 0 || by < 0 || bx >= bmw || by >= bmh {
+                        continue;
+                    }
+                    let idx = (by * bmw + bx) as usize;
+                    let mut mobj_ptr = level.blocklinks[idx];
+                    while let Some(ptr) = mobj_ptr {
+                        let thing = unsafe { &mut *ptr };
+                        mobj_ptr = thing.b_next;
+                        pusher.push_thing(thing);
+                    }
+                }
+            }
+            return false;
+        }
+
+        let kind = pusher.kind;
+        let (x_mag, y_mag) = (pusher.x_mag, pusher.y_mag);
+        // In deep water (linedef 242) the water surface stands in for the
+        // ground
+        let water = pusher
+            .sector
+            .heightsec
+            .map(|c| level.level_data.sectors[c].floorheight);
+        pusher.sector.run_mut_func_on_thinglist(|thing| {
+            if thing.player.is_none()
+                || thing
+                    .flags
+                    .intersects(MapObjFlag::Nogravity | MapObjFlag::Noclip)
+            {
+                return true;
+            }
+
+            let (xspeed, yspeed) = match kind {
+                PushKind::Wind if thing.z > water.unwrap_or(thing.floorz) => (x_mag, y_mag),
+                // Fully underwater
+                PushKind::Wind
+                    if water.is_some_and(|ht| thing.player().is_some_and(|p| p.viewz < ht)) =>
+                {
+                    (0, 0)
+                }
+                // On the ground, or wading
+                PushKind::Wind => (x_mag >> 1, y_mag >> 1),
+                PushKind::Current
+                    if thing.z
+                        > water.unwrap_or_else(|| {
+                            thing.subsector.sector.floor_z(thing.x, thing.y)
+                        }) =>
+                {
+                    (0, 0)
+                }
+                PushKind::Current => (x_mag, y_mag),
+                PushKind::Point => unreachable!(),
+            };
+            thing.momx += FixedT::from_fixed(xspeed << (16 - PUSH_FACTOR));
+            thing.momy += FixedT::from_fixed(yspeed << (16 - PUSH_FACTOR));
+            true
+        });
+
+        false
+    }
+
+    fn set_thinker_ptr(&mut self, ptr: *mut Thinker) {
+        self.thinker = ptr;
+    }
+
+    fn thinker_mut(&mut self) -> &mut Thinker {
+        #[cfg(feature = "null_check")]
+        assert!(!self.thinker.is_null(), "pusher thinker was null");
+        unsafe { Thinker::from_erased(self.thinker) }
+    }
+}
+
+/// Find the first `MT_PUSH` or `MT_PULL` thing in `sector`.
+///
+/// Doom function name `P_GetPushThing`
+pub(crate) fn get_push_thing(sector: &Sector) -> Option<*mut Thinker> {
+    let mut found = None;
+    sector.run_func_on_thinglist(|thing| {
+        if matches!(thing.kind, MapObjKind::MT_PUSH | MapObjKind::MT_PULL) {
+            found = Some(thing.thinker);
+            return false;
+        }
+        true
+    });
+    found
+}
+
+/// Doom function name `P_SpawnPushers`
+pub fn spawn_pushers(level: &mut LevelState) {
+    let level_iter = unsafe { &mut *ptr::from_mut(level) };
+    for line in level_iter.level_data.linedefs.iter() {
+        let kind = match line.special {
+            224 => PushKind::Wind,
+            225 => PushKind::Current,
+            226 => PushKind::Point,
+            _ => continue,
+        };
+        debug!("line-special #{}: {kind:?} pusher", line.special);
+
+        for sector in level_iter
+            .level_data
+            .sectors
+            .iter_mut()
+            .filter(|s| s.tag == line.tag)
+        {
+            let source = if kind == PushKind::Point {
+                // No MT_PUSH/MT_PULL in the sector means no effect
+                let Some(source) = get_push_thing(sector) else {
+                    continue;
+                };
+                Some(source)
+            } else {
+                None
+            };
+            Pusher::spawn(
+                sector,
+                kind,
+                line.delta_fp[0],
+                line.delta_fp[1],
+                source,
+                level,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PushKind, Pusher};
+    use level::MapPtr;
+    use level::map_defs::Sector;
+
+    #[test]
+    fn push_kind_round_trip() {
+        for kind in [PushKind::Point, PushKind::Wind, PushKind::Current] {
+            assert_eq!(PushKind::try_from(kind as u8), Ok(kind));
+        }
+        assert_eq!(PushKind::try_from(3), Err(3));
+    }
+
+    #[test]
+    fn wind_magnitude_from_line() {
+        let mut sector = Sector::default();
+        let pusher = Pusher::new(MapPtr::new(&mut sector), PushKind::Wind, 64, -32, None);
+        assert_eq!(pusher.magnitude, 64 + 32 - 16);
+        assert_eq!(pusher.radius, 0);
+    }
+}
@@ -0,0 +1,343 @@
+//! BOOM scrollers: walls, floors, ceilings and floor conveyors.
+//!
+//! Linedefs 250-253 scroll the flats of the tagged sectors in the direction
+//! and speed of the line (253 also carries things standing on the floor, 252
+//! only carries). 254 scrolls the walls of the tagged lines the same way and
+//! 255 scrolls its own first side by that side's offsets. 245-249 are the same
+//! as 250-254 but only move when the heights of the line's front sector
+//! change, and 214-218 accelerate with those changes instead.
+//!
+//! Vanilla special 48 (and BOOM's 85) are plain wall scrollers.
+//!
+//! Doom source name `p_spec` (BOOM additions)
+
+use std::ptr::null_mut;
+
+use crate::SectorExt as _;
+use crate::level::LevelState;
+use crate::thing::{MapObjFlag, MapObject};
+use crate::thinker::{Think, Thinker, ThinkerData};
+use level::MovementType;
+use log::debug;
+use math::{FixedT, r_point_to_dist};
+
+/// Line delta to scroll speed: 32 units of line is one unit per tic
+const SCROLL_SHIFT: i32 = 5;
+/// Scales a floor scroll speed down to the speed things are carried at
+const CARRY_FACTOR: FixedT = FixedT::from_fixed(0x1800);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollKind {
+    /// Scrolls the first sidedef of a linedef
+    Side,
+    Floor,
+    Ceiling,
+    /// Moves things standing on the floor
+    Carry,
+}
+
+impl TryFrom<u8> for ScrollKind {
+    type Error = u8;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(Self::Side),
+            1 => Ok(Self::Floor),
+            2 => Ok(Self::Ceiling),
+            3 => Ok(Self::Carry),
+            _ => Err(v),
+        }
+    }
+}
+
+pub struct Scroller {
+    pub thinker: *mut Thinker,
+    pub kind: ScrollKind,
+    /// Scroll per tic, or per unit of control sector movement
+    pub dx: FixedT,
+    pub dy: FixedT,
+    /// Linedef index for `Side`, otherwise the sector index
+    pub affectee: usize,
+    /// Sector whose height changes drive displacement and accelerative
+    /// scrollers
+    pub control: Option<usize>,
+    /// Control sector floor + ceiling height as of the last tic
+    pub last_height: FixedT,
+    pub accel: bool,
+    /// Accumulated velocity of an accelerative scroller
+    pub vdx: FixedT,
+    pub vdy: FixedT,
+}
+
+impl Scroller {
+    /// Doom function name `Add_Scroller`
+    pub fn spawn(
+        kind: ScrollKind,
+        dx: FixedT,
+        dy: FixedT,
+        control: Option<usize>,
+        affectee: usize,
+        accel: bool,
+        level: &mut LevelState,
+    ) {
+        let last_height = control.map_or(FixedT::ZERO, |c| {
+            let sector = &level.level_data.sectors[c];
+            sector.floorheight + sector.ceilingheight
+        });
+        let scroller = Self {
+            thinker: null_mut(),
+            kind,
+            dx,
+            dy,
+            affectee,
+            control,
+            last_height,
+            accel,
+            vdx: FixedT::ZERO,
+            vdy: FixedT::ZERO,
+        };
+        let thinker = MapObject::create_thinker(ThinkerData::Scroller(scroller), Self::think);
+
+        if let Some(ptr) = level.thinkers.push::<Self>(thinker) {
+            ptr.set_obj_thinker_ptr();
+        }
+    }
+
+    /// Scroll of a wall along its own direction, matching the flats scrolled
+    /// by a line of the same delta.
+    ///
+    /// Doom function name `Add_WallScroller`
+    fn wall_scroll(dx: FixedT, dy: FixedT, delta_fp: [i32; 2]) -> (FixedT, FixedT) {
+        let ldx = FixedT::from_fixed(delta_fp[0]);
+        let ldy = FixedT::from_fixed(delta_fp[1]);
+        let d = r_point_to_dist(ldx, ldy, FixedT::ZERO, FixedT::ZERO);
+        let x = -(dy.fixed_mul(ldy) + dx.fixed_mul(ldx)).fixed_div(d);
+        let y = -(dx.fixed_mul(ldy) - dy.fixed_mul(ldx)).fixed_div(d);
+        (x, y)
+    }
+}
+
+impl Think for Scroller {
+    fn think(object: &mut Thinker, level: &mut LevelState) -> bool {
+        let scroller = object.scroller_mut();
+        #[cfg(feature = "null_check")]
+        assert!(!scroller.thinker.is_null(), "scroller thinker was null");
+
+        let mut dx = scroller.dx;
+        let mut dy = scroller.dy;
+        if let Some(control) = scroller.control {
+            // Scroll by how far the control sector moved this tic
+            let sector = &level.level_data.sectors[control];
+            let height = sector.floorheight + sector.ceilingheight;
+            let delta = height - scroller.last_height;
+            scroller.last_height = height;
+            dx = dx.fixed_mul(delta);
+            dy = dy.fixed_mul(delta);
+        }
+        if scroller.accel {
+            dx += scroller.vdx;
+            dy += scroller.vdy;
+            scroller.vdx = dx;
+            scroller.vdy = dy;
+        }
+        if dx == FixedT::ZERO && dy == FixedT::ZERO {
+            return false;
+        }
+
+        let level_data = &mut level.level_data;
+        let affectee = scroller.affectee;
+        match scroller.kind {
+            ScrollKind::Side => {
+                let side = &mut level_data.linedefs[affectee].front_sidedef;
+                side.textureoffset += dx;
+                side.rowoffset += dy;
+                level_data.bsp_3d.update_wall_scroll(affectee);
+            }
+            ScrollKind::Floor => {
+                let sector = &mut level_data.sectors[affectee];
+                sector.floor_xoffs += dx;
+                sector.floor_yoffs += dy;
+                level_data.bsp_3d.update_flat_scroll(
+                    affectee,
+                    MovementType::Floor,
+                    sector.floor_xoffs.to_f32(),
+                    sector.floor_yoffs.to_f32(),
+                );
+            }
+            ScrollKind::Ceiling => {
+                let sector = &mut level_data.sectors[affectee];
+                sector.ceiling_xoffs += dx;
+                sector.ceiling_yoffs += dy;
+                level_data.bsp_3d.update_flat_scroll(
+                    affectee,
+                    MovementType::Ceiling,
+                    sector.ceiling_xoffs.to_f32(),
+                    sector.ceiling_yoffs.to_f32(),
+                );
+            }
+            ScrollKind::Carry => {
+                let height = level_data.sectors[affectee].floorheight;
+                // Things below the surface of deep water (linedef 242) are
+                // carried whether or not they stand on the floor
+                let waterheight = level_data.sectors[affectee]
+                    .heightsec
+                    .map(|c| level_data.sectors[c].floorheight)
+                    .filter(|&h| h > height);
+                let sector = &mut level_data.sectors[affectee];
+                sector.run_mut_func_on_thinglist(|thing| {
+                    if !thing.flags.contains(MapObjFlag::Noclip)
+                        && ((!thing.flags.contains(MapObjFlag::Nogravity) && thing.z <= height)
+                            || waterheight.is_some_and(|w| thing.z < w))
+                    {
+                        thing.momx += dx;
+                        thing.momy += dy;
+                    }
+                    true
+                });
+            }
+        }
+
+        false
+    }
+
+    fn set_thinker_ptr(&mut self, ptr: *mut Thinker) {
+        self.thinker = ptr;
+    }
+
+    fn thinker_mut(&mut self) -> &mut Thinker {
+        #[cfg(feature = "null_check")]
+        assert!(!self.thinker.is_null(), "scroller thinker was null");
+        unsafe { Thinker::from_erased(self.thinker) }
+    }
+}
+
+/// Doom function name `P_SpawnScrollers`
+pub fn spawn_scrollers(level: &mut LevelState) {
+    for i in 0..level.level_data.linedefs.len() {
+        let line = &level.level_data.linedefs[i];
+        let mut dx = FixedT::from_fixed(line.delta_fp[0] >> SCROLL_SHIFT);
+        let mut dy = FixedT::from_fixed(line.delta_fp[1] >> SCROLL_SHIFT);
+        let tag = line.tag;
+        let mut special = line.special;
+        let mut control = None;
+        let mut accel = false;
+
+        // Displacement (245-249) and accelerative (214-218) scrollers are
+        // 250-254 driven by the front sector's height changes
+        if (245..=249).contains(&special) {
+            special += 250 - 245;
+            control = Some(line.front_sidedef.sector.num as usize);
+        } else if (214..=218).contains(&special) {
+            special += 250 - 214;
+            accel = true;
+            control = Some(line.front_sidedef.sector.num as usize);
+        }
+        if !matches!(special, 48 | 85 | 250..=255) {
+            continue;
+        }
+        debug!("line-special #{}: scroller", line.special);
+
+        let tagged_sectors: Vec<usize> = level
+            .level_data
+            .sectors
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.tag == tag)
+            .map(|(s, _)| s)
+            .collect();
+
+        match special {
+            250 => {
+                for &s in &tagged_sectors {
+                    Scroller::spawn(ScrollKind::Ceiling, -dx, dy, control, s, accel, level);
+                }
+            }
+            251..=253 => {
+                if special != 252 {
+                    for &s in &tagged_sectors {
+                        Scroller::spawn(ScrollKind::Floor, -dx, dy, control, s, accel, level);
+                    }
+                }
+                if special != 251 {
+                    dx = dx.fixed_mul(CARRY_FACTOR);
+                    dy = dy.fixed_mul(CARRY_FACTOR);
+                    for &s in &tagged_sectors {
+                        Scroller::spawn(ScrollKind::Carry, dx, dy, control, s, accel, level);
+                    }
+                }
+            }
+            254 => {
+                for l in 0..level.level_data.linedefs.len() {
+                    let target = &level.level_data.linedefs[l];
+                    if l == i || target.tag != tag {
+                        continue;
+                    }
+                    let (x, y) = Scroller::wall_scroll(dx, dy, target.delta_fp);
+                    Scroller::spawn(ScrollKind::Side, x, y, control, l, accel, level);
+                }
+            }
+            255 => {
+                let side = &line.front_sidedef;
+                let (x, y) = (-side.textureoffset, side.rowoffset);
+                Scroller::spawn(ScrollKind::Side, x, y, None, i, false, level);
+            }
+            48 => {
+                Scroller::spawn(
+                    ScrollKind::Side,
+                    FixedT::ONE,
+                    FixedT::ZERO,
+                    None,
+                    i,
+                    false,
+                    level,
+                );
+            }
+            85 => {
+                Scroller::spawn(
+                    ScrollKind::Side,
+                    -FixedT::ONE,
+                    FixedT::ZERO,
+                    None,
+                    i,
+                    false,
+                    level,
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScrollKind, Scroller};
+    use math::FixedT;
+
+    #[test]
+    fn scroll_kind_round_trip() {
+        for kind in [
+            ScrollKind::Side,
+            ScrollKind::Floor,
+            ScrollKind::Ceiling,
+            ScrollKind::Carry,
+        ] {
+            assert_eq!(ScrollKind::try_from(kind as u8), Ok(kind));
+        }
+        assert_eq!(ScrollKind::try_from(4), Err(4));
+    }
+
+    #[test]
+    fn wall_scroll_follows_line_direction() {
+        // A flat scroller line pointing +x scrolls a wall along +x backwards
+        // in texture space, and one along the wall's normal moves it up. The
+        // line length goes through the fine tables, so allow a unit of slop.
+        let wall = [128 << 16, 0];
+        let (x, y) = Scroller::wall_scroll(FixedT::ONE, FixedT::ZERO, wall);
+        assert!((x.to_fixed_raw() + 0x10000).abs() <= 1);
+        assert_eq!(y, FixedT::ZERO);
+
+        let (x, y) = Scroller::wall_scroll(FixedT::ZERO, FixedT::ONE, wall);
+        assert_eq!(x, FixedT::ZERO);
+        assert!((y.to_fixed_raw() - 0x10000).abs() <= 1);
+    }
+}
@@ -10,17 +10,21 @@ use crate::doom_def::{ONCEILINGZ, ONFLOORZ};
 use crate::env::ceiling::{CeilKind, ev_do_ceiling};
 use crate::env::doors::{DoorKind, ev_do_door};
 use crate::env::floor::{FloorKind, StairKind, ev_build_stairs, ev_do_floor};
+use crate::env::friction::spawn_friction;
 use crate::env::generalized;
 use crate::env::lights::{
     FASTDARK, FireFlicker, Glow, LightFlash, SLOWDARK, StrobeFlash, ev_start_light_strobing,
     ev_turn_light_on, ev_turn_tag_lights_off,
 };
 use crate::env::platforms::{PlatKind, ev_do_platform, ev_stop_platform};
+use crate::env::pusher::spawn_pushers;
+use crate::env::scroller::spawn_scrollers;
 use crate::env::switch::{change_switch_texture, start_sector_sound};
 use crate::env::teleport::teleport;
-use crate::info::{MOBJINFO, MapObjKind};
+use crate::info::MapObjKind;
 use crate::level::LevelState;
 use crate::pic::ButtonWhere;
+use crate::tables::mobj_info;
 use crate::thing::MapObject;
 use crate::{MapObjFlag, TICRATE};
 use level::map_defs::{LineDef, Sector, SectorHeight};
@@ -796,12 +800,12 @@ pub fn spawn_specials(level: &mut LevelState) {
         }
     }
 
-    for line in level_iter.level_data.linedefs.iter_mut() {
-        // Scrolling wall
-        if line.special == 48 {
-            level.line_special_list.push(MapPtr::new(line));
-        }
-    }
+    // Scrolling walls (48) and the BOOM scroller family
+    spawn_scrollers(level);
+
+    // BOOM: ice/mud sectors and wind/current/point pushers
+    spawn_friction(level);
+    spawn_pushers(level);
 }
 
 /// Doom function name `P_UpdateSpecials`
@@ -845,24 +849,12 @@ pub fn update_specials(level: &mut LevelState, pic_data: &mut PicData) {
             }
         }
     }
-    for i in 0..level.line_special_list.len() {
-        let line = &mut level.line_special_list[i];
-        line.front_sidedef.textureoffset += 1;
-        if line.front_sidedef.textureoffset == FixedT::MAX {
-            line.front_sidedef.textureoffset = FixedT::ZERO;
-        }
-        // Special-48 scrollers start at offset 0 (the build-baked UV), so the
-        // live offset is the scroll delta (matches software25d).
-        let num = line.num;
-        let scroll = f32::from(line.front_sidedef.textureoffset);
-        level.level_data.bsp_3d.set_wall_scroll(num, scroll);
-    }
 }
 
 /// P_RespawnSpecials
 pub fn respawn_specials(level: &mut LevelState) {
-    // only respawn items in deathmatch
-    if level.options.deathmatch == 0 && !level.options.respawn_monsters {
+    // only respawn items in altdeath
+    if level.options.deathmatch != 2 {
         return;
     }
 
@@ -896,7 +888,7 @@ pub fn respawn_specials(level: &mut LevelState) {
 
         let mut i = 0;
         for n in 0..MapObjKind::Count as u16 {
-            if mthing.1.kind == MOBJINFO[n as usize].doomednum as i16 {
+            if mthing.1.kind == mobj_info()[n as usize].doomednum as i16 {
                 i = n;
                 break;
             }
@@ -911,7 +903,7 @@ pub fn respawn_specials(level: &mut LevelState) {
 
         let kind = MapObjKind::from(i);
 
-        let z = if MOBJINFO[i as usize]
+        let z = if mobj_info()[i as usize]
             .flags
             .contains(MapObjFlag::Spawnceiling)
         {
@@ -932,7 +924,7 @@ pub fn respawn_specials(level: &mut LevelState) {
         };
         // OG: mobj->angle = ANG45 * (mthing->angle/45)
         thing.angle = Angle::from_bam(math::ANG45.wrapping_mul((mthing.1.angle as u32) / 45));
-        thing.spawnpoint = mthing.1;
+        thing.set_spawnpoint(mthing.1);
     }
 }
 
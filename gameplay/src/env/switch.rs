@@ -3,6 +3,7 @@
 use std::ptr;
 
 use log::{debug, warn};
+use math::m_random;
 use sound_common::{SfxName, SndServerTx};
 
 use crate::env::generalized;
@@ -68,6 +69,7 @@ pub(crate) fn start_sector_sound(line: &LineDef, sfx: SfxName, snd: &SndServerTx
         sfx,
         x: sfx_origin.x,
         y: sfx_origin.y,
+        pitch: sfx.random_pitch(m_random),
     }) {
         log::warn!("Could not send sector sfx, sound thread gone: {e}");
     }
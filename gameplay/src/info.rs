@@ -22,7 +22,7 @@ pub const SPRNAMES: [&str; NUM_SPRNAMES] = [
 "CAND", "CBRA", "COL6", "TRE1", "TRE2", "ELEC", "CEYE", "FSKU", 
 "COL5", "TBLU", "TGRN", "TRED", "SMBT", "SMGT", "SMRT", "HDB1", 
 "HDB2", "HDB3", "HDB4", "HDB5", "HDB6", "POB1", "POB2", "BRS1", 
-"TLMP", "TLP2", 
+"TLMP", "TLP2", "TNT1", 
 
 ];
 
@@ -47,7 +47,7 @@ GOR3, GOR4, GOR5, SMIT, COL1, COL2, COL3, COL4,
 CAND, CBRA, COL6, TRE1, TRE2, ELEC, CEYE, FSKU, 
 COL5, TBLU, TGRN, TRED, SMBT, SMGT, SMRT, HDB1, 
 HDB2, HDB3, HDB4, HDB5, HDB6, POB1, POB2, BRS1, 
-TLMP, TLP2, 
+TLMP, TLP2, TNT1, 
 Count,
 }
 impl Default for SpriteNum {
@@ -193,7 +193,7 @@ BLUETORCH3, BLUETORCH4, GREENTORCH, GREENTORCH2, GREENTORCH3, GREENTORCH4, REDTO
 REDTORCH3, REDTORCH4, BTORCHSHRT, BTORCHSHRT2, BTORCHSHRT3, BTORCHSHRT4, GTORCHSHRT, GTORCHSHRT2, 
 GTORCHSHRT3, GTORCHSHRT4, RTORCHSHRT, RTORCHSHRT2, RTORCHSHRT3, RTORCHSHRT4, HANGNOGUTS, HANGBNOBRAIN, 
 HANGTLOOKDN, HANGTSKULL, HANGTLOOKUP, HANGTNOBRAIN, COLONGIBS, SMALLPOOL, BRAINSTEM, TECHLAMP, 
-TECHLAMP2, TECHLAMP3, TECHLAMP4, TECH2LAMP, TECH2LAMP2, TECH2LAMP3, TECH2LAMP4, 
+TECHLAMP2, TECHLAMP3, TECHLAMP4, TECH2LAMP, TECH2LAMP2, TECH2LAMP3, TECH2LAMP4, TNT1, 
 Count,
 }
 impl From<u16> for StateNum {
@@ -223,7 +223,7 @@ MT_MISC54, MT_MISC55, MT_MISC56, MT_MISC57, MT_MISC58, MT_MISC59, MT_MISC60, MT_
 MT_MISC62, MT_MISC63, MT_MISC64, MT_MISC65, MT_MISC66, MT_MISC67, MT_MISC68, MT_MISC69, 
 MT_MISC70, MT_MISC71, MT_MISC72, MT_MISC73, MT_MISC74, MT_MISC75, MT_MISC76, MT_MISC77, 
 MT_MISC78, MT_MISC79, MT_MISC80, MT_MISC81, MT_MISC82, MT_MISC83, MT_MISC84, MT_MISC85, 
-MT_MISC86, 
+MT_MISC86, MT_PUSH, MT_PULL, 
 Count,
 }
 impl MapObjKind {
@@ -328,6 +328,24 @@ pub enum ActionId {
     ASpawnsound,
     ASpawnfly,
     ABrainexplode,
+    ASpawnobject,
+    AMonsterprojectile,
+    AMonsterbulletattack,
+    AMonstermeleeattack,
+    ARadiusdamage,
+    ANoisealert,
+    AHealchase,
+    ASeektracer,
+    AFindtracer,
+    ACleartracer,
+    AJumpifhealthbelow,
+    AJumpiftargetinsight,
+    AJumpiftargetcloser,
+    AJumpiftracerinsight,
+    AJumpiftracercloser,
+    AJumpifflagsset,
+    AAddflags,
+    ARemoveflags,
     // Player actions
     PLight0,
     PWeaponready,
@@ -351,10 +369,20 @@ pub enum ActionId {
     PFireplasma,
     PBfgsound,
     PFirebfg,
+    PWeaponprojectile,
+    PWeaponbulletattack,
+    PWeaponmeleeattack,
+    PWeaponsound,
+    PWeaponalert,
+    PWeaponjump,
+    PConsumeammo,
+    PCheckammo,
+    PRefireto,
+    PGunflashto,
 }
 
 /// State data without function pointers — safe for static arrays.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StateData {
     /// Sprite to use
     pub sprite: SpriteNum,
@@ -368,11 +396,13 @@ pub struct StateData {
     pub next_state: StateNum,
     pub misc1: i32,
     pub misc2: i32,
+    /// MBF21 codepointer arguments
+    pub args: [i32; 8],
 }
 
 const NUM_STATES: usize = StateNum::Count as usize;
 pub static STATES: [StateData; NUM_STATES] = [
-    // StateData { sprite, frame, tics, action, next_state, misc1, misc2 }
+    // StateData { sprite, frame, tics, action, next_state, misc1, misc2, args }
     StateData { // S_NULL
         sprite: SpriteNum::TROO,
         frame: 0,
@@ -381,6 +411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_LIGHTDONE
         sprite: SpriteNum::SHTG,
@@ -390,6 +421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH
         sprite: SpriteNum::PUNG,
@@ -399,6 +431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCHDOWN
         sprite: SpriteNum::PUNG,
@@ -408,6 +441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCHDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCHUP
         sprite: SpriteNum::PUNG,
@@ -417,6 +451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCHUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH1
         sprite: SpriteNum::PUNG,
@@ -426,6 +461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH2
         sprite: SpriteNum::PUNG,
@@ -435,6 +471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH3
         sprite: SpriteNum::PUNG,
@@ -444,6 +481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH4
         sprite: SpriteNum::PUNG,
@@ -453,6 +491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUNCH5
         sprite: SpriteNum::PUNG,
@@ -462,6 +501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUNCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOL
         sprite: SpriteNum::PISG,
@@ -471,6 +511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOLDOWN
         sprite: SpriteNum::PISG,
@@ -480,6 +521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOLDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOLUP
         sprite: SpriteNum::PISG,
@@ -489,6 +531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOLUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOL1
         sprite: SpriteNum::PISG,
@@ -498,6 +541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOL2
         sprite: SpriteNum::PISG,
@@ -507,6 +551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOL3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOL3
         sprite: SpriteNum::PISG,
@@ -516,6 +561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOL4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOL4
         sprite: SpriteNum::PISG,
@@ -525,6 +571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PISTOL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PISTOLFLASH
         sprite: SpriteNum::PISF,
@@ -534,6 +581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN
         sprite: SpriteNum::SHTG,
@@ -543,6 +591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUNDOWN
         sprite: SpriteNum::SHTG,
@@ -552,6 +601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUNDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUNUP
         sprite: SpriteNum::SHTG,
@@ -561,6 +611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUNUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN1
         sprite: SpriteNum::SHTG,
@@ -570,6 +621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN2
         sprite: SpriteNum::SHTG,
@@ -579,6 +631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN3
         sprite: SpriteNum::SHTG,
@@ -588,6 +641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN4
         sprite: SpriteNum::SHTG,
@@ -597,6 +651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN5
         sprite: SpriteNum::SHTG,
@@ -606,6 +661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN6
         sprite: SpriteNum::SHTG,
@@ -615,6 +671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN7
         sprite: SpriteNum::SHTG,
@@ -624,6 +681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN8
         sprite: SpriteNum::SHTG,
@@ -633,6 +691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUN9
         sprite: SpriteNum::SHTG,
@@ -642,6 +701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUNFLASH1
         sprite: SpriteNum::SHTF,
@@ -651,6 +711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SGUNFLASH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SGUNFLASH2
         sprite: SpriteNum::SHTF,
@@ -660,6 +721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN
         sprite: SpriteNum::SHT2,
@@ -669,6 +731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUNDOWN
         sprite: SpriteNum::SHT2,
@@ -678,6 +741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUNDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUNUP
         sprite: SpriteNum::SHT2,
@@ -687,6 +751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUNUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN1
         sprite: SpriteNum::SHT2,
@@ -696,6 +761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN2
         sprite: SpriteNum::SHT2,
@@ -705,6 +771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN3
         sprite: SpriteNum::SHT2,
@@ -714,6 +781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN4
         sprite: SpriteNum::SHT2,
@@ -723,6 +791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN5
         sprite: SpriteNum::SHT2,
@@ -732,6 +801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN6
         sprite: SpriteNum::SHT2,
@@ -741,6 +811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN7
         sprite: SpriteNum::SHT2,
@@ -750,6 +821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN8
         sprite: SpriteNum::SHT2,
@@ -759,6 +831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN9
         sprite: SpriteNum::SHT2,
@@ -768,6 +841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUN10
         sprite: SpriteNum::SHT2,
@@ -777,6 +851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSNR1
         sprite: SpriteNum::SHT2,
@@ -786,6 +861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSNR2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSNR2
         sprite: SpriteNum::SHT2,
@@ -795,6 +871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUNDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUNFLASH1
         sprite: SpriteNum::SHT2,
@@ -804,6 +881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::DSGUNFLASH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DSGUNFLASH2
         sprite: SpriteNum::SHT2,
@@ -813,6 +891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAIN
         sprite: SpriteNum::CHGG,
@@ -822,6 +901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAIN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAINDOWN
         sprite: SpriteNum::CHGG,
@@ -831,6 +911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAINDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAINUP
         sprite: SpriteNum::CHGG,
@@ -840,6 +921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAINUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAIN1
         sprite: SpriteNum::CHGG,
@@ -849,6 +931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAIN2
         sprite: SpriteNum::CHGG,
@@ -858,6 +941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAIN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAIN3
         sprite: SpriteNum::CHGG,
@@ -867,6 +951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CHAIN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAINFLASH1
         sprite: SpriteNum::CHGF,
@@ -876,6 +961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CHAINFLASH2
         sprite: SpriteNum::CHGF,
@@ -885,6 +971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILE
         sprite: SpriteNum::MISG,
@@ -894,6 +981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEDOWN
         sprite: SpriteNum::MISG,
@@ -903,6 +991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILEDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEUP
         sprite: SpriteNum::MISG,
@@ -912,6 +1001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILEUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILE1
         sprite: SpriteNum::MISG,
@@ -921,6 +1011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILE2
         sprite: SpriteNum::MISG,
@@ -930,6 +1021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILE3
         sprite: SpriteNum::MISG,
@@ -939,6 +1031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEFLASH1
         sprite: SpriteNum::MISF,
@@ -948,6 +1041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILEFLASH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEFLASH2
         sprite: SpriteNum::MISF,
@@ -957,6 +1051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILEFLASH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEFLASH3
         sprite: SpriteNum::MISF,
@@ -966,6 +1061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MISSILEFLASH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MISSILEFLASH4
         sprite: SpriteNum::MISF,
@@ -975,6 +1071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAW
         sprite: SpriteNum::SAWG,
@@ -984,6 +1081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAWB,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAWB
         sprite: SpriteNum::SAWG,
@@ -993,6 +1091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAW,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAWDOWN
         sprite: SpriteNum::SAWG,
@@ -1002,6 +1101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAWDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAWUP
         sprite: SpriteNum::SAWG,
@@ -1011,6 +1111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAWUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAW1
         sprite: SpriteNum::SAWG,
@@ -1020,6 +1121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAW2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAW2
         sprite: SpriteNum::SAWG,
@@ -1029,6 +1131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAW3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SAW3
         sprite: SpriteNum::SAWG,
@@ -1038,6 +1141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SAW,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMA
         sprite: SpriteNum::PLSG,
@@ -1047,6 +1151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASMA,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMADOWN
         sprite: SpriteNum::PLSG,
@@ -1056,6 +1161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASMADOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMAUP
         sprite: SpriteNum::PLSG,
@@ -1065,6 +1171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASMAUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMA1
         sprite: SpriteNum::PLSG,
@@ -1074,6 +1181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASMA2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMA2
         sprite: SpriteNum::PLSG,
@@ -1083,6 +1191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASMA,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMAFLASH1
         sprite: SpriteNum::PLSF,
@@ -1092,6 +1201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASMAFLASH2
         sprite: SpriteNum::PLSF,
@@ -1101,6 +1211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFG
         sprite: SpriteNum::BFGG,
@@ -1110,6 +1221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFG,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGDOWN
         sprite: SpriteNum::BFGG,
@@ -1119,6 +1231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGDOWN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGUP
         sprite: SpriteNum::BFGG,
@@ -1128,6 +1241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGUP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFG1
         sprite: SpriteNum::BFGG,
@@ -1137,6 +1251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFG2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFG2
         sprite: SpriteNum::BFGG,
@@ -1146,6 +1261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFG3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFG3
         sprite: SpriteNum::BFGG,
@@ -1155,6 +1271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFG4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFG4
         sprite: SpriteNum::BFGG,
@@ -1164,6 +1281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFG,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGFLASH1
         sprite: SpriteNum::BFGF,
@@ -1173,6 +1291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGFLASH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGFLASH2
         sprite: SpriteNum::BFGF,
@@ -1182,6 +1301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIGHTDONE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOOD1
         sprite: SpriteNum::BLUD,
@@ -1191,6 +1311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOOD2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOOD2
         sprite: SpriteNum::BLUD,
@@ -1200,6 +1321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOOD3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOOD3
         sprite: SpriteNum::BLUD,
@@ -1209,6 +1331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUFF1
         sprite: SpriteNum::PUFF,
@@ -1218,6 +1341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUFF2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUFF2
         sprite: SpriteNum::PUFF,
@@ -1227,6 +1351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUFF3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUFF3
         sprite: SpriteNum::PUFF,
@@ -1236,6 +1361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PUFF4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PUFF4
         sprite: SpriteNum::PUFF,
@@ -1245,6 +1371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TBALL1
         sprite: SpriteNum::BAL1,
@@ -1254,6 +1381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TBALL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TBALL2
         sprite: SpriteNum::BAL1,
@@ -1263,6 +1391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TBALL1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TBALLX1
         sprite: SpriteNum::BAL1,
@@ -1272,6 +1401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TBALLX2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TBALLX2
         sprite: SpriteNum::BAL1,
@@ -1281,6 +1411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TBALLX3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TBALLX3
         sprite: SpriteNum::BAL1,
@@ -1290,6 +1421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RBALL1
         sprite: SpriteNum::BAL2,
@@ -1299,6 +1431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RBALL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RBALL2
         sprite: SpriteNum::BAL2,
@@ -1308,6 +1441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RBALL1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RBALLX1
         sprite: SpriteNum::BAL2,
@@ -1317,6 +1451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RBALLX2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RBALLX2
         sprite: SpriteNum::BAL2,
@@ -1326,6 +1461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RBALLX3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RBALLX3
         sprite: SpriteNum::BAL2,
@@ -1335,6 +1471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASBALL
         sprite: SpriteNum::PLSS,
@@ -1344,6 +1481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASBALL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASBALL2
         sprite: SpriteNum::PLSS,
@@ -1353,6 +1491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASBALL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASEXP
         sprite: SpriteNum::PLSE,
@@ -1362,6 +1501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASEXP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASEXP2
         sprite: SpriteNum::PLSE,
@@ -1371,6 +1511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASEXP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASEXP3
         sprite: SpriteNum::PLSE,
@@ -1380,6 +1521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASEXP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASEXP4
         sprite: SpriteNum::PLSE,
@@ -1389,6 +1531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLASEXP5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLASEXP5
         sprite: SpriteNum::PLSE,
@@ -1398,6 +1541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ROCKET
         sprite: SpriteNum::MISL,
@@ -1407,6 +1551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ROCKET,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGSHOT
         sprite: SpriteNum::BFS1,
@@ -1416,6 +1561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGSHOT2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGSHOT2
         sprite: SpriteNum::BFS1,
@@ -1425,6 +1571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGSHOT,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND
         sprite: SpriteNum::BFE1,
@@ -1434,6 +1581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGLAND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND2
         sprite: SpriteNum::BFE1,
@@ -1443,6 +1591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGLAND3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND3
         sprite: SpriteNum::BFE1,
@@ -1452,6 +1601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGLAND4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND4
         sprite: SpriteNum::BFE1,
@@ -1461,6 +1611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGLAND5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND5
         sprite: SpriteNum::BFE1,
@@ -1470,6 +1621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGLAND6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGLAND6
         sprite: SpriteNum::BFE1,
@@ -1479,6 +1631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGEXP
         sprite: SpriteNum::BFE2,
@@ -1488,6 +1641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGEXP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGEXP2
         sprite: SpriteNum::BFE2,
@@ -1497,6 +1651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGEXP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGEXP3
         sprite: SpriteNum::BFE2,
@@ -1506,6 +1661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BFGEXP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFGEXP4
         sprite: SpriteNum::BFE2,
@@ -1515,6 +1671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EXPLODE1
         sprite: SpriteNum::MISL,
@@ -1524,6 +1681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EXPLODE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EXPLODE2
         sprite: SpriteNum::MISL,
@@ -1533,6 +1691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EXPLODE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EXPLODE3
         sprite: SpriteNum::MISL,
@@ -1542,6 +1701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG
         sprite: SpriteNum::TFOG,
@@ -1551,6 +1711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG01,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG01
         sprite: SpriteNum::TFOG,
@@ -1560,6 +1721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG02,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG02
         sprite: SpriteNum::TFOG,
@@ -1569,6 +1731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG2
         sprite: SpriteNum::TFOG,
@@ -1578,6 +1741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG3
         sprite: SpriteNum::TFOG,
@@ -1587,6 +1751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG4
         sprite: SpriteNum::TFOG,
@@ -1596,6 +1761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG5
         sprite: SpriteNum::TFOG,
@@ -1605,6 +1771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG6
         sprite: SpriteNum::TFOG,
@@ -1614,6 +1781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG7
         sprite: SpriteNum::TFOG,
@@ -1623,6 +1791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG8
         sprite: SpriteNum::TFOG,
@@ -1632,6 +1801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG9
         sprite: SpriteNum::TFOG,
@@ -1641,6 +1811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TFOG10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TFOG10
         sprite: SpriteNum::TFOG,
@@ -1650,6 +1821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG
         sprite: SpriteNum::IFOG,
@@ -1659,6 +1831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG01,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG01
         sprite: SpriteNum::IFOG,
@@ -1668,6 +1841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG02,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG02
         sprite: SpriteNum::IFOG,
@@ -1677,6 +1851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG2
         sprite: SpriteNum::IFOG,
@@ -1686,6 +1861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG3
         sprite: SpriteNum::IFOG,
@@ -1695,6 +1871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG4
         sprite: SpriteNum::IFOG,
@@ -1704,6 +1881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::IFOG5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_IFOG5
         sprite: SpriteNum::IFOG,
@@ -1713,6 +1891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY
         sprite: SpriteNum::PLAY,
@@ -1722,6 +1901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_RUN1
         sprite: SpriteNum::PLAY,
@@ -1731,6 +1911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_RUN2
         sprite: SpriteNum::PLAY,
@@ -1740,6 +1921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_RUN3
         sprite: SpriteNum::PLAY,
@@ -1749,6 +1931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_RUN4
         sprite: SpriteNum::PLAY,
@@ -1758,6 +1941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_ATK1
         sprite: SpriteNum::PLAY,
@@ -1767,6 +1951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_ATK2
         sprite: SpriteNum::PLAY,
@@ -1776,6 +1961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_ATK1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_PAIN
         sprite: SpriteNum::PLAY,
@@ -1785,6 +1971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_PAIN2
         sprite: SpriteNum::PLAY,
@@ -1794,6 +1981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE1
         sprite: SpriteNum::PLAY,
@@ -1803,6 +1991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE2
         sprite: SpriteNum::PLAY,
@@ -1812,6 +2001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE3
         sprite: SpriteNum::PLAY,
@@ -1821,6 +2011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE4
         sprite: SpriteNum::PLAY,
@@ -1830,6 +2021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE5
         sprite: SpriteNum::PLAY,
@@ -1839,6 +2031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE6
         sprite: SpriteNum::PLAY,
@@ -1848,6 +2041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_DIE7
         sprite: SpriteNum::PLAY,
@@ -1857,6 +2051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE1
         sprite: SpriteNum::PLAY,
@@ -1866,6 +2061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE2
         sprite: SpriteNum::PLAY,
@@ -1875,6 +2071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE3
         sprite: SpriteNum::PLAY,
@@ -1884,6 +2081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE4
         sprite: SpriteNum::PLAY,
@@ -1893,6 +2091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE5
         sprite: SpriteNum::PLAY,
@@ -1902,6 +2101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE6
         sprite: SpriteNum::PLAY,
@@ -1911,6 +2111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE7
         sprite: SpriteNum::PLAY,
@@ -1920,6 +2121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE8
         sprite: SpriteNum::PLAY,
@@ -1929,6 +2131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PLAY_XDIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAY_XDIE9
         sprite: SpriteNum::PLAY,
@@ -1938,6 +2141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_STND
         sprite: SpriteNum::POSS,
@@ -1947,6 +2151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_STND2
         sprite: SpriteNum::POSS,
@@ -1956,6 +2161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN1
         sprite: SpriteNum::POSS,
@@ -1965,6 +2171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN2
         sprite: SpriteNum::POSS,
@@ -1974,6 +2181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN3
         sprite: SpriteNum::POSS,
@@ -1983,6 +2191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN4
         sprite: SpriteNum::POSS,
@@ -1992,6 +2201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN5
         sprite: SpriteNum::POSS,
@@ -2001,6 +2211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN6
         sprite: SpriteNum::POSS,
@@ -2010,6 +2221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN7
         sprite: SpriteNum::POSS,
@@ -2019,6 +2231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RUN8
         sprite: SpriteNum::POSS,
@@ -2028,6 +2241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_ATK1
         sprite: SpriteNum::POSS,
@@ -2037,6 +2251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_ATK2
         sprite: SpriteNum::POSS,
@@ -2046,6 +2261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_ATK3
         sprite: SpriteNum::POSS,
@@ -2055,6 +2271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_PAIN
         sprite: SpriteNum::POSS,
@@ -2064,6 +2281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_PAIN2
         sprite: SpriteNum::POSS,
@@ -2073,6 +2291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_DIE1
         sprite: SpriteNum::POSS,
@@ -2082,6 +2301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_DIE2
         sprite: SpriteNum::POSS,
@@ -2091,6 +2311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_DIE3
         sprite: SpriteNum::POSS,
@@ -2100,6 +2321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_DIE4
         sprite: SpriteNum::POSS,
@@ -2109,6 +2331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_DIE5
         sprite: SpriteNum::POSS,
@@ -2118,6 +2341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE1
         sprite: SpriteNum::POSS,
@@ -2127,6 +2351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE2
         sprite: SpriteNum::POSS,
@@ -2136,6 +2361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE3
         sprite: SpriteNum::POSS,
@@ -2145,6 +2371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE4
         sprite: SpriteNum::POSS,
@@ -2154,6 +2381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE5
         sprite: SpriteNum::POSS,
@@ -2163,6 +2391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE6
         sprite: SpriteNum::POSS,
@@ -2172,6 +2401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE7
         sprite: SpriteNum::POSS,
@@ -2181,6 +2411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE8
         sprite: SpriteNum::POSS,
@@ -2190,6 +2421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_XDIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_XDIE9
         sprite: SpriteNum::POSS,
@@ -2199,6 +2431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RAISE1
         sprite: SpriteNum::POSS,
@@ -2208,6 +2441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RAISE2
         sprite: SpriteNum::POSS,
@@ -2217,6 +2451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RAISE3
         sprite: SpriteNum::POSS,
@@ -2226,6 +2461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_POSS_RAISE4
         sprite: SpriteNum::POSS,
@@ -2235,6 +2471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::POSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_STND
         sprite: SpriteNum::SPOS,
@@ -2244,6 +2481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_STND2
         sprite: SpriteNum::SPOS,
@@ -2253,6 +2491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN1
         sprite: SpriteNum::SPOS,
@@ -2262,6 +2501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN2
         sprite: SpriteNum::SPOS,
@@ -2271,6 +2511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN3
         sprite: SpriteNum::SPOS,
@@ -2280,6 +2521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN4
         sprite: SpriteNum::SPOS,
@@ -2289,6 +2531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN5
         sprite: SpriteNum::SPOS,
@@ -2298,6 +2541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN6
         sprite: SpriteNum::SPOS,
@@ -2307,6 +2551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN7
         sprite: SpriteNum::SPOS,
@@ -2316,6 +2561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RUN8
         sprite: SpriteNum::SPOS,
@@ -2325,6 +2571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_ATK1
         sprite: SpriteNum::SPOS,
@@ -2334,6 +2581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_ATK2
         sprite: SpriteNum::SPOS,
@@ -2343,6 +2591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_ATK3
         sprite: SpriteNum::SPOS,
@@ -2352,6 +2601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_PAIN
         sprite: SpriteNum::SPOS,
@@ -2361,6 +2611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_PAIN2
         sprite: SpriteNum::SPOS,
@@ -2370,6 +2621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_DIE1
         sprite: SpriteNum::SPOS,
@@ -2379,6 +2631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_DIE2
         sprite: SpriteNum::SPOS,
@@ -2388,6 +2641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_DIE3
         sprite: SpriteNum::SPOS,
@@ -2397,6 +2651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_DIE4
         sprite: SpriteNum::SPOS,
@@ -2406,6 +2661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_DIE5
         sprite: SpriteNum::SPOS,
@@ -2415,6 +2671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE1
         sprite: SpriteNum::SPOS,
@@ -2424,6 +2681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE2
         sprite: SpriteNum::SPOS,
@@ -2433,6 +2691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE3
         sprite: SpriteNum::SPOS,
@@ -2442,6 +2701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE4
         sprite: SpriteNum::SPOS,
@@ -2451,6 +2711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE5
         sprite: SpriteNum::SPOS,
@@ -2460,6 +2721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE6
         sprite: SpriteNum::SPOS,
@@ -2469,6 +2731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE7
         sprite: SpriteNum::SPOS,
@@ -2478,6 +2741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE8
         sprite: SpriteNum::SPOS,
@@ -2487,6 +2751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_XDIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_XDIE9
         sprite: SpriteNum::SPOS,
@@ -2496,6 +2761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RAISE1
         sprite: SpriteNum::SPOS,
@@ -2505,6 +2771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RAISE2
         sprite: SpriteNum::SPOS,
@@ -2514,6 +2781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RAISE3
         sprite: SpriteNum::SPOS,
@@ -2523,6 +2791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RAISE4
         sprite: SpriteNum::SPOS,
@@ -2532,6 +2801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPOS_RAISE5
         sprite: SpriteNum::SPOS,
@@ -2541,6 +2811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_STND
         sprite: SpriteNum::VILE,
@@ -2550,6 +2821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_STND2
         sprite: SpriteNum::VILE,
@@ -2559,6 +2831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN1
         sprite: SpriteNum::VILE,
@@ -2568,6 +2841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN2
         sprite: SpriteNum::VILE,
@@ -2577,6 +2851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN3
         sprite: SpriteNum::VILE,
@@ -2586,6 +2861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN4
         sprite: SpriteNum::VILE,
@@ -2595,6 +2871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN5
         sprite: SpriteNum::VILE,
@@ -2604,6 +2881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN6
         sprite: SpriteNum::VILE,
@@ -2613,6 +2891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN7
         sprite: SpriteNum::VILE,
@@ -2622,6 +2901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN8
         sprite: SpriteNum::VILE,
@@ -2631,6 +2911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN9
         sprite: SpriteNum::VILE,
@@ -2640,6 +2921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN10
         sprite: SpriteNum::VILE,
@@ -2649,6 +2931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN11
         sprite: SpriteNum::VILE,
@@ -2658,6 +2941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_RUN12
         sprite: SpriteNum::VILE,
@@ -2667,6 +2951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK1
         sprite: SpriteNum::VILE,
@@ -2676,6 +2961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK2
         sprite: SpriteNum::VILE,
@@ -2685,6 +2971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK3
         sprite: SpriteNum::VILE,
@@ -2694,6 +2981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK4
         sprite: SpriteNum::VILE,
@@ -2703,6 +2991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK5
         sprite: SpriteNum::VILE,
@@ -2712,6 +3001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK6
         sprite: SpriteNum::VILE,
@@ -2721,6 +3011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK7
         sprite: SpriteNum::VILE,
@@ -2730,6 +3021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK8
         sprite: SpriteNum::VILE,
@@ -2739,6 +3031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK9
         sprite: SpriteNum::VILE,
@@ -2748,6 +3041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK10
         sprite: SpriteNum::VILE,
@@ -2757,6 +3051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_ATK11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_ATK11
         sprite: SpriteNum::VILE,
@@ -2766,6 +3061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_HEAL1
         sprite: SpriteNum::VILE,
@@ -2775,6 +3071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_HEAL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_HEAL2
         sprite: SpriteNum::VILE,
@@ -2784,6 +3081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_HEAL3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_HEAL3
         sprite: SpriteNum::VILE,
@@ -2793,6 +3091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_PAIN
         sprite: SpriteNum::VILE,
@@ -2802,6 +3101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_PAIN2
         sprite: SpriteNum::VILE,
@@ -2811,6 +3111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE1
         sprite: SpriteNum::VILE,
@@ -2820,6 +3121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE2
         sprite: SpriteNum::VILE,
@@ -2829,6 +3131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE3
         sprite: SpriteNum::VILE,
@@ -2838,6 +3141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE4
         sprite: SpriteNum::VILE,
@@ -2847,6 +3151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE5
         sprite: SpriteNum::VILE,
@@ -2856,6 +3161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE6
         sprite: SpriteNum::VILE,
@@ -2865,6 +3171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE7
         sprite: SpriteNum::VILE,
@@ -2874,6 +3181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE8
         sprite: SpriteNum::VILE,
@@ -2883,6 +3191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE9
         sprite: SpriteNum::VILE,
@@ -2892,6 +3201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::VILE_DIE10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_VILE_DIE10
         sprite: SpriteNum::VILE,
@@ -2901,6 +3211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE1
         sprite: SpriteNum::FIRE,
@@ -2910,6 +3221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE2
         sprite: SpriteNum::FIRE,
@@ -2919,6 +3231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE3
         sprite: SpriteNum::FIRE,
@@ -2928,6 +3241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE4
         sprite: SpriteNum::FIRE,
@@ -2937,6 +3251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE5
         sprite: SpriteNum::FIRE,
@@ -2946,6 +3261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE6
         sprite: SpriteNum::FIRE,
@@ -2955,6 +3271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE7
         sprite: SpriteNum::FIRE,
@@ -2964,6 +3281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE8
         sprite: SpriteNum::FIRE,
@@ -2973,6 +3291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE9
         sprite: SpriteNum::FIRE,
@@ -2982,6 +3301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE10
         sprite: SpriteNum::FIRE,
@@ -2991,6 +3311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE11
         sprite: SpriteNum::FIRE,
@@ -3000,6 +3321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE12
         sprite: SpriteNum::FIRE,
@@ -3009,6 +3331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE13,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE13
         sprite: SpriteNum::FIRE,
@@ -3018,6 +3341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE14,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE14
         sprite: SpriteNum::FIRE,
@@ -3027,6 +3351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE15,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE15
         sprite: SpriteNum::FIRE,
@@ -3036,6 +3361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE16,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE16
         sprite: SpriteNum::FIRE,
@@ -3045,6 +3371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE17,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE17
         sprite: SpriteNum::FIRE,
@@ -3054,6 +3381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE18,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE18
         sprite: SpriteNum::FIRE,
@@ -3063,6 +3391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE19,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE19
         sprite: SpriteNum::FIRE,
@@ -3072,6 +3401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE20,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE20
         sprite: SpriteNum::FIRE,
@@ -3081,6 +3411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE21,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE21
         sprite: SpriteNum::FIRE,
@@ -3090,6 +3421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE22,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE22
         sprite: SpriteNum::FIRE,
@@ -3099,6 +3431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE23,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE23
         sprite: SpriteNum::FIRE,
@@ -3108,6 +3441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE24,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE24
         sprite: SpriteNum::FIRE,
@@ -3117,6 +3451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE25,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE25
         sprite: SpriteNum::FIRE,
@@ -3126,6 +3461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE26,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE26
         sprite: SpriteNum::FIRE,
@@ -3135,6 +3471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE27,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE27
         sprite: SpriteNum::FIRE,
@@ -3144,6 +3481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE28,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE28
         sprite: SpriteNum::FIRE,
@@ -3153,6 +3491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE29,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE29
         sprite: SpriteNum::FIRE,
@@ -3162,6 +3501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FIRE30,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FIRE30
         sprite: SpriteNum::FIRE,
@@ -3171,6 +3511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMOKE1
         sprite: SpriteNum::PUFF,
@@ -3180,6 +3521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SMOKE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMOKE2
         sprite: SpriteNum::PUFF,
@@ -3189,6 +3531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SMOKE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMOKE3
         sprite: SpriteNum::PUFF,
@@ -3198,6 +3541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SMOKE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMOKE4
         sprite: SpriteNum::PUFF,
@@ -3207,6 +3551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SMOKE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMOKE5
         sprite: SpriteNum::PUFF,
@@ -3216,6 +3561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TRACER
         sprite: SpriteNum::FATB,
@@ -3225,6 +3571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TRACER2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TRACER2
         sprite: SpriteNum::FATB,
@@ -3234,6 +3581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TRACER,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TRACEEXP1
         sprite: SpriteNum::FBXP,
@@ -3243,6 +3591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TRACEEXP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TRACEEXP2
         sprite: SpriteNum::FBXP,
@@ -3252,6 +3601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TRACEEXP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TRACEEXP3
         sprite: SpriteNum::FBXP,
@@ -3261,6 +3611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_STND
         sprite: SpriteNum::SKEL,
@@ -3270,6 +3621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_STND2
         sprite: SpriteNum::SKEL,
@@ -3279,6 +3631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN1
         sprite: SpriteNum::SKEL,
@@ -3288,6 +3641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN2
         sprite: SpriteNum::SKEL,
@@ -3297,6 +3651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN3
         sprite: SpriteNum::SKEL,
@@ -3306,6 +3661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN4
         sprite: SpriteNum::SKEL,
@@ -3315,6 +3671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN5
         sprite: SpriteNum::SKEL,
@@ -3324,6 +3681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN6
         sprite: SpriteNum::SKEL,
@@ -3333,6 +3691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN7
         sprite: SpriteNum::SKEL,
@@ -3342,6 +3701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN8
         sprite: SpriteNum::SKEL,
@@ -3351,6 +3711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN9
         sprite: SpriteNum::SKEL,
@@ -3360,6 +3721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN10
         sprite: SpriteNum::SKEL,
@@ -3369,6 +3731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN11
         sprite: SpriteNum::SKEL,
@@ -3378,6 +3741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RUN12
         sprite: SpriteNum::SKEL,
@@ -3387,6 +3751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_FIST1
         sprite: SpriteNum::SKEL,
@@ -3396,6 +3761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_FIST2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_FIST2
         sprite: SpriteNum::SKEL,
@@ -3405,6 +3771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_FIST3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_FIST3
         sprite: SpriteNum::SKEL,
@@ -3414,6 +3781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_FIST4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_FIST4
         sprite: SpriteNum::SKEL,
@@ -3423,6 +3791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_MISS1
         sprite: SpriteNum::SKEL,
@@ -3432,6 +3801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_MISS2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_MISS2
         sprite: SpriteNum::SKEL,
@@ -3441,6 +3811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_MISS3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_MISS3
         sprite: SpriteNum::SKEL,
@@ -3450,6 +3821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_MISS4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_MISS4
         sprite: SpriteNum::SKEL,
@@ -3459,6 +3831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_PAIN
         sprite: SpriteNum::SKEL,
@@ -3468,6 +3841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_PAIN2
         sprite: SpriteNum::SKEL,
@@ -3477,6 +3851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE1
         sprite: SpriteNum::SKEL,
@@ -3486,6 +3861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE2
         sprite: SpriteNum::SKEL,
@@ -3495,6 +3871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE3
         sprite: SpriteNum::SKEL,
@@ -3504,6 +3881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE4
         sprite: SpriteNum::SKEL,
@@ -3513,6 +3891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE5
         sprite: SpriteNum::SKEL,
@@ -3522,6 +3901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_DIE6
         sprite: SpriteNum::SKEL,
@@ -3531,6 +3911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE1
         sprite: SpriteNum::SKEL,
@@ -3540,6 +3921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE2
         sprite: SpriteNum::SKEL,
@@ -3549,6 +3931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE3
         sprite: SpriteNum::SKEL,
@@ -3558,6 +3941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE4
         sprite: SpriteNum::SKEL,
@@ -3567,6 +3951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE5
         sprite: SpriteNum::SKEL,
@@ -3576,6 +3961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKEL_RAISE6
         sprite: SpriteNum::SKEL,
@@ -3585,6 +3971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKEL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATSHOT1
         sprite: SpriteNum::MANF,
@@ -3594,6 +3981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATSHOT2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATSHOT2
         sprite: SpriteNum::MANF,
@@ -3603,6 +3991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATSHOT1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATSHOTX1
         sprite: SpriteNum::MISL,
@@ -3612,6 +4001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATSHOTX2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATSHOTX2
         sprite: SpriteNum::MISL,
@@ -3621,6 +4011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATSHOTX3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATSHOTX3
         sprite: SpriteNum::MISL,
@@ -3630,6 +4021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_STND
         sprite: SpriteNum::FATT,
@@ -3639,6 +4031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_STND2
         sprite: SpriteNum::FATT,
@@ -3648,6 +4041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN1
         sprite: SpriteNum::FATT,
@@ -3657,6 +4051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN2
         sprite: SpriteNum::FATT,
@@ -3666,6 +4061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN3
         sprite: SpriteNum::FATT,
@@ -3675,6 +4071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN4
         sprite: SpriteNum::FATT,
@@ -3684,6 +4081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN5
         sprite: SpriteNum::FATT,
@@ -3693,6 +4091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN6
         sprite: SpriteNum::FATT,
@@ -3702,6 +4101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN7
         sprite: SpriteNum::FATT,
@@ -3711,6 +4111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN8
         sprite: SpriteNum::FATT,
@@ -3720,6 +4121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN9
         sprite: SpriteNum::FATT,
@@ -3729,6 +4131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN10
         sprite: SpriteNum::FATT,
@@ -3738,6 +4141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN11
         sprite: SpriteNum::FATT,
@@ -3747,6 +4151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RUN12
         sprite: SpriteNum::FATT,
@@ -3756,6 +4161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK1
         sprite: SpriteNum::FATT,
@@ -3765,6 +4171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK2
         sprite: SpriteNum::FATT,
@@ -3774,6 +4181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK3
         sprite: SpriteNum::FATT,
@@ -3783,6 +4191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK4
         sprite: SpriteNum::FATT,
@@ -3792,6 +4201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK5
         sprite: SpriteNum::FATT,
@@ -3801,6 +4211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK6
         sprite: SpriteNum::FATT,
@@ -3810,6 +4221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK7
         sprite: SpriteNum::FATT,
@@ -3819,6 +4231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK8
         sprite: SpriteNum::FATT,
@@ -3828,6 +4241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK9
         sprite: SpriteNum::FATT,
@@ -3837,6 +4251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_ATK10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_ATK10
         sprite: SpriteNum::FATT,
@@ -3846,6 +4261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_PAIN
         sprite: SpriteNum::FATT,
@@ -3855,6 +4271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_PAIN2
         sprite: SpriteNum::FATT,
@@ -3864,6 +4281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE1
         sprite: SpriteNum::FATT,
@@ -3873,6 +4291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE2
         sprite: SpriteNum::FATT,
@@ -3882,6 +4301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE3
         sprite: SpriteNum::FATT,
@@ -3891,6 +4311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE4
         sprite: SpriteNum::FATT,
@@ -3900,6 +4321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE5
         sprite: SpriteNum::FATT,
@@ -3909,6 +4331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE6
         sprite: SpriteNum::FATT,
@@ -3918,6 +4341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE7
         sprite: SpriteNum::FATT,
@@ -3927,6 +4351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE8
         sprite: SpriteNum::FATT,
@@ -3936,6 +4361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE9
         sprite: SpriteNum::FATT,
@@ -3945,6 +4371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_DIE10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_DIE10
         sprite: SpriteNum::FATT,
@@ -3954,6 +4381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE1
         sprite: SpriteNum::FATT,
@@ -3963,6 +4391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE2
         sprite: SpriteNum::FATT,
@@ -3972,6 +4401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE3
         sprite: SpriteNum::FATT,
@@ -3981,6 +4411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE4
         sprite: SpriteNum::FATT,
@@ -3990,6 +4421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE5
         sprite: SpriteNum::FATT,
@@ -3999,6 +4431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE6
         sprite: SpriteNum::FATT,
@@ -4008,6 +4441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE7
         sprite: SpriteNum::FATT,
@@ -4017,6 +4451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RAISE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FATT_RAISE8
         sprite: SpriteNum::FATT,
@@ -4026,6 +4461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FATT_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_STND
         sprite: SpriteNum::CPOS,
@@ -4035,6 +4471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_STND2
         sprite: SpriteNum::CPOS,
@@ -4044,6 +4481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN1
         sprite: SpriteNum::CPOS,
@@ -4053,6 +4491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN2
         sprite: SpriteNum::CPOS,
@@ -4062,6 +4501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN3
         sprite: SpriteNum::CPOS,
@@ -4071,6 +4511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN4
         sprite: SpriteNum::CPOS,
@@ -4080,6 +4521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN5
         sprite: SpriteNum::CPOS,
@@ -4089,6 +4531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN6
         sprite: SpriteNum::CPOS,
@@ -4098,6 +4541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN7
         sprite: SpriteNum::CPOS,
@@ -4107,6 +4551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RUN8
         sprite: SpriteNum::CPOS,
@@ -4116,6 +4561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_ATK1
         sprite: SpriteNum::CPOS,
@@ -4125,6 +4571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_ATK2
         sprite: SpriteNum::CPOS,
@@ -4134,6 +4581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_ATK3
         sprite: SpriteNum::CPOS,
@@ -4143,6 +4591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_ATK4
         sprite: SpriteNum::CPOS,
@@ -4152,6 +4601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_PAIN
         sprite: SpriteNum::CPOS,
@@ -4161,6 +4611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_PAIN2
         sprite: SpriteNum::CPOS,
@@ -4170,6 +4621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE1
         sprite: SpriteNum::CPOS,
@@ -4179,6 +4631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE2
         sprite: SpriteNum::CPOS,
@@ -4188,6 +4641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE3
         sprite: SpriteNum::CPOS,
@@ -4197,6 +4651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE4
         sprite: SpriteNum::CPOS,
@@ -4206,6 +4661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE5
         sprite: SpriteNum::CPOS,
@@ -4215,6 +4671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE6
         sprite: SpriteNum::CPOS,
@@ -4224,6 +4681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_DIE7
         sprite: SpriteNum::CPOS,
@@ -4233,6 +4691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE1
         sprite: SpriteNum::CPOS,
@@ -4242,6 +4701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE2
         sprite: SpriteNum::CPOS,
@@ -4251,6 +4711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE3
         sprite: SpriteNum::CPOS,
@@ -4260,6 +4721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE4
         sprite: SpriteNum::CPOS,
@@ -4269,6 +4731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE5
         sprite: SpriteNum::CPOS,
@@ -4278,6 +4741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_XDIE6
         sprite: SpriteNum::CPOS,
@@ -4287,6 +4751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE1
         sprite: SpriteNum::CPOS,
@@ -4296,6 +4761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE2
         sprite: SpriteNum::CPOS,
@@ -4305,6 +4771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE3
         sprite: SpriteNum::CPOS,
@@ -4314,6 +4781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE4
         sprite: SpriteNum::CPOS,
@@ -4323,6 +4791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE5
         sprite: SpriteNum::CPOS,
@@ -4332,6 +4801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE6
         sprite: SpriteNum::CPOS,
@@ -4341,6 +4811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RAISE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CPOS_RAISE7
         sprite: SpriteNum::CPOS,
@@ -4350,6 +4821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CPOS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_STND
         sprite: SpriteNum::TROO,
@@ -4359,6 +4831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_STND2
         sprite: SpriteNum::TROO,
@@ -4368,6 +4841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN1
         sprite: SpriteNum::TROO,
@@ -4377,6 +4851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN2
         sprite: SpriteNum::TROO,
@@ -4386,6 +4861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN3
         sprite: SpriteNum::TROO,
@@ -4395,6 +4871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN4
         sprite: SpriteNum::TROO,
@@ -4404,6 +4881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN5
         sprite: SpriteNum::TROO,
@@ -4413,6 +4891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN6
         sprite: SpriteNum::TROO,
@@ -4422,6 +4901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN7
         sprite: SpriteNum::TROO,
@@ -4431,6 +4911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RUN8
         sprite: SpriteNum::TROO,
@@ -4440,6 +4921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_ATK1
         sprite: SpriteNum::TROO,
@@ -4449,6 +4931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_ATK2
         sprite: SpriteNum::TROO,
@@ -4458,6 +4941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_ATK3
         sprite: SpriteNum::TROO,
@@ -4467,6 +4951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_PAIN
         sprite: SpriteNum::TROO,
@@ -4476,6 +4961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_PAIN2
         sprite: SpriteNum::TROO,
@@ -4485,6 +4971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_DIE1
         sprite: SpriteNum::TROO,
@@ -4494,6 +4981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_DIE2
         sprite: SpriteNum::TROO,
@@ -4503,6 +4991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_DIE3
         sprite: SpriteNum::TROO,
@@ -4512,6 +5001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_DIE4
         sprite: SpriteNum::TROO,
@@ -4521,6 +5011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_DIE5
         sprite: SpriteNum::TROO,
@@ -4530,6 +5021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE1
         sprite: SpriteNum::TROO,
@@ -4539,6 +5031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE2
         sprite: SpriteNum::TROO,
@@ -4548,6 +5041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE3
         sprite: SpriteNum::TROO,
@@ -4557,6 +5051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE4
         sprite: SpriteNum::TROO,
@@ -4566,6 +5061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE5
         sprite: SpriteNum::TROO,
@@ -4575,6 +5071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE6
         sprite: SpriteNum::TROO,
@@ -4584,6 +5081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE7
         sprite: SpriteNum::TROO,
@@ -4593,6 +5091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_XDIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_XDIE8
         sprite: SpriteNum::TROO,
@@ -4602,6 +5101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RAISE1
         sprite: SpriteNum::TROO,
@@ -4611,6 +5111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RAISE2
         sprite: SpriteNum::TROO,
@@ -4620,6 +5121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RAISE3
         sprite: SpriteNum::TROO,
@@ -4629,6 +5131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RAISE4
         sprite: SpriteNum::TROO,
@@ -4638,6 +5141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TROO_RAISE5
         sprite: SpriteNum::TROO,
@@ -4647,6 +5151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TROO_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_STND
         sprite: SpriteNum::SARG,
@@ -4656,6 +5161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_STND2
         sprite: SpriteNum::SARG,
@@ -4665,6 +5171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN1
         sprite: SpriteNum::SARG,
@@ -4674,6 +5181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN2
         sprite: SpriteNum::SARG,
@@ -4683,6 +5191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN3
         sprite: SpriteNum::SARG,
@@ -4692,6 +5201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN4
         sprite: SpriteNum::SARG,
@@ -4701,6 +5211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN5
         sprite: SpriteNum::SARG,
@@ -4710,6 +5221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN6
         sprite: SpriteNum::SARG,
@@ -4719,6 +5231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN7
         sprite: SpriteNum::SARG,
@@ -4728,6 +5241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RUN8
         sprite: SpriteNum::SARG,
@@ -4737,6 +5251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_ATK1
         sprite: SpriteNum::SARG,
@@ -4746,6 +5261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_ATK2
         sprite: SpriteNum::SARG,
@@ -4755,6 +5271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_ATK3
         sprite: SpriteNum::SARG,
@@ -4764,6 +5281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_PAIN
         sprite: SpriteNum::SARG,
@@ -4773,6 +5291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_PAIN2
         sprite: SpriteNum::SARG,
@@ -4782,6 +5301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE1
         sprite: SpriteNum::SARG,
@@ -4791,6 +5311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE2
         sprite: SpriteNum::SARG,
@@ -4800,6 +5321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE3
         sprite: SpriteNum::SARG,
@@ -4809,6 +5331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE4
         sprite: SpriteNum::SARG,
@@ -4818,6 +5341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE5
         sprite: SpriteNum::SARG,
@@ -4827,6 +5351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_DIE6
         sprite: SpriteNum::SARG,
@@ -4836,6 +5361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE1
         sprite: SpriteNum::SARG,
@@ -4845,6 +5371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE2
         sprite: SpriteNum::SARG,
@@ -4854,6 +5381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE3
         sprite: SpriteNum::SARG,
@@ -4863,6 +5391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE4
         sprite: SpriteNum::SARG,
@@ -4872,6 +5401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE5
         sprite: SpriteNum::SARG,
@@ -4881,6 +5411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SARG_RAISE6
         sprite: SpriteNum::SARG,
@@ -4890,6 +5421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SARG_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_STND
         sprite: SpriteNum::HEAD,
@@ -4899,6 +5431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RUN1
         sprite: SpriteNum::HEAD,
@@ -4908,6 +5441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_ATK1
         sprite: SpriteNum::HEAD,
@@ -4917,6 +5451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_ATK2
         sprite: SpriteNum::HEAD,
@@ -4926,6 +5461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_ATK3
         sprite: SpriteNum::HEAD,
@@ -4935,6 +5471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_PAIN
         sprite: SpriteNum::HEAD,
@@ -4944,6 +5481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_PAIN2
         sprite: SpriteNum::HEAD,
@@ -4953,6 +5491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_PAIN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_PAIN3
         sprite: SpriteNum::HEAD,
@@ -4962,6 +5501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE1
         sprite: SpriteNum::HEAD,
@@ -4971,6 +5511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE2
         sprite: SpriteNum::HEAD,
@@ -4980,6 +5521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE3
         sprite: SpriteNum::HEAD,
@@ -4989,6 +5531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE4
         sprite: SpriteNum::HEAD,
@@ -4998,6 +5541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE5
         sprite: SpriteNum::HEAD,
@@ -5007,6 +5551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_DIE6
         sprite: SpriteNum::HEAD,
@@ -5016,6 +5561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE1
         sprite: SpriteNum::HEAD,
@@ -5025,6 +5571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE2
         sprite: SpriteNum::HEAD,
@@ -5034,6 +5581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE3
         sprite: SpriteNum::HEAD,
@@ -5043,6 +5591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE4
         sprite: SpriteNum::HEAD,
@@ -5052,6 +5601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE5
         sprite: SpriteNum::HEAD,
@@ -5061,6 +5611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEAD_RAISE6
         sprite: SpriteNum::HEAD,
@@ -5070,6 +5621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEAD_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRBALL1
         sprite: SpriteNum::BAL7,
@@ -5079,6 +5631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRBALL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRBALL2
         sprite: SpriteNum::BAL7,
@@ -5088,6 +5641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRBALL1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRBALLX1
         sprite: SpriteNum::BAL7,
@@ -5097,6 +5651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRBALLX2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRBALLX2
         sprite: SpriteNum::BAL7,
@@ -5106,6 +5661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRBALLX3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRBALLX3
         sprite: SpriteNum::BAL7,
@@ -5115,6 +5671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_STND
         sprite: SpriteNum::BOSS,
@@ -5124,6 +5681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_STND2
         sprite: SpriteNum::BOSS,
@@ -5133,6 +5691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN1
         sprite: SpriteNum::BOSS,
@@ -5142,6 +5701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN2
         sprite: SpriteNum::BOSS,
@@ -5151,6 +5711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN3
         sprite: SpriteNum::BOSS,
@@ -5160,6 +5721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN4
         sprite: SpriteNum::BOSS,
@@ -5169,6 +5731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN5
         sprite: SpriteNum::BOSS,
@@ -5178,6 +5741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN6
         sprite: SpriteNum::BOSS,
@@ -5187,6 +5751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN7
         sprite: SpriteNum::BOSS,
@@ -5196,6 +5761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RUN8
         sprite: SpriteNum::BOSS,
@@ -5205,6 +5771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_ATK1
         sprite: SpriteNum::BOSS,
@@ -5214,6 +5781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_ATK2
         sprite: SpriteNum::BOSS,
@@ -5223,6 +5791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_ATK3
         sprite: SpriteNum::BOSS,
@@ -5232,6 +5801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_PAIN
         sprite: SpriteNum::BOSS,
@@ -5241,6 +5811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_PAIN2
         sprite: SpriteNum::BOSS,
@@ -5250,6 +5821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE1
         sprite: SpriteNum::BOSS,
@@ -5259,6 +5831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE2
         sprite: SpriteNum::BOSS,
@@ -5268,6 +5841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE3
         sprite: SpriteNum::BOSS,
@@ -5277,6 +5851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE4
         sprite: SpriteNum::BOSS,
@@ -5286,6 +5861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE5
         sprite: SpriteNum::BOSS,
@@ -5295,6 +5871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE6
         sprite: SpriteNum::BOSS,
@@ -5304,6 +5881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_DIE7
         sprite: SpriteNum::BOSS,
@@ -5313,6 +5891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE1
         sprite: SpriteNum::BOSS,
@@ -5322,6 +5901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE2
         sprite: SpriteNum::BOSS,
@@ -5331,6 +5911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE3
         sprite: SpriteNum::BOSS,
@@ -5340,6 +5921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE4
         sprite: SpriteNum::BOSS,
@@ -5349,6 +5931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE5
         sprite: SpriteNum::BOSS,
@@ -5358,6 +5941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE6
         sprite: SpriteNum::BOSS,
@@ -5367,6 +5951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RAISE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOSS_RAISE7
         sprite: SpriteNum::BOSS,
@@ -5376,6 +5961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOSS_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_STND
         sprite: SpriteNum::BOS2,
@@ -5385,6 +5971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_STND2
         sprite: SpriteNum::BOS2,
@@ -5394,6 +5981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN1
         sprite: SpriteNum::BOS2,
@@ -5403,6 +5991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN2
         sprite: SpriteNum::BOS2,
@@ -5412,6 +6001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN3
         sprite: SpriteNum::BOS2,
@@ -5421,6 +6011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN4
         sprite: SpriteNum::BOS2,
@@ -5430,6 +6021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN5
         sprite: SpriteNum::BOS2,
@@ -5439,6 +6031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN6
         sprite: SpriteNum::BOS2,
@@ -5448,6 +6041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN7
         sprite: SpriteNum::BOS2,
@@ -5457,6 +6051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RUN8
         sprite: SpriteNum::BOS2,
@@ -5466,6 +6061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_ATK1
         sprite: SpriteNum::BOS2,
@@ -5475,6 +6071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_ATK2
         sprite: SpriteNum::BOS2,
@@ -5484,6 +6081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_ATK3
         sprite: SpriteNum::BOS2,
@@ -5493,6 +6091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_PAIN
         sprite: SpriteNum::BOS2,
@@ -5502,6 +6101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_PAIN2
         sprite: SpriteNum::BOS2,
@@ -5511,6 +6111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE1
         sprite: SpriteNum::BOS2,
@@ -5520,6 +6121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE2
         sprite: SpriteNum::BOS2,
@@ -5529,6 +6131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE3
         sprite: SpriteNum::BOS2,
@@ -5538,6 +6141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE4
         sprite: SpriteNum::BOS2,
@@ -5547,6 +6151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE5
         sprite: SpriteNum::BOS2,
@@ -5556,6 +6161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE6
         sprite: SpriteNum::BOS2,
@@ -5565,6 +6171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_DIE7
         sprite: SpriteNum::BOS2,
@@ -5574,6 +6181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE1
         sprite: SpriteNum::BOS2,
@@ -5583,6 +6191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE2
         sprite: SpriteNum::BOS2,
@@ -5592,6 +6201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE3
         sprite: SpriteNum::BOS2,
@@ -5601,6 +6211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE4
         sprite: SpriteNum::BOS2,
@@ -5610,6 +6221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE5
         sprite: SpriteNum::BOS2,
@@ -5619,6 +6231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE6
         sprite: SpriteNum::BOS2,
@@ -5628,6 +6241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RAISE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BOS2_RAISE7
         sprite: SpriteNum::BOS2,
@@ -5637,6 +6251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BOS2_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_STND
         sprite: SpriteNum::SKUL,
@@ -5646,6 +6261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_STND2
         sprite: SpriteNum::SKUL,
@@ -5655,6 +6271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_RUN1
         sprite: SpriteNum::SKUL,
@@ -5664,6 +6281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_RUN2
         sprite: SpriteNum::SKUL,
@@ -5673,6 +6291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_ATK1
         sprite: SpriteNum::SKUL,
@@ -5682,6 +6301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_ATK2
         sprite: SpriteNum::SKUL,
@@ -5691,6 +6311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_ATK3
         sprite: SpriteNum::SKUL,
@@ -5700,6 +6321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_ATK4
         sprite: SpriteNum::SKUL,
@@ -5709,6 +6331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_PAIN
         sprite: SpriteNum::SKUL,
@@ -5718,6 +6341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_PAIN2
         sprite: SpriteNum::SKUL,
@@ -5727,6 +6351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE1
         sprite: SpriteNum::SKUL,
@@ -5736,6 +6361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE2
         sprite: SpriteNum::SKUL,
@@ -5745,6 +6371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE3
         sprite: SpriteNum::SKUL,
@@ -5754,6 +6381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE4
         sprite: SpriteNum::SKUL,
@@ -5763,6 +6391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE5
         sprite: SpriteNum::SKUL,
@@ -5772,6 +6401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SKULL_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULL_DIE6
         sprite: SpriteNum::SKUL,
@@ -5781,6 +6411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_STND
         sprite: SpriteNum::SPID,
@@ -5790,6 +6421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_STND2
         sprite: SpriteNum::SPID,
@@ -5799,6 +6431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN1
         sprite: SpriteNum::SPID,
@@ -5808,6 +6441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN2
         sprite: SpriteNum::SPID,
@@ -5817,6 +6451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN3
         sprite: SpriteNum::SPID,
@@ -5826,6 +6461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN4
         sprite: SpriteNum::SPID,
@@ -5835,6 +6471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN5
         sprite: SpriteNum::SPID,
@@ -5844,6 +6481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN6
         sprite: SpriteNum::SPID,
@@ -5853,6 +6491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN7
         sprite: SpriteNum::SPID,
@@ -5862,6 +6501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN8
         sprite: SpriteNum::SPID,
@@ -5871,6 +6511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN9
         sprite: SpriteNum::SPID,
@@ -5880,6 +6521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN10
         sprite: SpriteNum::SPID,
@@ -5889,6 +6531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN11
         sprite: SpriteNum::SPID,
@@ -5898,6 +6541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_RUN12
         sprite: SpriteNum::SPID,
@@ -5907,6 +6551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_ATK1
         sprite: SpriteNum::SPID,
@@ -5916,6 +6561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_ATK2
         sprite: SpriteNum::SPID,
@@ -5925,6 +6571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_ATK3
         sprite: SpriteNum::SPID,
@@ -5934,6 +6581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_ATK4
         sprite: SpriteNum::SPID,
@@ -5943,6 +6591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_PAIN
         sprite: SpriteNum::SPID,
@@ -5952,6 +6601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_PAIN2
         sprite: SpriteNum::SPID,
@@ -5961,6 +6611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE1
         sprite: SpriteNum::SPID,
@@ -5970,6 +6621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE2
         sprite: SpriteNum::SPID,
@@ -5979,6 +6631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE3
         sprite: SpriteNum::SPID,
@@ -5988,6 +6641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE4
         sprite: SpriteNum::SPID,
@@ -5997,6 +6651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE5
         sprite: SpriteNum::SPID,
@@ -6006,6 +6661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE6
         sprite: SpriteNum::SPID,
@@ -6015,6 +6671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE7
         sprite: SpriteNum::SPID,
@@ -6024,6 +6681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE8
         sprite: SpriteNum::SPID,
@@ -6033,6 +6691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE9
         sprite: SpriteNum::SPID,
@@ -6042,6 +6701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE10
         sprite: SpriteNum::SPID,
@@ -6051,6 +6711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPID_DIE11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPID_DIE11
         sprite: SpriteNum::SPID,
@@ -6060,6 +6721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_STND
         sprite: SpriteNum::BSPI,
@@ -6069,6 +6731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_STND2
         sprite: SpriteNum::BSPI,
@@ -6078,6 +6741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_SIGHT
         sprite: SpriteNum::BSPI,
@@ -6087,6 +6751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN1
         sprite: SpriteNum::BSPI,
@@ -6096,6 +6761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN2
         sprite: SpriteNum::BSPI,
@@ -6105,6 +6771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN3
         sprite: SpriteNum::BSPI,
@@ -6114,6 +6781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN4
         sprite: SpriteNum::BSPI,
@@ -6123,6 +6791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN5
         sprite: SpriteNum::BSPI,
@@ -6132,6 +6801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN6
         sprite: SpriteNum::BSPI,
@@ -6141,6 +6811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN7
         sprite: SpriteNum::BSPI,
@@ -6150,6 +6821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN8
         sprite: SpriteNum::BSPI,
@@ -6159,6 +6831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN9
         sprite: SpriteNum::BSPI,
@@ -6168,6 +6841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN10
         sprite: SpriteNum::BSPI,
@@ -6177,6 +6851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN11
         sprite: SpriteNum::BSPI,
@@ -6186,6 +6861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RUN12
         sprite: SpriteNum::BSPI,
@@ -6195,6 +6871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_ATK1
         sprite: SpriteNum::BSPI,
@@ -6204,6 +6881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_ATK2
         sprite: SpriteNum::BSPI,
@@ -6213,6 +6891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_ATK3
         sprite: SpriteNum::BSPI,
@@ -6222,6 +6901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_ATK4
         sprite: SpriteNum::BSPI,
@@ -6231,6 +6911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_PAIN
         sprite: SpriteNum::BSPI,
@@ -6240,6 +6921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_PAIN2
         sprite: SpriteNum::BSPI,
@@ -6249,6 +6931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE1
         sprite: SpriteNum::BSPI,
@@ -6258,6 +6941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE2
         sprite: SpriteNum::BSPI,
@@ -6267,6 +6951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE3
         sprite: SpriteNum::BSPI,
@@ -6276,6 +6961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE4
         sprite: SpriteNum::BSPI,
@@ -6285,6 +6971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE5
         sprite: SpriteNum::BSPI,
@@ -6294,6 +6981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE6
         sprite: SpriteNum::BSPI,
@@ -6303,6 +6991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_DIE7
         sprite: SpriteNum::BSPI,
@@ -6312,6 +7001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE1
         sprite: SpriteNum::BSPI,
@@ -6321,6 +7011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE2
         sprite: SpriteNum::BSPI,
@@ -6330,6 +7021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE3
         sprite: SpriteNum::BSPI,
@@ -6339,6 +7031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE4
         sprite: SpriteNum::BSPI,
@@ -6348,6 +7041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE5
         sprite: SpriteNum::BSPI,
@@ -6357,6 +7051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE6
         sprite: SpriteNum::BSPI,
@@ -6366,6 +7061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RAISE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSPI_RAISE7
         sprite: SpriteNum::BSPI,
@@ -6375,6 +7071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSPI_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLAZ
         sprite: SpriteNum::APLS,
@@ -6384,6 +7081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLAZ2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLAZ2
         sprite: SpriteNum::APLS,
@@ -6393,6 +7091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLAZ,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLEX
         sprite: SpriteNum::APBX,
@@ -6402,6 +7101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLEX2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLEX2
         sprite: SpriteNum::APBX,
@@ -6411,6 +7111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLEX3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLEX3
         sprite: SpriteNum::APBX,
@@ -6420,6 +7121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLEX4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLEX4
         sprite: SpriteNum::APBX,
@@ -6429,6 +7131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARACH_PLEX5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARACH_PLEX5
         sprite: SpriteNum::APBX,
@@ -6438,6 +7141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_STND
         sprite: SpriteNum::CYBR,
@@ -6447,6 +7151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_STND2
         sprite: SpriteNum::CYBR,
@@ -6456,6 +7161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN1
         sprite: SpriteNum::CYBR,
@@ -6465,6 +7171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN2
         sprite: SpriteNum::CYBR,
@@ -6474,6 +7181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN3
         sprite: SpriteNum::CYBR,
@@ -6483,6 +7191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN4
         sprite: SpriteNum::CYBR,
@@ -6492,6 +7201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN5
         sprite: SpriteNum::CYBR,
@@ -6501,6 +7211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN6
         sprite: SpriteNum::CYBR,
@@ -6510,6 +7221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN7
         sprite: SpriteNum::CYBR,
@@ -6519,6 +7231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_RUN8
         sprite: SpriteNum::CYBR,
@@ -6528,6 +7241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK1
         sprite: SpriteNum::CYBR,
@@ -6537,6 +7251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK2
         sprite: SpriteNum::CYBR,
@@ -6546,6 +7261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK3
         sprite: SpriteNum::CYBR,
@@ -6555,6 +7271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK4
         sprite: SpriteNum::CYBR,
@@ -6564,6 +7281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_ATK5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK5
         sprite: SpriteNum::CYBR,
@@ -6573,6 +7291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_ATK6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_ATK6
         sprite: SpriteNum::CYBR,
@@ -6582,6 +7301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_PAIN
         sprite: SpriteNum::CYBR,
@@ -6591,6 +7311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE1
         sprite: SpriteNum::CYBR,
@@ -6600,6 +7321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE2
         sprite: SpriteNum::CYBR,
@@ -6609,6 +7331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE3
         sprite: SpriteNum::CYBR,
@@ -6618,6 +7341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE4
         sprite: SpriteNum::CYBR,
@@ -6627,6 +7351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE5
         sprite: SpriteNum::CYBR,
@@ -6636,6 +7361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE6
         sprite: SpriteNum::CYBR,
@@ -6645,6 +7371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE7
         sprite: SpriteNum::CYBR,
@@ -6654,6 +7381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE8
         sprite: SpriteNum::CYBR,
@@ -6663,6 +7391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE9
         sprite: SpriteNum::CYBR,
@@ -6672,6 +7401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::CYBER_DIE10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CYBER_DIE10
         sprite: SpriteNum::CYBR,
@@ -6681,6 +7411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_STND
         sprite: SpriteNum::PAIN,
@@ -6690,6 +7421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN1
         sprite: SpriteNum::PAIN,
@@ -6699,6 +7431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN2
         sprite: SpriteNum::PAIN,
@@ -6708,6 +7441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN3
         sprite: SpriteNum::PAIN,
@@ -6717,6 +7451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN4
         sprite: SpriteNum::PAIN,
@@ -6726,6 +7461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN5
         sprite: SpriteNum::PAIN,
@@ -6735,6 +7471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RUN6
         sprite: SpriteNum::PAIN,
@@ -6744,6 +7481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_ATK1
         sprite: SpriteNum::PAIN,
@@ -6753,6 +7491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_ATK2
         sprite: SpriteNum::PAIN,
@@ -6762,6 +7501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_ATK3
         sprite: SpriteNum::PAIN,
@@ -6771,6 +7511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_ATK4
         sprite: SpriteNum::PAIN,
@@ -6780,6 +7521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_PAIN
         sprite: SpriteNum::PAIN,
@@ -6789,6 +7531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_PAIN2
         sprite: SpriteNum::PAIN,
@@ -6798,6 +7541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE1
         sprite: SpriteNum::PAIN,
@@ -6807,6 +7551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE2
         sprite: SpriteNum::PAIN,
@@ -6816,6 +7561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE3
         sprite: SpriteNum::PAIN,
@@ -6825,6 +7571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE4
         sprite: SpriteNum::PAIN,
@@ -6834,6 +7581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE5
         sprite: SpriteNum::PAIN,
@@ -6843,6 +7591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_DIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_DIE6
         sprite: SpriteNum::PAIN,
@@ -6852,6 +7601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE1
         sprite: SpriteNum::PAIN,
@@ -6861,6 +7611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE2
         sprite: SpriteNum::PAIN,
@@ -6870,6 +7621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE3
         sprite: SpriteNum::PAIN,
@@ -6879,6 +7631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE4
         sprite: SpriteNum::PAIN,
@@ -6888,6 +7641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE5
         sprite: SpriteNum::PAIN,
@@ -6897,6 +7651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RAISE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PAIN_RAISE6
         sprite: SpriteNum::PAIN,
@@ -6906,6 +7661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PAIN_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_STND
         sprite: SpriteNum::SSWV,
@@ -6915,6 +7671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_STND2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_STND2
         sprite: SpriteNum::SSWV,
@@ -6924,6 +7681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_STND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN1
         sprite: SpriteNum::SSWV,
@@ -6933,6 +7691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN2
         sprite: SpriteNum::SSWV,
@@ -6942,6 +7701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN3
         sprite: SpriteNum::SSWV,
@@ -6951,6 +7711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN4
         sprite: SpriteNum::SSWV,
@@ -6960,6 +7721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN5
         sprite: SpriteNum::SSWV,
@@ -6969,6 +7731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN6
         sprite: SpriteNum::SSWV,
@@ -6978,6 +7741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN7
         sprite: SpriteNum::SSWV,
@@ -6987,6 +7751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RUN8
         sprite: SpriteNum::SSWV,
@@ -6996,6 +7761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK1
         sprite: SpriteNum::SSWV,
@@ -7005,6 +7771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK2
         sprite: SpriteNum::SSWV,
@@ -7014,6 +7781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK3
         sprite: SpriteNum::SSWV,
@@ -7023,6 +7791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK4
         sprite: SpriteNum::SSWV,
@@ -7032,6 +7801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK5
         sprite: SpriteNum::SSWV,
@@ -7041,6 +7811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_ATK6
         sprite: SpriteNum::SSWV,
@@ -7050,6 +7821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_ATK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_PAIN
         sprite: SpriteNum::SSWV,
@@ -7059,6 +7831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_PAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_PAIN2
         sprite: SpriteNum::SSWV,
@@ -7068,6 +7841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_DIE1
         sprite: SpriteNum::SSWV,
@@ -7077,6 +7851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_DIE2
         sprite: SpriteNum::SSWV,
@@ -7086,6 +7861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_DIE3
         sprite: SpriteNum::SSWV,
@@ -7095,6 +7871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_DIE4
         sprite: SpriteNum::SSWV,
@@ -7104,6 +7881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_DIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_DIE5
         sprite: SpriteNum::SSWV,
@@ -7113,6 +7891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE1
         sprite: SpriteNum::SSWV,
@@ -7122,6 +7901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE2
         sprite: SpriteNum::SSWV,
@@ -7131,6 +7911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE3
         sprite: SpriteNum::SSWV,
@@ -7140,6 +7921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE4
         sprite: SpriteNum::SSWV,
@@ -7149,6 +7931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE5
         sprite: SpriteNum::SSWV,
@@ -7158,6 +7941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE6
         sprite: SpriteNum::SSWV,
@@ -7167,6 +7951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE7
         sprite: SpriteNum::SSWV,
@@ -7176,6 +7961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE8
         sprite: SpriteNum::SSWV,
@@ -7185,6 +7971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_XDIE9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_XDIE9
         sprite: SpriteNum::SSWV,
@@ -7194,6 +7981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RAISE1
         sprite: SpriteNum::SSWV,
@@ -7203,6 +7991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RAISE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RAISE2
         sprite: SpriteNum::SSWV,
@@ -7212,6 +8001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RAISE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RAISE3
         sprite: SpriteNum::SSWV,
@@ -7221,6 +8011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RAISE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RAISE4
         sprite: SpriteNum::SSWV,
@@ -7230,6 +8021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RAISE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SSWV_RAISE5
         sprite: SpriteNum::SSWV,
@@ -7239,6 +8031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SSWV_RUN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_KEENSTND
         sprite: SpriteNum::KEEN,
@@ -7248,6 +8041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::KEENSTND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN
         sprite: SpriteNum::KEEN,
@@ -7257,6 +8051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN2
         sprite: SpriteNum::KEEN,
@@ -7266,6 +8061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN3
         sprite: SpriteNum::KEEN,
@@ -7275,6 +8071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN4
         sprite: SpriteNum::KEEN,
@@ -7284,6 +8081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN5
         sprite: SpriteNum::KEEN,
@@ -7293,6 +8091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN6
         sprite: SpriteNum::KEEN,
@@ -7302,6 +8101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN7
         sprite: SpriteNum::KEEN,
@@ -7311,6 +8111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN8
         sprite: SpriteNum::KEEN,
@@ -7320,6 +8121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN9,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN9
         sprite: SpriteNum::KEEN,
@@ -7329,6 +8131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN10,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN10
         sprite: SpriteNum::KEEN,
@@ -7338,6 +8141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN11,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN11
         sprite: SpriteNum::KEEN,
@@ -7347,6 +8151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::COMMKEEN12,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COMMKEEN12
         sprite: SpriteNum::KEEN,
@@ -7356,6 +8161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_KEENPAIN
         sprite: SpriteNum::KEEN,
@@ -7365,6 +8171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::KEENPAIN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_KEENPAIN2
         sprite: SpriteNum::KEEN,
@@ -7374,6 +8181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::KEENSTND,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN
         sprite: SpriteNum::BBRN,
@@ -7383,6 +8191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN_PAIN
         sprite: SpriteNum::BBRN,
@@ -7392,6 +8201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAIN,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN_DIE1
         sprite: SpriteNum::BBRN,
@@ -7401,6 +8211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAIN_DIE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN_DIE2
         sprite: SpriteNum::BBRN,
@@ -7410,6 +8221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAIN_DIE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN_DIE3
         sprite: SpriteNum::BBRN,
@@ -7419,6 +8231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAIN_DIE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAIN_DIE4
         sprite: SpriteNum::BBRN,
@@ -7428,6 +8241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEYE
         sprite: SpriteNum::SSWV,
@@ -7437,6 +8251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAINEYE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEYESEE
         sprite: SpriteNum::SSWV,
@@ -7446,6 +8261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAINEYE1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEYE1
         sprite: SpriteNum::SSWV,
@@ -7455,6 +8271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAINEYE1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWN1
         sprite: SpriteNum::BOSF,
@@ -7464,6 +8281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWN2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWN2
         sprite: SpriteNum::BOSF,
@@ -7473,6 +8291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWN3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWN3
         sprite: SpriteNum::BOSF,
@@ -7482,6 +8301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWN4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWN4
         sprite: SpriteNum::BOSF,
@@ -7491,6 +8311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWN1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE1
         sprite: SpriteNum::FIRE,
@@ -7500,6 +8321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE2
         sprite: SpriteNum::FIRE,
@@ -7509,6 +8331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE3
         sprite: SpriteNum::FIRE,
@@ -7518,6 +8341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE4
         sprite: SpriteNum::FIRE,
@@ -7527,6 +8351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE5
         sprite: SpriteNum::FIRE,
@@ -7536,6 +8361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE6
         sprite: SpriteNum::FIRE,
@@ -7545,6 +8371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE7,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE7
         sprite: SpriteNum::FIRE,
@@ -7554,6 +8381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SPAWNFIRE8,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SPAWNFIRE8
         sprite: SpriteNum::FIRE,
@@ -7563,6 +8391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEXPLODE1
         sprite: SpriteNum::MISL,
@@ -7572,6 +8401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAINEXPLODE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEXPLODE2
         sprite: SpriteNum::MISL,
@@ -7581,6 +8411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BRAINEXPLODE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINEXPLODE3
         sprite: SpriteNum::MISL,
@@ -7590,6 +8421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARM1
         sprite: SpriteNum::ARM1,
@@ -7599,6 +8431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARM1A,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARM1A
         sprite: SpriteNum::ARM1,
@@ -7608,6 +8441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARM1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARM2
         sprite: SpriteNum::ARM2,
@@ -7617,6 +8451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARM2A,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ARM2A
         sprite: SpriteNum::ARM2,
@@ -7626,6 +8461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::ARM2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BAR1
         sprite: SpriteNum::BAR1,
@@ -7635,6 +8471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BAR2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BAR2
         sprite: SpriteNum::BAR1,
@@ -7644,6 +8481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BAR1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BEXP
         sprite: SpriteNum::BEXP,
@@ -7653,6 +8491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BEXP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BEXP2
         sprite: SpriteNum::BEXP,
@@ -7662,6 +8501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BEXP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BEXP3
         sprite: SpriteNum::BEXP,
@@ -7671,6 +8511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BEXP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BEXP4
         sprite: SpriteNum::BEXP,
@@ -7680,6 +8521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BEXP5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BEXP5
         sprite: SpriteNum::BEXP,
@@ -7689,6 +8531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BBAR1
         sprite: SpriteNum::FCAN,
@@ -7698,6 +8541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BBAR2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BBAR2
         sprite: SpriteNum::FCAN,
@@ -7707,6 +8551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BBAR3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BBAR3
         sprite: SpriteNum::FCAN,
@@ -7716,6 +8561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BBAR1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1
         sprite: SpriteNum::BON1,
@@ -7725,6 +8571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1A,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1A
         sprite: SpriteNum::BON1,
@@ -7734,6 +8581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1B,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1B
         sprite: SpriteNum::BON1,
@@ -7743,6 +8591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1C,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1C
         sprite: SpriteNum::BON1,
@@ -7752,6 +8601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1D,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1D
         sprite: SpriteNum::BON1,
@@ -7761,6 +8611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1E,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON1E
         sprite: SpriteNum::BON1,
@@ -7770,6 +8621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON1,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2
         sprite: SpriteNum::BON2,
@@ -7779,6 +8631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2A,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2A
         sprite: SpriteNum::BON2,
@@ -7788,6 +8641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2B,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2B
         sprite: SpriteNum::BON2,
@@ -7797,6 +8651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2C,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2C
         sprite: SpriteNum::BON2,
@@ -7806,6 +8661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2D,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2D
         sprite: SpriteNum::BON2,
@@ -7815,6 +8671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2E,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BON2E
         sprite: SpriteNum::BON2,
@@ -7824,6 +8681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BON2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BKEY
         sprite: SpriteNum::BKEY,
@@ -7833,6 +8691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BKEY2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BKEY2
         sprite: SpriteNum::BKEY,
@@ -7842,6 +8701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BKEY,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RKEY
         sprite: SpriteNum::RKEY,
@@ -7851,6 +8711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RKEY2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RKEY2
         sprite: SpriteNum::RKEY,
@@ -7860,6 +8721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RKEY,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_YKEY
         sprite: SpriteNum::YKEY,
@@ -7869,6 +8731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::YKEY2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_YKEY2
         sprite: SpriteNum::YKEY,
@@ -7878,6 +8741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::YKEY,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSKULL
         sprite: SpriteNum::BSKU,
@@ -7887,6 +8751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSKULL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BSKULL2
         sprite: SpriteNum::BSKU,
@@ -7896,6 +8761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BSKULL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RSKULL
         sprite: SpriteNum::RSKU,
@@ -7905,6 +8771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RSKULL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RSKULL2
         sprite: SpriteNum::RSKU,
@@ -7914,6 +8781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RSKULL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_YSKULL
         sprite: SpriteNum::YSKU,
@@ -7923,6 +8791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::YSKULL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_YSKULL2
         sprite: SpriteNum::YSKU,
@@ -7932,6 +8801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::YSKULL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_STIM
         sprite: SpriteNum::STIM,
@@ -7941,6 +8811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEDI
         sprite: SpriteNum::MEDI,
@@ -7950,6 +8821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL
         sprite: SpriteNum::SOUL,
@@ -7959,6 +8831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL2
         sprite: SpriteNum::SOUL,
@@ -7968,6 +8841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL3
         sprite: SpriteNum::SOUL,
@@ -7977,6 +8851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL4
         sprite: SpriteNum::SOUL,
@@ -7986,6 +8861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL5
         sprite: SpriteNum::SOUL,
@@ -7995,6 +8871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SOUL6
         sprite: SpriteNum::SOUL,
@@ -8004,6 +8881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::SOUL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINV
         sprite: SpriteNum::PINV,
@@ -8013,6 +8891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINV2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINV2
         sprite: SpriteNum::PINV,
@@ -8022,6 +8901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINV3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINV3
         sprite: SpriteNum::PINV,
@@ -8031,6 +8911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINV4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINV4
         sprite: SpriteNum::PINV,
@@ -8040,6 +8921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINV,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PSTR
         sprite: SpriteNum::PSTR,
@@ -8049,6 +8931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINS
         sprite: SpriteNum::PINS,
@@ -8058,6 +8941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINS2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINS2
         sprite: SpriteNum::PINS,
@@ -8067,6 +8951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINS3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINS3
         sprite: SpriteNum::PINS,
@@ -8076,6 +8961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINS4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PINS4
         sprite: SpriteNum::PINS,
@@ -8085,6 +8971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PINS,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEGA
         sprite: SpriteNum::MEGA,
@@ -8094,6 +8981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MEGA2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEGA2
         sprite: SpriteNum::MEGA,
@@ -8103,6 +8991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MEGA3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEGA3
         sprite: SpriteNum::MEGA,
@@ -8112,6 +9001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MEGA4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEGA4
         sprite: SpriteNum::MEGA,
@@ -8121,6 +9011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::MEGA,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SUIT
         sprite: SpriteNum::SUIT,
@@ -8130,6 +9021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP
         sprite: SpriteNum::PMAP,
@@ -8139,6 +9031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP2
         sprite: SpriteNum::PMAP,
@@ -8148,6 +9041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP3
         sprite: SpriteNum::PMAP,
@@ -8157,6 +9051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP4
         sprite: SpriteNum::PMAP,
@@ -8166,6 +9061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP5,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP5
         sprite: SpriteNum::PMAP,
@@ -8175,6 +9071,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP6,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PMAP6
         sprite: SpriteNum::PMAP,
@@ -8184,6 +9081,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PMAP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PVIS
         sprite: SpriteNum::PVIS,
@@ -8193,6 +9091,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PVIS2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PVIS2
         sprite: SpriteNum::PVIS,
@@ -8202,6 +9101,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::PVIS,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CLIP
         sprite: SpriteNum::CLIP,
@@ -8211,6 +9111,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_AMMO
         sprite: SpriteNum::AMMO,
@@ -8220,6 +9121,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_ROCK
         sprite: SpriteNum::ROCK,
@@ -8229,6 +9131,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BROK
         sprite: SpriteNum::BROK,
@@ -8238,6 +9141,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CELL
         sprite: SpriteNum::CELL,
@@ -8247,6 +9151,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CELP
         sprite: SpriteNum::CELP,
@@ -8256,6 +9161,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SHEL
         sprite: SpriteNum::SHEL,
@@ -8265,6 +9171,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SBOX
         sprite: SpriteNum::SBOX,
@@ -8274,6 +9181,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BPAK
         sprite: SpriteNum::BPAK,
@@ -8283,6 +9191,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BFUG
         sprite: SpriteNum::BFUG,
@@ -8292,6 +9201,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MGUN
         sprite: SpriteNum::MGUN,
@@ -8301,6 +9211,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CSAW
         sprite: SpriteNum::CSAW,
@@ -8310,6 +9221,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_LAUN
         sprite: SpriteNum::LAUN,
@@ -8319,6 +9231,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_PLAS
         sprite: SpriteNum::PLAS,
@@ -8328,6 +9241,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SHOT
         sprite: SpriteNum::SHOT,
@@ -8337,6 +9251,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SHOT2
         sprite: SpriteNum::SGN2,
@@ -8346,6 +9261,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COLU
         sprite: SpriteNum::COLU,
@@ -8355,6 +9271,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_STALAG
         sprite: SpriteNum::SMT2,
@@ -8364,6 +9281,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOODYTWITCH
         sprite: SpriteNum::GOR1,
@@ -8373,6 +9291,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOODYTWITCH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOODYTWITCH2
         sprite: SpriteNum::GOR1,
@@ -8382,6 +9301,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOODYTWITCH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOODYTWITCH3
         sprite: SpriteNum::GOR1,
@@ -8391,6 +9311,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOODYTWITCH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLOODYTWITCH4
         sprite: SpriteNum::GOR1,
@@ -8400,6 +9321,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLOODYTWITCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DEADTORSO
         sprite: SpriteNum::PLAY,
@@ -8409,6 +9331,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DEADBOTTOM
         sprite: SpriteNum::PLAY,
@@ -8418,6 +9341,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEADSONSTICK
         sprite: SpriteNum::POL2,
@@ -8427,6 +9351,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GIBS
         sprite: SpriteNum::POL5,
@@ -8436,6 +9361,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEADONASTICK
         sprite: SpriteNum::POL4,
@@ -8445,6 +9371,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEADCANDLES
         sprite: SpriteNum::POL3,
@@ -8454,6 +9381,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEADCANDLES2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEADCANDLES2
         sprite: SpriteNum::POL3,
@@ -8463,6 +9391,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEADCANDLES,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_DEADSTICK
         sprite: SpriteNum::POL1,
@@ -8472,6 +9401,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_LIVESTICK
         sprite: SpriteNum::POL6,
@@ -8481,6 +9411,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIVESTICK2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_LIVESTICK2
         sprite: SpriteNum::POL6,
@@ -8490,6 +9421,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::LIVESTICK,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEAT2
         sprite: SpriteNum::GOR2,
@@ -8499,6 +9431,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEAT3
         sprite: SpriteNum::GOR3,
@@ -8508,6 +9441,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEAT4
         sprite: SpriteNum::GOR4,
@@ -8517,6 +9451,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_MEAT5
         sprite: SpriteNum::GOR5,
@@ -8526,6 +9461,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_STALAGTITE
         sprite: SpriteNum::SMIT,
@@ -8535,6 +9471,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TALLGRNCOL
         sprite: SpriteNum::COL1,
@@ -8544,6 +9481,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SHRTGRNCOL
         sprite: SpriteNum::COL2,
@@ -8553,6 +9491,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TALLREDCOL
         sprite: SpriteNum::COL3,
@@ -8562,6 +9501,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SHRTREDCOL
         sprite: SpriteNum::COL4,
@@ -8571,6 +9511,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CANDLESTIK
         sprite: SpriteNum::CAND,
@@ -8580,6 +9521,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_CANDELABRA
         sprite: SpriteNum::CBRA,
@@ -8589,6 +9531,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SKULLCOL
         sprite: SpriteNum::COL6,
@@ -8598,6 +9541,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TORCHTREE
         sprite: SpriteNum::TRE1,
@@ -8607,6 +9551,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BIGTREE
         sprite: SpriteNum::TRE2,
@@ -8616,6 +9561,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECHPILLAR
         sprite: SpriteNum::ELEC,
@@ -8625,6 +9571,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EVILEYE
         sprite: SpriteNum::CEYE,
@@ -8634,6 +9581,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EVILEYE2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EVILEYE2
         sprite: SpriteNum::CEYE,
@@ -8643,6 +9591,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EVILEYE3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EVILEYE3
         sprite: SpriteNum::CEYE,
@@ -8652,6 +9601,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EVILEYE4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_EVILEYE4
         sprite: SpriteNum::CEYE,
@@ -8661,6 +9611,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::EVILEYE,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FLOATSKULL
         sprite: SpriteNum::FSKU,
@@ -8670,6 +9621,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FLOATSKULL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FLOATSKULL2
         sprite: SpriteNum::FSKU,
@@ -8679,6 +9631,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FLOATSKULL3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_FLOATSKULL3
         sprite: SpriteNum::FSKU,
@@ -8688,6 +9641,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::FLOATSKULL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEARTCOL
         sprite: SpriteNum::COL5,
@@ -8697,6 +9651,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEARTCOL2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HEARTCOL2
         sprite: SpriteNum::COL5,
@@ -8706,6 +9661,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::HEARTCOL,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLUETORCH
         sprite: SpriteNum::TBLU,
@@ -8715,6 +9671,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLUETORCH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLUETORCH2
         sprite: SpriteNum::TBLU,
@@ -8724,6 +9681,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLUETORCH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLUETORCH3
         sprite: SpriteNum::TBLU,
@@ -8733,6 +9691,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLUETORCH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BLUETORCH4
         sprite: SpriteNum::TBLU,
@@ -8742,6 +9701,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BLUETORCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GREENTORCH
         sprite: SpriteNum::TGRN,
@@ -8751,6 +9711,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GREENTORCH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GREENTORCH2
         sprite: SpriteNum::TGRN,
@@ -8760,6 +9721,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GREENTORCH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GREENTORCH3
         sprite: SpriteNum::TGRN,
@@ -8769,6 +9731,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GREENTORCH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GREENTORCH4
         sprite: SpriteNum::TGRN,
@@ -8778,6 +9741,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GREENTORCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_REDTORCH
         sprite: SpriteNum::TRED,
@@ -8787,6 +9751,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::REDTORCH2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_REDTORCH2
         sprite: SpriteNum::TRED,
@@ -8796,6 +9761,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::REDTORCH3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_REDTORCH3
         sprite: SpriteNum::TRED,
@@ -8805,6 +9771,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::REDTORCH4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_REDTORCH4
         sprite: SpriteNum::TRED,
@@ -8814,6 +9781,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::REDTORCH,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BTORCHSHRT
         sprite: SpriteNum::SMBT,
@@ -8823,6 +9791,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BTORCHSHRT2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BTORCHSHRT2
         sprite: SpriteNum::SMBT,
@@ -8832,6 +9801,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BTORCHSHRT3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BTORCHSHRT3
         sprite: SpriteNum::SMBT,
@@ -8841,6 +9811,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BTORCHSHRT4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BTORCHSHRT4
         sprite: SpriteNum::SMBT,
@@ -8850,6 +9821,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::BTORCHSHRT,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GTORCHSHRT
         sprite: SpriteNum::SMGT,
@@ -8859,6 +9831,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GTORCHSHRT2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GTORCHSHRT2
         sprite: SpriteNum::SMGT,
@@ -8868,6 +9841,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GTORCHSHRT3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GTORCHSHRT3
         sprite: SpriteNum::SMGT,
@@ -8877,6 +9851,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GTORCHSHRT4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_GTORCHSHRT4
         sprite: SpriteNum::SMGT,
@@ -8886,6 +9861,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::GTORCHSHRT,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RTORCHSHRT
         sprite: SpriteNum::SMRT,
@@ -8895,6 +9871,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RTORCHSHRT2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RTORCHSHRT2
         sprite: SpriteNum::SMRT,
@@ -8904,6 +9881,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RTORCHSHRT3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RTORCHSHRT3
         sprite: SpriteNum::SMRT,
@@ -8913,6 +9891,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RTORCHSHRT4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_RTORCHSHRT4
         sprite: SpriteNum::SMRT,
@@ -8922,6 +9901,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::RTORCHSHRT,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGNOGUTS
         sprite: SpriteNum::HDB1,
@@ -8931,6 +9911,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGBNOBRAIN
         sprite: SpriteNum::HDB2,
@@ -8940,6 +9921,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGTLOOKDN
         sprite: SpriteNum::HDB3,
@@ -8949,6 +9931,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGTSKULL
         sprite: SpriteNum::HDB4,
@@ -8958,6 +9941,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGTLOOKUP
         sprite: SpriteNum::HDB5,
@@ -8967,6 +9951,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_HANGTNOBRAIN
         sprite: SpriteNum::HDB6,
@@ -8976,6 +9961,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_COLONGIBS
         sprite: SpriteNum::POB1,
@@ -8985,6 +9971,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_SMALLPOOL
         sprite: SpriteNum::POB2,
@@ -8994,6 +9981,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_BRAINSTEM
         sprite: SpriteNum::BRS1,
@@ -9003,6 +9991,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::None,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECHLAMP
         sprite: SpriteNum::TLMP,
@@ -9012,6 +10001,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECHLAMP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECHLAMP2
         sprite: SpriteNum::TLMP,
@@ -9021,6 +10011,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECHLAMP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECHLAMP3
         sprite: SpriteNum::TLMP,
@@ -9030,6 +10021,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECHLAMP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECHLAMP4
         sprite: SpriteNum::TLMP,
@@ -9039,6 +10031,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECHLAMP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECH2LAMP
         sprite: SpriteNum::TLP2,
@@ -9048,6 +10041,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECH2LAMP2,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECH2LAMP2
         sprite: SpriteNum::TLP2,
@@ -9057,6 +10051,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECH2LAMP3,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECH2LAMP3
         sprite: SpriteNum::TLP2,
@@ -9066,6 +10061,7 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECH2LAMP4,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
     },
     StateData { // S_TECH2LAMP4
         sprite: SpriteNum::TLP2,
@@ -9075,10 +10071,22 @@ pub static STATES: [StateData; NUM_STATES] = [
         next_state: StateNum::TECH2LAMP,
         misc1: 0,
         misc2: 0,
+        args: [0; 8],
+    },
+    StateData { // S_TNT1
+        sprite: SpriteNum::TNT1,
+        frame: 0,
+        tics: -1,
+        action: ActionId::None,
+        next_state: StateNum::TNT1,
+        misc1: 0,
+        misc2: 0,
+        args: [0; 8],
     },
 ];
 
-use crate::thing::MapObjFlag;
+use crate::doom_def::MELEERANGE;
+use crate::thing::{MapObjFlag, MapObjFlag2};
 use sound_common::SfxName;
 
 #[derive(Debug, Copy, Clone)]
@@ -9108,6 +10116,21 @@ pub struct MapObjInfo {
     pub activesound: SfxName,
     pub flags: MapObjFlag,
     pub raisestate: StateNum,
+    /// MBF21 thing flags
+    pub flags2: MapObjFlag2,
+    /// MBF21: things in the same group never infight. 0 is ungrouped
+    pub infighting_group: i32,
+    /// MBF21: projectiles can't hurt things in the same group. 0 falls back
+    /// to same-species immunity, -1 hurts everything
+    pub projectile_group: i32,
+    /// MBF21: splash damage can't hurt things in the same group
+    pub splash_group: i32,
+    /// MBF21: played when a `RIP` projectile tears through a thing
+    pub ripsound: SfxName,
+    /// MBF21: raw speed on `-fast`, 0 to use `speed`
+    pub fastspeed: i32,
+    /// MBF21: melee reach in map units
+    pub meleerange: i32,
 }
 
 const NUM_CATEGORIES: usize = MapObjKind::Count as usize;
@@ -9137,6 +10160,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Pickup.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_POSSESSED
     MapObjInfo {
@@ -9163,6 +10193,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Posact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::POSS_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SHOTGUY
     MapObjInfo {
@@ -9189,6 +10226,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Posact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::SPOS_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_VILE
     MapObjInfo {
@@ -9215,6 +10259,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Vilact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Shortmrange.bits() | MapObjFlag2::Dmgignored.bits() | MapObjFlag2::Nothreshold.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_FIRE
     MapObjInfo {
@@ -9241,6 +10292,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_UNDEAD
     MapObjInfo {
@@ -9267,6 +10325,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Skeact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::SKEL_RAISE1,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Longmelee.bits() | MapObjFlag2::Rangehalf.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_TRACER
     MapObjInfo {
@@ -9293,6 +10358,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SMOKE
     MapObjInfo {
@@ -9319,6 +10391,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_FATSO
     MapObjInfo {
@@ -9345,6 +10424,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Posact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::FATT_RAISE1,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Map07boss1.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_FATSHOT
     MapObjInfo {
@@ -9371,6 +10457,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_CHAINGUY
     MapObjInfo {
@@ -9397,6 +10490,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Posact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::CPOS_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_TROOP
     MapObjInfo {
@@ -9423,6 +10523,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Bgact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::TROO_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SERGEANT
     MapObjInfo {
@@ -9449,6 +10556,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::SARG_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SHADOWS
     MapObjInfo {
@@ -9475,6 +10589,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Shadow.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::SARG_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_HEAD
     MapObjInfo {
@@ -9501,6 +10622,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Float.bits() | MapObjFlag::Nogravity.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::HEAD_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BRUISER
     MapObjInfo {
@@ -9527,6 +10655,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::BOSS_RAISE1,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::E1m8boss.bits()),
+        infighting_group: 0,
+        projectile_group: 1,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BRUISERSHOT
     MapObjInfo {
@@ -9553,6 +10688,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_KNIGHT
     MapObjInfo {
@@ -9579,6 +10721,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::BOS2_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 1,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SKULL
     MapObjInfo {
@@ -9605,6 +10754,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Float.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Rangehalf.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SPIDER
     MapObjInfo {
@@ -9631,6 +10787,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Noradiusdmg.bits() | MapObjFlag2::Rangehalf.bits() | MapObjFlag2::Fullvolsounds.bits() | MapObjFlag2::E3m8boss.bits() | MapObjFlag2::E4m8boss.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BABY
     MapObjInfo {
@@ -9657,6 +10820,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Bspact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::BSPI_RAISE1,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Map07boss2.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_CYBORG
     MapObjInfo {
@@ -9683,6 +10853,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::from_bits_truncate(MapObjFlag2::Noradiusdmg.bits() | MapObjFlag2::Highermprob.bits() | MapObjFlag2::Rangehalf.bits() | MapObjFlag2::Fullvolsounds.bits() | MapObjFlag2::E2m8boss.bits() | MapObjFlag2::E4m6boss.bits()),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_PAIN
     MapObjInfo {
@@ -9709,6 +10886,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Dmact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Float.bits() | MapObjFlag::Nogravity.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::PAIN_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_WOLFSS
     MapObjInfo {
@@ -9735,6 +10919,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::Posact,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::SSWV_RAISE1,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_KEEN
     MapObjInfo {
@@ -9761,6 +10952,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Countkill.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BOSSBRAIN
     MapObjInfo {
@@ -9787,6 +10985,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BOSSSPIT
     MapObjInfo {
@@ -9813,6 +11018,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nosector.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BOSSTARGET
     MapObjInfo {
@@ -9839,6 +11051,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nosector.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SPAWNSHOT
     MapObjInfo {
@@ -9865,6 +11084,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits() | MapObjFlag::Noclip.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SPAWNFIRE
     MapObjInfo {
@@ -9891,6 +11117,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BARREL
     MapObjInfo {
@@ -9917,6 +11150,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Shootable.bits() | MapObjFlag::Noblood.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_TROOPSHOT
     MapObjInfo {
@@ -9943,6 +11183,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_HEADSHOT
     MapObjInfo {
@@ -9969,6 +11216,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_ROCKET
     MapObjInfo {
@@ -9995,6 +11249,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_PLASMA
     MapObjInfo {
@@ -10021,6 +11282,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BFG
     MapObjInfo {
@@ -10047,6 +11315,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_ARACHPLAZ
     MapObjInfo {
@@ -10073,6 +11348,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Missile.bits() | MapObjFlag::Dropoff.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_PUFF
     MapObjInfo {
@@ -10099,6 +11381,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_BLOOD
     MapObjInfo {
@@ -10125,6 +11414,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_TFOG
     MapObjInfo {
@@ -10151,6 +11447,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_IFOG
     MapObjInfo {
@@ -10177,6 +11480,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_TELEPORTMAN
     MapObjInfo {
@@ -10203,6 +11513,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nosector.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_EXTRABFG
     MapObjInfo {
@@ -10229,6 +11546,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC0
     MapObjInfo {
@@ -10255,6 +11579,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC1
     MapObjInfo {
@@ -10281,6 +11612,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC2
     MapObjInfo {
@@ -10307,6 +11645,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC3
     MapObjInfo {
@@ -10333,6 +11678,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC4
     MapObjInfo {
@@ -10359,6 +11711,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC5
     MapObjInfo {
@@ -10385,6 +11744,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC6
     MapObjInfo {
@@ -10411,6 +11777,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC7
     MapObjInfo {
@@ -10437,6 +11810,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC8
     MapObjInfo {
@@ -10463,6 +11843,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC9
     MapObjInfo {
@@ -10489,6 +11876,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Notdmatch.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC10
     MapObjInfo {
@@ -10515,6 +11909,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC11
     MapObjInfo {
@@ -10541,6 +11942,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC12
     MapObjInfo {
@@ -10567,6 +11975,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_INV
     MapObjInfo {
@@ -10593,6 +12008,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC13
     MapObjInfo {
@@ -10619,6 +12041,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_INS
     MapObjInfo {
@@ -10645,6 +12074,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC14
     MapObjInfo {
@@ -10671,6 +12107,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC15
     MapObjInfo {
@@ -10697,6 +12140,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC16
     MapObjInfo {
@@ -10723,6 +12173,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MEGA
     MapObjInfo {
@@ -10749,6 +12206,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits() | MapObjFlag::Countitem.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_CLIP
     MapObjInfo {
@@ -10775,6 +12239,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC17
     MapObjInfo {
@@ -10801,6 +12272,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC18
     MapObjInfo {
@@ -10827,6 +12305,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC19
     MapObjInfo {
@@ -10853,6 +12338,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC20
     MapObjInfo {
@@ -10879,6 +12371,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC21
     MapObjInfo {
@@ -10905,6 +12404,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC22
     MapObjInfo {
@@ -10931,6 +12437,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC23
     MapObjInfo {
@@ -10957,6 +12470,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC24
     MapObjInfo {
@@ -10983,6 +12503,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC25
     MapObjInfo {
@@ -11009,6 +12536,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_CHAINGUN
     MapObjInfo {
@@ -11035,6 +12569,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC26
     MapObjInfo {
@@ -11061,6 +12602,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC27
     MapObjInfo {
@@ -11087,6 +12635,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC28
     MapObjInfo {
@@ -11113,6 +12668,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SHOTGUN
     MapObjInfo {
@@ -11139,6 +12701,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_SUPERSHOTGUN
     MapObjInfo {
@@ -11165,6 +12734,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Special.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC29
     MapObjInfo {
@@ -11191,6 +12767,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC30
     MapObjInfo {
@@ -11217,6 +12800,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC31
     MapObjInfo {
@@ -11243,6 +12833,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC32
     MapObjInfo {
@@ -11269,6 +12866,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC33
     MapObjInfo {
@@ -11295,6 +12899,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC34
     MapObjInfo {
@@ -11321,6 +12932,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC35
     MapObjInfo {
@@ -11347,6 +12965,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC36
     MapObjInfo {
@@ -11373,6 +12998,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC37
     MapObjInfo {
@@ -11399,6 +13031,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC38
     MapObjInfo {
@@ -11425,6 +13064,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC39
     MapObjInfo {
@@ -11451,6 +13097,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC40
     MapObjInfo {
@@ -11477,6 +13130,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC41
     MapObjInfo {
@@ -11503,6 +13163,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC42
     MapObjInfo {
@@ -11529,6 +13196,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC43
     MapObjInfo {
@@ -11555,6 +13229,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC44
     MapObjInfo {
@@ -11581,6 +13262,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC45
     MapObjInfo {
@@ -11607,6 +13295,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC46
     MapObjInfo {
@@ -11633,6 +13328,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC47
     MapObjInfo {
@@ -11659,6 +13361,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC48
     MapObjInfo {
@@ -11685,6 +13394,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC49
     MapObjInfo {
@@ -11711,6 +13427,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC50
     MapObjInfo {
@@ -11737,6 +13460,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC51
     MapObjInfo {
@@ -11763,6 +13493,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC52
     MapObjInfo {
@@ -11789,6 +13526,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC53
     MapObjInfo {
@@ -11815,6 +13559,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC54
     MapObjInfo {
@@ -11841,6 +13592,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC55
     MapObjInfo {
@@ -11867,6 +13625,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC56
     MapObjInfo {
@@ -11893,6 +13658,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC57
     MapObjInfo {
@@ -11919,6 +13691,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC58
     MapObjInfo {
@@ -11945,6 +13724,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC59
     MapObjInfo {
@@ -11971,6 +13757,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC60
     MapObjInfo {
@@ -11997,6 +13790,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC61
     MapObjInfo {
@@ -12023,6 +13823,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC62
     MapObjInfo {
@@ -12049,6 +13856,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC63
     MapObjInfo {
@@ -12075,6 +13889,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC64
     MapObjInfo {
@@ -12101,6 +13922,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC65
     MapObjInfo {
@@ -12127,6 +13955,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC66
     MapObjInfo {
@@ -12153,6 +13988,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC67
     MapObjInfo {
@@ -12179,6 +14021,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC68
     MapObjInfo {
@@ -12205,6 +14054,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC69
     MapObjInfo {
@@ -12231,6 +14087,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC70
     MapObjInfo {
@@ -12257,6 +14120,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC71
     MapObjInfo {
@@ -12283,6 +14153,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::empty(),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC72
     MapObjInfo {
@@ -12309,6 +14186,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC73
     MapObjInfo {
@@ -12335,6 +14219,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC74
     MapObjInfo {
@@ -12361,6 +14252,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC75
     MapObjInfo {
@@ -12387,6 +14285,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC76
     MapObjInfo {
@@ -12413,6 +14318,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC77
     MapObjInfo {
@@ -12439,6 +14351,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC78
     MapObjInfo {
@@ -12465,6 +14384,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC79
     MapObjInfo {
@@ -12491,6 +14417,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC80
     MapObjInfo {
@@ -12517,6 +14450,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC81
     MapObjInfo {
@@ -12543,6 +14483,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC82
     MapObjInfo {
@@ -12569,6 +14516,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC83
     MapObjInfo {
@@ -12595,6 +14549,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Solid.bits() | MapObjFlag::Spawnceiling.bits() | MapObjFlag::Nogravity.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC84
     MapObjInfo {
@@ -12621,6 +14582,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC85
     MapObjInfo {
@@ -12647,6 +14615,13 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
     // MT_MISC86
     MapObjInfo {
@@ -12673,5 +14648,78 @@ pub const MOBJINFO: [MapObjInfo; NUM_CATEGORIES] = [
         activesound: SfxName::None,
         flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
         raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
+    },
+    // MT_PUSH
+    MapObjInfo {
+        doomednum: 5001,
+        spawnstate: StateNum::TNT1,
+        spawnhealth: 1000,
+        seestate: StateNum::None,
+        seesound: SfxName::None,
+        reactiontime: 8,
+        attacksound: SfxName::None,
+        painstate: StateNum::None,
+        painchance: 0,
+        painsound: SfxName::None,
+        meleestate: StateNum::None,
+        missilestate: StateNum::None,
+        deathstate: StateNum::None,
+        xdeathstate: StateNum::None,
+        deathsound: SfxName::None,
+        speed: 0,
+        radius: 8.0,
+        height: 8.0,
+        mass: 10,
+        damage: 0,
+        activesound: SfxName::None,
+        flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
+        raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
+    },
+    // MT_PULL
+    MapObjInfo {
+        doomednum: 5002,
+        spawnstate: StateNum::TNT1,
+        spawnhealth: 1000,
+        seestate: StateNum::None,
+        seesound: SfxName::None,
+        reactiontime: 8,
+        attacksound: SfxName::None,
+        painstate: StateNum::None,
+        painchance: 0,
+        painsound: SfxName::None,
+        meleestate: StateNum::None,
+        missilestate: StateNum::None,
+        deathstate: StateNum::None,
+        xdeathstate: StateNum::None,
+        deathsound: SfxName::None,
+        speed: 0,
+        radius: 8.0,
+        height: 8.0,
+        mass: 10,
+        damage: 0,
+        activesound: SfxName::None,
+        flags: MapObjFlag::from_bits_truncate(MapObjFlag::Noblockmap.bits()),
+        raisestate: StateNum::None,
+        flags2: MapObjFlag2::empty(),
+        infighting_group: 0,
+        projectile_group: 0,
+        splash_group: 0,
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     },
 ];
@@ -73,6 +73,215 @@ pub const STSTR_BEHOLDX: &str = "Power-up Toggled";
 pub const STSTR_CHOPPERS: &str = "... doesn't suck - GM";
 pub const STSTR_CLEV: &str = "Changing Level...";
 
+// Automap
+pub const AMSTR_FOLLOWON: &str = "Follow Mode ON";
+pub const AMSTR_FOLLOWOFF: &str = "Follow Mode OFF";
+pub const AMSTR_GRIDON: &str = "Grid ON";
+pub const AMSTR_GRIDOFF: &str = "Grid OFF";
+pub const AMSTR_MARKEDSPOT: &str = "Marked Spot";
+pub const AMSTR_MARKSCLEARED: &str = "All Marks Cleared";
+
+// Level names
+/// `HUSTR_E1M1` to `HUSTR_E4M9`
+pub const MAPNAMES: [&str; 36] = [
+    "E1M1: Hangar",
+    "E1M2: Nuclear Plant",
+    "E1M3: Toxin Refinery",
+    "E1M4: Command Control",
+    "E1M5: Phobos Lab",
+    "E1M6: Central Processing",
+    "E1M7: Computer Station",
+    "E1M8: Phobos Anomaly",
+    "E1M9: Military Base",
+    "E2M1: Deimos Anomaly",
+    "E2M2: Containment Area",
+    "E2M3: Refinery",
+    "E2M4: Deimos Lab",
+    "E2M5: Command Center",
+    "E2M6: Halls of the Damned",
+    "E2M7: Spawning Vats",
+    "E2M8: Tower of Babel",
+    "E2M9: Fortress of Mystery",
+    "E3M1: Hell Keep",
+    "E3M2: Slough of Despair",
+    "E3M3: Pandemonium",
+    "E3M4: House of Pain",
+    "E3M5: Unholy Cathedral",
+    "E3M6: Mt. Erebus",
+    "E3M7: Limbo",
+    "E3M8: Dis",
+    "E3M9: Warrens",
+    "E4M1: Hell Beneath",
+    "E4M2: Perfect Hatred",
+    "E4M3: Sever The Wicked",
+    "E4M4: Unruly Evil",
+    "E4M5: They Will Repent",
+    "E4M6: Against Thee Wickedly",
+    "E4M7: And Hell Followed",
+    "E4M8: Unto The Cruel",
+    "E4M9: Fear",
+];
+/// `HUSTR_1` to `HUSTR_32`
+pub const MAPNAMES2: [&str; 32] = [
+    "level 1: entryway",
+    "level 2: underhalls",
+    "level 3: the gantlet",
+    "level 4: the focus",
+    "level 5: the waste tunnels",
+    "level 6: the crusher",
+    "level 7: dead simple",
+    "level 8: tricks and traps",
+    "level 9: the pit",
+    "level 10: refueling base",
+    "level 11: 'o' of destruction!",
+    "level 12: the factory",
+    "level 13: downtown",
+    "level 14: the inmost dens",
+    "level 15: industrial zone",
+    "level 16: suburbs",
+    "level 17: tenements",
+    "level 18: the courtyard",
+    "level 19: the citadel",
+    "level 20: gotcha!",
+    "level 21: nirvana",
+    "level 22: the catacombs",
+    "level 23: barrels o' fun",
+    "level 24: the chasm",
+    "level 25: bloodfalls",
+    "level 26: the abandoned mines",
+    "level 27: monster condo",
+    "level 28: the spirit world",
+    "level 29: the living end",
+    "level 30: icon of sin",
+    "level 31: wolfenstein",
+    "level 32: grosse",
+];
+/// Plutonia, `PHUSTR_1` to `PHUSTR_32`
+pub const MAPNAMESP: [&str; 32] = [
+    "level 1: congo",
+    "level 2: well of souls",
+    "level 3: aztec",
+    "level 4: caged",
+    "level 5: ghost town",
+    "level 6: baron's lair",
+    "level 7: caughtyard",
+    "level 8: realm",
+    "level 9: abattoire",
+    "level 10: onslaught",
+    "level 11: hunted",
+    "level 12: speed",
+    "level 13: the crypt",
+    "level 14: genesis",
+    "level 15: the twilight",
+    "level 16: the omen",
+    "level 17: compound",
+    "level 18: neurosphere",
+    "level 19: nme",
+    "level 20: the death domain",
+    "level 21: slayer",
+    "level 22: impossible mission",
+    "level 23: tombstone",
+    "level 24: the final frontier",
+    "level 25: the temple of darkness",
+    "level 26: bunker",
+    "level 27: anti-christ",
+    "level 28: the sewers",
+    "level 29: odyssey of noises",
+    "level 30: the gateway of hell",
+    "level 31: cyberden",
+    "level 32: go 2 it",
+];
+/// TNT, `THUSTR_1` to `THUSTR_32`
+pub const MAPNAMEST: [&str; 32] = [
+    "level 1: system control",
+    "level 2: human bbq",
+    "level 3: power control",
+    "level 4: wormhole",
+    "level 5: hanger",
+    "level 6: open season",
+    "level 7: prison",
+    "level 8: metal",
+    "level 9: stronghold",
+    "level 10: redemption",
+    "level 11: storage facility",
+    "level 12: crater",
+    "level 13: nukage processing",
+    "level 14: steel works",
+    "level 15: dead zone",
+    "level 16: deepest reaches",
+    "level 17: processing area",
+    "level 18: mill",
+    "level 19: shipping/respawning",
+    "level 20: central processing",
+    "level 21: administration center",
+    "level 22: habitat",
+    "level 23: lunar mining project",
+    "level 24: quarry",
+    "level 25: baron's den",
+    "level 26: ballistyx",
+    "level 27: mount pain",
+    "level 28: heck",
+    "level 29: river styx",
+    "level 30: last call",
+    "level 31: pharaoh",
+    "level 32: caribbean",
+];
+
+// Netgame
+pub const NETNEWGAME: &str = "You can't start a new game while in a net game!";
+pub const NETLOAD: &str = "You can't load while in a net game!";
+pub const NETEND: &str = "You can't end a net game!";
+pub const NETLEFT: [&str; 4] = [
+    "Player 1 left the game",
+    "Player 2 left the game",
+    "Player 3 left the game",
+    "Player 4 left the game",
+];
+
+// Chat
+pub const HUSTR_CHATMACRO1: &str = "I'm ready to kick butt!";
+pub const HUSTR_CHATMACRO2: &str = "I'm OK.";
+pub const HUSTR_CHATMACRO3: &str = "I'm not looking too good!";
+pub const HUSTR_CHATMACRO4: &str = "Help!";
+pub const HUSTR_CHATMACRO5: &str = "You suck!";
+pub const HUSTR_CHATMACRO6: &str = "Next time, scumbag...";
+pub const HUSTR_CHATMACRO7: &str = "Come here!";
+pub const HUSTR_CHATMACRO8: &str = "I'll take care of it.";
+pub const HUSTR_CHATMACRO9: &str = "Yes";
+pub const HUSTR_CHATMACRO0: &str = "No";
+
+pub const HUSTR_TALKTOSELF1: &str = "You mumble to yourself";
+pub const HUSTR_TALKTOSELF2: &str = "Who's there?";
+pub const HUSTR_TALKTOSELF3: &str = "You scare yourself";
+pub const HUSTR_TALKTOSELF4: &str = "You start to rave";
+pub const HUSTR_TALKTOSELF5: &str = "You've lost it...";
+
+pub const HUSTR_MSGU: &str = "[Message unsent]";
+
+pub const HUSTR_PLRGREEN: &str = "Green: ";
+pub const HUSTR_PLRINDIGO: &str = "Indigo: ";
+pub const HUSTR_PLRBROWN: &str = "Brown: ";
+pub const HUSTR_PLRRED: &str = "Red: ";
+
+// Doom II cast call
+pub const CC_ZOMBIE: &str = "ZOMBIEMAN";
+pub const CC_SHOTGUN: &str = "SHOTGUN GUY";
+pub const CC_HEAVY: &str = "HEAVY WEAPON DUDE";
+pub const CC_IMP: &str = "IMP";
+pub const CC_DEMON: &str = "DEMON";
+pub const CC_LOST: &str = "LOST SOUL";
+pub const CC_CACO: &str = "CACODEMON";
+pub const CC_HELL: &str = "HELL KNIGHT";
+pub const CC_BARON: &str = "BARON OF HELL";
+pub const CC_ARACH: &str = "ARACHNOTRON";
+pub const CC_PAIN: &str = "PAIN ELEMENTAL";
+pub const CC_REVEN: &str = "REVENANT";
+pub const CC_MANCU: &str = "MANCUBUS";
+pub const CC_ARCH: &str = "ARCH-VILE";
+pub const CC_SPIDER: &str = "THE SPIDER MASTERMIND";
+pub const CC_CYBER: &str = "THE CYBERDEMON";
+pub const CC_HERO: &str = "OUR HERO";
+
 // Options menu
 pub const OPT_TITLE: &str = "OPTIONS";
 
@@ -83,6 +292,7 @@ pub const OPT_MUS_TYPE: &str = "MUSIC TYPE";
 pub const OPT_MUS_OPL2: &str = "OPL2";
 pub const OPT_MUS_OPL3: &str = "OPL3";
 pub const OPT_MUS_GUS: &str = "GUS";
+pub const OPT_PITCH_SHIFT: &str = "PITCH SHIFT";
 
 pub const OPT_VIDEO: &str = "VIDEO..";
 pub const OPT_MODE: &str = "MODE";
@@ -119,6 +329,41 @@ pub const OPT_DETAIL_LOW: &str = "LOW";
 pub const OPT_INPUT: &str = "INPUT SETUP..";
 pub const OPT_MOUSE_SENS: &str = "MOUSE SENSITIVITY";
 pub const OPT_INVERT_Y: &str = "INVERT Y AXIS";
+pub const OPT_CONTROLS: &str = "CONTROLS..";
+pub const OPT_BIND_RESET: &str = "RESET DEFAULTS";
+pub const OPT_BIND_PROMPT: &str = "PRESS A KEY...";
+pub const OPT_BIND_NONE: &str = "---";
+pub const OPT_BIND_HELP: &str = "ENTER TO BIND, BACKSPACE TO CLEAR";
+
+pub const CTL_FORWARD: &str = "MOVE FORWARD";
+pub const CTL_BACKWARD: &str = "MOVE BACKWARD";
+pub const CTL_TURN_LEFT: &str = "TURN LEFT";
+pub const CTL_TURN_RIGHT: &str = "TURN RIGHT";
+pub const CTL_STRAFE_LEFT: &str = "STRAFE LEFT";
+pub const CTL_STRAFE_RIGHT: &str = "STRAFE RIGHT";
+pub const CTL_STRAFE: &str = "STRAFE ON";
+pub const CTL_RUN: &str = "RUN";
+pub const CTL_RUN_TOGGLE: &str = "ALWAYS RUN";
+pub const CTL_FIRE: &str = "FIRE";
+pub const CTL_USE: &str = "USE";
+pub const CTL_LOOK_UP: &str = "LOOK UP";
+pub const CTL_LOOK_DOWN: &str = "LOOK DOWN";
+pub const CTL_WEAPON: [&str; 9] = [
+    "FIST/CHAINSAW",
+    "PISTOL",
+    "SHOTGUN",
+    "CHAINGUN",
+    "ROCKET LAUNCHER",
+    "PLASMA RIFLE",
+    "BFG 9000",
+    "CHAINSAW",
+    "SUPER SHOTGUN",
+];
+pub const CTL_NEXT_WEAPON: &str = "NEXT WEAPON";
+pub const CTL_PREV_WEAPON: &str = "PREV WEAPON";
+pub const CTL_AUTOMAP: &str = "AUTOMAP";
+pub const CTL_QUICKSAVE: &str = "QUICKSAVE";
+pub const CTL_QUICKLOAD: &str = "QUICKLOAD";
 
 pub const OPT_HUD: &str = "HUD SETUP..";
 pub const OPT_HUD_SIZE: &str = "SIZE";
@@ -1 +1,170 @@
 pub mod english;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static STRINGS: OnceLock<StringTable> = OnceLock::new();
+
+/// DEHACKED string replacements. `Text` blocks match on the original text,
+/// BEX `[STRINGS]` on the mnemonic.
+#[derive(Debug, Default)]
+pub(crate) struct StringTable {
+    by_text: HashMap<&'static str, &'static str>,
+    by_name: HashMap<String, &'static str>,
+}
+
+impl StringTable {
+    pub(crate) fn replace_text(&mut self, original: &str, replacement: &str) {
+        let original: &'static str = Box::leak(original.to_owned().into_boxed_str());
+        let replacement: &'static str = Box::leak(replacement.to_owned().into_boxed_str());
+        self.by_text.insert(original, replacement);
+    }
+
+    pub(crate) fn replace_named(&mut self, mnemonic: &str, replacement: &str) {
+        if let Some(original) = english_by_mnemonic(mnemonic) {
+            self.replace_text(original, replacement);
+        }
+        let replacement: &'static str = Box::leak(replacement.to_owned().into_boxed_str());
+        self.by_name
+            .insert(mnemonic.to_ascii_uppercase(), replacement);
+    }
+}
+
+/// Install DEHACKED string replacements. Returns false if strings were
+/// already in use.
+pub(crate) fn install_strings(table: StringTable) -> bool {
+    STRINGS.set(table).is_ok()
+}
+
+/// The DEHACKED replacement for `text`, or `text` itself.
+pub fn deh_string(text: &'static str) -> &'static str {
+    STRINGS
+        .get()
+        .and_then(|t| t.by_text.get(text))
+        .copied()
+        .unwrap_or(text)
+}
+
+/// The BEX `[STRINGS]` replacement for `mnemonic`, falling back to
+/// `deh_string(text)`. For strings that live outside `english`.
+pub fn deh_named(mnemonic: &str, text: &'static str) -> &'static str {
+    STRINGS
+        .get()
+        .and_then(|t| t.by_name.get(mnemonic))
+        .copied()
+        .unwrap_or_else(|| deh_string(text))
+}
+
+/// BEX mnemonic for the vanilla strings in `english`.
+fn english_by_mnemonic(mnemonic: &str) -> Option<&'static str> {
+    match mnemonic.to_ascii_uppercase().as_str() {
+        "GOTARMOR" => Some(english::GOTARMOR),
+        "GOTMEGA" => Some(english::GOTMEGA),
+        "GOTHTHBONUS" => Some(english::GOTHTHBONUS),
+        "GOTARMBONUS" => Some(english::GOTARMBONUS),
+        "GOTSTIM" => Some(english::GOTSTIM),
+        "GOTMEDINEED" => Some(english::GOTMEDINEED),
+        "GOTMEDIKIT" => Some(english::GOTMEDIKIT),
+        "GOTSUPER" => Some(english::GOTSUPER),
+        "GOTBLUECARD" => Some(english::GOTBLUECARD),
+        "GOTYELWCARD" => Some(english::GOTYELWCARD),
+        "GOTREDCARD" => Some(english::GOTREDCARD),
+        "GOTBLUESKUL" => Some(english::GOTBLUESKUL),
+        "GOTYELWSKUL" => Some(english::GOTYELWSKUL),
+        "GOTREDSKULL" => Some(english::GOTREDSKULL),
+        "GOTINVUL" => Some(english::GOTINVUL),
+        "GOTBERSERK" => Some(english::GOTBERSERK),
+        "GOTINVIS" => Some(english::GOTINVIS),
+        "GOTSUIT" => Some(english::GOTSUIT),
+        "GOTMAP" => Some(english::GOTMAP),
+        "GOTVISOR" => Some(english::GOTVISOR),
+        "GOTMSPHERE" => Some(english::GOTMSPHERE),
+        "GOTCLIP" => Some(english::GOTCLIP),
+        "GOTCLIPBOX" => Some(english::GOTCLIPBOX),
+        "GOTROCKET" => Some(english::GOTROCKET),
+        "GOTROCKBOX" => Some(english::GOTROCKBOX),
+        "GOTCELL" => Some(english::GOTCELL),
+        "GOTCELLBOX" => Some(english::GOTCELLBOX),
+        "GOTSHELLS" => Some(english::GOTSHELLS),
+        "GOTSHELLBOX" => Some(english::GOTSHELLBOX),
+        "GOTBACKPACK" => Some(english::GOTBACKPACK),
+        "GOTBFG9000" => Some(english::GOTBFG9000),
+        "GOTCHAINGUN" => Some(english::GOTCHAINGUN),
+        "GOTCHAINSAW" => Some(english::GOTCHAINSAW),
+        "GOTLAUNCHER" => Some(english::GOTLAUNCHER),
+        "GOTPLASMA" => Some(english::GOTPLASMA),
+        "GOTSHOTGUN" => Some(english::GOTSHOTGUN),
+        "GOTSHOTGUN2" => Some(english::GOTSHOTGUN2),
+        "PD_BLUEO" => Some(english::PD_BLUEO),
+        "PD_REDO" => Some(english::PD_REDO),
+        "PD_YELLOWO" => Some(english::PD_YELLOWO),
+        "PD_BLUEK" => Some(english::PD_BLUEK),
+        "PD_REDK" => Some(english::PD_REDK),
+        "PD_YELLOWK" => Some(english::PD_YELLOWK),
+        "PD_BLUEC" => Some(english::PD_BLUEC),
+        "PD_REDC" => Some(english::PD_REDC),
+        "PD_YELLOWC" => Some(english::PD_YELLOWC),
+        "PD_BLUES" => Some(english::PD_BLUES),
+        "PD_REDS" => Some(english::PD_REDS),
+        "PD_YELLOWS" => Some(english::PD_YELLOWS),
+        "PD_ANY" => Some(english::PD_ANY),
+        "PD_ALL3" => Some(english::PD_ALL3),
+        "PD_ALL6" => Some(english::PD_ALL6),
+        "STSTR_MUS" => Some(english::STSTR_MUS),
+        "STSTR_NOMUS" => Some(english::STSTR_NOMUS),
+        "STSTR_DQDON" => Some(english::STSTR_DQDON),
+        "STSTR_DQDOFF" => Some(english::STSTR_DQDOFF),
+        "STSTR_KFAADDED" => Some(english::STSTR_KFAADDED),
+        "STSTR_FAADDED" => Some(english::STSTR_FAADDED),
+        "STSTR_NCON" => Some(english::STSTR_NCON),
+        "STSTR_NCOFF" => Some(english::STSTR_NCOFF),
+        "STSTR_BEHOLD" => Some(english::STSTR_BEHOLD),
+        "STSTR_BEHOLDX" => Some(english::STSTR_BEHOLDX),
+        "STSTR_CHOPPERS" => Some(english::STSTR_CHOPPERS),
+        "STSTR_CLEV" => Some(english::STSTR_CLEV),
+        "AMSTR_FOLLOWON" => Some(english::AMSTR_FOLLOWON),
+        "AMSTR_FOLLOWOFF" => Some(english::AMSTR_FOLLOWOFF),
+        "AMSTR_GRIDON" => Some(english::AMSTR_GRIDON),
+        "AMSTR_GRIDOFF" => Some(english::AMSTR_GRIDOFF),
+        "AMSTR_MARKEDSPOT" => Some(english::AMSTR_MARKEDSPOT),
+        "AMSTR_MARKSCLEARED" => Some(english::AMSTR_MARKSCLEARED),
+        "HUSTR_CHATMACRO1" => Some(english::HUSTR_CHATMACRO1),
+        "HUSTR_CHATMACRO2" => Some(english::HUSTR_CHATMACRO2),
+        "HUSTR_CHATMACRO3" => Some(english::HUSTR_CHATMACRO3),
+        "HUSTR_CHATMACRO4" => Some(english::HUSTR_CHATMACRO4),
+        "HUSTR_CHATMACRO5" => Some(english::HUSTR_CHATMACRO5),
+        "HUSTR_CHATMACRO6" => Some(english::HUSTR_CHATMACRO6),
+        "HUSTR_CHATMACRO7" => Some(english::HUSTR_CHATMACRO7),
+        "HUSTR_CHATMACRO8" => Some(english::HUSTR_CHATMACRO8),
+        "HUSTR_CHATMACRO9" => Some(english::HUSTR_CHATMACRO9),
+        "HUSTR_CHATMACRO0" => Some(english::HUSTR_CHATMACRO0),
+        "HUSTR_TALKTOSELF1" => Some(english::HUSTR_TALKTOSELF1),
+        "HUSTR_TALKTOSELF2" => Some(english::HUSTR_TALKTOSELF2),
+        "HUSTR_TALKTOSELF3" => Some(english::HUSTR_TALKTOSELF3),
+        "HUSTR_TALKTOSELF4" => Some(english::HUSTR_TALKTOSELF4),
+        "HUSTR_TALKTOSELF5" => Some(english::HUSTR_TALKTOSELF5),
+        "HUSTR_MSGU" => Some(english::HUSTR_MSGU),
+        "HUSTR_PLRGREEN" => Some(english::HUSTR_PLRGREEN),
+        "HUSTR_PLRINDIGO" => Some(english::HUSTR_PLRINDIGO),
+        "HUSTR_PLRBROWN" => Some(english::HUSTR_PLRBROWN),
+        "HUSTR_PLRRED" => Some(english::HUSTR_PLRRED),
+        "CC_ZOMBIE" => Some(english::CC_ZOMBIE),
+        "CC_SHOTGUN" => Some(english::CC_SHOTGUN),
+        "CC_HEAVY" => Some(english::CC_HEAVY),
+        "CC_IMP" => Some(english::CC_IMP),
+        "CC_DEMON" => Some(english::CC_DEMON),
+        "CC_LOST" => Some(english::CC_LOST),
+        "CC_CACO" => Some(english::CC_CACO),
+        "CC_HELL" => Some(english::CC_HELL),
+        "CC_BARON" => Some(english::CC_BARON),
+        "CC_ARACH" => Some(english::CC_ARACH),
+        "CC_PAIN" => Some(english::CC_PAIN),
+        "CC_REVEN" => Some(english::CC_REVEN),
+        "CC_MANCU" => Some(english::CC_MANCU),
+        "CC_ARCH" => Some(english::CC_ARCH),
+        "CC_SPIDER" => Some(english::CC_SPIDER),
+        "CC_CYBER" => Some(english::CC_CYBER),
+        "CC_HERO" => Some(english::CC_HERO),
+        _ => None,
+    }
+}
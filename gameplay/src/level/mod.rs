@@ -9,7 +9,8 @@ pub mod tests;
 use std::collections::VecDeque;
 use std::ptr;
 
-use level::{LevelData, LineDef, MapPtr};
+use level::LevelData;
+use math::m_random;
 use sound_common::{SfxName, SndServerTx, SoundAction};
 use wad::WadData;
 use wad::types::WadThing;
@@ -18,11 +19,11 @@ use game_config::{GameMode, GameOptions};
 
 use pic_data::{PicAnimation, PicData, Switches};
 
-use crate::Player;
-use crate::doom_def::{GameAction, MAX_DEATHMATCH_STARTS, MAX_RESPAWNS, MAXPLAYERS};
+use crate::doom_def::{BODYQUESIZE, GameAction, MAX_DEATHMATCH_STARTS, MAX_RESPAWNS, MAXPLAYERS};
 use crate::env::platforms::{PlatStatus, Platform};
 use crate::pic::Button;
 use crate::thinker::ThinkerAlloc;
+use crate::{MapObject, Player};
 
 /// The world the player is in; exists only while the player is in it.
 ///
@@ -48,6 +49,8 @@ pub struct LevelState {
     /// The `Things` for deathmatch start locations
     pub(super) deathmatch_starts: [Option<WadThing>; MAX_DEATHMATCH_STARTS],
     pub(super) deathmatch_p: Vec<WadThing>,
+    /// Corpses left by netgame respawns, oldest first
+    pub(super) body_queue: VecDeque<*mut MapObject>,
 
     /// for intermission
     pub total_level_kills: i32,
@@ -71,8 +74,6 @@ pub struct LevelState {
     pub(crate) texture_heights: Vec<i32>,
     /// List of used buttons. Typically these buttons or switches are timed.
     pub(crate) button_list: Vec<Button>,
-    /// Scrolling walls
-    pub(super) line_special_list: Vec<MapPtr<LineDef>>,
 
     /// Tracks which players are currently active, set by d_net.c loop.
     /// This is a raw pointer to the array in `Game`, and must not be modified
@@ -93,7 +94,7 @@ pub struct LevelState {
     pub(crate) active_platforms: Vec<*mut Platform>,
     pub(crate) sky_num: usize,
     /// OG Doom blocklinks: per-block linked list heads for thing iteration
-    pub(crate) blocklinks: Vec<Option<*mut crate::thing::MapObject>>,
+    pub(crate) blocklinks: Vec<Option<*mut MapObject>>,
     /// UMAPINFO boss death actions for this map (None = use defaults)
     pub boss_actions: Option<wad::umapinfo::BossActions>,
 }
@@ -132,6 +133,7 @@ impl LevelState {
             player_starts: [None; MAXPLAYERS],
             deathmatch_starts: [None; MAX_DEATHMATCH_STARTS],
             deathmatch_p: Vec::with_capacity(MAX_DEATHMATCH_STARTS),
+            body_queue: VecDeque::with_capacity(BODYQUESIZE),
             total_level_kills: 0,
             total_level_items: 0,
             total_level_secrets: 0,
@@ -142,7 +144,6 @@ impl LevelState {
             texture_heights: Default::default(),
             animations: Default::default(),
             button_list: Vec::with_capacity(50),
-            line_special_list: Vec::with_capacity(50),
             game_mode,
             snd_command,
             players_in_game,
@@ -203,6 +204,14 @@ impl LevelState {
         unsafe { &*self.players_in_game }
     }
 
+    /// Index of `player` in the game's player array.
+    pub(super) fn player_num(&self, player: *const Player) -> usize {
+        self.players()
+            .iter()
+            .position(|p| ptr::eq(p, player))
+            .unwrap_or_default()
+    }
+
     pub(super) fn players(&self) -> &[Player; MAXPLAYERS] {
         unsafe { &*self.players }
     }
@@ -273,6 +282,7 @@ impl LevelState {
             sfx,
             x,
             y,
+            pitch: sfx.random_pitch(m_random),
         }) {
             log::warn!("Could not send sfx, sound thread gone: {e}");
         }
@@ -11,6 +11,7 @@
 
 use std::f32::consts::TAU;
 
+mod dehacked;
 pub mod demo_trace;
 pub mod dirs;
 mod doom_def;
@@ -25,23 +26,27 @@ mod player;
 mod player_sprite;
 pub mod save;
 pub(crate) mod sector_ext;
+mod tables;
 #[cfg(test)]
 mod test_support;
 mod thing;
 mod thinker;
 
-pub use doom_def::{
-    AmmoType, Card, DOOM_VERSION, GameAction, MAXPLAYERS, PowerType, TICRATE, WEAPON_INFO,
-};
+pub use dehacked::apply_dehacked;
+pub use doom_def::{AmmoType, Card, DOOM_VERSION, GameAction, MAXPLAYERS, PowerType, TICRATE};
 pub use env::specials::{respawn_specials, spawn_specials, update_specials};
 pub use env::teleport::teleport_move;
-pub use info::{MapObjKind, SPRNAMES, STATES, StateNum};
-pub use lang::english;
+pub use info::{MapObjKind, StateNum};
+pub use lang::{deh_named, deh_string, english};
 pub use level::LevelState;
 pub use pic::{Button, ButtonWhere};
 pub use player::{Player, PlayerCheat, PlayerState, PlayerStatus, WorldEndPlayerInfo};
 pub use player_sprite::PspDef;
 pub use sector_ext::SectorExt;
+pub use tables::{
+    CheatCodes, MiscInfo, cheat_codes, clip_ammo, max_ammo, misc_info, mobj_info, par_time,
+    sprite_names, states, weapon_info,
+};
 pub use thing::{MapObjFlag, MapObject};
 
 /// Normalise an angle in radians into `[0, TAU)`, handling any magnitude.
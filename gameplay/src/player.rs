@@ -7,16 +7,16 @@ use log::{debug, error, info};
 use sound_common::SfxName;
 
 use crate::doom_def::{
-    AmmoType, BFGCELLS, CLIP_AMMO, Card, MAX_AMMO, MAXHEALTH, MAXPLAYERS, PowerDuration, PowerType,
-    VIEWHEIGHT, WEAPON_INFO,
+    AmmoType, Card, MAXHEALTH, MAXPLAYERS, PowerDuration, PowerType, VIEWHEIGHT, WeaponFlag,
 };
-use crate::info::{STATES, StateNum};
+use crate::info::StateNum;
 use crate::level::LevelState;
 use crate::player_sprite::{PspDef, WEAPONBOTTOM};
+use crate::tables::{clip_ammo, max_ammo, mbf21, misc_info, states, weapon_info};
 use crate::thing::enemy::noise_alert;
 use crate::thing::{BONUSADD, MapObjFlag, MapObject};
 use game_config::tic_cmd::{TIC_CMD_BUTTONS, TicCmd};
-use game_config::{GameMode, Skill, WeaponType};
+use game_config::{GameMode, GameOptions, Skill, WeaponType};
 use math::{Angle, fixed_to_float, p_random, r_point_to_angle};
 use pic_data::INVERSECOLORMAP;
 
@@ -135,7 +135,7 @@ impl Default for PlayerStatus {
             attackdown: false,
             usedown: false,
             readyweapon: WeaponType::Pistol,
-            health: MAXHEALTH,
+            health: misc_info().initial_health,
             armorpoints: 0,
             armortype: 0,
             cards: Default::default(),
@@ -151,8 +151,8 @@ impl Default for PlayerStatus {
             attacked_angle_count: 0,
             cheats: PlayerCheat::empty(),
         };
-        tmp.ammo[AmmoType::Clip as usize] = 50;
-        tmp.maxammo.copy_from_slice(&MAX_AMMO);
+        tmp.ammo[AmmoType::Clip as usize] = misc_info().initial_bullets;
+        tmp.maxammo.copy_from_slice(&max_ammo());
         tmp.weaponowned[WeaponType::Fist as usize] = true;
         tmp.weaponowned[WeaponType::Pistol as usize] = true;
         tmp
@@ -268,13 +268,13 @@ impl Player {
 
             psprites: [
                 PspDef {
-                    state: Some(&STATES[StateNum::PISTOLUP as usize]),
+                    state: Some(&states()[StateNum::PISTOLUP as usize]),
                     tics: 1,
                     sx: 0.0,
                     sy: WEAPONBOTTOM,
                 },
                 PspDef {
-                    state: Some(&STATES[StateNum::PISTOLFLASH as usize]),
+                    state: Some(&states()[StateNum::PISTOLFLASH as usize]),
                     tics: 1,
                     sx: 0.0,
                     sy: WEAPONBOTTOM,
@@ -299,6 +299,10 @@ impl Player {
         self.mobj = Some(mobj);
     }
 
+    pub(crate) const fn clear_mobj(&mut self) {
+        self.mobj = None;
+    }
+
     /// Unchecked access to the raw `MapObject` pointer cast to ref
     ///
     /// # Safety
@@ -353,11 +357,13 @@ impl Player {
         let kill_count = self.total_kills;
         let item_count = self.items_collected;
         let secret_count = self.secrets_found;
+        let frags = self.frags;
 
         *self = Self::default();
         self.total_kills = kill_count;
         self.items_collected = item_count;
         self.secrets_found = secret_count;
+        self.frags = frags;
 
         self.status = PlayerStatus::default();
         self.status.attackdown = true;
@@ -461,15 +467,17 @@ impl Player {
             }
 
             self.onground = mobj.z <= mobj.floorz;
+            // BOOM: ice and mud change how much grip the player has
+            let movefactor = mobj.move_factor();
 
             if self.cmd.forwardmove != 0 && self.onground {
                 let angle = mobj.angle;
-                self.thrust(angle, self.cmd.forwardmove as i32 * 2048);
+                self.thrust(angle, self.cmd.forwardmove as i32 * movefactor);
             }
 
             if self.cmd.sidemove != 0 && self.onground {
                 let bam = mobj.angle.to_bam().wrapping_sub(ANG90);
-                self.thrust(Angle::from_bam(bam), self.cmd.sidemove as i32 * 2048);
+                self.thrust(Angle::from_bam(bam), self.cmd.sidemove as i32 * movefactor);
             }
 
             if (self.cmd.forwardmove != 0 || self.cmd.sidemove != 0)
@@ -513,7 +521,7 @@ impl Player {
                 break;
             }
 
-            let state = &STATES[state_num as usize];
+            let state = &states()[state_num as usize];
             self.psprites[position].state = Some(state);
             self.psprites[position].tics = state.tics;
 
@@ -643,9 +651,9 @@ impl Player {
         }
 
         if num != 0 {
-            num *= CLIP_AMMO[ammo as usize];
+            num *= clip_ammo()[ammo as usize];
         } else {
-            num = CLIP_AMMO[ammo as usize] / 2;
+            num = clip_ammo()[ammo as usize] / 2;
         }
 
         if skill == Skill::Baby || skill == Skill::Nightmare {
@@ -659,6 +667,11 @@ impl Player {
             self.status.ammo[ammo as usize] = self.status.maxammo[ammo as usize];
         }
 
+        if mbf21() {
+            self.give_ammo_auto_switch(ammo, old_ammo);
+            return true;
+        }
+
         // If non zero ammo, don't change up weapons, player was lower on purpose.
         if old_ammo != 0 {
             return true;
@@ -697,16 +710,62 @@ impl Player {
         true
     }
 
-    pub(crate) fn give_weapon(&mut self, weapon: WeaponType, dropped: bool, skill: Skill) -> bool {
+    /// MBF21 ammo pickup switching: leave an `Autoswitchfrom` weapon for the
+    /// best owned weapon that the pickup made usable.
+    fn give_ammo_auto_switch(&mut self, ammo: AmmoType, old_ammo: u32) {
+        let ready = &weapon_info()[self.status.readyweapon as usize];
+        if ready.ammo == ammo || !ready.flags.contains(WeaponFlag::Autoswitchfrom) {
+            return;
+        }
+
+        let ammo_held = self.status.ammo[ammo as usize];
+        let best = (self.status.readyweapon as usize + 1..WeaponType::NumWeapons as usize)
+            .rev()
+            .find(|&i| {
+                let info = &weapon_info()[i];
+                self.status.weaponowned[i]
+                    && !info.flags.contains(WeaponFlag::Noautoswitchto)
+                    && info.ammo == ammo
+                    && info.ammo_per_shot > old_ammo
+                    && info.ammo_per_shot <= ammo_held
+            });
+        if let Some(weapon) = best.and_then(|i| WeaponType::try_from(i as u8).ok()) {
+            self.pendingweapon = weapon;
+        }
+    }
+
+    /// Returns false if the weapon should stay where it is, which in a
+    /// netgame (other than altdeath) is always the case for placed weapons.
+    ///
+    /// Doom function name `P_GiveWeapon`
+    pub(crate) fn give_weapon(
+        &mut self,
+        weapon: WeaponType,
+        dropped: bool,
+        options: &GameOptions,
+    ) -> bool {
+        let ammo = weapon_info()[weapon as usize].ammo;
+        if options.netgame && options.deathmatch != 2 && !dropped {
+            // Leave placed weapons forever on net games
+            if self.status.weaponowned[weapon as usize] {
+                return false;
+            }
+            self.status.bonuscount += BONUSADD;
+            self.status.weaponowned[weapon as usize] = true;
+            let clips = if options.deathmatch != 0 { 5 } else { 2 };
+            self.give_ammo(ammo, clips, options.skill);
+            self.pendingweapon = weapon;
+            self.start_sound(SfxName::Wpnup);
+            return false;
+        }
+
         let mut gave_ammo = false;
         let mut gave_weapon = false;
-        // TODO: if (netgame && (deathmatch != 2) && !dropped) {
-        let ammo = WEAPON_INFO[weapon as usize].ammo;
         if ammo != AmmoType::NoAmmo {
             if dropped {
-                gave_ammo = self.give_ammo(ammo, 1, skill);
+                gave_ammo = self.give_ammo(ammo, 1, options.skill);
             } else {
-                gave_ammo = self.give_ammo(ammo, 2, skill);
+                gave_ammo = self.give_ammo(ammo, 2, options.skill);
             }
         }
 
@@ -773,7 +832,10 @@ impl Player {
             PowerType::Infrared => {
                 self.status.powers[power as usize] = PowerDuration::Infrared as i32;
             }
-            PowerType::NumPowers | PowerType::Allmap => {}
+            PowerType::Allmap => {
+                self.status.powers[power as usize] = 1;
+            }
+            PowerType::NumPowers => {}
         }
         true
     }
@@ -788,18 +850,22 @@ impl Player {
             return;
         }
 
-        let new_state = WEAPON_INFO[self.status.readyweapon as usize].atkstate;
-        self.set_psprite(PsprNum::Weapon as usize, new_state);
-        if let Some(mobj) = self.mobj_mut() {
+        let info = &weapon_info()[self.status.readyweapon as usize];
+        self.set_psprite(PsprNum::Weapon as usize, info.atkstate);
+        if !info.flags.contains(WeaponFlag::Silent)
+            && let Some(mobj) = self.mobj_mut()
+        {
             noise_alert(mobj);
         }
     }
 
     pub(crate) fn check_ammo(&mut self) -> bool {
-        let ammo = &WEAPON_INFO[self.status.readyweapon as usize].ammo;
+        let ammo = &weapon_info()[self.status.readyweapon as usize].ammo;
         // Minimum for one shot varies with weapon
-        let count = if self.status.readyweapon == WeaponType::BFG {
-            BFGCELLS
+        let count = if mbf21() {
+            weapon_info()[self.status.readyweapon as usize].ammo_per_shot
+        } else if self.status.readyweapon == WeaponType::BFG {
+            weapon_info()[WeaponType::BFG as usize].ammo_per_shot
         } else if self.status.readyweapon == WeaponType::SuperShotgun {
             2
         } else {
@@ -840,7 +906,8 @@ impl Player {
             {
                 self.pendingweapon = WeaponType::Missile;
             } else if self.status.weaponowned[WeaponType::BFG as usize]
-                && self.status.ammo[AmmoType::Cell as usize] >= 40
+                && self.status.ammo[AmmoType::Cell as usize]
+                    >= weapon_info()[WeaponType::BFG as usize].ammo_per_shot
             // TODO: && (gamemode != shareware)
             {
                 self.pendingweapon = WeaponType::BFG;
@@ -855,7 +922,7 @@ impl Player {
 
         self.set_psprite(
             PsprNum::Weapon as usize,
-            WEAPON_INFO[self.status.readyweapon as usize].downstate,
+            weapon_info()[self.status.readyweapon as usize].downstate,
         );
 
         false
@@ -870,7 +937,7 @@ impl Player {
             // TODO: StartSound(player->mo, sfx_sawup);
         }
 
-        let new_state = WEAPON_INFO[self.pendingweapon as usize].upstate;
+        let new_state = weapon_info()[self.pendingweapon as usize].upstate;
         self.pendingweapon = WeaponType::NoChange;
         self.psprites[PsprNum::Weapon as usize].sy = WEAPONBOTTOM;
 
@@ -884,8 +951,19 @@ impl Player {
         }
     }
 
-    pub(crate) fn subtract_readyweapon_ammo(&mut self, num: u32) {
-        let idx = WEAPON_INFO[self.status.readyweapon as usize].ammo as usize;
+    /// Take a shot's worth of ammo: `vanilla_amount`, or the weapon's
+    /// `ammo_per_shot` under MBF21.
+    pub(crate) fn subtract_readyweapon_ammo(&mut self, vanilla_amount: u32) {
+        let info = &weapon_info()[self.status.readyweapon as usize];
+        if info.ammo == AmmoType::NoAmmo {
+            return;
+        }
+        let num = if mbf21() {
+            info.ammo_per_shot
+        } else {
+            vanilla_amount
+        };
+        let idx = info.ammo as usize;
         // Cap at 0: never underflow if the shot costs more ammo than is held.
         self.status.ammo[idx] = self.status.ammo[idx].saturating_sub(num);
     }
@@ -894,11 +972,67 @@ impl Player {
     pub(crate) fn drop_weapon(&mut self) {
         self.set_psprite(
             PsprNum::Weapon as usize,
-            WEAPON_INFO[self.status.readyweapon as usize].downstate,
+            weapon_info()[self.status.readyweapon as usize].downstate,
         );
     }
 }
 
+/// Next/previous weapon order, with the weapon number to request for each.
+/// The chainsaw and super shotgun are reached through the fist and shotgun
+/// numbers, as `think` converts those (G_NextWeapon in Chocolate Doom).
+const WEAPON_ORDER: [(WeaponType, WeaponType); 9] = [
+    (WeaponType::Fist, WeaponType::Fist),
+    (WeaponType::Chainsaw, WeaponType::Fist),
+    (WeaponType::Pistol, WeaponType::Pistol),
+    (WeaponType::Shotgun, WeaponType::Shotgun),
+    (WeaponType::SuperShotgun, WeaponType::Shotgun),
+    (WeaponType::Chaingun, WeaponType::Chaingun),
+    (WeaponType::Missile, WeaponType::Missile),
+    (WeaponType::Plasma, WeaponType::Plasma),
+    (WeaponType::BFG, WeaponType::BFG),
+];
+
+impl Player {
+    /// The weapon number to put in a `TicCmd` to step `direction` (1 or -1)
+    /// through the weapons the player can select, from the pending weapon if
+    /// one is on the way up.
+    pub fn cycle_weapon(&self, direction: i32, mode: GameMode) -> u8 {
+        let current = if self.pendingweapon == WeaponType::NoChange {
+            self.status.readyweapon
+        } else {
+            self.pendingweapon
+        };
+        let selectable = |w: WeaponType| {
+            let owned = &self.status.weaponowned;
+            match w {
+                WeaponType::SuperShotgun if mode != GameMode::Commercial => false,
+                WeaponType::Plasma | WeaponType::BFG if mode == GameMode::Shareware => false,
+                WeaponType::Fist
+                    if owned[WeaponType::Chainsaw as usize]
+                        && self.status.powers[PowerType::Strength as usize] == 0 =>
+                {
+                    false
+                }
+                _ => owned[w as usize],
+            }
+        };
+
+        let len = WEAPON_ORDER.len() as i32;
+        let start = WEAPON_ORDER
+            .iter()
+            .position(|(w, _)| *w == current)
+            .unwrap_or(0) as i32;
+        let mut i = start;
+        loop {
+            i = (i + direction).rem_euclid(len);
+            if i == start || selectable(WEAPON_ORDER[i as usize].0) {
+                break;
+            }
+        }
+        WEAPON_ORDER[i as usize].1 as u8
+    }
+}
+
 /// P_PlayerThink
 /// The Doom source has the thinker in a specific location in the object structs
 /// which enables a cast to t_thinker. We can't do that in rust so need to use
@@ -1117,3 +1251,63 @@ impl Player {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owning(weapons: &[WeaponType]) -> Player {
+        let mut p = Player::new();
+        p.status.weaponowned = [false; WeaponType::NumWeapons as usize];
+        for w in weapons {
+            p.status.weaponowned[*w as usize] = true;
+        }
+        p.status.readyweapon = WeaponType::Pistol;
+        p.pendingweapon = WeaponType::NoChange;
+        p
+    }
+
+    #[test]
+    fn cycle_weapon_skips_unowned_and_wraps() {
+        let p = owning(&[WeaponType::Fist, WeaponType::Pistol, WeaponType::Missile]);
+        assert_eq!(
+            p.cycle_weapon(1, GameMode::Retail),
+            WeaponType::Missile as u8
+        );
+        assert_eq!(p.cycle_weapon(-1, GameMode::Retail), WeaponType::Fist as u8);
+
+        let mut p = p;
+        p.pendingweapon = WeaponType::Missile;
+        assert_eq!(p.cycle_weapon(1, GameMode::Retail), WeaponType::Fist as u8);
+    }
+
+    #[test]
+    fn cycle_weapon_reaches_chainsaw_and_super_shotgun() {
+        let all = [
+            WeaponType::Fist,
+            WeaponType::Chainsaw,
+            WeaponType::Pistol,
+            WeaponType::Shotgun,
+            WeaponType::SuperShotgun,
+        ];
+        let mut p = owning(&all);
+        // Without berserk the fist is passed over for the chainsaw.
+        assert_eq!(
+            p.cycle_weapon(-1, GameMode::Commercial),
+            WeaponType::Fist as u8
+        );
+        p.status.readyweapon = WeaponType::Chainsaw;
+        assert_eq!(
+            p.cycle_weapon(-1, GameMode::Commercial),
+            WeaponType::Shotgun as u8
+        );
+
+        p.status.readyweapon = WeaponType::Shotgun;
+        assert_eq!(
+            p.cycle_weapon(1, GameMode::Commercial),
+            WeaponType::Shotgun as u8
+        );
+        // No super shotgun outside Doom II, so on to the chainsaw.
+        assert_eq!(p.cycle_weapon(1, GameMode::Retail), WeaponType::Fist as u8);
+    }
+}
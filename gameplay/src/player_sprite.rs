@@ -2,10 +2,16 @@
 
 use sound_common::SfxName;
 
-use crate::doom_def::{MELEERANGE, MISSILERANGE, PowerType, WEAPON_INFO};
-use crate::info::{STATES, StateData, StateNum};
+use crate::doom_def::{AmmoType, MELEERANGE, MISSILERANGE, PowerType, WeaponFlag};
+use crate::info::{StateData, StateNum};
 use crate::player::{Player, PsprNum};
+use crate::tables::{states, weapon_info};
 use crate::thing::MapObject;
+use crate::thing::enemy::noise_alert;
+use crate::thing::mbf21::{
+    adjust_missile, arg_kind, arg_sound, arg_state, random_damage, random_hitscan_angle,
+    random_hitscan_slope,
+};
 use crate::{MapObjKind, PlayerState};
 use game_config::WeaponType;
 use game_config::tic_cmd::TIC_CMD_BUTTONS;
@@ -46,8 +52,8 @@ pub(crate) fn a_weaponready(player: &mut Player, pspr: &mut PspDef) {
     let mut level_time = 0;
     let readyweapon = player.status.readyweapon;
     if let Some(mobj) = player.mobj_mut() {
-        if std::ptr::eq(mobj.state, &STATES[StateNum::PLAY_ATK1 as usize])
-            || std::ptr::eq(mobj.state, &STATES[StateNum::PLAY_ATK2 as usize])
+        if std::ptr::eq(mobj.state, &states()[StateNum::PLAY_ATK1 as usize])
+            || std::ptr::eq(mobj.state, &states()[StateNum::PLAY_ATK2 as usize])
         {
             mobj.set_state(StateNum::PLAY);
         }
@@ -55,7 +61,7 @@ pub(crate) fn a_weaponready(player: &mut Player, pspr: &mut PspDef) {
         level_time = unsafe { (*mobj.level).level_time };
 
         if let Some(state) = pspr.state {
-            let check = &STATES[StateNum::SAW as usize];
+            let check = &states()[StateNum::SAW as usize];
             if readyweapon == WeaponType::Chainsaw
                 && state.sprite == check.sprite
                 && state.frame == check.frame
@@ -72,7 +78,7 @@ pub(crate) fn a_weaponready(player: &mut Player, pspr: &mut PspDef) {
         // change weapon
         //  (pending weapon should allready be validated)
         if player.status.readyweapon != WeaponType::NoChange {
-            let new_state = WEAPON_INFO[player.status.readyweapon as usize].downstate;
+            let new_state = weapon_info()[player.status.readyweapon as usize].downstate;
             player.set_psprite(PsprNum::Weapon as usize, new_state);
         }
         return;
@@ -81,8 +87,9 @@ pub(crate) fn a_weaponready(player: &mut Player, pspr: &mut PspDef) {
     //  the missile launcher and bfg do not auto fire
     if player.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0 {
         if !player.status.attackdown
-            || (player.status.readyweapon != WeaponType::Missile
-                && player.status.readyweapon != WeaponType::BFG)
+            || !weapon_info()[player.status.readyweapon as usize]
+                .flags
+                .contains(WeaponFlag::Noautofire)
         {
             player.status.attackdown = true;
             player.fire_weapon();
@@ -135,7 +142,7 @@ pub(crate) fn a_raise(player: &mut Player, pspr: &mut PspDef) {
     }
     pspr.sy = WEAPONTOP;
 
-    let new_state = WEAPON_INFO[player.status.readyweapon as usize].readystate;
+    let new_state = weapon_info()[player.status.readyweapon as usize].readystate;
     player.set_psprite(PsprNum::Weapon as usize, new_state);
 }
 
@@ -156,7 +163,7 @@ pub(crate) fn a_firepistol(player: &mut Player, _pspr: &mut PspDef) {
     player.subtract_readyweapon_ammo(1);
     player.set_psprite(
         PsprNum::Flash as usize,
-        WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+        weapon_info()[player.status.readyweapon as usize].flashstate,
     );
 }
 
@@ -177,7 +184,7 @@ pub(crate) fn a_fireshotgun(player: &mut Player, _pspr: &mut PspDef) {
     player.subtract_readyweapon_ammo(1);
     player.set_psprite(
         PsprNum::Flash as usize,
-        WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+        weapon_info()[player.status.readyweapon as usize].flashstate,
     );
 }
 
@@ -208,7 +215,7 @@ pub(crate) fn a_fireshotgun2(player: &mut Player, _pspr: &mut PspDef) {
     player.subtract_readyweapon_ammo(2);
     player.set_psprite(
         PsprNum::Flash as usize,
-        WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+        weapon_info()[player.status.readyweapon as usize].flashstate,
     );
 }
 
@@ -218,7 +225,7 @@ pub(crate) fn a_firecgun(player: &mut Player, pspr: &mut PspDef) {
     }
     shoot_bullet(player);
     let state = StateNum::from(
-        WEAPON_INFO[player.status.readyweapon as usize].flashstate as u16
+        weapon_info()[player.status.readyweapon as usize].flashstate as u16
             + pspr.state.unwrap().next_state as u16
             - StateNum::CHAIN1 as u16
             - 1,
@@ -231,7 +238,8 @@ pub(crate) fn a_firecgun(player: &mut Player, pspr: &mut PspDef) {
 pub(crate) fn a_fireplasma(player: &mut Player, _pspr: &mut PspDef) {
     player.subtract_readyweapon_ammo(1);
     let state = StateNum::from(
-        (WEAPON_INFO[player.status.readyweapon as usize].flashstate as u16 + p_random() as u16) & 1,
+        (weapon_info()[player.status.readyweapon as usize].flashstate as u16 + p_random() as u16)
+            & 1,
     );
     player.set_psprite(PsprNum::Flash as usize, state);
     if let Some(mobj) = player.mobj_raw() {
@@ -250,7 +258,7 @@ pub(crate) fn a_firemissile(player: &mut Player, _pspr: &mut PspDef) {
     player.subtract_readyweapon_ammo(1);
     // player.set_psprite(
     //     PsprNum::Flash as usize,
-    //     WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+    //     weapon_info()[player.status.readyweapon as usize].flashstate,
     // );
     if let Some(mobj) = player.mobj_raw() {
         unsafe {
@@ -265,10 +273,10 @@ pub(crate) fn a_firemissile(player: &mut Player, _pspr: &mut PspDef) {
 }
 
 pub(crate) fn a_firebfg(player: &mut Player, _pspr: &mut PspDef) {
-    player.subtract_readyweapon_ammo(1);
+    player.subtract_readyweapon_ammo(weapon_info()[WeaponType::BFG as usize].ammo_per_shot);
     // player.set_psprite(
     //     PsprNum::Flash as usize,
-    //     WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+    //     weapon_info()[player.status.readyweapon as usize].flashstate,
     // );
     if let Some(mobj) = player.mobj_raw() {
         unsafe {
@@ -318,7 +326,7 @@ pub(crate) fn a_gunflash(player: &mut Player, _pspr: &mut PspDef) {
     player.set_mobj_state(StateNum::PLAY_ATK2);
     player.set_psprite(
         PsprNum::Flash as usize,
-        WEAPON_INFO[player.status.readyweapon as usize].flashstate,
+        weapon_info()[player.status.readyweapon as usize].flashstate,
     );
 }
 
@@ -425,3 +433,183 @@ pub(crate) fn a_light1(player: &mut Player, _pspr: &mut PspDef) {
 pub(crate) fn a_light2(player: &mut Player, _pspr: &mut PspDef) {
     player.extralight = 2;
 }
+
+/// The psprite slot `pspr` occupies, for the MBF21 pointers that jump it.
+fn psprite_position(player: &Player, pspr: &PspDef) -> usize {
+    if std::ptr::eq(pspr, &player.psprites[PsprNum::Flash as usize]) {
+        PsprNum::Flash as usize
+    } else {
+        PsprNum::Weapon as usize
+    }
+}
+
+/// The MBF21 arguments of the frame `pspr` is showing.
+fn pspr_args(pspr: &PspDef) -> [i32; 8] {
+    pspr.state.map(|state| state.args).unwrap_or_default()
+}
+
+/// A_WeaponProjectile(type, angle, pitch, hoffset, voffset)
+pub(crate) fn a_weaponprojectile(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let Some(kind) = arg_kind(args[0]) else {
+        return;
+    };
+    if let Some(mobj) = player.mobj_raw() {
+        unsafe {
+            let (missile, aim) =
+                MapObject::spawn_player_missile(&mut *mobj, kind, &mut (*(*mobj).level));
+            adjust_missile(missile, (*mobj).angle, args[1], args[2], args[3], args[4]);
+            // Seekers prefer whatever the player is aiming at
+            missile.tracer = aim.map(|res| res.line_target.thinker);
+        }
+    }
+}
+
+/// A_WeaponBulletAttack(hspread, vspread, numbullets, damagebase, damagedice)
+pub(crate) fn a_weaponbulletattack(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let distance: FixedT = MISSILERANGE.into();
+    if let Some(mobj) = player.mobj_mut() {
+        let bullet_slope = mobj
+            .bullet_slope(distance)
+            .map(|res| res.aimslope)
+            .unwrap_or(FixedT::ZERO);
+
+        for _ in 0..args[2] {
+            let damage = random_damage(args[3], args[4]);
+            let angle = mobj
+                .angle
+                .to_bam()
+                .wrapping_add(random_hitscan_angle(args[0]));
+            let slope = bullet_slope + random_hitscan_slope(args[1]);
+            mobj.shoot_line_attack(distance, Angle::from_bam(angle), slope, damage);
+        }
+    }
+}
+
+/// A_WeaponMeleeAttack(damagebase, damagedice, zerkfactor, sound, range)
+pub(crate) fn a_weaponmeleeattack(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let mut damage = random_damage(args[0], args[1]);
+    if player.status.powers[PowerType::Strength as usize] != 0 {
+        damage = ((damage as i64 * args[2] as i64) >> 16) as i32;
+    }
+
+    if let Some(mobj) = player.mobj_mut() {
+        let range = if args[4] == 0 {
+            FixedT::from(mobj.info.meleerange)
+        } else {
+            FixedT::from_fixed(args[4])
+        };
+
+        // OG: angle += (P_Random()-P_Random())<<18
+        let t = p_random();
+        let spread = ((t - p_random()) << 18) as u32;
+        let angle = Angle::from_bam(mobj.angle.to_bam().wrapping_add(spread));
+
+        let old_angle = mobj.angle;
+        mobj.angle = angle;
+        let slope = mobj.aim_line_attack(range);
+        mobj.angle = old_angle;
+        mobj.line_attack(damage, range, angle, slope.clone());
+
+        if let Some(res) = slope {
+            let target = res.line_target;
+            mobj.start_sound(arg_sound(args[3]));
+            let dx = target.x - mobj.x;
+            let dy = target.y - mobj.y;
+            mobj.angle = Angle::from_bam(r_point_to_angle(dx, dy));
+        }
+    }
+}
+
+/// A_WeaponSound(sound, fullvolume)
+pub(crate) fn a_weaponsound(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let sfx = arg_sound(args[0]);
+    if let Some(mobj) = player.mobj_mut() {
+        if args[1] != 0 {
+            mobj.start_global_sound(sfx);
+        } else {
+            mobj.start_sound(sfx);
+        }
+    }
+}
+
+/// A_WeaponAlert: wake monsters as if the weapon had been fired.
+pub(crate) fn a_weaponalert(player: &mut Player, _pspr: &mut PspDef) {
+    if let Some(mobj) = player.mobj_mut() {
+        noise_alert(mobj);
+    }
+}
+
+/// A_WeaponJump(state, chance)
+pub(crate) fn a_weaponjump(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    if let Some(state) = arg_state(args[0])
+        && p_random() < args[1]
+    {
+        player.set_psprite(psprite_position(player, pspr), state);
+    }
+}
+
+/// The ammo a consume/check pointer deals in: `amount`, or the weapon's
+/// `ammo_per_shot` if zero. `None` for weapons without ammo.
+fn pointer_ammo(player: &Player, amount: i32) -> Option<(usize, u32)> {
+    let info = &weapon_info()[player.status.readyweapon as usize];
+    if info.ammo == AmmoType::NoAmmo {
+        return None;
+    }
+    let amount = if amount == 0 {
+        info.ammo_per_shot
+    } else {
+        amount.max(0) as u32
+    };
+    Some((info.ammo as usize, amount))
+}
+
+/// A_ConsumeAmmo(amount)
+pub(crate) fn a_consumeammo(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    if let Some((ammo, amount)) = pointer_ammo(player, args[0]) {
+        player.status.ammo[ammo] = player.status.ammo[ammo].saturating_sub(amount);
+    }
+}
+
+/// A_CheckAmmo(state, amount)
+pub(crate) fn a_checkammo(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    if let Some((ammo, amount)) = pointer_ammo(player, args[1])
+        && let Some(state) = arg_state(args[0])
+        && player.status.ammo[ammo] < amount
+    {
+        player.set_psprite(psprite_position(player, pspr), state);
+    }
+}
+
+/// A_RefireTo(state, noammocheck)
+pub(crate) fn a_refireto(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let Some(state) = arg_state(args[0]) else {
+        return;
+    };
+    if (args[1] != 0 || player.check_ammo())
+        && player.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0
+        && player.pendingweapon == WeaponType::NoChange
+        && player.status.health != 0
+    {
+        player.set_psprite(psprite_position(player, pspr), state);
+    }
+}
+
+/// A_GunFlashTo(state, nothirdperson)
+pub(crate) fn a_gunflashto(player: &mut Player, pspr: &mut PspDef) {
+    let args = pspr_args(pspr);
+    let Some(state) = arg_state(args[0]) else {
+        return;
+    };
+    if args[1] == 0 {
+        player.set_mobj_state(StateNum::PLAY_ATK2);
+    }
+    player.set_psprite(PsprNum::Flash as usize, state);
+}
@@ -8,7 +8,7 @@ use std::fmt;
 use std::ptr::{self, null_mut};
 
 use level::map_defs::{Sector, SectorHeight};
-use level::{LevelData, LineDefFlags, MapPtr};
+use level::{LevelData, LineDefFlags, MapPtr, MovementType};
 use log::{debug, warn};
 use math::{Angle, FixedT, get_prndindex, get_rndindex, set_prndindex, set_rndindex};
 use wad::types::WadThing;
@@ -17,22 +17,26 @@ use crate::doom_def::MAXPLAYERS;
 use crate::env::ceiling::{CeilKind, CeilingMove};
 use crate::env::doors::{DoorKind, VerticalDoor};
 use crate::env::floor::{FloorKind, FloorMove};
+use crate::env::friction::Friction;
 use crate::env::lights::{FireFlicker, Glow, LightFlash, StrobeFlash};
 use crate::env::platforms::{PlatKind, PlatStatus, Platform};
-use crate::info::{MOBJINFO, MapObjKind, STATES, SpriteNum, StateNum};
+use crate::env::pusher::{PushKind, Pusher, get_push_thing};
+use crate::env::scroller::{ScrollKind, Scroller};
+use crate::info::{MapObjKind, SpriteNum};
 use crate::level::LevelState;
 use crate::pic::Button;
 use crate::player::{Player, PlayerState};
+use crate::tables::{mobj_info, state_index, states};
 use crate::thing::MapObject;
 use crate::thinker::{Think as _, ThinkerData};
 use game_config::{Skill, WeaponType};
 
 const SAVE_MAGIC: &[u8; 4] = b"R4DS";
-const SAVE_VERSION: u32 = 1;
+const SAVE_VERSION: u32 = 5;
 const HEADER_SIZE: usize = 64;
 const SAVE_DESCRIPTION_SIZE: usize = 24;
 // Wire record sizes for load_world skip-ahead (must match field read sequences)
-const SECTOR_SAVE_BYTES: usize = 4 + 4 + 4 + 4 + 4 + 2 + 2; // floor, ceil, floorpic, ceilpic, light, special, tag
+const SECTOR_SAVE_BYTES: usize = 4 + 4 + 4 + 4 + 4 + 2 + 2 + 4 * 4; // floor, ceil, floorpic, ceilpic, light, special, tag, flat offsets
 const LINEDEF_SAVE_BYTES: usize = 4 + 2 + 2; // flags, special, tag
 const SIDEDEF_SAVE_BYTES: usize = 4 + 4 + 4 + 4 + 4; // texoffset, rowoffset, top, bot, mid
 
@@ -46,6 +50,9 @@ const TAG_LIGHT_FLASH: u8 = 6;
 const TAG_STROBE: u8 = 7;
 const TAG_FIRE_FLICKER: u8 = 8;
 const TAG_GLOW: u8 = 9;
+const TAG_FRICTION: u8 = 10;
+const TAG_PUSHER: u8 = 11;
+const TAG_SCROLLER: u8 = 12;
 
 #[derive(Debug)]
 pub enum SaveError {
@@ -56,6 +63,7 @@ pub enum SaveError {
     InvalidThinkerTag(u8),
     InvalidStateNum(u16),
     InvalidSectorNum(u32),
+    InvalidLineNum(u32),
     InvalidEnum(&'static str, u32),
 }
 
@@ -69,6 +77,7 @@ impl fmt::Display for SaveError {
             Self::InvalidThinkerTag(t) => write!(f, "invalid thinker tag: {t}"),
             Self::InvalidStateNum(s) => write!(f, "invalid state num: {s}"),
             Self::InvalidSectorNum(s) => write!(f, "invalid sector num: {s}"),
+            Self::InvalidLineNum(l) => write!(f, "invalid linedef num: {l}"),
             Self::InvalidEnum(ty, v) => write!(f, "invalid {ty} discriminant: {v}"),
         }
     }
@@ -82,21 +91,16 @@ impl From<std::io::Error> for SaveError {
     }
 }
 
-/// Convert a state reference to its index in the global STATES array.
+/// Convert a state reference to its index in the state table.
 fn state_to_index(state: &'static crate::info::StateData) -> u16 {
-    let base = std::ptr::addr_of!(STATES).cast::<crate::info::StateData>() as usize;
-    let ptr = ptr::from_ref(state) as usize;
-    let idx = (ptr - base) / size_of::<crate::info::StateData>();
-    idx as u16
+    state_index(state) as u16
 }
 
 /// Convert a state index back to a reference.
 fn index_to_state(idx: u16) -> Result<&'static crate::info::StateData, SaveError> {
-    let max = StateNum::Count as u16;
-    if idx >= max {
-        return Err(SaveError::InvalidStateNum(idx));
-    }
-    Ok(&STATES[idx as usize])
+    states()
+        .get(idx as usize)
+        .ok_or(SaveError::InvalidStateNum(idx))
 }
 
 pub struct SaveWriter {
@@ -344,6 +348,10 @@ fn save_world(w: &mut SaveWriter, level_data: &LevelData) {
         w.write_u32(s.lightlevel as u32);
         w.write_u32(s.special);
         w.write_i16(s.tag);
+        w.write_fixed(s.floor_xoffs);
+        w.write_fixed(s.floor_yoffs);
+        w.write_fixed(s.ceiling_xoffs);
+        w.write_fixed(s.ceiling_yoffs);
     }
 
     w.write_u32(level_data.linedefs.len() as u32);
@@ -385,6 +393,10 @@ fn load_world(r: &mut SaveReader, level_data: &mut LevelData) -> Result<(), Save
         sectors[i].lightlevel = r.read_u32()? as usize;
         sectors[i].special = r.read_u32()?;
         sectors[i].tag = r.read_i16()?;
+        sectors[i].floor_xoffs = r.read_fixed()?;
+        sectors[i].floor_yoffs = r.read_fixed()?;
+        sectors[i].ceiling_xoffs = r.read_fixed()?;
+        sectors[i].ceiling_yoffs = r.read_fixed()?;
     }
     // skip extra sectors in save if map has fewer
     for _ in count..n_sectors {
@@ -420,6 +432,18 @@ fn load_world(r: &mut SaveReader, level_data: &mut LevelData) -> Result<(), Save
         r.skip(SIDEDEF_SAVE_BYTES)?;
     }
 
+    // Scrolled wall and flat offsets go to the 3D renderers' texture scroll
+    for i in 0..level_data.linedefs.len() {
+        level_data.bsp_3d.update_wall_scroll(i);
+    }
+    for (i, s) in level_data.sectors.iter().enumerate() {
+        let bsp_3d = &mut level_data.bsp_3d;
+        let (fx, fy) = (s.floor_xoffs.to_f32(), s.floor_yoffs.to_f32());
+        bsp_3d.update_flat_scroll(i, MovementType::Floor, fx, fy);
+        let (cx, cy) = (s.ceiling_xoffs.to_f32(), s.ceiling_yoffs.to_f32());
+        bsp_3d.update_flat_scroll(i, MovementType::Ceiling, cx, cy);
+    }
+
     Ok(())
 }
 
@@ -448,17 +472,21 @@ fn save_mobj_with_player_index(w: &mut SaveWriter, m: &MapObject, player_idx: i8
     w.write_i32(m.tics);
     w.write_u16(state_to_index(m.state));
     w.write_u32(m.flags.bits());
+    w.write_u32(m.flags2.bits());
     w.write_i32(m.health);
     w.write_u8(m.movedir as u8);
     w.write_i32(m.movecount);
     w.write_i32(m.reactiontime);
     w.write_i32(m.threshold);
+    w.write_i32(m.friction.to_fixed_raw());
+    w.write_i32(m.movefactor);
     w.write_u8(m.lastlook as u8);
     w.write_i16(m.spawnpoint.x);
     w.write_i16(m.spawnpoint.y);
     w.write_i16(m.spawnpoint.angle);
     w.write_i16(m.spawnpoint.kind);
     w.write_i16(m.spawnpoint.flags);
+    w.write_bool(m.spawnpoint.translucent);
     w.write_u8(player_idx as u8);
 }
 
@@ -475,7 +503,7 @@ fn resolve_sector(
 }
 
 /// Restore all thinkers (map objects, doors, floors, ceilings, platforms,
-/// lights) from save data. Dispatches on per-thinker type tag.
+/// lights, friction, pushers, scrollers) from save data. Dispatches on per-thinker type tag.
 fn load_thinkers(
     r: &mut SaveReader,
     level: &mut LevelState,
@@ -515,6 +543,15 @@ fn load_thinkers(
             TAG_GLOW => {
                 load_glow(r, level)?;
             }
+            TAG_FRICTION => {
+                load_friction(r, level)?;
+            }
+            TAG_PUSHER => {
+                load_pusher(r, level)?;
+            }
+            TAG_SCROLLER => {
+                load_scroller(r, level)?;
+            }
             other => return Err(SaveError::InvalidThinkerTag(other)),
         }
     }
@@ -546,21 +583,25 @@ fn load_mobj(
     let tics = r.read_i32()?;
     let state_idx = r.read_u16()?;
     let flags_raw = r.read_u32()?;
+    let flags2_raw = r.read_u32()?;
     let health = r.read_i32()?;
     let movedir = r.read_u8()?;
     let movecount = r.read_i32()?;
     let reactiontime = r.read_i32()?;
     let threshold = r.read_i32()?;
+    let friction = r.read_i32()?;
+    let movefactor = r.read_i32()?;
     let lastlook = r.read_u8()?;
     let sp_x = r.read_i16()?;
     let sp_y = r.read_i16()?;
     let sp_angle = r.read_i16()?;
     let sp_kind = r.read_i16()?;
     let sp_flags = r.read_i16()?;
+    let sp_translucent = r.read_bool()?;
     let player_idx = r.read_u8()? as i8;
 
     let kind = MapObjKind::from(kind_raw);
-    let info = MOBJINFO[kind as usize];
+    let info = mobj_info()[kind as usize];
     let state = index_to_state(state_idx)?;
 
     let level_ptr = ptr::from_mut(level);
@@ -584,11 +625,14 @@ fn load_mobj(
         tics,
         state,
         crate::thing::MapObjFlag::from_bits_truncate(flags_raw),
+        crate::thing::MapObjFlag2::from_bits_truncate(flags2_raw),
         health,
         crate::thing::MoveDir::from(movedir as usize),
         movecount,
         reactiontime,
         threshold,
+        friction,
+        movefactor,
         lastlook as usize,
         WadThing {
             x: sp_x,
@@ -596,6 +640,7 @@ fn load_mobj(
             angle: sp_angle,
             kind: sp_kind,
             flags: sp_flags,
+            translucent: sp_translucent,
         },
         level_ptr,
     );
@@ -876,6 +921,101 @@ fn load_glow(r: &mut SaveReader, level: &mut LevelState) -> Result<(), SaveError
     Ok(())
 }
 
+fn load_friction(r: &mut SaveReader, level: &mut LevelState) -> Result<(), SaveError> {
+    let sector_num = r.read_u32()?;
+    let friction = r.read_i32()?;
+    let movefactor = r.read_i32()?;
+
+    let sector = resolve_sector(sector_num, &mut level.level_data)?;
+
+    let f = Friction {
+        thinker: null_mut(),
+        sector,
+        friction: FixedT::from_fixed(friction),
+        movefactor,
+    };
+
+    let thinker = Friction::create_thinker(ThinkerData::Friction(f), Friction::think);
+    level.thinkers.push_raw(thinker);
+
+    Ok(())
+}
+
+/// Deserialize a pusher. Point sources re-find their `MT_PUSH`/`MT_PULL`
+/// thing in the sector, which was restored earlier in the thinker list.
+fn load_pusher(r: &mut SaveReader, level: &mut LevelState) -> Result<(), SaveError> {
+    let sector_num = r.read_u32()?;
+    let kind_raw = r.read_u8()?;
+    let x_mag = r.read_i32()?;
+    let y_mag = r.read_i32()?;
+
+    let kind =
+        PushKind::try_from(kind_raw).map_err(|v| SaveError::InvalidEnum("PushKind", v as u32))?;
+    let sector = resolve_sector(sector_num, &mut level.level_data)?;
+    let source = if kind == PushKind::Point {
+        let Some(source) = get_push_thing(&sector) else {
+            warn!("Pusher in sector {sector_num} lost its source thing, dropping");
+            return Ok(());
+        };
+        Some(source)
+    } else {
+        None
+    };
+
+    let p = Pusher::new(sector, kind, x_mag, y_mag, source);
+    let thinker = Pusher::create_thinker(ThinkerData::Pusher(p), Pusher::think);
+    level.thinkers.push_raw(thinker);
+
+    Ok(())
+}
+
+fn load_scroller(r: &mut SaveReader, level: &mut LevelState) -> Result<(), SaveError> {
+    let kind_raw = r.read_u8()?;
+    let affectee = r.read_u32()?;
+    let dx = r.read_fixed()?;
+    let dy = r.read_fixed()?;
+    let control = r.read_i32()?;
+    let last_height = r.read_fixed()?;
+    let accel = r.read_bool()?;
+    let vdx = r.read_fixed()?;
+    let vdy = r.read_fixed()?;
+
+    let kind = ScrollKind::try_from(kind_raw)
+        .map_err(|v| SaveError::InvalidEnum("ScrollKind", v as u32))?;
+    let level_data = &level.level_data;
+    if kind == ScrollKind::Side {
+        if affectee as usize >= level_data.linedefs.len() {
+            return Err(SaveError::InvalidLineNum(affectee));
+        }
+    } else if affectee as usize >= level_data.sectors.len() {
+        return Err(SaveError::InvalidSectorNum(affectee));
+    }
+    let control = if control < 0 {
+        None
+    } else if control as usize >= level_data.sectors.len() {
+        return Err(SaveError::InvalidSectorNum(control as u32));
+    } else {
+        Some(control as usize)
+    };
+
+    let s = Scroller {
+        thinker: null_mut(),
+        kind,
+        dx,
+        dy,
+        affectee: affectee as usize,
+        control,
+        last_height,
+        accel,
+        vdx,
+        vdy,
+    };
+    let thinker = Scroller::create_thinker(ThinkerData::Scroller(s), Scroller::think);
+    level.thinkers.push_raw(thinker);
+
+    Ok(())
+}
+
 /// Serialize all player state: status, inventory, weapons, psprites, frags.
 fn save_players(w: &mut SaveWriter, players: &[Player], players_in_game: &[bool; MAXPLAYERS]) {
     for i in 0..MAXPLAYERS {
@@ -1091,6 +1231,7 @@ fn save_respawn_queue(w: &mut SaveWriter, level: &LevelState) {
         w.write_i16(thing.angle);
         w.write_i16(thing.kind);
         w.write_i16(thing.flags);
+        w.write_bool(thing.translucent);
     }
 }
 
@@ -1105,6 +1246,7 @@ fn load_respawn_queue(r: &mut SaveReader, level: &mut LevelState) -> Result<(),
         let angle = r.read_i16()?;
         let kind = r.read_i16()?;
         let flags = r.read_i16()?;
+        let translucent = r.read_bool()?;
         level.respawn_queue.push_back((
             time,
             WadThing {
@@ -1113,6 +1255,7 @@ fn load_respawn_queue(r: &mut SaveReader, level: &mut LevelState) -> Result<(),
                 angle,
                 kind,
                 flags,
+                translucent,
             },
         ));
     }
@@ -1282,6 +1425,31 @@ pub fn save_game_to_bytes(
             w.write_u32(g.max_light as u32);
             w.write_i32(g.direction);
         }
+        ThinkerData::Friction(f) => {
+            w.write_u8(TAG_FRICTION);
+            w.write_u32(sector_index(&f.sector));
+            w.write_i32(f.friction.to_fixed_raw());
+            w.write_i32(f.movefactor);
+        }
+        ThinkerData::Pusher(p) => {
+            w.write_u8(TAG_PUSHER);
+            w.write_u32(sector_index(&p.sector));
+            w.write_u8(p.kind as u8);
+            w.write_i32(p.x_mag);
+            w.write_i32(p.y_mag);
+        }
+        ThinkerData::Scroller(s) => {
+            w.write_u8(TAG_SCROLLER);
+            w.write_u8(s.kind as u8);
+            w.write_u32(s.affectee as u32);
+            w.write_fixed(s.dx);
+            w.write_fixed(s.dy);
+            w.write_i32(s.control.map_or(-1, |c| c as i32));
+            w.write_fixed(s.last_height);
+            w.write_bool(s.accel);
+            w.write_fixed(s.vdx);
+            w.write_fixed(s.vdy);
+        }
         _ => {}
     });
 
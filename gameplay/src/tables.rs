@@ -0,0 +1,202 @@
+//! Runtime copies of the info tables.
+//!
+//! The generated `STATES`/`MOBJINFO` and the `doom_def` constants are the
+//! unmodified defaults. DEHACKED patches a copy of them once at startup (see
+//! `dehacked`) and everything else reads through the accessors here. If
+//! nothing is installed the first access locks in the defaults.
+
+use std::sync::OnceLock;
+
+use crate::doom_def::{CLIP_AMMO, MAX_AMMO, WEAPON_INFO, WeaponInfo};
+use crate::info::{MOBJINFO, MapObjInfo, SPRNAMES, STATES, StateData};
+
+static TABLES: OnceLock<InfoTables> = OnceLock::new();
+
+/// Every table DEHACKED can patch.
+#[derive(Debug, Clone)]
+pub(crate) struct InfoTables {
+    pub states: Vec<StateData>,
+    pub mobj_info: Vec<MapObjInfo>,
+    pub sprite_names: Vec<&'static str>,
+    pub weapon_info: Vec<WeaponInfo>,
+    pub max_ammo: [u32; 4],
+    pub clip_ammo: [u32; 4],
+    pub misc: MiscInfo,
+    pub cheats: CheatCodes,
+    /// BEX par times as `(episode, map, seconds)`, episode 0 for Doom II.
+    pub pars: Vec<(usize, usize, i32)>,
+    /// A patch declared `Doom version = 2021`.
+    pub mbf21: bool,
+}
+
+impl Default for InfoTables {
+    fn default() -> Self {
+        Self {
+            states: STATES.to_vec(),
+            mobj_info: MOBJINFO.to_vec(),
+            sprite_names: SPRNAMES.to_vec(),
+            weapon_info: WEAPON_INFO.to_vec(),
+            max_ammo: MAX_AMMO,
+            clip_ammo: CLIP_AMMO,
+            misc: MiscInfo::default(),
+            cheats: CheatCodes::default(),
+            pars: Vec::new(),
+            mbf21: false,
+        }
+    }
+}
+
+/// Gameplay constants from the DEHACKED `Misc` block.
+#[derive(Debug, Clone, Copy)]
+pub struct MiscInfo {
+    pub initial_health: i32,
+    pub initial_bullets: u32,
+    /// Cap for health bonuses.
+    pub max_health: i32,
+    /// Cap for armour bonuses.
+    pub max_armor: i32,
+    pub green_armor_class: i32,
+    pub blue_armor_class: i32,
+    pub max_soulsphere: i32,
+    pub soulsphere_health: i32,
+    pub megasphere_health: i32,
+    pub god_mode_health: i32,
+    pub idfa_armor: i32,
+    pub idfa_armor_class: i32,
+    pub idkfa_armor: i32,
+    pub idkfa_armor_class: i32,
+    /// `Monsters Infight = 221`: projectiles hurt their own species.
+    pub species_infighting: bool,
+}
+
+impl Default for MiscInfo {
+    fn default() -> Self {
+        Self {
+            initial_health: 100,
+            initial_bullets: 50,
+            max_health: 200,
+            max_armor: 200,
+            green_armor_class: 1,
+            blue_armor_class: 2,
+            max_soulsphere: 200,
+            soulsphere_health: 100,
+            megasphere_health: 200,
+            god_mode_health: 100,
+            idfa_armor: 200,
+            idfa_armor_class: 2,
+            idkfa_armor: 200,
+            idkfa_armor_class: 2,
+            species_infighting: false,
+        }
+    }
+}
+
+/// Cheat sequences from the DEHACKED `Cheat` block.
+#[derive(Debug, Clone, Copy)]
+pub struct CheatCodes {
+    pub god: &'static str,
+    pub mus: &'static str,
+    pub ammo: &'static str,
+    pub ammo_no_key: &'static str,
+    pub noclip: &'static str,
+    pub commercial_noclip: &'static str,
+    /// `idbehold` v, s, i, r, a, l in `PowerType` order.
+    pub powerup: [&'static str; 6],
+    pub choppers: &'static str,
+    pub clev: &'static str,
+    pub mypos: &'static str,
+}
+
+impl Default for CheatCodes {
+    fn default() -> Self {
+        Self {
+            god: "iddqd",
+            mus: "idmus",
+            ammo: "idkfa",
+            ammo_no_key: "idfa",
+            noclip: "idspispopd",
+            commercial_noclip: "idclip",
+            powerup: [
+                "idbeholdv",
+                "idbeholds",
+                "idbeholdi",
+                "idbeholdr",
+                "idbeholda",
+                "idbeholdl",
+            ],
+            choppers: "idchoppers",
+            clev: "idclev",
+            mypos: "idmypos",
+        }
+    }
+}
+
+/// Install patched tables. Returns false if the tables were already in use,
+/// in which case the patch is dropped.
+pub(crate) fn install_tables(tables: InfoTables) -> bool {
+    TABLES.set(tables).is_ok()
+}
+
+fn tables() -> &'static InfoTables {
+    TABLES.get_or_init(InfoTables::default)
+}
+
+/// The state table, indexed by `StateNum`.
+pub fn states() -> &'static [StateData] {
+    &tables().states
+}
+
+/// The thing table, indexed by `MapObjKind`.
+pub fn mobj_info() -> &'static [MapObjInfo] {
+    &tables().mobj_info
+}
+
+/// Sprite lump prefixes, indexed by `SpriteNum`.
+pub fn sprite_names() -> &'static [&'static str] {
+    &tables().sprite_names
+}
+
+/// Weapon definitions, indexed by `WeaponType`.
+pub fn weapon_info() -> &'static [WeaponInfo] {
+    &tables().weapon_info
+}
+
+/// Maximum ammo per `AmmoType` without a backpack.
+pub fn max_ammo() -> [u32; 4] {
+    tables().max_ammo
+}
+
+/// Ammo in a clip per `AmmoType`.
+pub fn clip_ammo() -> [u32; 4] {
+    tables().clip_ammo
+}
+
+pub fn misc_info() -> &'static MiscInfo {
+    &tables().misc
+}
+
+pub fn cheat_codes() -> &'static CheatCodes {
+    &tables().cheats
+}
+
+/// A DEHACKED `[PARS]` par time for the map, if one was patched.
+pub fn par_time(episode: usize, map: usize) -> Option<i32> {
+    tables()
+        .pars
+        .iter()
+        .rev()
+        .find(|&&(e, m, _)| e == episode && m == map)
+        .map(|&(_, _, seconds)| seconds)
+}
+
+/// MBF21 rules are on: weapons always use their `ammo_per_shot` and ammo
+/// pickups switch by weapon flags.
+pub(crate) fn mbf21() -> bool {
+    tables().mbf21
+}
+
+/// Index of `state` in the state table.
+pub(crate) fn state_index(state: &'static StateData) -> usize {
+    let base = states().as_ptr() as usize;
+    (std::ptr::from_ref(state) as usize - base) / size_of::<StateData>()
+}
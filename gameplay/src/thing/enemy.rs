@@ -15,12 +15,13 @@ use std::ptr;
 use crate::doom_def::MISSILERANGE;
 use crate::env::doors::{DoorKind, ev_do_door};
 use crate::env::floor::{FloorKind, ev_do_floor};
-use crate::info::{MOBJINFO, StateNum};
+use crate::info::StateNum;
 use crate::level::LevelState;
 use crate::maputl::PortalZ;
-use crate::thing::{MapObjFlag, MapObject, MoveDir};
+use crate::tables::mobj_info;
+use crate::thing::{MapObjFlag, MapObjFlag2, MapObject, MoveDir};
 use crate::thinker::ThinkerData;
-use crate::{MAXPLAYERS, MapObjKind, SectorExt as _, teleport_move};
+use crate::{MapObjKind, SectorExt as _, teleport_move};
 use game_config::{GameMode, Skill};
 use level::map_defs::{LineDef, SlopeType};
 use level::{LineDefFlags, MapPtr, Sector};
@@ -45,12 +46,18 @@ const SKULLSPEED: i32 = 20;
 /// the original comment stating that if a monster yells it alerts surrounding
 /// monsters
 pub(crate) fn noise_alert(target: &mut MapObject) {
+    let sect = target.subsector.sector.clone();
+    noise_alert_from(target, sect);
+}
+
+/// Flood sound from `sector` with `target` as the sound target. MBF21's
+/// `A_NoiseAlert` lets a monster raise the alarm from its own sector.
+pub(crate) fn noise_alert_from(target: &mut MapObject, sector: MapPtr<Sector>) {
     let vc = unsafe {
         (*target.level).valid_count += 1;
         (*target.level).valid_count
     };
-    let sect = target.subsector.sector.clone();
-    sound_flood(sect, vc, 0, target);
+    sound_flood(sector, vc, 0, target);
 }
 
 fn sound_flood(
@@ -201,12 +208,16 @@ pub(crate) fn a_chase(actor: &mut MapObject) {
     }
 
     // nomissile:
-    // // possibly choose another target
-    // if (netgame && !actor->threshold && !P_CheckSight(actor, actor->target))
-    // {
-    // if (P_LookForPlayers(actor, true))
-    // return; // got a new target
-    // }
+    // possibly choose another target
+    let netgame = unsafe { (*actor.level).options.netgame };
+    if netgame
+        && actor.threshold == 0
+        && let Some(target) = actor.target
+        && !actor.check_sight_target(unsafe { (*target).mobj() })
+        && actor.look_for_players(true)
+    {
+        return; // got a new target
+    }
 
     // // chase towards player
     actor.movecount -= 1;
@@ -268,13 +279,15 @@ pub(crate) fn a_look(actor: &mut MapObject) {
             _ => actor.info.seesound,
         };
 
-        // if actor.kind == MapObjKind::MT_SPIDER || actor.kind == MapObjKind::MT_CYBORG
-        // {     // TODO: FULL VOLUME!
-        //     actor.start_sound(sound);
-        // } else {
-        //     actor.start_sound(sound);
-        // }
-        actor.start_sound(sound);
+        if actor
+            .flags2
+            .intersects(MapObjFlag2::Boss | MapObjFlag2::Fullvolsounds)
+        {
+            // full volume
+            actor.start_global_sound(sound);
+        } else {
+            actor.start_sound(sound);
+        }
     }
 
     actor.set_state(actor.info.seestate);
@@ -317,9 +330,12 @@ pub(crate) fn a_scream(actor: &mut MapObject) {
     };
 
     // Check for bosses.
-    if matches!(actor.kind, MapObjKind::MT_SPIDER | MapObjKind::MT_CYBORG) {
+    if actor
+        .flags2
+        .intersects(MapObjFlag2::Boss | MapObjFlag2::Fullvolsounds)
+    {
         // full volume
-        // TODO: start_sound("a_scream", None, sound);
+        actor.start_global_sound(sound);
     } else {
         actor.start_sound(sound);
     }
@@ -382,6 +398,8 @@ pub(crate) fn a_keendie(actor: &mut MapObject) {
         sides: [0, 0],
         default_special: 0,
         default_tag: 0,
+        alpha: 1.0,
+        additive: false,
     };
     ev_do_door(MapPtr::new(&mut junk), DoorKind::BlazeOpen, level);
 }
@@ -606,39 +624,50 @@ fn vile_raise_check(actor: &MapObject, obj: &mut MapObject) -> bool {
 }
 
 pub(crate) fn a_vilechase(actor: &mut MapObject) {
-    if actor.movedir != MoveDir::None {
-        // look for corpses
-        let mut ss = actor.subsector.clone();
-        let res = ss.sector.run_mut_func_on_thinglist(|obj| {
-            // Check corpses are within radius
-            if !vile_raise_check(actor, obj) {
-                // found one so raise it
-                let last_target = actor.target.take();
-                actor.target = Some(obj.thinker);
-                a_facetarget(actor);
-                actor.target = last_target;
-
-                actor.set_state(StateNum::VILE_HEAL1);
-                actor.start_sound(SfxName::Slop);
-                // info = corpsehit->info;
-
-                obj.set_state(obj.info.raisestate);
-                obj.height = obj.height * 2;
-                obj.flags = obj.info.flags;
-                obj.health = obj.info.spawnhealth;
-                obj.target = None;
-                return false;
+    if !heal_corpse(actor, StateNum::VILE_HEAL1, SfxName::Slop) {
+        a_chase(actor);
+    }
+}
+
+/// Look for a corpse to raise, entering `heal_state` and playing `sound` if
+/// one is found. Shared by the archvile and MBF21 `A_HealChase`.
+pub(crate) fn heal_corpse(actor: &mut MapObject, heal_state: StateNum, sound: SfxName) -> bool {
+    if actor.movedir == MoveDir::None {
+        return false;
+    }
+
+    // look for corpses
+    let mut ss = actor.subsector.clone();
+    let res = ss.sector.run_mut_func_on_thinglist(|obj| {
+        // Check corpses are within radius
+        if !vile_raise_check(actor, obj) {
+            // found one so raise it
+            let last_target = actor.target.take();
+            actor.target = Some(obj.thinker);
+            a_facetarget(actor);
+            actor.target = last_target;
+
+            actor.set_state(heal_state);
+            if sound != SfxName::None {
+                actor.start_sound(sound);
             }
-            true
-        });
-        if !res {
-            // found a corpse so return
-            trace!("Archvile found a corpse to raise");
-            return;
+            // info = corpsehit->info;
+
+            obj.set_state(obj.info.raisestate);
+            obj.height = obj.height * 2;
+            obj.flags = obj.info.flags;
+            obj.flags2 = obj.info.flags2;
+            obj.health = obj.info.spawnhealth;
+            obj.target = None;
+            return false;
         }
+        true
+    });
+    if !res {
+        // found a corpse so return
+        trace!("{:?} found a corpse to raise", actor.kind);
     }
-
-    a_chase(actor);
+    !res
 }
 
 pub(crate) fn a_viletarget(actor: &mut MapObject) {
@@ -875,7 +904,7 @@ fn a_painshootskull(actor: &mut MapObject, angle: Angle<Bam>) {
     let bam = angle.to_bam();
     // OG: prestep = 4*FRACUNIT + 3*(actor->info->radius +
     // mobjinfo[MT_SKULL].radius)/2
-    let skull_radius_raw = float_to_fixed(MOBJINFO[MapObjKind::MT_SKULL as usize].radius);
+    let skull_radius_raw = float_to_fixed(mobj_info()[MapObjKind::MT_SKULL as usize].radius);
     let prestep_raw = 4 * 0x10000_i32 + 3 * (actor.radius.to_fixed_raw() + skull_radius_raw) / 2;
     let prestep = FixedT::from_fixed(prestep_raw);
     let spawn_x = actor.x + prestep.fixed_mul(FixedT::cos_bam(bam));
@@ -1037,68 +1066,50 @@ pub(crate) fn a_bossdeath(actor: &mut MapObject) {
         }
     }
 
-    // Default boss death logic
+    // Default boss death logic, keyed on the MBF21 boss flags
+    let flags2 = actor.flags2;
     if mode == GameMode::Commercial {
-        if map != 7 {
-            return;
-        }
-        if mt != MapObjKind::MT_FATSO && mt != MapObjKind::MT_BABY {
+        if map != 7 || !flags2.intersects(MapObjFlag2::Map07boss1 | MapObjFlag2::Map07boss2) {
             return;
         }
     } else {
-        match episode {
-            1 => {
-                if map != 8 && mt != MapObjKind::MT_BRUISER {
-                    return;
-                }
-            }
-            2 => {
-                if map != 8 && mt != MapObjKind::MT_CYBORG {
-                    return;
-                }
-            }
-            3 => {
-                if map != 8 && mt != MapObjKind::MT_SPIDER {
-                    return;
-                }
-            }
-            4 => {
-                if map != 6 && mt != MapObjKind::MT_CYBORG {
-                    return;
-                }
-                if map != 8 && mt != MapObjKind::MT_SPIDER {
-                    return;
-                }
-            }
-            _ => {
-                if map != 8 {
-                    return;
-                }
-            }
-        }
-        for (i, p) in level.players().iter().enumerate() {
-            if p.status.health > 0 {
-                break;
-            }
-            if i == MAXPLAYERS - 1 {
-                return;
-            }
+        let boss = match (episode, map) {
+            (1, 8) => flags2.contains(MapObjFlag2::E1m8boss),
+            (2, 8) => flags2.contains(MapObjFlag2::E2m8boss),
+            (3, 8) => flags2.contains(MapObjFlag2::E3m8boss),
+            (4, 6) => flags2.contains(MapObjFlag2::E4m6boss),
+            (4, 8) => flags2.contains(MapObjFlag2::E4m8boss),
+            (4, _) => false,
+            (_, m) => m == 8,
+        };
+        if !boss {
+            return;
         }
     }
 
+    // make sure there is a player alive for victory
+    let alive = level
+        .players()
+        .iter()
+        .zip(level.players_in_game())
+        .any(|(p, &in_game)| in_game && p.status.health > 0);
+    if !alive {
+        return;
+    }
+
     if !all_bosses_dead(actor, level) {
         return;
     }
 
     let mut junk = make_boss_junk_linedef(actor, 666);
 
-    if mode == GameMode::Commercial && map == 7 {
-        if mt == MapObjKind::MT_FATSO {
+    if mode == GameMode::Commercial {
+        if flags2.contains(MapObjFlag2::Map07boss1) {
             junk.tag = 666;
             ev_do_floor(MapPtr::new(&mut junk), FloorKind::LowerFloorToLowest, level);
             return;
         }
-        if mt == MapObjKind::MT_BABY {
+        if flags2.contains(MapObjFlag2::Map07boss2) {
             junk.tag = 667;
             ev_do_floor(MapPtr::new(&mut junk), FloorKind::RaiseToTexture, level);
             return;
@@ -1157,6 +1168,8 @@ fn make_boss_junk_linedef(actor: &MapObject, tag: i16) -> LineDef {
         sides: [0, 0],
         default_special: 0,
         default_tag: 0,
+        alpha: 1.0,
+        additive: false,
     }
 }
 
@@ -6,11 +6,12 @@ use log::{debug, error, info};
 use sound_common::SfxName;
 
 use crate::MapObject;
-use crate::doom_def::{AmmoType, Card, PowerType};
-use crate::info::{MapObjKind, STATES, SpriteNum, StateNum};
+use crate::doom_def::{AmmoType, Card, PowerType, WeaponFlag};
+use crate::info::{MapObjKind, SpriteNum, StateNum};
 use crate::lang::english::*;
 use crate::player::{PlayerCheat, PlayerState};
-use crate::thing::MapObjFlag;
+use crate::tables::{misc_info, states, weapon_info};
+use crate::thing::{MapObjFlag, MapObjFlag2};
 use game_config::{Skill, WeaponType};
 use math::{ANG180, FixedT, p_random, r_point_to_angle};
 
@@ -74,11 +75,15 @@ impl MapObject {
         // inflict thrust and push the victim out of reach,
         // thus kick away unless using the chainsaw.
         if let Some((inflict_x, inflict_y, inflict_z)) = inflictor {
-            let no_chainsaw = source.as_ref().is_none_or(|src| {
-                src.player.is_none()
-                    || unsafe { (*src.player.unwrap()).status.readyweapon != WeaponType::Chainsaw }
+            let thrust_weapon = source.as_ref().is_none_or(|src| {
+                src.player.is_none_or(|player| {
+                    let weapon = unsafe { (*player).status.readyweapon };
+                    !weapon_info()[weapon as usize]
+                        .flags
+                        .contains(WeaponFlag::Nothrust)
+                })
             });
-            if !self.flags.contains(MapObjFlag::Noclip) && no_chainsaw {
+            if !self.flags.contains(MapObjFlag::Noclip) && thrust_weapon {
                 let dx = self.x - inflict_x;
                 let dy = self.y - inflict_y;
                 let mut ang = r_point_to_angle(dx, dy);
@@ -171,15 +176,18 @@ impl MapObject {
 
         self.reactiontime = 0; // AWAKE AND READY!
 
-        if (self.threshold == 0 || self.kind == MapObjKind::MT_VILE)
+        if (self.threshold == 0 || self.flags2.contains(MapObjFlag2::Nothreshold))
             && let Some(source) = source
         {
             // TODO: gameversion <= exe_doom_1_2
-            if !ptr::eq(self, source) && source.kind != MapObjKind::MT_VILE {
+            if !ptr::eq(self, source)
+                && !source.flags2.contains(MapObjFlag2::Dmgignored)
+                && !self.infighting_immune(source)
+            {
                 self.target = Some(source.thinker);
                 self.threshold = BASETHRESHOLD;
 
-                if ptr::eq(self.state, &STATES[self.info.spawnstate as usize])
+                if ptr::eq(self.state, &states()[self.info.spawnstate as usize])
                     && self.info.seestate != StateNum::None
                 {
                     self.set_state(self.info.seestate);
@@ -188,6 +196,22 @@ impl MapObject {
         }
     }
 
+    /// MBF21: monsters in the same infighting group don't retaliate against
+    /// each other.
+    pub(crate) fn infighting_immune(&self, source: &Self) -> bool {
+        self.info.infighting_group != 0
+            && self.info.infighting_group == source.info.infighting_group
+    }
+
+    /// MBF21: `source`'s projectiles can't hurt `self`. Group 0 covers the
+    /// same species, a negative group hurts everything except the shooter.
+    pub(crate) fn projectile_immune(&self, source: &Self) -> bool {
+        let group = self.info.projectile_group;
+        (group >= 0 || ptr::eq(self, source))
+            && ((group == 0 && self.kind == source.kind)
+                || (group != 0 && group == source.info.projectile_group))
+    }
+
     /// Doom function name `P_KillMobj`
     fn kill(&mut self, mut source: Option<&mut Self>) {
         self.flags
@@ -200,15 +224,15 @@ impl MapObject {
         self.flags.insert(MapObjFlag::Corpse | MapObjFlag::Dropoff);
         self.height = self.height / 4;
 
+        let target_num = self.player.map(|p| self.level().player_num(p));
         if let Some(source) = source.as_mut() {
             if let Some(player) = source.player_mut() {
                 if self.flags.contains(MapObjFlag::Countkill) {
                     player.total_kills += 1;
                 }
 
-                if self.player.is_some() {
-                    // TODO: set correct player for frags
-                    player.frags[0] += 1;
+                if let Some(num) = target_num {
+                    player.frags[num] += 1;
                 }
             }
         } else {
@@ -222,9 +246,10 @@ impl MapObject {
         if let Some(player) = self.player_mut() {
             info!("Killing player");
             // Environment kills count against you
-            if source.is_none() {
-                // TODO: set correct player for frags
-                player.frags[0] += 1;
+            if source.is_none()
+                && let Some(num) = target_num
+            {
+                player.frags[num] += 1;
             }
 
             player.player_state = PlayerState::Dead;
@@ -281,49 +306,50 @@ impl MapObject {
                 return;
             }
 
-            let skill = unsafe { (*self.level).options.skill };
+            let options = unsafe { &(*self.level).options };
+            let skill = options.skill;
             match special.sprite {
                 SpriteNum::ARM1 => {
-                    if !player.give_armour(1) {
+                    if !player.give_armour(misc_info().green_armor_class) {
                         return;
                     }
                     player.message = Some(GOTARMOR);
                 }
                 SpriteNum::ARM2 => {
-                    if !player.give_armour(2) {
+                    if !player.give_armour(misc_info().blue_armor_class) {
                         return;
                     }
                     player.message = Some(GOTMEGA);
                 }
                 SpriteNum::BON1 => {
                     player.status.health += 1; // Go over 100%
-                    if player.status.health > 200 {
-                        player.status.health = 200;
+                    if player.status.health > misc_info().max_health {
+                        player.status.health = misc_info().max_health;
                     }
                     player.message = Some(GOTHTHBONUS);
                 }
                 SpriteNum::BON2 => {
                     player.status.armorpoints += 1; // Go over 100%
-                    if player.status.armorpoints > 200 {
-                        player.status.armorpoints = 200;
+                    if player.status.armorpoints > misc_info().max_armor {
+                        player.status.armorpoints = misc_info().max_armor;
                     }
                     if player.status.armortype == 0 {
-                        player.status.armortype = 1;
+                        player.status.armortype = misc_info().green_armor_class;
                     }
                     player.message = Some(GOTARMBONUS);
                 }
                 SpriteNum::SOUL => {
-                    player.status.health += 100;
-                    if player.status.health > 200 {
-                        player.status.health = 200;
+                    player.status.health += misc_info().soulsphere_health;
+                    if player.status.health > misc_info().max_soulsphere {
+                        player.status.health = misc_info().max_soulsphere;
                     }
                     player.message = Some(GOTSUPER);
                     sound = SfxName::Getpow;
                 }
                 SpriteNum::MEGA => {
                     // TODO: if (gamemode != commercial) return;
-                    player.status.health = 200;
-                    player.give_armour(2);
+                    player.status.health = misc_info().megasphere_health;
+                    player.give_armour(misc_info().blue_armor_class);
                     player.message = Some(GOTMSPHERE);
                     sound = SfxName::Getpow;
                 }
@@ -334,42 +360,60 @@ impl MapObject {
                         player.message = Some(GOTBLUECARD);
                     }
                     player.give_key(Card::Bluecard);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::YKEY => {
                     if !player.status.cards[Card::Yellowcard as usize] {
                         player.message = Some(GOTYELWCARD);
                     }
                     player.give_key(Card::Yellowcard);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::RKEY => {
                     if !player.status.cards[Card::Redcard as usize] {
                         player.message = Some(GOTREDCARD);
                     }
                     player.give_key(Card::Redcard);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::BSKU => {
                     if !player.status.cards[Card::Blueskull as usize] {
                         player.message = Some(GOTBLUESKUL);
                     }
                     player.give_key(Card::Blueskull);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::YSKU => {
                     if !player.status.cards[Card::Yellowskull as usize] {
                         player.message = Some(GOTYELWSKUL);
                     }
                     player.give_key(Card::Yellowskull);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::RSKU => {
                     if !player.status.cards[Card::Redskull as usize] {
                         player.message = Some(GOTREDSKULL);
                     }
                     player.give_key(Card::Redskull);
-                    // TODO: if (netgame) return;
+                    // Keys stay for the other players in a netgame
+                    if options.netgame {
+                        return;
+                    }
                 }
                 SpriteNum::STIM => {
                     if !player.give_body(10) {
@@ -503,7 +547,7 @@ impl MapObject {
 
                 // Weapons
                 SpriteNum::BFUG => {
-                    if !player.give_weapon(WeaponType::BFG, false, skill) {
+                    if !player.give_weapon(WeaponType::BFG, false, options) {
                         return;
                     }
                     player.message = Some(GOTBFG9000);
@@ -513,7 +557,7 @@ impl MapObject {
                     if !player.give_weapon(
                         WeaponType::Chaingun,
                         special.flags.contains(MapObjFlag::Dropped),
-                        skill,
+                        options,
                     ) {
                         return;
                     }
@@ -521,21 +565,21 @@ impl MapObject {
                     sound = SfxName::Wpnup;
                 }
                 SpriteNum::CSAW => {
-                    if !player.give_weapon(WeaponType::Chainsaw, false, skill) {
+                    if !player.give_weapon(WeaponType::Chainsaw, false, options) {
                         return;
                     }
                     player.message = Some(GOTCHAINSAW);
                     sound = SfxName::Wpnup;
                 }
                 SpriteNum::LAUN => {
-                    if !player.give_weapon(WeaponType::Missile, false, skill) {
+                    if !player.give_weapon(WeaponType::Missile, false, options) {
                         return;
                     }
                     player.message = Some(GOTLAUNCHER);
                     sound = SfxName::Wpnup;
                 }
                 SpriteNum::PLAS => {
-                    if !player.give_weapon(WeaponType::Plasma, false, skill) {
+                    if !player.give_weapon(WeaponType::Plasma, false, options) {
                         return;
                     }
                     player.message = Some(GOTPLASMA);
@@ -545,7 +589,7 @@ impl MapObject {
                     if !player.give_weapon(
                         WeaponType::Shotgun,
                         special.flags.contains(MapObjFlag::Dropped),
-                        skill,
+                        options,
                     ) {
                         return;
                     }
@@ -556,7 +600,7 @@ impl MapObject {
                     if !player.give_weapon(
                         WeaponType::SuperShotgun,
                         special.flags.contains(MapObjFlag::Dropped),
-                        skill,
+                        options,
                     ) {
                         return;
                     }
@@ -0,0 +1,569 @@
+//! MBF21 actor codepointers.
+//!
+//! Parameterised actions that DEHACKED patches declaring `Doom version = 2021`
+//! can assign to any frame. Arguments come from the frame's `args`; angles and
+//! distances are 16.16 fixed point as in the spec.
+//!
+//! Doom source name `p_enemy` (MBF21 additions)
+
+use sound_common::SfxName;
+use std::ptr;
+
+use crate::doom_def::MISSILERANGE;
+use crate::info::{MapObjKind, StateNum};
+use crate::thing::enemy::{a_chase, a_facetarget, heal_corpse, noise_alert_from};
+use crate::thing::{MapObjFlag, MapObjFlag2, MapObject};
+use crate::thinker::Thinker;
+use math::{
+    ANG90, ANG180, Angle, Bam, FixedT, fine_tan, p_aprox_distance, p_random, r_point_to_angle,
+};
+
+const MAPBLOCKSHIFT: i32 = 23;
+
+/// Degrees in 16.16 fixed point to a BAM angle. Only valid for positive
+/// input, as in the spec.
+pub(crate) fn fixed_to_angle(a: i32) -> u32 {
+    (((a as i64) << 16) / 360) as u32
+}
+
+/// Approximate the slope of a signed BAM angle, positive up, using the
+/// finetangent table. Steeper than straight up or down clamps to the ends.
+fn angle_to_slope(a: i32) -> FixedT {
+    if a >= ANG90 as i32 {
+        fine_tan(4095)
+    } else if -(a as i64) > ANG90 as i64 {
+        fine_tan(0)
+    } else {
+        fine_tan(((ANG90 as i64 + a as i64) as u32 >> 19) as usize)
+    }
+}
+
+/// Slope of a pitch given in 16.16 fixed point degrees.
+pub(crate) fn deg_to_slope(a: i32) -> FixedT {
+    if a >= 0 {
+        angle_to_slope(fixed_to_angle(a) as i32)
+    } else {
+        angle_to_slope(-(fixed_to_angle(-a) as i32))
+    }
+}
+
+/// A random BAM offset within `spread` degrees either side. Consumes two
+/// random numbers.
+pub(crate) fn random_hitscan_angle(spread: i32) -> u32 {
+    let spread_bam = fixed_to_angle(spread.wrapping_abs()) as i64;
+    let t = p_random();
+    ((spread_bam * (t - p_random()) as i64) / 255) as u32
+}
+
+/// A random slope offset within `spread` degrees either side.
+pub(crate) fn random_hitscan_slope(spread: i32) -> FixedT {
+    angle_to_slope(random_hitscan_angle(spread) as i32)
+}
+
+/// `(P_Random() % dice + 1) * base`, treating a zero die as one-sided.
+pub(crate) fn random_damage(base: i32, dice: i32) -> i32 {
+    (p_random() % dice.max(1) + 1) * base
+}
+
+/// A state index from a codepointer argument, if it names a valid frame.
+pub(crate) fn arg_state(arg: i32) -> Option<StateNum> {
+    (0..StateNum::Count as i32)
+        .contains(&arg)
+        .then(|| StateNum::from(arg as u16))
+}
+
+/// A sound from a codepointer argument. Out of range numbers play nothing.
+pub(crate) fn arg_sound(arg: i32) -> SfxName {
+    u8::try_from(arg)
+        .ok()
+        .and_then(|n| SfxName::try_from(n).ok())
+        .unwrap_or(SfxName::None)
+}
+
+/// A thing type from a codepointer argument. Types are 1-based, as DEHACKED
+/// numbers them.
+pub(crate) fn arg_kind(arg: i32) -> Option<MapObjKind> {
+    (1..=MapObjKind::Count as i32)
+        .contains(&arg)
+        .then(|| MapObjKind::from((arg - 1) as u16))
+}
+
+/// P_CheckFov: is `other` within `fov` of the direction `this` faces.
+fn check_fov(this: &MapObject, other: &MapObject, fov: u32) -> bool {
+    let angle = r_point_to_angle(other.x - this.x, other.y - this.y);
+    let min = this.angle.to_bam().wrapping_sub(fov / 2);
+    let max = this.angle.to_bam().wrapping_add(fov / 2);
+    if min > max {
+        angle >= min || angle <= max
+    } else {
+        angle >= min && angle <= max
+    }
+}
+
+/// Rotate the offset (`x`, `y`) by the fine angle of `bam`.
+fn rotate(x: i32, y: i32, bam: u32) -> (FixedT, FixedT) {
+    let x = FixedT::from_fixed(x);
+    let y = FixedT::from_fixed(y);
+    let cos = FixedT::cos_bam(bam);
+    let sin = FixedT::sin_bam(bam);
+    (
+        x.fixed_mul(cos) - y.fixed_mul(sin),
+        x.fixed_mul(sin) + y.fixed_mul(cos),
+    )
+}
+
+/// Turn a freshly spawned missile by `angle` degrees, pitch it by `pitch`
+/// degrees and shift it sideways by `offset_xy` relative to `facing` and up
+/// by `offset_z`. Shared with the player's `A_WeaponProjectile`.
+pub(crate) fn adjust_missile(
+    missile: &mut MapObject,
+    facing: Angle<Bam>,
+    angle: i32,
+    pitch: i32,
+    offset_xy: i32,
+    offset_z: i32,
+) {
+    missile.angle = Angle::from_bam(missile.angle.to_bam().wrapping_add(fixed_to_angle(angle)));
+    let bam = missile.angle.to_bam();
+    let speed = FixedT::from_fixed(missile.info.speed);
+    missile.momx = speed.fixed_mul(FixedT::cos_bam(bam));
+    missile.momy = speed.fixed_mul(FixedT::sin_bam(bam));
+    missile.momz += speed.fixed_mul(deg_to_slope(pitch));
+
+    let side = facing.to_bam().wrapping_sub(ANG90);
+    let offset = FixedT::from_fixed(offset_xy);
+    unsafe {
+        missile.unset_thing_position();
+        missile.x += offset.fixed_mul(FixedT::cos_bam(side));
+        missile.y += offset.fixed_mul(FixedT::sin_bam(side));
+        missile.z += FixedT::from_fixed(offset_z);
+        missile.set_thing_position();
+    }
+}
+
+/// A_SpawnObject(type, angle, x, y, z, momx, momy, momz)
+pub(crate) fn a_spawnobject(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let Some(kind) = arg_kind(args[0]) else {
+        return;
+    };
+
+    let an = actor.angle.to_bam().wrapping_add(fixed_to_angle(args[1]));
+    let (dx, dy) = rotate(args[2], args[3], an);
+
+    let level = unsafe { &mut *actor.level };
+    let mobj = MapObject::spawn_map_object(
+        actor.x + dx,
+        actor.y + dy,
+        actor.z + FixedT::from_fixed(args[4]),
+        kind,
+        level,
+    );
+    let mobj = unsafe { &mut *mobj };
+    mobj.angle = Angle::from_bam(an);
+
+    let (momx, momy) = rotate(args[5], args[6], an);
+    mobj.momx = momx;
+    mobj.momy = momy;
+    mobj.momz = FixedT::from_fixed(args[7]);
+
+    if mobj.info.flags.contains(MapObjFlag::Missile) {
+        if actor.info.flags.contains(MapObjFlag::Missile) {
+            // A missile spawning missiles passes on its owner and seek target
+            mobj.target = actor.target;
+            mobj.tracer = actor.tracer;
+        } else {
+            mobj.target = Some(actor.thinker);
+            mobj.tracer = actor.target;
+        }
+    }
+}
+
+/// A_MonsterProjectile(type, angle, pitch, hoffset, voffset)
+pub(crate) fn a_monsterprojectile(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let (Some(target), Some(kind)) = (actor.target, arg_kind(args[0])) else {
+        return;
+    };
+    let target = unsafe { (*target).mobj_mut() };
+
+    a_facetarget(actor);
+    let level = unsafe { &mut *actor.level };
+    let missile = MapObject::spawn_missile(actor, target, kind, level);
+    adjust_missile(missile, actor.angle, args[1], args[2], args[3], args[4]);
+    // Always set the tracer so seeker missiles can be fired at will
+    missile.tracer = actor.target;
+}
+
+/// A_MonsterBulletAttack(hspread, vspread, numbullets, damagebase, damagedice)
+pub(crate) fn a_monsterbulletattack(actor: &mut MapObject) {
+    if actor.target.is_none() {
+        return;
+    }
+    let args = actor.state.args;
+
+    a_facetarget(actor);
+    actor.start_sound(actor.info.attacksound);
+
+    let distance: FixedT = MISSILERANGE.into();
+    let aim_slope = actor
+        .aim_line_attack(distance)
+        .map(|res| res.aimslope)
+        .unwrap_or(FixedT::ZERO);
+
+    for _ in 0..args[2] {
+        let damage = random_damage(args[3], args[4]);
+        let angle = actor
+            .angle
+            .to_bam()
+            .wrapping_add(random_hitscan_angle(args[0]));
+        let slope = aim_slope + random_hitscan_slope(args[1]);
+        actor.shoot_line_attack(distance, Angle::from_bam(angle), slope, damage);
+    }
+}
+
+/// A_MonsterMeleeAttack(damagebase, damagedice, sound, range)
+pub(crate) fn a_monstermeleeattack(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let Some(target) = actor.target else {
+        return;
+    };
+    let target = unsafe { (*target).mobj_mut() };
+
+    let range = if args[3] == 0 {
+        FixedT::from(actor.info.meleerange)
+    } else {
+        FixedT::from_fixed(args[3])
+    };
+    let range = range + target.radius - 20;
+
+    a_facetarget(actor);
+    if !actor.check_range(range) {
+        return;
+    }
+
+    actor.start_sound(arg_sound(args[2]));
+    let damage = random_damage(args[0], args[1]);
+    target.p_take_damage(Some((actor.x, actor.y, actor.z)), Some(actor), damage);
+}
+
+/// A_RadiusDamage(damage, radius)
+pub(crate) fn a_radiusdamage(actor: &mut MapObject) {
+    let args = actor.state.args;
+    actor.radius_attack_distance(args[0], args[1]);
+}
+
+/// A_NoiseAlert: wake monsters that can hear the actor, aimed at its target.
+pub(crate) fn a_noisealert(actor: &mut MapObject) {
+    if let Some(target) = actor.target {
+        let target = unsafe { (*target).mobj_mut() };
+        noise_alert_from(target, actor.subsector.sector.clone());
+    }
+}
+
+/// A_HealChase(state, sound)
+pub(crate) fn a_healchase(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let Some(state) = arg_state(args[0]) else {
+        return;
+    };
+    if !heal_corpse(actor, state, arg_sound(args[1])) {
+        a_chase(actor);
+    }
+}
+
+/// A_SeekTracer(threshold, maxturnangle)
+///
+/// Doom function name `P_SeekerMissile` with `seekcenter` set.
+pub(crate) fn a_seektracer(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let threshold = fixed_to_angle(args[0]);
+    let max_turn = fixed_to_angle(args[1]);
+
+    let Some(tracer) = actor.tracer else {
+        return;
+    };
+    let target = unsafe { (*tracer).mobj() };
+    if !target.flags.contains(MapObjFlag::Shootable) {
+        // Target died
+        actor.tracer = None;
+        return;
+    }
+
+    // P_FaceMobj
+    let facing = actor.angle.to_bam();
+    let exact = r_point_to_angle(target.x - actor.x, target.y - actor.y);
+    let (mut delta, clockwise) = if exact > facing {
+        let diff = exact - facing;
+        if diff > ANG180 {
+            (u32::MAX - diff, false)
+        } else {
+            (diff, true)
+        }
+    } else {
+        let diff = facing - exact;
+        if diff > ANG180 {
+            (u32::MAX - diff, true)
+        } else {
+            (diff, false)
+        }
+    };
+
+    if delta > threshold {
+        delta >>= 1;
+        if delta > max_turn {
+            delta = max_turn;
+        }
+    }
+    let bam = if clockwise {
+        facing.wrapping_add(delta)
+    } else {
+        facing.wrapping_sub(delta)
+    };
+    actor.angle = Angle::from_bam(bam);
+
+    let speed = FixedT::from_fixed(actor.info.speed);
+    actor.momx = speed.fixed_mul(FixedT::cos_bam(bam));
+    actor.momy = speed.fixed_mul(FixedT::sin_bam(bam));
+
+    // OG: dist = P_AproxDistance(...) / speed (plain int div)
+    let adist = p_aprox_distance(target.x - actor.x, target.y - actor.y);
+    let dist = (adist.to_fixed_raw() / actor.info.speed.max(1)).max(1);
+    let dz = target.z + target.height.shr(1) - actor.z;
+    actor.momz = FixedT::from_fixed(dz.to_fixed_raw() / dist);
+}
+
+/// A_FindTracer(fov, rangeblocks)
+pub(crate) fn a_findtracer(actor: &mut MapObject) {
+    if actor.tracer.is_some() {
+        return;
+    }
+    let args = actor.state.args;
+    let fov = fixed_to_angle(args[0]);
+    actor.tracer = rough_target_search(actor, fov, args[1]);
+}
+
+/// P_RoughTargetSearch: search blockmap cells in growing squares around
+/// `actor` for the first suitable tracer target.
+fn rough_target_search(actor: &mut MapObject, fov: u32, distance: i32) -> Option<*mut Thinker> {
+    let level = unsafe { &*actor.level };
+    let bm = level.level_data.blockmap();
+    let (columns, rows) = (bm.columns, bm.rows);
+    let start_x = (actor.x.to_fixed_raw() - bm.x_origin) >> MAPBLOCKSHIFT;
+    let start_y = (actor.y.to_fixed_raw() - bm.y_origin) >> MAPBLOCKSHIFT;
+
+    let mut check = |bx: i32, by: i32| {
+        if bx < 0 || by < 0 || bx >= columns || by >= rows {
+            return None;
+        }
+        rough_block_check(actor, (by * columns + bx) as usize, fov)
+    };
+
+    if let Some(found) = check(start_x, start_y) {
+        return Some(found);
+    }
+    for count in 1..=distance {
+        let min_x = (start_x - count).clamp(0, columns - 1);
+        let max_x = (start_x + count).clamp(0, columns - 1);
+        let min_y = (start_y - count).clamp(0, rows - 1);
+        let max_y = (start_y + count).clamp(0, rows - 1);
+
+        // Top and bottom rows
+        for bx in min_x..=max_x {
+            if let Some(found) = check(bx, min_y).or_else(|| check(bx, max_y)) {
+                return Some(found);
+            }
+        }
+        // Left and right columns
+        for by in (min_y + 1)..max_y {
+            if let Some(found) = check(min_x, by).or_else(|| check(max_x, by)) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// RoughBlockCheck: the first thing in a blockmap cell that `actor` could
+/// seek.
+fn rough_block_check(actor: &mut MapObject, index: usize, fov: u32) -> Option<*mut Thinker> {
+    let level = unsafe { &*actor.level };
+    let deathmatch = level.options.deathmatch != 0;
+    let mut link = level.blocklinks[index];
+    while let Some(ptr) = link {
+        let thing = unsafe { &*ptr };
+        link = thing.b_next;
+
+        // Skip things that can't be hurt, and the owner
+        if !thing.flags.contains(MapObjFlag::Shootable)
+            || actor.target.is_some_and(|t| ptr::eq(t, thing.thinker))
+        {
+            continue;
+        }
+        // Skip the owner's side (players or monsters; there are no friendly
+        // monsters) unless it is fighting that thing, or in deathmatch
+        if let Some(owner) = actor.target()
+            && !owner.target.is_some_and(|t| ptr::eq(t, thing.thinker))
+            && !(deathmatch && thing.player.is_some() && owner.player.is_some())
+            && thing.player.is_some() == owner.player.is_some()
+        {
+            continue;
+        }
+        if fov > 0 && !check_fov(actor, thing, fov) {
+            continue;
+        }
+        if !actor.check_sight_target(thing) {
+            continue;
+        }
+        return Some(thing.thinker);
+    }
+    None
+}
+
+/// A_ClearTracer
+pub(crate) fn a_cleartracer(actor: &mut MapObject) {
+    actor.tracer = None;
+}
+
+/// A_JumpIfHealthBelow(state, health)
+pub(crate) fn a_jumpifhealthbelow(actor: &mut MapObject) {
+    let args = actor.state.args;
+    if let Some(state) = arg_state(args[0])
+        && actor.health < args[1]
+    {
+        actor.set_state(state);
+    }
+}
+
+/// Jump to `state` if `other` is within `fov` and visible.
+fn jump_if_in_sight(actor: &mut MapObject, other: Option<*mut Thinker>) {
+    let args = actor.state.args;
+    let (Some(other), Some(state)) = (other, arg_state(args[0])) else {
+        return;
+    };
+    let other = unsafe { (*other).mobj() };
+    let fov = fixed_to_angle(args[1]);
+    if fov > 0 && !check_fov(actor, other, fov) {
+        return;
+    }
+    if actor.check_sight_target(other) {
+        actor.set_state(state);
+    }
+}
+
+/// Jump to `state` if `other` is closer than the distance argument.
+fn jump_if_closer(actor: &mut MapObject, other: Option<*mut Thinker>) {
+    let args = actor.state.args;
+    let (Some(other), Some(state)) = (other, arg_state(args[0])) else {
+        return;
+    };
+    let other = unsafe { (*other).mobj() };
+    let dist = p_aprox_distance(actor.x - other.x, actor.y - other.y);
+    if FixedT::from_fixed(args[1]) > dist {
+        actor.set_state(state);
+    }
+}
+
+/// A_JumpIfTargetInSight(state, fov)
+pub(crate) fn a_jumpiftargetinsight(actor: &mut MapObject) {
+    let target = actor.target;
+    jump_if_in_sight(actor, target);
+}
+
+/// A_JumpIfTargetCloser(state, distance)
+pub(crate) fn a_jumpiftargetcloser(actor: &mut MapObject) {
+    let target = actor.target;
+    jump_if_closer(actor, target);
+}
+
+/// A_JumpIfTracerInSight(state, fov)
+pub(crate) fn a_jumpiftracerinsight(actor: &mut MapObject) {
+    let tracer = actor.tracer;
+    jump_if_in_sight(actor, tracer);
+}
+
+/// A_JumpIfTracerCloser(state, distance)
+pub(crate) fn a_jumpiftracercloser(actor: &mut MapObject) {
+    let tracer = actor.tracer;
+    jump_if_closer(actor, tracer);
+}
+
+/// A_JumpIfFlagsSet(state, flags, flags2)
+pub(crate) fn a_jumpifflagsset(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let flags = MapObjFlag::from_bits_retain(args[1] as u32);
+    let flags2 = MapObjFlag2::from_bits_retain(args[2] as u32);
+    if let Some(state) = arg_state(args[0])
+        && actor.flags.contains(flags)
+        && actor.flags2.contains(flags2)
+    {
+        actor.set_state(state);
+    }
+}
+
+/// Set or clear flags, relinking the thing if that changes whether it
+/// belongs in the blockmap or sector lists.
+fn change_flags(actor: &mut MapObject, flags: MapObjFlag, flags2: MapObjFlag2, add: bool) {
+    let links = MapObjFlag::Noblockmap | MapObjFlag::Nosector;
+    let relink = if add {
+        !(flags & links).difference(actor.flags).is_empty()
+    } else {
+        actor.flags.intersects(flags & links)
+    };
+
+    if relink {
+        unsafe { actor.unset_thing_position() };
+    }
+    actor.flags.set(flags, add);
+    actor.flags2.set(flags2, add);
+    if relink {
+        unsafe { actor.set_thing_position() };
+    }
+}
+
+/// A_AddFlags(flags, flags2)
+pub(crate) fn a_addflags(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let flags = MapObjFlag::from_bits_retain(args[0] as u32);
+    let flags2 = MapObjFlag2::from_bits_retain(args[1] as u32);
+    change_flags(actor, flags, flags2, true);
+}
+
+/// A_RemoveFlags(flags, flags2)
+pub(crate) fn a_removeflags(actor: &mut MapObject) {
+    let args = actor.state.args;
+    let flags = MapObjFlag::from_bits_retain(args[0] as u32);
+    let flags2 = MapObjFlag2::from_bits_retain(args[1] as u32);
+    change_flags(actor, flags, flags2, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_degrees_to_bam() {
+        assert_eq!(fixed_to_angle(90 << 16), ANG90);
+        assert_eq!(fixed_to_angle(180 << 16), ANG180);
+        assert_eq!(fixed_to_angle(0), 0);
+    }
+
+    #[test]
+    fn level_pitch_is_flat() {
+        // finetangent has no exact zero; the middle entry is 25/65536
+        assert!(deg_to_slope(0).doom_abs() < FixedT::from_fixed(64));
+        let up = deg_to_slope(45 << 16);
+        assert!((up - FixedT::ONE).doom_abs() < FixedT::from_fixed(64));
+        assert!(deg_to_slope(-(45 << 16)) < FixedT::ZERO);
+    }
+
+    #[test]
+    fn arg_ranges() {
+        assert_eq!(arg_kind(0), None);
+        assert_eq!(arg_kind(1), Some(MapObjKind::MT_PLAYER));
+        assert_eq!(arg_state(-1), None);
+        assert_eq!(arg_state(StateNum::Count as i32), None);
+        assert_eq!(arg_sound(-1), SfxName::None);
+        assert_eq!(arg_sound(1), SfxName::Pistol);
+    }
+}
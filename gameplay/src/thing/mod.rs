@@ -9,6 +9,7 @@ mod movement;
 pub use movement::*;
 use sound_common::SfxName;
 pub(crate) mod enemy;
+pub(crate) mod mbf21;
 mod shooting;
 
 use bitflags::bitflags;
@@ -16,6 +17,7 @@ use std::fmt::Debug;
 use std::ptr::{self, null_mut};
 
 use self::movement::SubSectorMinMax;
+use self::shooting::AimResult;
 
 use crate::SectorExt as _;
 use crate::doom_def::{MELEERANGE, MTF_SINGLE_PLAYER};
@@ -27,10 +29,13 @@ use log::{debug, error, trace, warn};
 use math::{Bam, FixedT, p_aprox_distance, r_point_to_angle};
 use wad::types::WadThing;
 
-use crate::doom_def::{MAXPLAYERS, MTF_AMBUSH, ONCEILINGZ, ONFLOORZ, TICRATE, VIEWHEIGHT};
-use crate::info::{MOBJINFO, MapObjInfo, MapObjKind, STATES, SpriteNum, StateData, StateNum};
+use crate::doom_def::{
+    BODYQUESIZE, MAXPLAYERS, MTF_AMBUSH, ONCEILINGZ, ONFLOORZ, TICRATE, VIEWHEIGHT,
+};
+use crate::info::{MapObjInfo, MapObjKind, SpriteNum, StateData, StateNum};
 use crate::maputl::BestSlide;
 use crate::player::{Player, PlayerState};
+use crate::tables::{mobj_info, state_index, states};
 use level::map_defs::SubSector;
 use math::{ANG45, Angle, p_random, p_subrandom};
 
@@ -94,12 +99,60 @@ bitflags! {
         const Notdmatch = 0x2000000;
         /// Player color translation table bits (bits 26-27).
         const Translation = 0xC000000;
+        /// BOOM: draw through the translucency map (`TRANMAP`).
+        const Translucent = 0x8000_0000;
     }
 }
 
 /// Bit shift for the Translation color field within MapObjFlag.
 pub const TRANSSHIFT: u32 = 26;
 
+bitflags! {
+    /// MBF21 `flags2`. The vanilla special cases for particular monsters are
+    /// expressed as these so DEHACKED can move them to other things.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct MapObjFlag2: u32 {
+        /// Falls at 1/8 gravity.
+        const Lograv = 0x1;
+        /// Short missile range (archvile).
+        const Shortmrange = 0x2;
+        /// Other monsters won't retaliate against its attacks (archvile).
+        const Dmgignored = 0x4;
+        /// Immune to splash damage (cyberdemon, mastermind).
+        const Noradiusdmg = 0x8;
+        /// Its explosions hurt things with `Noradiusdmg`.
+        const Forceradiusdmg = 0x10;
+        /// Fires missiles more often (cyberdemon).
+        const Highermprob = 0x20;
+        /// Halves distance when deciding to fire missiles.
+        const Rangehalf = 0x40;
+        /// Switches targets without waiting out a threshold (archvile).
+        const Nothreshold = 0x80;
+        /// Long melee range: only fires missiles from afar (revenant).
+        const Longmelee = 0x100;
+        /// Full volume see/death sounds and splash immunity.
+        const Boss = 0x200;
+        /// Death lowers tag 666 floors on MAP07 (mancubus).
+        const Map07boss1 = 0x400;
+        /// Death raises tag 667 floors on MAP07 (arachnotron).
+        const Map07boss2 = 0x800;
+        /// E1M8 boss (baron).
+        const E1m8boss = 0x1000;
+        /// E2M8 boss (cyberdemon).
+        const E2m8boss = 0x2000;
+        /// E3M8 boss (mastermind).
+        const E3m8boss = 0x4000;
+        /// E4M6 boss (cyberdemon).
+        const E4m6boss = 0x8000;
+        /// E4M8 boss (mastermind).
+        const E4m8boss = 0x10000;
+        /// Projectile rips through things instead of exploding on them.
+        const Rip = 0x20000;
+        /// Full volume see/death sounds (cyberdemon, mastermind).
+        const Fullvolsounds = 0x40000;
+    }
+}
+
 pub struct MapObject {
     /// `MapObject` is owned by the `Thinker`. If the `MapObject` is ever moved
     /// out of the `Thinker` then you must update sector thing lists and self
@@ -165,6 +218,7 @@ pub struct MapObject {
     //  using the enum as the indexer
     pub state: &'static StateData,
     pub flags: MapObjFlag,
+    pub flags2: MapObjFlag2,
     pub health: i32,
     /// Movement direction, movement generation (zig-zagging).
     /// 0-7
@@ -183,6 +237,10 @@ pub struct MapObject {
     /// If >0, the target will be chased
     /// no matter what (even if shot)
     pub(crate) threshold: i32,
+    /// BOOM: momentum multiplier for the next tic, set by friction sectors
+    pub(crate) friction: FixedT,
+    /// BOOM: player thrust multiplier while on a friction sector
+    pub(crate) movefactor: i32,
     /// Additional info record for player avatars only. Only valid if type ==
     /// MT_PLAYER. RUST: If this is not `None` then the pointer is
     /// guaranteed to point to a player
@@ -221,11 +279,14 @@ impl MapObject {
         tics: i32,
         state: &'static StateData,
         flags: MapObjFlag,
+        flags2: MapObjFlag2,
         health: i32,
         movedir: MoveDir,
         movecount: i32,
         reactiontime: i32,
         threshold: i32,
+        friction: i32,
+        movefactor: i32,
         lastlook: usize,
         spawnpoint: WadThing,
         level: *mut LevelState,
@@ -261,12 +322,15 @@ impl MapObject {
             tics,
             state,
             flags,
+            flags2,
             health,
             movedir,
             movecount,
             best_slide: BestSlide::default(),
             reactiontime,
             threshold,
+            friction: FixedT::from_fixed(friction),
+            movefactor,
             target: None,
             tracer: None,
             player: None,
@@ -299,6 +363,7 @@ impl Debug for MapObject {
             .field("tics", &self.tics)
             .field("state", &self.state)
             .field("flags", &self.flags)
+            .field("flags2", &self.flags2)
             .field("health", &self.health)
             .field("movecount", &self.movecount)
             .field("reactiontime", &self.reactiontime)
@@ -342,6 +407,7 @@ impl MapObject {
             momz: FixedT::ZERO,
             valid_count: 0,
             flags: info.flags,
+            flags2: info.flags2,
             health: info.spawnhealth,
             tics: state.tics,
             movedir: MoveDir::East,
@@ -349,6 +415,8 @@ impl MapObject {
             best_slide: BestSlide::default(),
             reactiontime,
             threshold: 0,
+            friction: FixedT::from_fixed(FRICTION),
+            movefactor: ORIG_FRICTION_FACTOR,
             lastlook: p_random() as usize % MAXPLAYERS,
             spawnpoint: WadThing::default(),
             target: None,
@@ -392,7 +460,7 @@ impl MapObject {
 
     /// State table index (for trace comparison with OG Doom).
     pub fn state_index(&self) -> usize {
-        (ptr::from_ref(self.state) as usize - STATES.as_ptr() as usize) / size_of::<StateData>()
+        state_index(self.state)
     }
 
     /// State tics remaining.
@@ -552,12 +620,10 @@ impl MapObject {
         // // setup gun psprite
         // TODO: P_SetupPsprites(p);
 
-        // // give all cards in death match mode
-        // if deathmatch {
-        //     for i in 0..Card::NUMCARDS as usize {
-        //         p.cards[i] = true;
-        //     }
-        // }
+        // Give all cards in death match mode
+        if level.options.deathmatch != 0 {
+            player.status.cards.fill(true);
+        }
 
         // if mthing.kind - 1 == consoleplayer {
         //     // wake up the status bar
@@ -567,6 +633,148 @@ impl MapObject {
         // }
     }
 
+    /// Can `player_num` spawn at `mthing` without being stuck in something?
+    /// On a respawn the old corpse goes in the body queue and a teleport fog
+    /// marks the spot.
+    ///
+    /// Doom function name `G_CheckSpot`
+    fn check_spot(
+        player_num: usize,
+        mthing: &WadThing,
+        level: &mut LevelState,
+        players: &mut [Player],
+    ) -> bool {
+        let x = FixedT::from(mthing.x as i32);
+        let y = FixedT::from(mthing.y as i32);
+        let Some(corpse) = players[player_num].mobj_raw() else {
+            // First spawn of the level, only avoid the players placed so far
+            return players[..player_num]
+                .iter()
+                .filter_map(Player::mobj)
+                .all(|mobj| mobj.x != x || mobj.y != y);
+        };
+        if !unsafe { &mut *corpse }.p_check_position(x, y, &mut SubSectorMinMax::default()) {
+            return false;
+        }
+
+        // Flush an old corpse if needed
+        if level.body_queue.len() >= BODYQUESIZE
+            && let Some(old) = level.body_queue.pop_front()
+        {
+            unsafe { (*old).remove() };
+        }
+        level.body_queue.push_back(corpse);
+
+        let floor = level.level_data.point_in_subsector(x, y).sector.floorheight;
+        let bam = ANG45.wrapping_mul((mthing.angle as u32) / 45);
+        let fog = Self::spawn_map_object(
+            x + FixedT::from(20).fixed_mul(FixedT::cos_bam(bam)),
+            y + FixedT::from(20).fixed_mul(FixedT::sin_bam(bam)),
+            floor,
+            MapObjKind::MT_TFOG,
+            level,
+        );
+        unsafe { (*fog).start_sound(SfxName::Telept) };
+        true
+    }
+
+    /// Spawn `player_num` at a random free deathmatch start, or at their own
+    /// start if twenty tries find none.
+    ///
+    /// Doom function name `G_DeathMatchSpawnPlayer`
+    fn deathmatch_spawn_player(
+        player_num: usize,
+        level: &mut LevelState,
+        players: &mut [Player],
+        active_players: &[bool; MAXPLAYERS],
+    ) {
+        let selections = level.deathmatch_p.len();
+        if selections < 4 {
+            warn!("Only {selections} deathmatch spots, 4 required");
+        }
+        if selections != 0 {
+            for _ in 0..20 {
+                let mut spot = level.deathmatch_p[p_random() as usize % selections];
+                if Self::check_spot(player_num, &spot, level, players) {
+                    spot.kind = player_num as i16 + 1;
+                    Self::p_spawn_player(&spot, level, players, active_players);
+                    return;
+                }
+            }
+        }
+
+        // No good spot, so the player will probably get stuck
+        if let Some(start) = level.player_starts[player_num] {
+            Self::p_spawn_player(&start, level, players, active_players);
+        }
+    }
+
+    /// Place every player at a random deathmatch start once the map's things
+    /// are in.
+    ///
+    /// Doom function name `P_SetupLevel` (deathmatch)
+    pub fn spawn_deathmatch_players(
+        level: &mut LevelState,
+        players: &mut [Player],
+        active_players: &[bool; MAXPLAYERS],
+    ) {
+        for (player, _) in players
+            .iter_mut()
+            .zip(active_players)
+            .filter(|(_, active)| **active)
+        {
+            player.clear_mobj();
+        }
+        for player_num in (0..MAXPLAYERS).filter(|&i| active_players[i]) {
+            Self::deathmatch_spawn_player(player_num, level, players, active_players);
+        }
+    }
+
+    /// Respawn a dead player in a netgame without reloading the level,
+    /// leaving their corpse behind. Deathmatch picks a random deathmatch
+    /// start, co-op the player's own start or else any free player start.
+    ///
+    /// Doom function name `G_DoReborn` (netgame)
+    pub fn net_reborn_player(
+        player_num: usize,
+        level: &mut LevelState,
+        players: &mut [Player],
+        active_players: &[bool; MAXPLAYERS],
+    ) {
+        // Dissociate the corpse
+        if let Some(mobj) = players[player_num].mobj_mut() {
+            mobj.player = None;
+        }
+
+        if level.options.deathmatch != 0 {
+            Self::deathmatch_spawn_player(player_num, level, players, active_players);
+            return;
+        }
+
+        if let Some(start) = level.player_starts[player_num]
+            && Self::check_spot(player_num, &start, level, players)
+        {
+            Self::p_spawn_player(&start, level, players, active_players);
+            return;
+        }
+
+        // Try to spawn at one of the other players' spots
+        for i in 0..MAXPLAYERS {
+            if let Some(mut start) = level.player_starts[i]
+                && Self::check_spot(player_num, &start, level, players)
+            {
+                start.kind = player_num as i16 + 1;
+                Self::p_spawn_player(&start, level, players, active_players);
+                return;
+            }
+        }
+
+        // Probably going to get stuck
+        if let Some(start) = level.player_starts[player_num] {
+            Self::p_spawn_player(&start, level, players, active_players);
+        }
+    }
+
     /// P_SpawnMapThing
     pub fn p_spawn_map_thing(
         mthing: WadThing,
@@ -594,7 +802,7 @@ impl MapObject {
         }
 
         // check for appropriate skill level
-        if level.options.deathmatch == 0 && mthing.flags & MTF_SINGLE_PLAYER != 0 {
+        if !level.options.netgame && mthing.flags & MTF_SINGLE_PLAYER != 0 {
             return;
         }
         let bit: i16;
@@ -613,7 +821,7 @@ impl MapObject {
         // find which type to spawn
         let mut i = 0;
         for n in 0..MapObjKind::Count as u16 {
-            if mthing.kind == MOBJINFO[n as usize].doomednum as i16 {
+            if mthing.kind == mobj_info()[n as usize].doomednum as i16 {
                 i = n;
                 break;
             }
@@ -628,7 +836,9 @@ impl MapObject {
 
         // don't spawn keycards and players in deathmatch
         if level.options.deathmatch != 0
-            && MOBJINFO[i as usize].flags.contains(MapObjFlag::Notdmatch)
+            && mobj_info()[i as usize]
+                .flags
+                .contains(MapObjFlag::Notdmatch)
         {
             return;
         }
@@ -637,14 +847,16 @@ impl MapObject {
         let kind = MapObjKind::from(i);
         if no_monsters
             && (kind == MapObjKind::MT_SKULL
-                || MOBJINFO[i as usize].flags.contains(MapObjFlag::Countkill))
+                || mobj_info()[i as usize]
+                    .flags
+                    .contains(MapObjFlag::Countkill))
         {
             return;
         }
 
         let x = mthing.x as i32;
         let y = mthing.y as i32;
-        let z = if MOBJINFO[i as usize]
+        let z = if mobj_info()[i as usize]
             .flags
             .contains(MapObjFlag::Spawnceiling)
         {
@@ -671,7 +883,16 @@ impl MapObject {
             mobj.flags.insert(MapObjFlag::Ambush);
         }
 
-        mobj.spawnpoint = mthing;
+        mobj.set_spawnpoint(mthing);
+    }
+
+    /// Record the map thing this object spawned from, taking on its UDMF
+    /// translucency.
+    pub(crate) fn set_spawnpoint(&mut self, mthing: WadThing) {
+        if mthing.translucent {
+            self.flags.insert(MapObjFlag::Translucent);
+        }
+        self.spawnpoint = mthing;
     }
 
     /// A thinker for metal spark/puff, typically used for gun-strikes against
@@ -724,14 +945,15 @@ impl MapObject {
         }
     }
 
-    /// A thinker for shooty blowy things.
+    /// A thinker for shooty blowy things. Also returns the auto-aim result so
+    /// callers can use the `linetarget`.
     ///
     /// Doom function name is `P_SpawnPlayerMissile`
-    pub(crate) fn spawn_player_missile(
+    pub(crate) fn spawn_player_missile<'a>(
         source: &mut Self,
         kind: MapObjKind,
         level: &mut LevelState,
-    ) {
+    ) -> (&'a mut Self, Option<AimResult>) {
         // OG: aim from source (player) BEFORE spawning missile
         let orig_angle = source.angle;
         let mut an = orig_angle;
@@ -773,9 +995,11 @@ impl MapObject {
         mobj.momx = speed.fixed_mul(FixedT::cos_bam(bam));
         mobj.momy = speed.fixed_mul(FixedT::sin_bam(bam));
         mobj.momz = slope
+            .as_ref()
             .map(|s| speed.fixed_mul(s.aimslope))
             .unwrap_or(FixedT::ZERO);
         mobj.check_missile_spawn();
+        (mobj, slope)
     }
 
     /// A thinker for shooty blowy things.
@@ -859,7 +1083,7 @@ impl MapObject {
         kind: MapObjKind,
         level: &mut LevelState,
     ) -> *mut Self {
-        let info = MOBJINFO[kind as usize];
+        let info = mobj_info()[kind as usize];
         let reactiontime = if level.options.skill != Skill::Nightmare {
             info.reactiontime
         } else {
@@ -868,7 +1092,7 @@ impl MapObject {
 
         // do not set the state with P_SetMobjState,
         // because action routines can not be called yet
-        let state = &STATES[info.spawnstate as usize];
+        let state = &states()[info.spawnstate as usize];
 
         let mobj = Self::new(x, y, z, reactiontime, kind, info, state, level);
 
@@ -909,12 +1133,12 @@ impl MapObject {
         let mut state = state;
         loop {
             if matches!(state, StateNum::None) {
-                self.state = &STATES[StateNum::None as usize];
+                self.state = &states()[StateNum::None as usize];
                 self.remove();
                 return false;
             }
 
-            let st = &STATES[state as usize];
+            let st = &states()[state as usize];
             self.state = st;
             self.tics = st.tics;
             self.sprite = st.sprite;
@@ -939,20 +1163,14 @@ impl MapObject {
     /// # Safety
     /// Thing must have had a SubSector set on creation.
     pub(crate) unsafe fn unset_thing_position(&mut self) {
-        if !MOBJINFO[self.kind as usize]
-            .flags
-            .contains(MapObjFlag::Nosector)
-        {
+        if !self.flags.contains(MapObjFlag::Nosector) {
             let mut ss = self.subsector.clone();
             unsafe {
                 ss.sector.remove_from_thinglist(self.thinker_mut());
             }
         }
 
-        if !MOBJINFO[self.kind as usize]
-            .flags
-            .contains(MapObjFlag::Noblockmap)
-        {
+        if !self.flags.contains(MapObjFlag::Noblockmap) {
             // Unlink from blockmap chain
             if let Some(next) = self.b_next {
                 unsafe {
@@ -986,18 +1204,12 @@ impl MapObject {
     pub(crate) unsafe fn set_thing_position(&mut self) {
         let level = unsafe { &mut *self.level };
         let mut subsector = level.level_data.point_in_subsector(self.x, self.y);
-        if !MOBJINFO[self.kind as usize]
-            .flags
-            .contains(MapObjFlag::Nosector)
-        {
+        if !self.flags.contains(MapObjFlag::Nosector) {
             unsafe { subsector.sector.add_to_thinglist(self.thinker) }
         }
         self.subsector = subsector;
 
-        if !MOBJINFO[self.kind as usize]
-            .flags
-            .contains(MapObjFlag::Noblockmap)
-        {
+        if !self.flags.contains(MapObjFlag::Noblockmap) {
             let level = unsafe { &mut *self.level };
             let bm = level.level_data.blockmap();
             let bx = (self.x.to_fixed_raw() - bm.x_origin) >> MAPBLOCKSHIFT;
@@ -1021,10 +1233,10 @@ impl MapObject {
 
     /// P_RemoveMobj
     pub(crate) fn remove(&mut self) {
-        // Respawn specials for nightmare/deathmatch
+        // Respawn specials in altdeath
         if (self.flags.contains(MapObjFlag::Special) && !self.flags.contains(MapObjFlag::Dropped))
             && (self.kind != MapObjKind::MT_INV && self.kind != MapObjKind::MT_INS)
-            && (self.level().options.respawn_monsters || self.level().options.deathmatch != 0)
+            && self.level().options.deathmatch == 2
         {
             let time = self.level().level_time;
             let respawn = self.spawnpoint;
@@ -1124,6 +1336,13 @@ impl MapObject {
         }
     }
 
+    /// Play at full volume wherever the listener is (`S_StartSound(NULL, ..)`).
+    pub(crate) fn start_global_sound(&self, sfx: SfxName) {
+        unsafe {
+            (*self.level).start_sound(sfx, self.x.to_f32(), self.y.to_f32(), 0);
+        }
+    }
+
     /// P_NightmareRespawn
     pub fn nightmare_respawn(&mut self) {
         let sp_x = FixedT::from(self.spawnpoint.x as i32);
@@ -1158,7 +1377,7 @@ impl MapObject {
             &mut *Self::spawn_map_object(sp_x, sp_y, z.into(), self.kind, self.level_mut())
         };
         thing.angle = Angle::from_bam(ANG45.wrapping_mul((mthing.angle as u32) / 45));
-        thing.spawnpoint = mthing;
+        thing.set_spawnpoint(mthing);
         thing.reactiontime = 18;
         if mthing.flags & MTF_AMBUSH != 0 {
             self.flags.insert(MapObjFlag::Ambush);
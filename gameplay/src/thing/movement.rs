@@ -6,21 +6,23 @@ use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use std::ptr;
 
 use log::{debug, error};
+use sound_common::SfxName;
 
 use crate::doom_def::{FLOATSPEED, USERANGE, VIEWHEIGHT};
 use crate::env::specials::cross_special_line;
 use crate::env::switch::p_use_special_line;
-use crate::info::{STATES, StateData, StateNum};
+use crate::info::StateNum;
 use crate::maputl::{
     BestSlide, Intercept, PortalZ, box_on_line_side, path_traverse_blockmap, point_on_line_side,
 };
+use crate::tables::{misc_info, state_index};
 use crate::{MapObjKind, MapObject};
 use level::MapPtr;
 use level::flags::LineDefFlags;
 use level::map_defs::{LineDef, SlopeType};
-use math::{ANG180, Angle, AngleInner, FixedT, p_random, r_point_to_angle};
+use math::{ANG180, Angle, AngleInner, FixedT, p_aprox_distance, p_random, r_point_to_angle};
 
-use super::MapObjFlag;
+use super::{MapObjFlag, MapObjFlag2};
 
 const MAPBLOCKSHIFT: i32 = 23;
 /// BBox index: max Y
@@ -37,6 +39,10 @@ pub const GRAVITY: i32 = 0x10000;
 pub const MAXMOVE: i32 = 30 * 0x10000;
 pub const STOPSPEED: i32 = 0x1000;
 pub const FRICTION: i32 = 0xE800;
+/// BOOM: player thrust multiplier on normal floors
+pub const ORIG_FRICTION_FACTOR: i32 = 2048;
+/// BOOM: momentum above which mud gives better footing
+const MORE_FRICTION_MOMENTUM: i32 = 15000;
 /// FRACUNIT + 1 sentinel for "no slide hit found"
 const FRACUNIT_SENTINEL: i32 = 0x10001;
 /// 0x800 fudge factor to avoid re-hitting the wall
@@ -154,10 +160,16 @@ impl MapObject {
                 return;
             }
         } else if !self.flags.contains(MapObjFlag::Nogravity) {
+            // MBF21: low gravity things fall at 1/8 speed
+            let gravity = if self.flags2.contains(MapObjFlag2::Lograv) {
+                GRAVITY >> 3
+            } else {
+                GRAVITY
+            };
             if self.momz.is_zero() {
-                self.momz = FixedT::from_fixed(-GRAVITY * 2);
+                self.momz = FixedT::from_fixed(-gravity * 2);
             } else {
-                self.momz -= FixedT::from_fixed(GRAVITY);
+                self.momz -= FixedT::from_fixed(gravity);
             }
         }
 
@@ -297,8 +309,7 @@ impl MapObject {
             && (self.player.is_none() || pfwd == 0 && pside == 0)
         {
             if self.player().is_some() {
-                let state_idx = (ptr::from_ref(self.state) as usize - STATES.as_ptr() as usize)
-                    / size_of::<StateData>();
+                let state_idx = state_index(self.state);
                 let run1 = StateNum::PLAY_RUN1 as usize;
                 if state_idx >= run1 && state_idx < run1 + 4 {
                     self.set_state(StateNum::PLAY);
@@ -307,9 +318,34 @@ impl MapObject {
             self.momx = FixedT::ZERO;
             self.momy = FixedT::ZERO;
         } else {
-            let friction = FixedT::from_fixed(FRICTION);
-            self.momx = self.momx.fixed_mul(friction);
-            self.momy = self.momy.fixed_mul(friction);
+            // Friction sectors adjust this each tic, it otherwise stays vanilla
+            self.momx = self.momx.fixed_mul(self.friction);
+            self.momy = self.momy.fixed_mul(self.friction);
+            self.friction = FixedT::from_fixed(FRICTION);
+        }
+    }
+
+    /// Player thrust multiplier for the floor being stood on. Ice uses the
+    /// sector's reduced factor, mud starts slow and improves with momentum.
+    ///
+    /// Doom function name `P_GetMoveFactor`
+    pub(crate) fn move_factor(&self) -> i32 {
+        let friction = self.friction.to_fixed_raw();
+        if friction == FRICTION {
+            return ORIG_FRICTION_FACTOR;
+        }
+        if friction > FRICTION {
+            return self.movefactor;
+        }
+        let momentum = p_aprox_distance(self.momx, self.momy).to_fixed_raw();
+        if momentum > MORE_FRICTION_MOMENTUM << 2 {
+            self.movefactor << 3
+        } else if momentum > MORE_FRICTION_MOMENTUM << 1 {
+            self.movefactor << 2
+        } else if momentum > MORE_FRICTION_MOMENTUM {
+            self.movefactor << 1
+        } else {
+            self.movefactor
         }
     }
 
@@ -490,7 +526,7 @@ impl MapObject {
         thing: &mut Self,
         endpoint_x: FixedT,
         endpoint_y: FixedT,
-        _ctrl: &mut SubSectorMinMax,
+        ctrl: &mut SubSectorMinMax,
     ) -> bool {
         if !thing
             .flags
@@ -540,19 +576,15 @@ impl MapObject {
             if let Some(target) = self.target {
                 let target = unsafe { (*target).mobj_mut() };
 
-                // OG: same species OR knight↔bruiser cross-species
-                if target.kind == thing.kind
-                    || (target.kind == MapObjKind::MT_KNIGHT
-                        && thing.kind == MapObjKind::MT_BRUISER)
-                    || (target.kind == MapObjKind::MT_BRUISER
-                        && thing.kind == MapObjKind::MT_KNIGHT)
-                {
+                // OG: same species OR knight↔bruiser cross-species, now
+                // through the MBF21 projectile groups
+                if thing.projectile_immune(target) {
                     // Don't hit same species as originator.
                     if ptr::eq(thing, target) {
                         return true;
                     }
 
-                    if thing.kind != MapObjKind::MT_PLAYER {
+                    if thing.kind != MapObjKind::MT_PLAYER && !misc_info().species_infighting {
                         // Explode, but do no damage.
                         // Let players missile other players.
                         return false;
@@ -563,6 +595,19 @@ impl MapObject {
                     return !thing.flags.contains(MapObjFlag::Solid);
                 }
 
+                if self.flags2.contains(MapObjFlag2::Rip) {
+                    let damage = ((p_random() & 3) + 2) * self.info.damage;
+                    if !thing.flags.contains(MapObjFlag::Noblood) {
+                        Self::spawn_blood(self.x, self.y, self.z, damage, self.level_mut());
+                    }
+                    if self.info.ripsound != SfxName::None {
+                        self.start_sound(self.info.ripsound);
+                    }
+                    thing.p_take_damage(Some((self.x, self.y, self.z)), Some(target), damage);
+                    ctrl.spec_hits.clear();
+                    return true;
+                }
+
                 let damage = ((p_random() % 8) + 1) * self.info.damage;
                 thing.p_take_damage(Some((self.x, self.y, self.z)), Some(target), damage);
                 return false;
@@ -892,7 +937,7 @@ impl MapObject {
                 // TODO: ordering is not great
                 let portal = PortalZ::new(line);
                 if portal.range <= 0 {
-                    self.start_sound(sound_common::SfxName::Noway);
+                    self.start_sound(SfxName::Noway);
                     // can't use through a wall
                     debug!("*UNNGFF!* Can't reach from this side");
                     return false;
@@ -9,16 +9,17 @@ use math::{
 };
 use sound_common::SfxName;
 
+use crate::MapObject;
 use crate::doom_def::{MAXPLAYERS, MAXRADIUS, MELEERANGE};
 use crate::env::specials::shoot_special_line;
-use crate::info::{MOBJINFO, StateNum};
+use crate::info::StateNum;
 use crate::maputl::{Intercept, PortalZ, p_divline_side_raw, path_traverse_blockmap};
-use crate::{MapObjKind, MapObject};
+use crate::tables::mobj_info;
 use level::map_defs::LineDef;
 use level::{LevelData, LineDefFlags, MapPtr, is_leaf, leaf_index};
 use math::Angle;
 
-use super::{MapObjFlag, PT_ADDLINES, PT_ADDTHINGS};
+use super::{MapObjFlag, MapObjFlag2, PT_ADDLINES, PT_ADDTHINGS};
 
 const MAPBLOCKSHIFT: i32 = 23;
 
@@ -32,7 +33,7 @@ impl MapObject {
         self.momx = FixedT::ZERO;
         self.momy = FixedT::ZERO;
         self.momz = FixedT::ZERO;
-        self.set_state(MOBJINFO[self.kind as usize].deathstate);
+        self.set_state(mobj_info()[self.kind as usize].deathstate);
 
         self.tics -= p_random() & 3;
 
@@ -145,12 +146,18 @@ impl MapObject {
     ///
     /// Doom function name `P_RadiusAttack`
     pub fn radius_attack(&mut self, damage: i32) {
+        self.radius_attack_distance(damage, damage);
+    }
+
+    /// `P_RadiusAttack` with the MBF21 split between peak `damage` and the
+    /// `distance` it falls off over. Vanilla blasts have both equal.
+    pub(crate) fn radius_attack_distance(&mut self, damage: i32, distance: i32) {
         let level = unsafe { &mut *self.level };
         level.valid_count = level.valid_count.wrapping_add(1);
         let valid = level.valid_count;
 
-        // OG: dist = (damage + MAXRADIUS) << FRACBITS
-        let dist_raw = (damage + MAXRADIUS) << 16;
+        // OG: dist = (distance + MAXRADIUS) << FRACBITS
+        let dist_raw = (distance + MAXRADIUS) << 16;
         let bm = level.level_data.blockmap();
         let orgx = bm.x_origin;
         let orgy = bm.y_origin;
@@ -174,7 +181,7 @@ impl MapObject {
                 while let Some(ptr) = mobj_ptr {
                     let thing = unsafe { &mut *ptr };
                     mobj_ptr = thing.b_next;
-                    if !self.radius_damage_other(thing, damage, valid) {
+                    if !self.radius_damage_other(thing, damage, distance, valid) {
                         return;
                     }
                 }
@@ -183,7 +190,13 @@ impl MapObject {
     }
 
     /// Cause damage to other thing if in radius of self
-    fn radius_damage_other(&mut self, other: &mut Self, damage: i32, valid: usize) -> bool {
+    fn radius_damage_other(
+        &mut self,
+        other: &mut Self,
+        damage: i32,
+        distance: i32,
+        valid: usize,
+    ) -> bool {
         if other.valid_count == valid {
             return true;
         }
@@ -193,7 +206,16 @@ impl MapObject {
             return true;
         }
 
-        if matches!(other.kind, MapObjKind::MT_CYBORG | MapObjKind::MT_SPIDER) {
+        // Boss spider and cyborg take no damage from concussion
+        if other
+            .flags2
+            .intersects(MapObjFlag2::Noradiusdmg | MapObjFlag2::Boss)
+            && !self.flags2.contains(MapObjFlag2::Forceradiusdmg)
+        {
+            return true;
+        }
+
+        if other.info.splash_group != 0 && other.info.splash_group == self.info.splash_group {
             return true;
         }
 
@@ -204,13 +226,17 @@ impl MapObject {
         dist -= other.radius;
         let dist_i = if dist.to_i32() < 0 { 0 } else { dist.to_i32() };
 
-        if dist_i >= damage {
+        if dist_i >= distance {
             return true; // out of range
         }
 
         // OG: P_DamageMobj(thing, bombspot, bombsource, bombdamage - dist)
         if self.check_sight_target(other) {
-            let actual = damage - dist_i;
+            let actual = if damage == distance {
+                damage - dist_i
+            } else {
+                damage * (distance - dist_i) / distance + 1
+            };
             other.p_take_damage(
                 Some((self.x, self.y, self.z)),
                 self.target.map(|t| unsafe { (*t).mobj_mut() }),
@@ -402,6 +428,22 @@ impl MapObject {
             return false;
         }
 
+        // BOOM: the fake planes of deep water (linedef 242) block sight
+        // between things on either side of them
+        let sectors = &self.level().level_data.sectors;
+        let fake_plane_between = |looker: &Self, other: &Self| {
+            let Some(control) = looker.subsector.sector.heightsec.map(|c| &sectors[c]) else {
+                return false;
+            };
+            // BOOM compares the other's z plus the looker's height here
+            (looker.z + looker.height <= control.floorheight && other.z >= control.floorheight)
+                || (looker.z >= control.ceilingheight
+                    && other.z + looker.height <= control.ceilingheight)
+        };
+        if fake_plane_between(self, target) || fake_plane_between(target, self) {
+            return false;
+        }
+
         self.check_sight(target.x, target.y, target.z, target.height)
     }
 
@@ -409,11 +451,22 @@ impl MapObject {
     pub(crate) fn check_melee_range(&mut self) -> bool {
         #[cfg(feature = "hprof")]
         profile!("check_melee_range");
+        let melee = self.info.meleerange - 20;
+        if let Some(range) = self.target().map(|target| target.radius + melee) {
+            return self.check_range(range);
+        }
+        false
+    }
+
+    /// Return true if the current target is closer than `range` and visible.
+    ///
+    /// Doom function name `P_CheckRange` (MBF21)
+    pub(crate) fn check_range(&mut self, range: FixedT) -> bool {
         if let Some(target) = self.target {
             let target = unsafe { (*target).mobj() };
 
             let dist = p_aprox_distance(target.x - self.x, target.y - self.y);
-            if dist >= target.radius + (MELEERANGE - 20) {
+            if dist >= range {
                 return false;
             }
 
@@ -455,21 +508,15 @@ impl MapObject {
             // OG: dist >>= 16 to get integer units
             let mut dist_i = dist.to_i32();
 
-            if self.kind == MapObjKind::MT_VILE && dist_i > 14 * 64 {
+            if self.flags2.contains(MapObjFlag2::Shortmrange) && dist_i > 14 * 64 {
                 return false; // too far away
             }
 
-            if self.kind == MapObjKind::MT_UNDEAD {
-                if dist_i < 196 {
-                    return false; // Close in to punch
-                }
-                dist_i >>= 1;
+            if self.flags2.contains(MapObjFlag2::Longmelee) && dist_i < 196 {
+                return false; // Close in to punch
             }
 
-            if matches!(
-                self.kind,
-                MapObjKind::MT_CYBORG | MapObjKind::MT_SPIDER | MapObjKind::MT_SKULL
-            ) {
+            if self.flags2.contains(MapObjFlag2::Rangehalf) {
                 dist_i >>= 1;
             }
 
@@ -477,7 +524,7 @@ impl MapObject {
                 dist_i = 200;
             }
 
-            if self.kind == MapObjKind::MT_CYBORG && dist_i > 160 {
+            if self.flags2.contains(MapObjFlag2::Highermprob) && dist_i > 160 {
                 dist_i = 160;
             }
 
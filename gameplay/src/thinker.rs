@@ -9,8 +9,11 @@ use std::ptr::{self, null_mut};
 use crate::env::ceiling::CeilingMove;
 use crate::env::doors::VerticalDoor;
 use crate::env::floor::FloorMove;
+use crate::env::friction::Friction;
 use crate::env::lights::{FireFlicker, Glow, LightFlash, StrobeFlash};
 use crate::env::platforms::Platform;
+use crate::env::pusher::Pusher;
+use crate::env::scroller::Scroller;
 use crate::level::LevelState;
 use crate::thing::MapObject;
 
@@ -430,6 +433,9 @@ pub enum ThinkerData {
     StrobeFlash(StrobeFlash),
     FireFlicker(FireFlicker),
     Glow(Glow),
+    Friction(Friction),
+    Pusher(Pusher),
+    Scroller(Scroller),
     /// The thinker function should set to this when the linked-list node
     /// and memory is no-longer required. On thinker run it will be set to
     /// `Free` and unlinked.
@@ -451,6 +457,9 @@ impl Debug for ThinkerData {
             Self::StrobeFlash(_) => f.debug_tuple("StrobeFlash").finish(),
             Self::FireFlicker(_) => f.debug_tuple("FireFlicker").finish(),
             Self::Glow(_) => f.debug_tuple("Glow").finish(),
+            Self::Friction(_) => f.debug_tuple("Friction").finish(),
+            Self::Pusher(_) => f.debug_tuple("Pusher").finish(),
+            Self::Scroller(_) => f.debug_tuple("Scroller").finish(),
             Self::Remove => f.debug_tuple("Remove").finish(),
             Self::Free => f.debug_tuple("Free - this shouldn't ever be seen").finish(),
         }
@@ -522,6 +531,9 @@ impl Thinker {
             ThinkerData::StrobeFlash(obj) => obj.set_thinker_ptr(ptr),
             ThinkerData::FireFlicker(obj) => obj.set_thinker_ptr(ptr),
             ThinkerData::Glow(obj) => obj.set_thinker_ptr(ptr),
+            ThinkerData::Friction(obj) => obj.set_thinker_ptr(ptr),
+            ThinkerData::Pusher(obj) => obj.set_thinker_ptr(ptr),
+            ThinkerData::Scroller(obj) => obj.set_thinker_ptr(ptr),
             ThinkerData::Remove => {
                 error!("Tried to set the Thinker pointer for an Object that was 'Remove'");
             }
@@ -659,6 +671,36 @@ impl Thinker {
             panic!("ObjectType is not Glow");
         }
     }
+
+    /// Get inner `Friction` data as mut. Panics if the inner is not actually
+    /// `Friction`
+    pub fn friction_mut(&mut self) -> &mut Friction {
+        if let ThinkerData::Friction(obj) = &mut self.data {
+            obj
+        } else {
+            panic!("ObjectType is not Friction");
+        }
+    }
+
+    /// Get inner `Pusher` data as mut. Panics if the inner is not actually
+    /// `Pusher`
+    pub fn pusher_mut(&mut self) -> &mut Pusher {
+        if let ThinkerData::Pusher(obj) = &mut self.data {
+            obj
+        } else {
+            panic!("ObjectType is not Pusher");
+        }
+    }
+
+    /// Get inner `Scroller` data as mut. Panics if the inner is not actually
+    /// `Scroller`
+    pub fn scroller_mut(&mut self) -> &mut Scroller {
+        if let ThinkerData::Scroller(obj) = &mut self.data {
+            obj
+        } else {
+            panic!("ObjectType is not Scroller");
+        }
+    }
 }
 
 impl Debug for Thinker {
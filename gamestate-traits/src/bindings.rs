@@ -0,0 +1,462 @@
+//! Player actions and the inputs bound to them.
+//!
+//! Each `Action` holds up to `MAX_BINDINGS` bindings. A binding is a key,
+//! mouse button, wheel direction or gamepad button, optionally with a
+//! modifier that must be held with it (`Ctrl+S`). The text form used by the
+//! config file is the `Display`/`FromStr` pair on `Binding`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{KeyCode, MouseBtn, PadButton};
+
+/// Most bindings one action can have; binding another drops the oldest.
+pub const MAX_BINDINGS: usize = 4;
+
+/// Everything the player can bind an input to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Forward,
+    Backward,
+    TurnLeft,
+    TurnRight,
+    StrafeLeft,
+    StrafeRight,
+    /// Held: turning keys and mouse x strafe instead.
+    Strafe,
+    /// Held: move at run speed.
+    Run,
+    /// Flip between walking and running without holding `Run`.
+    RunToggle,
+    Fire,
+    Use,
+    LookUp,
+    LookDown,
+    Weapon1,
+    Weapon2,
+    Weapon3,
+    Weapon4,
+    Weapon5,
+    Weapon6,
+    Weapon7,
+    Weapon8,
+    Weapon9,
+    NextWeapon,
+    PrevWeapon,
+    Automap,
+    QuickSave,
+    QuickLoad,
+}
+
+impl Action {
+    /// Every action, in the order the Controls menu lists them.
+    pub const ALL: [Self; 27] = [
+        Self::Forward,
+        Self::Backward,
+        Self::TurnLeft,
+        Self::TurnRight,
+        Self::StrafeLeft,
+        Self::StrafeRight,
+        Self::Strafe,
+        Self::Run,
+        Self::RunToggle,
+        Self::Fire,
+        Self::Use,
+        Self::LookUp,
+        Self::LookDown,
+        Self::Weapon1,
+        Self::Weapon2,
+        Self::Weapon3,
+        Self::Weapon4,
+        Self::Weapon5,
+        Self::Weapon6,
+        Self::Weapon7,
+        Self::Weapon8,
+        Self::Weapon9,
+        Self::NextWeapon,
+        Self::PrevWeapon,
+        Self::Automap,
+        Self::QuickSave,
+        Self::QuickLoad,
+    ];
+
+    /// The weapon slot actions, `Weapon1` first.
+    pub const WEAPONS: [Self; 9] = [
+        Self::Weapon1,
+        Self::Weapon2,
+        Self::Weapon3,
+        Self::Weapon4,
+        Self::Weapon5,
+        Self::Weapon6,
+        Self::Weapon7,
+        Self::Weapon8,
+        Self::Weapon9,
+    ];
+
+    /// Config file name of the action.
+    pub fn name(self) -> String {
+        let debug = format!("{self:?}");
+        let mut name = String::with_capacity(debug.len() + 4);
+        for (i, c) in debug.chars().enumerate() {
+            if c.is_ascii_uppercase() && i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        }
+        name
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+}
+
+/// A modifier key that can be required by a binding. Either side counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    Ctrl,
+    Shift,
+    Alt,
+}
+
+impl Modifier {
+    const ALL: [Self; 3] = [Self::Ctrl, Self::Shift, Self::Alt];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// The set of modifiers currently held.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// Modifiers held when `keys` are down.
+    pub fn from_keys<'a>(keys: impl IntoIterator<Item = &'a KeyCode>) -> Self {
+        let mut mods = Self::default();
+        for m in keys.into_iter().filter_map(|k| k.modifier()) {
+            mods.0 |= m.bit();
+        }
+        mods
+    }
+
+    pub const fn contains(self, m: Modifier) -> bool {
+        self.0 & m.bit() != 0
+    }
+
+    /// The first held modifier, Ctrl before Shift before Alt.
+    pub fn first(self) -> Option<Modifier> {
+        Modifier::ALL.into_iter().find(|&m| self.contains(m))
+    }
+}
+
+/// A physical input that can be bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Input {
+    Key(KeyCode),
+    Mouse(MouseBtn),
+    WheelUp,
+    WheelDown,
+    Pad(PadButton),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Binding {
+    pub input: Input,
+    /// Must be held for the binding to trigger. A binding without one
+    /// triggers whatever is held, so `Run` can be held while moving.
+    pub modifier: Option<Modifier>,
+}
+
+impl Binding {
+    pub const fn new(input: Input) -> Self {
+        Self {
+            input,
+            modifier: None,
+        }
+    }
+
+    pub const fn key(key: KeyCode) -> Self {
+        Self::new(Input::Key(key))
+    }
+
+    pub const fn mouse(button: MouseBtn) -> Self {
+        Self::new(Input::Mouse(button))
+    }
+
+    pub const fn pad(button: PadButton) -> Self {
+        Self::new(Input::Pad(button))
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(m) = self.modifier {
+            write!(f, "{m:?}+")?;
+        }
+        match self.input {
+            Input::Key(k) => write!(f, "{k:?}"),
+            Input::Mouse(b) => write!(f, "Mouse{}", b.number()),
+            Input::WheelUp => write!(f, "WheelUp"),
+            Input::WheelDown => write!(f, "WheelDown"),
+            Input::Pad(b) => write!(f, "Pad{b:?}"),
+        }
+    }
+}
+
+impl FromStr for Binding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (modifier, input) = match s.split_once('+') {
+            Some((m, input)) => {
+                let m = Modifier::ALL
+                    .into_iter()
+                    .find(|c| format!("{c:?}").eq_ignore_ascii_case(m))
+                    .ok_or_else(|| format!("unknown modifier {m}"))?;
+                (Some(m), input)
+            }
+            None => (None, s),
+        };
+        let input = if input.eq_ignore_ascii_case("WheelUp") {
+            Input::WheelUp
+        } else if input.eq_ignore_ascii_case("WheelDown") {
+            Input::WheelDown
+        } else if let Some(n) = input
+            .get(..5)
+            .filter(|p| p.eq_ignore_ascii_case("Mouse"))
+            .and_then(|_| input.get(5..))
+        {
+            n.parse()
+                .ok()
+                .and_then(MouseBtn::from_number)
+                .map(Input::Mouse)
+                .ok_or_else(|| format!("unknown mouse button {input}"))?
+        } else if let Some(b) = input
+            .get(..3)
+            .filter(|p| p.eq_ignore_ascii_case("Pad"))
+            .and_then(|_| input.get(3..))
+            .and_then(PadButton::from_name)
+        {
+            Input::Pad(b)
+        } else {
+            KeyCode::from_name(input)
+                .map(Input::Key)
+                .ok_or_else(|| format!("unknown key {input}"))?
+        };
+        Ok(Self {
+            input,
+            modifier,
+        })
+    }
+}
+
+/// The bindings of every action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bindings {
+    /// Indexed by `Action as usize`.
+    binds: Vec<Vec<Binding>>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use KeyCode as K;
+        use PadButton as P;
+
+        let mut b = Self::unbound();
+        let keys: [(Action, &[KeyCode]); 20] = [
+            (Action::Forward, &[K::W, K::Up]),
+            (Action::Backward, &[K::S, K::Down]),
+            (Action::TurnLeft, &[K::Left]),
+            (Action::TurnRight, &[K::Right]),
+            (Action::StrafeLeft, &[K::A]),
+            (Action::StrafeRight, &[K::D]),
+            (Action::Strafe, &[K::RAlt]),
+            (Action::Run, &[K::LShift, K::RShift]),
+            (Action::RunToggle, &[K::CapsLock]),
+            (Action::Fire, &[K::RCtrl]),
+            (Action::Use, &[K::Space, K::E]),
+            (Action::LookUp, &[K::PageUp]),
+            (Action::LookDown, &[K::PageDown]),
+            (Action::NextWeapon, &[K::RightBracket]),
+            (Action::PrevWeapon, &[K::LeftBracket]),
+            (Action::Automap, &[K::Tab]),
+            (Action::QuickSave, &[K::F6]),
+            (Action::QuickLoad, &[K::F9]),
+            (Action::Weapon1, &[K::Num1]),
+            (Action::Weapon2, &[K::Num2]),
+        ];
+        for (action, keys) in keys {
+            for &k in keys {
+                b.bind(action, Binding::key(k));
+            }
+        }
+        let slots = [
+            K::Num3,
+            K::Num4,
+            K::Num5,
+            K::Num6,
+            K::Num7,
+            K::Num8,
+            K::Num9,
+        ];
+        for (action, k) in Action::WEAPONS[2..].iter().zip(slots) {
+            b.bind(*action, Binding::key(k));
+        }
+        b.bind(Action::Fire, Binding::mouse(MouseBtn::Left));
+        b.bind(Action::Forward, Binding::mouse(MouseBtn::Right));
+        b.bind(Action::Strafe, Binding::mouse(MouseBtn::Middle));
+        b.bind(Action::NextWeapon, Binding::new(Input::WheelUp));
+        b.bind(Action::PrevWeapon, Binding::new(Input::WheelDown));
+        let buttons = [
+            (Action::Forward, P::DPadUp),
+            (Action::Backward, P::DPadDown),
+            (Action::TurnLeft, P::DPadLeft),
+            (Action::TurnRight, P::DPadRight),
+            (Action::Fire, P::RightTrigger),
+            (Action::Run, P::LeftTrigger),
+            (Action::RunToggle, P::LeftStick),
+            (Action::Use, P::A),
+            (Action::NextWeapon, P::RightShoulder),
+            (Action::PrevWeapon, P::LeftShoulder),
+            (Action::Automap, P::Back),
+        ];
+        for (action, button) in buttons {
+            b.bind(action, Binding::pad(button));
+        }
+        b
+    }
+}
+
+impl Bindings {
+    /// No action bound to anything.
+    pub fn unbound() -> Self {
+        Self {
+            binds: Action::ALL.iter().map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn get(&self, action: Action) -> &[Binding] {
+        &self.binds[action as usize]
+    }
+
+    /// Add `binding` to `action`, taking it off any other action first. The
+    /// oldest binding is dropped once `action` has `MAX_BINDINGS`.
+    pub fn bind(&mut self, action: Action, binding: Binding) {
+        for binds in &mut self.binds {
+            binds.retain(|b| *b != binding);
+        }
+        let binds = &mut self.binds[action as usize];
+        if binds.len() == MAX_BINDINGS {
+            binds.remove(0);
+        }
+        binds.push(binding);
+    }
+
+    pub fn clear(&mut self, action: Action) {
+        self.binds[action as usize].clear();
+    }
+
+    /// The action a press of `input` triggers with `mods` held. A binding
+    /// that needs one of the held modifiers wins over a plain binding, so
+    /// `Ctrl+F6` and `F6` can do different things.
+    pub fn action_for(&self, input: Input, mods: Modifiers) -> Option<Action> {
+        let mut plain = None;
+        for action in Action::ALL {
+            for b in self.get(action).iter().filter(|b| b.input == input) {
+                match b.modifier {
+                    Some(m) if mods.contains(m) => return Some(action),
+                    Some(_) => {}
+                    None => plain = plain.or(Some(action)),
+                }
+            }
+        }
+        plain
+    }
+
+    /// True when a press of `key` with `mods` held triggers `action`.
+    pub fn is_key_for(&self, action: Action, key: KeyCode, mods: Modifiers) -> bool {
+        self.action_for(Input::Key(key), mods) == Some(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binding_text_round_trips() {
+        for text in [
+            "W",
+            "Num1",
+            "LShift",
+            "Mouse1",
+            "Mouse3",
+            "WheelDown",
+            "Ctrl+S",
+            "PadA",
+            "Shift+PadRightTrigger",
+        ] {
+            let b: Binding = text.parse().unwrap();
+            assert_eq!(b.to_string(), text);
+        }
+        assert_eq!(
+            "alt+mouse2".parse::<Binding>(),
+            Ok(Binding {
+                input: Input::Mouse(MouseBtn::Right),
+                modifier: Some(Modifier::Alt),
+            })
+        );
+        assert!("Hyper+W".parse::<Binding>().is_err());
+        assert!("NotAKey".parse::<Binding>().is_err());
+    }
+
+    #[test]
+    fn action_names_round_trip() {
+        assert_eq!(Action::NextWeapon.name(), "next_weapon");
+        for a in Action::ALL {
+            assert_eq!(Action::from_name(&a.name()), Some(a));
+        }
+    }
+
+    #[test]
+    fn binding_moves_between_actions() {
+        let mut b = Bindings::default();
+        b.bind(Action::Use, Binding::key(KeyCode::W));
+        assert_eq!(
+            b.get(Action::Forward),
+            [
+                Binding::key(KeyCode::Up),
+                Binding::mouse(MouseBtn::Right),
+                Binding::pad(PadButton::DPadUp)
+            ]
+        );
+        assert!(b.get(Action::Use).contains(&Binding::key(KeyCode::W)));
+
+        for k in [KeyCode::F, KeyCode::G, KeyCode::H, KeyCode::J, KeyCode::K] {
+            b.bind(Action::Automap, Binding::key(k));
+        }
+        assert_eq!(b.get(Action::Automap).len(), MAX_BINDINGS);
+        assert_eq!(b.get(Action::Automap)[0], Binding::key(KeyCode::G));
+    }
+
+    #[test]
+    fn modified_binding_wins_when_held() {
+        let mut b = Bindings::default();
+        b.bind(
+            Action::QuickLoad,
+            Binding {
+                input: Input::Key(KeyCode::F6),
+                modifier: Some(Modifier::Shift),
+            },
+        );
+        let shift = Modifiers::from_keys(&[KeyCode::RShift]);
+        assert!(b.is_key_for(Action::QuickSave, KeyCode::F6, Modifiers::default()));
+        assert!(b.is_key_for(Action::QuickLoad, KeyCode::F6, shift));
+        assert_eq!(
+            b.action_for(Input::WheelUp, shift),
+            Some(Action::NextWeapon)
+        );
+    }
+}
@@ -1,10 +1,12 @@
-//! Backend-agnostic key and mouse button enums.
+//! Backend-agnostic key, mouse button and gamepad button enums.
 //!
 //! Discriminant values match SDL2 scancodes so existing `user.toml` config
 //! files remain valid without migration.
 
 use std::fmt;
 
+use crate::bindings::Modifier;
+
 /// Keyboard key codes. `#[repr(i32)]` with SDL2-matching discriminants for
 /// config file compatibility.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -53,6 +55,15 @@ pub enum KeyCode {
     Space = 44,
     Minus = 45,
     Equals = 46,
+    LeftBracket = 47,
+    RightBracket = 48,
+    Backslash = 49,
+    Semicolon = 51,
+    Apostrophe = 52,
+    Grave = 53,
+    Comma = 54,
+    Period = 55,
+    Slash = 56,
     CapsLock = 57,
     F1 = 58,
     F2 = 59,
@@ -67,6 +78,12 @@ pub enum KeyCode {
     F11 = 68,
     F12 = 69,
     Pause = 72,
+    Insert = 73,
+    Home = 74,
+    PageUp = 75,
+    Delete = 76,
+    End = 77,
+    PageDown = 78,
     Right = 79,
     Left = 80,
     Down = 81,
@@ -124,6 +141,15 @@ const ALL_KEYS: &[KeyCode] = &[
     KeyCode::Space,
     KeyCode::Minus,
     KeyCode::Equals,
+    KeyCode::LeftBracket,
+    KeyCode::RightBracket,
+    KeyCode::Backslash,
+    KeyCode::Semicolon,
+    KeyCode::Apostrophe,
+    KeyCode::Grave,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Slash,
     KeyCode::CapsLock,
     KeyCode::F1,
     KeyCode::F2,
@@ -138,6 +164,12 @@ const ALL_KEYS: &[KeyCode] = &[
     KeyCode::F11,
     KeyCode::F12,
     KeyCode::Pause,
+    KeyCode::Insert,
+    KeyCode::Home,
+    KeyCode::PageUp,
+    KeyCode::Delete,
+    KeyCode::End,
+    KeyCode::PageDown,
     KeyCode::Right,
     KeyCode::Left,
     KeyCode::Down,
@@ -167,6 +199,25 @@ impl KeyCode {
         ALL_KEYS.iter().find(|k| **k as i32 == val).copied()
     }
 
+    /// Look a key up by its variant name (`"Num1"`, `"PageUp"`), ignoring
+    /// case. The inverse of `{:?}`, used for the bindings config.
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_KEYS
+            .iter()
+            .find(|k| format!("{k:?}").eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// The modifier this key is, if it is one.
+    pub const fn modifier(self) -> Option<Modifier> {
+        match self {
+            Self::LCtrl | Self::RCtrl => Some(Modifier::Ctrl),
+            Self::LShift | Self::RShift => Some(Modifier::Shift),
+            Self::LAlt | Self::RAlt => Some(Modifier::Alt),
+            _ => None,
+        }
+    }
+
     /// Convert this key to the corresponding ASCII character, if any.
     /// Used by the cheat code system (replaces `Keycode::from_scancode`).
     pub const fn to_char(self) -> Option<char> {
@@ -210,6 +261,15 @@ impl KeyCode {
             Self::Space => Some(' '),
             Self::Minus => Some('-'),
             Self::Equals => Some('='),
+            Self::LeftBracket => Some('['),
+            Self::RightBracket => Some(']'),
+            Self::Backslash => Some('\\'),
+            Self::Semicolon => Some(';'),
+            Self::Apostrophe => Some('\''),
+            Self::Grave => Some('`'),
+            Self::Comma => Some(','),
+            Self::Period => Some('.'),
+            Self::Slash => Some('/'),
             _ => None,
         }
     }
@@ -222,6 +282,10 @@ pub enum MouseBtn {
     Left = 1,
     Middle = 2,
     Right = 3,
+    /// Side button, "back".
+    X1 = 4,
+    /// Side button, "forward".
+    X2 = 5,
 }
 
 impl MouseBtn {
@@ -231,7 +295,84 @@ impl MouseBtn {
             1 => Some(Self::Left),
             2 => Some(Self::Middle),
             3 => Some(Self::Right),
+            4 => Some(Self::X1),
+            5 => Some(Self::X2),
+            _ => None,
+        }
+    }
+
+    /// Button number as players know it: 1 left, 2 right, 3 middle, then the
+    /// side buttons.
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Left => 1,
+            Self::Right => 2,
+            Self::Middle => 3,
+            Self::X1 => 4,
+            Self::X2 => 5,
+        }
+    }
+
+    pub const fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::Left),
+            2 => Some(Self::Right),
+            3 => Some(Self::Middle),
+            4 => Some(Self::X1),
+            5 => Some(Self::X2),
             _ => None,
         }
     }
 }
+
+/// Gamepad buttons, named after the SDL game controller layout (A is the
+/// bottom face button). The triggers count as pressed past a threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PadButton {
+    A,
+    B,
+    X,
+    Y,
+    Back,
+    Guide,
+    Start,
+    LeftStick,
+    RightStick,
+    LeftShoulder,
+    RightShoulder,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl PadButton {
+    pub const ALL: [Self; 17] = [
+        Self::A,
+        Self::B,
+        Self::X,
+        Self::Y,
+        Self::Back,
+        Self::Guide,
+        Self::Start,
+        Self::LeftStick,
+        Self::RightStick,
+        Self::LeftShoulder,
+        Self::RightShoulder,
+        Self::DPadUp,
+        Self::DPadDown,
+        Self::DPadLeft,
+        Self::DPadRight,
+        Self::LeftTrigger,
+        Self::RightTrigger,
+    ];
+
+    /// Look a button up by its variant name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|b| format!("{b:?}").eq_ignore_ascii_case(name))
+    }
+}
@@ -2,15 +2,19 @@
 //! intermission screens to get certain information they require or cause a
 //! gamestate change.
 
+pub mod bindings;
 pub mod keys;
 
-pub use keys::{KeyCode, MouseBtn};
+pub use bindings::{Action, Binding, Bindings, Input, Modifier, Modifiers};
+pub use keys::{KeyCode, MouseBtn, PadButton};
 
-use game_config::{GameMode, Skill};
-use gameplay::{MAXPLAYERS, PlayerStatus, WorldEndPlayerInfo};
+use game_config::{GameMission, GameMode, Skill};
+use gameplay::{MAXPLAYERS, MapObject, PlayerStatus, WorldEndPlayerInfo};
+use level::LevelData;
 use render_common::DrawBuffer;
 use sound_common::{MusTrack, SfxName};
 use wad::WadData;
+use wad::umapinfo::MapEntry;
 
 /// The current state of the game-exe: whether we are playing, gazing at the
 /// intermission screen, the game-exe final animation, or a demo.
@@ -46,6 +50,7 @@ pub enum ConfigKey {
     SfxVolume,
     MusVolume,
     MusicType,
+    PitchShift,
     WindowMode,
     VSync,
     Renderer,
@@ -70,6 +75,7 @@ pub enum ConfigKey {
 pub trait GameTraits {
     fn defered_init_new(&mut self, skill: Skill, episode: usize, map: usize);
     fn get_mode(&self) -> GameMode;
+    fn get_mission(&self) -> GameMission;
     fn game_state(&self) -> GameState;
     fn read_save_descriptions(&self) -> Vec<Option<String>>;
     fn load_game(&mut self, name: String);
@@ -86,7 +92,30 @@ pub trait GameTraits {
     fn player_end_info(&self) -> &WorldEndPlayerInfo;
     fn player_status(&self) -> PlayerStatus;
     fn player_msg_take(&mut self) -> Option<String>;
+    fn player_msg_set(&mut self, msg: &'static str);
+    fn player_mobj(&self) -> Option<&MapObject>;
     fn get_wad_data(&self) -> &WadData;
+    fn level_data(&self) -> Option<&LevelData>;
+    /// Lump name of the current map, e.g. `E1M1` or `MAP01`.
+    fn map_name(&self) -> String;
+    /// UMAPINFO entry for the current map, if any.
+    fn map_entry(&self) -> Option<&MapEntry>;
+    fn automap_active(&self) -> bool;
+    fn set_automap_active(&mut self, active: bool);
+    fn netgame(&self) -> bool;
+    /// 0 for cooperative, 1 for deathmatch, 2 for altdeath
+    fn deathmatch(&self) -> u8;
+    fn console_player(&self) -> usize;
+    fn players_in_game(&self) -> [bool; MAXPLAYERS];
+    /// The console player's frags against each player, their own being
+    /// suicides.
+    fn player_frags(&self) -> [i32; MAXPLAYERS];
+    /// Queue a chat character to go out with the console player's commands,
+    /// one per tic. Returns false if the queue is full.
+    fn queue_chat_char(&mut self, c: u8) -> bool;
+    /// Take the chat character that came in with `player`'s command this tic,
+    /// or 0.
+    fn take_chat_char(&mut self, player: usize) -> u8;
 }
 
 pub trait ConfigTraits {
@@ -96,11 +125,29 @@ pub trait ConfigTraits {
     fn is_config_dirty(&self) -> bool;
     fn clear_config_dirty(&mut self);
     fn config_snapshot(&self) -> [i32; ConfigKey::KeyCount as usize];
+    fn bindings(&self) -> &Bindings;
+    /// Replace the bindings. Callers mark the config changed to persist them.
+    fn set_bindings(&mut self, bindings: Bindings);
+    /// Modifier keys held as of the last input event.
+    fn modifiers(&self) -> Modifiers;
+    /// The action a press of `sc` triggers with the held modifiers.
+    fn key_action(&self, sc: KeyCode) -> Option<Action> {
+        self.bindings().action_for(Input::Key(sc), self.modifiers())
+    }
 }
 
 pub trait SubsystemTrait {
     fn init<T: GameTraits + ConfigTraits>(&mut self, game: &T);
     fn responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, game: &mut T) -> bool;
+    /// A mouse button, wheel step or gamepad button press, taken by screens
+    /// with an action it may be bound to.
+    fn device_responder<T: GameTraits + ConfigTraits>(
+        &mut self,
+        _input: Input,
+        _game: &mut T,
+    ) -> bool {
+        false
+    }
     fn ticker<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) -> bool;
     fn draw(&mut self, buffer: &mut impl DrawBuffer);
 }
@@ -0,0 +1,407 @@
+//! Demo (LMP) playback and recording.
+//!
+//! A vanilla demo is a 13 byte header (version, skill, episode, map,
+//! deathmatch, respawn, fast, nomonsters, consoleplayer, then one byte per
+//! player in game), followed by 4 bytes per player per tic and terminated by
+//! `DEMO_MARKER`. Doom 1.91 `-longtics` demos use version 111 and store
+//! `angleturn` as 16 bits, making each tic 5 bytes.
+
+use std::iter::Peekable;
+use std::path::PathBuf;
+use std::vec::IntoIter;
+
+use game_config::Skill;
+use game_config::tic_cmd::TicCmd;
+use gameplay::MAXPLAYERS;
+use log::{info, warn};
+
+use crate::Game;
+
+pub const DEMO_MARKER: u8 = 0x80;
+/// Version byte written by Doom 1.9
+pub const DEMO_VERSION: u8 = 109;
+/// Version byte written by Doom 1.91 with `-longtics`
+pub const DEMO_VERSION_LONGTICS: u8 = 111;
+/// Largest tic record: forwardmove, sidemove, angleturn (2), buttons
+const DEMO_MAX_TIC_SIZE: usize = 5;
+/// Extension appended to recorded demo names that lack one
+const DEMO_EXTENSION: &str = "lmp";
+
+/// Tic encoding, selected by the demo version byte
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DemoFormat {
+    /// Doom 1.9: `angleturn` is stored as its rounded high byte
+    #[default]
+    Vanilla,
+    /// Doom 1.91 `-longtics`: `angleturn` is stored as full 16 bits
+    LongTics,
+}
+
+impl DemoFormat {
+    pub const fn version(self) -> u8 {
+        match self {
+            Self::Vanilla => DEMO_VERSION,
+            Self::LongTics => DEMO_VERSION_LONGTICS,
+        }
+    }
+
+    /// Bytes per player per tic
+    const fn tic_size(self) -> usize {
+        match self {
+            Self::Vanilla => 4,
+            Self::LongTics => 5,
+        }
+    }
+}
+
+impl TryFrom<u8> for DemoFormat {
+    type Error = u8;
+
+    fn try_from(version: u8) -> Result<Self, Self::Error> {
+        match version {
+            DEMO_VERSION => Ok(Self::Vanilla),
+            DEMO_VERSION_LONGTICS => Ok(Self::LongTics),
+            _ => Err(version),
+        }
+    }
+}
+
+/// The demo header, describing the game the tics were recorded against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemoHeader {
+    pub format: DemoFormat,
+    pub skill: Skill,
+    pub episode: usize,
+    pub map: usize,
+    pub deathmatch: u8,
+    pub respawn_parm: bool,
+    pub fast_parm: bool,
+    pub no_monsters: bool,
+    pub consoleplayer: usize,
+    pub players_in_game: [bool; MAXPLAYERS],
+}
+
+impl DemoHeader {
+    /// Read the header from the start of a demo. Returns `None` if the demo is
+    /// truncated or the version is not supported.
+    fn read(buffer: &mut impl Iterator<Item = u8>) -> Option<Self> {
+        let format = match DemoFormat::try_from(buffer.next()?) {
+            Ok(format) => format,
+            Err(version) => {
+                warn!("Unsupported demo version {version}");
+                return None;
+            }
+        };
+        let skill = Skill::from(buffer.next()?);
+        let episode = buffer.next()? as usize;
+        let map = buffer.next()? as usize;
+        let deathmatch = buffer.next()?;
+        let respawn_parm = buffer.next()? == 1;
+        let fast_parm = buffer.next()? == 1;
+        let no_monsters = buffer.next()? == 1;
+        let consoleplayer = buffer.next()? as usize;
+        let mut players_in_game = [false; MAXPLAYERS];
+        for player in &mut players_in_game {
+            *player = buffer.next()? == 1;
+        }
+        Some(Self {
+            format,
+            skill,
+            episode,
+            map,
+            deathmatch,
+            respawn_parm,
+            fast_parm,
+            no_monsters,
+            consoleplayer,
+            players_in_game,
+        })
+    }
+
+    /// G_BeginRecording
+    fn write(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.format.version());
+        buffer.push(self.skill as u8);
+        buffer.push(self.episode as u8);
+        buffer.push(self.map as u8);
+        buffer.push(self.deathmatch);
+        buffer.push(self.respawn_parm as u8);
+        buffer.push(self.fast_parm as u8);
+        buffer.push(self.no_monsters as u8);
+        buffer.push(self.consoleplayer as u8);
+        for player in &self.players_in_game {
+            buffer.push(*player as u8);
+        }
+    }
+}
+
+/// Vanilla stores only the high byte of `angleturn`, rounded to nearest.
+fn encode_tic(cmd: &TicCmd, format: DemoFormat, buffer: &mut Vec<u8>) {
+    buffer.push(cmd.forwardmove as u8);
+    buffer.push(cmd.sidemove as u8);
+    match format {
+        DemoFormat::Vanilla => buffer.push((cmd.angleturn.wrapping_add(128) >> 8) as u8),
+        DemoFormat::LongTics => buffer.extend_from_slice(&cmd.angleturn.to_le_bytes()),
+    }
+    buffer.push(cmd.buttons);
+}
+
+fn decode_tic(tic: &[u8], format: DemoFormat, cmd: &mut TicCmd) {
+    cmd.forwardmove = tic[0] as i8;
+    cmd.sidemove = tic[1] as i8;
+    match format {
+        DemoFormat::Vanilla => {
+            cmd.angleturn = (tic[2] as i16) << 8;
+            cmd.buttons = tic[3];
+        }
+        DemoFormat::LongTics => {
+            cmd.angleturn = i16::from_le_bytes([tic[2], tic[3]]);
+            cmd.buttons = tic[4];
+        }
+    }
+}
+
+/// Data and details used for playback and recording of demos
+pub struct DemoData {
+    /// Demo being played?
+    pub playback: bool,
+    /// Is in the overall demo loop? (titles, credits, demos)
+    pub advance: bool,
+    pub(crate) sequence: i8,
+    buffer: Peekable<IntoIter<u8>>,
+    pub(crate) name: String,
+    /// Exit the game when this demo finishes (set by --demo CLI arg).
+    pub(crate) exit_on_finish: bool,
+    /// Tic encoding of the demo being played or recorded
+    pub format: DemoFormat,
+    /// Demo being recorded?
+    pub recording: bool,
+    /// File the recording is written to when it ends
+    record_path: PathBuf,
+    record_buffer: Vec<u8>,
+}
+
+impl DemoData {
+    pub(crate) fn new() -> Self {
+        Self {
+            playback: false,
+            advance: false,
+            sequence: 0,
+            buffer: Vec::new().into_iter().peekable(),
+            name: String::new(),
+            exit_on_finish: false,
+            format: DemoFormat::default(),
+            recording: false,
+            record_path: PathBuf::new(),
+            record_buffer: Vec::new(),
+        }
+    }
+
+    /// Load a demo for playback and read the header. The demo data is left
+    /// positioned at the first tic.
+    pub(crate) fn start_playback(&mut self, data: Vec<u8>) -> Option<DemoHeader> {
+        self.buffer = data.into_iter().peekable();
+        let header = DemoHeader::read(&mut self.buffer)?;
+        self.format = header.format;
+        Some(header)
+    }
+
+    /// G_ReadDemoTicCmd. Returns `false` when the end of the demo is reached,
+    /// leaving `cmd` untouched.
+    pub(crate) fn read_tic_cmd(&mut self, cmd: &mut TicCmd) -> bool {
+        if self.buffer.peek().is_none_or(|byte| *byte == DEMO_MARKER) {
+            return false;
+        }
+        let mut tic = [0; DEMO_MAX_TIC_SIZE];
+        let tic = &mut tic[..self.format.tic_size()];
+        for byte in tic.iter_mut() {
+            let Some(b) = self.buffer.next() else {
+                return false;
+            };
+            *byte = b;
+        }
+        decode_tic(tic, self.format, cmd);
+        true
+    }
+
+    /// G_RecordDemo
+    pub fn record(&mut self, name: &str, format: DemoFormat) {
+        let mut path = PathBuf::from(name);
+        if path.extension().is_none() {
+            path.set_extension(DEMO_EXTENSION);
+        }
+        info!("Recording demo to {}", path.display());
+        self.record_path = path;
+        self.format = format;
+        self.record_buffer.clear();
+        self.recording = true;
+    }
+
+    /// Start a fresh recording with `header`, discarding any tics already
+    /// recorded so the demo always plays back from a new game.
+    fn begin_recording(&mut self, header: &DemoHeader) {
+        self.record_buffer.clear();
+        header.write(&mut self.record_buffer);
+    }
+
+    /// G_WriteDemoTiccmd. The command is then replaced by the stored form so
+    /// live play runs exactly what playback will.
+    pub(crate) fn write_tic_cmd(&mut self, cmd: &mut TicCmd) {
+        let start = self.record_buffer.len();
+        encode_tic(cmd, self.format, &mut self.record_buffer);
+        *cmd = TicCmd::new();
+        decode_tic(&self.record_buffer[start..], self.format, cmd);
+    }
+
+    /// G_CheckDemoStatus for recording: terminate the demo and write it out.
+    pub fn end_recording(&mut self) {
+        if !self.recording {
+            return;
+        }
+        self.recording = false;
+        if self.record_buffer.is_empty() {
+            warn!(
+                "Demo {} has no tics, not written",
+                self.record_path.display()
+            );
+            return;
+        }
+        self.record_buffer.push(DEMO_MARKER);
+        match std::fs::write(&self.record_path, &self.record_buffer) {
+            Ok(()) => info!(
+                "Demo {} recorded ({} bytes)",
+                self.record_path.display(),
+                self.record_buffer.len()
+            ),
+            Err(e) => warn!("Failed to write demo {}: {e}", self.record_path.display()),
+        }
+    }
+}
+
+impl Game {
+    /// The header describing the game as currently set up
+    fn demo_header(&self) -> DemoHeader {
+        DemoHeader {
+            format: self.demo.format,
+            skill: self.options.skill,
+            episode: self.options.episode,
+            map: self.options.map,
+            deathmatch: self.options.deathmatch,
+            respawn_parm: self.options.respawn_parm,
+            fast_parm: self.options.fast_parm,
+            no_monsters: self.options.no_monsters,
+            consoleplayer: self.consoleplayer,
+            players_in_game: self.players_in_game,
+        }
+    }
+
+    /// G_BeginRecording, called once the new game options are validated.
+    pub(crate) fn begin_demo_recording(&mut self) {
+        if self.demo.recording {
+            let header = self.demo_header();
+            self.demo.begin_recording(&header);
+        }
+    }
+
+    /// Read the demo named by `demo.name`, trying WAD lumps first and then a
+    /// file on disk so recorded demos can be played back directly.
+    pub(crate) fn demo_bytes(&self) -> Option<Vec<u8>> {
+        if let Some(lump) = self.wad_data.get_lump(&self.demo.name) {
+            return Some(lump.data.clone());
+        }
+        std::fs::read(&self.demo.name).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(format: DemoFormat) -> DemoHeader {
+        DemoHeader {
+            format,
+            skill: Skill::Hard,
+            episode: 1,
+            map: 3,
+            deathmatch: 0,
+            respawn_parm: false,
+            fast_parm: true,
+            no_monsters: false,
+            consoleplayer: 0,
+            players_in_game: [true, false, false, false],
+        }
+    }
+
+    /// Record one tic with `format`, then play the demo back. Returns the tic
+    /// as live play saw it and as playback read it.
+    fn record_and_play(format: DemoFormat, mut cmd: TicCmd) -> (TicCmd, TicCmd) {
+        let mut demo = DemoData::new();
+        demo.record("test", format);
+        demo.begin_recording(&header(format));
+        demo.write_tic_cmd(&mut cmd);
+        assert_eq!(
+            demo.record_buffer.len(),
+            13 + format.tic_size(),
+            "header is 13 bytes, then one tic"
+        );
+
+        demo.record_buffer.push(DEMO_MARKER);
+        let data = std::mem::take(&mut demo.record_buffer);
+        let mut playback = DemoData::new();
+        assert_eq!(playback.start_playback(data), Some(header(format)));
+        assert_eq!(playback.format, format);
+
+        let mut read = TicCmd::new();
+        assert!(playback.read_tic_cmd(&mut read), "one tic was recorded");
+        assert!(!playback.read_tic_cmd(&mut read), "marker ends the demo");
+        (cmd, read)
+    }
+
+    fn assert_same_tic(a: &TicCmd, b: &TicCmd) {
+        assert_eq!(a.forwardmove, b.forwardmove);
+        assert_eq!(a.sidemove, b.sidemove);
+        assert_eq!(a.angleturn, b.angleturn);
+        assert_eq!(a.buttons, b.buttons);
+    }
+
+    #[test]
+    fn vanilla_tics_play_back() {
+        let cmd = TicCmd {
+            forwardmove: 50,
+            sidemove: -24,
+            angleturn: 640,
+            buttons: 1,
+            lookdir: 12,
+            ..TicCmd::new()
+        };
+        let (live, read) = record_and_play(DemoFormat::Vanilla, cmd);
+        assert_eq!(
+            live.angleturn, 768,
+            "angleturn is rounded to the stored byte"
+        );
+        assert_eq!(live.lookdir, 0, "lookdir is not stored in a demo");
+        assert_same_tic(&live, &read);
+    }
+
+    #[test]
+    fn longtics_keep_full_angleturn() {
+        let cmd = TicCmd {
+            forwardmove: -25,
+            sidemove: 40,
+            angleturn: -1234,
+            buttons: 2,
+            ..TicCmd::new()
+        };
+        let (live, read) = record_and_play(DemoFormat::LongTics, cmd);
+        assert_eq!(live.angleturn, -1234, "longtics store the full angleturn");
+        assert_same_tic(&live, &read);
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let mut buf = Vec::new();
+        header(DemoFormat::Vanilla).write(&mut buf);
+        buf[0] = 110;
+        assert!(DemoHeader::read(&mut buf.into_iter()).is_none());
+    }
+}
@@ -1,9 +1,16 @@
-use crate::Game;
-use game_config::{GameMode, Skill};
-use gameplay::{GameAction, PlayerStatus, WorldEndPlayerInfo, save};
-use gamestate_traits::{ConfigKey, ConfigTraits, GameState, GameTraits, WorldInfo};
-use sound_common::{EPISODE4_MUS, MUS_ID, MusTrack, SfxName, SoundAction, read_mus_to_midi};
+use crate::{CHAT_QUEUE_SIZE, Game};
+use game_config::{GameMission, GameMode, Skill};
+use gameplay::{
+    GameAction, MAXPLAYERS, MapObject, PlayerStatus, WorldEndPlayerInfo, deh_string, english, save,
+};
+use gamestate_traits::{
+    Bindings, ConfigKey, ConfigTraits, GameState, GameTraits, Modifiers, WorldInfo,
+};
+use level::LevelData;
+use math::m_random;
+use sound_common::{EPISODE4_MUS, MusTrack, SfxName, SoundAction};
 use wad::WadData;
+use wad::umapinfo::MapEntry;
 
 impl GameTraits for Game {
     /// G_InitNew
@@ -16,6 +23,10 @@ impl GameTraits for Game {
     /// of impact of changing game-exe vars beyong action here, probably
     /// nothing.
     fn defered_init_new(&mut self, skill: Skill, episode: usize, map: usize) {
+        if self.is_netgame() {
+            self.players[self.consoleplayer].message = Some(deh_string(english::NETNEWGAME));
+            return;
+        }
         self.options.skill = skill;
         self.options.episode = episode;
         self.options.map = map;
@@ -26,6 +37,10 @@ impl GameTraits for Game {
         self.game_type.mode
     }
 
+    fn get_mission(&self) -> GameMission {
+        self.game_type.mission
+    }
+
     fn game_state(&self) -> GameState {
         self.gamestate
     }
@@ -47,6 +62,10 @@ impl GameTraits for Game {
     }
 
     fn load_game(&mut self, name: String) {
+        if self.is_netgame() {
+            self.players[self.consoleplayer].message = Some(deh_string(english::NETLOAD));
+            return;
+        }
         self.save_name = Some(name);
         self.pending_action = GameAction::LoadGame;
     }
@@ -58,7 +77,12 @@ impl GameTraits for Game {
     }
 
     fn toggle_pause_game(&mut self) {
-        self.paused = !self.paused;
+        // Every node pauses on the same tic
+        if self.is_netgame() {
+            self.send_pause = true;
+        } else {
+            self.paused = !self.paused;
+        }
     }
 
     fn quit_game(&mut self) {
@@ -71,6 +95,7 @@ impl GameTraits for Game {
             sfx,
             x: 0.0,
             y: 0.0,
+            pitch: sfx.random_pitch(m_random),
         };
         if let Err(e) = self.sound_cmd.send(sfx) {
             log::warn!("Could not send sfx, sound thread gone: {e}");
@@ -132,6 +157,10 @@ impl GameTraits for Game {
     }
 
     fn start_title(&mut self) {
+        if self.is_netgame() {
+            self.players[self.consoleplayer].message = Some(deh_string(english::NETEND));
+            return;
+        }
         self.start_title();
     }
 
@@ -151,12 +180,73 @@ impl GameTraits for Game {
         self.players[self.consoleplayer]
             .message
             .take()
-            .map(|s| s.to_owned())
+            .map(|s| deh_string(s).to_owned())
+    }
+
+    fn player_msg_set(&mut self, msg: &'static str) {
+        self.players[self.consoleplayer].message = Some(msg);
+    }
+
+    fn player_mobj(&self) -> Option<&MapObject> {
+        self.players[self.consoleplayer].mobj()
     }
 
     fn get_wad_data(&self) -> &WadData {
         &self.wad_data
     }
+
+    fn level_data(&self) -> Option<&LevelData> {
+        self.level.as_ref().map(|l| &l.level_data)
+    }
+
+    fn map_name(&self) -> String {
+        self.current_map_name()
+    }
+
+    fn map_entry(&self) -> Option<&MapEntry> {
+        let umapinfo = self.umapinfo.as_ref()?;
+        umapinfo.get(&self.current_map_name())
+    }
+
+    fn automap_active(&self) -> bool {
+        self.automap
+    }
+
+    fn set_automap_active(&mut self, active: bool) {
+        self.automap = active;
+    }
+
+    fn netgame(&self) -> bool {
+        self.is_netgame()
+    }
+
+    fn deathmatch(&self) -> u8 {
+        self.options.deathmatch
+    }
+
+    fn console_player(&self) -> usize {
+        self.consoleplayer
+    }
+
+    fn players_in_game(&self) -> [bool; MAXPLAYERS] {
+        self.players_in_game
+    }
+
+    fn player_frags(&self) -> [i32; MAXPLAYERS] {
+        self.players[self.consoleplayer].frags
+    }
+
+    fn queue_chat_char(&mut self, c: u8) -> bool {
+        if self.chat_queue.len() >= CHAT_QUEUE_SIZE {
+            return false;
+        }
+        self.chat_queue.push_back(c);
+        true
+    }
+
+    fn take_chat_char(&mut self, player: usize) -> u8 {
+        std::mem::take(&mut self.players[player].cmd.chatchar)
+    }
 }
 
 impl ConfigTraits for Game {
@@ -173,6 +263,9 @@ impl ConfigTraits for Game {
             ConfigKey::MusVolume => {
                 let _ = self.sound_cmd.send(SoundAction::MusicVolume(val));
             }
+            ConfigKey::PitchShift => {
+                let _ = self.sound_cmd.send(SoundAction::SfxPitchShift(val != 0));
+            }
             _ => {}
         }
     }
@@ -192,6 +285,18 @@ impl ConfigTraits for Game {
     fn config_snapshot(&self) -> [i32; ConfigKey::KeyCount as usize] {
         self.config_values
     }
+
+    fn bindings(&self) -> &Bindings {
+        &self.bindings
+    }
+
+    fn set_bindings(&mut self, bindings: Bindings) {
+        self.bindings = bindings;
+    }
+
+    fn modifiers(&self) -> Modifiers {
+        self.held_mods
+    }
 }
 
 impl Game {
@@ -216,15 +321,12 @@ impl Game {
         }
     }
 
+    /// The raw lump; the sound server sniffs MUS, MIDI and digital formats.
     fn music_data_for_lump(&self, lump_name: &str) -> Option<Vec<u8>> {
         let lump = self.wad_data.get_lump(lump_name)?;
         if lump.data.len() < 4 {
             return None;
         }
-        if lump.data[..4] == MUS_ID {
-            read_mus_to_midi(&lump.data)
-        } else {
-            Some(lump.data.clone())
-        }
+        Some(lump.data.clone())
     }
 }
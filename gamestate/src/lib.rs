@@ -20,34 +20,40 @@
 //! only during the `GameState::Level` state, and draws to the buffer after the
 //! player view is drawn.
 
+pub mod demo;
 pub mod game_impl;
+pub mod net;
 pub mod subsystems;
 
 const SHUTDOWN_DRAIN_DELAY: Duration = Duration::from_millis(500);
 
+pub use crate::demo::{DEMO_MARKER, DemoData, DemoFormat};
+pub use crate::net::{CONNECT_TIMEOUT, DOOMPORT, NetGame};
 use crate::subsystems::GameSubsystem;
 use game_config::tic_cmd::{TIC_CMD_BUTTONS, TicCmd};
 use game_config::{GameMission, GameMode, GameOptions, Skill};
 use gameplay::{
-    GameAction, LevelState, MAXPLAYERS, MapObject, Player, PlayerState, respawn_specials, save,
-    spawn_specials, update_specials,
+    GameAction, LevelState, MAXPLAYERS, MapObject, Player, PlayerState, apply_dehacked,
+    respawn_specials, save, spawn_specials, update_specials,
+};
+use gamestate_traits::{
+    Bindings, ConfigKey, GameState, GameTraits as _, KeyCode, Modifiers, SubsystemTrait, WorldInfo,
 };
-use gamestate_traits::{ConfigKey, GameState, GameTraits as _, SubsystemTrait, WorldInfo};
 use log::{debug, error, info, trace, warn};
 use math::m_clear_random;
 use pic_data::PicData;
 use sound_common::{MusTrack, SndServerTx, SoundAction};
-use std::iter::Peekable;
+use std::collections::VecDeque;
 use std::ptr;
 use std::thread::JoinHandle;
 use std::time::Duration;
-use std::vec::IntoIter;
-use wad::WadData;
 use wad::types::WadPatch;
 use wad::umapinfo::UMapInfo;
+use wad::{WadData, dehacked};
 
-pub const DEMO_MARKER: u8 = 0x80;
 pub const BACKUPTICS: usize = 12;
+/// Chat characters waiting to go out with the console player's commands
+const CHAT_QUEUE_SIZE: usize = 128;
 /// Description of the unregistered shareware release
 pub const DESC_SHAREWARE: &str = "DOOM Shareware";
 /// Description of registered shareware release
@@ -57,19 +63,6 @@ pub const DESC_ULTIMATE: &str = "The Ultimate DOOM";
 /// Description of DOOM II commercial release
 pub const DESC_COMMERCIAL: &str = "DOOM 2: Hell on Earth";
 
-/// Data and details used for playback of demos
-pub struct DemoData {
-    /// Demo being played?
-    pub playback: bool,
-    /// Is in the overall demo loop? (titles, credits, demos)
-    pub advance: bool,
-    sequence: i8,
-    buffer: Peekable<IntoIter<u8>>,
-    name: String,
-    /// Exit the game when this demo finishes (set by --demo CLI arg).
-    exit_on_finish: bool,
-}
-
 /// Details used for the demo screens (title, help, ordering)
 pub struct PageData {
     pub name: &'static str,
@@ -123,7 +116,7 @@ pub struct Game {
     running: bool,
 
     /// Showing automap?
-    automap: bool,
+    pub automap: bool,
     /// player taking events and displaying
     pub consoleplayer: usize,
     /// view being displayed
@@ -152,10 +145,18 @@ pub struct Game {
     /// Intermission and world/map end data, used to show map and world stats,
     /// and queue up the next map or episode.
     world_info: WorldInfo,
-    // d_net.c
+    /// Every player's commands for the next `BACKUPTICS` tics, indexed by
+    /// tic modulo `BACKUPTICS`
     pub netcmds: [[TicCmd; BACKUPTICS]; MAXPLAYERS],
-    // d_net.c
-    _localcmds: [TicCmd; BACKUPTICS],
+    /// The netgame being played, if any
+    net: Option<NetGame>,
+    /// Per player, the state hash of each of the last `BACKUPTICS` tics that
+    /// their commands are checked against
+    consistancy: [[i16; BACKUPTICS]; MAXPLAYERS],
+    /// Pause (or unpause) every node with the next command
+    send_pause: bool,
+    /// Chat characters to send, one per command
+    chat_queue: VecDeque<u8>,
     usergame: bool,
     game_skill: Skill,
     pub paused: bool,
@@ -164,6 +165,10 @@ pub struct Game {
     pub frozen: bool,
     pub config_values: [i32; ConfigKey::KeyCount as usize],
     pub config_dirty: bool,
+    /// Action bindings, shared by the tic builder and the menus.
+    pub bindings: Bindings,
+    /// Modifier keys held as of the last input event.
+    pub held_mods: Modifiers,
     /// Pending save/load filename (without extension)
     save_name: Option<String>,
     /// User-editable description for the save slot
@@ -180,6 +185,7 @@ pub struct Game {
 
 impl Drop for Game {
     fn drop(&mut self) {
+        self.demo.end_recording();
         // Best-effort shutdown: never panic in `drop` (a panic here during
         // unwinding aborts the process). Log and continue instead.
         if let Err(e) = self.sound_cmd.send(SoundAction::Shutdown) {
@@ -260,6 +266,18 @@ pub fn prepare_wad(mut options: GameOptions, mut wad: WadData) -> (GameOptions,
         }
     }
 
+    let mut patches = wad.dehacked();
+    for path in &options.deh {
+        match std::fs::read(path) {
+            Ok(bytes) => match dehacked::parse(&String::from_utf8_lossy(&bytes)) {
+                Ok(patch) => patches.push(patch),
+                Err(e) => warn!("{path}: {e}"),
+            },
+            Err(e) => warn!("Could not read {path}: {e}"),
+        }
+    }
+    apply_dehacked(&patches);
+
     println!(
         "\nROOM-4-DOOM v{}. Playing {}",
         env!("CARGO_PKG_VERSION"),
@@ -321,21 +339,24 @@ impl Game {
 
         let lump = wad.get_lump("TITLEPIC").expect("TITLEPIC missing");
         let page_cache = WadPatch::from_lump(lump);
-        let pic_data = PicData::init(&wad, &gameplay::SPRNAMES);
+        let pic_data = PicData::init(&wad, gameplay::sprite_names());
+
+        let mut demo = DemoData::new();
+        if let Some(name) = &options.record {
+            let format = if options.longtics {
+                DemoFormat::LongTics
+            } else {
+                DemoFormat::Vanilla
+            };
+            demo.record(name, format);
+        }
 
         Self {
             wad_data: wad,
             wad_name,
             level_start_tic: 0,
             level: None,
-            demo: DemoData {
-                playback: false,
-                buffer: Vec::new().into_iter().peekable(),
-                name: String::new(),
-                advance: false,
-                sequence: 0,
-                exit_on_finish: false,
-            },
+            demo,
             page: PageData {
                 name: "TITLEPIC",
                 cache: page_cache,
@@ -369,7 +390,10 @@ impl Game {
             world_info: WorldInfo::default(),
 
             netcmds: [[TicCmd::new(); BACKUPTICS]; MAXPLAYERS],
-            _localcmds: [TicCmd::new(); BACKUPTICS],
+            net: None,
+            consistancy: [[0; BACKUPTICS]; MAXPLAYERS],
+            send_pause: false,
+            chat_queue: VecDeque::with_capacity(CHAT_QUEUE_SIZE),
 
             usergame: false,
             game_skill: Skill::default(),
@@ -377,6 +401,8 @@ impl Game {
             frozen: false,
             config_values: initial_config,
             config_dirty: false,
+            bindings: Bindings::default(),
+            held_mods: Modifiers::default(),
             save_name: None,
             save_description: String::new(),
             options,
@@ -412,12 +438,16 @@ impl Game {
         debug!("Entered do_new_game");
 
         self.options.respawn_monsters = matches!(self.options.skill, Skill::Nightmare);
-        self.options.netgame = false;
-        self.options.deathmatch = 0;
-        self.players_in_game.fill(false);
-        self.players_in_game[self.consoleplayer] = true;
+        // A netgame keeps the players and rules it was started with
+        if self.net.is_none() {
+            self.options.netgame = false;
+            self.options.deathmatch = 0;
+            self.players_in_game.fill(false);
+            self.players_in_game[self.consoleplayer] = true;
+        }
 
         self.init_new();
+        self.begin_demo_recording();
         self.pending_action = GameAction::None;
     }
 
@@ -577,6 +607,13 @@ impl Game {
                     &self.players_in_game,
                 );
             }
+            if self.options.deathmatch != 0 {
+                MapObject::spawn_deathmatch_players(
+                    level,
+                    &mut self.players,
+                    &self.players_in_game,
+                );
+            }
             spawn_specials(level);
             level.boss_actions = map_entry.and_then(|e| e.boss_actions.clone());
 
@@ -592,7 +629,15 @@ impl Game {
         self.world_info.maxfrags = 0;
         self.players[self.consoleplayer].viewz = 1.into();
 
-        self.world_info.partime = map_entry.and_then(|e| e.par_time).unwrap_or(180);
+        let deh_episode = if self.game_type.mode == GameMode::Commercial {
+            0
+        } else {
+            self.options.episode
+        };
+        self.world_info.partime = map_entry
+            .and_then(|e| e.par_time)
+            .or_else(|| gameplay::par_time(deh_episode, self.options.map))
+            .unwrap_or(180);
 
         if let Some(music) = map_entry.and_then(|e| e.music.as_deref()) {
             self.change_music_by_lump(music);
@@ -601,11 +646,23 @@ impl Game {
         }
     }
 
-    fn do_reborn(&mut self, _player_num: usize) {
-        info!("Player respawned");
-        self.pending_action = GameAction::LoadLevel;
-        // self.players[player_num].
-        // TODO: deathmatch spawns
+    /// A single player restarts the level, netgame players respawn in it.
+    ///
+    /// Doom function name `G_DoReborn`
+    fn do_reborn(&mut self, player_num: usize) {
+        if !self.is_netgame() {
+            info!("Player respawned");
+            self.pending_action = GameAction::LoadLevel;
+            return;
+        }
+        if let Some(level) = &mut self.level {
+            MapObject::net_reborn_player(
+                player_num,
+                level,
+                &mut self.players,
+                &self.players_in_game,
+            );
+        }
     }
 
     /// Directory for save files.
@@ -786,24 +843,8 @@ impl Game {
 
     /// G_ReadDemoTicCmd
     fn read_demo_tic_cmd(&mut self, cmd: &mut TicCmd) {
-        if let Some(byte) = self.demo.buffer.peek()
-            && *byte == DEMO_MARKER
-        {
+        if !self.demo.read_tic_cmd(cmd) {
             self.check_demo_status();
-            return;
-        }
-
-        if let Some(byte) = self.demo.buffer.next() {
-            cmd.forwardmove = byte as i8;
-        }
-        if let Some(byte) = self.demo.buffer.next() {
-            cmd.sidemove = byte as i8;
-        }
-        if let Some(byte) = self.demo.buffer.next() {
-            cmd.angleturn = (byte as i16) << 8;
-        }
-        if let Some(byte) = self.demo.buffer.next() {
-            cmd.buttons = byte;
         }
     }
 
@@ -891,58 +932,32 @@ impl Game {
     fn do_play_demo(&mut self) {
         self.pending_action = GameAction::None;
 
-        if let Some(demo) = self.wad_data.get_lump(&self.demo.name) {
-            self.demo.buffer = demo.data.clone().into_iter().peekable();
-
-            if let Some(byte) = self.demo.buffer.next()
-                && byte != 109
-            {
-                self.pending_action = GameAction::None;
-                return;
-            }
-
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.skill = Skill::from(byte);
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.episode = byte as usize;
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.map = byte as usize;
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.deathmatch = byte;
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.respawn_parm = byte == 1;
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.options.fast_parm = byte == 1;
-            }
-            if let Some(byte) = self.demo.buffer.next()
-                && !self.options.no_monsters
-            {
-                self.options.no_monsters = byte == 1;
-            }
-            if let Some(byte) = self.demo.buffer.next() {
-                self.consoleplayer = byte as usize;
-            }
-            for player in &mut self.players_in_game {
-                if let Some(byte) = self.demo.buffer.next() {
-                    *player = byte == 1;
-                }
-            }
-            if self.players_in_game[1] {
-                // TODO: netgame stuff
-            }
-
-            self.init_new();
-            self.usergame = false;
-            self.demo.playback = true;
-        } else {
+        let Some(data) = self.demo_bytes() else {
             error!("Demo {} does not exist", self.demo.name);
-            self.pending_action = GameAction::None;
+            return;
+        };
+        let Some(header) = self.demo.start_playback(data) else {
+            return;
+        };
+
+        self.options.skill = header.skill;
+        self.options.episode = header.episode;
+        self.options.map = header.map;
+        self.options.deathmatch = header.deathmatch;
+        self.options.respawn_parm = header.respawn_parm;
+        self.options.fast_parm = header.fast_parm;
+        if !self.options.no_monsters {
+            self.options.no_monsters = header.no_monsters;
+        }
+        self.consoleplayer = header.consoleplayer;
+        self.players_in_game = header.players_in_game;
+        if self.players_in_game[1] {
+            // TODO: netgame stuff
         }
+
+        self.init_new();
+        self.usergame = false;
+        self.demo.playback = true;
     }
 
     /// Load the next level and set the `GameAction` to None
@@ -961,6 +976,7 @@ impl Game {
     /// and trigger victory or intermission state.
     fn do_completed(&mut self) {
         self.pending_action = GameAction::None;
+        self.automap = false;
 
         for (i, in_game) in self.players_in_game.iter().enumerate() {
             if *in_game {
@@ -1093,7 +1109,7 @@ impl Game {
 
     fn start_finale(&mut self) {
         self.world_info.didsecret = self.players[self.consoleplayer].didsecret;
-        self.world_info.episode = self.options.episode;
+        self.world_info.episode = self.options.episode - 1;
         self.world_info.last = self.options.map;
 
         self.gamestate = GameState::Finale;
@@ -1107,14 +1123,16 @@ impl Game {
     /// change the game-exe state or cause an action through `GameAction`.
     ///
     /// Doom function name `G_Ticker`
-    pub fn ticker<I, S, H, F>(&mut self, machinations: &mut GameSubsystem<I, S, H, F>)
+    pub fn ticker<I, S, H, F, A>(&mut self, machinations: &mut GameSubsystem<I, S, H, F, A>)
     where
         I: SubsystemTrait,
         S: SubsystemTrait,
         H: SubsystemTrait,
         F: SubsystemTrait,
+        A: SubsystemTrait,
     {
         trace!("Entered ticker");
+        self.net_drop_players();
         // do player reborns if needed
         for i in 0..MAXPLAYERS {
             if self.players_in_game[i] && self.players[i].player_state == PlayerState::Reborn {
@@ -1154,49 +1172,39 @@ impl Game {
             GameAction::Screenshot => todo!("M_ScreenShot(); gameaction = ga_nothing"),
         }
 
-        // TODO: get commands, check consistancy,
-        // and build new consistancy check
-        // buf = (gametic / ticdup) % BACKUPTICS;
+        // get commands, check consistancy, and build new consistancy check
+        let buf = self.game_tic as usize % BACKUPTICS;
+        let check = self.is_netgame() && !self.demo.playback;
+        let consistancy = if check { self.consistancy_value() } else { 0 };
 
-        // Checks ticcmd consistency and turbo cheat
         for i in 0..MAXPLAYERS {
             if self.players_in_game[i] {
                 // sets the players cmd for this tic
-                self.players[i].cmd = self.netcmds[i][0];
-                // memcpy(cmd, &netcmds[i][buf], sizeof(ticcmd_t));
+                self.players[i].cmd = self.netcmds[i][buf];
                 if self.demo.playback {
                     let mut cmd = self.players[i].cmd;
                     self.read_demo_tic_cmd(&mut cmd);
                     self.players[i].cmd = cmd;
+                } else if self.demo.recording {
+                    self.demo.write_tic_cmd(&mut self.players[i].cmd);
+                }
+                if check {
+                    self.check_consistancy(i, buf, consistancy);
                 }
-                // if (demorecording)
-                //     TODO: G_WriteDemoTiccmd(cmd);
-                // TODO: Netgame stuff here
             }
         }
 
         // check for special buttons
         for i in 0..MAXPLAYERS {
-            #[allow(clippy::if_same_then_else)]
             if self.players_in_game[i]
                 && self.players[i].cmd.buttons & TIC_CMD_BUTTONS.bt_special > 0
             {
                 let mask = self.players[i].cmd.buttons & TIC_CMD_BUTTONS.bt_specialmask;
-                if mask == TIC_CMD_BUTTONS.bt_specialmask {
-                    //     paused ^= 1;
-                    //     if (paused)
-                    //         S_PauseSound();
-                    //     else
-                    //         S_ResumeSound();
-                    //     break;
-                } else if mask == TIC_CMD_BUTTONS.bts_savegame {
-                    //     if (!savedescription[0])
-                    //         strcpy(savedescription, "NET GAME");
-                    //     savegameslot =
-                    //         (players[i].cmd.buttons & BTS_SAVEMASK) >>
-                    // BTS_SAVESHIFT;     gameaction =
-                    // ga_savegame;     break;
+                if mask == TIC_CMD_BUTTONS.bts_pause {
+                    self.paused = !self.paused;
+                    // TODO: S_PauseSound() / S_ResumeSound()
                 }
+                // TODO: BTS_SAVEGAME, saving the game at each console
             }
         }
 
@@ -1207,15 +1215,22 @@ impl Game {
                 // update statusbar information
                 machinations.statusbar.ticker(self);
                 // update the automap display info
-                // AM_Ticker();
+                self.mark_seen_lines();
+                machinations.automap.ticker(self);
                 // update the HUD statuses (things like timeout displayed messages)
                 machinations.hud_msgs.ticker(self);
             }
             GameState::Intermission => {
+                if self.is_netgame() && self.net_accelerate() {
+                    machinations.intermission.responder(KeyCode::Return, self);
+                }
                 // WI_Ticker calls world_done()
                 machinations.intermission.ticker(self);
             }
             GameState::Finale => {
+                if self.is_netgame() && self.net_accelerate() {
+                    machinations.finale.responder(KeyCode::Return, self);
+                }
                 machinations.finale.ticker(self);
             }
             GameState::DemoScreen => {
@@ -1227,6 +1242,18 @@ impl Game {
         }
     }
 
+    /// Reveal automap lines in view of the console player. The 2.5D renderer
+    /// also marks lines as it draws; this covers every renderer.
+    fn mark_seen_lines(&mut self) {
+        if let Some(level) = &mut self.level
+            && let Some(mobj) = self.players[self.consoleplayer].mobj()
+        {
+            level
+                .level_data
+                .mark_seen_lines(mobj.x, mobj.y, mobj.angle.inner().0);
+        }
+    }
+
     /// Gameplay ticker. Updates the game-exe level state along with all
     /// thinkers inside that level. Also watches for `TicCmd` that initiate
     /// another action or state such as pausing in menus, demo recording,
@@ -0,0 +1,990 @@
+//! Peer-to-peer lockstep netgames over UDP.
+//!
+//! Every node runs the whole game and sends its console player's `TicCmd`s to
+//! every other node. A tic only runs once the commands of every player in the
+//! game have arrived, so all nodes step through the same commands. Each
+//! packet carries all of the sender's commands the receiver hasn't
+//! acknowledged yet (never more than `BACKUPTICS`), so a lost packet is
+//! covered by the next one, along with the acknowledgement of the receiver's
+//! own commands.
+//!
+//! Each command also carries the game state hash
+//! (`gameplay::demo_trace::state_hash`) of the tic `BACKUPTICS` before it,
+//! which the other nodes check against their own to catch a desync.
+//!
+//! Nodes start with a handshake: each sends `Hello` until it has heard from
+//! all the others and they from it. Player 0's hello carries the game
+//! settings (skill, map, deathmatch, ...) the others adopt.
+//!
+//! Doom source names `d_net.c` and `i_net.c`
+
+use std::array;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, ToSocketAddrs as _, UdpSocket};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use game_config::tic_cmd::{TIC_CMD_BUTTONS, TicCmd};
+use game_config::{GameOptions, NetSettings, Skill};
+use gameplay::{GameAction, MAXPLAYERS, english};
+use log::{error, info, warn};
+use math::get_prndindex;
+
+use crate::{BACKUPTICS, Game};
+
+/// Default UDP port of a node
+pub const DOOMPORT: u16 = 5029;
+/// How long to wait for the other players to show up
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(120);
+
+const NET_MAGIC: [u8; 4] = *b"R4DN";
+const NET_VERSION: u8 = 1;
+const KIND_HELLO: u8 = 0;
+const KIND_TICS: u8 = 1;
+/// Magic, version, kind and sender
+const HEADER_SIZE: usize = 7;
+/// Ack, start tic, exit flag and command count of a `Tics` packet
+const TICS_HEADER_SIZE: usize = 10;
+/// Bytes per command on the wire
+const TIC_SIZE: usize = 10;
+/// Largest packet: a `Tics` packet with a full resend window
+const MAX_PACKET: usize = HEADER_SIZE + TICS_HEADER_SIZE + BACKUPTICS * TIC_SIZE;
+const HELLO_INTERVAL: Duration = Duration::from_millis(100);
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+/// How often unacknowledged commands are resent, about once a tic
+const RESEND_INTERVAL: Duration = Duration::from_millis(28);
+/// Silence from a player before it gets logged
+const STALL_WARNING: Duration = Duration::from_secs(5);
+/// The goodbye is sent a few times in case some of the packets get lost
+const EXIT_REPEATS: usize = 4;
+/// Tries at sending a packet past errors left by earlier ones
+const SEND_TRIES: usize = 4;
+
+/// Game settings player 0 hands out during the handshake
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NetSetup {
+    skill: Skill,
+    episode: u8,
+    map: u8,
+    deathmatch: u8,
+    no_monsters: bool,
+    respawn_parm: bool,
+    fast_parm: bool,
+}
+
+impl NetSetup {
+    fn from_options(options: &GameOptions) -> Self {
+        Self {
+            skill: options.skill,
+            episode: options.episode as u8,
+            map: options.map as u8,
+            deathmatch: options.deathmatch,
+            no_monsters: options.no_monsters,
+            respawn_parm: options.respawn_parm,
+            fast_parm: options.fast_parm,
+        }
+    }
+
+    fn apply(self, options: &mut GameOptions) {
+        options.skill = self.skill;
+        options.episode = self.episode as usize;
+        options.map = self.map as usize;
+        options.deathmatch = self.deathmatch;
+        options.no_monsters = self.no_monsters;
+        options.respawn_parm = self.respawn_parm;
+        options.fast_parm = self.fast_parm;
+    }
+
+    fn encode(self, out: &mut Vec<u8>) {
+        let flags = u8::from(self.no_monsters)
+            | (u8::from(self.respawn_parm) << 1)
+            | (u8::from(self.fast_parm) << 2);
+        out.extend([
+            (self.skill as i32 + 1) as u8,
+            self.episode,
+            self.map,
+            self.deathmatch,
+            flags,
+        ]);
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        let [skill, episode, map, deathmatch, flags] = *data else {
+            return None;
+        };
+        // Skill is sent off by one for the "-skill 0" hack
+        let skill = skill as i32 - 1;
+        if skill > Skill::Nightmare as i32 {
+            return None;
+        }
+        Some(Self {
+            skill: Skill::from(skill),
+            episode,
+            map,
+            deathmatch,
+            no_monsters: flags & 1 != 0,
+            respawn_parm: flags & 2 != 0,
+            fast_parm: flags & 4 != 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Packet {
+    /// Handshake: how many players the sender expects, a bit per player it
+    /// has heard from, and the game settings if it is player 0
+    Hello {
+        numplayers: u8,
+        heard: u8,
+        setup: NetSetup,
+    },
+    /// The sender's commands for `start..start + cmds.len()`, and the first
+    /// of the receiver's tics the sender doesn't have. With `exit` set the
+    /// sender leaves the game after the last of these commands.
+    Tics {
+        ack: u32,
+        start: u32,
+        exit: bool,
+        cmds: Vec<TicCmd>,
+    },
+}
+
+impl Packet {
+    fn encode(&self, sender: usize) -> Vec<u8> {
+        let mut out = Vec::with_capacity(MAX_PACKET);
+        out.extend(NET_MAGIC);
+        out.push(NET_VERSION);
+        match self {
+            Self::Hello {
+                numplayers,
+                heard,
+                setup,
+            } => {
+                out.extend([KIND_HELLO, sender as u8, *numplayers, *heard]);
+                setup.encode(&mut out);
+            }
+            Self::Tics {
+                ack,
+                start,
+                exit,
+                cmds,
+            } => {
+                out.extend([KIND_TICS, sender as u8]);
+                out.extend(ack.to_le_bytes());
+                out.extend(start.to_le_bytes());
+                out.extend([u8::from(*exit), cmds.len() as u8]);
+                for cmd in cmds {
+                    encode_cmd(cmd, &mut out);
+                }
+            }
+        }
+        out
+    }
+
+    /// The sender and contents of a packet, or `None` if it isn't one of
+    /// ours.
+    fn decode(data: &[u8]) -> Option<(usize, Self)> {
+        let (header, body) = data.split_at_checked(HEADER_SIZE)?;
+        if header[..4] != NET_MAGIC || header[4] != NET_VERSION {
+            return None;
+        }
+        let sender = header[6] as usize;
+        if sender >= MAXPLAYERS {
+            return None;
+        }
+        let packet = match header[5] {
+            KIND_HELLO => {
+                let (&[numplayers, heard], setup) = body.split_first_chunk()?;
+                Self::Hello {
+                    numplayers,
+                    heard,
+                    setup: NetSetup::decode(setup)?,
+                }
+            }
+            KIND_TICS => {
+                let (fixed, cmds) = body.split_at_checked(TICS_HEADER_SIZE)?;
+                let count = fixed[9] as usize;
+                if count > BACKUPTICS || cmds.len() != count * TIC_SIZE {
+                    return None;
+                }
+                Self::Tics {
+                    ack: u32::from_le_bytes([fixed[0], fixed[1], fixed[2], fixed[3]]),
+                    start: u32::from_le_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]),
+                    exit: fixed[8] != 0,
+                    cmds: cmds.chunks_exact(TIC_SIZE).map(decode_cmd).collect(),
+                }
+            }
+            _ => return None,
+        };
+        Some((sender, packet))
+    }
+}
+
+fn encode_cmd(cmd: &TicCmd, out: &mut Vec<u8>) {
+    out.extend([cmd.forwardmove as u8, cmd.sidemove as u8]);
+    out.extend(cmd.angleturn.to_le_bytes());
+    out.extend(cmd.consistancy.to_le_bytes());
+    out.extend([cmd.chatchar, cmd.buttons]);
+    out.extend(cmd.lookdir.to_le_bytes());
+}
+
+fn decode_cmd(data: &[u8]) -> TicCmd {
+    TicCmd {
+        forwardmove: data[0] as i8,
+        sidemove: data[1] as i8,
+        angleturn: i16::from_le_bytes([data[2], data[3]]),
+        consistancy: i16::from_le_bytes([data[4], data[5]]),
+        chatchar: data[6],
+        buttons: data[7],
+        lookdir: i16::from_le_bytes([data[8], data[9]]),
+    }
+}
+
+/// A node of a peer-to-peer netgame. Sends the console player's commands to
+/// every other node and collects theirs into `Game::netcmds`.
+pub struct NetGame {
+    socket: UdpSocket,
+    /// Address of each other player's node
+    peers: [Option<SocketAddr>; MAXPLAYERS],
+    consoleplayer: usize,
+    players_in_game: [bool; MAXPLAYERS],
+    /// Game settings, as handed out by player 0
+    setup: NetSetup,
+    /// The console player's commands, kept until every peer has them
+    localcmds: [TicCmd; BACKUPTICS],
+    /// The tic the console player's next command is for
+    maketic: u32,
+    /// Per player, the first tic whose command hasn't arrived yet
+    nettics: [u32; MAXPLAYERS],
+    /// Per player, the first of the console player's commands they don't
+    /// have
+    acked: [u32; MAXPLAYERS],
+    /// Per player, the tic they leave the game at
+    exit_tic: [Option<u32>; MAXPLAYERS],
+    last_send: Instant,
+    last_heard: [Instant; MAXPLAYERS],
+    last_warning: Instant,
+}
+
+impl NetGame {
+    /// Listen on the port given in `settings` and handshake with every peer.
+    /// Blocks until all of them are there, or `timeout` passes. The game
+    /// settings of player 0 are written to `options`.
+    ///
+    /// Doom function name `D_CheckNetGame`
+    pub fn connect(
+        settings: &NetSettings,
+        options: &mut GameOptions,
+        timeout: Duration,
+    ) -> io::Result<Self> {
+        let numplayers = settings.peers.len() + 1;
+        if numplayers > MAXPLAYERS || settings.player >= numplayers {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "player {} of {numplayers} is not a valid netgame player",
+                    settings.player + 1
+                ),
+            ));
+        }
+
+        let mut peers = [None; MAXPLAYERS];
+        let others = (0..numplayers).filter(|&p| p != settings.player);
+        for (player, peer) in others.zip(&settings.peers) {
+            let addrs = match peer.to_socket_addrs() {
+                Ok(addrs) => addrs,
+                Err(_) => (peer.as_str(), DOOMPORT).to_socket_addrs()?,
+            };
+            let addr = addrs.into_iter().find(SocketAddr::is_ipv4).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::AddrNotAvailable,
+                    format!("no IPv4 address for player {}: {peer}", player + 1),
+                )
+            })?;
+            peers[player] = Some(addr);
+        }
+
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, settings.port))?;
+        socket.set_nonblocking(true)?;
+        info!(
+            "Netgame: player {} of {numplayers}, listening on port {}",
+            settings.player + 1,
+            settings.port
+        );
+
+        let now = Instant::now();
+        let mut net = Self {
+            socket,
+            peers,
+            consoleplayer: settings.player,
+            players_in_game: array::from_fn(|p| p < numplayers),
+            setup: NetSetup::from_options(options),
+            localcmds: [TicCmd::new(); BACKUPTICS],
+            maketic: 0,
+            nettics: [0; MAXPLAYERS],
+            acked: [0; MAXPLAYERS],
+            exit_tic: [None; MAXPLAYERS],
+            last_send: now,
+            last_heard: [now; MAXPLAYERS],
+            last_warning: now,
+        };
+        net.arbitrate(timeout)?;
+        net.setup.apply(options);
+        info!("Netgame: all {numplayers} players are in, {:?}", net.setup);
+        Ok(net)
+    }
+
+    /// Exchange hellos until every node has heard from every other, taking
+    /// the game settings from player 0.
+    ///
+    /// Doom function name `D_ArbitrateNetStart`
+    fn arbitrate(&mut self, timeout: Duration) -> io::Result<()> {
+        let everyone = self.player_mask();
+        let mut heard = 1 << self.consoleplayer;
+        // Players known to have heard from everyone
+        let mut ready = heard;
+        let mut have_setup = self.consoleplayer == 0;
+        let start = Instant::now();
+        let mut last_hello: Option<Instant> = None;
+
+        while heard != everyone || ready != everyone || !have_setup {
+            if start.elapsed() > timeout {
+                let missing: Vec<usize> = (0..MAXPLAYERS)
+                    .filter(|p| everyone & !ready & (1 << p) != 0)
+                    .map(|p| p + 1)
+                    .collect();
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out waiting for players {missing:?}"),
+                ));
+            }
+            if last_hello.is_none_or(|t| t.elapsed() >= HELLO_INTERVAL) {
+                self.send_hello(heard)?;
+                last_hello = Some(Instant::now());
+            }
+
+            while let Some((player, packet)) = self.recv()? {
+                let bit = 1 << player;
+                match packet {
+                    Packet::Hello {
+                        numplayers,
+                        heard: theirs,
+                        setup,
+                    } => {
+                        if numplayers as usize != self.numplayers() {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                format!(
+                                    "player {} expects {numplayers} players, not {}",
+                                    player + 1,
+                                    self.numplayers()
+                                ),
+                            ));
+                        }
+                        heard |= bit;
+                        if theirs == everyone {
+                            ready |= bit;
+                        }
+                        if player == 0 {
+                            self.setup = setup;
+                            have_setup = true;
+                        }
+                    }
+                    // Already playing, so they've heard from everyone
+                    Packet::Tics {
+                        ..
+                    } => {
+                        heard |= bit;
+                        ready |= bit;
+                    }
+                }
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        // For anyone still waiting on us. If this is lost they take our first
+        // commands instead.
+        self.send_hello(heard)
+    }
+
+    /// A bit per player in the game
+    fn player_mask(&self) -> u8 {
+        (0..MAXPLAYERS)
+            .filter(|&p| self.players_in_game[p])
+            .fold(0, |mask, p| mask | (1 << p))
+    }
+
+    fn numplayers(&self) -> usize {
+        self.players_in_game.iter().filter(|&&p| p).count()
+    }
+
+    /// Is `player` another node still in the game?
+    fn is_active_peer(&self, player: usize) -> bool {
+        player != self.consoleplayer
+            && self.players_in_game[player]
+            && self.exit_tic[player].is_none()
+    }
+
+    fn send(&self, player: usize, packet: &Packet) -> io::Result<()> {
+        let Some(addr) = self.peers[player] else {
+            return Ok(());
+        };
+        let data = packet.encode(self.consoleplayer);
+        for _ in 0..SEND_TRIES {
+            match self.socket.send_to(&data, addr) {
+                Ok(_) => return Ok(()),
+                // As good as a lost packet, it gets resent
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                // Left over from an earlier packet, this one didn't go yet
+                Err(e) if is_port_unreachable(&e) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn send_hello(&self, heard: u8) -> io::Result<()> {
+        let hello = Packet::Hello {
+            numplayers: self.numplayers() as u8,
+            heard,
+            setup: self.setup,
+        };
+        for player in 0..MAXPLAYERS {
+            if self.is_active_peer(player) {
+                self.send(player, &hello)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Send every peer the console player's commands they don't have yet.
+    ///
+    /// Doom function name `HSendPacket`
+    fn send_tics(&mut self, exit: bool) -> io::Result<()> {
+        for player in 0..MAXPLAYERS {
+            if !self.is_active_peer(player) {
+                continue;
+            }
+            let start = self.acked[player];
+            let cmds = (start..self.maketic)
+                .map(|tic| self.localcmds[tic as usize % BACKUPTICS])
+                .collect();
+            let tics = Packet::Tics {
+                ack: self.nettics[player],
+                start,
+                exit,
+                cmds,
+            };
+            self.send(player, &tics)?;
+        }
+        self.last_send = Instant::now();
+        Ok(())
+    }
+
+    /// The next well formed packet from a peer, if any are waiting.
+    ///
+    /// Doom function name `HGetPacket`
+    fn recv(&self) -> io::Result<Option<(usize, Packet)>> {
+        let mut buf = [0; MAX_PACKET];
+        loop {
+            let len = match self.socket.recv_from(&mut buf) {
+                Ok((len, _)) => len,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+                Err(e) if is_port_unreachable(&e) => continue,
+                Err(e) => return Err(e),
+            };
+            match Packet::decode(&buf[..len]) {
+                Some((player, packet)) if self.peers[player].is_some() => {
+                    return Ok(Some((player, packet)));
+                }
+                _ => warn!("Netgame: dropped a bad packet"),
+            }
+        }
+    }
+
+    /// The tic the console player's next command is for
+    pub const fn maketic(&self) -> u32 {
+        self.maketic
+    }
+
+    /// Can the console player make another command? Not if it would run too
+    /// far ahead of the game, or past what a peer has acknowledged for the
+    /// resend window to still cover.
+    pub fn can_make_tic(&self, gametic: u32) -> bool {
+        self.maketic.saturating_sub(gametic) < (BACKUPTICS / 2) as u32
+            && (0..MAXPLAYERS)
+                .filter(|&p| self.is_active_peer(p))
+                .all(|p| self.maketic - self.acked[p] < BACKUPTICS as u32)
+    }
+
+    /// Queue the console player's command for the next tic and send it off.
+    ///
+    /// Doom function name `NetUpdate`
+    pub fn make_tic(
+        &mut self,
+        cmd: TicCmd,
+        netcmds: &mut [[TicCmd; BACKUPTICS]; MAXPLAYERS],
+    ) -> io::Result<()> {
+        let buf = self.maketic as usize % BACKUPTICS;
+        self.localcmds[buf] = cmd;
+        netcmds[self.consoleplayer][buf] = cmd;
+        self.maketic += 1;
+        self.nettics[self.consoleplayer] = self.maketic;
+        self.send_tics(false)
+    }
+
+    /// Take in the commands that have arrived, and resend ours if nothing has
+    /// gone out for a while.
+    ///
+    /// Doom function name `GetPackets`
+    pub fn update(&mut self, netcmds: &mut [[TicCmd; BACKUPTICS]; MAXPLAYERS]) -> io::Result<()> {
+        while let Some((player, packet)) = self.recv()? {
+            self.last_heard[player] = Instant::now();
+            match packet {
+                // Still in the handshake. Commands tell them we're all here
+                // without asking for a reply, a hello would echo forever.
+                Packet::Hello {
+                    ..
+                } => self.send_tics(false)?,
+                Packet::Tics {
+                    ack,
+                    start,
+                    exit,
+                    cmds,
+                } => self.take_tics(player, ack, start, exit, &cmds, netcmds),
+            }
+        }
+        if self.last_send.elapsed() >= RESEND_INTERVAL {
+            self.send_tics(false)?;
+        }
+        self.warn_stalled();
+        Ok(())
+    }
+
+    fn take_tics(
+        &mut self,
+        player: usize,
+        ack: u32,
+        start: u32,
+        exit: bool,
+        cmds: &[TicCmd],
+        netcmds: &mut [[TicCmd; BACKUPTICS]; MAXPLAYERS],
+    ) {
+        if !self.is_active_peer(player) {
+            return;
+        }
+        self.acked[player] = self.acked[player].max(ack.min(self.maketic));
+
+        let next = self.nettics[player];
+        // Out of order, wait for the resend that fills the gap
+        if start > next {
+            return;
+        }
+        let end = start.saturating_add(cmds.len() as u32);
+        for (tic, cmd) in (start..end).zip(cmds) {
+            if tic >= next {
+                netcmds[player][tic as usize % BACKUPTICS] = *cmd;
+            }
+        }
+        self.nettics[player] = next.max(end);
+        if exit {
+            info!("Netgame: player {} leaves at tic {end}", player + 1);
+            self.exit_tic[player] = Some(self.nettics[player]);
+        }
+    }
+
+    fn warn_stalled(&mut self) {
+        if self.last_warning.elapsed() < STALL_WARNING {
+            return;
+        }
+        for player in 0..MAXPLAYERS {
+            let silence = self.last_heard[player].elapsed();
+            if self.is_active_peer(player) && silence >= STALL_WARNING {
+                warn!(
+                    "Netgame: nothing from player {} for {}s",
+                    player + 1,
+                    silence.as_secs()
+                );
+                self.last_warning = Instant::now();
+            }
+        }
+    }
+
+    /// Have the commands of every player still in the game for `gametic`
+    /// arrived?
+    pub fn tic_ready(&self, gametic: u32) -> bool {
+        (0..MAXPLAYERS)
+            .filter(|&p| self.players_in_game[p])
+            .all(|p| {
+                self.nettics[p] > gametic || self.exit_tic[p].is_some_and(|exit| gametic >= exit)
+            })
+    }
+
+    /// The tic `player` leaves the game at, once they've said they're going
+    pub const fn exit_tic(&self, player: usize) -> Option<u32> {
+        self.exit_tic[player]
+    }
+}
+
+impl Drop for NetGame {
+    /// Doom function name `D_QuitNetGame`
+    fn drop(&mut self) {
+        for _ in 0..EXIT_REPEATS {
+            if let Err(e) = self.send_tics(true) {
+                warn!("Netgame: could not say goodbye: {e}");
+                break;
+            }
+        }
+    }
+}
+
+/// An ICMP port unreachable for a packet sent to a node that isn't up yet, or
+/// has left. It surfaces on whichever socket call comes next, even one for
+/// another node.
+fn is_port_unreachable(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset
+    )
+}
+
+impl Game {
+    /// Join the netgame `net` connected to: take on its players and start a
+    /// new game on the next tic.
+    pub fn start_net_game(&mut self, net: NetGame) {
+        self.options.netgame = true;
+        self.consoleplayer = net.consoleplayer;
+        self.players_in_game = net.players_in_game;
+        self.net = Some(net);
+        self.pending_action = GameAction::NewGame;
+    }
+
+    /// Make the console player's command for the next tic if the netgame
+    /// allows, and exchange packets with the other players. The command gets
+    /// the consistency check for its tic and the next queued chat character.
+    ///
+    /// Doom function name `NetUpdate`
+    pub fn net_update(&mut self, mut cmd: TicCmd) {
+        let Some(net) = self.net.as_mut() else {
+            return;
+        };
+        let result = if net.can_make_tic(self.game_tic) {
+            let buf = net.maketic() as usize % BACKUPTICS;
+            cmd.consistancy = self.consistancy[self.consoleplayer][buf];
+            cmd.chatchar = self.chat_queue.pop_front().unwrap_or(0);
+            if self.send_pause {
+                self.send_pause = false;
+                cmd.buttons = TIC_CMD_BUTTONS.bt_special | TIC_CMD_BUTTONS.bts_pause;
+            }
+            net.make_tic(cmd, &mut self.netcmds)
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result.and_then(|()| net.update(&mut self.netcmds)) {
+            error!("Netgame: {e}");
+        }
+    }
+
+    /// Are the commands of every player for the next tic here?
+    pub fn net_tic_ready(&self) -> bool {
+        self.net
+            .as_ref()
+            .is_some_and(|net| net.tic_ready(self.game_tic))
+    }
+
+    /// Take out the players who have left the game by this tic.
+    pub(crate) fn net_drop_players(&mut self) {
+        let Some(net) = &self.net else {
+            return;
+        };
+        for player in 0..MAXPLAYERS {
+            if self.players_in_game[player]
+                && net
+                    .exit_tic(player)
+                    .is_some_and(|exit| self.game_tic >= exit)
+            {
+                self.players_in_game[player] = false;
+                self.players[self.consoleplayer].message = Some(english::NETLEFT[player]);
+            }
+        }
+    }
+
+    /// Has any player pressed attack or use since the last tic? In a netgame
+    /// this is what skips the intermission and finale, so that every node
+    /// skips together.
+    ///
+    /// Doom function name `WI_checkForAccelerate`
+    pub(crate) fn net_accelerate(&mut self) -> bool {
+        let mut accelerate = false;
+        for (i, player) in self.players.iter_mut().enumerate() {
+            if !self.players_in_game[i] {
+                continue;
+            }
+            let attack = player.cmd.buttons & TIC_CMD_BUTTONS.bt_attack != 0;
+            let used = player.cmd.buttons & TIC_CMD_BUTTONS.bt_use != 0;
+            accelerate |= (attack && !player.status.attackdown) || (used && !player.status.usedown);
+            player.status.attackdown = attack;
+            player.status.usedown = used;
+        }
+        accelerate
+    }
+
+    /// The value commands made this tic carry for the consistency check: the
+    /// gameplay state hash, or the `p_random` index outside of levels.
+    pub(crate) fn consistancy_value(&self) -> i16 {
+        match &self.level {
+            Some(level) => gameplay::demo_trace::state_hash(level) as i16,
+            None => get_prndindex() as i16,
+        }
+    }
+
+    /// Check the state `player`'s command was made with against ours for the
+    /// same tic, then record this tic's for the commands made now. A
+    /// mismatch means the nodes have desynced and ends the game.
+    pub(crate) fn check_consistancy(&mut self, player: usize, buf: usize, value: i16) {
+        let theirs = self.players[player].cmd.consistancy;
+        if self.game_tic > BACKUPTICS as u32 && self.consistancy[player][buf] != theirs {
+            error!(
+                "Netgame: consistency failure with player {} at tic {} ({theirs} should be {})",
+                player + 1,
+                self.game_tic,
+                self.consistancy[player][buf]
+            );
+            self.running = false;
+        }
+        self.consistancy[player][buf] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BACKUPTICS, NetGame, NetSetup, Packet, RESEND_INTERVAL};
+    use game_config::tic_cmd::TicCmd;
+    use game_config::{GameOptions, NetSettings, Skill};
+    use gameplay::MAXPLAYERS;
+    use std::net::{Ipv4Addr, UdpSocket};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn free_port() -> u16 {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        socket.local_addr().unwrap().port()
+    }
+
+    /// A command that says who made it and for which tic
+    fn cmd_for(player: usize, tic: u32) -> TicCmd {
+        TicCmd {
+            forwardmove: player as i8,
+            angleturn: tic as i16,
+            ..TicCmd::new()
+        }
+    }
+
+    fn setup() -> NetSetup {
+        NetSetup {
+            skill: Skill::Hard,
+            episode: 2,
+            map: 7,
+            deathmatch: 2,
+            no_monsters: true,
+            respawn_parm: false,
+            fast_parm: true,
+        }
+    }
+
+    #[test]
+    fn packets_round_trip() {
+        let hello = Packet::Hello {
+            numplayers: 3,
+            heard: 0b101,
+            setup: setup(),
+        };
+        assert_eq!(Packet::decode(&hello.encode(2)), Some((2, hello)));
+
+        let tics = Packet::Tics {
+            ack: 40,
+            start: 35,
+            exit: true,
+            cmds: vec![
+                TicCmd {
+                    forwardmove: -50,
+                    sidemove: 40,
+                    angleturn: -1280,
+                    consistancy: 0x1234,
+                    chatchar: b'h',
+                    buttons: 0x81,
+                    lookdir: -90,
+                },
+                TicCmd::new(),
+            ],
+        };
+        assert_eq!(Packet::decode(&tics.encode(1)), Some((1, tics.clone())));
+
+        let mut bad = tics.encode(1);
+        bad.pop();
+        assert_eq!(Packet::decode(&bad), None);
+        assert_eq!(Packet::decode(b"R4DN"), None);
+    }
+
+    #[test]
+    fn skill_zero_hack_survives_the_wire() {
+        let mut out = Vec::new();
+        NetSetup {
+            skill: Skill::NoItems,
+            ..setup()
+        }
+        .encode(&mut out);
+        assert_eq!(NetSetup::decode(&out).unwrap().skill, Skill::NoItems);
+        out[0] = 7;
+        assert_eq!(NetSetup::decode(&out), None);
+    }
+
+    /// Run a node through `tics` tics, checking every player's command for
+    /// each tic as it becomes ready.
+    fn run_node(settings: NetSettings, numplayers: usize, tics: u32) -> GameOptions {
+        let mut options = GameOptions::default();
+        if settings.player == 0 {
+            setup().apply(&mut options);
+        }
+        let mut net = NetGame::connect(&settings, &mut options, TIMEOUT).unwrap();
+        let mut netcmds = [[TicCmd::new(); BACKUPTICS]; MAXPLAYERS];
+        let mut gametic = 0;
+        let start = Instant::now();
+        while gametic < tics {
+            assert!(start.elapsed() < TIMEOUT, "stalled at tic {gametic}");
+            if net.maketic() < tics && net.can_make_tic(gametic) {
+                let cmd = cmd_for(settings.player, net.maketic());
+                net.make_tic(cmd, &mut netcmds).unwrap();
+            }
+            net.update(&mut netcmds).unwrap();
+            while gametic < tics && net.tic_ready(gametic) {
+                for (player, cmds) in netcmds.iter().enumerate().take(numplayers) {
+                    assert_eq!(
+                        cmds[gametic as usize % BACKUPTICS],
+                        cmd_for(player, gametic)
+                    );
+                }
+                gametic += 1;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        // Keep answering until the others are done too
+        let linger = Instant::now();
+        while linger.elapsed() < Duration::from_millis(300) {
+            net.update(&mut netcmds).unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+        options
+    }
+
+    #[test]
+    fn three_nodes_run_the_same_commands() {
+        const PLAYERS: usize = 3;
+        const TICS: u32 = 60;
+        let ports: Vec<u16> = (0..PLAYERS).map(|_| free_port()).collect();
+        let nodes: Vec<_> = (0..PLAYERS)
+            .map(|player| {
+                let settings = NetSettings {
+                    player,
+                    port: ports[player],
+                    peers: (0..PLAYERS)
+                        .filter(|&p| p != player)
+                        .map(|p| format!("127.0.0.1:{}", ports[p]))
+                        .collect(),
+                };
+                thread::spawn(move || run_node(settings, PLAYERS, TICS))
+            })
+            .collect();
+        for node in nodes {
+            let options = node.join().unwrap();
+            assert_eq!(options.skill, Skill::Hard);
+            assert_eq!((options.episode, options.map), (2, 7));
+            assert_eq!(options.deathmatch, 2);
+            assert!(options.no_monsters && options.fast_parm);
+        }
+    }
+
+    #[test]
+    fn unacknowledged_tics_are_resent() {
+        let port = free_port();
+        let peer = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        peer.set_read_timeout(Some(TIMEOUT)).unwrap();
+        let settings = NetSettings {
+            player: 0,
+            port,
+            peers: vec![peer.local_addr().unwrap().to_string()],
+        };
+        let node = thread::spawn(move || {
+            NetGame::connect(&settings, &mut GameOptions::default(), TIMEOUT).unwrap()
+        });
+
+        // Handshake by hand as player 1, until the node is listening
+        let node_addr = (Ipv4Addr::LOCALHOST, port);
+        let hello = Packet::Hello {
+            numplayers: 2,
+            heard: 0b11,
+            setup: setup(),
+        };
+        while !node.is_finished() {
+            peer.send_to(&hello.encode(1), node_addr).unwrap();
+            thread::sleep(Duration::from_millis(20));
+        }
+        let mut net = node.join().unwrap();
+
+        let mut netcmds = [[TicCmd::new(); BACKUPTICS]; MAXPLAYERS];
+        for tic in 0..3 {
+            net.make_tic(cmd_for(0, tic), &mut netcmds).unwrap();
+        }
+        // Never acknowledged, so packets keep starting from tic 0
+        thread::sleep(RESEND_INTERVAL);
+        net.update(&mut netcmds).unwrap();
+        let mut buf = [0; 512];
+        let mut last = None;
+        while let Ok(len) = peer.recv(&mut buf) {
+            if let Some((
+                0,
+                Packet::Tics {
+                    start,
+                    cmds,
+                    ..
+                },
+            )) = Packet::decode(&buf[..len])
+            {
+                assert_eq!(start, 0);
+                if cmds.len() == 3 {
+                    last = Some(cmds);
+                    break;
+                }
+            }
+        }
+        let cmds = last.expect("no resend of all three tics");
+        assert!((0..3).all(|tic| cmds[tic as usize] == cmd_for(0, tic)));
+
+        // Nothing more can be made once the resend window would overflow
+        let mut made = 3;
+        while net.can_make_tic(made) {
+            net.make_tic(cmd_for(0, made), &mut netcmds).unwrap();
+            made += 1;
+        }
+        assert_eq!(made, BACKUPTICS as u32);
+
+        // Acknowledge everything and leave after two tics of our own
+        let bye = Packet::Tics {
+            ack: made,
+            start: 0,
+            exit: true,
+            cmds: vec![cmd_for(1, 0), cmd_for(1, 1)],
+        };
+        peer.send_to(&bye.encode(1), node_addr).unwrap();
+        let start = Instant::now();
+        while net.exit_tic(1).is_none() {
+            assert!(start.elapsed() < TIMEOUT);
+            net.update(&mut netcmds).unwrap();
+        }
+        assert_eq!(net.exit_tic(1), Some(2));
+        assert_eq!(netcmds[1][1], cmd_for(1, 1));
+        assert!(net.tic_ready(5));
+        assert!(net.can_make_tic(made - 1));
+    }
+}
@@ -1,80 +1,220 @@
-use gamestate_traits::{KeyCode, MouseBtn};
+//! Action bindings as stored in the user config.
+//!
+//! Each action is written by name with its bindings in text form, e.g.
+//! `(action:"fire",keys:["RCtrl","Mouse1"])`. Entries that no longer parse
+//! are skipped with a warning, and actions missing from the file keep their
+//! default bindings. A config from before bindings, with its fixed
+//! `key_fire` style fields, still loads and keeps those keys. Gamepad tuning
+//! rides along as `pad`.
+
+use gamestate_traits::{Action, Binding, Bindings, KeyCode, MouseBtn};
+use log::warn;
 use nanoserde::{DeRon, SerRon};
 
+use crate::gamepad::PadConfig;
+
 #[derive(Debug, Clone, DeRon, SerRon)]
-pub struct InputConfig {
-    pub(crate) key_right: i32,
-    pub(crate) key_left: i32,
-    pub(crate) key_up: i32,
-    pub(crate) key_down: i32,
-    pub(crate) key_strafeleft: i32,
-    pub(crate) key_straferight: i32,
-    pub(crate) key_fire: i32,
-    pub(crate) key_use: i32,
-    pub(crate) key_strafe: i32,
-    pub(crate) key_speed: i32,
-    pub(crate) mousebfire: u8,
-    pub(crate) mousebstrafe: u8,
-    pub(crate) mousebforward: u8,
+pub struct ActionConfig {
+    pub action: String,
+    pub keys: Vec<String>,
+}
+
+pub use file::InputConfig;
+
+mod file {
+    // The optional fields expand to if-let blocks in the derive
+    #![allow(clippy::question_mark, reason = "DeRon expansion")]
+
+    use nanoserde::{DeRon, SerRon};
+
+    use super::ActionConfig;
+    use crate::gamepad::PadConfig;
+
+    #[derive(Debug, Clone, DeRon, SerRon)]
+    pub struct InputConfig {
+        #[nserde(default)]
+        pub bindings: Vec<ActionConfig>,
+        #[nserde(default)]
+        pub pad: PadConfig,
+        // Fixed keys of older configs, read once and written back as bindings
+        pub(super) key_right: Option<i32>,
+        pub(super) key_left: Option<i32>,
+        pub(super) key_up: Option<i32>,
+        pub(super) key_down: Option<i32>,
+        pub(super) key_strafeleft: Option<i32>,
+        pub(super) key_straferight: Option<i32>,
+        pub(super) key_fire: Option<i32>,
+        pub(super) key_use: Option<i32>,
+        pub(super) key_strafe: Option<i32>,
+        pub(super) key_speed: Option<i32>,
+        pub(super) mousebfire: Option<u8>,
+        pub(super) mousebstrafe: Option<u8>,
+        pub(super) mousebforward: Option<u8>,
+    }
 }
 
 impl Default for InputConfig {
     fn default() -> Self {
+        (&Bindings::default()).into()
+    }
+}
+
+impl From<&Bindings> for InputConfig {
+    fn from(b: &Bindings) -> Self {
         Self {
-            key_right: KeyCode::Right as i32,
-            key_left: KeyCode::Left as i32,
-
-            key_up: KeyCode::W as i32,
-            key_down: KeyCode::S as i32,
-            key_strafeleft: KeyCode::A as i32,
-            key_straferight: KeyCode::D as i32,
-            key_fire: KeyCode::RCtrl as i32,
-            key_use: KeyCode::Space as i32,
-            key_strafe: KeyCode::RAlt as i32,
-            key_speed: KeyCode::LShift as i32,
-
-            mousebfire: MouseBtn::Left as u8,
-            mousebstrafe: MouseBtn::Middle as u8,
-            mousebforward: MouseBtn::Right as u8,
+            bindings: Action::ALL
+                .iter()
+                .map(|&a| ActionConfig {
+                    action: a.name(),
+                    keys: b.get(a).iter().map(Binding::to_string).collect(),
+                })
+                .collect(),
+            pad: PadConfig::default(),
+            key_right: None,
+            key_left: None,
+            key_up: None,
+            key_down: None,
+            key_strafeleft: None,
+            key_straferight: None,
+            key_fire: None,
+            key_use: None,
+            key_strafe: None,
+            key_speed: None,
+            mousebfire: None,
+            mousebstrafe: None,
+            mousebforward: None,
         }
     }
 }
 
-pub struct InputConfigResolved {
-    pub(crate) key_right: KeyCode,
-    pub(crate) key_left: KeyCode,
-    pub(crate) key_up: KeyCode,
-    pub(crate) key_down: KeyCode,
-    pub(crate) key_strafeleft: KeyCode,
-    pub(crate) key_straferight: KeyCode,
-    pub(crate) key_fire: KeyCode,
-    pub(crate) key_use: KeyCode,
-    pub(crate) key_strafe: KeyCode,
-    pub(crate) key_speed: KeyCode,
-    pub(crate) mousebfire: MouseBtn,
-    pub(crate) mousebstrafe: MouseBtn,
-    pub(crate) mousebforward: MouseBtn,
+impl InputConfig {
+    /// Replace the stored bindings, keeping the pad tuning.
+    pub fn set_bindings(&mut self, b: &Bindings) {
+        let pad = self.pad.clone();
+        *self = Self::from(b);
+        self.pad = pad;
+    }
+
+    /// Carry an older config's fixed keys over to their actions.
+    fn apply_legacy(&self, bindings: &mut Bindings) {
+        let keys = [
+            (Action::TurnRight, self.key_right),
+            (Action::TurnLeft, self.key_left),
+            (Action::Forward, self.key_up),
+            (Action::Backward, self.key_down),
+            (Action::StrafeLeft, self.key_strafeleft),
+            (Action::StrafeRight, self.key_straferight),
+            (Action::Fire, self.key_fire),
+            (Action::Use, self.key_use),
+            (Action::Strafe, self.key_strafe),
+            (Action::Run, self.key_speed),
+        ];
+        for (action, key) in keys {
+            if let Some(key) = key.and_then(KeyCode::from_i32) {
+                bindings.clear(action);
+                bindings.bind(action, Binding::key(key));
+            }
+        }
+        let buttons = [
+            (Action::Fire, self.mousebfire),
+            (Action::Strafe, self.mousebstrafe),
+            (Action::Forward, self.mousebforward),
+        ];
+        for (action, button) in buttons {
+            if let Some(button) = button.and_then(MouseBtn::from_u8) {
+                bindings.bind(action, Binding::mouse(button));
+            }
+        }
+    }
 }
 
-impl From<&InputConfig> for InputConfigResolved {
-    fn from(i: &InputConfig) -> Self {
-        Self {
-            key_right: KeyCode::from_i32(i.key_right).expect("invalid keycode in config"),
-            key_left: KeyCode::from_i32(i.key_left).expect("invalid keycode in config"),
-            key_up: KeyCode::from_i32(i.key_up).expect("invalid keycode in config"),
-            key_down: KeyCode::from_i32(i.key_down).expect("invalid keycode in config"),
-            key_strafeleft: KeyCode::from_i32(i.key_strafeleft).expect("invalid keycode in config"),
-            key_straferight: KeyCode::from_i32(i.key_straferight)
-                .expect("invalid keycode in config"),
-            key_fire: KeyCode::from_i32(i.key_fire).expect("invalid keycode in config"),
-            key_use: KeyCode::from_i32(i.key_use).expect("invalid keycode in config"),
-            key_strafe: KeyCode::from_i32(i.key_strafe).expect("invalid keycode in config"),
-            key_speed: KeyCode::from_i32(i.key_speed).expect("invalid keycode in config"),
-            mousebfire: MouseBtn::from_u8(i.mousebfire).expect("invalid mouse button in config"),
-            mousebstrafe: MouseBtn::from_u8(i.mousebstrafe)
-                .expect("invalid mouse button in config"),
-            mousebforward: MouseBtn::from_u8(i.mousebforward)
-                .expect("invalid mouse button in config"),
+impl From<&InputConfig> for Bindings {
+    fn from(cfg: &InputConfig) -> Self {
+        let mut bindings = Self::default();
+        cfg.apply_legacy(&mut bindings);
+        for entry in &cfg.bindings {
+            let Some(action) = Action::from_name(&entry.action) else {
+                warn!("Unknown action in input config: {}", entry.action);
+                continue;
+            };
+            bindings.clear(action);
+            for key in &entry.keys {
+                match key.parse() {
+                    Ok(b) => bindings.bind(action, b),
+                    Err(e) => warn!("Skipping {} binding: {e}", entry.action),
+                }
+            }
         }
+        bindings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bindings_round_trip_through_ron() {
+        let mut b = Bindings::default();
+        b.bind(Action::Automap, Binding::mouse(MouseBtn::X1));
+        b.clear(Action::QuickLoad);
+        let ron = InputConfig::from(&b).serialize_ron();
+        let cfg = InputConfig::deserialize_ron(&ron).unwrap();
+        assert_eq!(Bindings::from(&cfg), b);
+    }
+
+    #[test]
+    fn pre_bindings_config_keeps_its_keys() {
+        let k = |k: KeyCode| k as i32;
+        let ron = format!(
+            "(key_right:{},key_left:{},key_up:{},key_down:{},key_strafeleft:{},\
+             key_straferight:{},key_fire:{},key_use:{},key_strafe:{},key_speed:{},\
+             mousebfire:1,mousebstrafe:2,mousebforward:3)",
+            k(KeyCode::Right),
+            k(KeyCode::Left),
+            k(KeyCode::Up),
+            k(KeyCode::Down),
+            k(KeyCode::A),
+            k(KeyCode::D),
+            k(KeyCode::F),
+            k(KeyCode::E),
+            k(KeyCode::RAlt),
+            k(KeyCode::LShift),
+        );
+        let cfg = InputConfig::deserialize_ron(&ron).unwrap();
+        let b = Bindings::from(&cfg);
+        assert_eq!(
+            b.get(Action::Fire),
+            [Binding::key(KeyCode::F), Binding::mouse(MouseBtn::Left)]
+        );
+        assert_eq!(b.get(Action::Use), [Binding::key(KeyCode::E)]);
+        assert_eq!(
+            b.get(Action::Forward),
+            [Binding::key(KeyCode::Up), Binding::mouse(MouseBtn::Right)]
+        );
+        assert_eq!(
+            b.get(Action::QuickSave),
+            Bindings::default().get(Action::QuickSave)
+        );
+
+        // Saved back as bindings only
+        let mut saved = cfg.clone();
+        saved.set_bindings(&b);
+        let ron = saved.serialize_ron();
+        assert!(!ron.contains("key_fire"));
+        let reloaded = InputConfig::deserialize_ron(&ron).unwrap();
+        assert_eq!(Bindings::from(&reloaded), b);
+    }
+
+    #[test]
+    fn bad_entries_keep_defaults() {
+        let cfg = InputConfig::deserialize_ron(
+            r#"(bindings:[(action:"fire",keys:["Q","Bogus"]),(action:"dance",keys:["X"])])"#,
+        )
+        .unwrap();
+        assert_eq!(cfg.pad, PadConfig::default());
+        let b = Bindings::from(&cfg);
+        assert_eq!(b.get(Action::Fire), [Binding::key(KeyCode::Q)]);
+        assert_eq!(b.get(Action::Use), Bindings::default().get(Action::Use));
     }
 }
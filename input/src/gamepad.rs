@@ -0,0 +1,236 @@
+//! Backend-neutral gamepad state: analogue axes, the deadzone and response
+//! curve applied to them, and rumble requests going back out.
+//!
+//! Backends feed raw axis values normalised to -1.0..=1.0 (sticks, up and
+//! right positive) or 0.0..=1.0 (triggers) through `InputEvents`, and button
+//! presses as `Input::Pad`. The sticks are shaped here and turned into
+//! movement by `InputEvents::build_tic_cmd`.
+
+use nanoserde::{DeRon, SerRon};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadAxis {
+    LeftX,
+    LeftY,
+    RightX,
+    RightY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+/// Tuning for the analogue inputs, stored with the bindings.
+#[derive(Debug, Clone, PartialEq, DeRon, SerRon)]
+pub struct PadConfig {
+    /// Stick travel, 0.0..1.0, that reads as centred.
+    pub deadzone: f32,
+    /// Response curve exponent past the deadzone; 1.0 is linear, higher
+    /// gives finer control near the centre.
+    pub curve: f32,
+    /// Right stick turn rate, as a multiple of the keyboard's fast turn.
+    pub turn_speed: f32,
+    /// Right stick look rate, as a multiple of the look keys.
+    pub look_speed: f32,
+    pub invert_y: bool,
+    /// Trigger travel at which a trigger counts as pressed.
+    pub trigger_threshold: f32,
+    pub rumble: bool,
+}
+
+impl Default for PadConfig {
+    fn default() -> Self {
+        Self {
+            deadzone: 0.2,
+            curve: 2.0,
+            turn_speed: 1.0,
+            look_speed: 1.0,
+            invert_y: false,
+            trigger_threshold: 0.5,
+            rumble: true,
+        }
+    }
+}
+
+/// A request for the backend to rumble every connected pad that can.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rumble {
+    /// 0.0..=1.0
+    pub strength: f32,
+    pub duration_ms: u32,
+}
+
+impl Rumble {
+    /// The rumble for a hit that raised the damage count by `damage`.
+    pub fn for_damage(damage: i32) -> Self {
+        let strength = (damage as f32 / 40.0).clamp(0.25, 1.0);
+        Self {
+            strength,
+            duration_ms: 100 + (strength * 200.0) as u32,
+        }
+    }
+}
+
+/// The last value of every axis.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PadAxes {
+    values: [f32; 6],
+}
+
+impl PadAxes {
+    /// Store `value` for `axis`, returning the previous value.
+    pub(crate) fn set(&mut self, axis: PadAxis, value: f32) -> f32 {
+        let range = match axis {
+            PadAxis::LeftTrigger | PadAxis::RightTrigger => 0.0..=1.0,
+            _ => -1.0..=1.0,
+        };
+        let value = value.clamp(*range.start(), *range.end());
+        std::mem::replace(&mut self.values[axis as usize], value)
+    }
+
+    pub(crate) fn get(&self, axis: PadAxis) -> f32 {
+        self.values[axis as usize]
+    }
+
+    /// The left (`false`) or right (`true`) stick after the deadzone and
+    /// curve.
+    pub(crate) fn stick(&self, right: bool, cfg: &PadConfig) -> (f32, f32) {
+        let (x, y) = if right {
+            (self.get(PadAxis::RightX), self.get(PadAxis::RightY))
+        } else {
+            (self.get(PadAxis::LeftX), self.get(PadAxis::LeftY))
+        };
+        shape_stick(x, y, cfg.deadzone, cfg.curve)
+    }
+}
+
+/// Apply a radial deadzone and response curve to a stick position. The
+/// direction is kept; the travel past the deadzone is rescaled to 0.0..=1.0
+/// and raised to `curve`.
+pub fn shape_stick(x: f32, y: f32, deadzone: f32, curve: f32) -> (f32, f32) {
+    let mag = x.hypot(y);
+    if mag <= deadzone || mag == 0.0 {
+        return (0.0, 0.0);
+    }
+    let travel = ((mag - deadzone) / (1.0 - deadzone)).min(1.0);
+    let scale = travel.powf(curve.max(0.1)) / mag;
+    (x * scale, y * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use game_config::tic_cmd::{FORWARDMOVE, SIDEMOVE, TIC_CMD_BUTTONS};
+    use gamestate_traits::{Bindings, PadButton};
+
+    use super::*;
+    use crate::InputEvents;
+
+    fn tic(events: &mut InputEvents) -> game_config::tic_cmd::TicCmd {
+        events.build_tic_cmd(&Bindings::default(), |_| 0)
+    }
+
+    #[test]
+    fn deadzone_and_curve() {
+        assert_eq!(shape_stick(0.1, 0.1, 0.2, 1.0), (0.0, 0.0));
+        let (x, y) = shape_stick(0.0, 0.6, 0.2, 1.0);
+        assert_eq!(x, 0.0);
+        assert!((y - 0.5).abs() < 1e-6);
+        let (_, y) = shape_stick(0.0, 0.6, 0.2, 2.0);
+        assert!((y - 0.25).abs() < 1e-6);
+        // Diagonal full travel keeps its direction at unit length.
+        let (x, y) = shape_stick(1.0, -1.0, 0.2, 2.0);
+        assert!((x.hypot(y) - 1.0).abs() < 1e-6);
+        assert!((x + y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn left_stick_moves_and_strafes() {
+        let mut events = InputEvents::default();
+        events.set_pad_config(PadConfig {
+            curve: 1.0,
+            deadzone: 0.0,
+            ..PadConfig::default()
+        });
+        events.set_pad_axis(PadAxis::LeftY, 1.0);
+        let cmd = tic(&mut events);
+        assert_eq!(i32::from(cmd.forwardmove), FORWARDMOVE[0]);
+        assert_eq!(cmd.sidemove, 0);
+
+        events.set_pad_axis(PadAxis::LeftY, 0.0);
+        events.set_pad_axis(PadAxis::LeftX, -0.5);
+        let cmd = tic(&mut events);
+        assert_eq!(cmd.forwardmove, 0);
+        assert_eq!(i32::from(cmd.sidemove), -SIDEMOVE[0] / 2);
+
+        // Running, via the trigger bound to Run.
+        events.set_pad_axis(PadAxis::LeftX, 0.0);
+        events.set_pad_axis(PadAxis::LeftY, 1.0);
+        events.set_pad_axis(PadAxis::LeftTrigger, 0.9);
+        let cmd = tic(&mut events);
+        assert_eq!(i32::from(cmd.forwardmove), FORWARDMOVE[1]);
+    }
+
+    #[test]
+    fn right_stick_turns_and_looks() {
+        let mut events = InputEvents::default();
+        events.set_pad_axis(PadAxis::RightX, 1.0);
+        let cmd = tic(&mut events);
+        assert!(cmd.angleturn < 0, "right turns clockwise");
+        assert_eq!(cmd.lookdir, 0);
+
+        events.set_pad_axis(PadAxis::RightX, 0.0);
+        events.set_pad_axis(PadAxis::RightY, 0.1);
+        let cmd = tic(&mut events);
+        assert_eq!((cmd.angleturn, cmd.lookdir), (0, 0), "inside the deadzone");
+
+        events.set_pad_axis(PadAxis::RightY, 1.0);
+        assert!(tic(&mut events).lookdir > 0, "up looks up");
+    }
+
+    #[test]
+    fn trigger_and_buttons_drive_actions() {
+        let mut events = InputEvents::default();
+        assert_eq!(events.set_pad_axis(PadAxis::RightTrigger, 0.4), None);
+        assert_eq!(
+            events.set_pad_axis(PadAxis::RightTrigger, 0.7),
+            Some(PadButton::RightTrigger)
+        );
+        assert_eq!(events.set_pad_axis(PadAxis::RightTrigger, 0.8), None);
+        events.set_pad_button(PadButton::A);
+        let cmd = tic(&mut events);
+        assert_ne!(cmd.buttons & TIC_CMD_BUTTONS.bt_attack, 0);
+        assert_ne!(cmd.buttons & TIC_CMD_BUTTONS.bt_use, 0);
+
+        events.set_pad_axis(PadAxis::RightTrigger, 0.1);
+        events.unset_pad_button(PadButton::A);
+        assert_eq!(tic(&mut events).buttons, 0);
+
+        // The shoulder cycles weapons once per press.
+        let mut events = InputEvents::default();
+        events.set_pad_button(PadButton::RightShoulder);
+        let cmd = events.build_tic_cmd(&Bindings::default(), |dir| if dir > 0 { 6 } else { 1 });
+        assert_ne!(cmd.buttons & TIC_CMD_BUTTONS.bt_change, 0);
+        assert_eq!(
+            (cmd.buttons & TIC_CMD_BUTTONS.bt_weaponmask) >> TIC_CMD_BUTTONS.bt_weaponshift,
+            6
+        );
+        assert_eq!(tic(&mut events).buttons, 0, "held, not pressed again");
+    }
+
+    #[test]
+    fn damage_requests_rumble() {
+        let mut events = InputEvents::default();
+        events.feedback_damage(10);
+        let rumble = events.take_rumble().expect("hit rumbles");
+        assert_eq!(rumble, Rumble::for_damage(10));
+        assert_eq!(events.take_rumble(), None);
+
+        // Fading damage count, then a disabled pad config.
+        events.feedback_damage(9);
+        assert_eq!(events.take_rumble(), None);
+        events.set_pad_config(PadConfig {
+            rumble: false,
+            ..PadConfig::default()
+        });
+        events.feedback_damage(50);
+        assert_eq!(events.take_rumble(), None);
+    }
+}
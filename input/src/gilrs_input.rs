@@ -0,0 +1,149 @@
+//! gilrs gamepad backend for the winit loop, which has no controller events
+//! of its own.
+
+use gamestate_traits::PadButton;
+use gilrs::ff::{BaseEffect, BaseEffectType, Effect, EffectBuilder, Replay, Ticks};
+use gilrs::{Axis, Button, EventType, Gilrs};
+use log::{info, warn};
+
+use crate::InputEvents;
+use crate::gamepad::PadAxis;
+
+/// Convert a gilrs button to the backend-agnostic `PadButton`. The analogue
+/// triggers arrive as axes instead.
+const fn gilrs_button_to_padbutton(b: Button) -> Option<PadButton> {
+    Some(match b {
+        Button::South => PadButton::A,
+        Button::East => PadButton::B,
+        Button::West => PadButton::X,
+        Button::North => PadButton::Y,
+        Button::Select => PadButton::Back,
+        Button::Mode => PadButton::Guide,
+        Button::Start => PadButton::Start,
+        Button::LeftThumb => PadButton::LeftStick,
+        Button::RightThumb => PadButton::RightStick,
+        Button::LeftTrigger => PadButton::LeftShoulder,
+        Button::RightTrigger => PadButton::RightShoulder,
+        Button::DPadUp => PadButton::DPadUp,
+        Button::DPadDown => PadButton::DPadDown,
+        Button::DPadLeft => PadButton::DPadLeft,
+        Button::DPadRight => PadButton::DPadRight,
+        _ => return None,
+    })
+}
+
+/// Convert a gilrs stick axis to a `PadAxis`. gilrs already has up positive.
+const fn gilrs_axis_to_padaxis(a: Axis) -> Option<PadAxis> {
+    Some(match a {
+        Axis::LeftStickX => PadAxis::LeftX,
+        Axis::LeftStickY => PadAxis::LeftY,
+        Axis::RightStickX => PadAxis::RightX,
+        Axis::RightStickY => PadAxis::RightY,
+        _ => return None,
+    })
+}
+
+/// Gamepads through gilrs. Does nothing if gilrs could not start.
+pub struct GilrsPad {
+    gilrs: Option<Gilrs>,
+    /// The rumble playing; dropping it stops it.
+    effect: Option<Effect>,
+}
+
+impl Default for GilrsPad {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GilrsPad {
+    pub fn new() -> Self {
+        let gilrs = Gilrs::new()
+            .inspect_err(|e| warn!("No gamepad support: {e}"))
+            .ok();
+        if let Some(g) = &gilrs {
+            for (_, pad) in g.gamepads() {
+                info!("Found gamepad {}", pad.name());
+            }
+        }
+        Self {
+            gilrs,
+            effect: None,
+        }
+    }
+
+    /// Feed pending gamepad events into `events` and play any rumble it asks
+    /// for. Returns the buttons pressed since the last poll, already held;
+    /// the caller offers them to the menus and unsets those taken.
+    pub fn poll(&mut self, events: &mut InputEvents) -> Vec<PadButton> {
+        let mut pressed = Vec::new();
+        let Some(gilrs) = &mut self.gilrs else {
+            return pressed;
+        };
+        while let Some(ev) = gilrs.next_event() {
+            match ev.event {
+                EventType::ButtonPressed(b, _) => {
+                    if let Some(b) = gilrs_button_to_padbutton(b) {
+                        events.set_pad_button(b);
+                        pressed.push(b);
+                    }
+                }
+                EventType::ButtonReleased(b, _) => {
+                    if let Some(b) = gilrs_button_to_padbutton(b) {
+                        events.unset_pad_button(b);
+                    }
+                }
+                EventType::ButtonChanged(b, value, _) => {
+                    let axis = match b {
+                        Button::LeftTrigger2 => PadAxis::LeftTrigger,
+                        Button::RightTrigger2 => PadAxis::RightTrigger,
+                        _ => continue,
+                    };
+                    pressed.extend(events.set_pad_axis(axis, value));
+                }
+                EventType::AxisChanged(a, value, _) => {
+                    if let Some(axis) = gilrs_axis_to_padaxis(a) {
+                        events.set_pad_axis(axis, value);
+                    }
+                }
+                EventType::Connected => {
+                    info!("Connected gamepad {}", gilrs.gamepad(ev.id).name());
+                }
+                EventType::Disconnected => {
+                    info!("Disconnected gamepad {}", gilrs.gamepad(ev.id).name());
+                    events.reset_pad();
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(rumble) = events.take_rumble() {
+            let ids: Vec<_> = gilrs
+                .gamepads()
+                .filter(|(_, pad)| pad.is_ff_supported())
+                .map(|(id, _)| id)
+                .collect();
+            if !ids.is_empty() {
+                let effect = EffectBuilder::new()
+                    .add_effect(BaseEffect {
+                        kind: BaseEffectType::Strong {
+                            magnitude: (rumble.strength * f32::from(u16::MAX)) as u16,
+                        },
+                        scheduling: Replay {
+                            play_for: Ticks::from_ms(rumble.duration_ms),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .gamepads(&ids)
+                    .finish(gilrs)
+                    .and_then(|e| e.play().map(|()| e));
+                match effect {
+                    Ok(e) => self.effect = Some(e),
+                    Err(e) => warn!("Gamepad rumble failed: {e}"),
+                }
+            }
+        }
+        pressed
+    }
+}
@@ -1,16 +1,16 @@
 //! All input handling. The output is generally a `TicCmd` used to run
 //! inputs in the `Game` in a generalised way.
 //!
-//! Also does config options for controls.
+//! Also does the config for action bindings.
 
 pub mod config;
+pub mod gamepad;
 
 use std::collections::hash_set::HashSet;
 
-use config::InputConfigResolved;
-use game_config::WeaponType;
 use game_config::tic_cmd::*;
-use gamestate_traits::{KeyCode, MouseBtn};
+use gamepad::{PadAxes, PadAxis, PadConfig, Rumble};
+use gamestate_traits::{Action, Bindings, Input, KeyCode, Modifiers, MouseBtn, PadButton};
 
 /// Backend-agnostic non-input events forwarded to the game loop.
 #[derive(Debug, Clone, Copy)]
@@ -29,6 +29,18 @@ pub struct InputEvents {
     mouse_acceleration: f32,
     turn_held: u32,
     invert_y: bool,
+    /// Wheel steps since the last tic.
+    impulses: Vec<Input>,
+    /// Actions held at the last tic, to see new presses.
+    latched: HashSet<Action>,
+    /// Toggled by `Action::RunToggle`; inverts `Action::Run`.
+    always_run: bool,
+    pad_buttons: HashSet<PadButton>,
+    pad_axes: PadAxes,
+    pad_config: PadConfig,
+    /// Console player damage count seen at the last tic.
+    last_damage: i32,
+    rumble: Option<Rumble>,
 }
 
 impl InputEvents {
@@ -99,17 +111,115 @@ impl InputEvents {
         }
     }
 
-    pub fn build_tic_cmd(&mut self, cfg: &InputConfigResolved) -> TicCmd {
+    pub fn set_pad_button(&mut self, b: PadButton) {
+        self.pad_buttons.insert(b);
+    }
+
+    pub fn unset_pad_button(&mut self, b: PadButton) {
+        self.pad_buttons.remove(&b);
+    }
+
+    pub fn set_pad_config(&mut self, cfg: PadConfig) {
+        self.pad_config = cfg;
+    }
+
+    /// Record a gamepad axis position. A trigger crossing its threshold holds
+    /// or releases its `PadButton`; the button is returned on the press so
+    /// the backend can offer it to the menus like any other button.
+    pub fn set_pad_axis(&mut self, axis: PadAxis, value: f32) -> Option<PadButton> {
+        let prev = self.pad_axes.set(axis, value);
+        let button = match axis {
+            PadAxis::LeftTrigger => PadButton::LeftTrigger,
+            PadAxis::RightTrigger => PadButton::RightTrigger,
+            _ => return None,
+        };
+        let threshold = self.pad_config.trigger_threshold;
+        let now = self.pad_axes.get(axis);
+        if now < threshold {
+            self.pad_buttons.remove(&button);
+            None
+        } else if prev < threshold {
+            self.pad_buttons.insert(button);
+            Some(button)
+        } else {
+            None
+        }
+    }
+
+    /// Centre every axis and release every button, for a pad that went away.
+    pub fn reset_pad(&mut self) {
+        self.pad_axes = PadAxes::default();
+        self.pad_buttons.clear();
+    }
+
+    /// Watch the console player's damage count, asking for a rumble when it
+    /// rises.
+    pub fn feedback_damage(&mut self, damagecount: i32) {
+        if damagecount > self.last_damage && self.pad_config.rumble {
+            self.rumble = Some(Rumble::for_damage(damagecount - self.last_damage));
+        }
+        self.last_damage = damagecount;
+    }
+
+    /// The rumble the backend should play, if any.
+    pub fn take_rumble(&mut self) -> Option<Rumble> {
+        self.rumble.take()
+    }
+
+    /// Modifier keys currently held.
+    pub fn mods(&self) -> Modifiers {
+        Modifiers::from_keys(&self.key_state)
+    }
+
+    /// A wheel step or other press with no release, seen by the next tic.
+    pub fn add_impulse(&mut self, input: Input) {
+        self.impulses.push(input);
+    }
+
+    /// Actions whose bindings are held right now.
+    fn held_actions(&self, bindings: &Bindings) -> HashSet<Action> {
+        let mods = self.mods();
+        let keys = self.key_state.iter().map(|k| Input::Key(*k));
+        let buttons = self.mouse_state.iter().map(|b| Input::Mouse(*b));
+        let pad = self.pad_buttons.iter().map(|b| Input::Pad(*b));
+        keys.chain(buttons)
+            .chain(pad)
+            .filter_map(|i| bindings.action_for(i, mods))
+            .collect()
+    }
+
+    /// Build this tic's command. `next_weapon` gives the weapon number to
+    /// switch to for a next (1) or previous (-1) weapon press.
+    pub fn build_tic_cmd(
+        &mut self,
+        bindings: &Bindings,
+        next_weapon: impl FnOnce(i32) -> u8,
+    ) -> TicCmd {
         let mut cmd = TicCmd::default();
 
-        let strafe = self.is_kb_pressed(cfg.key_strafe) || self.is_mb_pressed(cfg.mousebstrafe);
-        let speed = usize::from(self.is_kb_pressed(cfg.key_speed));
+        let held_now = self.held_actions(bindings);
+        let mods = self.mods();
+        let mut pressed: HashSet<Action> = held_now.difference(&self.latched).copied().collect();
+        pressed.extend(
+            self.impulses
+                .drain(..)
+                .filter_map(|i| bindings.action_for(i, mods)),
+        );
+        self.latched.clone_from(&held_now);
+        let held = |a: Action| held_now.contains(&a);
+
+        if pressed.contains(&Action::RunToggle) {
+            self.always_run = !self.always_run;
+        }
+
+        let strafe = held(Action::Strafe);
+        let speed = usize::from(held(Action::Run) != self.always_run);
 
         let mut side = 0;
         let mut forward = 0;
 
-        let turn_right = self.is_kb_pressed(cfg.key_right);
-        let turn_left = self.is_kb_pressed(cfg.key_left);
+        let turn_right = held(Action::TurnRight);
+        let turn_left = held(Action::TurnLeft);
 
         if turn_left || turn_right {
             self.turn_held += 1;
@@ -120,68 +230,92 @@ impl InputEvents {
         let turn_speed = if self.turn_held < 6 { 2 } else { speed };
 
         if strafe {
-            if self.is_kb_pressed(cfg.key_right) {
+            if turn_right {
                 side += SIDEMOVE[speed];
             }
-            if self.is_kb_pressed(cfg.key_left) {
+            if turn_left {
                 side -= SIDEMOVE[speed];
             }
         } else {
-            if self.is_kb_pressed(cfg.key_right) {
+            if turn_right {
                 cmd.angleturn -= ANGLETURN[turn_speed];
             }
-            if self.is_kb_pressed(cfg.key_left) {
+            if turn_left {
                 cmd.angleturn += ANGLETURN[turn_speed];
             }
         }
 
-        if self.is_kb_pressed(cfg.key_up) {
+        if held(Action::Forward) {
             forward += FORWARDMOVE[speed];
         }
 
-        if self.is_kb_pressed(cfg.key_down) {
+        if held(Action::Backward) {
             forward -= FORWARDMOVE[speed];
         }
 
-        if self.is_kb_pressed(cfg.key_straferight) {
+        if held(Action::StrafeRight) {
             side += SIDEMOVE[speed];
         }
 
-        if self.is_kb_pressed(cfg.key_strafeleft) {
+        if held(Action::StrafeLeft) {
             side -= SIDEMOVE[speed];
         }
 
-        if self.is_kb_pressed(cfg.key_fire) || self.is_mb_pressed(cfg.mousebfire) {
+        if held(Action::Fire) {
             cmd.buttons |= TIC_CMD_BUTTONS.bt_attack;
         }
 
-        if self.is_kb_pressed(cfg.key_use) {
+        if held(Action::Use) {
             cmd.buttons |= TIC_CMD_BUTTONS.bt_use;
         }
 
-        for i in 0..WeaponType::NumWeapons as u8 {
-            if let Some(key) = KeyCode::from_i32(30 + i as i32)
-                && self.is_kb_pressed(key)
-            {
+        // Slot 9 asks for the shotgun, which becomes the super shotgun when
+        // owned.
+        for (slot, action) in Action::WEAPONS.into_iter().enumerate() {
+            if held(action) {
+                let weapon = if slot == 8 { 2 } else { slot as u8 };
                 cmd.buttons |= TIC_CMD_BUTTONS.bt_change;
-                if i == 8 {
-                    cmd.buttons |= 2 << TIC_CMD_BUTTONS.bt_weaponshift;
-                } else {
-                    cmd.buttons |= i << TIC_CMD_BUTTONS.bt_weaponshift;
-                }
+                cmd.buttons |= weapon << TIC_CMD_BUTTONS.bt_weaponshift;
             }
         }
 
-        // Mouse
-        if self.is_mb_pressed(cfg.mousebforward) {
-            forward += FORWARDMOVE[speed];
+        let cycle = i32::from(pressed.contains(&Action::NextWeapon))
+            - i32::from(pressed.contains(&Action::PrevWeapon));
+        if cycle != 0 && cmd.buttons & TIC_CMD_BUTTONS.bt_change == 0 {
+            cmd.buttons |= TIC_CMD_BUTTONS.bt_change;
+            cmd.buttons |= next_weapon(cycle) << TIC_CMD_BUTTONS.bt_weaponshift;
         }
 
+        // Gamepad sticks: left moves, right turns (or strafes) and looks.
+        let (lx, ly) = self.pad_axes.stick(false, &self.pad_config);
+        let (rx, ry) = self.pad_axes.stick(true, &self.pad_config);
+        forward += (ly * FORWARDMOVE[speed] as f32) as i32;
+        side += (lx * SIDEMOVE[speed] as f32) as i32;
+        if strafe {
+            side += (rx * SIDEMOVE[speed] as f32) as i32;
+        } else {
+            let turn = rx * f32::from(ANGLETURN[1]) * self.pad_config.turn_speed;
+            cmd.angleturn -= turn as i16;
+        }
+        let ry = if self.pad_config.invert_y { -ry } else { ry };
+        let pad_look = (ry * f32::from(ANGLETURN[2]) * self.pad_config.look_speed) as i32;
+
         let mousex = self.mouse_delta.0;
         let mousey = self.mouse_delta.1;
 
-        let look_y = if self.invert_y { mousey } else { -mousey };
-        cmd.lookdir = (look_y * 0x8).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let mut look_y = if self.invert_y {
+            mousey * 0x8
+        } else {
+            -mousey * 0x8
+        };
+        look_y += pad_look;
+        if held(Action::LookUp) {
+            look_y += ANGLETURN[2] as i32;
+        }
+        if held(Action::LookDown) {
+            look_y -= ANGLETURN[2] as i32;
+        }
+        cmd.lookdir = look_y.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
 
         if strafe {
             side += mousex * 2;
@@ -200,19 +334,17 @@ impl InputEvents {
     }
 }
 
-/// Backend-agnostic input state: events, config, and quit flag.
+/// Backend-agnostic input state: events and quit flag. Bindings live with
+/// the game so the menus can change them.
 pub struct InputState {
     pub events: InputEvents,
-    pub config: InputConfigResolved,
     pub quit: bool,
 }
 
-impl InputState {
-    /// Create new input state with defaults.
-    pub fn new(config: InputConfigResolved) -> Self {
+impl Default for InputState {
+    fn default() -> Self {
         Self {
             events: InputEvents::new((5, 1)),
-            config,
             quit: false,
         }
     }
@@ -223,9 +355,15 @@ mod sdl2_input;
 #[cfg(feature = "input-sdl2")]
 pub use sdl2_input::InputSdl2;
 
+#[cfg(feature = "input-winit")]
+mod gilrs_input;
+#[cfg(feature = "input-winit")]
+pub use gilrs_input::GilrsPad;
 #[cfg(feature = "input-winit")]
 mod winit_input;
 #[cfg(feature = "input-winit")]
 pub use winit_input::winit_keycode_to_keycode;
 #[cfg(feature = "input-winit")]
 pub use winit_input::winit_mousebutton_to_mousebtn;
+#[cfg(feature = "input-winit")]
+pub use winit_input::winit_wheel_to_input;
@@ -1,13 +1,17 @@
-//! SDL2 input backend — polls `EventPump` for keyboard, mouse, and window
-//! events.
+//! SDL2 input backend — polls `EventPump` for keyboard, mouse, game
+//! controller and window events.
 
-use gamestate_traits::{KeyCode, MouseBtn};
-use sdl2::EventPump;
+use std::collections::HashMap;
+
+use gamestate_traits::{Input, KeyCode, Modifiers, MouseBtn, PadButton};
+use log::{info, warn};
+use sdl2::controller::{Axis, Button, GameController};
 use sdl2::event::Event;
 use sdl2::keyboard::Scancode as Sc;
 use sdl2::mouse::MouseButton as Mb;
+use sdl2::{EventPump, GameControllerSubsystem};
 
-use crate::config::InputConfigResolved;
+use crate::gamepad::PadAxis;
 use crate::{InputState, RawEvent};
 
 /// Convert an SDL2 scancode to the backend-agnostic `KeyCode`.
@@ -20,29 +24,73 @@ fn sdl_mousebutton_to_mousebtn(mb: Mb) -> Option<MouseBtn> {
     MouseBtn::from_u8(mb as u8)
 }
 
+/// Convert an SDL2 controller button to the backend-agnostic `PadButton`.
+const fn sdl_button_to_padbutton(b: Button) -> Option<PadButton> {
+    Some(match b {
+        Button::A => PadButton::A,
+        Button::B => PadButton::B,
+        Button::X => PadButton::X,
+        Button::Y => PadButton::Y,
+        Button::Back => PadButton::Back,
+        Button::Guide => PadButton::Guide,
+        Button::Start => PadButton::Start,
+        Button::LeftStick => PadButton::LeftStick,
+        Button::RightStick => PadButton::RightStick,
+        Button::LeftShoulder => PadButton::LeftShoulder,
+        Button::RightShoulder => PadButton::RightShoulder,
+        Button::DPadUp => PadButton::DPadUp,
+        Button::DPadDown => PadButton::DPadDown,
+        Button::DPadLeft => PadButton::DPadLeft,
+        Button::DPadRight => PadButton::DPadRight,
+        _ => return None,
+    })
+}
+
+/// Convert an SDL2 controller axis and raw value to a `PadAxis` and its
+/// normalised value. SDL2 has stick Y positive down.
+fn sdl_axis_to_padaxis(axis: Axis, value: i16) -> (PadAxis, f32) {
+    let v = f32::from(value) / f32::from(i16::MAX);
+    match axis {
+        Axis::LeftX => (PadAxis::LeftX, v),
+        Axis::LeftY => (PadAxis::LeftY, -v),
+        Axis::RightX => (PadAxis::RightX, v),
+        Axis::RightY => (PadAxis::RightY, -v),
+        Axis::TriggerLeft => (PadAxis::LeftTrigger, v),
+        Axis::TriggerRight => (PadAxis::RightTrigger, v),
+    }
+}
+
 /// SDL2 input: wraps an `EventPump` and the shared `InputState`.
 pub struct InputSdl2 {
     pump: EventPump,
+    pads: Option<GameControllerSubsystem>,
+    /// Open controllers by joystick instance id.
+    controllers: HashMap<u32, GameController>,
     pub state: InputState,
 }
 
 impl InputSdl2 {
-    /// Create from an SDL2 event pump and resolved config.
-    pub fn new(mut pump: EventPump, config: InputConfigResolved) -> Self {
+    /// Create from an SDL2 event pump. Controllers are opened as SDL2
+    /// reports them if `pads` is given.
+    pub fn new(mut pump: EventPump, pads: Option<GameControllerSubsystem>) -> Self {
         pump.pump_events();
         Self {
             pump,
-            state: InputState::new(config),
+            pads,
+            controllers: HashMap::new(),
+            state: InputState::default(),
         }
     }
 
     /// Poll all pending SDL2 events and translate to backend-agnostic types.
     ///
-    /// `input_callback` is called on key-down for menu/cheat consumption.
+    /// `input_callback` is called on key-down, mouse button down, wheel
+    /// steps and controller button presses for menu/cheat consumption, with
+    /// the modifiers held at the time; a consumed input is not held.
     /// `events_callback` receives non-input window events (resize, etc.).
     pub fn update(
         &mut self,
-        mut input_callback: impl FnMut(KeyCode) -> bool,
+        mut input_callback: impl FnMut(Input, Modifiers) -> bool,
         mut events_callback: impl FnMut(RawEvent),
     ) {
         while let Some(event) = self.pump.poll_event() {
@@ -52,7 +100,7 @@ impl InputSdl2 {
                     ..
                 } => {
                     if let Some(kc) = sdl_scancode_to_keycode(sc) {
-                        if input_callback(kc) {
+                        if input_callback(Input::Key(kc), self.state.events.mods()) {
                             self.state.events.unset_kb(kc);
                         } else {
                             self.state.events.set_kb(kc);
@@ -71,7 +119,9 @@ impl InputSdl2 {
                     mouse_btn,
                     ..
                 } => {
-                    if let Some(mb) = sdl_mousebutton_to_mousebtn(mouse_btn) {
+                    if let Some(mb) = sdl_mousebutton_to_mousebtn(mouse_btn)
+                        && !input_callback(Input::Mouse(mb), self.state.events.mods())
+                    {
                         self.state.events.set_mb(mb);
                     }
                 }
@@ -92,6 +142,62 @@ impl InputSdl2 {
                     let yrel = self.state.events.apply_mouse_accel(yrel as f32) as i32;
                     self.state.events.apply_mouse_sensitivity((xrel, yrel));
                 }
+                Event::MouseWheel {
+                    y,
+                    ..
+                } if y != 0 => {
+                    let step = if y > 0 {
+                        Input::WheelUp
+                    } else {
+                        Input::WheelDown
+                    };
+                    if !input_callback(step, self.state.events.mods()) {
+                        self.state.events.add_impulse(step);
+                    }
+                }
+                Event::ControllerDeviceAdded {
+                    which,
+                    ..
+                } => self.open_controller(which),
+                Event::ControllerDeviceRemoved {
+                    which,
+                    ..
+                } => {
+                    if let Some(c) = self.controllers.remove(&which) {
+                        info!("Removed controller {}", c.name());
+                        self.state.events.reset_pad();
+                    }
+                }
+                Event::ControllerAxisMotion {
+                    axis,
+                    value,
+                    ..
+                } => {
+                    let (axis, value) = sdl_axis_to_padaxis(axis, value);
+                    if let Some(b) = self.state.events.set_pad_axis(axis, value)
+                        && input_callback(Input::Pad(b), self.state.events.mods())
+                    {
+                        self.state.events.unset_pad_button(b);
+                    }
+                }
+                Event::ControllerButtonDown {
+                    button,
+                    ..
+                } => {
+                    if let Some(b) = sdl_button_to_padbutton(button)
+                        && !input_callback(Input::Pad(b), self.state.events.mods())
+                    {
+                        self.state.events.set_pad_button(b);
+                    }
+                }
+                Event::ControllerButtonUp {
+                    button,
+                    ..
+                } => {
+                    if let Some(b) = sdl_button_to_padbutton(button) {
+                        self.state.events.unset_pad_button(b);
+                    }
+                }
                 Event::Window {
                     win_event,
                     ..
@@ -106,6 +212,28 @@ impl InputSdl2 {
                 _ => {}
             }
         }
+
+        if let Some(rumble) = self.state.events.take_rumble() {
+            let strength = (rumble.strength * f32::from(u16::MAX)) as u16;
+            for c in self.controllers.values_mut() {
+                // Controllers without rumble report an error; nothing to do.
+                c.set_rumble(strength, strength / 2, rumble.duration_ms)
+                    .ok();
+            }
+        }
+    }
+
+    fn open_controller(&mut self, index: u32) {
+        let Some(pads) = &self.pads else {
+            return;
+        };
+        match pads.open(index) {
+            Ok(c) => {
+                info!("Opened controller {}", c.name());
+                self.controllers.insert(c.instance_id(), c);
+            }
+            Err(e) => warn!("Could not open controller {index}: {e}"),
+        }
     }
 
     /// Whether the user requested quit.
@@ -1,7 +1,7 @@
 //! winit input helpers — conversion from winit key/mouse types to
-//! backend-agnostic `KeyCode`/`MouseBtn`.
+//! backend-agnostic `KeyCode`/`MouseBtn`/`Input`.
 
-use gamestate_traits::{KeyCode, MouseBtn};
+use gamestate_traits::{Input, KeyCode, MouseBtn};
 use winit::keyboard::KeyCode as WinitKey;
 
 /// Convert a winit `KeyCode` to the backend-agnostic `KeyCode`.
@@ -50,6 +50,15 @@ pub fn winit_keycode_to_keycode(wk: WinitKey) -> Option<KeyCode> {
         WinitKey::Space => KeyCode::Space,
         WinitKey::Minus => KeyCode::Minus,
         WinitKey::Equal => KeyCode::Equals,
+        WinitKey::BracketLeft => KeyCode::LeftBracket,
+        WinitKey::BracketRight => KeyCode::RightBracket,
+        WinitKey::Backslash => KeyCode::Backslash,
+        WinitKey::Semicolon => KeyCode::Semicolon,
+        WinitKey::Quote => KeyCode::Apostrophe,
+        WinitKey::Backquote => KeyCode::Grave,
+        WinitKey::Comma => KeyCode::Comma,
+        WinitKey::Period => KeyCode::Period,
+        WinitKey::Slash => KeyCode::Slash,
         WinitKey::CapsLock => KeyCode::CapsLock,
         WinitKey::F1 => KeyCode::F1,
         WinitKey::F2 => KeyCode::F2,
@@ -64,6 +73,12 @@ pub fn winit_keycode_to_keycode(wk: WinitKey) -> Option<KeyCode> {
         WinitKey::F11 => KeyCode::F11,
         WinitKey::F12 => KeyCode::F12,
         WinitKey::Pause => KeyCode::Pause,
+        WinitKey::Insert => KeyCode::Insert,
+        WinitKey::Home => KeyCode::Home,
+        WinitKey::PageUp => KeyCode::PageUp,
+        WinitKey::Delete => KeyCode::Delete,
+        WinitKey::End => KeyCode::End,
+        WinitKey::PageDown => KeyCode::PageDown,
         WinitKey::ArrowRight => KeyCode::Right,
         WinitKey::ArrowLeft => KeyCode::Left,
         WinitKey::ArrowDown => KeyCode::Down,
@@ -84,6 +99,23 @@ pub fn winit_mousebutton_to_mousebtn(mb: winit::event::MouseButton) -> Option<Mo
         winit::event::MouseButton::Left => Some(MouseBtn::Left),
         winit::event::MouseButton::Middle => Some(MouseBtn::Middle),
         winit::event::MouseButton::Right => Some(MouseBtn::Right),
-        _ => None,
+        winit::event::MouseButton::Back => Some(MouseBtn::X1),
+        winit::event::MouseButton::Forward => Some(MouseBtn::X2),
+        winit::event::MouseButton::Other(_) => None,
+    }
+}
+
+/// A wheel scroll as a wheel step input, `None` for sideways or no movement.
+pub fn winit_wheel_to_input(delta: winit::event::MouseScrollDelta) -> Option<Input> {
+    let y = match delta {
+        winit::event::MouseScrollDelta::LineDelta(_, y) => y,
+        winit::event::MouseScrollDelta::PixelDelta(p) => p.y as f32,
+    };
+    if y > 0.0 {
+        Some(Input::WheelUp)
+    } else if y < 0.0 {
+        Some(Input::WheelDown)
+    } else {
+        None
     }
 }
@@ -201,6 +201,14 @@ impl BSP3D {
         }
 
         let uv_len = poly_verts.len();
+        let poly_base_offset = polygons
+            .iter()
+            .map(|p| {
+                p.sidedef.as_ref().map_or([0.0; 2], |sd| {
+                    [f32::from(sd.textureoffset), f32::from(sd.rowoffset)]
+                })
+            })
+            .collect();
         let mut bsp = Self {
             nodes: nodes3d,
             node_bboxes,
@@ -217,7 +225,8 @@ impl BSP3D {
             poly_tex: vec![NO_INDEX; n],
             poly_back_tex: vec![NO_INDEX; n],
             poly_flags,
-            poly_scroll: vec![0.0; n],
+            poly_scroll: vec![[0.0; 2]; n],
+            poly_base_offset,
             polygons,
             sector_leaves,
             sector_floor_polys,
@@ -301,6 +310,11 @@ impl BSP3D {
                     neighbours.push(other.num as usize);
                 }
             }
+            // Transfer heights draw this sector at its control sector's planes
+            if let Some(control) = sector.heightsec.and_then(|c| sectors.get(c)) {
+                min_floor = min_floor.min(control.floorheight.to_f32());
+                max_ceil = max_ceil.max(control.ceilingheight.to_f32());
+            }
 
             // Own leaves plus every two-sided neighbour's leaves: the shared
             // wall driven by this mover lives in the neighbour's subsector.
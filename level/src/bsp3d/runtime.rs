@@ -8,7 +8,7 @@
 
 use crate::MapPtr;
 use crate::flags::LineDefFlags;
-use crate::map_defs::{LineDef, Sector, SideDef};
+use crate::map_defs::{LineDef, Sector, SideDef, SurfaceLight};
 use glam::{Vec2, Vec3};
 use math::{FixedT, r_point_on_side_raw};
 use rbsp::bsp3d::{NO_INDEX, PolyFlags};
@@ -216,9 +216,13 @@ pub struct BSP3D {
     pub poly_back_tex: Vec<u32>,
     /// Resolved per-polygon flag bits (see [`PolyFlags`]).
     pub poly_flags: Vec<PolyFlags>,
-    /// Per-polygon horizontal texture scroll in texels (special-48 scrollers),
-    /// added to U at sample time. Delta beyond the resolve-baked offset.
-    pub poly_scroll: Vec<f32>,
+    /// Per-polygon `[u, v]` texture scroll in texels (wall and flat
+    /// scrollers), added to the UV at sample time. Delta beyond the
+    /// resolve-baked offset.
+    pub poly_scroll: Vec<[f32; 2]>,
+    /// Wall sidedef `[textureoffset, rowoffset]` captured at parse and baked
+    /// into the UV by resolve; live offsets beyond it go to `poly_scroll`.
+    pub(super) poly_base_offset: Vec<[f32; 2]>,
     /// Cold per-polygon data (events + light lookup).
     pub polygons: Vec<Polygon3D>,
     pub sector_leaves: Vec<Vec<usize>>,
@@ -275,6 +279,11 @@ impl BSP3D {
         &self.node_bboxes
     }
 
+    /// Flat number that marks a sky, if the map has one.
+    pub(crate) const fn sky_num(&self) -> Option<usize> {
+        self.sky_num
+    }
+
     pub fn root_node(&self) -> u32 {
         self.root_node
     }
@@ -340,6 +349,47 @@ impl BSP3D {
             .filter(|&gi| self.poly_is_flat(gi) && self.polygons[gi].normal.z < 0.0)
     }
 
+    /// Sector whose light level a polygon is drawn with: the BOOM light
+    /// transfer sector (213, 261) for flats that have one, else its own.
+    #[inline]
+    pub fn poly_light_sector(&self, gi: usize) -> &Sector {
+        let poly = &self.polygons[gi];
+        if !self.poly_is_flat(gi) {
+            return &poly.sector;
+        }
+        let transfer = if poly.normal.z > 0.0 {
+            poly.sector.floorlightsec.as_ref()
+        } else {
+            poly.sector.ceilinglightsec.as_ref()
+        };
+        transfer.map_or(&poly.sector, |s| s)
+    }
+
+    /// UDMF light of a polygon's surface: the sidedef's for walls, the
+    /// sector's plane light for flats.
+    pub fn poly_surface_light(&self, gi: usize) -> SurfaceLight {
+        let poly = &self.polygons[gi];
+        if !self.poly_is_flat(gi) {
+            return poly
+                .sidedef
+                .as_ref()
+                .map_or_else(SurfaceLight::default, |sd| sd.light);
+        }
+        if poly.normal.z > 0.0 {
+            poly.sector.floor_light
+        } else {
+            poly.sector.ceiling_light
+        }
+    }
+
+    /// Light level a polygon is drawn with: its light sector's level through
+    /// the surface's UDMF light.
+    #[inline]
+    pub fn poly_lightlevel(&self, gi: usize) -> usize {
+        self.poly_surface_light(gi)
+            .apply(self.poly_light_sector(gi).lightlevel)
+    }
+
     /// The vertex indices of one polygon.
     #[inline]
     pub fn poly_vert_indices(&self, gi: usize) -> &[usize] {
@@ -509,9 +559,15 @@ impl BSP3D {
             if slot == WallSlot::Middle && back_sidedef.is_some() {
                 flags |= PolyFlags::MASKED_MIDDLE;
             }
-            if linedef.special == 260 {
+            if linedef.is_translucent() {
                 flags |= PolyFlags::TRANSLUCENT;
             }
+            let part = match slot {
+                WallSlot::Upper => sidedef.top,
+                WallSlot::Lower => sidedef.bottom,
+                WallSlot::Middle => sidedef.mid,
+            };
+            let (x_scale, y_scale) = (part.xscale.to_f32(), part.yscale.to_f32());
 
             // UV bake — anchors verified against doom-og-src r_segs.c. The
             // texture-top z (`anchor`) includes the +texheight shifts: they
@@ -522,11 +578,13 @@ impl BSP3D {
             } else {
                 away_tex
             };
+            // Texture height in map units: a UDMF y scale stretches it.
             let tex_h = self
                 .wall_tex_height
                 .get(tex_for_height as usize)
                 .copied()
-                .unwrap_or(0.0);
+                .unwrap_or(0.0)
+                / y_scale;
             let unpeg_top = linedef.flags.contains(LineDefFlags::UnpegTop);
             let unpeg_bottom = linedef.flags.contains(LineDefFlags::UnpegBottom);
             let anchor = match slot {
@@ -559,13 +617,14 @@ impl BSP3D {
             let v0 = self.vertices[self.poly_verts[s]];
             let v1 = self.vertices[self.poly_verts[s + 1]];
             let dir = Vec2::new(v1.x - v0.x, v1.y - v0.y).normalize();
-            let x_off = f32::from(sidedef.textureoffset) + seg_offset;
-            let y_off = f32::from(sidedef.rowoffset);
+            let [base_x, base_y] = self.poly_base_offset[gi];
+            let x_off = base_x + seg_offset + part.xoffset.to_f32();
+            let y_off = base_y + part.yoffset.to_f32();
             for i in s..e {
                 let world = self.vertices[self.poly_verts[i]];
                 let u = (world.x - v0.x) * dir.x + (world.y - v0.y) * dir.y + x_off;
                 let v = anchor - world.z + y_off;
-                self.poly_vertex_uv[i] = [u, v];
+                self.poly_vertex_uv[i] = [u * x_scale, v * y_scale];
             }
         }
 
@@ -615,10 +674,25 @@ impl BSP3D {
         }
     }
 
-    /// Bake a flat's texel UV (pure XY rotation — never re-resolved).
+    /// Bake a flat's texel UV (pure XY rotation — never re-resolved), turned
+    /// by the sector's UDMF flat rotation, and seed its scroll from the
+    /// sector's flat offsets (UDMF panning).
     pub(super) fn resolve_flat_uv(&mut self, gi: usize) {
-        let tex_cos = HORIZONTAL_TEX_DIRECTION.cos();
-        let tex_sin = HORIZONTAL_TEX_DIRECTION.sin();
+        let p = &self.polygons[gi];
+        let sector = &p.sector;
+        let (rotation, xoffs, yoffs) = if p.normal.z > 0.0 {
+            (sector.floor_angle, sector.floor_xoffs, sector.floor_yoffs)
+        } else {
+            (
+                sector.ceiling_angle,
+                sector.ceiling_xoffs,
+                sector.ceiling_yoffs,
+            )
+        };
+        // Turning the texture counter-clockwise turns the sample point back.
+        let direction = HORIZONTAL_TEX_DIRECTION - rotation.rad();
+        let tex_cos = direction.cos();
+        let tex_sin = direction.sin();
         let (s, e) = self.poly_vertex_range[gi];
         for i in s..e {
             let world = self.vertices[self.poly_verts[i]];
@@ -626,6 +700,7 @@ impl BSP3D {
             let v = world.x * tex_sin + world.y * tex_cos;
             self.poly_vertex_uv[i] = [u, v];
         }
+        self.poly_scroll[gi] = [yoffs.to_f32(), xoffs.to_f32()];
     }
 
     // ------------------------------------------------------------------
@@ -755,9 +830,14 @@ impl BSP3D {
 
     /// Set vertex Z for all polygons of a surface type in a sector and
     /// re-resolve the sector's walls so textures stay anchored (tile) instead
-    /// of stretching. No AABB update — interp travel stays inside the
-    /// mover-expanded bounds.
-    fn set_surface_height(&mut self, sector_id: usize, movement: MovementType, height: f32) {
+    /// of stretching. No AABB update — interp travel and transfer heights stay
+    /// inside the mover-expanded bounds.
+    pub(crate) fn set_surface_height(
+        &mut self,
+        sector_id: usize,
+        movement: MovementType,
+        height: f32,
+    ) {
         self.set_surface_z(sector_id, movement, height);
         self.resolve_sector_walls(sector_id);
         self.geometry_dirty = true;
@@ -795,26 +875,75 @@ impl BSP3D {
         }
     }
 
-    /// Set horizontal texture scroll (texels) for all wall polygons of a
-    /// scrolling linedef (special 48). `delta` is the live offset minus the
-    /// build-baked one, added to U at sample time so it does not double-count.
-    pub fn set_wall_scroll(&mut self, linedef_id: usize, delta: f32) {
+    /// Sync the texture scroll of a linedef's wall polygons with their
+    /// sidedefs' live offsets (wall scrollers). The delta beyond the parse-baked
+    /// offset is added to the UV at sample time so it does not double-count.
+    pub fn update_wall_scroll(&mut self, linedef_id: usize) {
         if linedef_id >= self.linedef_wall_polys.len() {
             return;
         }
         let Self {
             linedef_wall_polys,
+            polygons,
             poly_scroll,
+            poly_base_offset,
             texture_dirty,
             texture_dirty_polys,
             texture_dirty_full,
             ..
         } = self;
         for &gi in &linedef_wall_polys[linedef_id] {
-            poly_scroll[gi] = delta;
-            mark_texture_dirty_poly(texture_dirty_polys, texture_dirty_full, gi);
+            let Some(sidedef) = polygons[gi].sidedef.as_ref() else {
+                continue;
+            };
+            let [base_x, base_y] = poly_base_offset[gi];
+            let scroll = [
+                f32::from(sidedef.textureoffset) - base_x,
+                f32::from(sidedef.rowoffset) - base_y,
+            ];
+            if poly_scroll[gi] != scroll {
+                poly_scroll[gi] = scroll;
+                mark_texture_dirty_poly(texture_dirty_polys, texture_dirty_full, gi);
+                *texture_dirty = true;
+            }
+        }
+    }
+
+    /// Set the texture scroll of a sector's floor or ceiling polygons from its
+    /// BOOM flat offsets (map units). Flat U runs along -y and V along x, so
+    /// the y offset scrolls U and the x offset scrolls V.
+    pub fn update_flat_scroll(
+        &mut self,
+        sector_id: usize,
+        movement_type: MovementType,
+        xoffs: f32,
+        yoffs: f32,
+    ) {
+        let Self {
+            sector_floor_polys,
+            sector_ceiling_polys,
+            poly_scroll,
+            texture_dirty,
+            texture_dirty_polys,
+            texture_dirty_full,
+            ..
+        } = self;
+        let polys = match movement_type {
+            MovementType::Floor => sector_floor_polys.get(sector_id),
+            MovementType::Ceiling => sector_ceiling_polys.get(sector_id),
+            MovementType::None => None,
+        };
+        let Some(polys) = polys else {
+            return;
+        };
+        let scroll = [yoffs, xoffs];
+        for &gi in polys {
+            if poly_scroll[gi] != scroll {
+                poly_scroll[gi] = scroll;
+                mark_texture_dirty_poly(texture_dirty_polys, texture_dirty_full, gi);
+                *texture_dirty = true;
+            }
         }
-        *texture_dirty = true;
     }
 
     // ------------------------------------------------------------------
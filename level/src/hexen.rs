@@ -0,0 +1,470 @@
+//! Hexen-format ("Doom in Hexen") maps.
+//!
+//! The binary Hexen layout widens THINGS (TID, spawn height, special + args)
+//! and LINEDEFS (a byte special with five args in place of the sector tag,
+//! activation in the flags), and the map carries a `BEHAVIOR` lump. ACS is not
+//! run: each record is translated at load to its classic Doom counterpart so
+//! the loader, BSP builder and mover dispatch see an ordinary Doom map.
+//!
+//! Parameterised specials are resolved to a `FloorKind`/`CeilKind`/`DoorKind`/
+//! `PlatKind`/`StairKind` mover, with `args[0]` as the tag and the speed arg
+//! picking the normal or turbo/blaze kind, then to the vanilla line number
+//! for that mover and activation. The load pass normalises that number like
+//! any classic line, so `default_special` keeps the meaning the key-door,
+//! monster-use and exit paths check. Specials with no Doom mover are dropped.
+
+use log::{debug, warn};
+use wad::types::{WadHexenLineDef, WadHexenThing, WadLineDef, WadThing};
+
+use crate::env_kinds::{CeilKind, DoorKind, FloorKind, PlatKind, StairKind};
+
+/// The Doom line flags (bits 0-8); Hexen reuses everything above them.
+const ML_DOOM_MASK: u16 = 0x01FF;
+const ML_REPEAT_SPECIAL: u16 = 0x0200;
+const ML_SPAC_SHIFT: u16 = 10;
+const ML_SPAC_MASK: u16 = 0x7;
+
+// Special activation (`SPAC_*`).
+const SPAC_CROSS: u16 = 0;
+const SPAC_USE: u16 = 1;
+const SPAC_MCROSS: u16 = 2;
+const SPAC_IMPACT: u16 = 3;
+
+/// Skill bits and ambush, shared with Doom.
+const HTF_DOOM_MASK: i16 = 0x000F;
+const HTF_SINGLE: i16 = 0x0100;
+/// Doom `MTF_NOTSINGLE`: multiplayer only.
+const MTF_NOT_SINGLE: i16 = 16;
+
+/// Doom's normal mover speeds in Hexen units (1/8 map unit per tic). A faster
+/// speed arg selects the turbo/blaze kind.
+const DOOR_SPEED: u8 = 16;
+const FLOOR_SPEED: u8 = 8;
+const PLAT_SPEED: u8 = 32;
+const CRUSH_SPEED: u8 = 8;
+
+// Doom sector types sit at 65-81 in Hexen-format maps, and the BOOM damage/
+// secret/friction/push bits are shifted up past the low byte.
+const SECTOR_DOOM_FIRST: i16 = 65;
+const SECTOR_DOOM_LAST: i16 = 81;
+const SECTOR_DOOM_OFFSET: i16 = 64;
+const SECTOR_BOOM_SHIFT: i16 = 3;
+const SECTOR_BOOM_MASK: i16 = 0x03E0;
+
+/// Key colour a Doom locked door checks; card and skull both open it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Blue,
+    Yellow,
+    Red,
+}
+
+/// What a Hexen special does, in terms of the Doom movers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Floor(FloorKind),
+    Ceil(CeilKind),
+    Door(DoorKind),
+    /// Door on the line's back sector (tag 0, used)
+    ManualDoor(DoorKind),
+    LockedDoor {
+        key: Key,
+        manual: bool,
+        open: bool,
+    },
+    /// `PlatKind` and its raise amount for `RaiseAndChange`
+    Plat(PlatKind, i32),
+    Stairs(StairKind),
+    Exit,
+    SecretExit,
+    Teleport,
+}
+
+/// Translate a Hexen linedef into the classic record, keeping the Doom flag
+/// bits and replacing the parameterised special with a vanilla number + tag.
+pub(crate) fn linedef_to_wad(l: &WadHexenLineDef) -> WadLineDef {
+    let (special, tag) = translate_special(l.special, l.args, l.flags);
+    let back = l.back_sidedef.unwrap_or(u16::MAX);
+    WadLineDef::new(
+        l.start_vertex,
+        l.end_vertex,
+        l.flags & ML_DOOM_MASK,
+        special,
+        tag,
+        l.front_sidedef,
+        l.back_sidedef,
+        [l.front_sidedef, back],
+    )
+}
+
+/// Translate a Hexen thing. Skill and ambush bits carry over; Hexen marks the
+/// modes a thing appears in, Doom marks multiplayer-only things.
+pub(crate) fn thing_to_wad(t: &WadHexenThing) -> WadThing {
+    if t.special != 0 {
+        debug!("thing {}: special {} ignored", t.tid, t.special);
+    }
+    let mut flags = t.flags & HTF_DOOM_MASK;
+    if t.flags & HTF_SINGLE == 0 {
+        flags |= MTF_NOT_SINGLE;
+    }
+    WadThing::new(t.x, t.y, t.angle, t.kind, flags)
+}
+
+/// Translate a Hexen-format sector type to the Doom type plus BOOM bits.
+pub(crate) fn sector_special(kind: i16) -> i16 {
+    let low = kind & 0xFF;
+    let doom = if (SECTOR_DOOM_FIRST..=SECTOR_DOOM_LAST).contains(&low) {
+        low - SECTOR_DOOM_OFFSET
+    } else {
+        if low != 0 {
+            debug!("sector type {low} has no Doom equivalent");
+        }
+        0
+    };
+    doom | ((kind >> SECTOR_BOOM_SHIFT) & SECTOR_BOOM_MASK)
+}
+
+/// Resolve a Hexen special to `(vanilla special, tag)`, or `(0, 0)` when there
+/// is no Doom equivalent for it or its activation.
+fn translate_special(special: u8, args: [u8; 5], flags: u16) -> (i16, i16) {
+    if special == 0 {
+        return (0, 0);
+    }
+    let spac = (flags >> ML_SPAC_SHIFT) & ML_SPAC_MASK;
+    let repeat = flags & ML_REPEAT_SPECIAL != 0;
+    let Some((action, tag)) = action_for(special, args, spac) else {
+        warn!("Hexen line special {special} {args:?} not supported");
+        return (0, 0);
+    };
+    // Monster-cross lines become plain walk lines: Doom decides per special
+    // whether monsters may trigger them.
+    let slot = match spac {
+        SPAC_CROSS | SPAC_MCROSS => 0,
+        SPAC_USE => 2,
+        SPAC_IMPACT => 4,
+        _ => {
+            warn!("Hexen line special {special}: activation {spac} not supported");
+            return (0, 0);
+        }
+    };
+    let numbers = vanilla_numbers(action);
+    let exact = numbers[slot + repeat as usize];
+    // Doom lacks some once/repeat pairs; the other of the two is closest.
+    let number = if exact != 0 {
+        exact
+    } else {
+        numbers[slot + !repeat as usize]
+    };
+    if number == 0 {
+        warn!("Hexen line special {special}: {action:?} has no Doom line for activation {spac}");
+        return (0, 0);
+    }
+    (number, tag)
+}
+
+/// The mover a special drives and the tag it acts on. Specials are the Hexen
+/// and ZDoom numbers Doom-in-Hexen maps use.
+fn action_for(special: u8, args: [u8; 5], spac: u16) -> Option<(Action, i16)> {
+    let tag = args[0] as i16;
+    let speed = args[1];
+    let manual = tag == 0 && spac == SPAC_USE;
+
+    let action = match special {
+        // Door_Close, Door_Open, Door_Raise, Door_CloseWaitOpen
+        10 => Action::Door(pick(
+            speed > DOOR_SPEED,
+            DoorKind::Close,
+            DoorKind::BlazeClose,
+        )),
+        11 | 12 => {
+            let kind = match (special, speed > DOOR_SPEED) {
+                (11, false) => DoorKind::Open,
+                (11, true) => DoorKind::BlazeOpen,
+                (_, false) => DoorKind::Normal,
+                (_, true) => DoorKind::BlazeRaise,
+            };
+            if manual {
+                Action::ManualDoor(kind)
+            } else {
+                Action::Door(kind)
+            }
+        }
+        249 => Action::Door(DoorKind::Close30ThenOpen),
+        // Door_LockedRaise: a zero delay opens and stays open.
+        13 => Action::LockedDoor {
+            key: lock_key(args[3])?,
+            manual,
+            open: args[2] == 0,
+        },
+
+        // Floor_LowerToLowest, Floor_LowerToHighest
+        21 => Action::Floor(FloorKind::LowerFloorToLowest),
+        242 => Action::Floor(pick(
+            speed > FLOOR_SPEED,
+            FloorKind::LowerFloor,
+            FloorKind::TurboLower,
+        )),
+        // Floor_RaiseToNearest, Floor_RaiseToLowestCeiling
+        25 => Action::Floor(pick(
+            speed > FLOOR_SPEED,
+            FloorKind::RaiseFloorToNearest,
+            FloorKind::RaiseFloorTurbo,
+        )),
+        238 => Action::Floor(FloorKind::RaiseFloor),
+        // Floor_RaiseAndCrush, Floor_RaiseAndCrushDoom
+        28 | 99 => Action::Floor(FloorKind::RaiseFloorCrush),
+        // Floor_RaiseByValue, Floor_RaiseByValueTimes8
+        23 if args[2] == 24 => Action::Floor(FloorKind::RaiseFloor24),
+        35 if args[2] == 64 => Action::Floor(FloorKind::RaiseFloor512),
+        // Floor_RaiseByTexture, Floor_LowerToLowestTxTy, Floor_Donut
+        240 => Action::Floor(FloorKind::RaiseToTexture),
+        241 => Action::Floor(FloorKind::LowerAndChange),
+        250 => Action::Floor(FloorKind::DonutRaise),
+
+        // Ceiling_CrushAndRaise, Ceiling_CrushAndRaiseA
+        42 | 196 => Action::Ceil(pick(
+            speed > CRUSH_SPEED,
+            CeilKind::CrushAndRaise,
+            CeilKind::FastCrushAndRaise,
+        )),
+        // Ceiling_CrushAndRaiseSilentA, Ceiling_LowerAndCrush,
+        // Ceiling_LowerToFloor
+        197 => Action::Ceil(CeilKind::SilentCrushAndRaise),
+        43 => Action::Ceil(CeilKind::LowerAndCrush),
+        254 => Action::Ceil(CeilKind::LowerToFloor),
+
+        // Plat_PerpetualRaise(Lip), Plat_DownWaitUpStay(Lip)
+        60 | 207 => Action::Plat(PlatKind::PerpetualRaise, 0),
+        62 | 206 => Action::Plat(
+            pick(
+                speed > PLAT_SPEED,
+                PlatKind::DownWaitUpStay,
+                PlatKind::BlazeDWUS,
+            ),
+            0,
+        ),
+        // Plat_RaiseAndStayTx0, Plat_UpByValueStayTx (height in 8s)
+        228 => Action::Plat(PlatKind::RaiseToNearestAndChange, 0),
+        230 if matches!(args[2], 3 | 4) => {
+            Action::Plat(PlatKind::RaiseAndChange, args[2] as i32 * 8)
+        }
+
+        // Stairs_BuildUp, Stairs_BuildUpDoom: the step height picks the kind.
+        27 | 217 => Action::Stairs(pick(args[2] >= 16, StairKind::Build8, StairKind::Turbo16)),
+
+        // Exit_Normal, Exit_Secret
+        243 => return Some((Action::Exit, 0)),
+        244 => return Some((Action::SecretExit, 0)),
+        // Teleport: only the sector-tag form, Doom has no thing IDs.
+        70 if args[0] == 0 && args[1] != 0 => return Some((Action::Teleport, args[1] as i16)),
+        _ => return None,
+    };
+    Some((action, tag))
+}
+
+/// The turbo/blaze kind when `fast`, else the normal one.
+fn pick<T>(fast: bool, normal: T, turbo: T) -> T {
+    if fast { turbo } else { normal }
+}
+
+/// Hexen lock number to the Doom key colour (`LOCKDEFS` numbering).
+fn lock_key(lock: u8) -> Option<Key> {
+    match lock {
+        2 | 5 | 130 => Some(Key::Blue),
+        3 | 6 | 131 => Some(Key::Yellow),
+        1 | 4 | 129 => Some(Key::Red),
+        _ => None,
+    }
+}
+
+/// Vanilla line numbers for an action, indexed `[W1, WR, S1, SR, G1, GR]`.
+/// Zero where Doom has no such line.
+#[rustfmt::skip]
+#[allow(
+    clippy::match_same_arms,
+    reason = "one arm per mover kind; distinct kinds may share a line number"
+)]
+fn vanilla_numbers(action: Action) -> [i16; 6] {
+    match action {
+        Action::Floor(kind) => match kind {
+            FloorKind::LowerFloor            => [19, 83, 102, 45, 0, 0],
+            FloorKind::LowerFloorToLowest    => [38, 82, 23, 60, 0, 0],
+            FloorKind::TurboLower            => [36, 98, 71, 70, 0, 0],
+            FloorKind::RaiseFloor            => [5, 91, 101, 64, 24, 0],
+            FloorKind::RaiseFloorToNearest   => [119, 128, 18, 69, 0, 0],
+            FloorKind::RaiseToTexture        => [30, 96, 0, 0, 0, 0],
+            FloorKind::LowerAndChange        => [37, 84, 0, 0, 0, 0],
+            FloorKind::RaiseFloor24          => [58, 92, 0, 0, 0, 0],
+            FloorKind::RaiseFloor24andChange => [59, 93, 0, 0, 0, 0],
+            FloorKind::RaiseFloorCrush       => [56, 94, 55, 65, 0, 0],
+            FloorKind::RaiseFloorTurbo       => [130, 129, 131, 132, 0, 0],
+            FloorKind::DonutRaise            => [0, 0, 9, 0, 0, 0],
+            FloorKind::RaiseFloor512         => [0, 0, 140, 0, 0, 0],
+        },
+        Action::Ceil(kind) => match kind {
+            CeilKind::LowerToFloor        => [0, 0, 41, 43, 0, 0],
+            CeilKind::RaiseToHighest      => [0; 6],
+            CeilKind::LowerAndCrush       => [44, 72, 0, 0, 0, 0],
+            CeilKind::CrushAndRaise       => [25, 73, 49, 0, 0, 0],
+            CeilKind::FastCrushAndRaise   => [6, 77, 0, 0, 0, 0],
+            CeilKind::SilentCrushAndRaise => [141, 0, 0, 0, 0, 0],
+        },
+        Action::Door(kind) => match kind {
+            DoorKind::Normal          => [4, 90, 29, 63, 0, 0],
+            DoorKind::Close30ThenOpen => [16, 76, 0, 0, 0, 0],
+            DoorKind::Close           => [3, 75, 50, 42, 0, 0],
+            DoorKind::Open            => [2, 86, 103, 61, 0, 46],
+            DoorKind::RaiseIn5Mins    => [0; 6],
+            DoorKind::BlazeRaise      => [108, 105, 111, 114, 0, 0],
+            DoorKind::BlazeOpen       => [109, 106, 112, 115, 0, 0],
+            DoorKind::BlazeClose      => [110, 107, 113, 116, 0, 0],
+        },
+        Action::ManualDoor(kind) => match kind {
+            DoorKind::Normal     => [0, 0, 1, 1, 0, 0],
+            DoorKind::Open       => [0, 0, 31, 31, 0, 0],
+            DoorKind::BlazeRaise => [0, 0, 117, 117, 0, 0],
+            DoorKind::BlazeOpen  => [0, 0, 118, 118, 0, 0],
+            _                    => [0; 6],
+        },
+        Action::LockedDoor { key, manual, open } => match (key, manual, open) {
+            (Key::Blue, true, false)   => [0, 0, 26, 26, 0, 0],
+            (Key::Yellow, true, false) => [0, 0, 27, 27, 0, 0],
+            (Key::Red, true, false)    => [0, 0, 28, 28, 0, 0],
+            (Key::Blue, true, true)    => [0, 0, 32, 32, 0, 0],
+            (Key::Yellow, true, true)  => [0, 0, 34, 34, 0, 0],
+            (Key::Red, true, true)     => [0, 0, 33, 33, 0, 0],
+            // The tagged key switches all blaze the door open.
+            (Key::Blue, false, _)      => [0, 0, 133, 99, 0, 0],
+            (Key::Yellow, false, _)    => [0, 0, 137, 136, 0, 0],
+            (Key::Red, false, _)       => [0, 0, 135, 134, 0, 0],
+        },
+        Action::Plat(kind, amount) => match (kind, amount) {
+            (PlatKind::PerpetualRaise, _)          => [53, 87, 0, 0, 0, 0],
+            (PlatKind::DownWaitUpStay, _)          => [10, 88, 21, 62, 0, 0],
+            (PlatKind::RaiseAndChange, 24)         => [0, 0, 15, 66, 0, 0],
+            (PlatKind::RaiseAndChange, _)          => [0, 0, 14, 67, 0, 0],
+            (PlatKind::RaiseToNearestAndChange, _) => [22, 95, 20, 68, 47, 0],
+            (PlatKind::BlazeDWUS, _)               => [121, 120, 122, 123, 0, 0],
+        },
+        Action::Stairs(kind) => match kind {
+            StairKind::Build8  => [8, 0, 7, 0, 0, 0],
+            StairKind::Turbo16 => [100, 0, 127, 0, 0, 0],
+        },
+        Action::Exit       => [52, 0, 11, 0, 0, 0],
+        Action::SecretExit => [124, 0, 51, 0, 0, 0],
+        Action::Teleport   => [39, 97, 0, 0, 0, 0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::special_encode;
+
+    const USE: u16 = SPAC_USE << ML_SPAC_SHIFT;
+    const IMPACT: u16 = SPAC_IMPACT << ML_SPAC_SHIFT;
+
+    #[test]
+    fn doors_by_speed_and_tag() {
+        // Door_Raise, tagged, switch once: the normal and blazing raise.
+        assert_eq!(translate_special(12, [5, 16, 150, 0, 0], USE), (29, 5));
+        assert_eq!(translate_special(12, [5, 64, 150, 0, 0], USE), (111, 5));
+        // Tag 0 used: the manual door on the back sector.
+        assert_eq!(
+            translate_special(12, [0, 16, 150, 0, 0], USE | ML_REPEAT_SPECIAL),
+            (1, 0)
+        );
+        assert_eq!(translate_special(11, [0, 64, 0, 0, 0], USE), (118, 0));
+        // Walk-over repeatable close.
+        assert_eq!(
+            translate_special(10, [3, 16, 0, 0, 0], ML_REPEAT_SPECIAL),
+            (75, 3)
+        );
+        // Gun-triggered open only exists as GR.
+        assert_eq!(translate_special(11, [2, 16, 0, 0, 0], IMPACT), (46, 2));
+    }
+
+    #[test]
+    fn locked_doors_keep_their_key() {
+        // Blue skull raise, yellow card open-stay, both manual.
+        assert_eq!(translate_special(13, [0, 16, 150, 5, 0], USE), (26, 0));
+        assert_eq!(translate_special(13, [0, 16, 0, 3, 0], USE), (34, 0));
+        // Tagged red switch, repeatable.
+        assert_eq!(
+            translate_special(13, [7, 64, 0, 129, 0], USE | ML_REPEAT_SPECIAL),
+            (134, 7)
+        );
+        // "Any key" has no Doom door.
+        assert_eq!(translate_special(13, [0, 16, 150, 100, 0], USE), (0, 0));
+    }
+
+    #[test]
+    fn floors_ceilings_plats_and_stairs() {
+        assert_eq!(translate_special(21, [4, 8, 0, 0, 0], 0), (38, 4));
+        assert_eq!(translate_special(242, [4, 32, 8, 0, 0], USE), (71, 4));
+        assert_eq!(
+            translate_special(23, [4, 8, 24, 0, 0], ML_REPEAT_SPECIAL),
+            (92, 4)
+        );
+        assert_eq!(translate_special(23, [4, 8, 24, 0, 0], 0), (58, 4));
+        assert_eq!(translate_special(196, [9, 16, 16, 10, 0], 0), (6, 9));
+        assert_eq!(
+            translate_special(62, [2, 32, 105, 0, 0], ML_REPEAT_SPECIAL),
+            (88, 2)
+        );
+        assert_eq!(translate_special(206, [2, 64, 105, 0, 0], USE), (122, 2));
+        assert_eq!(translate_special(230, [6, 4, 3, 0, 0], USE), (15, 6));
+        assert_eq!(translate_special(217, [1, 32, 16, 0, 0], 0), (100, 1));
+    }
+
+    #[test]
+    fn exits_and_teleports() {
+        assert_eq!(translate_special(243, [0; 5], USE), (11, 0));
+        assert_eq!(translate_special(244, [0; 5], 0), (124, 0));
+        assert_eq!(
+            translate_special(70, [0, 12, 0, 0, 0], ML_REPEAT_SPECIAL),
+            (97, 12)
+        );
+        // Thing-ID teleports need a TID lookup Doom lacks.
+        assert_eq!(translate_special(70, [3, 0, 0, 0, 0], 0), (0, 0));
+    }
+
+    #[test]
+    fn unsupported_specials_are_dropped() {
+        // ACS_Execute, and a mover with no Doom line for a gunshot.
+        assert_eq!(translate_special(80, [1, 0, 0, 0, 0], USE), (0, 0));
+        assert_eq!(translate_special(21, [4, 8, 0, 0, 0], IMPACT), (0, 0));
+    }
+
+    #[test]
+    fn translated_movers_normalise() {
+        // Every translated mover is one the load pass can rewrite, so the
+        // engine decodes it to the kind the Hexen special asked for.
+        let (special, _) = translate_special(25, [4, 32, 0, 0, 0], USE);
+        let spec = special_encode::decode(special_encode::encode_vanilla(special as u32).unwrap())
+            .unwrap();
+        assert_eq!(spec.kind, FloorKind::RaiseFloorTurbo as u8);
+        assert_eq!(spec.trigger, special_encode::Trigger::SwitchOnce);
+    }
+
+    #[test]
+    fn things_and_sectors() {
+        let thing = |flags| WadHexenThing {
+            kind: 3001,
+            flags,
+            ..WadHexenThing::default()
+        };
+        // Easy/normal/hard + ambush, in single player.
+        assert_eq!(thing_to_wad(&thing(0x010F)).flags, 0x0F);
+        // Coop/deathmatch only.
+        assert_eq!(thing_to_wad(&thing(0x0607)).flags, 0x07 | MTF_NOT_SINGLE);
+
+        assert_eq!(sector_special(0), 0);
+        assert_eq!(sector_special(65), 1);
+        assert_eq!(sector_special(71), 7);
+        // Secret + 20% damage carry over as the BOOM bits.
+        assert_eq!(sector_special(0x0400 | 0x0300), 0x80 | 0x60);
+        // Hexen-only types are dropped.
+        assert_eq!(sector_special(26), 0);
+    }
+}
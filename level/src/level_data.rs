@@ -1,15 +1,19 @@
 use crate::map_defs::{
-    BBox, Blockmap, LineDef, Sector, Segment, SideDef, SlopeType, SubSector, Vertex,
+    BBox, Blockmap, LineDef, SKY_TRANSFER, SKY_TRANSFER_FLIPPED, Sector, Segment, SideDef,
+    SidePart, SlopeType, SubSector, SurfaceLight, Vertex,
 };
 
 use crate::bsp3d::BSP3D;
 use crate::flags::LineDefFlags;
+use crate::hexen;
 use crate::map_array::MapArray;
-use crate::{MapPtr, SlopePlane, special_encode};
+use crate::transfer_heights::{FakeFlat, view_colourmap};
+use crate::{MapPtr, MovementType, SlopePlane, special_encode};
 use glam::Vec2;
 use log::{debug, info, warn};
-use math::{Angle, FixedT};
+use math::{Angle, Bam, FixedT};
 use rbsp::LineDefAccess as _;
+use rbsp::bsp3d::movers::TRANSFER_HEIGHTS;
 use rbsp::bsp3d::{Bsp3dBuilder, Bsp3dInput, Bsp3dLump};
 use std::collections::BTreeMap;
 use std::collections::hash_map::DefaultHasher;
@@ -19,6 +23,12 @@ use std::time::Instant;
 use std::{fs, process};
 
 const CELL_SIZE: f32 = 128.0;
+/// BOOM linedef special: tagged sectors take their floor light from the line's
+/// front sector
+const FLOOR_LIGHT_TRANSFER: u32 = 213;
+/// As [`FLOOR_LIGHT_TRANSFER`] for the ceiling
+const CEILING_LIGHT_TRANSFER: u32 = 261;
+use wad::extended::WadExtendedMap;
 use wad::types::*;
 use wad::{MapLump, WadData};
 
@@ -61,6 +71,12 @@ pub struct LevelData {
     reject: Vec<u8>,
     extents: MapExtents,
     pub bsp_3d: BSP3D,
+    /// Real values of the sectors overwritten by `apply_transfer_heights`
+    fake_flats: Vec<(usize, FakeFlat)>,
+    /// What the 3D geometry of each 242 sector shows. It is left in place
+    /// between frames and only touched when the view changes it, `None` when
+    /// unknown.
+    drawn_flats: Vec<Option<FakeFlat>>,
 }
 
 /// A two-sided segment's owner, side, and 1D span along its linedef.
@@ -137,6 +153,95 @@ impl LevelData {
         self.bsp_3d.restore_sector_state(&mut self.sectors);
     }
 
+    /// Draw every linedef 242 sector with its control sector's planes as seen
+    /// from `viewz` at `x, y`, returning the colourmap set the view should
+    /// use. Must call `restore_transfer_heights()` after rendering.
+    pub fn apply_transfer_heights(&mut self, x: FixedT, y: FixedT, viewz: FixedT) -> usize {
+        let leaf = self.bsp_3d.point_in_leaf(x, y);
+        let view_sector = &self.subsectors[self.bsp_3d.leaves[leaf].subsector].sector;
+        let view_control = view_sector.heightsec.map(|c| &self.sectors[c]);
+        let colourmap = view_colourmap(view_control, viewz);
+
+        let sky_num = self.bsp_3d.sky_num();
+        let fakes: Vec<(usize, FakeFlat)> = self
+            .sectors
+            .iter()
+            .enumerate()
+            .filter_map(|(i, sector)| {
+                let control = &self.sectors[sector.heightsec?];
+                Some((
+                    i,
+                    FakeFlat::transfer(sector, control, view_control, viewz, sky_num),
+                ))
+            })
+            .collect();
+
+        if self.bsp_3d.geometry_dirty() || self.bsp_3d.texture_dirty() {
+            // Movers or texture changes since the last frame may have written
+            // real values over the fakes
+            self.drawn_flats.clear();
+        }
+        self.drawn_flats.resize(self.sectors.len(), None);
+        self.fake_flats.clear();
+        for (sector_id, fake) in fakes {
+            self.fake_flats
+                .push((sector_id, FakeFlat::of(&self.sectors[sector_id])));
+            fake.write(&mut self.sectors[sector_id]);
+            if self.drawn_flats[sector_id] != Some(fake) {
+                self.sync_fake_flat(sector_id, self.drawn_flats[sector_id], &fake);
+                self.drawn_flats[sector_id] = Some(fake);
+            }
+        }
+        colourmap
+    }
+
+    /// Put back the real sector values replaced by `apply_transfer_heights()`.
+    /// The 3D geometry keeps the fakes for the next frame.
+    pub fn restore_transfer_heights(&mut self) {
+        for (sector_id, real) in std::mem::take(&mut self.fake_flats) {
+            let sector = &mut self.sectors[sector_id];
+            real.write(sector);
+            // Restoring the interpolation puts the real heights back in the
+            // geometry of a moving sector
+            if sector.floorheight != sector.interp_floorheight
+                || sector.ceilingheight != sector.interp_ceilingheight
+            {
+                self.drawn_flats[sector_id] = None;
+            }
+        }
+    }
+
+    /// Bring the 3D geometry of a sector from `from` over to `to`, all of it
+    /// when what it shows is unknown.
+    fn sync_fake_flat(&mut self, sector_id: usize, from: Option<FakeFlat>, to: &FakeFlat) {
+        let floor = |f: &FakeFlat| (f.floorheight, f.floorpic, f.floor_offs);
+        let ceiling = |f: &FakeFlat| (f.ceilingheight, f.ceilingpic, f.ceiling_offs);
+        for (movement, from, (height, pic, offs)) in [
+            (MovementType::Floor, from.as_ref().map(floor), floor(to)),
+            (
+                MovementType::Ceiling,
+                from.as_ref().map(ceiling),
+                ceiling(to),
+            ),
+        ] {
+            if from.is_none_or(|(h, _, _)| h != height) {
+                self.bsp_3d
+                    .set_surface_height(sector_id, movement, height.to_f32());
+            }
+            if from.is_none_or(|(_, p, _)| p != pic) {
+                self.bsp_3d.update_flat_texture(sector_id, movement);
+            }
+            if from.is_none_or(|(_, _, o)| o != offs) {
+                self.bsp_3d.update_flat_scroll(
+                    sector_id,
+                    movement,
+                    offs[0].to_f32(),
+                    offs[1].to_f32(),
+                );
+            }
+        }
+    }
+
     const fn set_scale(&mut self) {
         let map_width = self.extents.width;
         let map_height = self.extents.height;
@@ -192,22 +297,45 @@ impl LevelData {
             return;
         }
 
-        self.things = wad
-            .map_iter::<WadThing>(map_name, MapLump::Things)
-            .collect();
+        // Hexen-format maps are translated to the classic records up front,
+        // everything below sees an ordinary Doom map.
+        let hexen = wad.is_hexen_map(map_name);
+        if hexen {
+            info!("{map_name}: Hexen-format map");
+            self.things = wad
+                .map_iter::<WadHexenThing>(map_name, MapLump::Things)
+                .map(|t| hexen::thing_to_wad(&t))
+                .collect();
+        } else {
+            self.things = wad
+                .map_iter::<WadThing>(map_name, MapLump::Things)
+                .collect();
+        }
         info!("{}: Loaded {} things", map_name, self.things.len());
 
         // Sectors and sidedefs from WAD (unchanged by BSP builder)
         self.load_sectors(map_name, wad, &flat_num_for_name);
+        if hexen {
+            for sector in self.sectors.iter_mut() {
+                let special = hexen::sector_special(sector.default_special);
+                sector.special = special as u32;
+                sector.default_special = special;
+            }
+        }
         self.load_sidedefs(map_name, wad, &tex_order);
 
+        let wad_linedefs: Vec<WadLineDef> = if hexen {
+            wad.map_iter::<WadHexenLineDef>(map_name, MapLump::LineDefs)
+                .map(|l| hexen::linedef_to_wad(&l))
+                .collect()
+        } else {
+            wad.map_iter::<WadLineDef>(map_name, MapLump::LineDefs)
+                .collect()
+        };
+
         // 2D BSP + 3D geometry, always in lump form: WAD lump, cache,
         // or built fresh (then cached).
-        let (bsp, bsp3d_lump) = load_or_build_bsp(map_name, wad, sky_num, sky_pic);
-
-        let wad_linedefs: Vec<WadLineDef> = wad
-            .map_iter::<WadLineDef>(map_name, MapLump::LineDefs)
-            .collect();
+        let (bsp, bsp3d_lump) = load_or_build_bsp(map_name, wad, &wad_linedefs, sky_num, sky_pic);
 
         // --- Vertices: direct from rbsp. Frozen into a `MapArray` so the
         // MapPtrs taken into it below can never be invalidated by a move. ---
@@ -286,11 +414,20 @@ impl LevelData {
                     backsector: back_sector,
                     valid_count: 0,
                     sides,
+                    alpha: 1.0,
+                    additive: false,
                 }
             })
             .collect();
         info!("{}: Loaded {} linedefs", map_name, self.linedefs.len());
 
+        self.load_transfer_heights(wad, |side| {
+            wad.map_iter::<WadSideDef>(map_name, MapLump::SideDefs)
+                .nth(side)
+                .map(|s| [s.lower_tex, s.middle_tex, s.upper_tex])
+                .unwrap_or_default()
+        });
+
         self.finalize_after_linedefs(map_name, wad, &bsp, bsp3d_lump, sky_num, &tex_order);
     }
 
@@ -315,6 +452,8 @@ impl LevelData {
             }
         }
 
+        self.load_light_and_sky_transfers();
+
         // Map sectors to lines
         for line in self.linedefs.iter_mut() {
             let mut sector = line.frontsector.clone();
@@ -444,7 +583,8 @@ impl LevelData {
     /// Mirrors the classic front (things → sectors → sidedefs → bsp →
     /// vertices → linedefs) but from `UdmfMap` records, attaching sector slope
     /// planes, then runs the shared finalize tail. No WAD-record synthesis: the
-    /// 2D BSP is built straight from the UDMF-derived `BspInput`.
+    /// 2D BSP comes from the `ZNODES` GL nodes or is built straight from the
+    /// UDMF-derived `BspInput`.
     fn load_udmf(
         &mut self,
         map_name: &str,
@@ -479,6 +619,20 @@ impl LevelData {
                 );
                 sector.floor_plane = s.floor_plane.and_then(udmf_plane);
                 sector.ceil_plane = s.ceiling_plane.and_then(udmf_plane);
+                let [x, y] = s.panning_floor;
+                (sector.floor_xoffs, sector.floor_yoffs) = (udmf_fixed(x), udmf_fixed(y));
+                let [x, y] = s.panning_ceiling;
+                (sector.ceiling_xoffs, sector.ceiling_yoffs) = (udmf_fixed(x), udmf_fixed(y));
+                sector.floor_angle = udmf_angle(s.rotation_floor);
+                sector.ceiling_angle = udmf_angle(s.rotation_ceiling);
+                sector.floor_light = SurfaceLight {
+                    level: s.lightfloor,
+                    absolute: s.lightfloorabsolute,
+                };
+                sector.ceiling_light = SurfaceLight {
+                    level: s.lightceiling,
+                    absolute: s.lightceilingabsolute,
+                };
                 sector
             })
             .collect();
@@ -500,13 +654,21 @@ impl LevelData {
                 bottomtexture: tex_idx(&sd.texturebottom),
                 midtexture: tex_idx(&sd.texturemiddle),
                 sector: MapPtr::new(&mut self.sectors[sd.sector]),
+                top: udmf_side_part(&sd.top),
+                mid: udmf_side_part(&sd.mid),
+                bottom: udmf_side_part(&sd.bottom),
+                light: SurfaceLight {
+                    level: sd.light,
+                    absolute: sd.lightabsolute,
+                },
             })
             .collect();
         info!("{}: Loaded {} sidedefs", map_name, self.sidedefs.len());
 
         let udmf_input = rbsp::udmf_input::UdmfInput::from_map(&map);
         let bsp_input = udmf_input.into_bsp_input();
-        let (bsp, bsp3d_lump) = build_bsp_from_input(map_name, &bsp_input, sky_num, sky_pic);
+        let (bsp, bsp3d_lump) = bsp_from_gl_nodes(map_name, wad, &bsp_input, sky_num, sky_pic)
+            .unwrap_or_else(|| build_bsp_from_input(map_name, &bsp_input, sky_num, sky_pic));
 
         self.vertexes = bsp
             .vertices
@@ -577,11 +739,19 @@ impl LevelData {
                     backsector: back_sector,
                     valid_count: 0,
                     sides,
+                    alpha: l.alpha as f32,
+                    additive: l.renderstyle.as_deref() == Some("add"),
                 }
             })
             .collect();
         info!("{}: Loaded {} linedefs", map_name, self.linedefs.len());
 
+        self.load_transfer_heights(wad, |side| {
+            let sd = &map.sidedefs[side];
+            [&sd.texturebottom, &sd.texturemiddle, &sd.texturetop]
+                .map(|name| name.clone().unwrap_or_default())
+        });
+
         // UDMF WADs carry no BLOCKMAP/REJECT lumps; the tail's `load_blockmap`
         // falls back to `build_blockmap` and reject is empty when absent.
         self.finalize_after_linedefs(map_name, wad, &bsp, bsp3d_lump, sky_num, tex_order);
@@ -621,6 +791,80 @@ impl LevelData {
         }
     }
 
+    /// Link the sectors tagged by each linedef 242 to the line's front sector,
+    /// and take that sector's region colourmaps from the names on the line's
+    /// front sidedef (lower, middle, upper). `side_textures` gives those names
+    /// for a sidedef index. Untagged lines are skipped, as for movers, so the
+    /// 3D geometry is built for exactly the sectors that change.
+    ///
+    /// Doom function names `P_SpawnSpecials` (242) and `P_LoadSideDefs2`
+    fn load_transfer_heights(
+        &mut self,
+        wad: &WadData,
+        side_textures: impl Fn(usize) -> [String; 3],
+    ) {
+        let mut colourmaps: Option<Vec<String>> = None;
+        for line in self.linedefs.iter() {
+            if line.special != TRANSFER_HEIGHTS || line.tag == 0 {
+                continue;
+            }
+            let names =
+                colourmaps.get_or_insert_with(|| wad.colourmaps_iter().map(|c| c.name).collect());
+            // Index 0 is COLORMAP, C_START lumps follow. Texture names and
+            // unknown colourmaps leave the region on COLORMAP.
+            let [bottom, mid, top] = side_textures(line.sides[0] as usize).map(|name| {
+                names
+                    .iter()
+                    .position(|n| n.eq_ignore_ascii_case(&name))
+                    .map_or(0, |i| i + 1)
+            });
+
+            let control = line.frontsector.num as usize;
+            let sector = &mut self.sectors[control];
+            sector.bottommap = bottom;
+            sector.midmap = mid;
+            sector.topmap = top;
+            for sector in self.sectors.iter_mut().filter(|s| s.tag == line.tag) {
+                sector.heightsec = Some(control);
+            }
+            debug!(
+                "line-special #242: sectors tagged {} use sector {control}",
+                line.tag
+            );
+        }
+    }
+
+    /// Point every sector sharing the tag of a light transfer line (213, 261)
+    /// at the line's front sector, and of a sky transfer line (271, 272) at
+    /// the line itself.
+    ///
+    /// Doom function name `P_SpawnSpecials` (BOOM/MBF transfers)
+    fn load_light_and_sky_transfers(&mut self) {
+        for line in self.linedefs.iter_mut() {
+            let special = line.special;
+            if !matches!(
+                special,
+                FLOOR_LIGHT_TRANSFER | CEILING_LIGHT_TRANSFER | SKY_TRANSFER | SKY_TRANSFER_FLIPPED
+            ) {
+                continue;
+            }
+            debug!(
+                "line-special #{special}: transfer to sectors tagged {}",
+                line.tag
+            );
+            let tag = line.tag;
+            let front = line.frontsector.clone();
+            let line = MapPtr::new(line);
+            for sector in self.sectors.iter_mut().filter(|s| s.tag == tag) {
+                match special {
+                    FLOOR_LIGHT_TRANSFER => sector.floorlightsec = Some(front.clone()),
+                    CEILING_LIGHT_TRANSFER => sector.ceilinglightsec = Some(front.clone()),
+                    _ => sector.sky_line = Some(line.clone()),
+                }
+            }
+        }
+    }
+
     fn load_sectors(
         &mut self,
         map_name: &str,
@@ -675,6 +919,10 @@ impl LevelData {
                         .iter()
                         .position(|n| n.name == s.middle_tex.to_ascii_uppercase()),
                     sector: MapPtr::new(sector),
+                    top: SidePart::default(),
+                    mid: SidePart::default(),
+                    bottom: SidePart::default(),
+                    light: SurfaceLight::default(),
                 }
             })
             .collect();
@@ -952,11 +1200,12 @@ impl LevelData {
 const SKY_FLAT_NAME: &str = "F_SKY1";
 
 /// The map's 2D BSP + 3D geometry, always in lump form: from the WAD's
-/// `RBSP` lump when present, else from the cache, else built via the
-/// rbsp crate (and written to the cache for the next run).
+/// `RBSP` lump when present, else from its GL nodes, else from the cache, else
+/// built via the rbsp crate (and written to the cache for the next run).
 fn load_or_build_bsp(
     map_name: &str,
     wad: &WadData,
+    linedefs: &[WadLineDef],
     sky_num: Option<usize>,
     sky_pic: Option<usize>,
 ) -> (rbsp::BspOutput, Bsp3dLump) {
@@ -968,24 +1217,11 @@ fn load_or_build_bsp(
         info!("{map_name}: WAD RBSP lump is not the current version, rebuilding");
     }
 
-    let cache_path = rbsp_cache_path(map_name, wad, sky_num.is_some(), sky_pic.is_some());
-    if let Some(path) = &cache_path
-        && let Ok(bytes) = fs::read(path)
-    {
-        if let Some(found) = rbsp::rbsp_lump::read_rbsp_lump(&bytes) {
-            info!("{}: Loaded RBSP cache {}", map_name, path.display());
-            return found;
-        }
-        info!("{map_name}: stale RBSP cache, rebuilding");
-    }
-
     let input = rbsp::BspInput {
         vertices: wad
             .map_iter::<WadVertex>(map_name, MapLump::Vertexes)
             .collect(),
-        linedefs: wad
-            .map_iter::<WadLineDef>(map_name, MapLump::LineDefs)
-            .collect(),
+        linedefs: linedefs.to_vec(),
         sidedefs: wad
             .map_iter::<WadSideDef>(map_name, MapLump::SideDefs)
             .collect(),
@@ -993,6 +1229,24 @@ fn load_or_build_bsp(
             .map_iter::<WadSector>(map_name, MapLump::Sectors)
             .collect(),
     };
+
+    // GL subsectors are already convex outlines: only the 3D pass runs, so
+    // there is nothing worth caching.
+    if let Some(found) = bsp_from_gl_nodes(map_name, wad, &input, sky_num, sky_pic) {
+        return found;
+    }
+
+    let cache_path = rbsp_cache_path(map_name, wad, sky_num.is_some(), sky_pic.is_some());
+    if let Some(path) = &cache_path
+        && let Ok(bytes) = fs::read(path)
+    {
+        if let Some(found) = rbsp::rbsp_lump::read_rbsp_lump(&bytes) {
+            info!("{}: Loaded RBSP cache {}", map_name, path.display());
+            return found;
+        }
+        info!("{map_name}: stale RBSP cache, rebuilding");
+    }
+
     let (output, lump3d) = build_bsp_from_input(map_name, &input, sky_num, sky_pic);
 
     if let Some(path) = &cache_path {
@@ -1001,6 +1255,37 @@ fn load_or_build_bsp(
     (output, lump3d)
 }
 
+/// The 2D BSP and 3D geometry from the map's GL nodes, `None` when it has
+/// none or they do not fit the map.
+fn bsp_from_gl_nodes<V, L, S, SE>(
+    map_name: &str,
+    wad: &WadData,
+    input: &rbsp::BspInput<V, L, S, SE>,
+    sky_num: Option<usize>,
+    sky_pic: Option<usize>,
+) -> Option<(rbsp::BspOutput, Bsp3dLump)>
+where
+    V: rbsp::VertexCoords,
+    L: rbsp::LineDefAccess,
+    S: rbsp::SideDefAccess,
+    SE: rbsp::SectorAccess,
+{
+    let gl = WadExtendedMap::parse_gl(wad, map_name)?;
+    let Some(output) = rbsp::gl_nodes::output_from_gl_nodes(input, &gl) else {
+        warn!("{map_name}: GL nodes do not match the map, rebuilding");
+        return None;
+    };
+    info!(
+        "{}: Using {:?} GL nodes: {} ssectors, {} nodes",
+        map_name,
+        gl.node_type,
+        output.subsectors.len(),
+        output.nodes.len(),
+    );
+    let lump3d = build_bsp3d(input, &output, sky_num, sky_pic);
+    Some((output, lump3d))
+}
+
 /// Run the 2D BSP build + 3D geometry build on any trait-satisfying records.
 /// Shared by the classic (post-cache-miss) and UDMF load paths.
 fn build_bsp_from_input<V, L, S, SE>(
@@ -1024,16 +1309,31 @@ where
         output.subsectors.len(),
         output.nodes.len(),
     );
+    let lump3d = build_bsp3d(input, &output, sky_num, sky_pic);
+    (output, lump3d)
+}
+
+/// The 3D geometry over a finished 2D BSP, however that BSP was obtained.
+fn build_bsp3d<V, L, S, SE>(
+    input: &rbsp::BspInput<V, L, S, SE>,
+    output: &rbsp::BspOutput,
+    sky_num: Option<usize>,
+    sky_pic: Option<usize>,
+) -> Bsp3dLump
+where
+    L: rbsp::LineDefAccess,
+    S: rbsp::SideDefAccess,
+    SE: rbsp::SectorAccess,
+{
     let bsp3d_input = Bsp3dInput::new(
         &input.linedefs,
         &input.sidedefs,
         &input.sectors,
-        &output,
+        output,
         sky_num.is_some().then_some(SKY_FLAT_NAME),
         sky_pic.is_some(),
     );
-    let lump3d = Bsp3dBuilder::build(&bsp3d_input, &output.nodes);
-    (output, lump3d)
+    Bsp3dBuilder::build(&bsp3d_input, &output.nodes)
 }
 
 /// Convert a UDMF thing to the classic `WadThing` the spawn path consumes.
@@ -1061,6 +1361,29 @@ fn udmf_thing_to_wad(t: &wad::udmf::UdmfThing) -> WadThing {
         angle: t.angle as i16,
         kind: i16::try_from(t.kind).unwrap_or(0),
         flags,
+        translucent: t.alpha < 1.0,
+    }
+}
+
+/// A UDMF map-unit value in fixed point.
+fn udmf_fixed(v: f64) -> FixedT {
+    FixedT::from_f32(v as f32)
+}
+
+/// UDMF rotation in degrees to a BAM angle, exact for the common multiples
+/// of 45 that a float radian round trip would drift off.
+fn udmf_angle(degrees: f64) -> Angle<Bam> {
+    let turns = degrees.rem_euclid(360.0) / 360.0;
+    Angle::from_bam((turns * 4_294_967_296.0) as u64 as u32)
+}
+
+/// Engine per-part texture offset and scale from a UDMF sidedef part.
+fn udmf_side_part(part: &wad::udmf::UdmfSidePart) -> SidePart {
+    SidePart {
+        xoffset: udmf_fixed(part.offsetx),
+        yoffset: udmf_fixed(part.offsety),
+        xscale: udmf_fixed(part.scalex),
+        yscale: udmf_fixed(part.scaley),
     }
 }
 
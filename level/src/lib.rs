@@ -15,9 +15,12 @@ pub mod env_kinds;
 pub mod env_query;
 pub mod env_target;
 pub mod flags;
+mod hexen;
 pub mod level_data;
 pub mod map_array;
 pub mod map_defs;
+mod seen_lines;
+mod transfer_heights;
 // Re-exports for convenience
 pub use bsp3d::{
     AABB, BSP3D, BSPLeaf3D, Bsp3dBuilder, Bsp3dLump, IS_LEAF_MASK, LIGHT_LEVELS, MovementType,
@@ -27,7 +30,8 @@ pub use bsp3d::{
 pub use flags::LineDefFlags;
 pub use level_data::LevelData;
 pub use map_defs::{
-    BBox, Blockmap, LineDef, Sector, SectorHeight, Segment, SideDef, SlopeType, SubSector, Vertex,
+    BBox, Blockmap, LineDef, Sector, SectorHeight, SectorSky, Segment, SideDef, SidePart,
+    SlopeType, SubSector, SurfaceLight, Vertex,
 };
 pub use rbsp::{SlopePlane, special_encode};
 /// This exists to allow breaking the rules of borrows and in some cases
@@ -1,12 +1,20 @@
 use crate::MapPtr;
 use crate::flags::LineDefFlags;
 use glam::Vec2;
-use math::{Angle, FixedT, p_aprox_distance, r_point_on_side_raw};
+use math::{Angle, Bam, FixedT, p_aprox_distance, r_point_on_side_raw};
 use rbsp::SlopePlane;
 
 /// Sector floor/ceiling height type: fixed-point for deterministic gameplay.
 pub type SectorHeight = FixedT;
 
+/// MBF linedef special: tagged sectors draw the line's upper texture as sky,
+/// the way it looks on the wall.
+pub const SKY_TRANSFER: u32 = 271;
+/// As [`SKY_TRANSFER`] but mirrored, like the normal sky.
+pub const SKY_TRANSFER_FLIPPED: u32 = 272;
+/// BOOM linedef special: the middle texture is drawn translucent.
+pub const TRANSLUCENT_LINE: u32 = 260;
+
 /// Map vertex with both f32 position and original fixed-point coordinates.
 #[derive(Debug, Clone, Copy)]
 pub struct Vertex {
@@ -109,6 +117,73 @@ pub struct Sector {
     pub floor_plane: Option<SlopePlane>,
     /// Sloped ceiling plane (UDMF); `None` = flat at `ceilingheight`.
     pub ceil_plane: Option<SlopePlane>,
+
+    /// BOOM flat scrolling offsets in map units, added to the flat's texture
+    /// position (x to the column, y to the row).
+    pub floor_xoffs: FixedT,
+    pub floor_yoffs: FixedT,
+    pub ceiling_xoffs: FixedT,
+    pub ceiling_yoffs: FixedT,
+
+    /// BOOM linedef 242: control sector whose floor and ceiling are drawn in
+    /// place of this sector's. Collision still uses the real heights.
+    pub heightsec: Option<usize>,
+    /// Colourmap sets (0 is `COLORMAP`) for a view below the floor, between
+    /// the planes, and above the ceiling of this sector when it controls a
+    /// linedef 242 region.
+    pub bottommap: usize,
+    pub midmap: usize,
+    pub topmap: usize,
+
+    /// BOOM light transfers (213, 261): sector whose light level the floor or
+    /// ceiling is drawn with instead of this sector's own.
+    pub floorlightsec: Option<MapPtr<Self>>,
+    pub ceilinglightsec: Option<MapPtr<Self>>,
+    /// MBF sky transfer (271, 272): line whose front upper texture is drawn as
+    /// the sky of this sector.
+    pub sky_line: Option<MapPtr<LineDef>>,
+
+    /// ZDoom UDMF flat rotation (`rotationfloor`, `rotationceiling`),
+    /// counter-clockwise.
+    pub floor_angle: Angle<Bam>,
+    pub ceiling_angle: Angle<Bam>,
+    /// ZDoom UDMF plane light (`lightfloor`, `lightceiling`).
+    pub floor_light: SurfaceLight,
+    pub ceiling_light: SurfaceLight,
+}
+
+/// ZDoom UDMF surface light: added to the sector's light level, or used in
+/// its place when `absolute`. The default leaves the sector light as is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SurfaceLight {
+    pub level: i32,
+    pub absolute: bool,
+}
+
+impl SurfaceLight {
+    /// The light level a surface lit by `sector_light` is drawn with.
+    pub fn apply(self, sector_light: usize) -> usize {
+        let level = if self.absolute {
+            self.level
+        } else {
+            sector_light as i32 + self.level
+        };
+        level.clamp(0, 255) as usize
+    }
+}
+
+/// A sky taken from a wall by an MBF sky transfer line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectorSky {
+    /// Wall texture index of the sky
+    pub texture: usize,
+    /// Horizontal offset, added to the view angle as BAM
+    pub offset: FixedT,
+    /// Rows to shift the sky down by
+    pub rowoffset: FixedT,
+    /// Drawn as the texture looks on a wall (271) rather than mirrored like
+    /// the normal sky (272)
+    pub flipped: bool,
 }
 
 impl std::fmt::Debug for Sector {
@@ -166,6 +241,64 @@ impl Sector {
         }
     }
 
+    /// Light level the floor is lit by before its UDMF plane light: the BOOM
+    /// light transfer sector's, else this sector's.
+    pub fn floor_sector_lightlevel(&self) -> usize {
+        self.floorlightsec
+            .as_ref()
+            .map_or(self.lightlevel, |s| s.lightlevel)
+    }
+
+    /// As [`Self::floor_sector_lightlevel`] for the ceiling.
+    pub fn ceiling_sector_lightlevel(&self) -> usize {
+        self.ceilinglightsec
+            .as_ref()
+            .map_or(self.lightlevel, |s| s.lightlevel)
+    }
+
+    /// Light level the floor is drawn with.
+    pub fn floor_lightlevel(&self) -> usize {
+        self.floor_light.apply(self.floor_sector_lightlevel())
+    }
+
+    /// Light level the ceiling is drawn with.
+    pub fn ceiling_lightlevel(&self) -> usize {
+        self.ceiling_light.apply(self.ceiling_sector_lightlevel())
+    }
+
+    /// Where the floor flat sits: the BOOM/UDMF offsets and the UDMF rotation.
+    /// Two floors only draw as one plane when these match.
+    pub fn floor_placement(&self) -> (FixedT, FixedT, Angle<Bam>) {
+        (self.floor_xoffs, self.floor_yoffs, self.floor_angle)
+    }
+
+    /// As [`Self::floor_placement`] for the ceiling.
+    pub fn ceiling_placement(&self) -> (FixedT, FixedT, Angle<Bam>) {
+        (self.ceiling_xoffs, self.ceiling_yoffs, self.ceiling_angle)
+    }
+
+    /// Light level things in the sector are drawn with: the average of the
+    /// floor and ceiling light, as BOOM does. UDMF plane light is left out,
+    /// as ZDoom does.
+    pub fn sprite_lightlevel(&self) -> usize {
+        self.floor_sector_lightlevel()
+            .midpoint(self.ceiling_sector_lightlevel())
+    }
+
+    /// The sky drawn in place of this sector's sky flats, if a sky transfer
+    /// line with an upper texture targets it. Read live so scrolling the
+    /// line's sidedef scrolls the sky.
+    pub fn sky_transfer(&self) -> Option<SectorSky> {
+        let line = self.sky_line.as_ref()?;
+        let side = &line.front_sidedef;
+        Some(SectorSky {
+            texture: side.toptexture?,
+            offset: side.textureoffset,
+            rowoffset: side.rowoffset,
+            flipped: line.special == SKY_TRANSFER,
+        })
+    }
+
     /// Ceiling z at `(x, y)`: the slope plane when sloped, else `ceilingheight`.
     pub fn ceil_z(&self, x: FixedT, y: FixedT) -> FixedT {
         match self.ceil_plane {
@@ -190,6 +323,43 @@ pub struct SideDef {
 
     // Sector the SideDef is facing.
     pub sector: MapPtr<Sector>,
+
+    /// ZDoom UDMF per-part offset and scale of the upper, middle and lower
+    /// textures.
+    pub top: SidePart,
+    pub mid: SidePart,
+    pub bottom: SidePart,
+    /// ZDoom UDMF wall light (`light`, `lightabsolute`).
+    pub light: SurfaceLight,
+}
+
+impl SideDef {
+    /// Light level the walls of this side are drawn with.
+    pub fn lightlevel(&self) -> usize {
+        self.light.apply(self.sector.lightlevel)
+    }
+}
+
+/// ZDoom UDMF offset and scale of one wall texture, on top of the sidedef's
+/// `textureoffset`/`rowoffset`. The default is neutral.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SidePart {
+    pub xoffset: FixedT,
+    pub yoffset: FixedT,
+    /// Texels per map unit: above 1 tiles the texture tighter.
+    pub xscale: FixedT,
+    pub yscale: FixedT,
+}
+
+impl Default for SidePart {
+    fn default() -> Self {
+        Self {
+            xoffset: FixedT::ZERO,
+            yoffset: FixedT::ZERO,
+            xscale: FixedT::ONE,
+            yscale: FixedT::ONE,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -271,6 +441,20 @@ pub struct LineDef {
 
     // if == validcount, already checked
     pub valid_count: usize,
+
+    /// ZDoom UDMF `alpha` of the middle texture; 1.0 is opaque.
+    pub alpha: f32,
+    /// ZDoom UDMF `renderstyle = "add"`.
+    pub additive: bool,
+}
+
+impl LineDef {
+    /// The middle texture is drawn through the translucency map: BOOM
+    /// linedef 260, or a UDMF alpha or additive render style. The renderers
+    /// have one blend level, so any alpha below 1 draws the same.
+    pub fn is_translucent(&self) -> bool {
+        self.special == TRANSLUCENT_LINE || self.alpha < 1.0 || self.additive
+    }
 }
 
 impl std::fmt::Debug for LineDef {
@@ -368,6 +552,8 @@ pub struct Blockmap {
 
 #[cfg(test)]
 mod tests {
+    use super::Sector;
+    use crate::MapPtr;
     use glam::Vec2;
 
     fn point_on_side(v1: Vec2, v2: Vec2, v: Vec2) -> usize {
@@ -391,4 +577,25 @@ mod tests {
         let r = point_on_side(v1, v2, v);
         assert_eq!(r, 0);
     }
+
+    #[test]
+    fn light_transfers_split_floor_and_ceiling() {
+        let mut floor_light = Sector {
+            lightlevel: 96,
+            ..Sector::default()
+        };
+        let mut sector = Sector {
+            lightlevel: 160,
+            ..Sector::default()
+        };
+        assert_eq!(sector.floor_lightlevel(), 160);
+        assert_eq!(sector.ceiling_lightlevel(), 160);
+        assert_eq!(sector.sprite_lightlevel(), 160);
+        assert!(sector.sky_transfer().is_none());
+
+        sector.floorlightsec = Some(MapPtr::new(&mut floor_light));
+        assert_eq!(sector.floor_lightlevel(), 96);
+        assert_eq!(sector.ceiling_lightlevel(), 160);
+        assert_eq!(sector.sprite_lightlevel(), 128);
+    }
 }
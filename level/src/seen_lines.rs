@@ -0,0 +1,241 @@
+//! Automap line discovery, independent of the active renderer.
+//!
+//! OG Doom sets `ML_MAPPED` in `R_StoreWallRange`, so only the 2.5D renderer
+//! reveals lines as a side effect of drawing. This walks the BSP front to back
+//! from the viewer with a one-dimensional angular occlusion list (the
+//! `R_AddLine`/`R_CheckBBox` clipping without any drawing) so every renderer
+//! fills in the automap the same way.
+
+use glam::Vec2;
+use math::{ANG45, ANG180, FixedT, r_point_to_angle};
+
+use crate::flags::LineDefFlags;
+use crate::level_data::LevelData;
+use crate::map_defs::{Sector, Segment};
+use crate::{is_leaf, leaf_index};
+
+/// OG `clipangle` for the 90 degree field of view.
+const CLIP_ANGLE: u32 = ANG45;
+const BOXTOP: usize = 0;
+const BOXBOTTOM: usize = 1;
+const BOXLEFT: usize = 2;
+const BOXRIGHT: usize = 3;
+/// OG `checkcoord`: the two bbox corners spanning the widest angle, indexed by
+/// the viewer's position relative to the box.
+const CHECK_COORD: [[usize; 4]; 11] = [
+    [BOXRIGHT, BOXTOP, BOXLEFT, BOXBOTTOM],
+    [BOXRIGHT, BOXTOP, BOXLEFT, BOXTOP],
+    [BOXRIGHT, BOXBOTTOM, BOXLEFT, BOXTOP],
+    [0; 4],
+    [BOXLEFT, BOXTOP, BOXLEFT, BOXBOTTOM],
+    [0; 4],
+    [BOXRIGHT, BOXBOTTOM, BOXRIGHT, BOXTOP],
+    [0; 4],
+    [BOXLEFT, BOXTOP, BOXRIGHT, BOXBOTTOM],
+    [BOXLEFT, BOXBOTTOM, BOXRIGHT, BOXBOTTOM],
+    [BOXLEFT, BOXBOTTOM, BOXRIGHT, BOXTOP],
+];
+
+/// Viewer origin and facing for a seen-line pass.
+struct Viewer {
+    x: FixedT,
+    y: FixedT,
+    angle: u32,
+}
+
+/// Sorted, merged view-relative angular spans that nothing behind can be seen
+/// through. Units run left to right across the field of view, `0..=2 *
+/// CLIP_ANGLE`.
+struct SolidSpans(Vec<(u32, u32)>);
+
+impl SolidSpans {
+    fn covers(&self, first: u32, last: u32) -> bool {
+        self.0.iter().any(|&(f, l)| f <= first && l >= last)
+    }
+
+    fn insert(&mut self, first: u32, last: u32) {
+        let mut first = first;
+        let mut last = last;
+        self.0.retain(|&(f, l)| {
+            if l < first || f > last {
+                return true;
+            }
+            first = first.min(f);
+            last = last.max(l);
+            false
+        });
+        let pos = self.0.partition_point(|&(f, _)| f < first);
+        self.0.insert(pos, (first, last));
+    }
+}
+
+impl LevelData {
+    /// Mark every linedef visible from `(x, y)` facing `angle` (BAM) as
+    /// `Mapped` for the automap.
+    pub fn mark_seen_lines(&mut self, x: FixedT, y: FixedT, angle: u32) {
+        let viewer = Viewer {
+            x,
+            y,
+            angle,
+        };
+        let mut solid = SolidSpans(Vec::with_capacity(32));
+        self.seen_bsp_node(&viewer, self.bsp_3d.root_node(), &mut solid);
+    }
+
+    /// R_RenderBSPNode without the drawing.
+    fn seen_bsp_node(&self, viewer: &Viewer, node_id: u32, solid: &mut SolidSpans) {
+        if is_leaf(node_id) {
+            if let Some(leaf) = self.bsp_3d.get_leaf(leaf_index(node_id)) {
+                self.seen_subsector(viewer, leaf.subsector, solid);
+            }
+            return;
+        }
+        if node_id >= self.bsp_3d.first_plane_node() {
+            let subsector = self.bsp_3d.leaves[self.bsp_3d.subtree_leaf(node_id)].subsector;
+            self.seen_subsector(viewer, subsector, solid);
+            return;
+        }
+
+        let node = &self.bsp_3d.nodes()[node_id as usize];
+        let side = node.point_on_side_fixed(viewer.x, viewer.y);
+        let (front, back) = node.front_back_children_fixed(viewer.x, viewer.y);
+        self.seen_bsp_node(viewer, front, solid);
+
+        if bbox_visible(viewer, self.bsp_3d.node_bbox(node_id, side ^ 1), solid) {
+            self.seen_bsp_node(viewer, back, solid);
+        }
+    }
+
+    /// R_Subsector + R_AddLine without the drawing.
+    fn seen_subsector(&self, viewer: &Viewer, subsector: usize, solid: &mut SolidSpans) {
+        let subsect = &self.subsectors[subsector];
+        let front = subsect.sector.clone();
+        let start = subsect.start_seg as usize;
+        let end = start + subsect.seg_count as usize;
+        for seg in &self.segments[start..end] {
+            let Some((first, last)) = view_span(
+                viewer,
+                (seg.v1.x_fp, seg.v1.y_fp),
+                (seg.v2.x_fp, seg.v2.y_fp),
+            ) else {
+                continue;
+            };
+            if solid.covers(first, last) {
+                continue;
+            }
+            match seg_kind(seg, &front) {
+                SegKind::Solid => solid.insert(first, last),
+                SegKind::Portal => {}
+                SegKind::Empty => continue,
+            }
+            let mut linedef = seg.linedef.clone();
+            linedef.flags.insert(LineDefFlags::Mapped);
+        }
+    }
+}
+
+/// How a seg affects the occlusion list, following `R_AddLine`.
+enum SegKind {
+    /// One-sided walls and closed doors.
+    Solid,
+    /// Windows and height changes.
+    Portal,
+    /// Identical sectors on both sides; OG never stores these so they stay
+    /// unmapped.
+    Empty,
+}
+
+fn seg_kind(seg: &Segment, front: &Sector) -> SegKind {
+    let Some(back) = seg.backsector.as_ref() else {
+        return SegKind::Solid;
+    };
+    if back.ceilingheight <= front.floorheight || back.floorheight >= front.ceilingheight {
+        return SegKind::Solid;
+    }
+    if back.ceilingheight == front.ceilingheight
+        && back.floorheight == front.floorheight
+        && back.ceilingpic == front.ceilingpic
+        && back.floorpic == front.floorpic
+        && back.lightlevel == front.lightlevel
+        && seg.sidedef.midtexture.is_none()
+    {
+        return SegKind::Empty;
+    }
+    SegKind::Portal
+}
+
+/// Clip the angular span of `v1 -> v2` to the field of view. Returns the
+/// left-to-right span, or `None` if back-facing, out of view, or zero width.
+fn view_span(viewer: &Viewer, v1: (FixedT, FixedT), v2: (FixedT, FixedT)) -> Option<(u32, u32)> {
+    let mut angle1 = r_point_to_angle(v1.0 - viewer.x, v1.1 - viewer.y);
+    let mut angle2 = r_point_to_angle(v2.0 - viewer.x, v2.1 - viewer.y);
+
+    let span = angle1.wrapping_sub(angle2);
+    if span >= ANG180 {
+        return None;
+    }
+    angle1 = angle1.wrapping_sub(viewer.angle);
+    angle2 = angle2.wrapping_sub(viewer.angle);
+
+    let mut tspan = angle1.wrapping_add(CLIP_ANGLE);
+    if tspan > 2 * CLIP_ANGLE {
+        tspan -= 2 * CLIP_ANGLE;
+        if tspan >= span {
+            return None;
+        }
+        angle1 = CLIP_ANGLE;
+    }
+    tspan = CLIP_ANGLE.wrapping_sub(angle2);
+    if tspan > 2 * CLIP_ANGLE {
+        tspan -= 2 * CLIP_ANGLE;
+        if tspan >= span {
+            return None;
+        }
+        angle2 = CLIP_ANGLE.wrapping_neg();
+    }
+
+    let first = CLIP_ANGLE.wrapping_sub(angle1);
+    let last = CLIP_ANGLE.wrapping_sub(angle2);
+    (first < last).then_some((first, last))
+}
+
+/// R_CheckBBox against the angular occlusion list. `bbox` is `[left-top,
+/// right-bottom]`.
+fn bbox_visible(viewer: &Viewer, bbox: &[Vec2; 2], solid: &SolidSpans) -> bool {
+    let coords = [
+        FixedT::from_f32(bbox[0].y),
+        FixedT::from_f32(bbox[1].y),
+        FixedT::from_f32(bbox[0].x),
+        FixedT::from_f32(bbox[1].x),
+    ];
+
+    let boxx = if viewer.x <= coords[BOXLEFT] {
+        0
+    } else if viewer.x < coords[BOXRIGHT] {
+        1
+    } else {
+        2
+    };
+    let boxy = if viewer.y >= coords[BOXTOP] {
+        0
+    } else if viewer.y > coords[BOXBOTTOM] {
+        1
+    } else {
+        2
+    };
+    let boxpos = boxy * 4 + boxx;
+    if boxpos == 5 {
+        return true;
+    }
+
+    let check = CHECK_COORD[boxpos];
+    let v1 = (coords[check[0]], coords[check[1]]);
+    let v2 = (coords[check[2]], coords[check[3]]);
+    let angle1 = r_point_to_angle(v1.0 - viewer.x, v1.1 - viewer.y);
+    let angle2 = r_point_to_angle(v2.0 - viewer.x, v2.1 - viewer.y);
+    if angle1.wrapping_sub(angle2) >= ANG180 {
+        return true;
+    }
+
+    view_span(viewer, v1, v2).is_some_and(|(first, last)| !solid.covers(first, last))
+}
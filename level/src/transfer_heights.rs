@@ -0,0 +1,205 @@
+//! BOOM transfer heights (linedef 242): deep water and fake floors/ceilings.
+//!
+//! Every sector tagged by a 242 line is drawn with the floor and ceiling
+//! heights of the line's front (control) sector, while things still collide
+//! with its real heights. A viewer standing in such a sector sees one of three
+//! regions depending on the eye height against the control sector's planes:
+//! below the fake floor (underwater), between the planes, or above the fake
+//! ceiling. Each region can also swap in its own colourmap, named on the
+//! control line's front sidedef.
+//!
+//! Doom source names `R_FakeFlat` (r_bsp) and `R_SetupFrame` (r_main)
+
+use crate::map_defs::Sector;
+use math::FixedT;
+
+/// The parts of a sector the renderers see that a transfer can replace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FakeFlat {
+    pub floorheight: FixedT,
+    pub ceilingheight: FixedT,
+    pub floorpic: usize,
+    pub ceilingpic: usize,
+    pub lightlevel: usize,
+    /// BOOM flat scroll offsets, `[x, y]`
+    pub floor_offs: [FixedT; 2],
+    pub ceiling_offs: [FixedT; 2],
+}
+
+impl FakeFlat {
+    pub fn of(sector: &Sector) -> Self {
+        Self {
+            floorheight: sector.floorheight,
+            ceilingheight: sector.ceilingheight,
+            floorpic: sector.floorpic,
+            ceilingpic: sector.ceilingpic,
+            lightlevel: sector.lightlevel,
+            floor_offs: [sector.floor_xoffs, sector.floor_yoffs],
+            ceiling_offs: [sector.ceiling_xoffs, sector.ceiling_yoffs],
+        }
+    }
+
+    pub fn write(&self, sector: &mut Sector) {
+        sector.floorheight = self.floorheight;
+        sector.ceilingheight = self.ceilingheight;
+        sector.floorpic = self.floorpic;
+        sector.ceilingpic = self.ceilingpic;
+        sector.lightlevel = self.lightlevel;
+        [sector.floor_xoffs, sector.floor_yoffs] = self.floor_offs;
+        [sector.ceiling_xoffs, sector.ceiling_yoffs] = self.ceiling_offs;
+    }
+
+    /// How `sector` is drawn given its `control` sector. `view_control` is the
+    /// control sector of the sector the viewer stands in, if any, which picks
+    /// the underwater or above-ceiling region.
+    ///
+    /// Doom function name `R_FakeFlat`
+    pub fn transfer(
+        sector: &Sector,
+        control: &Sector,
+        view_control: Option<&Sector>,
+        viewz: FixedT,
+        sky_num: Option<usize>,
+    ) -> Self {
+        let mut fake = Self::of(sector);
+        fake.floorheight = control.floorheight;
+        fake.ceilingheight = control.ceilingheight;
+        let Some(view) = view_control else {
+            return fake;
+        };
+        let control_floor_offs = [control.floor_xoffs, control.floor_yoffs];
+        let control_ceiling_offs = [control.ceiling_xoffs, control.ceiling_yoffs];
+
+        if viewz <= view.floorheight {
+            // Underwater: the water surface becomes the ceiling
+            fake.floorheight = sector.floorheight;
+            fake.ceilingheight = control.floorheight - FixedT::ONE;
+            fake.floorpic = control.floorpic;
+            fake.floor_offs = control_floor_offs;
+            if sky_num == Some(control.ceilingpic) {
+                // Don't draw the sky under water, close the region instead
+                fake.floorheight = fake.ceilingheight + FixedT::ONE;
+                fake.ceilingpic = fake.floorpic;
+                fake.ceiling_offs = fake.floor_offs;
+            } else {
+                fake.ceilingpic = control.ceilingpic;
+                fake.ceiling_offs = control_ceiling_offs;
+            }
+            fake.lightlevel = control.lightlevel;
+        } else if viewz >= view.ceilingheight && sector.ceilingheight > control.ceilingheight {
+            // Above the fake ceiling: it becomes the floor
+            fake.ceilingheight = control.ceilingheight;
+            fake.floorheight = control.ceilingheight + FixedT::ONE;
+            fake.floorpic = control.ceilingpic;
+            fake.ceilingpic = control.ceilingpic;
+            fake.floor_offs = control_ceiling_offs;
+            fake.ceiling_offs = control_ceiling_offs;
+            if sky_num != Some(control.floorpic) {
+                fake.ceilingheight = sector.ceilingheight;
+                fake.floorpic = control.floorpic;
+                fake.floor_offs = control_floor_offs;
+            }
+            fake.lightlevel = control.lightlevel;
+        }
+        fake
+    }
+}
+
+/// Colourmap set for a viewer at `viewz` whose sector is controlled by
+/// `view_control`. 0 (`COLORMAP`) outside any transfer.
+///
+/// Doom function name `R_SetupFrame`
+pub(crate) fn view_colourmap(view_control: Option<&Sector>, viewz: FixedT) -> usize {
+    let Some(control) = view_control else {
+        return 0;
+    };
+    if viewz < control.floorheight {
+        control.bottommap
+    } else if viewz > control.ceilingheight {
+        control.topmap
+    } else {
+        control.midmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FakeFlat, view_colourmap};
+    use crate::map_defs::Sector;
+    use math::FixedT;
+
+    const SKY: usize = 99;
+
+    fn sector(floor: i32, ceiling: i32, floorpic: usize, ceilingpic: usize) -> Sector {
+        Sector::new(
+            0,
+            FixedT::from(floor),
+            FixedT::from(ceiling),
+            floorpic,
+            ceilingpic,
+            160,
+            0,
+            0,
+        )
+    }
+
+    #[test]
+    fn between_planes_uses_control_heights_only() {
+        let real = sector(0, 256, 1, 2);
+        let water = sector(64, 192, 3, 4);
+        let fake = FakeFlat::transfer(&real, &water, None, FixedT::from(128), Some(SKY));
+        assert_eq!(fake.floorheight, FixedT::from(64));
+        assert_eq!(fake.ceilingheight, FixedT::from(192));
+        assert_eq!((fake.floorpic, fake.ceilingpic), (1, 2));
+    }
+
+    #[test]
+    fn underwater_hangs_the_surface_over_the_real_floor() {
+        let real = sector(0, 256, 1, 2);
+        let water = sector(64, 256, 3, 4);
+        let fake = FakeFlat::transfer(&real, &water, Some(&water), FixedT::from(40), Some(SKY));
+        assert_eq!(fake.floorheight, FixedT::ZERO);
+        assert_eq!(fake.ceilingheight, FixedT::from(63));
+        assert_eq!((fake.floorpic, fake.ceilingpic), (3, 4));
+
+        // A sky over the water closes the region instead of showing the sky
+        let sky_water = sector(64, 256, 3, SKY);
+        let fake = FakeFlat::transfer(
+            &real,
+            &sky_water,
+            Some(&sky_water),
+            FixedT::from(40),
+            Some(SKY),
+        );
+        assert_eq!(fake.floorheight, FixedT::from(64));
+        assert_eq!(fake.ceilingpic, 3);
+    }
+
+    #[test]
+    fn above_the_fake_ceiling_keeps_the_real_ceiling() {
+        let real = sector(0, 256, 1, 2);
+        let control = sector(0, 128, 3, 4);
+        let fake = FakeFlat::transfer(
+            &real,
+            &control,
+            Some(&control),
+            FixedT::from(200),
+            Some(SKY),
+        );
+        assert_eq!(fake.floorheight, FixedT::from(129));
+        assert_eq!(fake.ceilingheight, FixedT::from(256));
+        assert_eq!((fake.floorpic, fake.ceilingpic), (3, 4));
+    }
+
+    #[test]
+    fn colourmap_follows_view_height() {
+        let mut control = sector(64, 192, 3, 4);
+        control.bottommap = 1;
+        control.midmap = 2;
+        control.topmap = 3;
+        assert_eq!(view_colourmap(None, FixedT::from(40)), 0);
+        assert_eq!(view_colourmap(Some(&control), FixedT::from(40)), 1);
+        assert_eq!(view_colourmap(Some(&control), FixedT::from(100)), 2);
+        assert_eq!(view_colourmap(Some(&control), FixedT::from(200)), 3);
+    }
+}
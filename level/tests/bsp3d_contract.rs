@@ -247,7 +247,7 @@ fn assert_surface_cache(map: &LevelData, name: &str) {
         );
         assert_eq!(
             flags.contains(PolyFlags::TRANSLUCENT),
-            ld.special == 260 && !flags.contains(PolyFlags::SKY_FILLER),
+            ld.is_translucent() && !flags.contains(PolyFlags::SKY_FILLER),
             "{name} wall {gi} TRANSLUCENT"
         );
         if flags.contains(PolyFlags::SKY_FILLER) {
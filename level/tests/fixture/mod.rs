@@ -0,0 +1,79 @@
+//! Shared builders for binary-format map fixtures: lump records and a temp
+//! PWAD holding one map.
+
+#![allow(dead_code)] // shared fixture; not all helpers are used by every test file
+
+use level::LevelData;
+use wad::write::write_pwad;
+use wad::{Lump, WadData};
+
+pub fn name8(name: &str) -> [u8; 8] {
+    let mut n = [0u8; 8];
+    n[..name.len()].copy_from_slice(name.as_bytes());
+    n
+}
+
+pub fn vertexes(verts: &[(i16, i16)]) -> Vec<u8> {
+    verts
+        .iter()
+        .flat_map(|(x, y)| [x.to_le_bytes(), y.to_le_bytes()].concat())
+        .collect()
+}
+
+/// One `STARTAN3` middle-textured sidedef per entry, facing `sectors[i]`.
+pub fn sidedefs(sectors: &[u16]) -> Vec<u8> {
+    sectors
+        .iter()
+        .flat_map(|&sector| {
+            let mut d = vec![0u8; 4];
+            d.extend_from_slice(&name8("-"));
+            d.extend_from_slice(&name8("-"));
+            d.extend_from_slice(&name8("STARTAN3"));
+            d.extend_from_slice(&sector.to_le_bytes());
+            d
+        })
+        .collect()
+}
+
+/// `FLOOR4_8`/`CEIL3_5` sectors at light 160, as `(floor, ceiling, special,
+/// tag)`.
+pub fn sectors(sectors: &[(i16, i16, i16, i16)]) -> Vec<u8> {
+    sectors
+        .iter()
+        .flat_map(|&(floor, ceil, special, tag)| {
+            let mut d = Vec::new();
+            d.extend_from_slice(&floor.to_le_bytes());
+            d.extend_from_slice(&ceil.to_le_bytes());
+            d.extend_from_slice(&name8("FLOOR4_8"));
+            d.extend_from_slice(&name8("CEIL3_5"));
+            for v in [160, special, tag] {
+                d.extend_from_slice(&v.to_le_bytes());
+            }
+            d
+        })
+        .collect()
+}
+
+/// Write `lumps` after a `MAP01` marker to a temp PWAD, hand it to `f` and
+/// remove it again.
+pub fn with_map_wad<R>(tag: &str, lumps: Vec<(&str, Vec<u8>)>, f: impl FnOnce(&WadData) -> R) -> R {
+    let lumps: Vec<Lump> = std::iter::once(("MAP01", Vec::new()))
+        .chain(lumps)
+        .map(|(name, data)| Lump {
+            name: name.to_owned(),
+            data,
+        })
+        .collect();
+    let path = std::env::temp_dir().join(format!("r4d-{tag}-{}.wad", std::process::id()));
+    std::fs::write(&path, write_pwad(&lumps).expect("serialize pwad")).expect("write temp wad");
+    let wad = WadData::new(&path);
+    let result = f(&wad);
+    std::fs::remove_file(&path).ok();
+    result
+}
+
+pub fn load_map(wad: &WadData) -> LevelData {
+    let mut level = LevelData::default();
+    level.load("MAP01", |_| None, wad, None, None);
+    level
+}
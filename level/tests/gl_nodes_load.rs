@@ -0,0 +1,156 @@
+//! Engine GL-node load: a classic map whose `NODES` lump holds `XGLN` nodes
+//! takes its subsectors, segs and leaf polygons from them instead of building
+//! a BSP, and the 3D geometry is built over those leaves.
+
+mod fixture;
+
+use level::LevelData;
+use math::FixedT;
+
+/// A 256x128 room, linedefs running clockwise so the fronts face in.
+const VERTEXES: [(i16, i16); 4] = [(0, 0), (0, 128), (256, 128), (256, 0)];
+
+fn linedefs() -> Vec<u8> {
+    [(0u16, 1u16), (1, 2), (2, 3), (3, 0)]
+        .iter()
+        .enumerate()
+        .flat_map(|(side, &(v1, v2))| {
+            let mut d = Vec::new();
+            for v in [v1, v2, 1, 0, 0, side as u16, u16::MAX] {
+                d.extend_from_slice(&v.to_le_bytes());
+            }
+            d
+        })
+        .collect()
+}
+
+fn things() -> Vec<u8> {
+    [64i16, 64, 0, 1, 7]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect()
+}
+
+fn xgln_nodes() -> Vec<u8> {
+    [b"XGLN".as_slice(), &test_utils::gl_room_nodes(false)].concat()
+}
+
+/// Load the room with `nodes` in the `gl_lump`, `NODES` or `SSECTORS`.
+fn load(tag: &str, gl_lump: &str, nodes: &[u8]) -> LevelData {
+    let mut lumps = vec![
+        ("THINGS", things()),
+        ("LINEDEFS", linedefs()),
+        ("SIDEDEFS", fixture::sidedefs(&[0; 4])),
+        ("VERTEXES", fixture::vertexes(&VERTEXES)),
+        ("SEGS", Vec::new()),
+        ("SSECTORS", Vec::new()),
+        ("NODES", Vec::new()),
+        ("SECTORS", fixture::sectors(&[(0, 128, 0, 0)])),
+        ("REJECT", Vec::new()),
+        ("BLOCKMAP", Vec::new()),
+    ];
+    let (_, data) = lumps
+        .iter_mut()
+        .find(|(name, _)| *name == gl_lump)
+        .expect("a node lump");
+    *data = nodes.to_vec();
+    fixture::with_map_wad(&format!("gl-{tag}"), lumps, fixture::load_map)
+}
+
+#[test]
+fn gl_subsectors_become_the_leaves() {
+    let level = load("xgln", "NODES", &xgln_nodes());
+
+    // The node builder would not split a single convex room; the GL nodes do.
+    assert_eq!(level.subsectors.len(), 2);
+    assert_eq!(level.vertexes.len(), 6, "WAD vertices then GL vertices");
+    assert_eq!(level.vertexes[4].x, 128.0);
+
+    // Minisegs are dropped, the six wall pieces remain.
+    assert_eq!(level.segments.len(), 6);
+    let east = &level.subsectors[0];
+    assert_eq!(east.seg_count, 3);
+    let top = &level.segments[east.start_seg as usize];
+    assert_eq!(top.linedef.num, 1);
+    assert_eq!(top.offset.to_f32(), 128.0, "east half of the north wall");
+
+    // The GL node's partition routes points to their half.
+    let bsp = &level.bsp_3d;
+    assert_eq!(bsp.point_in_leaf(FixedT::from(200), FixedT::from(64)), 0);
+    assert_eq!(bsp.point_in_leaf(FixedT::from(60), FixedT::from(64)), 1);
+
+    // Each leaf's floor is its half of the room.
+    for ss in 0..2 {
+        let floor = bsp.leaf_floor_polys(ss).next().expect("leaf has a floor");
+        let verts: Vec<_> = bsp
+            .poly_vert_indices(floor)
+            .iter()
+            .map(|&vi| bsp.vertex_get(vi))
+            .collect();
+        let twice_area: f32 = (0..verts.len())
+            .map(|i| {
+                let (a, b) = (verts[i], verts[(i + 1) % verts.len()]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum();
+        assert_eq!(twice_area / 2.0, 128.0 * 128.0, "leaf {ss} floor faces up");
+    }
+}
+
+#[test]
+fn zdbsp_gl_nodes_load_from_ssectors() {
+    let level = load("ssectors", "SSECTORS", &xgln_nodes());
+    assert_eq!(level.subsectors.len(), 2);
+    assert_eq!(level.segments.len(), 6);
+}
+
+#[test]
+fn udmf_gl_nodes_load_from_znodes() {
+    let textmap = "namespace = \"zdoom\";
+thing { x = 64.0; y = 64.0; type = 1; single = true; skill3 = true; }
+vertex { x = 0.0; y = 0.0; }
+vertex { x = 0.0; y = 128.0; }
+vertex { x = 256.0; y = 128.0; }
+vertex { x = 256.0; y = 0.0; }
+linedef { v1 = 0; v2 = 1; sidefront = 0; blocking = true; }
+linedef { v1 = 1; v2 = 2; sidefront = 1; blocking = true; }
+linedef { v1 = 2; v2 = 3; sidefront = 2; blocking = true; }
+linedef { v1 = 3; v2 = 0; sidefront = 3; blocking = true; }
+sidedef { sector = 0; texturemiddle = \"STARTAN3\"; }
+sidedef { sector = 0; texturemiddle = \"STARTAN3\"; }
+sidedef { sector = 0; texturemiddle = \"STARTAN3\"; }
+sidedef { sector = 0; texturemiddle = \"STARTAN3\"; }
+sector { heightfloor = 0; heightceiling = 128; texturefloor = \"FLOOR4_8\"; textureceiling = \"CEIL3_5\"; }
+";
+    let lumps = vec![
+        ("TEXTMAP", textmap.as_bytes().to_vec()),
+        ("ZNODES", xgln_nodes()),
+        ("ENDMAP", Vec::new()),
+    ];
+    let level = fixture::with_map_wad("gl-znodes", lumps, fixture::load_map);
+    assert_eq!(level.subsectors.len(), 2);
+    assert_eq!(level.segments.len(), 6);
+}
+
+#[test]
+fn unknown_gl_nodes_fall_back_to_building() {
+    let nodes = [b"XGL3".as_slice(), &test_utils::gl_room_nodes(true)].concat();
+    let level = load("xgl3", "SSECTORS", &nodes);
+    assert_eq!(level.subsectors.len(), 1);
+}
+
+#[test]
+fn mismatched_gl_nodes_fall_back_to_building() {
+    // A seg on a linedef the map does not have.
+    let mut nodes = xgln_nodes();
+    let first_seg_line = 4 + 4 + 4 + 16 + 4 + 8 + 4 + 8;
+    nodes[first_seg_line..first_seg_line + 2].copy_from_slice(&9u16.to_le_bytes());
+    let level = load("bad", "NODES", &nodes);
+
+    assert_eq!(
+        level.subsectors.len(),
+        1,
+        "rbsp keeps the convex room whole"
+    );
+    assert_eq!(level.segments.len(), 4);
+}
@@ -0,0 +1,114 @@
+//! Engine Hexen-format load: a map with a `BEHAVIOR` lump loads through
+//! `LevelData::load`, with its things, sector types and parameterised line
+//! specials translated to the classic Doom ones.
+
+mod fixture;
+
+use level::LineDefFlags;
+use level::special_encode::{self, Category};
+
+/// Hexen line activation: use, and the repeatable bit.
+const SPAC_USE: u16 = 1 << 10;
+const ML_REPEAT_SPECIAL: u16 = 0x0200;
+
+fn linedef(v1: u16, v2: u16, flags: u16, special: u8, args: [u8; 5], sides: [u16; 2]) -> Vec<u8> {
+    let mut d = Vec::new();
+    d.extend_from_slice(&v1.to_le_bytes());
+    d.extend_from_slice(&v2.to_le_bytes());
+    d.extend_from_slice(&flags.to_le_bytes());
+    d.push(special);
+    d.extend_from_slice(&args);
+    d.extend_from_slice(&sides[0].to_le_bytes());
+    d.extend_from_slice(&sides[1].to_le_bytes());
+    d
+}
+
+/// A room (sector 0) with a door (sector 1) to the east: the shared line is a
+/// manual Door_Raise, the west wall a walk-over Floor_LowerToLowest.
+fn linedefs() -> Vec<u8> {
+    let none = u16::MAX;
+    [
+        linedef(0, 5, 1, 21, [5, 8, 0, 0, 0], [0, none]),
+        linedef(5, 4, 1, 0, [0; 5], [1, none]),
+        linedef(1, 0, 1, 0, [0; 5], [2, none]),
+        linedef(
+            4,
+            1,
+            4 | SPAC_USE | ML_REPEAT_SPECIAL,
+            12,
+            [0, 16, 150, 0, 0],
+            [3, 4],
+        ),
+        linedef(4, 3, 1, 0, [0; 5], [5, none]),
+        linedef(3, 2, 1, 0, [0; 5], [6, none]),
+        linedef(2, 1, 1, 0, [0; 5], [7, none]),
+    ]
+    .concat()
+}
+
+/// A player 1 start in every mode, and a coop/deathmatch-only shotgun.
+fn things() -> Vec<u8> {
+    let things: [(i16, i16); 2] = [(1, 0x0707), (2001, 0x0607)];
+    things
+        .iter()
+        .flat_map(|&(kind, flags)| {
+            let mut d = Vec::new();
+            for v in [0i16, 64, 64, 0, 90, kind, flags] {
+                d.extend_from_slice(&v.to_le_bytes());
+            }
+            d.extend_from_slice(&[0; 6]);
+            d
+        })
+        .collect()
+}
+
+#[test]
+fn hexen_map_loads_as_doom() {
+    // The classic lump order with empty node lumps, then `BEHAVIOR`
+    let lumps = vec![
+        ("THINGS", things()),
+        ("LINEDEFS", linedefs()),
+        // Room sidedefs, then the door's
+        ("SIDEDEFS", fixture::sidedefs(&[0, 0, 0, 0, 1, 1, 1, 1])),
+        (
+            "VERTEXES",
+            fixture::vertexes(&[(0, 0), (128, 0), (256, 0), (256, 128), (128, 128), (0, 128)]),
+        ),
+        ("SEGS", Vec::new()),
+        ("SSECTORS", Vec::new()),
+        ("NODES", Vec::new()),
+        // Room: secret + light flicker. Door: closed.
+        (
+            "SECTORS",
+            fixture::sectors(&[(0, 128, 0x0400 | 65, 5), (0, 0, 0, 5)]),
+        ),
+        ("REJECT", Vec::new()),
+        ("BLOCKMAP", Vec::new()),
+        ("BEHAVIOR", Vec::new()),
+    ];
+    let level = fixture::with_map_wad("hexen", lumps, |wad| {
+        assert!(wad.is_hexen_map("MAP01"));
+        fixture::load_map(wad)
+    });
+
+    assert_eq!(level.things().len(), 2);
+    assert_eq!((level.things()[0].kind, level.things()[0].flags), (1, 7));
+    assert_eq!(level.things()[1].flags, 7 | 16, "multiplayer only");
+
+    assert_eq!(level.sectors.len(), 2);
+    assert_eq!(level.sectors[0].special, 0x80 | 1, "secret + flicker");
+
+    assert_eq!(level.linedefs.len(), 7);
+    // Floor_LowerToLowest, cross once: W1 38 with the tag from args[0].
+    let floor = &level.linedefs[0];
+    assert_eq!((floor.default_special, floor.tag), (38, 5));
+    let spec = special_encode::decode(floor.special).expect("normalised mover");
+    assert_eq!(spec.category, Category::Floor);
+
+    // Door_Raise, tag 0, used: the manual door on the back sector.
+    let door = &level.linedefs[3];
+    assert_eq!((door.default_special, door.tag), (1, 0));
+    assert!(special_encode::decode(door.special).is_some_and(|s| s.manual));
+    // The Hexen activation bits do not leak into the Doom flags.
+    assert_eq!(door.flags, LineDefFlags::TwoSided);
+}
@@ -0,0 +1,96 @@
+//! Automap line discovery: `LevelData::mark_seen_lines` reveals what the
+//! viewer can see and nothing hidden behind a closed door.
+
+use level::{LevelData, LineDefFlags};
+use math::FixedT;
+use wad::write::write_pwad;
+use wad::{Lump, WadData};
+
+const TWO_ROOMS: &str = include_str!("../../data/test_files/udmf/two_rooms.textmap");
+const WEST_WALL: usize = 1;
+const SHARED_LINE: usize = 3;
+const EAST_WALL: usize = 6;
+
+fn load(tag: &str, textmap: &str) -> LevelData {
+    let lumps = [
+        Lump {
+            name: "MAP01".to_owned(),
+            data: Vec::new(),
+        },
+        Lump {
+            name: "TEXTMAP".to_owned(),
+            data: textmap.as_bytes().to_vec(),
+        },
+        Lump {
+            name: "ENDMAP".to_owned(),
+            data: Vec::new(),
+        },
+    ];
+    let path = std::env::temp_dir().join(format!("r4d-seen-{tag}-{}.wad", std::process::id()));
+    std::fs::write(&path, write_pwad(&lumps).expect("serialize pwad")).expect("write temp wad");
+    let wad = WadData::new(&path);
+    let mut level = LevelData::default();
+    level.load("MAP01", |_| None, &wad, None, None);
+    std::fs::remove_file(&path).ok();
+    level
+}
+
+/// East room floor/ceiling swapped in for the fixture's `heightfloor = 32`.
+fn with_east_room(floor: i32, ceiling: i32) -> String {
+    let east = TWO_ROOMS
+        .rfind("heightfloor = 32;")
+        .expect("east room sector");
+    let mut textmap = TWO_ROOMS.to_owned();
+    textmap.replace_range(
+        east..,
+        &TWO_ROOMS[east..]
+            .replace("heightfloor = 32;", &format!("heightfloor = {floor};"))
+            .replace(
+                "heightceiling = 128;",
+                &format!("heightceiling = {ceiling};"),
+            ),
+    );
+    textmap
+}
+
+fn look_east(level: &mut LevelData) {
+    level.mark_seen_lines(FixedT::from(128), FixedT::from(128), 0);
+}
+
+fn mapped(level: &LevelData, line: usize) -> bool {
+    level.linedefs[line].flags.contains(LineDefFlags::Mapped)
+}
+
+#[test]
+fn step_reveals_far_room() {
+    let mut level = load("step", TWO_ROOMS);
+    look_east(&mut level);
+
+    assert!(mapped(&level, SHARED_LINE), "step line in view");
+    assert!(mapped(&level, EAST_WALL), "far wall seen through the step");
+    assert!(!mapped(&level, WEST_WALL), "wall behind the viewer");
+}
+
+#[test]
+fn closed_door_hides_far_room() {
+    let mut level = load("door", &with_east_room(0, 0));
+    look_east(&mut level);
+
+    assert!(mapped(&level, SHARED_LINE), "closed door in view");
+    assert!(!mapped(&level, EAST_WALL), "far wall occluded by the door");
+}
+
+#[test]
+fn trigger_line_stays_unmapped() {
+    let mut level = load("trigger", &with_east_room(0, 128));
+    look_east(&mut level);
+
+    assert!(
+        !mapped(&level, SHARED_LINE),
+        "identical sectors are never drawn"
+    );
+    assert!(
+        mapped(&level, EAST_WALL),
+        "far wall seen through the trigger"
+    );
+}
@@ -1,15 +1,17 @@
 //! Engine UDMF load: a `TEXTMAP` map loads through `LevelData::load`, its
 //! sector slope plane reaches the engine `Sector`, and the 3D-BSP floor
-//! geometry sits on that plane.
+//! geometry sits on that plane. The ZDoom render extensions reach the engine
+//! records and the 3D-BSP UV, scroll and light.
 
 use std::io::Write as _;
 
-use level::LevelData;
-use math::FixedT;
+use level::{LevelData, PolyFlags};
+use math::{Angle, Bam, FixedT};
 use wad::WadData;
 
 const SLOPED_FLOOR: &str = include_str!("../../data/test_files/udmf/sloped_floor.textmap");
 const FLAT_ROOM: &str = include_str!("../../data/test_files/udmf/flat_room.textmap");
+const ZDOOM_EXTENSIONS: &str = include_str!("../../data/test_files/udmf/zdoom_extensions.textmap");
 
 /// Write a minimal PWAD with one UDMF map: marker, TEXTMAP, ENDMAP.
 fn write_udmf_wad(path: &std::path::Path, map: &str, textmap: &[u8]) {
@@ -86,3 +88,48 @@ fn flat_udmf_map_has_no_slope() {
     assert!(level.sectors[0].floor_plane.is_none());
     assert!(level.sectors[0].ceil_plane.is_none());
 }
+
+#[test]
+fn zdoom_extensions_reach_engine_and_bsp3d() {
+    let level = load("MAP01", "zdoom-ext", ZDOOM_EXTENSIONS);
+
+    // Sidedef light: relative to the sector's 160, or absolute.
+    let west = &level.sidedefs[1];
+    assert_eq!(west.mid.xoffset, FixedT::from(16));
+    assert_eq!(west.mid.xscale, FixedT::from(2));
+    assert_eq!(west.lightlevel(), 192);
+    assert_eq!(level.sidedefs[2].lightlevel(), 200);
+
+    let east = &level.sectors[1];
+    assert_eq!(
+        (east.floor_xoffs, east.floor_yoffs),
+        (FixedT::from(8), FixedT::from(4))
+    );
+    assert_eq!(east.floor_angle, Angle::<Bam>::from_bam(math::ANG45));
+    assert_eq!(east.floor_lightlevel(), 112);
+    assert_eq!(east.ceiling_lightlevel(), 96);
+    assert_eq!(east.sprite_lightlevel(), 128, "plane light leaves sprites");
+
+    assert!(level.linedefs[3].is_translucent(), "alpha 0.5, additive");
+    assert!(!level.linedefs[0].is_translucent());
+    assert!(level.things()[1].translucent);
+    assert!(!level.things()[0].translucent);
+
+    // The west wall's UV carries the per-part offset over the sidedef's, then
+    // the scale: u = (0 + 4 + 16) * 2 at its start, v = (0 + 2 - 8) * 0.5 at
+    // the ceiling.
+    let bsp = &level.bsp_3d;
+    let wall = bsp.linedef_wall_polys[1][0];
+    let (s, e) = bsp.poly_vertex_range[wall];
+    let uvs = &bsp.poly_vertex_uv[s..e];
+    assert!(uvs.contains(&[40.0, -3.0]), "scaled, offset UV: {uvs:?}");
+    assert_eq!(bsp.poly_lightlevel(wall), 192);
+    for &gi in &bsp.linedef_wall_polys[3] {
+        assert!(bsp.poly_flags[gi].contains(PolyFlags::TRANSLUCENT));
+    }
+
+    // The east floor is panned through its scroll and lit by its plane light.
+    let floor = bsp.sector_floor_polys[1][0];
+    assert_eq!(bsp.poly_scroll[floor], [4.0, 8.0]);
+    assert_eq!(bsp.poly_lightlevel(floor), 112);
+}
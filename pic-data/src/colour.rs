@@ -6,6 +6,9 @@
 //! per-pixel light tables: folding those (768/2048 blocks) would force multi-MB
 //! rebuilds on every tint change. `PalLit` is one 14×256 table.
 
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
 use wad::types::WadColour;
 pub use wad::types::WadPalette;
 
@@ -30,6 +33,11 @@ pub trait PixelFmt: sealed::Sealed + Copy + Default + 'static {
     /// Halve the RGB channels (fuzz darken), preserving alpha/unused bits.
     /// `u8` (index) returns itself — the index path darkens via colourmap 6.
     fn darken(self) -> Self;
+
+    /// 15-bit key (5 bits per channel) for the reverse palette lookup. Only
+    /// compared against keys of the same format, so byte order is irrelevant.
+    /// `u8` (index) returns itself.
+    fn rgb_key(self) -> u16;
 }
 
 mod sealed {
@@ -51,6 +59,11 @@ impl PixelFmt for u8 {
     fn darken(self) -> Self {
         self
     }
+
+    #[inline]
+    fn rgb_key(self) -> u16 {
+        self as u16
+    }
 }
 
 impl PixelFmt for u16 {
@@ -73,6 +86,12 @@ impl PixelFmt for u16 {
     fn darken(self) -> Self {
         (self >> 1) & 0x7BEF
     }
+
+    /// Drop the low green bit.
+    #[inline]
+    fn rgb_key(self) -> u16 {
+        ((self >> 1) & 0x7FE0) | (self & 0x1F)
+    }
 }
 
 impl PixelFmt for u32 {
@@ -96,8 +115,16 @@ impl PixelFmt for u32 {
     fn darken(self) -> Self {
         (self & 0xFF00_0000) | ((self >> 1) & 0x007F_7F7F)
     }
+
+    #[inline]
+    fn rgb_key(self) -> u16 {
+        (((self >> 9) & 0x7C00) | ((self >> 6) & 0x03E0) | ((self >> 3) & 0x1F)) as u16
+    }
 }
 
+/// Entries in a reverse palette table, one per [`PixelFmt::rgb_key`].
+pub const RGB_KEYS: usize = 1 << 15;
+
 /// Palette ∘ gamma ∘ byte-order folded into one `PALETTE_LEN * 256` table.
 /// Built from gamma-baked palettes; rebuild only on palette change, tint select
 /// is a free block index.
@@ -105,6 +132,9 @@ impl PixelFmt for u32 {
 pub struct PalLit<T> {
     blocks: Box<[T]>,
     order: ByteOrder,
+    /// Per tint, final pixel key back to its palette index. Built on first use
+    /// (only translucent draws need it), dropped on rebuild.
+    reverse: [OnceLock<Box<[u8]>>; PALETTE_LEN],
 }
 
 impl<T: PixelFmt> PalLit<T> {
@@ -112,6 +142,7 @@ impl<T: PixelFmt> PalLit<T> {
         let mut s = Self {
             blocks: vec![T::default(); PALETTE_LEN * 256].into_boxed_slice(),
             order,
+            reverse: Default::default(),
         };
         s.rebuild(palettes);
         s
@@ -125,6 +156,9 @@ impl<T: PixelFmt> PalLit<T> {
                 self.blocks[base + i] = T::from_argb(colour, self.order);
             }
         }
+        for reverse in &mut self.reverse {
+            reverse.take();
+        }
     }
 
     /// Block for tint `tint`, indexed by lit palette index.
@@ -140,6 +174,49 @@ impl<T: PixelFmt> PalLit<T> {
     pub const fn order(&self) -> ByteOrder {
         self.order
     }
+
+    /// Reverse table for tint `tint`: [`PixelFmt::rgb_key`] of a final pixel to
+    /// the palette index it came from. Keys no palette entry produces (fuzz,
+    /// overlays) map to a neighbouring entry's index.
+    pub fn reverse(&self, tint: usize) -> &[u8] {
+        self.reverse[tint].get_or_init(|| build_reverse(self.block(tint)))
+    }
+}
+
+/// Key every palette entry of `block`, then flood the unused keys from their
+/// nearest keyed neighbour across the 32×32×32 grid. Lower indices win exact
+/// duplicates.
+fn build_reverse<T: PixelFmt>(block: &[T; 256]) -> Box<[u8]> {
+    let mut table = vec![0u8; RGB_KEYS].into_boxed_slice();
+    let mut seen = vec![false; RGB_KEYS];
+    let mut queue = VecDeque::with_capacity(RGB_KEYS);
+    for (i, px) in block.iter().enumerate() {
+        let key = px.rgb_key() as usize & (RGB_KEYS - 1);
+        if !seen[key] {
+            seen[key] = true;
+            table[key] = i as u8;
+            queue.push_back(key);
+        }
+    }
+    while let Some(key) = queue.pop_front() {
+        let (r, g, b) = (key >> 10, (key >> 5) & 0x1F, key & 0x1F);
+        let neighbours = [
+            (r > 0).then(|| key - 0x400),
+            (r < 31).then(|| key + 0x400),
+            (g > 0).then(|| key - 0x20),
+            (g < 31).then(|| key + 0x20),
+            (b > 0).then(|| key - 1),
+            (b < 31).then(|| key + 1),
+        ];
+        for next in neighbours.into_iter().flatten() {
+            if !seen[next] {
+                seen[next] = true;
+                table[next] = table[key];
+                queue.push_back(next);
+            }
+        }
+    }
+    table
 }
 
 /// A [`PalLit`] paired with the palette generation it was built from.
@@ -272,4 +349,18 @@ mod tests {
         // Untouched entries still match.
         assert_eq!(lit.block(0)[0], pals[0].0[0]);
     }
+
+    #[test]
+    fn reverse_maps_pixels_back_to_palette_entries() {
+        let pals = fake_palettes();
+        let lit = PalLit::<u16>::new(&pals, ByteOrder::Argb);
+        for tint in [0, 5] {
+            let block = lit.block(tint);
+            let reverse = lit.reverse(tint);
+            for &px in block {
+                let index = reverse[px.rgb_key() as usize] as usize;
+                assert_eq!(block[index].rgb_key(), px.rgb_key());
+            }
+        }
+    }
 }
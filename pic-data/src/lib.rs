@@ -5,6 +5,7 @@ pub mod colour;
 pub mod parallel;
 pub mod pic;
 pub mod sky;
+pub mod tranmap;
 pub mod voxel;
 
 pub use colour::{ByteOrder, PALETTE_LEN, PalLit, PalLitCache, PixelFmt, WadPalette};
@@ -21,6 +21,7 @@ use wad::types::{COLUMN_END, WadColour, WadPalette, WadPatch, WadTexture};
 
 use self::sprites::{SpriteDef, init_spritedefs};
 use crate::colour::{ByteOrder, PALETTE_LEN, PalLit, PixelFmt};
+use crate::tranmap::{TRANMAP_LEN, init_tranmap};
 use wad::types::GameMode;
 
 const MAXLIGHTZ: usize = 128;
@@ -216,10 +217,21 @@ pub struct PicData {
     // Usually 34 blocks of 256, each being an index into the palette. Heap-
     // allocated (like OG's zone-cached `colormaps`); render borrows into it.
     colourmap: Vec<Colourmap>,
+    /// Every colourmap set: `COLORMAP` first, then the BOOM `C_START`/`C_END`
+    /// lumps in lookup order. `colourmap` is a copy of the active one.
+    colourmap_sets: Vec<Vec<Colourmap>>,
+    /// Index into `colourmap_sets` of the set in `colourmap`
+    active_colourmap_set: usize,
+    /// Wall light level and scale to colourmap index
+    light_scale: [usize; LIGHTMAP_LEN],
+    /// Flat light level and distance to colourmap index
+    zlight_scale: [[usize; 128]; 16],
     /// Precomputed wall light colourmaps (16 light levels × 48 scales)
     lightscale_colourmap: Vec<Colourmap>,
     /// Precomputed flat light colourmaps (16 light levels × 128 distances)
     zlight_colourmap: Vec<Colourmap>,
+    /// BOOM translucency map, `[(background << 8) | foreground]`
+    tranmap: Box<[u8]>,
     use_fixed_colourmap: usize,
     walls: Vec<WallPic>,
     /// Used in animations
@@ -260,6 +272,10 @@ impl Default for PicData {
             crt_gamma: CrtGamma::default(),
             crt_tone_lut: [0; 256],
             colourmap: Vec::new(),
+            colourmap_sets: Vec::new(),
+            active_colourmap_set: 0,
+            light_scale: [0; LIGHTMAP_LEN],
+            zlight_scale: [[0; 128]; 16],
             use_fixed_colourmap: Default::default(),
             walls: Default::default(),
             wall_translation: Default::default(),
@@ -276,6 +292,7 @@ impl Default for PicData {
             last_cshift: (0, 0),
             lightscale_colourmap: vec![[0usize; 256]; LIGHTMAP_LEN],
             zlight_colourmap: vec![[0usize; 256]; 16 * 128],
+            tranmap: vec![0; TRANMAP_LEN].into_boxed_slice(),
         }
     }
 }
@@ -288,25 +305,13 @@ impl PicData {
     pub fn init_with_crt_gamma(wad: &WadData, sprite_names: &[&str], crt_gamma: CrtGamma) -> Self {
         print!("Init image data  [");
 
-        let colourmap = Self::init_colourmap(wad);
+        let colourmap_sets = Self::init_colourmap_sets(wad);
+        let colourmap = colourmap_sets[0].clone();
         let palettes = Self::init_palette(wad);
+        let tranmap = init_tranmap(wad, &palettes[0]);
         let light_scale = Self::init_light_scales();
         let zlight_scale = Self::init_zlight_scales();
 
-        // Precompute lightscale_colourmap: flatten light_scale indirection
-        let mut lightscale_colourmap = vec![[0usize; 256]; LIGHTMAP_LEN];
-        for (i, &light_scale_idx) in light_scale.iter().enumerate() {
-            lightscale_colourmap[i].clone_from_slice(&colourmap[light_scale_idx]);
-        }
-
-        // Precompute zlight_colourmap: flatten zlight_scale indirection
-        let mut zlight_colourmap = vec![[0usize; 256]; 16 * 128];
-        for i in 0..16 {
-            for j in 0..128 {
-                zlight_colourmap[i * 128 + j].clone_from_slice(&colourmap[zlight_scale[i][j]]);
-            }
-        }
-
         let (walls, sky_pic) = Self::init_wall_pics(wad);
         let wall_translation = (0..walls.len()).collect();
 
@@ -377,9 +382,14 @@ impl PicData {
             palettes,
             crt_gamma,
             crt_tone_lut,
-            lightscale_colourmap,
-            zlight_colourmap,
+            lightscale_colourmap: vec![[0usize; 256]; LIGHTMAP_LEN],
+            zlight_colourmap: vec![[0usize; 256]; 16 * 128],
+            tranmap,
             colourmap,
+            colourmap_sets,
+            active_colourmap_set: 0,
+            light_scale,
+            zlight_scale,
             use_fixed_colourmap: 0,
             sprite_patches,
             sprite_defs,
@@ -389,10 +399,41 @@ impl PicData {
             fade_mode: PaletteFade::Vanilla,
             last_cshift: (0, 0),
         };
+        s.build_light_colourmaps();
         s.apply_crt_gamma();
         s
     }
 
+    /// Flatten the light scale indirection for the active colourmap set.
+    fn build_light_colourmaps(&mut self) {
+        for (i, &light_scale_idx) in self.light_scale.iter().enumerate() {
+            self.lightscale_colourmap[i].clone_from_slice(&self.colourmap[light_scale_idx]);
+        }
+        for i in 0..16 {
+            for j in 0..128 {
+                self.zlight_colourmap[i * 128 + j]
+                    .clone_from_slice(&self.colourmap[self.zlight_scale[i][j]]);
+            }
+        }
+    }
+
+    /// Switch every light level over to colourmap set `set` (0 is `COLORMAP`,
+    /// BOOM `C_START` lumps follow). Used for the view inside a linedef 242
+    /// region. Unknown sets fall back to `COLORMAP`.
+    pub fn set_colourmap_set(&mut self, set: usize) {
+        let set = if set < self.colourmap_sets.len() {
+            set
+        } else {
+            0
+        };
+        if set == self.active_colourmap_set {
+            return;
+        }
+        self.active_colourmap_set = set;
+        self.colourmap.clone_from(&self.colourmap_sets[set]);
+        self.build_light_colourmaps();
+    }
+
     fn init_palette(wad: &WadData) -> [WadPalette; PALETTE_LEN] {
         print!(".");
         let mut tmp = [WadPalette::default(); PALETTE_LEN];
@@ -419,6 +460,23 @@ impl PicData {
             .collect()
     }
 
+    /// `COLORMAP` followed by each BOOM `C_START` colourmap. A custom lump
+    /// shorter than `COLORMAP` keeps the remaining levels of `COLORMAP`.
+    fn init_colourmap_sets(wad: &WadData) -> Vec<Vec<Colourmap>> {
+        let base = Self::init_colourmap(wad);
+        let mut sets = vec![base.clone()];
+        for lump in wad.colourmaps_iter() {
+            let mut set = base.clone();
+            for (map, data) in set.iter_mut().zip(lump.data.chunks_exact(256)) {
+                for (c, &d) in map.iter_mut().zip(data) {
+                    *c = d as usize;
+                }
+            }
+            sets.push(set);
+        }
+        sets
+    }
+
     /// Precompute the wall light scale LUT: maps (light level, scale) to
     /// colourmap index.
     fn init_light_scales() -> [usize; LIGHTMAP_LEN] {
@@ -702,6 +760,13 @@ impl PicData {
         &self.palettes
     }
 
+    /// BOOM translucency map, indexed `(background << 8) | foreground` by
+    /// lit palette index.
+    #[inline(always)]
+    pub fn tranmap(&self) -> &[u8] {
+        &self.tranmap
+    }
+
     /// Set palette based on player damage/bonus/power state.
     /// Arguments are extracted from Player to avoid depending on gameplay
     /// types.
@@ -1195,3 +1260,40 @@ mod fade_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod colourmap_set_tests {
+    use super::*;
+
+    /// Two sets of 34 maps: `COLORMAP` maps every colour to the light level,
+    /// the custom set to 200 + level.
+    fn pics() -> PicData {
+        let base: Vec<Colourmap> = (0..34).map(|l| [l; 256]).collect();
+        let custom: Vec<Colourmap> = (0..34).map(|l| [200 + l; 256]).collect();
+        let mut p = PicData {
+            colourmap: base.clone(),
+            colourmap_sets: vec![base, custom],
+            light_scale: PicData::init_light_scales(),
+            zlight_scale: PicData::init_zlight_scales(),
+            ..PicData::default()
+        };
+        p.build_light_colourmaps();
+        p
+    }
+
+    #[test]
+    fn switching_sets_rebuilds_light_tables() {
+        let mut p = pics();
+        let lit = p.base_colourmap(15, 47.0)[0];
+        assert!(lit < 34);
+
+        p.set_colourmap_set(1);
+        assert_eq!(p.base_colourmap(15, 47.0)[0], lit + 200);
+        assert!(p.flat_light_colourmap(15, 0)[0] >= 200);
+        assert_eq!(p.colourmap(0)[0], 200);
+
+        // Out of range falls back to COLORMAP
+        p.set_colourmap_set(7);
+        assert_eq!(p.base_colourmap(15, 47.0)[0], lit);
+    }
+}
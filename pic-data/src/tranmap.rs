@@ -0,0 +1,104 @@
+//! BOOM/MBF translucency map: a 256×256 palette index table.
+//!
+//! `tranmap[(background << 8) | foreground]` is the palette index closest to
+//! the foreground drawn over the background. It comes from a `TRANMAP` lump
+//! when the WAD has one, otherwise it is composed from `PLAYPAL` the way MBF
+//! does, so the indexed result matches PrBoom+.
+//!
+//! Doom source name `R_InitTranMap` (r_data, MBF)
+
+use crate::parallel::parallel_map;
+use wad::WadData;
+use wad::types::WadPalette;
+
+/// Size of a translucency map: one 256-entry row per background index.
+pub const TRANMAP_LEN: usize = 256 * 256;
+/// Foreground weight of the generated map, MBF's `tran_filter_pct` default.
+pub const TRAN_FILTER_PCT: i64 = 66;
+/// Fixed-point bits of the generated map's colour weights
+const TSC: i64 = 12;
+
+/// The WAD's `TRANMAP` lump, or one composed from `palette` if it has none
+/// (or it is the wrong size).
+pub fn init_tranmap(wad: &WadData, palette: &WadPalette) -> Box<[u8]> {
+    match wad.get_lump("TRANMAP") {
+        Some(lump) if lump.data.len() == TRANMAP_LEN => lump.data.clone().into_boxed_slice(),
+        _ => build_tranmap(palette, TRAN_FILTER_PCT),
+    }
+}
+
+/// Compose a translucency map from `palette`, weighting the foreground by
+/// `filter_pct` percent. Ties go to the highest palette index, as in MBF.
+pub fn build_tranmap(palette: &WadPalette, filter_pct: i64) -> Box<[u8]> {
+    let w1 = (filter_pct << TSC) / 100;
+    let w2 = (1 << TSC) - w1;
+    let rgb: Vec<[i64; 3]> = palette
+        .0
+        .iter()
+        .map(|&c| {
+            [
+                ((c >> 16) & 0xFF) as i64,
+                ((c >> 8) & 0xFF) as i64,
+                (c & 0xFF) as i64,
+            ]
+        })
+        .collect();
+    let tot: Vec<i64> = rgb
+        .iter()
+        .map(|c| (c[0] * c[0] + c[1] * c[1] + c[2] * c[2]) << (TSC - 1))
+        .collect();
+
+    let backgrounds: Vec<usize> = (0..256).collect();
+    let rows = parallel_map(&backgrounds, |&bg| {
+        let [r1, g1, b1] = rgb[bg].map(|c| c * w2);
+        let mut row = [0u8; 256];
+        for (fg, out) in row.iter_mut().enumerate() {
+            let r = rgb[fg][0] * w1 + r1;
+            let g = rgb[fg][1] * w1 + g1;
+            let b = rgb[fg][2] * w1 + b1;
+            let mut best = i64::MAX;
+            for colour in (0..256).rev() {
+                let c = rgb[colour];
+                let err = tot[colour] - c[0] * r - c[1] * g - c[2] * b;
+                if err < best {
+                    best = err;
+                    *out = colour as u8;
+                }
+            }
+        }
+        row
+    });
+    rows.concat().into_boxed_slice()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TRAN_FILTER_PCT, TRANMAP_LEN, build_tranmap};
+    use wad::types::WadPalette;
+
+    /// Greyscale ramp: index `i` is `(i, i, i)`.
+    fn grey_palette() -> WadPalette {
+        let mut pal = WadPalette::default();
+        for (i, c) in pal.0.iter_mut().enumerate() {
+            let v = i as u32;
+            *c = 0xFF00_0000 | (v << 16) | (v << 8) | v;
+        }
+        pal
+    }
+
+    #[test]
+    fn composed_map_blends_towards_the_foreground() {
+        let map = build_tranmap(&grey_palette(), TRAN_FILTER_PCT);
+        assert_eq!(map.len(), TRANMAP_LEN);
+        // Same colour over itself is itself
+        for i in [0usize, 17, 128, 255] {
+            assert_eq!(map[(i << 8) | i] as usize, i);
+        }
+        // White at 66% over black
+        let white_over_black = map[255] as i32;
+        assert!((white_over_black - 168).abs() <= 1, "{white_over_black}");
+        // Black at 66% over white
+        let black_over_white = map[255 << 8] as i32;
+        assert!((black_over_white - 87).abs() <= 1, "{black_over_white}");
+    }
+}
@@ -12,6 +12,8 @@ use crate::{BufferSize, DrawBuffer};
 pub struct PixelTarget<'a, T: PixelFmt> {
     surface: &'a mut [T],
     block: &'a [T; 256],
+    pal_lit: &'a PalLit<T>,
+    tint: usize,
     size: BufferSize,
     pitch: usize,
     order: ByteOrder,
@@ -34,6 +36,8 @@ impl<'a, T: PixelFmt> PixelTarget<'a, T> {
         Self {
             block: pal_lit.block(tint),
             order: pal_lit.order(),
+            pal_lit,
+            tint,
             surface,
             size,
             pitch,
@@ -65,6 +69,18 @@ impl<'a, T: PixelFmt> PixelTarget<'a, T> {
         self.write(pos, px);
     }
 
+    /// BOOM translucency RMW: blend lit palette index `lit` over the pixel at
+    /// `pos` through `tranmap` (`[(background << 8) | foreground]`). The
+    /// background index is recovered from the stored pixel.
+    ///
+    /// Doom function name `R_DrawTLColumn`
+    #[inline(always)]
+    pub fn store_translucent(&mut self, pos: usize, lit: u16, tranmap: &[u8]) {
+        let dst = unsafe { *self.surface.get_unchecked(pos) };
+        let bg = self.pal_lit.reverse(self.tint)[dst.rgb_key() as usize] as usize;
+        self.store(pos, tranmap[(bg << 8) | (lit as usize & 0xFF)] as u16);
+    }
+
     /// Fuzz RMW: RGB-halve `src_pos`'s pixel into `dst_pos`.
     #[inline(always)]
     pub fn fuzz(&mut self, dst_pos: usize, src_pos: usize) {
@@ -169,4 +185,16 @@ mod tests {
         t.fuzz(1, 0);
         assert_eq!(surface[1], 0x7BEF);
     }
+
+    #[test]
+    fn translucent_store_blends_through_tranmap() {
+        let lit = pal::<u32>();
+        let mut tranmap = vec![0u8; 256 * 256];
+        tranmap[(8 << 8) | 5] = 77;
+        let mut surface = [0u32; 1];
+        let mut t = PixelTarget::new(&mut surface, BufferSize::new(1, 1), 1, &lit, 0);
+        t.store(0, 8);
+        t.store_translucent(0, 5, &tranmap);
+        assert_eq!(surface[0], lit.block(0)[77]);
+    }
 }
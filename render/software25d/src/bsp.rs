@@ -354,6 +354,11 @@ impl Software25D {
             if back_sector.ceilingpic == front_sector.ceilingpic
                 && back_sector.floorpic == front_sector.floorpic
                 && back_sector.lightlevel == front_sector.lightlevel
+                && back_sector.floor_lightlevel() == front_sector.floor_lightlevel()
+                && back_sector.ceiling_lightlevel() == front_sector.ceiling_lightlevel()
+                && back_sector.floor_placement() == front_sector.floor_placement()
+                && back_sector.ceiling_placement() == front_sector.ceiling_placement()
+                && back_sector.sky_transfer() == front_sector.sky_transfer()
                 && seg.sidedef.midtexture.is_none()
             {
                 return;
@@ -1,7 +1,7 @@
 #[cfg(feature = "hprof")]
 use coarse_prof::profile;
 use game_config::tic_cmd::LookDirs;
-use level::{LineDefFlags, Segment};
+use level::{LineDefFlags, Segment, SidePart};
 use log::warn;
 use math::{ANG90, ANG180, ANGLETOFINESHIFT, Angle, Bam, FixedT, fine_tan};
 use pic_data::{FlatPic, PicData};
@@ -350,7 +350,8 @@ impl SegRender {
             // Checks to see if panes need updating?
             if self.worldlow != self.worldbottom
                 || backsector.floorpic != frontsector.floorpic
-                || backsector.lightlevel != frontsector.lightlevel
+                || backsector.floor_lightlevel() != frontsector.floor_lightlevel()
+                || backsector.floor_placement() != frontsector.floor_placement()
             {
                 self.markfloor = true;
             } else {
@@ -360,7 +361,9 @@ impl SegRender {
             //
             if self.worldhigh != self.worldtop
                 || backsector.ceilingpic != frontsector.ceilingpic
-                || backsector.lightlevel != frontsector.lightlevel
+                || backsector.ceiling_lightlevel() != frontsector.ceiling_lightlevel()
+                || backsector.ceiling_placement() != frontsector.ceiling_placement()
+                || backsector.sky_transfer() != frontsector.sky_transfer()
             {
                 self.markceiling = true;
             } else {
@@ -383,7 +386,8 @@ impl SegRender {
                     self.rw_toptexturemid = self.worldtop;
                 } else if let Some(top_tex) = sidedef.toptexture {
                     let texture_column = pic_data.wall_pic_column(top_tex, 0);
-                    let vtop = backsector.ceilingheight + FixedT::from(texture_column.len() as i32);
+                    let vtop = backsector.ceilingheight
+                        + FixedT::from(texture_column.len() as i32) / sidedef.top.yscale;
                     // texture bottom
                     self.rw_toptexturemid = vtop - view.viewz;
                 }
@@ -398,8 +402,8 @@ impl SegRender {
                 }
             }
 
-            self.rw_toptexturemid += sidedef.rowoffset;
-            self.rw_bottomtexturemid += sidedef.rowoffset;
+            self.rw_toptexturemid += sidedef.rowoffset + sidedef.top.yoffset;
+            self.rw_bottomtexturemid += sidedef.rowoffset + sidedef.bottom.yoffset;
 
             // OG: only allocate masked-texture columns when a midtexture exists.
             if sidedef.midtexture.is_some() {
@@ -417,14 +421,15 @@ impl SegRender {
             if linedef.flags.contains(LineDefFlags::UnpegBottom) {
                 if let Some(mid_tex) = sidedef.midtexture {
                     let texture_column = pic_data.wall_pic_column(mid_tex, 0);
-                    let vtop = frontsector.floorheight + FixedT::from(texture_column.len() as i32);
+                    let vtop = frontsector.floorheight
+                        + FixedT::from(texture_column.len() as i32) / sidedef.mid.yscale;
                     self.rw_midtexturemid = vtop - view.viewz;
                 }
             } else {
                 // top of texture at top
                 self.rw_midtexturemid = self.worldtop;
             }
-            self.rw_midtexturemid += sidedef.rowoffset;
+            self.rw_midtexturemid += sidedef.rowoffset + sidedef.mid.yoffset;
 
             ds_p.silhouette = SIL_BOTH;
             // negonearray at [0..width], screenheightarray at [width..2*width]
@@ -447,7 +452,7 @@ impl SegRender {
             //  }
             self.rw_offset += sidedef.textureoffset + seg.offset;
             self.rw_centerangle = Angle::<Bam>::from_bam(ANG90) + view.angle - self.rw_normalangle;
-            self.wall_lights = ((sidedef.sector.lightlevel >> 4) + view.extralight).min(15);
+            self.wall_lights = ((sidedef.lightlevel() >> 4) + view.extralight).min(15);
             // OG Doom fake contrast: horizontal segs (E-W) darken, vertical (N-S) lighten
             let seg_bam = seg.angle.to_bam();
             if seg_bam == 0 || seg_bam == ANG180 {
@@ -571,14 +576,30 @@ impl SegRender {
         let mut bottom: FixedT;
         let mut mid: FixedT;
         let mut angle;
-        let mut texture_column = 0;
+        let mut texture_pos = FixedT::ZERO;
         let sidedef = seg.sidedef.clone();
 
-        let flats_total_light = ((seg.frontsector.lightlevel >> 4) + view.extralight).min(15);
+        let floor_light = ((seg.frontsector.floor_lightlevel() >> 4) + view.extralight).min(15);
+        let ceil_light = ((seg.frontsector.ceiling_lightlevel() >> 4) + view.extralight).min(15);
+        let sky_transfer = seg.frontsector.sky_transfer();
         let ceil_height = (seg.frontsector.ceilingheight - view.viewz).doom_abs();
         let ceil_tex = pic_data.get_flat(seg.frontsector.ceilingpic);
         let floor_height = (seg.frontsector.floorheight - view.viewz).doom_abs();
         let floor_tex = pic_data.get_flat(seg.frontsector.floorpic);
+        let floor_angle = seg.frontsector.floor_angle;
+        let ceil_angle = seg.frontsector.ceiling_angle;
+        let (floor_x, floor_y) = flat_origin(
+            view,
+            floor_angle,
+            seg.frontsector.floor_xoffs,
+            seg.frontsector.floor_yoffs,
+        );
+        let (ceil_x, ceil_y) = flat_origin(
+            view,
+            ceil_angle,
+            seg.frontsector.ceiling_xoffs,
+            seg.frontsector.ceiling_yoffs,
+        );
 
         // allow so that f64 feature can still work
         #[allow(clippy::unnecessary_cast)]
@@ -607,15 +628,28 @@ impl SegRender {
                 if top <= bottom {
                     if seg.frontsector.ceilingpic == pic_data.sky_num() {
                         let screen_x_angle = self.screen_x[self.rw_startx.to_i32() as usize];
-                        let sky_bam = (view.angle + screen_x_angle).inner().0;
+                        let mut sky_bam = (view.angle + screen_x_angle).inner().0;
+                        let mut sky_pic = pic_data.sky_pic();
+                        let mut sky_mid = self.sky_mid;
+                        // MBF sky transfer: the line's offsets shift the sky,
+                        // and 271 draws it unmirrored as it looks on the wall
+                        if let Some(sky) = sky_transfer {
+                            sky_pic = sky.texture;
+                            sky_bam = sky_bam.wrapping_add(sky.offset.to_fixed_raw() as u32);
+                            if sky.flipped {
+                                sky_bam = !sky_bam;
+                            }
+                            sky_mid += sky.rowoffset;
+                        }
                         // BAM >> 22 gives ~1024 values per full rotation (4x wrap for 256-wide sky)
                         let sky_col_idx = (sky_bam >> 22) as usize;
-                        let sky_column = pic_data.wall_pic_column(pic_data.sky_pic(), sky_col_idx);
+                        let sky_column = pic_data.wall_pic_column(sky_pic, sky_col_idx);
 
                         self.dc_iscale = FixedT(58327); // 0.89 in 16.16
                         self.draw_wall_column(
                             sky_column,
-                            self.sky_mid,
+                            sky_mid,
+                            FixedT::ONE,
                             top.to_i32(),
                             bottom.to_i32(),
                             true,
@@ -625,12 +659,13 @@ impl SegRender {
                     } else {
                         let y0 = top.to_i32().max(0) as usize;
                         let y1 = bottom.to_i32().max(0) as usize;
+                        let (cos, sin) = flat_direction(x_angle, ceil_angle, cos, sin);
                         self.draw_flat_column(
                             ceil_tex,
-                            view.x,
-                            view.y,
+                            ceil_x,
+                            ceil_y,
                             ceil_height,
-                            flats_total_light,
+                            ceil_light,
                             cos,
                             sin,
                             distscale,
@@ -661,12 +696,13 @@ impl SegRender {
                     rdata.portal_clip.floorclip[clip_index] = top;
                     let y0 = top.to_i32().max(0) as usize;
                     let y1 = bottom.to_i32().max(0) as usize;
+                    let (cos, sin) = flat_direction(x_angle, floor_angle, cos, sin);
                     self.draw_flat_column(
                         floor_tex,
-                        view.x,
-                        view.y,
+                        floor_x,
+                        floor_y,
                         floor_height,
-                        flats_total_light,
+                        floor_light,
                         cos,
                         sin,
                         distscale,
@@ -682,9 +718,7 @@ impl SegRender {
                 angle = self.rw_centerangle + self.screen_x[self.rw_startx.to_i32() as usize];
                 let fine_idx = (angle.inner().0 >> ANGLETOFINESHIFT) as usize;
                 let tan_val = fine_tan(fine_idx);
-                texture_column = (self.rw_offset - self.rw_distance * tan_val)
-                    .doom_abs()
-                    .to_i32() as usize;
+                texture_pos = self.rw_offset - self.rw_distance * tan_val;
 
                 self.dc_iscale = if self.rw_scale.doom_abs() < FixedT(1) {
                     FixedT::MAX
@@ -696,10 +730,12 @@ impl SegRender {
             if self.midtexture {
                 if yl <= yh {
                     if let Some(mid_tex) = sidedef.midtexture {
-                        let texture_column = pic_data.wall_pic_column(mid_tex, texture_column);
+                        let texture_column = pic_data
+                            .wall_pic_column(mid_tex, part_column(texture_pos, &sidedef.mid));
                         self.draw_wall_column(
                             texture_column,
                             self.rw_midtexturemid,
+                            sidedef.mid.yscale,
                             yl.to_i32(),
                             yh.to_i32(),
                             false,
@@ -722,10 +758,12 @@ impl SegRender {
                     }
                     if mid >= yl {
                         if let Some(top_tex) = sidedef.toptexture {
-                            let texture_column = pic_data.wall_pic_column(top_tex, texture_column);
+                            let texture_column = pic_data
+                                .wall_pic_column(top_tex, part_column(texture_pos, &sidedef.top));
                             self.draw_wall_column(
                                 texture_column,
                                 self.rw_toptexturemid,
+                                sidedef.top.yscale,
                                 yl.to_i32(),
                                 mid.to_i32(),
                                 false,
@@ -752,10 +790,14 @@ impl SegRender {
                     }
                     if mid <= yh {
                         if let Some(bot_tex) = sidedef.bottomtexture {
-                            let texture_column = pic_data.wall_pic_column(bot_tex, texture_column);
+                            let texture_column = pic_data.wall_pic_column(
+                                bot_tex,
+                                part_column(texture_pos, &sidedef.bottom),
+                            );
                             self.draw_wall_column(
                                 texture_column,
                                 self.rw_bottomtexturemid,
+                                sidedef.bottom.yscale,
                                 mid.to_i32(),
                                 yh.to_i32(),
                                 false,
@@ -774,6 +816,7 @@ impl SegRender {
                 if self.maskedtexture {
                     let i = (self.maskedtexturecol + self.rw_startx).to_i32() as usize;
                     if self.openings.len() > i {
+                        let texture_column = part_column(texture_pos, &sidedef.mid);
                         self.openings[i] = FixedT::from(texture_column as i32);
                     }
                 }
@@ -787,12 +830,14 @@ impl SegRender {
     }
 
     /// Rasterize one textured wall column. In sky mode, texel indices are
-    /// halved and dc_iscale is caller-supplied.
+    /// halved and dc_iscale is caller-supplied. `yscale` is the UDMF texels
+    /// per map unit of the wall part.
     #[inline]
     fn draw_wall_column<P: PixelFmt>(
         &self,
         texture_column: &[u16],
         dc_texturemid: FixedT,
+        yscale: FixedT,
         y_start: i32,
         mut y_end: i32,
         sky: bool,
@@ -808,7 +853,8 @@ impl SegRender {
             return;
         }
 
-        let mut frac = dc_texturemid + (FixedT::from(y_start) - self.centery) * self.dc_iscale;
+        let iscale = self.dc_iscale * yscale;
+        let mut frac = dc_texturemid * yscale + (FixedT::from(y_start) - self.centery) * iscale;
         let mut pos = pixels.get_buf_index(self.rw_startx.to_i32() as usize, y_start as usize);
         let pitch = pixels.pitch();
 
@@ -830,7 +876,7 @@ impl SegRender {
                 let lit = unsafe { *colourmap.get_unchecked(tc as usize) } as u16;
                 pixels.store(pos, lit);
             }
-            frac += self.dc_iscale;
+            frac += iscale;
             pos += pitch;
         }
     }
@@ -1035,3 +1081,44 @@ impl SegRender {
         }
     }
 }
+
+/// Texture column of a wall part at `pos` along the seg: the part's UDMF
+/// offset and scale over the seg's own.
+#[inline]
+fn part_column(pos: FixedT, part: &SidePart) -> usize {
+    ((pos + part.xoffset) * part.xscale).doom_abs().to_i32() as usize
+}
+
+/// View position in a flat's texture space: turned back by the UDMF flat
+/// rotation, then shifted by the BOOM/UDMF flat offsets (x shifts the column,
+/// y the row).
+fn flat_origin(
+    view: &RenderView,
+    angle: Angle<Bam>,
+    xoffs: FixedT,
+    yoffs: FixedT,
+) -> (FixedT, FixedT) {
+    if angle.to_bam() == 0 {
+        return (view.x + xoffs, view.y - yoffs);
+    }
+    let (sin, cos) = (angle.sin_fixedt(), angle.cos_fixedt());
+    let x = view.x * cos + view.y * sin;
+    let y = view.y * cos - view.x * sin;
+    (x + xoffs, y - yoffs)
+}
+
+/// Column view direction in a flat's texture space; `cos`/`sin` are the
+/// unturned direction of `x_angle`.
+#[inline]
+fn flat_direction(
+    x_angle: Angle<Bam>,
+    angle: Angle<Bam>,
+    cos: FixedT,
+    sin: FixedT,
+) -> (FixedT, FixedT) {
+    if angle.to_bam() == 0 {
+        return (cos, sin);
+    }
+    let turned = x_angle - angle;
+    (turned.cos_fixedt(), turned.sin_fixedt())
+}
@@ -5,7 +5,7 @@ use std::ptr;
 
 use gameplay::{MapObjFlag, MapObject, SectorExt};
 use level::{LineDefFlags, Sector};
-use log::{error, warn};
+use log::warn;
 use math::{ANG45, FRACBITS, FRACUNIT, FixedT, r_point_to_angle};
 
 use pic_data::PicData;
@@ -106,7 +106,7 @@ impl Software25D {
             self.checked_idx += 1;
         }
 
-        let light_level = ((sector.lightlevel >> 4) + view.extralight).min(15);
+        let light_level = ((sector.sprite_lightlevel() >> 4) + view.extralight).min(15);
         <Sector as SectorExt>::run_func_on_thinglist(sector, |thing| {
             self.project_sprite(view, thing, light_level, screen_width, pic_data)
         });
@@ -171,7 +171,7 @@ impl Software25D {
         let sprnum = thing.state.sprite;
         let sprite_def = pic_data.sprite_def(sprnum as u32 as usize);
         if sprite_def.frames.is_empty() {
-            error!("No frames?, {:?}, {sprite_def:?}", thing.state);
+            // Invisible things (BOOM `TNT1`) have no sprite lumps
             return true;
         }
 
@@ -291,6 +291,8 @@ impl Software25D {
         let dc_texmid = vis.texture_mid;
         let mut frac = vis.start_frac;
         let is_shadow = vis.mobj_flags & MapObjFlag::Shadow.bits() != 0;
+        let tranmap =
+            (vis.mobj_flags & MapObjFlag::Translucent.bits() != 0).then(|| pic_data.tranmap());
         let colourmap = if is_shadow {
             pic_data.colourmap(33) // unused for fuzz, but needed for signature
         } else {
@@ -341,6 +343,7 @@ impl Software25D {
                         dc_texmid,
                         top,
                         bottom,
+                        tranmap,
                         rend,
                     );
                 }
@@ -627,7 +630,11 @@ impl Software25D {
             }
             let texnum = unsafe { seg.sidedef.midtexture.unwrap_unchecked() };
 
-            let wall_lights = ((seg.sidedef.sector.lightlevel >> 4) + view.extralight).min(15);
+            let wall_lights = ((seg.sidedef.lightlevel() >> 4) + view.extralight).min(15);
+            // BOOM translucent linedef, or UDMF alpha / additive
+            let tranmap = seg.linedef.is_translucent().then(|| pic_data.tranmap());
+            // UDMF texels per map unit of the middle texture
+            let yscale = seg.sidedef.mid.yscale;
 
             let rw_scalestep = ds.scalestep;
             let mut spryscale = ds.scale1 + (x1 - ds.x1) * rw_scalestep;
@@ -641,7 +648,8 @@ impl Software25D {
                 };
 
                 let texture_column = pic_data.wall_pic_column(texnum, 0);
-                dc_texturemid += FixedT::from((texture_column.len() - 1) as i32) - view.viewz;
+                dc_texturemid +=
+                    FixedT::from((texture_column.len() - 1) as i32) / yscale - view.viewz;
             } else {
                 dc_texturemid = if frontsector.ceilingheight < backsector.ceilingheight {
                     frontsector.ceilingheight
@@ -650,7 +658,7 @@ impl Software25D {
                 };
                 dc_texturemid -= view.viewz;
             }
-            dc_texturemid += seg.sidedef.rowoffset;
+            dc_texturemid += seg.sidedef.rowoffset + seg.sidedef.mid.yoffset;
 
             for x in x1.to_i32() as usize..=x2.to_i32() as usize {
                 if ds.maskedtexturecol + FixedT::from(x as i32) < 0 {
@@ -690,8 +698,9 @@ impl Software25D {
 
                     // calculate unclipped screen coordinates for post
                     let sprtopscreen = seg_renderer.centery - dc_texturemid * spryscale;
+                    let texscale = spryscale / yscale;
                     let bottomscreen =
-                        sprtopscreen + spryscale * FixedT::from(texture_column.len() as i32);
+                        sprtopscreen + texscale * FixedT::from(texture_column.len() as i32);
                     let mut top =
                         FixedT::from(inner_to_i32((sprtopscreen.0 + FRACUNIT - 1) >> FRACBITS));
                     let mut bottom = FixedT::from(inner_to_i32((bottomscreen.0 - 1) >> FRACBITS));
@@ -706,12 +715,13 @@ impl Software25D {
                     draw_masked_column(
                         texture_column,
                         pic_data.vert_light_colourmap(wall_lights, spryscale.to_f32()),
-                        1 / spryscale,
+                        1 / texscale,
                         seg_renderer.centery,
                         x,
-                        dc_texturemid,
+                        dc_texturemid * yscale,
                         top,
                         bottom,
+                        tranmap,
                         rend,
                     );
 
@@ -727,6 +737,7 @@ impl Software25D {
 ///
 /// Iterates texels top-to-bottom, skipping transparent pixels (`u16::MAX`),
 /// mapping through the colourmap for lighting, and writing to the framebuffer.
+/// With a `tranmap` each texel is blended over the framebuffer instead.
 #[allow(clippy::too_many_arguments)]
 fn draw_masked_column<P: PixelFmt>(
     texture_column: &[u16],
@@ -737,6 +748,7 @@ fn draw_masked_column<P: PixelFmt>(
     dc_texturemid: FixedT,
     yl: FixedT,
     mut yh: FixedT,
+    tranmap: Option<&[u8]>,
     pixels: &mut PixelTarget<P>,
 ) {
     if yh >= FixedT::from(pixels.size().height()) {
@@ -754,7 +766,10 @@ fn draw_masked_column<P: PixelFmt>(
             continue;
         }
         let lit = colourmap[texture_column[select] as usize] as u16;
-        pixels.store(y * pitch + dc_x, lit);
+        match tranmap {
+            Some(tranmap) => pixels.store_translucent(y * pitch + dc_x, lit, tranmap),
+            None => pixels.store(y * pitch + dc_x, lit),
+        }
         frac += fracstep;
     }
 }
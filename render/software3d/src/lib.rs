@@ -1,7 +1,7 @@
 #[cfg(feature = "hprof")]
 use coarse_prof::profile;
 use glam::{Mat4, Vec2, Vec3, Vec4};
-use level::{AABB, BSP3D, LevelData, is_leaf, leaf_index, light_band};
+use level::{AABB, BSP3D, LevelData, Sector, SectorSky, is_leaf, leaf_index, light_band};
 #[cfg(feature = "bench")]
 use math::Angle;
 use pic_data::sky::{SKY_TILES, SKY_V_STRETCH, build_sky_extended, nearest_palette_index};
@@ -198,7 +198,7 @@ impl RenderStats {
     }
 }
 
-/// Sky rendering state. Rebuilt when the sky texture changes.
+/// Sky rendering state for one sky texture. Rebuilt when the texture changes.
 pub(crate) struct SkyRend {
     /// Sky texture column at screen_x = 0 (wraps into [0, sky_width)).
     pub(crate) x_offset: f32,
@@ -208,8 +208,8 @@ pub(crate) struct SkyRend {
     pub(crate) v_scale: f32,
     /// Pitch-based additive offset keeping the sky world-fixed on Y.
     pub(crate) pitch_offset: f32,
-    /// Sky texture index last passed to `init_sky`; `usize::MAX` = not built.
-    last_pic: usize,
+    /// Sky texture index last passed to `build`; `usize::MAX` = not built.
+    pub(crate) last_pic: usize,
     /// Horizontal FOV in radians, derived from the projection matrix.
     pub(crate) h_fov: f32,
     /// Combined sky buffer of palette indices (column-major): original rows +
@@ -235,6 +235,66 @@ impl SkyRend {
             tex_width: 0,
         }
     }
+
+    /// One-time sky setup: precompute static scale factors and per-column edge
+    /// colours. Called when the sky texture changes (e.g. new map).
+    fn build(&mut self, sky_pic: usize, pic_data: &PicData, screen_w: f32, view_h: f32) {
+        let sky = pic_data.wall_pic(sky_pic);
+        let sky_w = sky.width;
+        let sky_h = sky.height;
+
+        // Horizontal step: sky tiles SKY_TILES times per full 360°, columns
+        // decrease left-to-right (matches 2.5d screen_to_angle convention).
+        self.x_step = -(self.h_fov * sky_w as f32 * SKY_TILES) / (screen_w * TAU);
+
+        // Vertical scale: texture is SKY_V_STRETCH times taller than the view.
+        self.v_scale = sky_h as f32 / (view_h * SKY_V_STRETCH);
+
+        // Extended sky as palette indices for the index plane (transparent -> 0).
+        let palette = pic_data.palette();
+        self.extended = build_sky_extended(
+            &sky.data,
+            sky_w,
+            sky_h,
+            pic_data.colourmap(0),
+            palette,
+            |c| nearest_palette_index(c, palette),
+        );
+        self.tex_height = sky_h;
+        self.tex_width = sky_w;
+
+        self.last_pic = sky_pic;
+    }
+
+    /// Per-frame sky update: recompute only the values that depend on player
+    /// angle and pitch. `proj_y` is the projection's vertical scale in pixels.
+    fn update(&mut self, angle_rad: f32, pitch_rad: f32, view_h: f32, proj_y: f32) {
+        let sky_h = self.tex_height as f32;
+        let sky_w = self.tex_width as f32;
+
+        // Horizontal offset: left edge of screen = angle + hfov/2 (decreasing
+        // rightward).
+        self.x_offset = (angle_rad + self.h_fov * 0.5) * sky_w * SKY_TILES / TAU;
+
+        // Vertical center + pitch offset: sky_h/2 sits at view center when
+        // pitch = 0; positive pitch (looking up) shifts rows toward the zenith.
+        let half_h = view_h * 0.5;
+        let sky_center_base = sky_h * 0.5 - half_h * self.v_scale;
+        self.pitch_offset = sky_center_base - pitch_rad * proj_y * self.v_scale;
+    }
+
+    /// Column origin and step for a sector's MBF sky transfer: the line's
+    /// texture offset turns the sky as a BAM angle, and a flipped sky mirrors
+    /// the columns.
+    pub(crate) fn transfer_columns(&self, sky: &SectorSky) -> (f32, f32) {
+        let turn = sky.offset.to_fixed_raw() as u32 as f32 / (u32::MAX as f32 + 1.0);
+        let x_offset = self.x_offset + turn * self.tex_width as f32 * SKY_TILES;
+        if sky.flipped {
+            (-x_offset, -self.x_step)
+        } else {
+            (x_offset, self.x_step)
+        }
+    }
 }
 
 /// Debug draw options and per-frame scratch buffers for debug overlays.
@@ -323,10 +383,15 @@ pub struct Software3D {
     // Per-frame traversal state — pre-allocated, reset each frame
     seen_sectors: Vec<bool>,
     visible_sectors: Vec<(usize, usize)>,
+    /// Translucent polygons (BOOM linedef 260, UDMF alpha) in front-to-back order,
+    /// deferred until everything behind them is drawn
+    translucent_polys: Vec<usize>,
     sprite_quads: Vec<SpriteQuad>,
     // Sub-structs
     stats: RenderStats,
     sky: SkyRend,
+    /// Skies of MBF sky transfer lines (271, 272), one per texture
+    transfer_skies: Vec<SkyRend>,
     debug: DebugDraw,
     fuzz_pos: usize,
     // Voxel rendering
@@ -357,9 +422,11 @@ impl Software3D {
             current_frame_id: 0,
             seen_sectors: Vec::new(),
             visible_sectors: Vec::new(),
+            translucent_polys: Vec::new(),
             sprite_quads: Vec::with_capacity(64),
             stats: RenderStats::new(),
             sky: SkyRend::new(),
+            transfer_skies: Vec::new(),
             debug: DebugDraw::default(),
             fuzz_pos: 0,
             voxel_manager: None,
@@ -406,62 +473,43 @@ impl Software3D {
         let aspect = (hfov / 2.0).tan() / (vfov / 2.0).tan();
         self.projection_matrix = Mat4::perspective_rh_gl(vfov, aspect, self.near_z, self.far_z);
         self.sky.h_fov = 2.0 * (1.0 / self.projection_matrix.x_axis.x).atan();
+        self.transfer_skies.clear();
     }
 
-    /// One-time sky setup: precompute static scale factors and per-column edge
-    /// colours. Called when the sky texture changes (e.g. new map).
-    fn init_sky(&mut self, sky_pic: usize, pic_data: &PicData) {
-        let sky = pic_data.wall_pic(sky_pic);
-        let sky_w = sky.width;
-        let sky_h = sky.height;
+    /// Per-frame sky update for the level sky and every MBF sky transfer
+    /// texture in use, rebuilding any whose texture changed.
+    fn update_sky_params(
+        &mut self,
+        angle_rad: f32,
+        pitch_rad: f32,
+        sectors: &[Sector],
+        pic_data: &PicData,
+    ) {
         let screen_w = self.width as f32;
         let view_h = self.view_height as f32;
+        let proj_y = view_h * 0.5 * self.projection_matrix.y_axis.y;
 
-        // Horizontal step: sky tiles SKY_TILES times per full 360°, columns
-        // decrease left-to-right (matches 2.5d screen_to_angle convention).
-        self.sky.x_step = -(self.sky.h_fov * sky_w as f32 * SKY_TILES) / (screen_w * TAU);
-
-        // Vertical scale: texture is SKY_V_STRETCH times taller than the view.
-        self.sky.v_scale = sky_h as f32 / (view_h * SKY_V_STRETCH);
-
-        // Extended sky as palette indices for the index plane (transparent -> 0).
-        let palette = pic_data.palette();
-        self.sky.extended = build_sky_extended(
-            &sky.data,
-            sky_w,
-            sky_h,
-            pic_data.colourmap(0),
-            palette,
-            |c| nearest_palette_index(c, palette),
-        );
-        self.sky.tex_height = sky_h;
-        self.sky.tex_width = sky_w;
-
-        self.sky.last_pic = sky_pic;
-    }
-
-    /// Per-frame sky update: recompute only the values that depend on player
-    /// angle and pitch. Calls `init_sky` first if the sky texture changed.
-    fn update_sky_params(&mut self, angle_rad: f32, pitch_rad: f32, pic_data: &PicData) {
         let sky_pic = pic_data.sky_pic();
         if sky_pic != self.sky.last_pic {
-            self.init_sky(sky_pic, pic_data);
+            self.sky.build(sky_pic, pic_data, screen_w, view_h);
         }
+        self.sky.update(angle_rad, pitch_rad, view_h, proj_y);
 
-        let sky_h = pic_data.wall_pic(sky_pic).height as f32;
-        let view_h = self.view_height as f32;
-        let sky_w = pic_data.wall_pic(sky_pic).width as f32;
-
-        // Horizontal offset: left edge of screen = angle + hfov/2 (decreasing
-        // rightward).
-        self.sky.x_offset = (angle_rad + self.sky.h_fov * 0.5) * sky_w * SKY_TILES / TAU;
-
-        // Vertical center + pitch offset: sky_h/2 sits at view center when
-        // pitch = 0; positive pitch (looking up) shifts rows toward the zenith.
-        let half_h = view_h * 0.5;
-        let sky_center_base = sky_h * 0.5 - half_h * self.sky.v_scale;
-        let proj_y = half_h * self.projection_matrix.y_axis.y;
-        self.sky.pitch_offset = sky_center_base - pitch_rad * proj_y * self.sky.v_scale;
+        for sky in sectors.iter().filter_map(Sector::sky_transfer) {
+            if !self
+                .transfer_skies
+                .iter()
+                .any(|s| s.last_pic == sky.texture)
+            {
+                let mut rend = SkyRend::new();
+                rend.h_fov = self.sky.h_fov;
+                rend.build(sky.texture, pic_data, screen_w, view_h);
+                self.transfer_skies.push(rend);
+            }
+        }
+        for rend in &mut self.transfer_skies {
+            rend.update(angle_rad, pitch_rad, view_h, proj_y);
+        }
     }
 
     /// Fill all pixels that have no solid geometry with the sky texture.
@@ -752,7 +800,7 @@ impl Software3D {
         // per-frame texcoord math here.
         let (tex_w, tex_h) = Self::poly_texture_dims(tex, is_flat, pic_data);
         let (uv_start, _) = bsp3d.poly_vertex_range[gi];
-        let scroll = bsp3d.poly_scroll[gi];
+        let [scroll_u, scroll_v] = bsp3d.poly_scroll[gi];
 
         for i in 0..vert_count {
             let vertex_idx = bsp3d.poly_verts[uv_start + i];
@@ -760,7 +808,8 @@ impl Software3D {
             let [tu, tv] = bsp3d.poly_vertex_uv[uv_start + i];
 
             input_vertices[i] = clip_pos;
-            input_tex_coords[i] = Vec3::new((tu + scroll) / tex_w, tv / tex_h, clip_pos.w);
+            input_tex_coords[i] =
+                Vec3::new((tu + scroll_u) / tex_w, (tv + scroll_v) / tex_h, clip_pos.w);
         }
 
         // Apply Sutherland-Hodgman clipping against all six frustum planes
@@ -862,8 +911,8 @@ impl Software3D {
         }
 
         let polygon = &bsp3d.polygons[gi];
-        let brightness =
-            light_band(polygon.sector.lightlevel, player_light, polygon.normal) as usize;
+        let lightlevel = bsp3d.poly_lightlevel(gi);
+        let brightness = light_band(lightlevel, player_light, polygon.normal) as usize;
         let bounds = (
             Vec2::new(scr_min_x, scr_min_y),
             Vec2::new(scr_max_x, scr_max_y),
@@ -984,10 +1033,11 @@ impl Software3D {
             self.seen_sectors.resize(sector_count, false);
             self.seen_sectors.fill(false);
             self.visible_sectors.clear();
+            self.translucent_polys.clear();
 
             let player_angle_rad = view.angle.rad();
             let player_pitch_rad = view.lookdir;
-            self.update_sky_params(player_angle_rad, player_pitch_rad, pic_data);
+            self.update_sky_params(player_angle_rad, player_pitch_rad, sectors, pic_data);
 
             self.render_bsp(
                 bsp_3d.root_node(),
@@ -1006,6 +1056,8 @@ impl Software3D {
                 self.draw_sky_fill(pic_data, buffer);
             }
 
+            self.draw_translucent_polygons(bsp_3d, pic_data, view.extralight, buffer);
+
             // Draw player weapon overlay on top of everything
             self.draw_player_weapons(view, pic_data, buffer);
 
@@ -1095,10 +1147,28 @@ impl Software3D {
         self.seen_sectors.resize(sectors.len(), false);
         self.seen_sectors.fill(false);
         self.visible_sectors.clear();
+        self.translucent_polys.clear();
 
-        self.update_sky_params(angle_rad, pitch_rad, pic_data);
+        self.update_sky_params(angle_rad, pitch_rad, sectors, pic_data);
 
         self.render_bsp(bsp_3d.root_node(), bsp_3d, pos, 0, false, pic_data, buffer);
+        self.draw_translucent_polygons(bsp_3d, pic_data, 0, buffer);
+    }
+
+    /// Draw the deferred translucent polygons back-to-front over the finished
+    /// scene, sprites and sky included.
+    fn draw_translucent_polygons<P: PixelFmt>(
+        &mut self,
+        bsp3d: &BSP3D,
+        pic_data: &PicData,
+        player_light: usize,
+        buffer: &mut PixelTarget<P>,
+    ) {
+        let polys = std::mem::take(&mut self.translucent_polys);
+        for &gi in polys.iter().rev() {
+            self.render_surface_polygon(gi, bsp3d, pic_data, player_light, buffer);
+        }
+        self.translucent_polys = polys;
     }
 
     /// Front-to-back BSP traversal with immediate rendering and Hi-Z AABB
@@ -1142,7 +1212,8 @@ impl Software3D {
                 let sid = sector.num as usize;
                 if !self.seen_sectors[sid] {
                     self.seen_sectors[sid] = true;
-                    self.visible_sectors.push((sid, sector.lightlevel >> 4));
+                    self.visible_sectors
+                        .push((sid, sector.sprite_lightlevel() >> 4));
                 }
             }
 
@@ -1151,7 +1222,11 @@ impl Software3D {
                     && self.cull_polygon_bounds(gi, bsp3d).is_some()
                 {
                     self.stats.polygons_submitted += 1;
-                    self.render_surface_polygon(gi, bsp3d, pic_data, player_light, buffer);
+                    if bsp3d.poly_is_translucent(gi) {
+                        self.translucent_polys.push(gi);
+                    } else {
+                        self.render_surface_polygon(gi, bsp3d, pic_data, player_light, buffer);
+                    }
                 }
             }
             return;
@@ -60,8 +60,27 @@ impl Software3D {
             sky_num,
         );
         let is_masked = bsp3d.poly_is_masked_middle(gi);
-        let is_translucent = bsp3d.poly_is_translucent(gi);
+        let tranmap = bsp3d.poly_is_translucent(gi).then(|| pic_data.tranmap());
         let is_sky = matches!(texture_sampler, TextureSampler::Sky);
+        // MBF sky transfer (271, 272): the sector's own sky texture, turned
+        // and mirrored by its line
+        let transfer = is_sky
+            .then(|| bsp3d.polygons[gi].sector.sky_transfer())
+            .flatten()
+            .and_then(|t| {
+                let sky = self
+                    .transfer_skies
+                    .iter()
+                    .find(|s| s.last_pic == t.texture)?;
+                Some((sky, t))
+            });
+        let (sky, sky_x_offset, sky_x_step, sky_row_offset) = match transfer {
+            Some((sky, t)) => {
+                let (x_offset, x_step) = sky.transfer_columns(&t);
+                (sky, x_offset, x_step, t.rowoffset.to_f32())
+            }
+            None => (&self.sky, self.sky.x_offset, self.sky.x_step, 0.0),
+        };
         let vertices = &screen_poly.0;
         let vertex_count = screen_poly.0.len();
         let width = self.width as f32;
@@ -144,10 +163,10 @@ impl Software3D {
             }
 
             if is_sky {
-                let sky_combined = &self.sky.extended;
-                let sky_tex_height = self.sky.tex_height;
-                let sky_w = self.sky.tex_width;
-                let sky_r = (y_f * self.sky.v_scale + self.sky.pitch_offset) as i32;
+                let sky_combined = &sky.extended;
+                let sky_tex_height = sky.tex_height;
+                let sky_w = sky.tex_width;
+                let sky_r = (y_f * sky.v_scale + sky.pitch_offset + sky_row_offset) as i32;
                 let mut x = x_start;
                 while x <= x_end {
                     if self
@@ -155,7 +174,7 @@ impl Software3D {
                         .depth_buffer
                         .test_and_set_depth_unchecked(x, y, edge_inv_w)
                     {
-                        let sky_col = (self.sky.x_offset + x as f32 * self.sky.x_step)
+                        let sky_col = (sky_x_offset + x as f32 * sky_x_step)
                             .rem_euclid(sky_w as f32)
                             as usize;
                         if let Some(color) =
@@ -221,16 +240,17 @@ impl Software3D {
                                 x += 1;
                                 continue;
                             }
-                            // TODO(index-fb): re-express translucency as an
-                            // index-domain effect. For now translucent writes the
-                            // index opaque with no depth, so geometry behind is
-                            // overwritten.
-                            if !is_translucent {
+                            // Translucent middles blend through the TRANMAP and
+                            // leave depth alone, so anything drawn later behind
+                            // them still shows through.
+                            if let Some(tranmap) = tranmap {
+                                buffer.store_translucent(y * buf_pitch + x, color, tranmap);
+                            } else {
                                 self.rasterizer
                                     .depth_buffer
                                     .set_depth_unchecked(x, y, edge_inv_w);
+                                buffer.store(y * buf_pitch + x, color);
                             }
-                            buffer.store(y * buf_pitch + x, color);
                         } else {
                             // Depth test before UV — avoids the perspective divide on misses
                             if !self
@@ -291,6 +311,7 @@ impl Software3D {
 
         let patch = pic_data.sprite_patch(quad.patch_index);
         let sprite_pitch = buffer.pitch();
+        let tranmap = quad.is_translucent.then(|| pic_data.tranmap());
 
         for y in setup.y_start..=setup.y_end {
             let Some(span) = Self::sprite_scanline(&setup, y) else {
@@ -332,7 +353,10 @@ impl Software3D {
 
                 let colourmap = pic_data.base_colourmap(quad.brightness, edge_inv_w * LIGHT_SCALE);
                 let lit = colourmap[color_index as usize] as u16;
-                buffer.store(y * sprite_pitch + x, lit);
+                match tranmap {
+                    Some(tranmap) => buffer.store_translucent(y * sprite_pitch + x, lit, tranmap),
+                    None => buffer.store(y * sprite_pitch + x, lit),
+                }
 
                 self.rasterizer
                     .depth_buffer
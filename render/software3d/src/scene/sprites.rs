@@ -29,6 +29,8 @@ pub(crate) struct SpriteQuad {
     pub(crate) patch_index: usize,
     pub(crate) brightness: usize,
     pub(crate) is_shadow: bool,
+    /// BOOM `MF_TRANSLUCENT`: blended through the translucency map
+    pub(crate) is_translucent: bool,
     depth: f32,
 }
 
@@ -333,6 +335,7 @@ impl Software3D {
             uvs,
             brightness,
             is_shadow: thing.flags.contains(MapObjFlag::Shadow),
+            is_translucent: thing.flags.contains(MapObjFlag::Translucent),
             depth,
         })
     }
@@ -83,7 +83,7 @@ pub struct Wgpu3D {
     flat_xlat: Vec<u32>,
     /// Reused per-corner attr/scroll scratch, re-fanned on texture_dirty.
     corner_attr: Vec<CornerAttr>,
-    corner_scroll: Vec<f32>,
+    corner_scroll: Vec<[f32; 2]>,
     /// Reused per-corner UV scratch, fanned from BSP3D poly_vertex_uv at upload
     /// and re-fanned on geometry_dirty (mover UV re-bake).
     corner_uv: Vec<[f32; 2]>,
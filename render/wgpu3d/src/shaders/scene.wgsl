@@ -21,6 +21,9 @@ struct CornerAttr {
     contrast_adjust: i32,   // fake-contrast band delta
     is_sky: u32,            // sky surface: sample sky, write depth (occludes)
     is_masked_mid: u32,     // two-sided middle: discard v outside [0,1), no tile
+    is_translucent: u32,    // translucent line: screen-door dither
+    light: i32,             // UDMF surface light, added to the sector light
+    light_absolute: u32,    // ... or used in its place
 };
 
 struct AtlasRect {
@@ -39,7 +42,7 @@ struct AtlasRect {
 @group(1) @binding(2) var<storage, read> corner_attr: array<CornerAttr>;
 @group(1) @binding(3) var<storage, read> corner_uv: array<vec2<f32>>;
 @group(1) @binding(4) var<storage, read> sector_light: array<f32>;
-@group(1) @binding(5) var<storage, read> corner_scroll: array<f32>;
+@group(1) @binding(5) var<storage, read> corner_scroll: array<vec2<f32>>;
 
 @group(2) @binding(0) var wall_atlas: texture_2d_array<f32>;
 @group(2) @binding(1) var flat_atlas: texture_2d_array<f32>;
@@ -79,15 +82,20 @@ struct VsOut {
     // Dome direction (worldpos - eye, z flattened) for sky-flagged corners.
     @location(6) sky_dir: vec3<f32>,
     @location(7) @interpolate(flat) is_masked_mid: u32,
+    @location(8) @interpolate(flat) is_translucent: u32,
 };
 
 // Sector baseline colourmap row (0 bright .. 31 dark) from the light band. The
 // band math mirrors level::bsp3d::build::light_band (shaders can't call Rust);
 // keep the two in sync.
-fn start_row(sector: u32, contrast_adjust: i32) -> f32 {
-    let raw = sector_light[sector];
+fn start_row(attr: CornerAttr) -> f32 {
+    // level::SurfaceLight::apply: the UDMF surface light over the sector's.
+    var raw = clamp(sector_light[attr.sector] + f32(attr.light), 0.0, 255.0);
+    if attr.light_absolute == 1u {
+        raw = clamp(f32(attr.light), 0.0, 255.0);
+    }
     let base = min(floor(raw / 16.0) + camera.extralight, light.light_levels);
-    let band = clamp(base + f32(contrast_adjust), 0.0, light.light_levels);
+    let band = clamp(base + f32(attr.contrast_adjust), 0.0, light.light_levels);
     return (light.light_levels - band) * 4.0;
 }
 
@@ -101,8 +109,8 @@ fn vs_main(@builtin(vertex_index) corner: u32) -> VsOut {
     // Eye-at-origin: subtract camera position per vertex (matches software3d).
     out.pos = camera.view_proj * vec4<f32>(world - camera.camera_pos, 1.0);
     // Texel-space UV + per-corner horizontal scroll (special-48 scrollers).
-    out.uv = corner_uv[corner] + vec2<f32>(corner_scroll[corner], 0.0);
-    out.start_row = start_row(attr.sector, attr.contrast_adjust);
+    out.uv = corner_uv[corner] + corner_scroll[corner];
+    out.start_row = start_row(attr);
     out.tex = attr.tex;
     out.is_flat = attr.is_flat;
     out.view_dist = out.pos.w;
@@ -110,6 +118,7 @@ fn vs_main(@builtin(vertex_index) corner: u32) -> VsOut {
     // View direction off real geometry for the cylinder sky mapping (no flatten).
     out.sky_dir = world - camera.camera_pos;
     out.is_masked_mid = attr.is_masked_mid;
+    out.is_translucent = attr.is_translucent;
     return out;
 }
 
@@ -145,6 +154,11 @@ fn fs_main(in: VsOut) -> @location(0) vec4<f32> {
             discard;
         }
     }
+    // Translucent line: no blended pass here, so drop every other pixel in a
+    // checkerboard for the software renderers' 50% translucency map.
+    if in.is_translucent == 1u && ((u32(in.pos.x) + u32(in.pos.y)) & 1u) == 0u {
+        discard;
+    }
     // Single Doom diminishing-light row: baseline minus a near-distance boost.
     let near = clamp((1.0 / max(in.view_dist, 1.0)) * light.dist_scale, 0.0, light.dist_rows_max);
     let row = clamp(in.start_row - near * 0.5, 0.0, light.max_row);
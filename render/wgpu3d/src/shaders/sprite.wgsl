@@ -27,7 +27,7 @@ struct SpriteInstance {
     layer: u32,             // atlas array layer
     flip: u32,              // 0/1 horizontal flip (U swap)
     brightness: u32,        // 0..15 sector/full-bright light level
-    flags: u32,             // bit0 fuzz/shadow (handled by pipeline variant)
+    flags: u32,             // bit0 fuzz/shadow (pipeline variant), bit1 translucent
     pad0: u32,
     pad1: u32,
 };
@@ -56,6 +56,7 @@ struct VsOut {
     @location(3) @interpolate(flat) rect_size: vec2<u32>,
     @location(4) @interpolate(flat) start_row: f32,
     @location(5) view_dist: f32,
+    @location(6) @interpolate(flat) flags: u32,
 };
 
 // Sector baseline colourmap row from the brightness band (mirrors
@@ -90,6 +91,7 @@ fn vs_main(@builtin(vertex_index) corner: u32, @builtin(instance_index) inst: u3
     out.rect_size = s.rect_size;
     out.start_row = sprite_start_row(s.brightness);
     out.view_dist = out.pos.w;
+    out.flags = s.flags;
     return out;
 }
 
@@ -106,6 +108,10 @@ fn fs_main(in: VsOut) -> @location(0) vec4<f32> {
     if rgba.a < 0.5 {
         discard;
     }
+    // Translucent: checkerboard screen-door, as the scene pass does for lines.
+    if (in.flags & 2u) != 0u && ((u32(in.pos.x) + u32(in.pos.y)) & 1u) == 0u {
+        discard;
+    }
     // Same smooth diminishing-light row as the scene pass.
     let near = clamp((1.0 / max(in.view_dist, 1.0)) * light.dist_scale, 0.0, light.dist_rows_max);
     let row = clamp(in.start_row - near * 0.5, 0.0, light.max_row);
@@ -130,7 +130,7 @@ impl VoxelScratch {
 
         self.sort.clear();
         for sector in level_data.sectors.iter() {
-            let light_level = sector.lightlevel >> 4;
+            let light_level = sector.sprite_lightlevel() >> 4;
             <Sector as SectorExt>::run_func_on_thinglist(sector, |thing: &MapObject| {
                 if ptr::from_ref(thing) as usize == view.player_mobj_id {
                     return true;
@@ -25,6 +25,12 @@ pub struct CornerAttr {
     pub is_sky: u32,
     /// Two-sided middle (masked): discard v outside [0,1) so it isn't tiled.
     pub is_masked_mid: u32,
+    /// Translucent line (BOOM 260, UDMF alpha): screen-door dithered.
+    pub is_translucent: u32,
+    /// UDMF surface light: added to the live sector light, or replacing it
+    /// when `light_absolute` is set.
+    pub light: i32,
+    pub light_absolute: u32,
 }
 
 /// CPU-side mesh buffers ready for upload.
@@ -80,13 +86,17 @@ fn poly_corner_ranges(poly_vertex_range: &[(usize, usize)]) -> Vec<(u32, u32)> {
 /// Per-polygon corner attributes from BSP3D. Shared by the initial fan and the
 /// texture_dirty re-fan (switches change `tex`).
 pub fn corner_attr_of(bsp3d: &BSP3D, p: usize) -> CornerAttr {
+    let surface_light = bsp3d.poly_surface_light(p);
     CornerAttr {
         tex: bsp3d.poly_tex[p],
         is_flat: bsp3d.poly_is_flat(p) as u32,
-        sector: bsp3d.polygons[p].sector.num as u32,
+        sector: bsp3d.poly_light_sector(p).num as u32,
         contrast_adjust: contrast_adjust(bsp3d.polygons[p].normal),
         is_sky: bsp3d.poly_is_sky(p) as u32,
         is_masked_mid: bsp3d.poly_is_masked_middle(p) as u32,
+        is_translucent: bsp3d.poly_is_translucent(p) as u32,
+        light: surface_light.level,
+        light_absolute: surface_light.absolute as u32,
     }
 }
 
@@ -225,10 +225,10 @@ impl ScenePipeline {
             contents: cast_slice(corner_uv),
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
         });
-        // Per-corner scroll (texels), re-fanned on texture_dirty (scrollers).
+        // Per-corner `[u, v]` scroll (texels), re-fanned on texture_dirty (scrollers).
         let corner_scroll = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("scene_corner_scroll"),
-            size: (mesh.corner_count().max(1) as u64) * size_of::<f32>() as u64,
+            size: (mesh.corner_count().max(1) as u64) * size_of::<[f32; 2]>() as u64,
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -400,7 +400,7 @@ impl GpuMesh {
 
     /// Re-upload per-corner texture scroll (scrollers). Caller gates on
     /// `BSP3D::texture_dirty`.
-    pub fn update_corner_scroll(&self, queue: &wgpu::Queue, scroll: &[f32]) {
+    pub fn update_corner_scroll(&self, queue: &wgpu::Queue, scroll: &[[f32; 2]]) {
         queue.write_buffer(&self.corner_scroll, 0, cast_slice(scroll));
     }
 
@@ -420,9 +420,9 @@ impl GpuMesh {
         &self,
         queue: &wgpu::Queue,
         first_corner: u32,
-        scroll: &[f32],
+        scroll: &[[f32; 2]],
     ) {
-        let offset = first_corner as u64 * size_of::<f32>() as u64;
+        let offset = first_corner as u64 * size_of::<[f32; 2]>() as u64;
         queue.write_buffer(&self.corner_scroll, offset, cast_slice(scroll));
     }
 
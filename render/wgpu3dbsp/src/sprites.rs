@@ -40,6 +40,8 @@ const VERTS_PER_QUAD: u32 = 6;
 const MAX_PSPRITES: usize = 2;
 /// `SpriteInstance.flags` bit0: spectre/shadow → fuzz (RGB-halve) pipeline.
 const SPRITE_FLAG_FUZZ: u32 = 1;
+/// `SpriteInstance.flags` bit1: translucent (BOOM flag, UDMF alpha) → dithered.
+const SPRITE_FLAG_TRANSLUCENT: u32 = 2;
 /// Thing frame flags (Doom): full-bright bit, and the mask for the frame index.
 const FF_FULLBRIGHT: u32 = 0x8000;
 const FF_FRAMEMASK: u32 = 0x7FFF;
@@ -275,10 +277,12 @@ impl SpriteScratch {
             let brightness = if thing.frame & FF_FULLBRIGHT != 0 {
                 LIGHT_LEVELS
             } else {
-                sector.lightlevel >> 4
+                sector.sprite_lightlevel() >> 4
             };
             let flags = if thing.flags.contains(MapObjFlag::Shadow) {
                 SPRITE_FLAG_FUZZ
+            } else if thing.flags.contains(MapObjFlag::Translucent) {
+                SPRITE_FLAG_TRANSLUCENT
             } else {
                 0
             };
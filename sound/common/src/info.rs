@@ -1,4 +1,9 @@
+use std::sync::OnceLock;
+
+static SFX_INFO: OnceLock<Vec<SfxInfoBase>> = OnceLock::new();
+
 /// SFX lump name and playback priority
+#[derive(Debug, Clone, Copy)]
 pub struct SfxInfoBase {
     pub name: &'static str,
     pub priority: i32,
@@ -125,3 +130,14 @@ pub const SFX_INFO_BASE: [SfxInfoBase; 109] = [
     SfxInfoBase::new("skeatk", 70),
     SfxInfoBase::new("radio", 60),
 ];
+
+/// Install a DEHACKED-patched copy of `SFX_INFO_BASE`. Returns false if the
+/// table was already in use.
+pub fn install_sfx_info(info: Vec<SfxInfoBase>) -> bool {
+    SFX_INFO.set(info).is_ok()
+}
+
+/// The sfx table in use, indexed by `SfxName`.
+pub fn sfx_info() -> &'static [SfxInfoBase] {
+    SFX_INFO.get_or_init(|| SFX_INFO_BASE.to_vec())
+}
@@ -5,7 +5,7 @@ pub use sounds::*;
 mod music;
 pub use music::*;
 pub mod info;
-pub use info::{SFX_INFO_BASE, SfxInfoBase};
+pub use info::{SFX_INFO_BASE, SfxInfoBase, install_sfx_info, sfx_info};
 pub mod mus2midi;
 pub use mus2midi::read_mus_to_midi;
 pub mod spatial;
@@ -43,6 +43,9 @@ pub enum SoundAction {
         sfx: SfxName,
         x: f32,
         y: f32,
+        /// `NORM_PITCH` plays the sample as recorded, see
+        /// [`SfxName::random_pitch`].
+        pitch: u8,
     },
     UpdateListener {
         uid: usize,
@@ -55,6 +58,8 @@ pub enum SoundAction {
     },
     StopSfxAll,
     SfxVolume(i32),
+    /// Play sfx at their `StartSfx` pitch (Doom 1.2) or all unshifted.
+    SfxPitchShift(bool),
     MusicVolume(i32),
     StartMusic(Vec<u8>, bool),
     PauseMusic,
@@ -1,3 +1,9 @@
+use std::sync::OnceLock;
+
+use crate::{MID_ID, MUS_ID};
+
+static MUSIC_NAMES: OnceLock<Vec<String>> = OnceLock::new();
+
 pub const EPISODE4_MUS: [MusTrack; 9] = [
     MusTrack::E3M4, // American   e4m1
     MusTrack::E3M2, // Romero     e4m2
@@ -92,14 +98,35 @@ pub enum MusTrack {
 }
 
 impl MusTrack {
+    /// Lump name without the `D_` prefix, as DEHACKED `Text` blocks see it.
+    pub fn base_name(self) -> String {
+        match self {
+            Self::None | Self::NumMus => String::new(),
+            other => format!("{other:?}").to_ascii_lowercase(),
+        }
+    }
+
     pub fn lump_name(self) -> String {
         match self {
             Self::None | Self::NumMus => String::new(),
-            other => format!("D_{:?}", other).to_ascii_uppercase(),
+            other => {
+                let name = MUSIC_NAMES
+                    .get()
+                    .and_then(|names| names.get(other as usize))
+                    .cloned()
+                    .unwrap_or_else(|| other.base_name());
+                format!("D_{name}").to_ascii_uppercase()
+            }
         }
     }
 }
 
+/// Install DEHACKED music renames, indexed by `MusTrack`. Returns false if
+/// names were already installed.
+pub fn install_music_names(names: Vec<String>) -> bool {
+    MUSIC_NAMES.set(names).is_ok()
+}
+
 impl TryFrom<u8> for MusTrack {
     type Error = u8;
 
@@ -113,3 +140,76 @@ impl TryFrom<u8> for MusTrack {
         Ok(unsafe { std::mem::transmute::<u8, MusTrack>(i) })
     }
 }
+
+/// Container format of a music lump, told apart by its leading bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicFormat {
+    /// Doom MUS, converted to MIDI before playback.
+    Mus,
+    /// Standard MIDI file (`MThd`).
+    Midi,
+    /// Ogg Vorbis (`OggS`).
+    Ogg,
+    /// FLAC (`fLaC`).
+    Flac,
+    /// RIFF WAVE.
+    Wav,
+}
+
+impl MusicFormat {
+    /// Sniff the format of `data`, `None` when it is none of the supported
+    /// ones.
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&MUS_ID) {
+            Some(Self::Mus)
+        } else if data.starts_with(&MID_ID) {
+            Some(Self::Midi)
+        } else if data.starts_with(b"OggS") {
+            Some(Self::Ogg)
+        } else if data.starts_with(b"fLaC") {
+            Some(Self::Flac)
+        } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WAVE") {
+            Some(Self::Wav)
+        } else {
+            None
+        }
+    }
+
+    /// Sampled audio, decoded and streamed rather than synthesized.
+    pub fn is_digital(self) -> bool {
+        matches!(self, Self::Ogg | Self::Flac | Self::Wav)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniff_music_formats() {
+        assert_eq!(
+            MusicFormat::sniff(b"MUS\x1a\x10\x00"),
+            Some(MusicFormat::Mus)
+        );
+        assert_eq!(
+            MusicFormat::sniff(b"MThd\0\0\0\x06"),
+            Some(MusicFormat::Midi)
+        );
+        assert_eq!(MusicFormat::sniff(b"OggS\0\x02"), Some(MusicFormat::Ogg));
+        assert_eq!(MusicFormat::sniff(b"fLaC\0"), Some(MusicFormat::Flac));
+        assert_eq!(
+            MusicFormat::sniff(b"RIFF\x24\0\0\0WAVEfmt "),
+            Some(MusicFormat::Wav)
+        );
+        assert!(MusicFormat::Ogg.is_digital());
+        assert!(!MusicFormat::Midi.is_digital());
+    }
+
+    #[test]
+    fn sniff_rejects_unknown_data() {
+        assert_eq!(MusicFormat::sniff(b""), None);
+        assert_eq!(MusicFormat::sniff(b"ID3\x04"), None);
+        // A RIFF container that is not a WAVE.
+        assert_eq!(MusicFormat::sniff(b"RIFF\x24\0\0\0AVI "), None);
+    }
+}
@@ -133,3 +133,45 @@ impl TryFrom<u8> for SfxName {
         Ok(unsafe { std::mem::transmute::<u8, SfxName>(i) })
     }
 }
+
+/// Unshifted sfx pitch, vanilla `NORM_PITCH`.
+pub const NORM_PITCH: u8 = 128;
+
+impl SfxName {
+    /// Vanilla `S_StartSound` pitch variation for this sound, drawing from
+    /// `rnd` (`M_Random`) only when it varies: the saw sounds by ±8, pickups
+    /// and the switch `tink` not at all, everything else by ±16.
+    ///
+    /// Doom 1.2 played the result; from 1.666 the random number was still
+    /// drawn but the sound library ignored it, which is why the server only
+    /// applies it when pitch shifting is enabled.
+    pub fn random_pitch(self, rnd: impl FnOnce() -> i32) -> u8 {
+        let delta = if (Self::Sawup..=Self::Sawhit).contains(&self) {
+            8 - (rnd() & 15)
+        } else if self != Self::Itemup && self != Self::Tink {
+            16 - (rnd() & 31)
+        } else {
+            0
+        };
+        (NORM_PITCH as i32 + delta).clamp(0, 255) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_pitch_follows_vanilla_ranges() {
+        assert_eq!(SfxName::Pistol.random_pitch(|| 0), NORM_PITCH + 16);
+        assert_eq!(SfxName::Pistol.random_pitch(|| 31), NORM_PITCH - 15);
+        assert_eq!(SfxName::Sawidl.random_pitch(|| 0), NORM_PITCH + 8);
+        assert_eq!(SfxName::Sawhit.random_pitch(|| 255), NORM_PITCH - 7);
+        assert_eq!(SfxName::Itemup.random_pitch(|| 200), NORM_PITCH);
+        assert_eq!(SfxName::Tink.random_pitch(|| 7), NORM_PITCH);
+        assert_eq!(
+            SfxName::Itemup.random_pitch(|| panic!("no M_Random draw")),
+            NORM_PITCH
+        );
+    }
+}
@@ -2,7 +2,12 @@
 //!
 //! Clean-room Rust implementation based on DOSBox DBOPL (WAVE_TABLEMUL mode).
 //! Public API: `init_tables()`, `Chip::new()`, `Chip::setup()`,
-//! `Chip::write_reg()`, `Chip::generate_block_2()`.
+//! `Chip::write_reg()`, `Chip::generate_block_2()` (OPL2, mono) and
+//! `Chip::generate_block_3()` (OPL3, stereo).
+//!
+//! Channels are stored in register order: 0-8 in the low bank, 9-17 in the
+//! high (`0x100`) bank. An OPL3 four-operator pair is channel `n` with
+//! channel `n + 3`, for `n` in 0-2 and 9-11.
 
 // FM synthesis carries waveform tables, register bits, and chip-state
 // fields that are part of the OPL2/3 spec but not all currently consumed
@@ -630,18 +635,7 @@ impl Channel {
     }
 
     /// Handle write to register 0xA0-0xA8 (frequency number low byte).
-    fn write_a0(
-        &mut self,
-        val: u8,
-        reg08: u8,
-        attack_rates: &[u32; 76],
-        linear_rates: &[u32; 76],
-        opl3_active: bool,
-    ) {
-        let four_op_raw = if opl3_active { self.four_mask } else { 0 };
-        if four_op_raw > 0x80 {
-            return;
-        }
+    fn write_a0(&mut self, val: u8, reg08: u8, attack_rates: &[u32; 76], linear_rates: &[u32; 76]) {
         let change = (self.chan_data ^ val as u32) & 0xFF;
         if change != 0 {
             self.chan_data ^= change;
@@ -650,67 +644,48 @@ impl Channel {
     }
 
     /// Handle write to register 0xB0-0xB8 (key-on, block number, frequency high
-    /// bits).
+    /// bits). Returns true when the key-on bit changed.
     fn write_b0(
         &mut self,
         val: u8,
         reg08: u8,
         attack_rates: &[u32; 76],
         linear_rates: &[u32; 76],
-        opl3_active: bool,
-    ) {
-        let four_op_raw = if opl3_active { self.four_mask } else { 0 };
-        if four_op_raw > 0x80 {
-            return;
-        }
+    ) -> bool {
         let change = (self.chan_data ^ ((val as u32) << 8)) & 0x1F00;
         if change != 0 {
             self.chan_data ^= change;
             self.update_frequency(reg08, attack_rates, linear_rates);
         }
         if ((val ^ self.reg_b0) & 0x20) == 0 {
-            return;
+            return false;
         }
         self.reg_b0 = val;
-        if (val & 0x20) != 0 {
-            self.op[0].key_on(0x1);
-            self.op[1].key_on(0x1);
-        } else {
-            self.op[0].key_off(0x1);
-            self.op[1].key_off(0x1);
+        self.key(val & 0x20 != 0);
+        true
+    }
+
+    fn key(&mut self, on: bool) {
+        for op in &mut self.op {
+            if on {
+                op.key_on(0x1);
+            } else {
+                op.key_off(0x1);
+            }
         }
     }
 
     /// Handle write to register 0xC0-0xC8 (feedback depth, synthesis mode, OPL3
-    /// panning).
-    fn write_c0(&mut self, val: u8, opl3_active: bool, reg_bd: u8) {
-        let change = val ^ self.reg_c0;
-        if change == 0 {
-            return;
+    /// panning). The synth mode itself is set by `Chip::update_synth`, which
+    /// needs the four-op partner. Returns false when nothing changed.
+    fn write_c0(&mut self, val: u8) -> bool {
+        if val == self.reg_c0 {
+            return false;
         }
         self.reg_c0 = val;
         let fb = (val >> 1) & 7;
         self.feedback = if fb != 0 { 9 - fb } else { 31 };
-
-        if opl3_active {
-            if (self.four_mask & 0x40) != 0 && (reg_bd & 0x20) != 0 {
-                // percussion — no synth change
-            } else if (val & 1) != 0 {
-                self.synth_mode = SynthMode::Sm3AM;
-            } else {
-                self.synth_mode = SynthMode::Sm3FM;
-            }
-            self.mask_left = if (val & 0x10) != 0 { -1 } else { 0 };
-            self.mask_right = if (val & 0x20) != 0 { -1 } else { 0 };
-        } else {
-            if (self.four_mask & 0x40) != 0 && (reg_bd & 0x20) != 0 {
-                // percussion
-            } else if (val & 1) != 0 {
-                self.synth_mode = SynthMode::Sm2AM;
-            } else {
-                self.synth_mode = SynthMode::Sm2FM;
-            }
-        }
+        true
     }
 
     /// Generate `samples` worth of audio for this channel and mix into
@@ -739,7 +714,7 @@ impl Channel {
                 // percussion always generates
             }
             _ => {
-                // 4-op modes not needed for OPL2
+                // 4-op modes are rendered by `generate_four_op`
                 return;
             }
         }
@@ -771,6 +746,75 @@ impl Channel {
             }
         }
     }
+
+    /// Generate `samples` of a four-operator pair into the stereo `output`.
+    /// This channel holds operators 1 and 2 and the pan bits, `pair` holds
+    /// operators 3 and 4.
+    ///
+    /// - FM-FM: 1 -> 2 -> 3 -> 4
+    /// - AM-FM: 1 + (2 -> 3 -> 4)
+    /// - FM-AM: (1 -> 2) + (3 -> 4)
+    /// - AM-AM: 1 + (2 -> 3) + 4
+    fn generate_four_op(
+        &mut self,
+        pair: &mut Self,
+        lfo: &ChipLfo,
+        samples: usize,
+        output: &mut [i32],
+    ) {
+        let mode = self.synth_mode;
+        let [op0, op1] = &mut self.op;
+        let [op2, op3] = &mut pair.op;
+        let silent = match mode {
+            SynthMode::Sm3FMFM => op3.silent(),
+            SynthMode::Sm3AMFM => op0.silent() && op3.silent(),
+            SynthMode::Sm3FMAM => op1.silent() && op3.silent(),
+            SynthMode::Sm3AMAM => op0.silent() && op2.silent() && op3.silent(),
+            _ => return,
+        };
+        if silent {
+            self.old = [0, 0];
+            return;
+        }
+
+        op0.prepare(lfo);
+        op1.prepare(lfo);
+        op2.prepare(lfo);
+        op3.prepare(lfo);
+
+        for i in 0..samples {
+            let mod_val = (self.old[0] as u32).wrapping_add(self.old[1] as u32) >> self.feedback;
+            self.old[0] = self.old[1];
+            self.old[1] = op0.get_sample(mod_val as i32);
+            let out0 = self.old[0];
+
+            let sample = match mode {
+                SynthMode::Sm3FMFM => {
+                    let next = op1.get_sample(out0);
+                    let next = op2.get_sample(next);
+                    op3.get_sample(next)
+                }
+                SynthMode::Sm3AMFM => {
+                    let next = op1.get_sample(0);
+                    let next = op2.get_sample(next);
+                    out0 + op3.get_sample(next)
+                }
+                SynthMode::Sm3FMAM => {
+                    let first = op1.get_sample(out0);
+                    let next = op2.get_sample(0);
+                    first + op3.get_sample(next)
+                }
+                _ => {
+                    let next = op1.get_sample(0);
+                    let second = op2.get_sample(next);
+                    out0 + second + op3.get_sample(0)
+                }
+            };
+
+            output[i * 2] += sample & self.mask_left as i32;
+            output[i * 2 + 1] += sample & self.mask_right as i32;
+        }
+    }
 }
 
 /// OPL2/OPL3 FM synthesis chip emulator
@@ -825,6 +869,13 @@ impl Chip {
             reg_bd: 0,
             reg104: 0,
         };
+        // Four-op pair bits in reg 0x104; 0x80 marks the second channel.
+        for i in 0..3 {
+            chip.chan[i].four_mask = 1 << i;
+            chip.chan[i + 3].four_mask = 0x80 | (1 << i);
+            chip.chan[i + 9].four_mask = 1 << (i + 3);
+            chip.chan[i + 12].four_mask = 0x80 | (1 << (i + 3));
+        }
         // Set four_mask for percussion channels
         chip.chan[6].four_mask = 0x40;
         chip.chan[7].four_mask = 0x40;
@@ -916,13 +967,28 @@ impl Chip {
     /// >= 0x100 target OPL3 high bank.
     pub fn write_reg(&mut self, reg: u32, val: u8) {
         match (reg & 0xF0) >> 4 {
-            0x0 => {
-                if reg == 0x01 {
-                    self.wave_form_mask = if (val & 0x20) != 0 { 0x7 } else { 0x0 };
-                } else if reg == 0x08 {
-                    self.reg08 = val;
+            0x0 => match reg {
+                0x01 => self.wave_form_mask = if (val & 0x20) != 0 { 0x7 } else { 0x0 },
+                0x08 => self.reg08 = val,
+                0x104 => {
+                    // Only the low 6 bits select pairs; 0x80 stays set so the
+                    // second channel of an enabled pair reads as > 0x80.
+                    if ((self.reg104 ^ val) & 0x3F) == 0 {
+                        return;
+                    }
+                    self.reg104 = 0x80 | (val & 0x3F);
+                    self.reset_synth();
                 }
-            }
+                0x105 => {
+                    let active = (val & 1) != 0;
+                    if active == self.opl3_active {
+                        return;
+                    }
+                    self.opl3_active = active;
+                    self.reset_synth();
+                }
+                _ => {}
+            },
             0x1 => {}
             0x2 | 0x3 => {
                 let index = (((reg >> 3) & 0x20) | (reg & 0x1F)) as usize;
@@ -957,12 +1023,13 @@ impl Chip {
             0xA => {
                 let index = (((reg >> 4) & 0x10) | (reg & 0xF)) as usize;
                 let ch = Self::chan_index(index);
-                if ch < 18 {
+                // Writes to the second channel of a four-op pair are ignored.
+                if ch < 18 && self.four_op(ch) <= 0x80 {
                     let reg08 = self.reg08;
                     let ar = self.attack_rates;
                     let lr = self.linear_rates;
-                    let opl3 = self.opl3_active;
-                    self.chan[ch].write_a0(val, reg08, &ar, &lr, opl3);
+                    self.chan[ch].write_a0(val, reg08, &ar, &lr);
+                    self.sync_pair(ch, false);
                 }
             }
             0xB => {
@@ -971,22 +1038,20 @@ impl Chip {
                 } else {
                     let index = (((reg >> 4) & 0x10) | (reg & 0xF)) as usize;
                     let ch = Self::chan_index(index);
-                    if ch < 18 {
+                    if ch < 18 && self.four_op(ch) <= 0x80 {
                         let reg08 = self.reg08;
                         let ar = self.attack_rates;
                         let lr = self.linear_rates;
-                        let opl3 = self.opl3_active;
-                        self.chan[ch].write_b0(val, reg08, &ar, &lr, opl3);
+                        let keyed = self.chan[ch].write_b0(val, reg08, &ar, &lr);
+                        self.sync_pair(ch, keyed);
                     }
                 }
             }
             0xC => {
                 let index = (((reg >> 4) & 0x10) | (reg & 0xF)) as usize;
                 let ch = Self::chan_index(index);
-                if ch < 18 {
-                    let opl3 = self.opl3_active;
-                    let bd = self.reg_bd;
-                    self.chan[ch].write_c0(val, opl3, bd);
+                if ch < 18 && self.chan[ch].write_c0(val) {
+                    self.update_synth(ch);
                 }
             }
             0xD => {}
@@ -1024,6 +1089,116 @@ impl Chip {
         }
     }
 
+    /// Generate a block of interleaved stereo samples (OPL3 mode). All 18
+    /// channels play, four-op pairs as one voice, each panned by its 0xC0
+    /// bits. `output` holds `2 * total` samples.
+    pub fn generate_block_3(&mut self, total: usize, output: &mut [i32]) {
+        output[..total * 2].fill(0);
+        let mut out_off = 0;
+        let mut remaining = total;
+
+        while remaining > 0 {
+            let samples = self.forward_lfo(remaining as u32) as usize;
+            let lfo = ChipLfo {
+                tremolo_value: self.tremolo_value,
+                vibrato_sign: self.vibrato_sign,
+                vibrato_shift: self.vibrato_shift,
+            };
+            let out = &mut output[out_off * 2..];
+            for ch_idx in 0..18 {
+                let four_op = self.four_op(ch_idx);
+                if (four_op & 0x3F) == 0 {
+                    self.chan[ch_idx].generate(&lfo, samples, out);
+                } else if (four_op & 0x80) == 0 {
+                    // First of a pair; the second is rendered with it.
+                    let (head, tail) = self.chan.split_at_mut(ch_idx + 3);
+                    head[ch_idx].generate_four_op(&mut tail[0], &lfo, samples, out);
+                }
+            }
+            remaining -= samples;
+            out_off += samples;
+        }
+    }
+
+    /// The reg 0x104 pair bits that apply to `ch`: zero unless OPL3 is
+    /// active and `ch` is in an enabled four-op pair, above 0x80 for the
+    /// pair's second channel.
+    fn four_op(&self, ch: usize) -> u8 {
+        if self.opl3_active {
+            self.reg104 & self.chan[ch].four_mask
+        } else {
+            0
+        }
+    }
+
+    /// Copy the frequency of the first channel of a four-op pair to the
+    /// second, and the key-on if it just changed. No-op for other channels.
+    fn sync_pair(&mut self, ch: usize, keyed: bool) {
+        if (self.four_op(ch) & 0x3F) == 0 {
+            return;
+        }
+        let data = self.chan[ch].chan_data;
+        let ar = self.attack_rates;
+        let lr = self.linear_rates;
+        self.chan[ch + 3].set_chan_data(data, &ar, &lr);
+        if keyed {
+            let on = (self.chan[ch].reg_b0 & 0x20) != 0;
+            self.chan[ch + 3].key(on);
+        }
+    }
+
+    /// Recompute the synth mode and pan masks of `ch` from its 0xC0 register,
+    /// its four-op pairing and rhythm mode. A four-op pair's mode combines
+    /// both channels' connection bits and lives on the first channel.
+    fn update_synth(&mut self, ch: usize) {
+        let chan = &self.chan[ch];
+        let percussion = (chan.four_mask & 0x40) != 0 && (self.reg_bd & 0x20) != 0;
+        let am = (chan.reg_c0 & 1) != 0;
+        if !self.opl3_active {
+            if !percussion {
+                self.chan[ch].synth_mode = if am {
+                    SynthMode::Sm2AM
+                } else {
+                    SynthMode::Sm2FM
+                };
+            }
+            return;
+        }
+
+        if (self.four_op(ch) & 0x3F) != 0 {
+            let first = if (chan.four_mask & 0x80) == 0 {
+                ch
+            } else {
+                ch - 3
+            };
+            let connection =
+                (self.chan[first].reg_c0 & 1) | ((self.chan[first + 3].reg_c0 & 1) << 1);
+            self.chan[first].synth_mode = match connection {
+                0 => SynthMode::Sm3FMFM,
+                1 => SynthMode::Sm3AMFM,
+                2 => SynthMode::Sm3FMAM,
+                _ => SynthMode::Sm3AMAM,
+            };
+        } else if !percussion {
+            self.chan[ch].synth_mode = if am {
+                SynthMode::Sm3AM
+            } else {
+                SynthMode::Sm3FM
+            };
+        }
+        let c0 = self.chan[ch].reg_c0;
+        self.chan[ch].mask_left = if (c0 & 0x10) != 0 { -1 } else { 0 };
+        self.chan[ch].mask_right = if (c0 & 0x20) != 0 { -1 } else { 0 };
+    }
+
+    /// Re-evaluate every channel's synth mode after an OPL3 enable or four-op
+    /// pairing change.
+    fn reset_synth(&mut self) {
+        for ch in 0..18 {
+            self.update_synth(ch);
+        }
+    }
+
     /// Advance the LFO (vibrato + tremolo) counters and return how many samples
     /// can be generated before the next LFO tick boundary.
     fn forward_lfo(&mut self, samples: u32) -> u32 {
@@ -1069,18 +1244,18 @@ impl Chip {
 
         if (change & 0x20) != 0 {
             if (val & 0x20) != 0 {
-                self.chan[6].synth_mode = SynthMode::Sm2Percussion;
-                self.chan[7].synth_mode = SynthMode::Sm2Percussion;
-                self.chan[8].synth_mode = SynthMode::Sm2Percussion;
+                let mode = if self.opl3_active {
+                    SynthMode::Sm3Percussion
+                } else {
+                    SynthMode::Sm2Percussion
+                };
+                self.chan[6].synth_mode = mode;
+                self.chan[7].synth_mode = mode;
+                self.chan[8].synth_mode = mode;
             } else {
-                let opl3 = self.opl3_active;
-                let bd = self.reg_bd;
-                self.chan[6].write_c0(self.chan[6].reg_c0 ^ 0xFF, opl3, bd);
-                self.chan[6].write_c0(self.chan[6].reg_c0 ^ 0xFF, opl3, bd);
-                self.chan[7].write_c0(self.chan[7].reg_c0 ^ 0xFF, opl3, bd);
-                self.chan[7].write_c0(self.chan[7].reg_c0 ^ 0xFF, opl3, bd);
-                self.chan[8].write_c0(self.chan[8].reg_c0 ^ 0xFF, opl3, bd);
-                self.chan[8].write_c0(self.chan[8].reg_c0 ^ 0xFF, opl3, bd);
+                self.update_synth(6);
+                self.update_synth(7);
+                self.update_synth(8);
             }
         }
 
@@ -1113,9 +1288,11 @@ impl Chip {
         }
         let mut ch = group * 3 + slot % 3;
         let op = slot / 3;
+        // The high bank starts at register channel 16, like `chan_index`.
         if ch >= 12 {
             ch += 4;
         }
+        let ch = Self::chan_index(ch);
         if ch < 18 && op < 2 {
             Some((ch, op))
         } else {
@@ -1216,4 +1393,107 @@ mod tests {
         let max = output.iter().map(|s| s.abs()).max().unwrap_or(0);
         assert!(max > 0, "Expected non-zero output, got silence");
     }
+
+    /// Program `ch`'s two operators with a loud, fast-attack sine.
+    fn program_voice(chip: &mut Chip, ch: usize) {
+        let bank = if ch >= 9 { 0x100 } else { 0 };
+        let local = (ch % 9) as u32;
+        let op1 = bank + local % 3 + (local / 3) * 8;
+        for op in [op1, op1 + 3] {
+            chip.write_reg(0x20 + op, 0x01);
+            chip.write_reg(0x40 + op, 0x00);
+            chip.write_reg(0x60 + op, 0xF0);
+            chip.write_reg(0x80 + op, 0x77);
+        }
+    }
+
+    fn opl3_chip() -> Chip {
+        init_tables();
+        let mut chip = Chip::new();
+        chip.setup(44100);
+        chip.write_reg(0x105, 0x01);
+        chip
+    }
+
+    fn channel_peaks(output: &[i32]) -> (i32, i32) {
+        let peak = |off: usize| output.iter().skip(off).step_by(2).map(|s| s.abs()).max();
+        (peak(0).unwrap_or(0), peak(1).unwrap_or(0))
+    }
+
+    #[test]
+    fn opl3_pans_by_c0_bits() {
+        let mut chip = opl3_chip();
+        program_voice(&mut chip, 0);
+        chip.write_reg(0xC0, 0x10); // FM, left only
+        chip.write_reg(0xA0, 0x98);
+        chip.write_reg(0xB0, 0x31);
+
+        let mut output = [0i32; 1024];
+        chip.generate_block_3(512, &mut output);
+        let (left, right) = channel_peaks(&output);
+        assert!(left > 0, "left speaker silent");
+        assert_eq!(right, 0, "right speaker should be off");
+    }
+
+    #[test]
+    fn opl3_high_bank_channel_plays() {
+        let mut chip = opl3_chip();
+        program_voice(&mut chip, 17);
+        chip.write_reg(0x1C8, 0x30);
+        chip.write_reg(0x1A8, 0x98);
+        chip.write_reg(0x1B8, 0x31);
+
+        let mut output = [0i32; 1024];
+        chip.generate_block_3(512, &mut output);
+        let (left, right) = channel_peaks(&output);
+        assert!(left > 0 && left == right, "centred high bank voice");
+
+        // OPL2 generation only renders the low bank.
+        let mut mono = [0i32; 512];
+        chip.generate_block_2(512, &mut mono);
+        assert!(mono.iter().all(|&s| s == 0));
+    }
+
+    #[test]
+    fn four_op_pair_plays_as_one_voice() {
+        let mut chip = opl3_chip();
+        chip.write_reg(0x104, 0x01); // pair channels 0 and 3
+        program_voice(&mut chip, 0);
+        program_voice(&mut chip, 3);
+        chip.write_reg(0xC0, 0x31); // first half AM
+        chip.write_reg(0xC3, 0x30); // second half FM
+        assert_eq!(chip.chan[0].synth_mode, SynthMode::Sm3AMFM);
+
+        // Keying the second channel directly is ignored; the first keys both.
+        chip.write_reg(0xB3, 0x31);
+        assert_eq!(chip.chan[3].op[1].state, EnvState::Off);
+        chip.write_reg(0xA0, 0x98);
+        chip.write_reg(0xB0, 0x31);
+        assert_eq!(chip.chan[3].op[1].state, EnvState::Attack);
+        assert_eq!(chip.chan[3].chan_data, chip.chan[0].chan_data);
+
+        let mut output = [0i32; 1024];
+        chip.generate_block_3(512, &mut output);
+        assert!(channel_peaks(&output).0 > 0);
+
+        // Unpairing returns both channels to two-op modes.
+        chip.write_reg(0x104, 0x00);
+        assert_eq!(chip.chan[0].synth_mode, SynthMode::Sm3AM);
+        assert_eq!(chip.chan[3].synth_mode, SynthMode::Sm3FM);
+    }
+
+    #[test]
+    fn opl3_selects_all_eight_waveforms() {
+        let mut chip = opl3_chip();
+        // No 0x01 waveform-enable write: OPL3 mode alone unlocks them.
+        chip.write_reg(0xE0, 0x05);
+        assert_eq!(chip.chan[0].op[0].wave_base, WAVE_BASE_TBL[5] as usize);
+
+        init_tables();
+        let mut opl2 = Chip::new();
+        opl2.setup(44100);
+        opl2.write_reg(0x01, 0x20);
+        opl2.write_reg(0xE0, 0x05);
+        assert_eq!(opl2.chan[0].op[0].wave_base, WAVE_BASE_TBL[1] as usize);
+    }
 }
@@ -1,6 +1,7 @@
 //! OPL MIDI player state machine.
 //!
-//! Drives an OPL2 `Chip` with MIDI events parsed from standard MIDI files.
+//! Drives an OPL2 `Chip` with MIDI events parsed from standard MIDI files, or
+//! both banks of an OPL3 with stereo panning (Chocolate Doom's `-opl3`).
 //! Backend-agnostic — no SDL2 or rodio dependency. Consumers wrap this in
 //! their audio callback / `Source` implementation.
 
@@ -13,7 +14,13 @@ const GENMIDI_NUM_PERCUSSION: usize = 47;
 const GENMIDI_FLAG_FIXED: u16 = 0x0001;
 const GENMIDI_FLAG_2VOICE: u16 = 0x0004;
 
-const OPL_CHANNELS: usize = 9;
+/// Voices of an OPL3 (both register banks). OPL2 mode uses the first
+/// `OPL2_VOICES`.
+const OPL_CHANNELS: usize = 18;
+const OPL2_VOICES: usize = 9;
+
+/// Channel 0xC0 pan bits: both speakers.
+const PAN_CENTRE: u8 = 0x30;
 
 /// GZDoom normalizes OPL output by dividing by 10240.0 for float [-1,1].
 /// For i16 output: 32767.0 / 10240.0 ≈ 3.2.
@@ -211,6 +218,8 @@ struct ChannelData {
     sustain: bool,
     /// Pitch bend offset (-64..+63).
     bend: i32,
+    /// MIDI CC10 pan as 0xC0 speaker bits, only audible in OPL3 mode.
+    pan: u8,
 }
 
 impl Default for ChannelData {
@@ -221,6 +230,7 @@ impl Default for ChannelData {
             program: 0,
             sustain: false,
             bend: 0,
+            pan: PAN_CENTRE,
         }
     }
 }
@@ -248,14 +258,17 @@ impl MidiTrack {
 
 /// Backend-agnostic OPL MIDI player.
 ///
-/// Manages MIDI parsing, note allocation across 9 OPL channels, GENMIDI
-/// instrument loading, and sample generation. Wrap in an audio callback
+/// Manages MIDI parsing, note allocation across 9 OPL2 or 18 OPL3 channels,
+/// GENMIDI instrument loading, and sample generation. Wrap in an audio callback
 /// or `rodio::Source` to produce output.
 pub struct OplPlayerState {
     chip: Chip,
     midi_track: MidiTrack,
     playing_notes: Vec<OplNote>,
     opl_channels: [bool; OPL_CHANNELS],
+    /// Voices in use: 9 for OPL2, 18 for OPL3.
+    num_voices: usize,
+    opl3: bool,
     sample_rate: u32,
     is_playing: bool,
     loop_music: bool,
@@ -277,16 +290,7 @@ impl OplPlayerState {
         let mut chip = Chip::new();
         chip.setup(sample_rate);
 
-        // Initialize all registers like Chocolate Doom's OPL_InitRegisters
-        for r in 0x40..=0x55 {
-            chip.write_reg(r, 0x3F);
-        }
-        for r in 0x60..=0xF5 {
-            chip.write_reg(r, 0x00);
-        }
-        for r in 1..0x40 {
-            chip.write_reg(r, 0x00);
-        }
+        Self::init_registers(&mut chip, 0);
         chip.write_reg(0x01, 0x20);
 
         let (main_instruments, percussion_instruments) = Self::load_genmidi(wad);
@@ -296,6 +300,8 @@ impl OplPlayerState {
             midi_track: MidiTrack::new(),
             playing_notes: Vec::new(),
             opl_channels: [false; OPL_CHANNELS],
+            num_voices: OPL2_VOICES,
+            opl3: false,
             sample_rate,
             is_playing: false,
             loop_music: false,
@@ -310,6 +316,31 @@ impl OplPlayerState {
         }
     }
 
+    /// Switch between OPL2 (9 voices, mono) and OPL3 (18 voices, CC10 pan)
+    /// like Chocolate Doom's `-opl3`. With twice the voices, double-voice
+    /// instruments rarely have to steal. Playing notes are cut.
+    pub fn set_opl3(&mut self, on: bool) {
+        if on == self.opl3 {
+            return;
+        }
+        self.stop_all_notes();
+        self.opl3 = on;
+        if on {
+            self.num_voices = OPL_CHANNELS;
+            self.chip.write_reg(0x105, 0x01);
+            Self::init_registers(&mut self.chip, 0x100);
+            // The bank sweep above cleared the OPL3 enable again.
+            self.chip.write_reg(0x105, 0x01);
+        } else {
+            self.num_voices = OPL2_VOICES;
+            self.chip.write_reg(0x105, 0x00);
+        }
+    }
+
+    pub fn is_opl3(&self) -> bool {
+        self.opl3
+    }
+
     /// Reinitialize the chip for a different sample rate.
     pub fn reinit_rate(&mut self, rate: u32) {
         self.chip.setup(rate);
@@ -347,14 +378,16 @@ impl OplPlayerState {
         self.stop_all_notes();
     }
 
-    /// Generate mono i16 samples into the buffer.
+    /// Generate interleaved stereo i16 samples into the buffer. OPL2 output
+    /// is mono, duplicated to both sides.
     pub fn generate_samples(&mut self, buffer: &mut [i16]) {
         if !self.is_playing {
             buffer.fill(0);
             return;
         }
 
-        for sample in buffer.iter_mut() {
+        let gain = OPL_OUTPUT_SCALE * (self.volume as f32 / 128.0);
+        for frame in buffer.chunks_exact_mut(2) {
             self.sample_counter += 1.0;
             while self.samples_per_tick > 0.0 && self.sample_counter >= self.samples_per_tick {
                 self.sample_counter -= self.samples_per_tick;
@@ -362,11 +395,18 @@ impl OplPlayerState {
                 self.process_midi_events();
             }
 
-            let mut opl_sample = [0i32; 1];
-            self.chip.generate_block_2(1, &mut opl_sample);
+            let mut opl_sample = [0i32; 2];
+            if self.opl3 {
+                self.chip.generate_block_3(1, &mut opl_sample);
+            } else {
+                self.chip.generate_block_2(1, &mut opl_sample[..1]);
+                opl_sample[1] = opl_sample[0];
+            }
 
-            let scaled = opl_sample[0] as f32 * OPL_OUTPUT_SCALE * (self.volume as f32 / 128.0);
-            *sample = scaled.clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            for (out, s) in frame.iter_mut().zip(opl_sample) {
+                let scaled = s as f32 * gain;
+                *out = scaled.clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            }
         }
     }
 
@@ -389,6 +429,35 @@ impl OplPlayerState {
         }
     }
 
+    /// Reset one register bank (0 or 0x100) like Chocolate Doom's
+    /// `OPL_InitRegisters`: operators silent, everything else zeroed.
+    fn init_registers(chip: &mut Chip, bank: u32) {
+        for r in 0x40..=0x55 {
+            chip.write_reg(bank | r, 0x3F);
+        }
+        for r in 0x60..=0xF5 {
+            chip.write_reg(bank | r, 0x00);
+        }
+        for r in 1..0x40 {
+            chip.write_reg(bank | r, 0x00);
+        }
+    }
+
+    /// The 0xA0/0xB0/0xC0 register offset of a voice: 0-8 in the low bank,
+    /// 9-17 in the high bank.
+    fn chan_reg(opl_channel: usize) -> u32 {
+        let bank = if opl_channel >= OPL2_VOICES { 0x100 } else { 0 };
+        bank | (opl_channel % OPL2_VOICES) as u32
+    }
+
+    /// The operator register offset of a voice's modulator; its carrier is
+    /// 3 above.
+    fn op_reg(opl_channel: usize) -> u32 {
+        let bank = if opl_channel >= OPL2_VOICES { 0x100 } else { 0 };
+        let local = (opl_channel % OPL2_VOICES) as u32;
+        bank | (local % 3 + (local / 3) * 8)
+    }
+
     /// Load and parse the GENMIDI lump from the WAD.
     ///
     /// Returns (main_instruments[128], percussion_instruments[47]).
@@ -654,15 +723,15 @@ impl OplPlayerState {
     }
 
     fn stop_all_notes(&mut self) {
-        for i in 0..OPL_CHANNELS {
-            self.chip.write_reg(0xB0 + i as u32, 0);
+        for i in 0..self.num_voices {
+            self.chip.write_reg(0xB0 + Self::chan_reg(i), 0);
         }
         self.playing_notes.clear();
         self.opl_channels.fill(false);
     }
 
     fn allocate_opl_channel(&mut self) -> Option<usize> {
-        for (i, &used) in self.opl_channels.iter().enumerate() {
+        for (i, &used) in self.opl_channels[..self.num_voices].iter().enumerate() {
             if !used {
                 self.opl_channels[i] = true;
                 return Some(i);
@@ -696,8 +765,10 @@ impl OplPlayerState {
             let stolen_note = self.playing_notes.remove(idx);
             let opl_channel = stolen_note.opl_channel;
 
-            self.chip
-                .write_reg(0xB0 + opl_channel as u32, (stolen_note.freq >> 8) as u8);
+            self.chip.write_reg(
+                0xB0 + Self::chan_reg(opl_channel),
+                (stolen_note.freq >> 8) as u8,
+            );
 
             Some(opl_channel)
         } else {
@@ -728,7 +799,7 @@ impl OplPlayerState {
     fn free_opl_channel(&mut self, channel: usize) {
         if channel < OPL_CHANNELS {
             self.opl_channels[channel] = false;
-            self.chip.write_reg(0xB0 + channel as u32, 0);
+            self.chip.write_reg(0xB0 + Self::chan_reg(channel), 0);
         }
     }
 
@@ -736,57 +807,77 @@ impl OplPlayerState {
     ///
     /// Writes modulator and carrier operator registers (tremolo, attack,
     /// sustain, waveform, scale/level) and the channel feedback/connection
-    /// register. For FM mode, the modulator level is set from the
-    /// instrument data; for AM mode, it is set to max attenuation
-    /// (carrier-only volume control).
+    /// register (with the MIDI channel's `pan` bits). For FM mode, the
+    /// modulator level is set from the instrument data; for AM mode, it is set
+    /// to max attenuation (carrier-only volume control).
     fn setup_instrument(
         &mut self,
         opl_channel: usize,
         instrument: &GenmidiInstrument,
         voice_num: usize,
+        pan: u8,
     ) {
         let voice = &instrument.voices[voice_num];
 
-        let op1_offset = if opl_channel < 3 {
-            opl_channel
-        } else if opl_channel < 6 {
-            opl_channel + 5
-        } else {
-            opl_channel + 10
-        };
+        let op1_offset = Self::op_reg(opl_channel);
         let op2_offset = op1_offset + 3;
 
         let modulating = (voice.feedback & 0x01) == 0;
 
         let carrier_level = (voice.carrier.scale & 0xC0) | 0x3F;
-        self.chip.write_reg(0x40 + op2_offset as u32, carrier_level);
+        self.chip.write_reg(0x40 + op2_offset, carrier_level);
         self.chip
-            .write_reg(0x20 + op2_offset as u32, voice.carrier.tremolo);
+            .write_reg(0x20 + op2_offset, voice.carrier.tremolo);
+        self.chip.write_reg(0x60 + op2_offset, voice.carrier.attack);
         self.chip
-            .write_reg(0x60 + op2_offset as u32, voice.carrier.attack);
+            .write_reg(0x80 + op2_offset, voice.carrier.sustain);
         self.chip
-            .write_reg(0x80 + op2_offset as u32, voice.carrier.sustain);
-        self.chip
-            .write_reg(0xE0 + op2_offset as u32, voice.carrier.waveform);
+            .write_reg(0xE0 + op2_offset, voice.carrier.waveform);
 
         let modulator_level = if modulating {
             (voice.modulator.scale & 0xC0) | (voice.modulator.level & 0x3F)
         } else {
             (voice.modulator.scale & 0xC0) | 0x3F
         };
+        self.chip.write_reg(0x40 + op1_offset, modulator_level);
         self.chip
-            .write_reg(0x40 + op1_offset as u32, modulator_level);
-        self.chip
-            .write_reg(0x20 + op1_offset as u32, voice.modulator.tremolo);
+            .write_reg(0x20 + op1_offset, voice.modulator.tremolo);
         self.chip
-            .write_reg(0x60 + op1_offset as u32, voice.modulator.attack);
+            .write_reg(0x60 + op1_offset, voice.modulator.attack);
         self.chip
-            .write_reg(0x80 + op1_offset as u32, voice.modulator.sustain);
+            .write_reg(0x80 + op1_offset, voice.modulator.sustain);
         self.chip
-            .write_reg(0xE0 + op1_offset as u32, voice.modulator.waveform);
+            .write_reg(0xE0 + op1_offset, voice.modulator.waveform);
 
         self.chip
-            .write_reg(0xC0 + opl_channel as u32, voice.feedback | 0x30);
+            .write_reg(0xC0 + Self::chan_reg(opl_channel), voice.feedback | pan);
+    }
+
+    /// Handle MIDI CC10 in OPL3 mode: map the pan to speaker bits and rewrite
+    /// 0xC0 of the channel's playing voices. Left and right are swapped as
+    /// in DMX, which Chocolate Doom reproduces.
+    fn set_channel_pan(&mut self, midi_channel: u8, pan: u8) {
+        if !self.opl3 {
+            return;
+        }
+        let bits = if pan >= 96 {
+            0x10
+        } else if pan <= 48 {
+            0x20
+        } else {
+            PAN_CENTRE
+        };
+        if self.channels[midi_channel as usize].pan == bits {
+            return;
+        }
+        self.channels[midi_channel as usize].pan = bits;
+        for note in &self.playing_notes {
+            if note.midi_channel == midi_channel {
+                let feedback = note.current_instrument.voices[note.instrument_voice].feedback;
+                self.chip
+                    .write_reg(0xC0 + Self::chan_reg(note.opl_channel), feedback | bits);
+            }
+        }
     }
 
     /// Compute and write operator volume registers for a voice.
@@ -801,13 +892,7 @@ impl OplPlayerState {
     ) -> u8 {
         let voice = &instrument.voices[voice_num];
 
-        let op1_offset = if opl_channel < 3 {
-            opl_channel
-        } else if opl_channel < 6 {
-            opl_channel + 5
-        } else {
-            opl_channel + 10
-        };
+        let op1_offset = Self::op_reg(opl_channel);
         let op2_offset = op1_offset + 3;
 
         let combined =
@@ -819,13 +904,13 @@ impl OplPlayerState {
         let car_reg = 0x3F - (car_level * full_volume / 128) as u8;
         let final_volume = car_reg | (voice.carrier.scale & 0xC0);
 
-        chip.write_reg(0x40 + op2_offset as u32, final_volume);
+        chip.write_reg(0x40 + op2_offset, final_volume);
 
         if (voice.feedback & 0x01) != 0 {
             let mod_level = (0x3F - (voice.modulator.level & 0x3F)) as u32;
             let mod_reg = 0x3F - (mod_level * full_volume / 128) as u8;
             let mod_final = mod_reg | (voice.modulator.scale & 0xC0);
-            chip.write_reg(0x40 + op1_offset as u32, mod_final);
+            chip.write_reg(0x40 + op1_offset, mod_final);
         }
 
         final_volume
@@ -922,10 +1007,14 @@ impl OplPlayerState {
         for playing_note in &mut self.playing_notes {
             if playing_note.opl_channel == opl_channel {
                 if playing_note.freq != freq_value {
-                    self.chip
-                        .write_reg(0xA0 + opl_channel as u32, (freq_value & 0xFF) as u8);
-                    self.chip
-                        .write_reg(0xB0 + opl_channel as u32, ((freq_value >> 8) | 0x20) as u8);
+                    self.chip.write_reg(
+                        0xA0 + Self::chan_reg(opl_channel),
+                        (freq_value & 0xFF) as u8,
+                    );
+                    self.chip.write_reg(
+                        0xB0 + Self::chan_reg(opl_channel),
+                        ((freq_value >> 8) | 0x20) as u8,
+                    );
                     playing_note.freq = freq_value;
                 }
                 break;
@@ -954,7 +1043,8 @@ impl OplPlayerState {
         };
 
         if let Some(opl_channel) = opl_channel {
-            self.setup_instrument(opl_channel, instrument, voice_num);
+            let pan = self.channels[channel as usize].pan;
+            self.setup_instrument(opl_channel, instrument, voice_num, pan);
 
             let channel_data = self.channels[channel as usize];
             let reg_volume = Self::set_voice_volume(
@@ -971,10 +1061,14 @@ impl OplPlayerState {
                 self.calculate_note_frequency(note, instrument, voice_num, channel);
             let freq_value = freq | ((octave as u16) << 10);
 
-            self.chip
-                .write_reg(0xA0 + opl_channel as u32, (freq_value & 0xFF) as u8);
-            self.chip
-                .write_reg(0xB0 + opl_channel as u32, ((freq_value >> 8) | 0x20) as u8);
+            self.chip.write_reg(
+                0xA0 + Self::chan_reg(opl_channel),
+                (freq_value & 0xFF) as u8,
+            );
+            self.chip.write_reg(
+                0xB0 + Self::chan_reg(opl_channel),
+                ((freq_value >> 8) | 0x20) as u8,
+            );
 
             Some((opl_channel, freq_value, reg_volume))
         } else {
@@ -1061,8 +1155,10 @@ impl OplPlayerState {
                     let opl_note = self.playing_notes.remove(i);
                     let opl_channel = opl_note.opl_channel;
 
-                    self.chip
-                        .write_reg(0xB0 + opl_channel as u32, (opl_note.freq >> 8) as u8);
+                    self.chip.write_reg(
+                        0xB0 + Self::chan_reg(opl_channel),
+                        (opl_note.freq >> 8) as u8,
+                    );
 
                     self.opl_channels[opl_channel] = false;
                 }
@@ -1081,8 +1177,10 @@ impl OplPlayerState {
                 let opl_note = self.playing_notes.remove(i);
                 let opl_channel = opl_note.opl_channel;
 
-                self.chip
-                    .write_reg(0xB0 + opl_channel as u32, (opl_note.freq >> 8) as u8);
+                self.chip.write_reg(
+                    0xB0 + Self::chan_reg(opl_channel),
+                    (opl_note.freq >> 8) as u8,
+                );
 
                 self.opl_channels[opl_channel] = false;
             } else {
@@ -1093,7 +1191,7 @@ impl OplPlayerState {
 
     /// Process all MIDI events whose absolute time has been reached.
     ///
-    /// Dispatches note on/off, control changes (volume, expression, sustain,
+    /// Dispatches note on/off, control changes (volume, pan, expression, sustain,
     /// all-sound-off, all-notes-off), program changes, pitch bend, and tempo
     /// meta events. Handles track looping when enabled.
     fn process_midi_events(&mut self) {
@@ -1130,6 +1228,7 @@ impl OplPlayerState {
                             self.channels[event_channel as usize].volume = event.data2;
                             self.refresh_channel_volumes(event_channel);
                         }
+                        10 => self.set_channel_pan(event_channel, event.data2),
                         11 => {
                             self.channels[event_channel as usize].expression = event.data2;
                             self.refresh_channel_volumes(event_channel);
@@ -1216,8 +1315,10 @@ impl OplPlayerState {
                     if !is_percussion {
                         let opl_note = self.playing_notes.remove(i);
                         let opl_channel = opl_note.opl_channel;
-                        self.chip
-                            .write_reg(0xB0 + opl_channel as u32, (opl_note.freq >> 8) as u8);
+                        self.chip.write_reg(
+                            0xB0 + Self::chan_reg(opl_channel),
+                            (opl_note.freq >> 8) as u8,
+                        );
                         self.opl_channels[opl_channel] = false;
                     } else {
                         i += 1;
@@ -1,7 +1,8 @@
 //! Pure-Rust sound backend using rodio (cpal) for audio output.
 //!
-//! Replaces SDL2_mixer for SFX playback. Music support (OPL/rustysynth)
-//! is handled by separate source modules added to the same output stream.
+//! Replaces SDL2_mixer for SFX playback. Music support (OPL/rustysynth for
+//! MUS and MIDI, rodio's decoder for OGG/FLAC/WAV) is handled by separate
+//! source modules added to the same output stream.
 
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
@@ -12,8 +13,9 @@ use log::{debug, info, warn};
 use opl2_emulator::OplPlayerState;
 use rodio::{DeviceSinkBuilder, MixerDeviceSink};
 use sound_common::{
-    MAX_DIST, MIXER_CHANNELS, MusicType, SAMPLE_RATE, SFX_INFO_BASE, SfxName, SndServerRx,
-    SndServerTx, SoundAction, SoundObject, dist_from_points, listener_to_source_angle_deg,
+    MAX_DIST, MIXER_CHANNELS, MusicFormat, MusicType, NORM_PITCH, SAMPLE_RATE, SfxName,
+    SndServerRx, SndServerTx, SoundAction, SoundObject, dist_from_points,
+    listener_to_source_angle_deg, read_mus_to_midi, sfx_info,
 };
 use wad::WadData;
 
@@ -25,7 +27,7 @@ const TIC_POLL_TIMEOUT: Duration = Duration::from_micros(500);
 mod source_format;
 
 mod mixer;
-use mixer::{BUFFER_SAMPLES, ChannelState, SfxMixer};
+use mixer::{BUFFER_SAMPLES, ChannelState, SfxMixer, pitch_step};
 
 mod opl_source;
 use opl_source::OplSource;
@@ -33,6 +35,9 @@ use opl_source::OplSource;
 mod gus_source;
 use gus_source::{GusPlayerState, GusSource};
 
+mod stream_source;
+use stream_source::{StreamPlayerState, StreamSource};
+
 /// Pre-loaded sound effect data
 struct SfxChunk {
     /// Mono f32 samples at 44100 Hz. `Arc` so playing a sound shares the
@@ -74,7 +79,9 @@ impl SndConfig {
         sf2_path: Option<&std::path::Path>,
     ) -> Self {
         let chunks = load_sfx_chunks(wad);
-        let opl_state = Arc::new(Mutex::new(OplPlayerState::new(SAMPLE_RATE, wad)));
+        let mut opl = OplPlayerState::new(SAMPLE_RATE, wad);
+        opl.set_opl3(music_type == MusicType::OPL3);
+        let opl_state = Arc::new(Mutex::new(opl));
         // Always try to load the SF2 so GUS is available for runtime switching.
         let gus_state = load_gus_state(sf2_path);
         let active_type = resolve_music_type(music_type, gus_state.is_some());
@@ -102,12 +109,18 @@ pub(crate) struct Snd {
     opl_state: Arc<Mutex<OplPlayerState>>,
     gus_state: Option<Arc<Mutex<GusPlayerState>>>,
     music_type: MusicType,
+    /// Digital (OGG/FLAC/WAV) music, played whatever `music_type` is.
+    stream_state: Arc<Mutex<StreamPlayerState>>,
+    /// The current track is digital, so pause/resume go to `stream_state`.
+    streaming: bool,
     stream: Option<StreamState>,
     chunks: Vec<SfxChunk>,
     listener: SoundObject<SfxName>,
     sources: [SoundObject<SfxName>; MIXER_CHANNELS as usize],
     sfx_vol: i32,
     mus_vol: i32,
+    /// Play sfx at their requested pitch rather than all at `NORM_PITCH`.
+    pitch_shift: bool,
 }
 
 /// Acquire `mutex`, run `f` on its guard, and log a warning if the lock
@@ -169,11 +182,11 @@ fn lump_sfx_to_f32(raw_lump: &[u8]) -> Option<Vec<f32>> {
 }
 
 /// Load all sfx lumps from the WAD into in-memory `SfxChunk`s, parallel
-/// to the order of `SFX_INFO_BASE`. Missing or malformed lumps degrade
+/// to the order of `sfx_info()`. Missing or malformed lumps degrade
 /// to empty samples (logged but never fatal); index-stability with
 /// `SfxName as usize` is preserved.
 fn load_sfx_chunks(wad: &WadData) -> Vec<SfxChunk> {
-    let chunks: Vec<SfxChunk> = SFX_INFO_BASE
+    let chunks: Vec<SfxChunk> = sfx_info()
         .iter()
         .map(|s| {
             let name = format!("DS{}", s.name.to_ascii_uppercase());
@@ -259,12 +272,15 @@ impl Snd {
             opl_state: config.opl_state,
             gus_state: config.gus_state,
             music_type: config.music_type,
+            stream_state: Arc::new(Mutex::new(StreamPlayerState::default())),
+            streaming: false,
             stream: None,
             chunks: config.chunks,
             listener: SoundObject::default(),
             sources: [SoundObject::default(); MIXER_CHANNELS as usize],
             sfx_vol: 64,
             mus_vol: 64,
+            pitch_shift: false,
         };
         snd.init_stream();
         snd
@@ -292,7 +308,7 @@ impl Snd {
         let mixer_source = SfxMixerSource::new(Arc::clone(&self.mixer));
         sink.mixer().add(mixer_source);
 
-        // Add both music sources — the inactive one generates silence.
+        // Add all music sources — the inactive ones generate silence.
         // This allows runtime switching between music types.
         let opl_source = OplSource::new(Arc::clone(&self.opl_state));
         sink.mixer().add(opl_source);
@@ -300,6 +316,8 @@ impl Snd {
             let gus_source = GusSource::new(Arc::clone(gus));
             sink.mixer().add(gus_source);
         }
+        let stream_source = StreamSource::new(Arc::clone(&self.stream_state));
+        sink.mixer().add(stream_source);
 
         self.stream = Some(StreamState {
             _sink: sink,
@@ -381,7 +399,8 @@ impl Snd {
                 sfx,
                 x,
                 y,
-            } => self.start_sound(uid, sfx, x, y),
+                pitch,
+            } => self.start_sound(uid, sfx, x, y, pitch),
             SoundAction::UpdateListener {
                 uid,
                 x,
@@ -399,6 +418,7 @@ impl Snd {
             SoundAction::StopMusic => self.stop_music(),
             SoundAction::SetMusicType(t) => self.set_music_type(t),
             SoundAction::SfxVolume(v) => self.set_sfx_volume(v),
+            SoundAction::SfxPitchShift(on) => self.pitch_shift = on,
             SoundAction::MusicVolume(v) => self.set_mus_volume(v),
             SoundAction::Shutdown => {
                 self.shutdown_sound();
@@ -408,7 +428,7 @@ impl Snd {
         true
     }
 
-    fn start_sound(&mut self, uid: usize, sfx: SfxName, mut x: f32, mut y: f32) {
+    fn start_sound(&mut self, uid: usize, sfx: SfxName, mut x: f32, mut y: f32, pitch: u8) {
         if uid == 0 {
             x = self.listener.x;
             y = self.listener.y;
@@ -451,11 +471,14 @@ impl Snd {
             priority: chunk.priority,
         };
 
+        let step = pitch_step(if self.pitch_shift { pitch } else { NORM_PITCH });
         let sources = &mut self.sources;
         with_lock(&self.mixer, "sfx mixer", |mixer| {
             let new_state = || ChannelState {
                 samples: Arc::clone(&chunk.samples),
                 cursor: 0,
+                frac: 0,
+                step,
                 active: true,
                 priority: chunk.priority,
                 pan,
@@ -543,11 +566,36 @@ impl Snd {
         });
     }
 
+    /// Sniff the lump format: MUS is converted and, like MIDI, goes to the
+    /// synthesizer selected by `music_type`; digital formats are streamed.
     fn start_music(&mut self, data: Vec<u8>, looping: bool) {
         if data.is_empty() {
             return;
         }
         let vol = self.mus_vol.clamp(0, 128);
+        let data = match MusicFormat::sniff(&data) {
+            Some(MusicFormat::Mus) => match read_mus_to_midi(&data) {
+                Some(midi) => midi,
+                None => return,
+            },
+            Some(MusicFormat::Midi) => data,
+            Some(format) => {
+                debug!("Streaming {format:?} music");
+                with_lock(&self.stream_state, "music stream", |s| {
+                    if let Err(e) = s.load_music(data, looping) {
+                        warn!("Failed to load {format:?} music: {e}");
+                        return;
+                    }
+                    s.volume = vol;
+                    self.streaming = true;
+                });
+                return;
+            }
+            None => {
+                warn!("Unrecognised music format, not playing");
+                return;
+            }
+        };
         match self.music_type {
             MusicType::GUS => {
                 if let Some(ref gus) = self.gus_state {
@@ -574,6 +622,10 @@ impl Snd {
     }
 
     fn pause_music(&mut self) {
+        if self.streaming {
+            with_lock(&self.stream_state, "music stream", |s| s.pause());
+            return;
+        }
         match self.music_type {
             MusicType::GUS => {
                 if let Some(ref gus) = self.gus_state {
@@ -587,6 +639,10 @@ impl Snd {
     }
 
     fn resume_music(&mut self) {
+        if self.streaming {
+            with_lock(&self.stream_state, "music stream", |s| s.resume());
+            return;
+        }
         match self.music_type {
             MusicType::GUS => {
                 if let Some(ref gus) = self.gus_state {
@@ -610,11 +666,14 @@ impl Snd {
         if let Some(ref gus) = self.gus_state {
             with_lock(gus, "GUS state", |g| g.stop_playback());
         }
+        with_lock(&self.stream_state, "music stream", |s| s.stop());
+        self.streaming = false;
     }
 
     fn set_mus_volume(&mut self, volume: i32) {
         self.mus_vol = volume;
         let clamped = volume.clamp(0, 128);
+        with_lock(&self.stream_state, "music stream", |s| s.volume = clamped);
         match self.music_type {
             MusicType::GUS => {
                 if let Some(ref gus) = self.gus_state {
@@ -632,6 +691,9 @@ impl Snd {
 
     fn set_music_type(&mut self, music_type: MusicType) {
         self.music_type = music_type;
+        with_lock(&self.opl_state, "OPL state", |opl| {
+            opl.set_opl3(music_type == MusicType::OPL3);
+        });
     }
 
     fn shutdown_sound(&mut self) {
@@ -1,7 +1,8 @@
 //! Custom 32-channel SFX mixer.
 //!
-//! All active channels are summed per-frame with per-channel pan and
-//! distance-based volume attenuation. The mixer produces an interleaved
+//! All active channels are summed per-frame with per-channel pan,
+//! distance-based volume attenuation and pitch (a 16.16 fixed-point step
+//! through the samples, linearly interpolated). The mixer produces an interleaved
 //! stereo `f32` stream at the project sample rate; block mixing into a
 //! preallocated scratch buffer matches the cadence used by the OPL and
 //! GUS music sources, so all three audio sources share one structural
@@ -9,7 +10,7 @@
 
 use std::sync::Arc;
 
-use sound_common::MIXER_CHANNELS;
+use sound_common::{MIXER_CHANNELS, NORM_PITCH};
 
 /// Number of stereo frames mixed per block. 512 frames at 44.1 kHz is
 /// ~11.6 ms — below human action-to-sound perception threshold and
@@ -18,6 +19,16 @@ use sound_common::MIXER_CHANNELS;
 const BUFFER_FRAMES: usize = 512;
 /// Number of `f32` samples per block (interleaved L,R).
 pub const BUFFER_SAMPLES: usize = BUFFER_FRAMES * 2;
+/// Fractional bits of `ChannelState::step`.
+const FRAC_BITS: u32 = 16;
+const FRAC_UNIT: u32 = 1 << FRAC_BITS;
+
+/// Sample step for a Doom pitch: `NORM_PITCH` plays as recorded, every 64
+/// above or below doubles or halves the rate (Chocolate Doom's `steptable`).
+pub fn pitch_step(pitch: u8) -> u32 {
+    let octaves = (pitch as f64 - NORM_PITCH as f64) / 64.0;
+    (octaves.exp2() * FRAC_UNIT as f64) as u32
+}
 
 /// Per-channel playback state
 #[derive(Clone)]
@@ -28,6 +39,10 @@ pub struct ChannelState {
     pub samples: Arc<[f32]>,
     /// Current playback position (in mono samples)
     pub cursor: usize,
+    /// Fraction of the way from `cursor` to the next sample, 16.16.
+    pub frac: u32,
+    /// Position advance per output frame, 16.16; see [`pitch_step`].
+    pub step: u32,
     /// Whether this channel is actively playing
     pub active: bool,
     /// Playback priority (higher = harder to evict)
@@ -43,6 +58,8 @@ impl Default for ChannelState {
         Self {
             samples: Arc::from([]),
             cursor: 0,
+            frac: 0,
+            step: FRAC_UNIT,
             active: false,
             priority: 0,
             pan: 0.0,
@@ -101,8 +118,13 @@ impl SfxMixer {
                     ch.active = false;
                     break;
                 }
-                let sample = ch.samples[ch.cursor] * dvol;
-                ch.cursor += 1;
+                let s0 = ch.samples[ch.cursor];
+                let s1 = ch.samples.get(ch.cursor + 1).copied().unwrap_or(s0);
+                let t = ch.frac as f32 / FRAC_UNIT as f32;
+                let sample = (s0 + (s1 - s0) * t) * dvol;
+                ch.frac += ch.step;
+                ch.cursor += (ch.frac >> FRAC_BITS) as usize;
+                ch.frac &= FRAC_UNIT - 1;
                 self.buffer[frame * 2] += sample * left_gain;
                 self.buffer[frame * 2 + 1] += sample * right_gain;
             }
@@ -184,4 +206,36 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn pitch_step_doubles_per_64() {
+        assert_eq!(pitch_step(NORM_PITCH), FRAC_UNIT);
+        assert_eq!(pitch_step(NORM_PITCH + 64), FRAC_UNIT * 2);
+        assert_eq!(pitch_step(NORM_PITCH - 64), FRAC_UNIT / 2);
+        assert!(pitch_step(NORM_PITCH + 16) > FRAC_UNIT);
+    }
+
+    /// A raised pitch runs through the samples faster, interpolating
+    /// between them at fractional positions.
+    #[test]
+    fn pitched_channel_resamples() {
+        let mut mixer = SfxMixer::new();
+        mixer.channels[0].samples = vec![0.0, 1.0, 0.0, 1.0].into();
+        mixer.channels[0].active = true;
+        mixer.channels[0].pan = -1.0;
+
+        mixer.channels[0].step = FRAC_UNIT * 2;
+        mixer.mix_block();
+        assert_eq!(mixer.buffer[0], 0.0);
+        assert_eq!(mixer.buffer[2], 0.0, "second frame skips to sample 2");
+        assert_eq!(mixer.buffer[4], 0.0, "done after two frames");
+        assert!(!mixer.channels[0].active);
+
+        mixer.channels[0].cursor = 0;
+        mixer.channels[0].active = true;
+        mixer.channels[0].step = FRAC_UNIT / 2;
+        mixer.mix_block();
+        let left: Vec<f32> = (0..4).map(|f| mixer.buffer[f * 2]).collect();
+        assert_eq!(left, [0.0, 0.5, 1.0, 0.5]);
+    }
 }
@@ -0,0 +1,134 @@
+//! Digital music playback (OGG Vorbis, FLAC, WAV) via rodio's decoder.
+//!
+//! The decoded track is converted to the stream's fixed stereo
+//! `SAMPLE_RATE` layout and pulled block-wise by `StreamSource`, which sits
+//! in the mixer alongside the OPL and GUS sources and emits silence while
+//! no track is loaded.
+
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+use log::warn;
+use rodio::source::UniformSourceIterator;
+use rodio::{ChannelCount, Decoder, SampleRate};
+use sound_common::SAMPLE_RATE;
+
+const BUFFER_SIZE: usize = 1024;
+
+/// Interleaved stereo samples of the current track.
+type Track = Box<dyn Iterator<Item = f32> + Send>;
+
+pub struct StreamPlayerState {
+    track: Option<Track>,
+    playing: bool,
+    pub volume: i32,
+}
+
+impl Default for StreamPlayerState {
+    fn default() -> Self {
+        Self {
+            track: None,
+            playing: false,
+            volume: 100,
+        }
+    }
+}
+
+impl StreamPlayerState {
+    /// Decode `data` and start playing it from the beginning. A looping
+    /// track restarts when the decoder runs out, a one-shot track unloads.
+    pub fn load_music(&mut self, data: Vec<u8>, looping: bool) -> Result<(), String> {
+        let channels = ChannelCount::new(2).expect("stereo channel count");
+        let rate = SampleRate::new(SAMPLE_RATE).expect("non-zero sample rate");
+        let cursor = Cursor::new(data);
+        let track: Track = if looping {
+            let decoder =
+                Decoder::new_looped(cursor).map_err(|e| format!("Failed to decode music: {e}"))?;
+            Box::new(UniformSourceIterator::new(decoder, channels, rate))
+        } else {
+            let decoder =
+                Decoder::new(cursor).map_err(|e| format!("Failed to decode music: {e}"))?;
+            Box::new(UniformSourceIterator::new(decoder, channels, rate))
+        };
+        self.track = Some(track);
+        self.playing = true;
+        Ok(())
+    }
+
+    /// Continue from where `pause` left off.
+    pub fn resume(&mut self) {
+        self.playing = self.track.is_some();
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Drop the track entirely.
+    pub fn stop(&mut self) {
+        self.track = None;
+        self.playing = false;
+    }
+
+    pub fn generate_samples(&mut self, buffer: &mut [f32]) {
+        let Some(track) = self.track.as_mut().filter(|_| self.playing) else {
+            buffer.fill(0.0);
+            return;
+        };
+        let vol = self.volume as f32 / 128.0;
+        for (i, s) in buffer.iter_mut().enumerate() {
+            match track.next() {
+                Some(sample) => *s = sample * vol,
+                None => {
+                    buffer[i..].fill(0.0);
+                    self.stop();
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Rodio `Source` that plays the decoded digital music track.
+pub struct StreamSource {
+    state: Arc<Mutex<StreamPlayerState>>,
+    /// Reusable interleaved stereo output (`BUFFER_SIZE` samples).
+    buffer: Vec<f32>,
+    cursor: usize,
+}
+
+impl StreamSource {
+    pub fn new(state: Arc<Mutex<StreamPlayerState>>) -> Self {
+        Self {
+            state,
+            buffer: vec![0.0f32; BUFFER_SIZE],
+            cursor: BUFFER_SIZE,
+        }
+    }
+
+    fn refill(&mut self) {
+        match self.state.lock() {
+            Ok(mut state) => state.generate_samples(&mut self.buffer),
+            Err(e) => {
+                warn!("Music stream mutex poisoned, emitting silence: {e}");
+                self.buffer.fill(0.0);
+            }
+        }
+        self.cursor = 0;
+    }
+}
+
+impl Iterator for StreamSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.cursor >= self.buffer.len() {
+            self.refill();
+        }
+        let sample = self.buffer[self.cursor];
+        self.cursor += 1;
+        Some(sample)
+    }
+}
+
+impl_stereo_source!(StreamSource);
@@ -0,0 +1,62 @@
+#!/usr/bin/env bash
+# Find where two builds desync on the same demo.
+#
+# Plays the demo headless under both binaries (e.g. a default build against a
+# `--features fixed64` build), compares the per-tic traces, then replays just
+# the first diverging tic with the full state dump and prints a field-level
+# diff of the sectors and thinkers that differ.
+#
+# Usage: demo-divergence.sh BIN_A BIN_B [demo]   (demo defaults to demo1)
+# Requires doom1.wad. Set IWAD to its path (default: data/doom1.wad, then
+# ~/DOOM/doom1.wad). Exits 0 if the demos match, 1 if they diverge.
+set -uo pipefail
+
+if [[ $# -lt 2 ]]; then
+    echo "usage: $0 BIN_A BIN_B [demo]"
+    exit 2
+fi
+BIN_A="$1"
+BIN_B="$2"
+DEMO="${3:-demo1}"
+
+ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+if [[ -z "${IWAD:-}" ]]; then
+    if [[ -f "$ROOT/data/doom1.wad" ]]; then
+        IWAD="$ROOT/data/doom1.wad"
+    else
+        IWAD="$HOME/DOOM/doom1.wad"
+    fi
+fi
+if [[ ! -f "$IWAD" ]]; then
+    echo "IWAD not found at $IWAD (set IWAD=...)"
+    exit 2
+fi
+TMP="$(mktemp -d)"
+trap 'rm -rf "$TMP"' EXIT
+
+# $1 binary, $2 output name, remaining args are env assignments.
+play() {
+    local bin="$1" out="$2"
+    shift 2
+    env "$@" "$bin" --iwad "$IWAD" --demo "$DEMO" --headless >"$TMP/$out.log" 2>&1
+}
+
+play "$BIN_A" a DEMO_TRACE="$TMP/a.txt"
+play "$BIN_B" b DEMO_TRACE="$TMP/b.txt"
+
+if report=$("$BIN_A" --trace-diff "$TMP/a.txt" --trace-diff "$TMP/b.txt"); then
+    echo "$DEMO: no divergence ($(wc -l <"$TMP/a.txt") tics)"
+    exit 0
+fi
+echo "$report"
+
+tic=$(sed -n 's/^first divergence at tic \([0-9]*\).*/\1/p' <<<"$report")
+if [[ -z "$tic" ]]; then
+    exit 1
+fi
+echo
+echo "State at tic $tic:"
+play "$BIN_A" a-dump DEMO_TRACE_DUMP="$TMP/a.dump" DEMO_TRACE_FROM="$tic" DEMO_TRACE_TO="$tic"
+play "$BIN_B" b-dump DEMO_TRACE_DUMP="$TMP/b.dump" DEMO_TRACE_FROM="$tic" DEMO_TRACE_TO="$tic"
+"$BIN_A" --trace-diff "$TMP/a.dump" --trace-diff "$TMP/b.dump"
+exit 1
@@ -193,7 +193,10 @@ pub fn write_info_file(data: Data, path: PathBuf) {
     {
         let mut actor_actions: Vec<&str> = Vec::new();
         let mut player_actions: Vec<&str> = Vec::new();
-        for id in &data.action_ids {
+        // MBF21 codepointers are only reachable through DEHACKED
+        let mbf21 = MBF21_FUNCS.iter().map(|f| validate_field(f));
+        let action_ids: Vec<String> = data.action_ids.iter().cloned().chain(mbf21).collect();
+        for id in &action_ids {
             let variant = id.trim_start_matches("ActionId::");
             if variant.starts_with('A') {
                 actor_actions.push(variant);
@@ -387,6 +390,15 @@ pub fn validate_field(input: &str) -> String {
         let mut tmp = "SfxName::".to_string();
         tmp.push_str(capitalize(input.trim_start_matches("sfx_")).as_str());
         tmp
+    } else if input.starts_with("MF2_") {
+        let parts: Vec<String> = input
+            .split('|')
+            .map(|mf| {
+                let append = mf.trim_start_matches("MF2_").to_ascii_lowercase();
+                format!("MapObjFlag2::{}.bits()", capitalize(&append))
+            })
+            .collect();
+        format!("MapObjFlag2::from_bits_truncate({})", parts.join(" | "))
     } else if input.starts_with("MF_") {
         // Flag — use from_bits_truncate(A.bits() | B.bits()) so it's valid in const
         // contexts
@@ -420,10 +432,43 @@ fn capitalize(s: &str) -> String {
     }
 }
 
-const PLAYER_FUNCS: [&str; 22] = [
+const MBF21_FUNCS: [&str; 28] = [
+    "A_SpawnObject",
+    "A_MonsterProjectile",
+    "A_MonsterBulletAttack",
+    "A_MonsterMeleeAttack",
+    "A_RadiusDamage",
+    "A_NoiseAlert",
+    "A_HealChase",
+    "A_SeekTracer",
+    "A_FindTracer",
+    "A_ClearTracer",
+    "A_JumpIfHealthBelow",
+    "A_JumpIfTargetInSight",
+    "A_JumpIfTargetCloser",
+    "A_JumpIfTracerInSight",
+    "A_JumpIfTracerCloser",
+    "A_JumpIfFlagsSet",
+    "A_AddFlags",
+    "A_RemoveFlags",
+    "A_WeaponProjectile",
+    "A_WeaponBulletAttack",
+    "A_WeaponMeleeAttack",
+    "A_WeaponSound",
+    "A_WeaponAlert",
+    "A_WeaponJump",
+    "A_ConsumeAmmo",
+    "A_CheckAmmo",
+    "A_RefireTo",
+    "A_GunFlashTo",
+];
+
+const PLAYER_FUNCS: [&str; 32] = [
     "a_bfgsound",
+    "a_checkammo",
     "a_checkreload",
     "a_closeshotgun2",
+    "a_consumeammo",
     "a_firebfg",
     "a_firecgun",
     "a_firemissile",
@@ -432,6 +477,7 @@ const PLAYER_FUNCS: [&str; 22] = [
     "a_fireshotgun",
     "a_fireshotgun2",
     "a_gunflash",
+    "a_gunflashto",
     "a_light0",
     "a_light1",
     "a_light2",
@@ -441,6 +487,13 @@ const PLAYER_FUNCS: [&str; 22] = [
     "a_punch",
     "a_raise",
     "a_refire",
+    "a_refireto",
     "a_saw",
+    "a_weaponalert",
+    "a_weaponbulletattack",
+    "a_weaponjump",
+    "a_weaponmeleeattack",
+    "a_weaponprojectile",
     "a_weaponready",
+    "a_weaponsound",
 ];
@@ -12,6 +12,7 @@ pub fn state_to_string(name: &str, info: &InfoType) -> String {
         next_state: {next_state},
         misc1: {misc1},
         misc2: {misc2},
+        args: [0; 8],
     }},"#,
         sprite = info.get("sprite").expect("State requires sprite name"),
         frame = info.get("frame").expect("State requires frame"),
@@ -71,6 +72,13 @@ pub fn info_to_string(name: &str, info: &InfoType) -> String {
         activesound: {activesound},
         flags: {flags},
         raisestate: {raisestate},
+        flags2: {flags2},
+        infighting_group: {infighting_group},
+        projectile_group: {projectile_group},
+        splash_group: {splash_group},
+        ripsound: SfxName::None,
+        fastspeed: 0,
+        meleerange: MELEERANGE,
     }},"#,
         doomednum = info.get("doomednum").unwrap_or(&"-1".to_string()),
         spawnstate = info
@@ -140,6 +148,12 @@ pub fn info_to_string(name: &str, info: &InfoType) -> String {
             .get("raisestate")
             .map(|n| if n == "0" { "StateNum::None" } else { n })
             .unwrap_or("StateNum::None"),
+        flags2 = info
+            .get("flags2")
+            .unwrap_or(&"MapObjFlag2::empty()".to_string()),
+        infighting_group = info.get("infighting_group").unwrap_or(&"0".to_string()),
+        projectile_group = info.get("projectile_group").unwrap_or(&"0".to_string()),
+        splash_group = info.get("splash_group").unwrap_or(&"0".to_string()),
     )
 }
 
@@ -5,7 +5,8 @@ pub const FILE_HEADER_STR: &str = r#"//! THIS FILE IS GENERATED WITH MULTIGEN
 "#;
 
 pub const MOBJ_INFO_HEADER_STR: &str = r#"
-use crate::thing::MapObjFlag;
+use crate::doom_def::MELEERANGE;
+use crate::thing::{MapObjFlag, MapObjFlag2};
 use sound_common::SfxName;
 "#;
 
@@ -39,6 +40,21 @@ pub struct MapObjInfo {
     pub activesound: SfxName,
     pub flags: MapObjFlag,
     pub raisestate: StateNum,
+    /// MBF21 thing flags
+    pub flags2: MapObjFlag2,
+    /// MBF21: things in the same group never infight. 0 is ungrouped
+    pub infighting_group: i32,
+    /// MBF21: projectiles can't hurt things in the same group. 0 falls back
+    /// to same-species immunity, -1 hurts everything
+    pub projectile_group: i32,
+    /// MBF21: splash damage can't hurt things in the same group
+    pub splash_group: i32,
+    /// MBF21: played when a `RIP` projectile tears through a thing
+    pub ripsound: SfxName,
+    /// MBF21: raw speed on `-fast`, 0 to use `speed`
+    pub fastspeed: i32,
+    /// MBF21: melee reach in map units
+    pub meleerange: i32,
 }
 "#;
 
@@ -90,7 +106,7 @@ impl From<u16> for StateNum {
 
 pub const STATE_ARRAY_STR: &str = r#"
 /// State data without function pointers — safe for static arrays.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StateData {
     /// Sprite to use
     pub sprite: SpriteNum,
@@ -104,11 +120,13 @@ pub struct StateData {
     pub next_state: StateNum,
     pub misc1: i32,
     pub misc2: i32,
+    /// MBF21 codepointer arguments
+    pub args: [i32; 8],
 }
 
 const NUM_STATES: usize = StateNum::Count as usize;
 pub static STATES: [StateData; NUM_STATES] = [
-    // StateData { sprite, frame, tics, action, next_state, misc1, misc2 }"#;
+    // StateData { sprite, frame, tics, action, next_state, misc1, misc2, args }"#;
 
 pub const MKIND_ENUM_HEADER: &str = r#"
 #[repr(u16)]
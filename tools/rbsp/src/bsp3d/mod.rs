@@ -13,7 +13,7 @@ pub mod movers;
 
 /// Bump when the builder's output changes for identical input — the engine
 /// keys its lump cache on this, so stale caches rebuild.
-pub const BUILDER_REVISION: u32 = 4;
+pub const BUILDER_REVISION: u32 = 5;
 
 pub use builder::{Bsp3dBuilder, HEIGHT_EPSILON, QUANT_PRECISION};
 pub use derive::LeafBounds;
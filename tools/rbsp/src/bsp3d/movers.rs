@@ -23,6 +23,10 @@ const EDGE_INSERT_EPSILON: f32 = 1.0;
 /// connect wall vertices to floor/ceiling polygon vertices.
 type VertexMap = HashMap<QuantizedVec2, HashMap<usize, usize>>;
 
+/// BOOM linedef special drawing its tagged sectors with the heights of the
+/// line's front sector.
+pub const TRANSFER_HEIGHTS: u32 = 242;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MoverKind {
     Floor,
@@ -49,8 +53,12 @@ impl MoverKind {
 /// [`crate::special_encode`]), so this decodes the generalized special and
 /// maps its category to which surface(s) move: floors/lifts/stairs raise the
 /// floor, ceilings/doors/crushers move the ceiling, and the composite
-/// vanilla-40 moves both.
+/// vanilla-40 moves both. BOOM's transfer heights (242) counts as both too:
+/// its sectors are drawn at the control sector's heights.
 pub fn classify_special(special: u32) -> Option<MoverKind> {
+    if special == TRANSFER_HEIGHTS {
+        return Some(MoverKind::Both);
+    }
     let spec = special_encode::decode(special)?;
     if spec.composite {
         return Some(MoverKind::Both);
@@ -0,0 +1,188 @@
+//! Adopt a map's pre-built GL nodes (`XGLN`/`XGL2`/`ZGLN`/`ZGL2`) as a
+//! [`BspOutput`], in place of [`crate::build_bsp`].
+//!
+//! GL subsectors are already closed convex outlines (their minisegs run along
+//! the partition lines), so they stand in for the carved leaf polygons as-is.
+//! Only the real segs are kept as segs; the outline keeps every vertex.
+
+use crate::polygon::signed_area;
+use crate::types::*;
+use wad::extended::{WadExtendedMap, WadGlSegment};
+use wad::types::WadNode;
+
+/// Build the output from GL nodes, `None` when they do not fit the map.
+///
+/// That is: not a GL variant, a different original vertex count, an index out
+/// of range, or a subsector with no real seg to take its sector from. The
+/// caller then falls back to building.
+#[allow(
+    trivial_numeric_casts,
+    reason = "f64 -> Float is identity only when Float = f64"
+)]
+pub fn output_from_gl_nodes<V, L, S, SE>(
+    input: &BspInput<V, L, S, SE>,
+    gl: &WadExtendedMap,
+) -> Option<BspOutput>
+where
+    V: VertexCoords,
+    L: LineDefAccess,
+    S: SideDefAccess,
+{
+    if !gl.node_type.is_gl() || gl.num_org_vertices != input.vertices.len() {
+        return None;
+    }
+
+    let vertices: Vec<Vertex> = input
+        .vertices
+        .iter()
+        .map(|v| Vertex {
+            x: v.x_f64() as Float,
+            y: v.y_f64() as Float,
+        })
+        .chain(gl.vertexes.iter().map(|v| Vertex {
+            x: v.x as Float,
+            y: v.y as Float,
+        }))
+        .collect();
+
+    let mut segs = Vec::with_capacity(gl.gl_segments.len());
+    let mut subsectors = Vec::with_capacity(gl.subsectors.len());
+    let mut poly_indices = Vec::with_capacity(gl.gl_segments.len());
+    for ss in &gl.subsectors {
+        let start = ss.start_seg as usize;
+        let ring = gl.gl_segments.get(start..start + ss.seg_count as usize)?;
+
+        let first_vertex = poly_indices.len() as u32;
+        let first_seg = segs.len() as u32;
+        for gs in ring {
+            if gs.start_vertex as usize >= vertices.len() {
+                return None;
+            }
+            poly_indices.push(gs.start_vertex);
+            if gs.linedef.is_some() {
+                segs.push(seg_from_gl(gs, input, &vertices)?);
+            }
+        }
+        let num_segs = segs.len() as u32 - first_seg;
+        let sector = segs.get(first_seg as usize)?.sector as u32;
+
+        // GL outlines wind clockwise, the 3D builder expects counter-clockwise.
+        let outline = &mut poly_indices[first_vertex as usize..];
+        if signed_area(outline, &vertices) < 0.0 {
+            outline.reverse();
+        }
+
+        subsectors.push(SubSector {
+            sector,
+            polygon: ConvexPoly {
+                first_vertex,
+                num_vertices: ring.len() as u32,
+                first_edge: 0,
+            },
+            first_seg,
+            num_segs,
+            seg_indices: (first_seg..first_seg + num_segs).collect(),
+        });
+    }
+
+    let nodes = gl
+        .nodes
+        .iter()
+        .map(|n| node_from_gl(n, gl.nodes.len(), subsectors.len()))
+        .collect::<Option<Vec<_>>>()?;
+    let root = if nodes.is_empty() {
+        IS_SSECTOR_MASK
+    } else {
+        nodes.len() as u32 - 1
+    };
+
+    Some(BspOutput {
+        vertices,
+        num_original_verts: input.vertices.len(),
+        segs,
+        subsectors,
+        nodes,
+        root,
+        poly_indices,
+    })
+}
+
+/// A real GL seg as a builder seg: sector from its sidedef, offset measured
+/// from the linedef end it starts at, direction from the linedef.
+fn seg_from_gl<V, L, S, SE>(
+    gs: &WadGlSegment,
+    input: &BspInput<V, L, S, SE>,
+    vertices: &[Vertex],
+) -> Option<Seg>
+where
+    L: LineDefAccess,
+    S: SideDefAccess,
+{
+    let linedef = gs.linedef? as usize;
+    let ld = input.linedefs.get(linedef)?;
+    let (side, sidedef, origin) = if gs.side == 0 {
+        (Side::Front, ld.front_sidedef_idx()?, ld.start_vertex_idx())
+    } else {
+        (Side::Back, ld.back_sidedef_idx()?, ld.end_vertex_idx())
+    };
+    let sector = input.sidedefs.get(sidedef)?.sector_idx();
+
+    let (start, end) = (gs.start_vertex as usize, gs.end_vertex as usize);
+    let (a, b) = (vertices.get(start)?, vertices.get(end)?);
+    let o = vertices.get(origin)?;
+    let (v1, v2) = (
+        vertices.get(ld.start_vertex_idx())?,
+        vertices.get(ld.end_vertex_idx())?,
+    );
+    let (mut dx, mut dy) = (v2.x - v1.x, v2.y - v1.y);
+    if side == Side::Back {
+        (dx, dy) = (-dx, -dy);
+    }
+    let (sx, sy) = (b.x - a.x, b.y - a.y);
+
+    Some(Seg {
+        start,
+        end,
+        linedef,
+        side,
+        sector,
+        offset: (a.x - o.x).hypot(a.y - o.y),
+        angle: sy.atan2(sx),
+        dx,
+        dy,
+        len: sx.hypot(sy),
+        dir_len: dx.hypot(dy),
+        linedef_v1: ld.start_vertex_idx(),
+    })
+}
+
+/// GL node to builder node. Children use the same bit-31 subsector flag; the
+/// bounding boxes are top, bottom, left, right.
+fn node_from_gl(n: &WadNode, num_nodes: usize, num_subsectors: usize) -> Option<Node> {
+    for child in n.children {
+        let (index, limit) = if child & IS_SSECTOR_MASK != 0 {
+            (child & !IS_SSECTOR_MASK, num_subsectors)
+        } else {
+            (child, num_nodes)
+        };
+        if index as usize >= limit {
+            return None;
+        }
+    }
+    let bbox = |b: [i16; 4]| BBox {
+        min_x: b[2] as Float,
+        min_y: b[1] as Float,
+        max_x: b[3] as Float,
+        max_y: b[0] as Float,
+    };
+    Some(Node {
+        x: n.x as Float,
+        y: n.y as Float,
+        dx: n.dx as Float,
+        dy: n.dy as Float,
+        bbox_right: bbox(n.bboxes[0]),
+        bbox_left: bbox(n.bboxes[1]),
+        child_right: n.children[0],
+        child_left: n.children[1],
+    })
+}
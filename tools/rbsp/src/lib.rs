@@ -27,6 +27,8 @@
 #[cfg(feature = "wad-types")]
 pub mod blockmap;
 pub mod bsp3d;
+#[cfg(feature = "wad-types")]
+pub mod gl_nodes;
 pub mod node;
 pub mod picknode;
 pub mod polygon;
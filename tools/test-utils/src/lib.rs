@@ -46,6 +46,57 @@ pub fn kvx_path(name: &str) -> PathBuf {
     user_wad_dir().join("cheello_voxels/voxels").join(name)
 }
 
+/// GL nodes, without the 4 byte signature, for a 256x128 room.
+///
+/// The room has vertices `(0,0) (0,128) (256,128) (256,0)` and linedefs
+/// running clockwise between them. The partition at x=128 lies on no
+/// linedef: new vertices 4 and 5 at its ends, an east and a west subsector
+/// each closed by a miniseg, and one node. `wide` gives the `XGL2` seg layout
+/// with `u32` linedefs.
+pub fn gl_room_nodes(wide: bool) -> Vec<u8> {
+    let mut d = Vec::new();
+    d.extend_from_slice(&4u32.to_le_bytes());
+    d.extend_from_slice(&2u32.to_le_bytes());
+    for (x, y) in [(128i32, 0i32), (128, 128)] {
+        d.extend_from_slice(&(x << 16).to_le_bytes());
+        d.extend_from_slice(&(y << 16).to_le_bytes());
+    }
+    d.extend_from_slice(&2u32.to_le_bytes());
+    d.extend_from_slice(&4u32.to_le_bytes());
+    d.extend_from_slice(&4u32.to_le_bytes());
+    d.extend_from_slice(&8u32.to_le_bytes());
+    let mini = u32::MAX;
+    let segs: [(u32, u32); 8] = [
+        // East: up the partition, then the walls clockwise.
+        (4, mini),
+        (5, 1),
+        (2, 2),
+        (3, 3),
+        // West.
+        (0, 0),
+        (1, 1),
+        (5, mini),
+        (4, 3),
+    ];
+    for (v1, line) in segs {
+        d.extend_from_slice(&v1.to_le_bytes());
+        d.extend_from_slice(&u32::MAX.to_le_bytes());
+        if wide {
+            d.extend_from_slice(&line.to_le_bytes());
+        } else {
+            d.extend_from_slice(&(line as u16).to_le_bytes());
+        }
+        d.push(0);
+    }
+    d.extend_from_slice(&1u32.to_le_bytes());
+    for v in [128i16, 0, 0, 128, 128, 0, 128, 256, 128, 0, 0, 128] {
+        d.extend_from_slice(&v.to_le_bytes());
+    }
+    d.extend_from_slice(&0x8000_0000u32.to_le_bytes());
+    d.extend_from_slice(&0x8000_0001u32.to_le_bytes());
+    d
+}
+
 /// Point the engine's rbsp sidecar cache at a per-process temp dir so tests
 /// never read stale entries from (or pollute) the user's real cache. Repeat
 /// loads within one test binary still exercise the cache-hit path.
@@ -94,6 +94,33 @@ pub fn draw_patch(
     sy: f32,
     palette: &WadPalette,
     pixels: &mut impl DrawBuffer,
+) {
+    draw_patch_columns(patch, x, y, sx, sy, palette, false, pixels);
+}
+
+/// Draw a WadPatch mirrored left to right, as for sprite rotations stored
+/// once for both sides. The left offset is applied as for [`draw_patch`].
+pub fn draw_patch_flipped(
+    patch: &WadPatch,
+    x: f32,
+    y: f32,
+    sx: f32,
+    sy: f32,
+    palette: &WadPalette,
+    pixels: &mut impl DrawBuffer,
+) {
+    draw_patch_columns(patch, x, y, sx, sy, palette, true, pixels);
+}
+
+fn draw_patch_columns(
+    patch: &WadPatch,
+    x: f32,
+    y: f32,
+    sx: f32,
+    sy: f32,
+    palette: &WadPalette,
+    flip: bool,
+    pixels: &mut impl DrawBuffer,
 ) {
     let buf_w = pixels.size().width();
     let buf_h = pixels.size().height();
@@ -101,8 +128,13 @@ pub fn draw_patch(
     let mut src_col: u32 = 0;
 
     for column in &patch.columns {
-        let col_x_start = (x_base + src_col as f32 * sx).floor() as i32;
-        let col_x_end = (x_base + (src_col + 1) as f32 * sx).floor() as i32;
+        let dst_col = if flip {
+            u32::from(patch.width).saturating_sub(src_col + 1)
+        } else {
+            src_col
+        };
+        let col_x_start = (x_base + dst_col as f32 * sx).floor() as i32;
+        let col_x_end = (x_base + (dst_col + 1) as f32 * sx).floor() as i32;
         let col_y = y + column.y_offset as f32 * sy;
 
         for (src_row, p) in column.pixels.iter().enumerate() {
@@ -287,6 +319,8 @@ pub struct HUDString {
     line_height: i32,
     current_char: usize,
     space_width: i32,
+    /// 0xRRGGBB tint for the whole string, see [`draw_patch_tinted`]
+    tint: Option<u32>,
 }
 
 impl HUDString {
@@ -296,6 +330,7 @@ impl HUDString {
             line_height: 10,
             current_char: 0,
             space_width: 4,
+            tint: None,
         }
     }
 
@@ -307,6 +342,7 @@ impl HUDString {
             line_height: 10,
             current_char: 0,
             space_width: 4,
+            tint: None,
         }
     }
 
@@ -345,9 +381,15 @@ impl HUDString {
         self.current_char = self.data.len();
     }
 
+    /// Draw in the colour `tint` (0xRRGGBB) until the next `clear`
+    pub fn set_tint(&mut self, tint: u32) {
+        self.tint = Some(tint);
+    }
+
     pub fn clear(&mut self) {
         self.current_char = 0;
         self.data.clear();
+        self.tint = None;
     }
 
     pub fn draw_pixels(
@@ -398,15 +440,11 @@ impl HUDString {
                 return None;
             }
 
-            draw_patch(
-                patch,
-                x,
-                y + self.line_height as f32 * sy - patch.height as f32 * sy,
-                sx,
-                sy,
-                palette,
-                pixels,
-            );
+            let y = y + self.line_height as f32 * sy - patch.height as f32 * sy;
+            match self.tint {
+                Some(tint) => draw_patch_tinted(patch, x, y, sx, sy, palette, tint, pixels),
+                None => draw_patch(patch, x, y, sx, sy, palette, pixels),
+            }
             x += patch.width as f32 * sx;
         }
         Some(())
@@ -0,0 +1,683 @@
+//! The automap: a top-down line drawing of the level shown in place of the
+//! player view.
+//!
+//! Lines are coloured with the OG Doom rules — one-sided and secret walls red,
+//! floor changes brown, ceiling changes yellow — and only once seen, unless
+//! the computer map power reveals the rest in grey. Vanilla keys: Tab toggles,
+//! `-`/`=` zoom, `0` shows the whole map, `f` follow, `g` grid, `m` mark,
+//! `c` clear marks, and the arrows pan when not following. The map label and
+//! author from UMAPINFO, or else the stock level name, are drawn in the
+//! bottom-left corner.
+
+use game_config::GameMission;
+use gameplay::{PowerType, deh_named, english};
+use gamestate_traits::{
+    Action, ConfigKey, ConfigTraits, GameTraits, Input, KeyCode, SubsystemTrait,
+};
+use hud_util::{draw_patch, draw_text_line, hud_scale, load_char_patches, load_num_sprites};
+use level::{LevelData, LineDef, LineDefFlags};
+use math::FRACUNIT;
+use render_common::{DrawBuffer, STBAR_HEIGHT};
+use wad::WadData;
+use wad::types::{WadPalette, WadPatch};
+use wad::umapinfo::{LabelKind, MapEntry};
+
+const BACKGROUND: u8 = 0;
+/// REDS
+const WALL_COLOUR: u8 = 176;
+/// REDS + REDRANGE / 2
+const TELEPORT_COLOUR: u8 = 184;
+/// BROWNS
+const FLOOR_CHANGE_COLOUR: u8 = 64;
+/// YELLOWS
+const CEILING_CHANGE_COLOUR: u8 = 231;
+/// GRAYS + 3, lines revealed by the computer map but not yet seen
+const UNSEEN_COLOUR: u8 = 99;
+/// GRAYS + GRAYSRANGE / 2
+const GRID_COLOUR: u8 = 104;
+/// WHITE
+const PLAYER_COLOUR: u8 = 209;
+/// GRAYS
+const CROSSHAIR_COLOUR: u8 = 96;
+
+const TELEPORT_SPECIAL: u32 = 39;
+const MAP_BLOCK_UNITS: f32 = 128.0;
+const PLAYER_RADIUS: f32 = 16.0;
+const NUM_MARKS: usize = 10;
+/// OG starts zoomed in a little from the whole-map view.
+const INITIAL_ZOOM: f32 = 1.0 / 0.7;
+const ZOOM_STEP: f32 = 1.25;
+/// Fraction of the view width or height moved per pan keypress.
+const PAN_STEP: f32 = 0.125;
+const TITLE_LINE_HEIGHT: f32 = 8.0;
+
+const ARROW_R: f32 = 8.0 * PLAYER_RADIUS / 7.0;
+/// OG `player_arrow`, pointing along +x in map units.
+const PLAYER_ARROW: [[MapPoint; 2]; 7] = [
+    [
+        MapPoint::new(-ARROW_R + ARROW_R / 8.0, 0.0),
+        MapPoint::new(ARROW_R, 0.0),
+    ],
+    [
+        MapPoint::new(ARROW_R, 0.0),
+        MapPoint::new(ARROW_R - ARROW_R / 2.0, ARROW_R / 4.0),
+    ],
+    [
+        MapPoint::new(ARROW_R, 0.0),
+        MapPoint::new(ARROW_R - ARROW_R / 2.0, -ARROW_R / 4.0),
+    ],
+    [
+        MapPoint::new(-ARROW_R + ARROW_R / 8.0, 0.0),
+        MapPoint::new(-ARROW_R - ARROW_R / 8.0, ARROW_R / 4.0),
+    ],
+    [
+        MapPoint::new(-ARROW_R + ARROW_R / 8.0, 0.0),
+        MapPoint::new(-ARROW_R - ARROW_R / 8.0, -ARROW_R / 4.0),
+    ],
+    [
+        MapPoint::new(-ARROW_R + 3.0 * ARROW_R / 8.0, 0.0),
+        MapPoint::new(-ARROW_R + ARROW_R / 8.0, ARROW_R / 4.0),
+    ],
+    [
+        MapPoint::new(-ARROW_R + 3.0 * ARROW_R / 8.0, 0.0),
+        MapPoint::new(-ARROW_R + ARROW_R / 8.0, -ARROW_R / 4.0),
+    ],
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct MapPoint {
+    x: f32,
+    y: f32,
+}
+
+impl MapPoint {
+    const fn new(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+}
+
+/// A linedef to draw this tic, with its palette colour.
+struct AutomapLine {
+    v1: MapPoint,
+    v2: MapPoint,
+    colour: u8,
+}
+
+/// Map-to-screen transform for one draw.
+struct MapView {
+    centre: MapPoint,
+    scale: f32,
+    width: f32,
+    height: f32,
+}
+
+impl MapView {
+    fn to_screen(&self, p: MapPoint) -> (f32, f32) {
+        (
+            self.width / 2.0 + (p.x - self.centre.x) * self.scale,
+            self.height / 2.0 - (p.y - self.centre.y) * self.scale,
+        )
+    }
+
+    /// Map-space corners of the view, bottom-left then top-right.
+    fn bounds(&self) -> (MapPoint, MapPoint) {
+        let half_w = self.width / 2.0 / self.scale;
+        let half_h = self.height / 2.0 / self.scale;
+        (
+            MapPoint::new(self.centre.x - half_w, self.centre.y - half_h),
+            MapPoint::new(self.centre.x + half_w, self.centre.y + half_h),
+        )
+    }
+}
+
+pub struct Automap {
+    palette: WadPalette,
+    mark_nums: [WadPatch; 10],
+    active: bool,
+    following: bool,
+    grid: bool,
+    bar_mode: bool,
+    /// Scale relative to fitting the whole map in view.
+    zoom: f32,
+    /// Map-space point at the centre of the view.
+    centre: MapPoint,
+    /// Zoom and centre to restore when leaving the whole-map view.
+    saved_view: Option<(f32, MapPoint)>,
+    /// Pixels per map unit at the last draw, for sizing pan steps.
+    scale: f32,
+    view_width: f32,
+    view_height: f32,
+    marks: [Option<MapPoint>; NUM_MARKS],
+    next_mark: usize,
+    map_name: String,
+    title: String,
+    author: Option<String>,
+    min: MapPoint,
+    max: MapPoint,
+    grid_origin: MapPoint,
+    lines: Vec<AutomapLine>,
+    player: MapPoint,
+    player_angle: f32,
+}
+
+impl Automap {
+    pub fn new(wad: &WadData) -> Self {
+        load_char_patches(wad);
+        let palette = wad.lump_iter::<WadPalette>("PLAYPAL").next().unwrap();
+
+        Self {
+            palette,
+            mark_nums: load_num_sprites("AMMNUM", 0, wad),
+            active: false,
+            following: true,
+            grid: false,
+            bar_mode: false,
+            zoom: INITIAL_ZOOM,
+            centre: MapPoint::default(),
+            saved_view: None,
+            scale: 1.0,
+            view_width: 320.0,
+            view_height: 168.0,
+            marks: [None; NUM_MARKS],
+            next_mark: 0,
+            map_name: String::new(),
+            title: String::new(),
+            author: None,
+            min: MapPoint::default(),
+            max: MapPoint::default(),
+            grid_origin: MapPoint::default(),
+            lines: Vec::new(),
+            player: MapPoint::default(),
+            player_angle: 0.0,
+        }
+    }
+
+    fn set_active(&mut self, active: bool, game: &mut impl GameTraits) {
+        self.active = active;
+        game.set_automap_active(active);
+    }
+
+    /// AM_LevelInit
+    fn level_init(
+        &mut self,
+        level: &LevelData,
+        map_name: String,
+        entry: Option<&MapEntry>,
+        mission: GameMission,
+    ) {
+        let extents = level.get_map_extents();
+        self.min = MapPoint::new(extents.min_vertex.x, extents.min_vertex.y);
+        self.max = MapPoint::new(extents.max_vertex.x, extents.max_vertex.y);
+        let blockmap = level.blockmap();
+        self.grid_origin = MapPoint::new(
+            blockmap.x_origin as f32 / FRACUNIT as f32,
+            blockmap.y_origin as f32 / FRACUNIT as f32,
+        );
+        self.centre = MapPoint::new(
+            f32::midpoint(self.min.x, self.max.x),
+            f32::midpoint(self.min.y, self.max.y),
+        );
+        self.zoom = INITIAL_ZOOM;
+        self.saved_view = None;
+        self.marks = [None; NUM_MARKS];
+        self.next_mark = 0;
+        (self.title, self.author) = map_title(&map_name, entry, level_name(&map_name, mission));
+        self.map_name = map_name;
+    }
+
+    fn collect_lines(&mut self, level: &LevelData, allmap: bool) {
+        self.lines.clear();
+        for line in level.linedefs.iter() {
+            if let Some(colour) = line_colour(line, allmap) {
+                self.lines.push(AutomapLine {
+                    v1: MapPoint::new(line.v1.pos.x, line.v1.pos.y),
+                    v2: MapPoint::new(line.v2.pos.x, line.v2.pos.y),
+                    colour,
+                });
+            }
+        }
+    }
+
+    fn pan(&mut self, dx: f32, dy: f32) {
+        self.centre.x += dx * self.view_width / self.scale;
+        self.centre.y += dy * self.view_height / self.scale;
+        self.saved_view = None;
+    }
+
+    fn zoom_by(&mut self, factor: f32) {
+        self.zoom *= factor;
+        self.saved_view = None;
+    }
+
+    /// AM_GOBIGKEY: toggle between the whole map and the previous view.
+    fn toggle_whole_map(&mut self) {
+        if let Some((zoom, centre)) = self.saved_view.take() {
+            self.zoom = zoom;
+            self.centre = centre;
+        } else {
+            self.saved_view = Some((self.zoom, self.centre));
+            self.zoom = 1.0;
+            self.centre = MapPoint::new(
+                f32::midpoint(self.min.x, self.max.x),
+                f32::midpoint(self.min.y, self.max.y),
+            );
+        }
+    }
+
+    fn add_mark(&mut self) {
+        self.marks[self.next_mark] = Some(self.centre);
+        self.next_mark = (self.next_mark + 1) % NUM_MARKS;
+    }
+
+    fn clear_marks(&mut self) {
+        self.marks = [None; NUM_MARKS];
+        self.next_mark = 0;
+    }
+
+    fn draw_grid(&self, view: &MapView, buffer: &mut impl DrawBuffer) {
+        let colour = self.palette.0[GRID_COLOUR as usize];
+        let (low, high) = view.bounds();
+
+        let mut x = self.grid_origin.x
+            + ((low.x - self.grid_origin.x) / MAP_BLOCK_UNITS).ceil() * MAP_BLOCK_UNITS;
+        while x < high.x {
+            let a = view.to_screen(MapPoint::new(x, low.y));
+            let b = view.to_screen(MapPoint::new(x, high.y));
+            draw_line(a, b, view, colour, buffer);
+            x += MAP_BLOCK_UNITS;
+        }
+
+        let mut y = self.grid_origin.y
+            + ((low.y - self.grid_origin.y) / MAP_BLOCK_UNITS).ceil() * MAP_BLOCK_UNITS;
+        while y < high.y {
+            let a = view.to_screen(MapPoint::new(low.x, y));
+            let b = view.to_screen(MapPoint::new(high.x, y));
+            draw_line(a, b, view, colour, buffer);
+            y += MAP_BLOCK_UNITS;
+        }
+    }
+
+    fn draw_player(&self, view: &MapView, buffer: &mut impl DrawBuffer) {
+        let colour = self.palette.0[PLAYER_COLOUR as usize];
+        let (sin, cos) = self.player_angle.sin_cos();
+        let place = |p: MapPoint| {
+            view.to_screen(MapPoint::new(
+                self.player.x + p.x * cos - p.y * sin,
+                self.player.y + p.x * sin + p.y * cos,
+            ))
+        };
+        for [a, b] in PLAYER_ARROW {
+            draw_line(place(a), place(b), view, colour, buffer);
+        }
+    }
+
+    fn draw_marks(&self, view: &MapView, sx: f32, sy: f32, buffer: &mut impl DrawBuffer) {
+        for (i, mark) in self.marks.iter().enumerate() {
+            if let Some(mark) = mark {
+                let (x, y) = view.to_screen(*mark);
+                if x >= 0.0 && x < view.width && y >= 0.0 && y < view.height {
+                    draw_patch(&self.mark_nums[i], x, y, sx, sy, &self.palette, buffer);
+                }
+            }
+        }
+    }
+
+    fn draw_title(&self, view: &MapView, sx: f32, sy: f32, buffer: &mut impl DrawBuffer) {
+        let mut y = view.height - TITLE_LINE_HEIGHT * sy;
+        if let Some(author) = &self.author {
+            draw_text_line(
+                &author.to_ascii_uppercase(),
+                0.0,
+                y,
+                sx,
+                sy,
+                &self.palette,
+                buffer,
+            );
+            y -= TITLE_LINE_HEIGHT * sy;
+        }
+        draw_text_line(
+            &self.title.to_ascii_uppercase(),
+            0.0,
+            y,
+            sx,
+            sy,
+            &self.palette,
+            buffer,
+        );
+    }
+}
+
+impl SubsystemTrait for Automap {
+    fn init<T: GameTraits + ConfigTraits>(&mut self, _game: &T) {}
+
+    /// AM_Responder
+    fn responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, game: &mut T) -> bool {
+        let toggle = game.key_action(sc) == Some(Action::Automap);
+        if !self.active {
+            if toggle {
+                self.set_active(true, game);
+                return true;
+            }
+            return false;
+        }
+
+        match sc {
+            _ if toggle => self.set_active(false, game),
+            KeyCode::Equals => self.zoom_by(ZOOM_STEP),
+            KeyCode::Minus => self.zoom_by(1.0 / ZOOM_STEP),
+            KeyCode::Num0 => self.toggle_whole_map(),
+            KeyCode::F => {
+                self.following = !self.following;
+                game.player_msg_set(if self.following {
+                    english::AMSTR_FOLLOWON
+                } else {
+                    english::AMSTR_FOLLOWOFF
+                });
+            }
+            KeyCode::G => {
+                self.grid = !self.grid;
+                game.player_msg_set(if self.grid {
+                    english::AMSTR_GRIDON
+                } else {
+                    english::AMSTR_GRIDOFF
+                });
+            }
+            KeyCode::M => {
+                self.add_mark();
+                game.player_msg_set(english::AMSTR_MARKEDSPOT);
+            }
+            KeyCode::C => {
+                self.clear_marks();
+                game.player_msg_set(english::AMSTR_MARKSCLEARED);
+            }
+            KeyCode::Left if !self.following => self.pan(-PAN_STEP, 0.0),
+            KeyCode::Right if !self.following => self.pan(PAN_STEP, 0.0),
+            KeyCode::Up if !self.following => self.pan(0.0, PAN_STEP),
+            KeyCode::Down if !self.following => self.pan(0.0, -PAN_STEP),
+            _ => return false,
+        }
+        true
+    }
+
+    /// AM_Ticker
+    fn ticker<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) -> bool {
+        self.active = game.automap_active();
+        let Some(level) = game.level_data() else {
+            return false;
+        };
+        let map_name = game.map_name();
+        if map_name != self.map_name {
+            self.level_init(level, map_name, game.map_entry(), game.get_mission());
+        }
+        if !self.active {
+            return false;
+        }
+
+        self.bar_mode = game.config_value(ConfigKey::HudSize) == 1;
+        let allmap = game.player_status().powers[PowerType::Allmap as usize] != 0;
+        self.collect_lines(level, allmap);
+        if let Some(mobj) = game.player_mobj() {
+            self.player = MapPoint::new(mobj.x.to_f32(), mobj.y.to_f32());
+            self.player_angle = mobj.angle.rad();
+            if self.following {
+                self.centre = self.player;
+            }
+        }
+        false
+    }
+
+    /// A mouse or pad button bound to the automap toggles it.
+    fn device_responder<T: GameTraits + ConfigTraits>(
+        &mut self,
+        input: Input,
+        game: &mut T,
+    ) -> bool {
+        if game.bindings().action_for(input, game.modifiers()) != Some(Action::Automap) {
+            return false;
+        }
+        self.set_active(!self.active, game);
+        true
+    }
+
+    /// AM_Drawer
+    fn draw(&mut self, buffer: &mut impl DrawBuffer) {
+        let (sx, sy) = hud_scale(buffer);
+        let width = buffer.size().width_f32();
+        let mut height = buffer.size().height_f32();
+        if self.bar_mode {
+            height -= STBAR_HEIGHT as f32 * sy;
+        }
+
+        let background = self.palette.0[BACKGROUND as usize];
+        for y in 0..height as usize {
+            for x in 0..width as usize {
+                buffer.set_pixel(x, y, background);
+            }
+        }
+
+        let map_w = (self.max.x - self.min.x).max(1.0);
+        let map_h = (self.max.y - self.min.y).max(1.0);
+        let fit = (width / map_w).min(height / map_h);
+        let max_zoom = (height / (2.0 * PLAYER_RADIUS) / fit).max(1.0);
+        self.zoom = self.zoom.clamp(1.0, max_zoom);
+        self.scale = fit * self.zoom;
+        self.view_width = width;
+        self.view_height = height;
+
+        let view = MapView {
+            centre: self.centre,
+            scale: self.scale,
+            width,
+            height,
+        };
+        if self.grid {
+            self.draw_grid(&view, buffer);
+        }
+        for line in &self.lines {
+            let colour = self.palette.0[line.colour as usize];
+            draw_line(
+                view.to_screen(line.v1),
+                view.to_screen(line.v2),
+                &view,
+                colour,
+                buffer,
+            );
+        }
+        self.draw_player(&view, buffer);
+        self.draw_marks(&view, sx, sy, buffer);
+        if !self.following {
+            let colour = self.palette.0[CROSSHAIR_COLOUR as usize];
+            buffer.set_pixel((width / 2.0) as usize, (height / 2.0) as usize, colour);
+        }
+        self.draw_title(&view, sx, sy, buffer);
+    }
+}
+
+/// AM_drawWalls colour choice, `None` if the line is not drawn.
+fn line_colour(line: &LineDef, allmap: bool) -> Option<u8> {
+    if line.flags.contains(LineDefFlags::UnMapped) {
+        return None;
+    }
+    if !line.flags.contains(LineDefFlags::Mapped) {
+        return allmap.then_some(UNSEEN_COLOUR);
+    }
+    let Some(back) = &line.backsector else {
+        return Some(WALL_COLOUR);
+    };
+    let front = &line.frontsector;
+    if line.special == TELEPORT_SPECIAL {
+        Some(TELEPORT_COLOUR)
+    } else if line.flags.contains(LineDefFlags::Secret) {
+        Some(WALL_COLOUR)
+    } else if back.floorheight != front.floorheight {
+        Some(FLOOR_CHANGE_COLOUR)
+    } else if back.ceilingheight != front.ceilingheight {
+        Some(CEILING_CHANGE_COLOUR)
+    } else {
+        None
+    }
+}
+
+/// The stock `HUSTR_*` name of an `ExMy` or `MAPxx` map, with any DEHACKED
+/// replacement.
+fn level_name(map_name: &str, mission: GameMission) -> Option<&'static str> {
+    let (mnemonic, name) = if let Some(map) = map_name.strip_prefix("MAP") {
+        let map: usize = map.parse().ok()?;
+        let (prefix, names) = match mission {
+            GameMission::PackPlut => ("PHUSTR", &english::MAPNAMESP),
+            GameMission::PackTnt => ("THUSTR", &english::MAPNAMEST),
+            _ => ("HUSTR", &english::MAPNAMES2),
+        };
+        (format!("{prefix}_{map}"), *names.get(map.checked_sub(1)?)?)
+    } else if let [b'E', e @ b'1'..=b'4', b'M', m @ b'1'..=b'9'] = map_name.as_bytes() {
+        let index = usize::from(e - b'1') * 9 + usize::from(m - b'1');
+        (format!("HUSTR_{map_name}"), english::MAPNAMES[index])
+    } else {
+        return None;
+    };
+    Some(deh_named(&mnemonic, name))
+}
+
+/// The automap title and author for a map. The UMAPINFO `label` replaces the
+/// lump name before the level name, or removes it when `clear`. Without an
+/// entry the stock level name is used, if the map has one.
+fn map_title(
+    map_name: &str,
+    entry: Option<&MapEntry>,
+    stock_name: Option<&str>,
+) -> (String, Option<String>) {
+    let Some(entry) = entry else {
+        return (stock_name.unwrap_or(map_name).to_owned(), None);
+    };
+    let label = match &entry.label {
+        Some(LabelKind::Text(label)) => Some(label.as_str()),
+        Some(LabelKind::Clear) => None,
+        None => Some(map_name),
+    };
+    let title = match (label, entry.level_name.as_deref()) {
+        (Some(label), Some(name)) => format!("{label}: {name}"),
+        (Some(label), None) => label.to_owned(),
+        (None, Some(name)) => name.to_owned(),
+        (None, None) => String::new(),
+    };
+    (title, entry.author.clone())
+}
+
+/// Draw a line in screen space, clipped to the view (Liang-Barsky).
+fn draw_line(
+    a: (f32, f32),
+    b: (f32, f32),
+    view: &MapView,
+    colour: u32,
+    buffer: &mut impl DrawBuffer,
+) {
+    let dx = b.0 - a.0;
+    let dy = b.1 - a.1;
+    let mut t0 = 0.0f32;
+    let mut t1 = 1.0f32;
+    for (p, q) in [
+        (-dx, a.0),
+        (dx, view.width - 1.0 - a.0),
+        (-dy, a.1),
+        (dy, view.height - 1.0 - a.1),
+    ] {
+        if p.abs() < f32::EPSILON {
+            if q < 0.0 {
+                return;
+            }
+            continue;
+        }
+        let r = q / p;
+        if p < 0.0 {
+            if r > t1 {
+                return;
+            }
+            t0 = t0.max(r);
+        } else {
+            if r < t0 {
+                return;
+            }
+            t1 = t1.min(r);
+        }
+    }
+
+    let (x0, y0) = (a.0 + t0 * dx, a.1 + t0 * dy);
+    let (x1, y1) = (a.0 + t1 * dx, a.1 + t1 * dy);
+    let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0);
+    for i in 0..=steps as i32 {
+        let t = i as f32 / steps;
+        let x = (x0 + (x1 - x0) * t).round();
+        let y = (y0 + (y1 - y0) * t).round();
+        buffer.set_pixel(x as usize, y as usize, colour);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_config::GameMission;
+    use wad::umapinfo::{LabelKind, MapEntry};
+
+    use super::{level_name, map_title};
+
+    fn entry(label: Option<LabelKind>, level_name: Option<&str>) -> MapEntry {
+        MapEntry {
+            map_name: "MAP07".to_owned(),
+            level_name: level_name.map(str::to_owned),
+            label,
+            ..MapEntry::default()
+        }
+    }
+
+    #[test]
+    fn title_without_umapinfo_is_stock_name() {
+        let name = level_name("E1M1", GameMission::Doom);
+        assert_eq!(map_title("E1M1", None, name).0, "E1M1: Hangar");
+        assert_eq!(map_title("MAP33", None, None), ("MAP33".to_owned(), None));
+    }
+
+    #[test]
+    fn stock_names_follow_the_mission() {
+        assert_eq!(level_name("E4M9", GameMission::Doom), Some("E4M9: Fear"));
+        assert_eq!(
+            level_name("MAP07", GameMission::Doom2),
+            Some("level 7: dead simple")
+        );
+        assert_eq!(
+            level_name("MAP01", GameMission::PackPlut),
+            Some("level 1: congo")
+        );
+        assert_eq!(
+            level_name("MAP32", GameMission::PackTnt),
+            Some("level 32: caribbean")
+        );
+        assert_eq!(level_name("MAP33", GameMission::Doom2), None);
+        assert_eq!(level_name("MAP00", GameMission::Doom2), None);
+        assert_eq!(level_name("E5M1", GameMission::Doom), None);
+    }
+
+    #[test]
+    fn title_defaults_label_to_lump_name() {
+        let e = entry(None, Some("Dead Simple"));
+        assert_eq!(map_title("MAP07", Some(&e), None).0, "MAP07: Dead Simple");
+    }
+
+    #[test]
+    fn title_uses_label_text() {
+        let e = entry(Some(LabelKind::Text("LV7".to_owned())), Some("Dead Simple"));
+        assert_eq!(map_title("MAP07", Some(&e), None).0, "LV7: Dead Simple");
+    }
+
+    #[test]
+    fn title_clear_label_drops_prefix() {
+        let mut e = entry(Some(LabelKind::Clear), Some("Dead Simple"));
+        e.author = Some("American McGee".to_owned());
+        assert_eq!(
+            map_title("MAP07", Some(&e), None),
+            ("Dead Simple".to_owned(), Some("American McGee".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! The Episode 3 end: the `PFUB2`/`PFUB1` panorama scrolls across, then
+//! "THE END" is shot on to the screen letter by letter.
+//!
+//! Doom function name `F_BunnyScroll`
+
+use gamestate_traits::GameTraits;
+use hud_util::draw_patch;
+use render_common::{ByteOrder, DrawBuffer, PixelFmt};
+use sound_common::SfxName;
+use wad::types::{BLACK, WadPalette, WadPatch};
+
+/// Tics before the panorama starts to move.
+const SCROLL_START: i32 = 230;
+/// Tic the blank `END0` stamp shows.
+const STAMP_START: i32 = 1130;
+/// Tic the first shot lands, then one every `STAMP_TICS`.
+const SHOTS_START: i32 = 1180;
+const STAMP_TICS: i32 = 5;
+const LAST_STAMP: i32 = 6;
+
+/// How far the panorama has scrolled, 0 showing all of the left half and 320
+/// all of the right.
+const fn scrolled(count: i32) -> i32 {
+    let s = 320 - (count - SCROLL_START) / 2;
+    if s > 320 {
+        320
+    } else if s < 0 {
+        0
+    } else {
+        s
+    }
+}
+
+/// The `END` stamp showing at `count`, if any.
+const fn stamp(count: i32) -> Option<i32> {
+    if count < STAMP_START {
+        None
+    } else if count < SHOTS_START {
+        Some(0)
+    } else {
+        let s = (count - SHOTS_START) / STAMP_TICS;
+        Some(if s > LAST_STAMP { LAST_STAMP } else { s })
+    }
+}
+
+pub(super) struct BunnyScroll {
+    /// `PFUB2`, scrolled off to the left.
+    left: WadPatch,
+    /// `PFUB1`, scrolled in from the right.
+    right: WadPatch,
+    /// `END0` to `END6`.
+    ends: Vec<WadPatch>,
+    count: i32,
+    last_stamp: i32,
+}
+
+impl BunnyScroll {
+    pub(super) fn new(game: &impl GameTraits) -> Self {
+        let wad = game.get_wad_data();
+        let patch = |name: &str| {
+            wad.get_lump(name)
+                .map(WadPatch::from_lump)
+                .unwrap_or_else(WadPatch::default)
+        };
+        Self {
+            left: patch("PFUB2"),
+            right: patch("PFUB1"),
+            ends: (0..=LAST_STAMP)
+                .map(|i| patch(&format!("END{i}")))
+                .collect(),
+            count: 0,
+            last_stamp: 0,
+        }
+    }
+
+    pub(super) fn ticker(&mut self, game: &mut impl GameTraits) {
+        self.count += 1;
+        if let Some(s) = stamp(self.count)
+            && s > self.last_stamp
+        {
+            game.start_sound(SfxName::Pistol);
+            self.last_stamp = s;
+        }
+    }
+
+    pub(super) fn draw(&self, palette: &WadPalette, pixels: &mut impl DrawBuffer) {
+        let (sx, sy) = hud_util::fullscreen_scale(pixels);
+        let x_ofs = ((pixels.size().width_f32() - 320.0 * sx) / 2.0).floor();
+        let black = PixelFmt::from_argb(BLACK, ByteOrder::Argb);
+        pixels.buf_mut().fill(black);
+
+        let scrolled = scrolled(self.count) as f32;
+        draw_patch(
+            &self.left,
+            x_ofs - scrolled * sx,
+            0.0,
+            sx,
+            sy,
+            palette,
+            pixels,
+        );
+        draw_patch(
+            &self.right,
+            x_ofs + (320.0 - scrolled) * sx,
+            0.0,
+            sx,
+            sy,
+            palette,
+            pixels,
+        );
+        // The panorama is wider than the screen; keep it to the 320 columns
+        let right_edge = (x_ofs + 320.0 * sx).ceil() as usize;
+        let width = pixels.size().width_usize();
+        for y in 0..pixels.size().height_usize() {
+            for x in (0..x_ofs as usize).chain(right_edge..width) {
+                pixels.set_pixel(x, y, BLACK);
+            }
+        }
+
+        if let Some(patch) = stamp(self.count).and_then(|s| self.ends.get(s as usize)) {
+            let x = x_ofs + (320.0 - 13.0 * 8.0) / 2.0 * sx;
+            let y = ((200.0 - 8.0 * 8.0) / 2.0 - f32::from(patch.top_offset)) * sy;
+            draw_patch(patch, x, y, sx, sy, palette, pixels);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scrolled, stamp};
+
+    #[test]
+    fn panorama_scrolls_at_half_speed() {
+        assert_eq!(scrolled(0), 320);
+        assert_eq!(scrolled(231), 320);
+        assert_eq!(scrolled(232), 319);
+        assert_eq!(scrolled(230 + 320), 160);
+        assert_eq!(scrolled(230 + 640), 0);
+        assert_eq!(scrolled(5000), 0);
+    }
+
+    #[test]
+    fn the_end_is_shot_on_letter_by_letter() {
+        assert_eq!(stamp(1129), None);
+        assert_eq!(stamp(1130), Some(0));
+        assert_eq!(stamp(1184), Some(0));
+        assert_eq!(stamp(1185), Some(1));
+        assert_eq!(stamp(1210), Some(6));
+        assert_eq!(stamp(9000), Some(6));
+    }
+}
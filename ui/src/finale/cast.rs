@@ -0,0 +1,324 @@
+//! The Doom II cast call: each monster walks, attacks and dies in turn over
+//! `BOSSBACK` until the player leaves.
+//!
+//! Doom functions `F_StartCast`, `F_CastTicker`, `F_CastResponder` and
+//! `F_CastDrawer`. The turn keys rotate the actor as in later ports.
+
+use gameplay::{
+    MapObjKind, StateNum, deh_string, english as lang, mobj_info, sprite_names, states,
+};
+use gamestate_traits::{Action, GameTraits};
+use hud_util::{draw_patch, draw_patch_flipped, draw_text_line, measure_text_line};
+use render_common::{ByteOrder, DrawBuffer, PixelFmt};
+use sound_common::{MusTrack, SfxName};
+use wad::WadData;
+use wad::types::{BLACK, WadPalette, WadPatch};
+
+const FF_FRAMEMASK: u32 = 0x7FFF;
+
+/// Frames of walking before an attack, and of attacking before walking again.
+const CAST_ATTACK_AT: i32 = 12;
+const CAST_ATTACK_END: i32 = 24;
+
+const CAST_ORDER: [(&str, MapObjKind); 17] = [
+    (lang::CC_ZOMBIE, MapObjKind::MT_POSSESSED),
+    (lang::CC_SHOTGUN, MapObjKind::MT_SHOTGUY),
+    (lang::CC_HEAVY, MapObjKind::MT_CHAINGUY),
+    (lang::CC_IMP, MapObjKind::MT_TROOP),
+    (lang::CC_DEMON, MapObjKind::MT_SERGEANT),
+    (lang::CC_LOST, MapObjKind::MT_SKULL),
+    (lang::CC_CACO, MapObjKind::MT_HEAD),
+    (lang::CC_HELL, MapObjKind::MT_KNIGHT),
+    (lang::CC_BARON, MapObjKind::MT_BRUISER),
+    (lang::CC_ARACH, MapObjKind::MT_BABY),
+    (lang::CC_PAIN, MapObjKind::MT_PAIN),
+    (lang::CC_REVEN, MapObjKind::MT_UNDEAD),
+    (lang::CC_MANCU, MapObjKind::MT_FATSO),
+    (lang::CC_ARCH, MapObjKind::MT_VILE),
+    (lang::CC_SPIDER, MapObjKind::MT_SPIDER),
+    (lang::CC_CYBER, MapObjKind::MT_CYBORG),
+    (lang::CC_HERO, MapObjKind::MT_PLAYER),
+];
+
+/// The attack sounds the cast plays itself, since the action functions that
+/// would normally make them don't run here.
+#[allow(
+    clippy::match_same_arms,
+    reason = "one arm per line of the vanilla sound table"
+)]
+const fn attack_sound(state: StateNum) -> Option<SfxName> {
+    Some(match state {
+        StateNum::PLAY_ATK1 => SfxName::Dshtgn,
+        StateNum::POSS_ATK2 => SfxName::Pistol,
+        StateNum::SPOS_ATK2 => SfxName::Shotgn,
+        StateNum::VILE_ATK2 => SfxName::Vilatk,
+        StateNum::SKEL_FIST2 => SfxName::Skeswg,
+        StateNum::SKEL_FIST4 => SfxName::Skepch,
+        StateNum::SKEL_MISS2 => SfxName::Skeatk,
+        StateNum::FATT_ATK8 | StateNum::FATT_ATK5 | StateNum::FATT_ATK2 => SfxName::Firsht,
+        StateNum::CPOS_ATK2 | StateNum::CPOS_ATK3 | StateNum::CPOS_ATK4 => SfxName::Shotgn,
+        StateNum::TROO_ATK3 => SfxName::Claw,
+        StateNum::SARG_ATK2 => SfxName::Sgtatk,
+        StateNum::BOSS_ATK2 | StateNum::BOS2_ATK2 | StateNum::HEAD_ATK2 => SfxName::Firsht,
+        StateNum::SKULL_ATK2 => SfxName::Sklatk,
+        StateNum::SPID_ATK2 | StateNum::SPID_ATK3 => SfxName::Shotgn,
+        StateNum::BSPI_ATK2 => SfxName::Plasma,
+        StateNum::CYBER_ATK2 | StateNum::CYBER_ATK4 | StateNum::CYBER_ATK6 => SfxName::Rlaunc,
+        StateNum::PAIN_ATK3 => SfxName::Sklatk,
+        _ => return None,
+    })
+}
+
+/// Find the sprite lump for `frame` of `sprite` seen from `rotation` (0 is
+/// the front, then anticlockwise in eighths), and whether it is stored
+/// mirrored. The last loaded lump wins.
+fn find_sprite<'a>(
+    wad: &'a WadData,
+    sprite: &str,
+    frame: u32,
+    rotation: usize,
+) -> Option<(&'a wad::Lump, bool)> {
+    let f = b'A' + frame as u8;
+    let r = b'1' + rotation as u8;
+    wad.lumps().iter().rev().find_map(|lump| {
+        let n = lump.name.as_bytes();
+        if n.len() < 6 || !lump.name.starts_with(sprite) {
+            return None;
+        }
+        if n[4] == f && (n[5] == b'0' || n[5] == r) {
+            Some((lump, false))
+        } else if n.len() >= 8 && n[6] == f && n[7] == r {
+            Some((lump, true))
+        } else {
+            None
+        }
+    })
+}
+
+pub(super) struct CastCall {
+    background: WadPatch,
+    /// Index in to `CAST_ORDER`.
+    num: usize,
+    tics: i32,
+    state: StateNum,
+    death: bool,
+    frames: i32,
+    on_melee: bool,
+    attacking: bool,
+    rotation: usize,
+    /// The current sprite and whether to draw it mirrored.
+    sprite: Option<(WadPatch, bool)>,
+}
+
+impl CastCall {
+    /// Doom function name `F_StartCast`
+    pub(super) fn new(game: &impl GameTraits) -> Self {
+        game.change_music(MusTrack::Evil);
+        let wad = game.get_wad_data();
+        let background = wad
+            .get_lump("BOSSBACK")
+            .map(WadPatch::from_lump)
+            .unwrap_or_else(WadPatch::default);
+        let state = mobj_info()[CAST_ORDER[0].1 as usize].seestate;
+        let mut cast = Self {
+            background,
+            num: 0,
+            tics: states()[state as usize].tics,
+            state,
+            death: false,
+            frames: 0,
+            on_melee: false,
+            attacking: false,
+            rotation: 0,
+            sprite: None,
+        };
+        cast.update_sprite(wad);
+        cast
+    }
+
+    fn kind(&self) -> MapObjKind {
+        CAST_ORDER[self.num].1
+    }
+
+    fn update_sprite(&mut self, wad: &WadData) {
+        let state = &states()[self.state as usize];
+        let name = sprite_names()[state.sprite as usize];
+        self.sprite = find_sprite(wad, name, state.frame & FF_FRAMEMASK, self.rotation)
+            .map(|(lump, flip)| (WadPatch::from_lump(lump), flip));
+    }
+
+    fn stop_attack(&mut self) {
+        self.attacking = false;
+        self.frames = 0;
+        self.state = mobj_info()[self.kind() as usize].seestate;
+    }
+
+    /// Doom function name `F_CastTicker`
+    pub(super) fn ticker(&mut self, game: &mut impl GameTraits) {
+        self.tics -= 1;
+        if self.tics > 0 {
+            return;
+        }
+
+        let current = &states()[self.state as usize];
+        if current.tics == -1 || current.next_state == StateNum::None {
+            // Death is over, on to the next monster
+            self.num = (self.num + 1) % CAST_ORDER.len();
+            self.death = false;
+            let info = &mobj_info()[self.kind() as usize];
+            if info.seesound != SfxName::None {
+                game.start_sound(info.seesound);
+            }
+            self.state = info.seestate;
+            self.frames = 0;
+        } else if self.state == StateNum::PLAY_ATK1 {
+            // The player's attack is a single state
+            self.stop_attack();
+        } else {
+            self.state = current.next_state;
+            self.frames += 1;
+            if let Some(sfx) = attack_sound(self.state) {
+                game.start_sound(sfx);
+            }
+        }
+
+        let info = &mobj_info()[self.kind() as usize];
+        if self.frames == CAST_ATTACK_AT {
+            // Alternate melee and missile attacks, for those with both
+            self.attacking = true;
+            self.state = if self.on_melee {
+                info.meleestate
+            } else {
+                info.missilestate
+            };
+            self.on_melee = !self.on_melee;
+            if self.state == StateNum::None {
+                self.state = if self.on_melee {
+                    info.meleestate
+                } else {
+                    info.missilestate
+                };
+            }
+        }
+        if self.attacking && (self.frames == CAST_ATTACK_END || self.state == info.seestate) {
+            self.stop_attack();
+        }
+
+        self.tics = states()[self.state as usize].tics;
+        if self.tics == -1 {
+            self.tics = 15;
+        }
+        self.update_sprite(game.get_wad_data());
+    }
+
+    /// A press during the cast: the turn actions rotate the actor, anything
+    /// else kills it.
+    ///
+    /// Doom function name `F_CastResponder`
+    pub(super) fn responder(&mut self, action: Option<Action>, game: &mut impl GameTraits) {
+        match action {
+            Some(Action::TurnLeft) => {
+                self.rotation = (self.rotation + 1) % 8;
+                self.update_sprite(game.get_wad_data());
+                return;
+            }
+            Some(Action::TurnRight) => {
+                self.rotation = (self.rotation + 7) % 8;
+                self.update_sprite(game.get_wad_data());
+                return;
+            }
+            _ => {}
+        }
+        if self.death {
+            return;
+        }
+        let info = &mobj_info()[self.kind() as usize];
+        self.death = true;
+        self.state = info.deathstate;
+        self.tics = states()[self.state as usize].tics;
+        self.frames = 0;
+        self.attacking = false;
+        if info.deathsound != SfxName::None {
+            game.start_sound(info.deathsound);
+        }
+        self.update_sprite(game.get_wad_data());
+    }
+
+    /// Doom function name `F_CastDrawer`
+    pub(super) fn draw(&self, palette: &WadPalette, pixels: &mut impl DrawBuffer) {
+        let (sx, sy) = hud_util::fullscreen_scale(pixels);
+        let x_ofs = ((pixels.size().width_f32() - 320.0 * sx) / 2.0).floor();
+        let black = PixelFmt::from_argb(BLACK, ByteOrder::Argb);
+        pixels.buf_mut().fill(black);
+        draw_patch(&self.background, x_ofs, 0.0, sx, sy, palette, pixels);
+
+        let name = deh_string(CAST_ORDER[self.num].0);
+        let width = measure_text_line(name, sx);
+        draw_text_line(
+            name,
+            x_ofs + (320.0 * sx - width) / 2.0,
+            180.0 * sy,
+            sx,
+            sy,
+            palette,
+            pixels,
+        );
+
+        if let Some((patch, flip)) = &self.sprite {
+            let x = x_ofs + 160.0 * sx;
+            let y = (170.0 - f32::from(patch.top_offset)) * sy;
+            if *flip {
+                draw_patch_flipped(patch, x, y, sx, sy, palette, pixels);
+            } else {
+                draw_patch(patch, x, y, sx, sy, palette, pixels);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gameplay::{StateNum, mobj_info, states};
+    use test_utils::doom1_wad_path;
+    use wad::WadData;
+
+    use super::{CAST_ORDER, find_sprite};
+
+    #[test]
+    fn every_cast_member_walks_and_dies() {
+        for (name, kind) in CAST_ORDER {
+            let info = &mobj_info()[kind as usize];
+            assert_ne!(info.seestate, StateNum::None, "{name}");
+            assert!(
+                info.meleestate != StateNum::None || info.missilestate != StateNum::None,
+                "{name} has no attack"
+            );
+            // The death frames must end, or the cast never moves on
+            let mut state = info.deathstate;
+            let mut steps = 0;
+            loop {
+                let s = &states()[state as usize];
+                if s.tics == -1 || s.next_state == StateNum::None {
+                    break;
+                }
+                state = s.next_state;
+                steps += 1;
+                assert!(steps < 32, "{name} death loops");
+            }
+        }
+    }
+
+    #[test]
+    fn sprite_rotations_and_mirrors() {
+        let wad = WadData::new(&doom1_wad_path());
+        let (lump, flip) = find_sprite(&wad, "POSS", 0, 0).unwrap();
+        assert_eq!((lump.name.as_str(), flip), ("POSSA1", false));
+        let (lump, flip) = find_sprite(&wad, "POSS", 0, 1).unwrap();
+        assert_eq!((lump.name.as_str(), flip), ("POSSA2A8", false));
+        let (lump, flip) = find_sprite(&wad, "POSS", 0, 7).unwrap();
+        assert_eq!((lump.name.as_str(), flip), ("POSSA2A8", true));
+        // Death frames have a single rotation for every angle
+        let (lump, flip) = find_sprite(&wad, "POSS", 7, 3).unwrap();
+        assert_eq!((lump.name.as_str(), flip), ("POSSH0", false));
+    }
+}
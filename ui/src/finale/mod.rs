@@ -1,14 +1,38 @@
+mod bunny;
+mod cast;
 mod text;
 
+use bunny::BunnyScroll;
+use cast::CastCall;
 use game_config::GameMode;
-use gameplay::TICRATE;
-use gamestate_traits::{ConfigTraits, GameTraits, KeyCode, SubsystemTrait};
-use hud_util::{HUD_STRING, HUDString, hud_scale, load_char_patches};
-use render_common::DrawBuffer;
+use gameplay::{TICRATE, deh_named};
+use gamestate_traits::{Action, ConfigTraits, GameTraits, Input, KeyCode, SubsystemTrait};
+use hud_util::{HUD_STRING, HUDString, draw_patch, hud_scale, load_char_patches};
+use log::warn;
+use render_common::{ByteOrder, DrawBuffer, PixelFmt};
 use sound_common::MusTrack;
 use text::*;
 use wad::WadData;
-use wad::types::{WadFlat, WadPalette};
+use wad::types::{BLACK, WadFlat, WadPalette, WadPatch};
+
+/// What the finale is showing.
+enum Stage {
+    Text,
+    /// A fullscreen picture, held until the player leaves.
+    Art(WadPatch),
+    Bunny(BunnyScroll),
+    Cast(CastCall),
+}
+
+/// Where the finale goes once the text is done.
+#[derive(Clone, PartialEq, Eq)]
+enum EndScreen {
+    /// Doom II between-chapter text; on to the next map.
+    NextMap,
+    Pic(String),
+    Bunny,
+    Cast,
+}
 
 pub struct Finale {
     palette: WadPalette,
@@ -17,6 +41,8 @@ pub struct Finale {
     text: HUDString,
     bg_flat: WadFlat,
     count: i32,
+    stage: Stage,
+    end_screen: EndScreen,
 }
 
 impl Finale {
@@ -38,10 +64,51 @@ impl Finale {
             text: HUD_STRING,
             bg_flat,
             count: 0,
+            stage: Stage::Text,
+            end_screen: EndScreen::NextMap,
         }
     }
 
+    /// Leave the text for the end screen.
+    fn start_end_screen(&mut self, game: &impl GameTraits) {
+        self.stage = match &self.end_screen {
+            EndScreen::NextMap => return,
+            EndScreen::Pic(name) => {
+                let patch = game.get_wad_data().get_lump(name).map(WadPatch::from_lump);
+                Stage::Art(patch.unwrap_or_else(|| {
+                    warn!("Missing end picture {name}");
+                    WadPatch::default()
+                }))
+            }
+            EndScreen::Bunny => {
+                game.change_music(MusTrack::Bunny);
+                Stage::Bunny(BunnyScroll::new(game))
+            }
+            EndScreen::Cast => Stage::Cast(CastCall::new(game)),
+        };
+    }
+
     fn draw_pixels(&mut self, pixels: &mut impl DrawBuffer) {
+        match &self.stage {
+            Stage::Text => {}
+            Stage::Art(patch) => {
+                let (sx, sy) = hud_util::fullscreen_scale(pixels);
+                let x_ofs = ((pixels.size().width_f32() - 320.0 * sx) / 2.0).floor();
+                pixels
+                    .buf_mut()
+                    .fill(PixelFmt::from_argb(BLACK, ByteOrder::Argb));
+                draw_patch(patch, x_ofs, 0.0, sx, sy, &self.palette, pixels);
+                return;
+            }
+            Stage::Bunny(bunny) => {
+                bunny.draw(&self.palette, pixels);
+                return;
+            }
+            Stage::Cast(cast) => {
+                cast.draw(&self.palette, pixels);
+                return;
+            }
+        }
         let (sx, sy) = hud_scale(pixels);
         self.screen_width = pixels.size().width();
         self.screen_height = pixels.size().height();
@@ -67,25 +134,58 @@ impl SubsystemTrait for Finale {
     fn init<T: GameTraits + ConfigTraits>(&mut self, game: &T) {
         let mut name = "FLOOR4_8";
         self.count = 20 * TICRATE;
+        self.stage = Stage::Text;
+        self.text.clear();
+        let episode = game.level_end_info().episode + 1;
+        let umapinfo_end = game.map_entry().and_then(|e| {
+            if e.end_cast {
+                Some(EndScreen::Cast)
+            } else if e.end_bunny {
+                Some(EndScreen::Bunny)
+            } else {
+                e.end_pic.clone().map(EndScreen::Pic)
+            }
+        });
+        self.end_screen = match umapinfo_end.clone() {
+            Some(end) => end,
+            None if game.get_mode() == GameMode::Commercial => {
+                if game.level_end_info().last == 30 {
+                    EndScreen::Cast
+                } else {
+                    EndScreen::NextMap
+                }
+            }
+            None => match episode {
+                2 => EndScreen::Pic("VICTORY2".to_owned()),
+                3 => EndScreen::Bunny,
+                4 => EndScreen::Pic("ENDPIC".to_owned()),
+                _ if game.get_mode() == GameMode::Retail => EndScreen::Pic("CREDIT".to_owned()),
+                _ => EndScreen::Pic("HELP2".to_owned()),
+            },
+        };
 
         if game.get_mode() != GameMode::Commercial {
             game.change_music(MusTrack::Victor);
-            match game.level_end_info().episode + 1 {
+            match episode {
                 1 => {
                     name = "FLOOR4_8";
-                    self.text.replace(E1TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("E1TEXT", E1TEXT).to_ascii_uppercase());
                 }
                 2 => {
                     name = "SFLR6_1";
-                    self.text.replace(E2TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("E2TEXT", E2TEXT).to_ascii_uppercase());
                 }
                 3 => {
                     name = "MFLR8_4";
-                    self.text.replace(E3TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("E3TEXT", E3TEXT).to_ascii_uppercase());
                 }
                 4 => {
                     name = "MFLR8_3";
-                    self.text.replace(E4TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("E4TEXT", E4TEXT).to_ascii_uppercase());
                 }
                 _ => {}
             }
@@ -94,27 +194,33 @@ impl SubsystemTrait for Finale {
             match game.level_end_info().last {
                 6 => {
                     name = "SLIME16";
-                    self.text.replace(C1TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C1TEXT", C1TEXT).to_ascii_uppercase());
                 }
                 11 => {
                     name = "RROCK14";
-                    self.text.replace(C2TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C2TEXT", C2TEXT).to_ascii_uppercase());
                 }
                 20 => {
                     name = "RROCK07";
-                    self.text.replace(C3TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C3TEXT", C3TEXT).to_ascii_uppercase());
                 }
                 30 => {
                     name = "RROCK17";
-                    self.text.replace(C4TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C4TEXT", C4TEXT).to_ascii_uppercase());
                 }
                 15 => {
                     name = "RROCK13";
-                    self.text.replace(C5TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C5TEXT", C5TEXT).to_ascii_uppercase());
                 }
                 31 => {
                     name = "RROCK19";
-                    self.text.replace(C6TEXT.to_ascii_uppercase());
+                    self.text
+                        .replace(deh_named("C6TEXT", C6TEXT).to_ascii_uppercase());
                 }
                 _ => {}
             }
@@ -125,9 +231,22 @@ impl SubsystemTrait for Finale {
             name: name.to_owned(),
             data: lump.data.clone(),
         };
+
+        // A UMAPINFO end on a map with no finale text goes straight to it
+        if umapinfo_end.is_some() && self.text.is_at_end() {
+            self.start_end_screen(game);
+        }
     }
 
-    fn responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, _game: &mut T) -> bool {
+    fn responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, game: &mut T) -> bool {
+        match &mut self.stage {
+            Stage::Text => {}
+            Stage::Art(_) | Stage::Bunny(_) => return false,
+            Stage::Cast(cast) => {
+                cast.responder(game.key_action(sc), game);
+                return true;
+            }
+        }
         if sc == KeyCode::Return || sc == KeyCode::Space {
             if !self.text.is_at_end() {
                 self.text.set_draw_all();
@@ -139,14 +258,47 @@ impl SubsystemTrait for Finale {
         false
     }
 
+    /// Fire and use presses, and turns, reach a running cast call.
+    fn device_responder<T: GameTraits + ConfigTraits>(
+        &mut self,
+        input: Input,
+        game: &mut T,
+    ) -> bool {
+        let Stage::Cast(cast) = &mut self.stage else {
+            return false;
+        };
+        let action = game.bindings().action_for(input, game.modifiers());
+        if !matches!(
+            action,
+            Some(Action::Fire | Action::Use | Action::TurnLeft | Action::TurnRight)
+        ) {
+            return false;
+        }
+        cast.responder(action, game);
+        true
+    }
+
     fn ticker<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) -> bool {
+        match &mut self.stage {
+            Stage::Text => {}
+            Stage::Art(_) => return false,
+            Stage::Bunny(bunny) => {
+                bunny.ticker(game);
+                return false;
+            }
+            Stage::Cast(cast) => {
+                cast.ticker(game);
+                return false;
+            }
+        }
         self.text.inc_current_char();
         self.count -= 1;
-        if self.count <= 0
-            && game.get_mode() == GameMode::Commercial
-            && game.level_end_info().last != 30
-        {
-            game.finale_done();
+        if self.count <= 0 {
+            if self.end_screen == EndScreen::NextMap {
+                game.finale_done();
+            } else {
+                self.start_end_screen(game);
+            }
         }
         false
     }
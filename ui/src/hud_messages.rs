@@ -1,10 +1,52 @@
-use gameplay::TICRATE;
-use gamestate_traits::{ConfigKey, ConfigTraits, GameTraits, KeyCode, SubsystemTrait};
-use hud_util::{HUD_STRING, HUDString, hud_scale, load_char_patches};
+//! Player messages and multiplayer chat.
+//!
+//! Chat goes out a character per tic in each player's `TicCmd::chatchar`,
+//! prefixed with who it is for, and is shown when the receiving node sees the
+//! terminating Enter.
+
+use game_config::GameMode;
+use gameplay::{MAXPLAYERS, TICRATE, deh_string, english};
+use gamestate_traits::{ConfigKey, ConfigTraits, GameState, GameTraits, KeyCode, SubsystemTrait};
+use hud_util::{HUD_STRING, HUDString, draw_text_line, hud_scale, load_char_patches};
 use render_common::DrawBuffer;
+use sound_common::SfxName;
 use wad::WadData;
 use wad::types::WadPalette;
 
+/// Chat destination for everyone, 1-4 are the players
+const HU_BROADCAST: u8 = 5;
+const KEY_ENTER: u8 = 13;
+const KEY_BACKSPACE: u8 = 127;
+/// Longest chat message
+const HU_MAXLINELENGTH: usize = 80;
+
+/// Keys to start a message to everyone, or to one player
+const KEY_CHAT: KeyCode = KeyCode::T;
+const KEY_CHAT_DEST: [KeyCode; MAXPLAYERS] = [KeyCode::G, KeyCode::I, KeyCode::B, KeyCode::R];
+
+const CHAT_MACROS: [&str; 10] = [
+    english::HUSTR_CHATMACRO0,
+    english::HUSTR_CHATMACRO1,
+    english::HUSTR_CHATMACRO2,
+    english::HUSTR_CHATMACRO3,
+    english::HUSTR_CHATMACRO4,
+    english::HUSTR_CHATMACRO5,
+    english::HUSTR_CHATMACRO6,
+    english::HUSTR_CHATMACRO7,
+    english::HUSTR_CHATMACRO8,
+    english::HUSTR_CHATMACRO9,
+];
+
+const PLAYER_NAMES: [&str; MAXPLAYERS] = [
+    english::HUSTR_PLRGREEN,
+    english::HUSTR_PLRINDIGO,
+    english::HUSTR_PLRBROWN,
+    english::HUSTR_PLRRED,
+];
+
+/// Chat text is drawn in the colour of the player who sent it
+const PLAYER_TINTS: [u32; MAXPLAYERS] = [0x40FF40, 0x8080FF, 0xC08040, 0xFF4040];
+
 pub struct Messages {
     palette: WadPalette,
     screen_width: i32,
@@ -16,6 +58,21 @@ pub struct Messages {
     count_down_max: i32,
     msg_mode: i32, // 0=off, 1=stack, 2=overwrite
     widescreen: bool,
+    /// Typing a chat message
+    chat_on: bool,
+    /// The console player's message as typed so far
+    chat_line: String,
+    /// Each player's incoming message so far
+    chat_input: [String; MAXPLAYERS],
+    /// Who each player's incoming message is for
+    chat_dest: [u8; MAXPLAYERS],
+    /// The last key was Alt, so a number sends that chat macro
+    alt_down: bool,
+    /// Times the console player has tried to talk to themselves
+    no_brainers: u32,
+    netgame: bool,
+    console_player: usize,
+    players_in_game: [bool; MAXPLAYERS],
 }
 
 impl Messages {
@@ -35,6 +92,15 @@ impl Messages {
             count_down_max: 4 * TICRATE,
             msg_mode: 1,
             widescreen: false,
+            chat_on: false,
+            chat_line: String::new(),
+            chat_input: Default::default(),
+            chat_dest: [0; MAXPLAYERS],
+            alt_down: false,
+            no_brainers: 0,
+            netgame: false,
+            console_player: 0,
+            players_in_game: [false; MAXPLAYERS],
         }
     }
 
@@ -64,6 +130,13 @@ impl Messages {
         self.count_down = self.count_down_max;
     }
 
+    /// Add a line of chat from `player`, in their colour.
+    pub fn add_chat_line(&mut self, player: usize, line: String) {
+        self.add_line(line);
+        let current = if self.msg_mode == 2 { 0 } else { self.current };
+        self.lines[current].set_tint(PLAYER_TINTS[player]);
+    }
+
     pub fn pop_last(&mut self) {
         if self.start == self.current {
             return;
@@ -74,7 +147,8 @@ impl Messages {
         }
     }
 
-    pub fn draw_wrapped(&self, buffer: &mut impl DrawBuffer) {
+    /// Draw the messages, returning the y below the last one.
+    pub fn draw_wrapped(&self, buffer: &mut impl DrawBuffer) -> f32 {
         let (sx, sy) = hud_scale(buffer);
 
         let x_ofs = if self.widescreen {
@@ -105,6 +179,138 @@ impl Messages {
             }
             pos += 1;
         }
+        y
+    }
+
+    /// The line being typed, with a cursor, under the messages.
+    fn draw_chat(&self, y: f32, buffer: &mut impl DrawBuffer) {
+        let (sx, sy) = hud_scale(buffer);
+        let x_ofs = if self.widescreen {
+            0.0
+        } else {
+            (buffer.size().width_f32() - 320.0 * sx) / 2.0
+        };
+        let line = format!("{}_", self.chat_line);
+        draw_text_line(&line, x_ofs + 10.0, y, sx, sy, &self.palette, buffer);
+    }
+
+    /// Start typing a message for `dest`.
+    fn start_chat<T: GameTraits>(&mut self, dest: u8, game: &mut T) {
+        self.chat_on = true;
+        self.chat_line.clear();
+        queue_chat_char(dest, game);
+    }
+
+    /// Send chat macro `num` as a complete message.
+    fn send_macro<T: GameTraits>(&mut self, num: usize, game: &mut T) {
+        // End whatever was being typed first
+        queue_chat_char(KEY_ENTER, game);
+        let text = deh_string(CHAT_MACROS[num]);
+        for c in text.bytes() {
+            queue_chat_char(c.to_ascii_uppercase(), game);
+        }
+        queue_chat_char(KEY_ENTER, game);
+        self.chat_on = false;
+        game.player_msg_set(text);
+    }
+
+    /// Keys while typing a message. All keys are eaten.
+    fn chat_responder<T: GameTraits>(&mut self, sc: KeyCode, game: &mut T) {
+        if matches!(sc, KeyCode::LAlt | KeyCode::RAlt) {
+            self.alt_down = true;
+            return;
+        }
+        let alt_down = std::mem::take(&mut self.alt_down);
+
+        match sc {
+            KeyCode::Return => {
+                self.chat_on = false;
+                if !self.chat_line.is_empty() {
+                    let line = std::mem::take(&mut self.chat_line);
+                    self.add_chat_line(self.console_player, line);
+                }
+                queue_chat_char(KEY_ENTER, game);
+            }
+            KeyCode::Escape => self.chat_on = false,
+            KeyCode::Backspace => {
+                if self.chat_line.pop().is_some() {
+                    queue_chat_char(KEY_BACKSPACE, game);
+                }
+            }
+            _ => {
+                let Some(c) = sc.to_char() else {
+                    return;
+                };
+                if alt_down && let Some(num) = c.to_digit(10) {
+                    self.send_macro(num as usize, game);
+                } else if self.chat_line.len() < HU_MAXLINELENGTH {
+                    let c = c.to_ascii_uppercase();
+                    self.chat_line.push(c);
+                    queue_chat_char(c as u8, game);
+                }
+            }
+        }
+    }
+
+    /// Pick up the chat characters that came in with the other players'
+    /// commands this tic.
+    fn receive_chat<T: GameTraits>(&mut self, game: &mut T) {
+        for (player, name) in PLAYER_NAMES.into_iter().enumerate() {
+            if !self.players_in_game[player] || player == self.console_player {
+                continue;
+            }
+            let c = game.take_chat_char(player);
+            match c {
+                0 => {}
+                1..=HU_BROADCAST => self.chat_dest[player] = c,
+                KEY_ENTER => {
+                    let text = std::mem::take(&mut self.chat_input[player]);
+                    let dest = self.chat_dest[player];
+                    if !text.is_empty()
+                        && (dest == HU_BROADCAST || dest as usize == self.console_player + 1)
+                    {
+                        let name = deh_string(name).to_ascii_uppercase();
+                        self.add_chat_line(player, format!("{name}{text}"));
+                        game.start_sound(if game.get_mode() == GameMode::Commercial {
+                            SfxName::Radio
+                        } else {
+                            SfxName::Tink
+                        });
+                    }
+                }
+                KEY_BACKSPACE => {
+                    self.chat_input[player].pop();
+                }
+                _ => {
+                    // Only what the HUD font can draw
+                    let c = c.to_ascii_uppercase() as char;
+                    if (c == ' ' || ('!'..='_').contains(&c))
+                        && self.chat_input[player].len() < HU_MAXLINELENGTH
+                    {
+                        self.chat_input[player].push(c);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Selecting yourself as who to talk to.
+    fn talk_to_self<T: GameTraits>(&mut self, game: &mut T) {
+        self.no_brainers += 1;
+        let msg = match self.no_brainers {
+            0..3 => english::HUSTR_TALKTOSELF1,
+            3..6 => english::HUSTR_TALKTOSELF2,
+            6..9 => english::HUSTR_TALKTOSELF3,
+            9..32 => english::HUSTR_TALKTOSELF4,
+            _ => english::HUSTR_TALKTOSELF5,
+        };
+        game.player_msg_set(msg);
+    }
+}
+
+fn queue_chat_char<T: GameTraits>(c: u8, game: &mut T) {
+    if !game.queue_chat_char(c) {
+        game.player_msg_set(english::HUSTR_MSGU);
     }
 }
 
@@ -113,9 +319,37 @@ impl SubsystemTrait for Messages {
         for l in &mut self.lines {
             l.clear();
         }
+        self.chat_on = false;
+        self.chat_line.clear();
+        for input in &mut self.chat_input {
+            input.clear();
+        }
     }
 
-    fn responder<T: GameTraits + ConfigTraits>(&mut self, _sc: KeyCode, _game: &mut T) -> bool {
+    fn responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, game: &mut T) -> bool {
+        if self.chat_on {
+            self.chat_responder(sc, game);
+            return true;
+        }
+        if !game.netgame() || game.game_state() != GameState::Level {
+            return false;
+        }
+        if sc == KEY_CHAT {
+            self.start_chat(HU_BROADCAST, game);
+            return true;
+        }
+        if self.players_in_game.iter().filter(|&&p| p).count() > 2
+            && let Some(player) = KEY_CHAT_DEST.iter().position(|&k| k == sc)
+        {
+            if player == self.console_player {
+                self.talk_to_self(game);
+                return true;
+            }
+            if self.players_in_game[player] {
+                self.start_chat(player as u8 + 1, game);
+                return true;
+            }
+        }
         false
     }
 
@@ -124,6 +358,12 @@ impl SubsystemTrait for Messages {
         self.count_down_max = msg_time * TICRATE;
         self.msg_mode = game.config_value(ConfigKey::HudMsgMode);
         self.widescreen = game.config_value(ConfigKey::HudWidth) != 0;
+        self.netgame = game.netgame();
+        self.console_player = game.console_player();
+        self.players_in_game = game.players_in_game();
+        if self.netgame {
+            self.receive_chat(game);
+        }
 
         for l in &mut self.lines {
             if !l.line().is_empty() {
@@ -150,7 +390,10 @@ impl SubsystemTrait for Messages {
     fn draw(&mut self, buffer: &mut impl DrawBuffer) {
         self.screen_width = buffer.size().width();
         self.screen_height = buffer.size().height();
-        self.draw_wrapped(buffer);
+        let y = self.draw_wrapped(buffer);
+        if self.chat_on {
+            self.draw_chat(y, buffer);
+        }
     }
 }
 
@@ -76,6 +76,10 @@ pub struct Intermission {
     // info updated by ticker
     player_info: WorldEndPlayerInfo,
     level_info: WorldInfo,
+    /// Show every player's stats, or frags in deathmatch
+    netgame: bool,
+    deathmatch: bool,
+    console_player: usize,
 
     pointer_on: bool,
     count: i32,
@@ -206,6 +210,9 @@ impl Intermission {
             mode,
             player_info: WorldEndPlayerInfo::default(),
             level_info: WorldInfo::default(),
+            netgame: false,
+            deathmatch: false,
+            console_player: 0,
             pointer_on: true,
             count: SHOW_NEXT_LOC_DELAY * TICRATE,
             state: State::None,
@@ -383,6 +390,10 @@ impl Intermission {
     // }
 }
 
+fn percent(count: i32, max: i32) -> i32 {
+    if max > 0 { (count * 100) / max } else { 0 }
+}
+
 impl SubsystemTrait for Intermission {
     fn init<T: GameTraits + ConfigTraits>(&mut self, game: &T) {
         self.bg_count = 0;
@@ -394,6 +405,9 @@ impl SubsystemTrait for Intermission {
 
         self.player_info = game.player_end_info().clone();
         self.level_info = game.level_end_info().clone();
+        self.netgame = game.netgame();
+        self.deathmatch = self.netgame && game.deathmatch() != 0;
+        self.console_player = game.console_player();
         self.current_bg = self.level_info.episode;
 
         // Pre-load intertext backdrop from UMAPINFO
@@ -436,21 +450,19 @@ impl SubsystemTrait for Intermission {
                 game.change_music(MusTrack::Inter);
             }
 
-            self.player_info.total_kills = if self.level_info.maxkills > 0 {
-                (self.player_info.total_kills * 100) / self.level_info.maxkills
-            } else {
-                0
-            };
-            self.player_info.items_collected = if self.level_info.maxitems > 0 {
-                (self.player_info.items_collected * 100) / self.level_info.maxitems
-            } else {
-                0
-            };
-            self.player_info.secrets_found = if self.level_info.maxsecret > 0 {
-                (self.player_info.secrets_found * 100) / self.level_info.maxsecret
-            } else {
-                0
+            let WorldInfo {
+                maxkills,
+                maxitems,
+                maxsecret,
+                ..
+            } = self.level_info;
+            let to_percent = |player: &mut WorldEndPlayerInfo| {
+                player.total_kills = percent(player.total_kills, maxkills);
+                player.items_collected = percent(player.items_collected, maxitems);
+                player.secrets_found = percent(player.secrets_found, maxsecret);
             };
+            to_percent(&mut self.player_info);
+            self.level_info.plyr.iter_mut().for_each(to_percent);
         }
 
         match self.state {
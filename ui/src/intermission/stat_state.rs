@@ -9,6 +9,22 @@ const SP_STATSY: f32 = 50.0;
 const SP_TIMEX: f32 = 16.0;
 const SP_TIMEY: f32 = 200.0 - 32.0;
 
+const WI_SPACINGY: f32 = 33.0;
+
+// Deathmatch frag matrix
+const DM_MATRIXX: f32 = 42.0;
+const DM_MATRIXY: f32 = 68.0;
+const DM_SPACINGX: f32 = 40.0;
+const DM_TOTALSX: f32 = 269.0;
+const DM_KILLERSX: f32 = 10.0;
+const DM_KILLERSY: f32 = 100.0;
+const DM_VICTIMSX: f32 = 5.0;
+const DM_VICTIMSY: f32 = 50.0;
+
+// Cooperative stats table
+const NG_STATSY: f32 = 50.0;
+const NG_SPACINGX: f32 = 64.0;
+
 impl Intermission {
     pub(super) fn init_stats(&mut self) {
         self.pointer_on = false;
@@ -81,6 +97,207 @@ impl Intermission {
         );
     }
 
+    /// Draw a number right-aligned at `x`, with a minus sign if negative.
+    fn draw_signed(&self, n: i32, x: f32, y: f32, sx: f32, sy: f32, pixels: &mut impl DrawBuffer) {
+        let x = draw_num(
+            n.unsigned_abs(),
+            x,
+            y,
+            0,
+            &self.patches.nums,
+            sx,
+            sy,
+            &self.palette,
+            pixels,
+        );
+        if n < 0 {
+            let minus = &self.patches.minus;
+            draw_patch(
+                minus,
+                x - minus.width as f32 * sx,
+                y,
+                sx,
+                sy,
+                &self.palette,
+                pixels,
+            );
+        }
+    }
+
+    /// A player's frags less their suicides.
+    ///
+    /// Doom function name `WI_fragSum`
+    fn frag_sum(&self, player: usize) -> i32 {
+        let frags = &self.level_info.plyr[player].frags;
+        (0..frags.len())
+            .filter(|&i| self.level_info.plyr[i].inn)
+            .map(|i| if i == player { -frags[i] } else { frags[i] })
+            .sum()
+    }
+
+    /// Who fragged whom: a row per killer, a column per victim, and each
+    /// player's total.
+    ///
+    /// Doom function name `WI_drawDeathmatchStats`
+    fn draw_deathmatch_stats(&self, x_ofs: f32, sx: f32, sy: f32, buffer: &mut impl DrawBuffer) {
+        let p = &self.patches;
+        let total = &p.total;
+        draw_patch(
+            total,
+            x_ofs + (DM_TOTALSX - (total.width / 2) as f32) * sx,
+            (DM_MATRIXY - WI_SPACINGY + 10.0) * sy,
+            sx,
+            sy,
+            &self.palette,
+            buffer,
+        );
+        draw_patch(
+            &p.killers,
+            x_ofs + DM_KILLERSX * sx,
+            DM_KILLERSY * sy,
+            sx,
+            sy,
+            &self.palette,
+            buffer,
+        );
+        draw_patch(
+            &p.victims,
+            x_ofs + DM_VICTIMSX * sx,
+            DM_VICTIMSY * sy,
+            sx,
+            sy,
+            &self.palette,
+            buffer,
+        );
+
+        // Player swatches along the top and down the side, starred for the
+        // console player
+        let mut x = DM_MATRIXX + DM_SPACINGX;
+        let mut y = DM_MATRIXY;
+        for (i, player) in self.level_info.plyr.iter().enumerate() {
+            if player.inn {
+                let half = (p.players[i].width / 2) as f32;
+                let top = (x_ofs + (x - half) * sx, (DM_MATRIXY - WI_SPACINGY) * sy);
+                let side = (x_ofs + (DM_MATRIXX - half) * sx, y * sy);
+                let mut swatches = vec![(&p.players[i], top), (&p.players[i], side)];
+                if i == self.console_player {
+                    swatches.extend([(&p.bstar, top), (&p.star, side)]);
+                }
+                for (patch, (px, py)) in swatches {
+                    draw_patch(patch, px, py, sx, sy, &self.palette, buffer);
+                }
+            }
+            x += DM_SPACINGX;
+            y += WI_SPACINGY;
+        }
+
+        let w = p.nums[0].width as f32;
+        let mut y = DM_MATRIXY + 10.0;
+        for (i, player) in self.level_info.plyr.iter().enumerate() {
+            if player.inn {
+                let mut x = DM_MATRIXX + DM_SPACINGX;
+                for (j, victim) in self.level_info.plyr.iter().enumerate() {
+                    if victim.inn {
+                        self.draw_signed(
+                            player.frags[j],
+                            x_ofs + (x + w) * sx,
+                            y * sy,
+                            sx,
+                            sy,
+                            buffer,
+                        );
+                    }
+                    x += DM_SPACINGX;
+                }
+                self.draw_signed(
+                    self.frag_sum(i),
+                    x_ofs + (DM_TOTALSX + w) * sx,
+                    y * sy,
+                    sx,
+                    sy,
+                    buffer,
+                );
+            }
+            y += WI_SPACINGY;
+        }
+    }
+
+    /// Kills, items and secrets for every player, and frags if anyone has
+    /// any.
+    ///
+    /// Doom function name `WI_drawNetgameStats`
+    fn draw_netgame_stats(&self, x_ofs: f32, sx: f32, sy: f32, buffer: &mut impl DrawBuffer) {
+        let p = &self.patches;
+        let do_frags = (0..self.level_info.plyr.len()).any(|i| self.frag_sum(i) != 0);
+        let stats_x = 32.0 + (p.star.width / 2) as f32 + if do_frags { 0.0 } else { 32.0 };
+
+        let mut titles = vec![&p.kills, &p.items, &p.secret];
+        if do_frags {
+            titles.push(&p.frags);
+        }
+        for (n, title) in titles.into_iter().enumerate() {
+            draw_patch(
+                title,
+                x_ofs + (stats_x + (n + 1) as f32 * NG_SPACINGX - title.width as f32) * sx,
+                NG_STATSY * sy,
+                sx,
+                sy,
+                &self.palette,
+                buffer,
+            );
+        }
+
+        let pwidth = p.percent.width as f32;
+        let mut y = NG_STATSY + p.kills.height as f32;
+        for (i, player) in self.level_info.plyr.iter().enumerate() {
+            if !player.inn {
+                continue;
+            }
+            let mut x = stats_x;
+            let swatch_x = x_ofs + (x - p.players[i].width as f32) * sx;
+            draw_patch(
+                &p.players[i],
+                swatch_x,
+                y * sy,
+                sx,
+                sy,
+                &self.palette,
+                buffer,
+            );
+            if i == self.console_player {
+                draw_patch(&p.star, swatch_x, y * sy, sx, sy, &self.palette, buffer);
+            }
+
+            for stat in [
+                player.total_kills,
+                player.items_collected,
+                player.secrets_found,
+            ] {
+                x += NG_SPACINGX;
+                self.draw_percent(
+                    stat.max(0) as u32,
+                    x_ofs + (x - pwidth) * sx,
+                    (y + 10.0) * sy,
+                    sx,
+                    sy,
+                    buffer,
+                );
+            }
+            if do_frags {
+                x += NG_SPACINGX;
+                self.draw_signed(
+                    self.frag_sum(i),
+                    x_ofs + x * sx,
+                    (y + 10.0) * sy,
+                    sx,
+                    sy,
+                    buffer,
+                );
+            }
+            y += WI_SPACINGY;
+        }
+    }
+
     fn draw_time(
         &self,
         t: u32,
@@ -136,6 +353,15 @@ impl Intermission {
         self.draw_animated_bg_pixels(x_ofs, sx, sy, buffer);
         self.draw_level_finish_pixels(x_ofs, sx, sy, buffer);
 
+        if self.deathmatch {
+            self.draw_deathmatch_stats(x_ofs, sx, sy, buffer);
+            return;
+        }
+        if self.netgame {
+            self.draw_netgame_stats(x_ofs, sx, sy, buffer);
+            return;
+        }
+
         let mut lh = (3.0 * self.patches.nums[0].height as f32 / 2.0) * sy;
         draw_patch(
             &self.patches.kills,
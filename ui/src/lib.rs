@@ -1,9 +1,11 @@
+pub mod automap;
 pub mod finale;
 pub mod hud_messages;
 pub mod intermission;
 pub mod menu;
 pub mod statusbar;
 
+pub use automap::Automap;
 pub use finale::Finale;
 pub use hud_messages::Messages;
 pub use intermission::Intermission;
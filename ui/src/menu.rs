@@ -6,7 +6,10 @@
 
 use game_config::{GameMode, Skill};
 use gameplay::english as lang;
-use gamestate_traits::{ConfigKey, ConfigTraits, GameState, GameTraits, KeyCode, SubsystemTrait};
+use gamestate_traits::{
+    Action, Binding, Bindings, ConfigKey, ConfigTraits, GameState, GameTraits, Input, KeyCode,
+    PadButton, SubsystemTrait,
+};
 use hud_util::{
     draw_patch, draw_text_line, draw_text_line_tinted, fullscreen_scale, hud_scale,
     measure_text_line,
@@ -16,6 +19,7 @@ use sound_common::SfxName;
 use std::collections::HashMap;
 use wad::WadData;
 use wad::types::{BLACK, WadPalette, WadPatch};
+use wad::umapinfo::UMapInfo;
 
 const SAVESTRINGSIZE: usize = 24;
 const LINEHEIGHT: i32 = 16;
@@ -24,6 +28,9 @@ const SAVE_SLOT_COUNT: usize = 6;
 const EMPTY_STRING: &str = "EMPTY SLOT";
 /// Save/load border tile count (original Doom uses 24 tiles of 8px each)
 const SAVE_BORDER_TILES: i32 = 24;
+/// Options pages scroll to keep their rows above this line, which is left
+/// for the restart and help notes.
+const OPTIONS_BOTTOM: i32 = 200 - LINEHEIGHT;
 /// Quicksave slot sentinel: not yet assigned
 const QS_UNSET: i32 = -1;
 /// Quicksave slot sentinel: user must pick a slot
@@ -43,9 +50,19 @@ enum Status {
 enum ItemKind {
     Patch,
     Label,
-    Slider { min: i32, max: i32, step: i32 },
+    Slider {
+        min: i32,
+        max: i32,
+        step: i32,
+    },
     Toggle,
-    Cycle { options: &'static [&'static str] },
+    Cycle {
+        options: &'static [&'static str],
+    },
+    /// The bindings of an action; Return waits for an input to add.
+    Binding(Action),
+    /// Drawn as text, for an entry whose patch the WAD lacks.
+    Text(String),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -61,6 +78,8 @@ enum MenuAction {
     OpenOptGraphics,
     OpenOptHud,
     OpenOptInput,
+    OpenOptControls,
+    ResetBindings,
     StartGame,
     LoadSlot,
     SaveSlot,
@@ -138,6 +157,32 @@ impl MenuItem {
         }
     }
 
+    fn binding(action: Action) -> Self {
+        Self {
+            status: Status::Ok,
+            patch: String::new(),
+            action: MenuAction::None,
+            hotkey: '\0',
+            kind: ItemKind::Binding(action),
+            config_key: None,
+            label: action_label(action),
+            cached_value: 0,
+        }
+    }
+
+    fn text(text: String, action: MenuAction, hotkey: char) -> Self {
+        Self {
+            status: Status::Ok,
+            patch: String::new(),
+            action,
+            hotkey,
+            kind: ItemKind::Text(text),
+            config_key: None,
+            label: "",
+            cached_value: 0,
+        }
+    }
+
     fn cycle(label: &'static str, key: ConfigKey, options: &'static [&'static str]) -> Self {
         Self {
             status: Status::Ok,
@@ -195,6 +240,8 @@ struct MenuSet {
     /// The index of the last item the user was on in this menu. When the user
     /// selects this `MenuSet` again this item will be pre-selected.
     last_on: usize,
+    /// First row shown when the items don't all fit on screen.
+    top: usize,
 }
 
 impl MenuSet {
@@ -214,10 +261,136 @@ impl MenuSet {
             x,
             y,
             last_on: 0,
+            top: 0,
+        }
+    }
+
+    /// Rows of an options page that fit above `OPTIONS_BOTTOM`.
+    fn visible_rows(&self) -> usize {
+        ((OPTIONS_BOTTOM - self.y) / LINEHEIGHT).max(1) as usize
+    }
+
+    /// Scroll so the selected item is on screen.
+    fn scroll_to_cursor(&mut self) {
+        let rows = self.visible_rows();
+        if self.last_on < self.top {
+            self.top = self.last_on;
+        } else if self.last_on >= self.top + rows {
+            self.top = self.last_on + 1 - rows;
         }
     }
 }
 
+/// Controls page label for an action.
+const fn action_label(action: Action) -> &'static str {
+    match action {
+        Action::Forward => lang::CTL_FORWARD,
+        Action::Backward => lang::CTL_BACKWARD,
+        Action::TurnLeft => lang::CTL_TURN_LEFT,
+        Action::TurnRight => lang::CTL_TURN_RIGHT,
+        Action::StrafeLeft => lang::CTL_STRAFE_LEFT,
+        Action::StrafeRight => lang::CTL_STRAFE_RIGHT,
+        Action::Strafe => lang::CTL_STRAFE,
+        Action::Run => lang::CTL_RUN,
+        Action::RunToggle => lang::CTL_RUN_TOGGLE,
+        Action::Fire => lang::CTL_FIRE,
+        Action::Use => lang::CTL_USE,
+        Action::LookUp => lang::CTL_LOOK_UP,
+        Action::LookDown => lang::CTL_LOOK_DOWN,
+        Action::Weapon1 => lang::CTL_WEAPON[0],
+        Action::Weapon2 => lang::CTL_WEAPON[1],
+        Action::Weapon3 => lang::CTL_WEAPON[2],
+        Action::Weapon4 => lang::CTL_WEAPON[3],
+        Action::Weapon5 => lang::CTL_WEAPON[4],
+        Action::Weapon6 => lang::CTL_WEAPON[5],
+        Action::Weapon7 => lang::CTL_WEAPON[6],
+        Action::Weapon8 => lang::CTL_WEAPON[7],
+        Action::Weapon9 => lang::CTL_WEAPON[8],
+        Action::NextWeapon => lang::CTL_NEXT_WEAPON,
+        Action::PrevWeapon => lang::CTL_PREV_WEAPON,
+        Action::Automap => lang::CTL_AUTOMAP,
+        Action::QuickSave => lang::CTL_QUICKSAVE,
+        Action::QuickLoad => lang::CTL_QUICKLOAD,
+    }
+}
+
+/// How a binding reads on the Controls page, e.g. `CTRL+S` or `MOUSE1`.
+fn binding_text(b: &Binding) -> String {
+    let input = match b.input {
+        Input::Key(k) => k.to_string(),
+        Input::Mouse(m) => format!("MOUSE{}", m.number()),
+        Input::WheelUp => "WHEEL UP".to_owned(),
+        Input::WheelDown => "WHEEL DOWN".to_owned(),
+        Input::Pad(p) => format!("PAD {p:?}"),
+    };
+    match b.modifier {
+        Some(m) => format!("{m:?}+{input}"),
+        None => input,
+    }
+    .to_ascii_uppercase()
+}
+
+/// An entry on the episode menu.
+#[derive(Debug, PartialEq, Eq)]
+struct EpisodeChoice {
+    /// Menu graphic, drawn if the WAD has it.
+    patch: String,
+    /// Drawn as text when the patch is missing.
+    name: String,
+    hotkey: char,
+    episode: usize,
+    map: usize,
+}
+
+/// The episode menu: the stock `M_EPIn` episodes the WAD has, then any
+/// UMAPINFO `episode` definitions. A definition starting a stock episode
+/// takes its place, and `episode = clear` drops the stock list. Doom II has
+/// no stock episodes.
+fn episode_choices(
+    mode: GameMode,
+    has_lump: impl Fn(&str) -> bool,
+    umapinfo: Option<&UMapInfo>,
+) -> Vec<EpisodeChoice> {
+    let clear = umapinfo.is_some_and(|u| u.clear_episodes);
+    let mut choices: Vec<EpisodeChoice> = if clear || mode == GameMode::Commercial {
+        Vec::new()
+    } else {
+        (1..=9)
+            .filter(|e| has_lump(&format!("M_EPI{e}")))
+            .map(|e| EpisodeChoice {
+                patch: format!("M_EPI{e}"),
+                name: String::new(),
+                hotkey: char::from_digit(e as u32, 10).unwrap(),
+                episode: e,
+                map: 1,
+            })
+            .collect()
+    };
+
+    for (entry, def) in umapinfo.into_iter().flat_map(UMapInfo::episode_starts) {
+        let choice = EpisodeChoice {
+            patch: def.patch.clone(),
+            name: def.name.to_ascii_uppercase(),
+            hotkey: def
+                .key
+                .chars()
+                .next()
+                .map_or('\0', |c| c.to_ascii_uppercase()),
+            // MAPxx entries parse as episode 0
+            episode: entry.episode.max(1),
+            map: entry.map,
+        };
+        match choices
+            .iter_mut()
+            .find(|c| (c.episode, c.map) == (choice.episode, choice.map))
+        {
+            Some(stock) => *stock = choice,
+            None => choices.push(choice),
+        }
+    }
+    choices
+}
+
 /// Must match the order of `MenuDoom::menus` declaration
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 enum MenuIndex {
@@ -234,6 +407,7 @@ enum MenuIndex {
     OptGraphics,
     OptHud,
     OptInput,
+    OptControls,
 }
 
 pub struct MenuState {
@@ -295,8 +469,10 @@ pub struct GameMenu {
 
     patches: Patches,
     palette: WadPalette,
-    /// Track the episode selected by episode menu
-    episode: usize,
+    /// The episode and first map of each episode menu entry.
+    episode_starts: Vec<(usize, usize)>,
+    /// Track the episode and map selected by episode menu
+    episode: (usize, usize),
     which_skull: usize,
     skull_anim_counter: i32,
     restart_needed: bool,
@@ -304,6 +480,13 @@ pub struct GameMenu {
     /// Snapshot of video config values taken on entry to OptVideo.
     /// Used to revert on backspace (without Apply).
     video_snapshot: Option<Vec<(ConfigKey, i32)>>,
+    /// The action waiting for an input on the Controls page.
+    bind_action: Option<Action>,
+    /// Modifier key pressed while waiting: bound alone when released, or
+    /// as the modifier of the next key.
+    bind_mod_key: Option<KeyCode>,
+    /// Copy of the game's bindings for drawing the Controls page.
+    bindings: Bindings,
 }
 
 impl GameMenu {
@@ -311,8 +494,8 @@ impl GameMenu {
     ///
     /// - Creates all menu pages (top-level, episodes, skill, options, etc.)
     /// - Pre-caches title/item/skull patches from the WAD
-    /// - Adjusts episode count based on available WAD lumps
-    pub fn new(mode: GameMode, wad: &WadData, buf_width: i32) -> Self {
+    /// - Builds the episode list from the WAD's lumps and UMAPINFO episodes
+    pub fn new(mode: GameMode, wad: &WadData, umapinfo: &Option<UMapInfo>, buf_width: i32) -> Self {
         let x_pos = |original_x: i32| -> i32 { original_x };
 
         let save_slot_items = |action: MenuAction| -> Vec<MenuItem> {
@@ -324,6 +507,24 @@ impl GameMenu {
                 .collect()
         };
 
+        let episodes = episode_choices(mode, |name| wad.lump_exists(name), umapinfo.as_ref());
+        let episode_starts = episodes.iter().map(|e| (e.episode, e.map)).collect();
+        let episode_items = episodes
+            .into_iter()
+            .map(|e| {
+                if wad.lump_exists(&e.patch) {
+                    MenuItem::new(Status::Ok, e.patch, MenuAction::SelectEpisode, e.hotkey)
+                } else {
+                    MenuItem::text(e.name, MenuAction::SelectEpisode, e.hotkey)
+                }
+            })
+            .collect::<Vec<_>>();
+        let skill_prev = if episode_items.is_empty() {
+            MenuIndex::TopLevel
+        } else {
+            MenuIndex::Episodes
+        };
+
         let menus = vec![
             MenuSet::new(
                 MenuIndex::TopLevel,
@@ -356,30 +557,11 @@ impl GameMenu {
                 vec![Title::new("M_EPISOD", x_pos(54), 38)],
                 x_pos(48),
                 63,
-                (1..=9)
-                    .filter_map(|e| {
-                        if wad.lump_exists(&format!("M_EPI{e}")) {
-                            let mut item = MenuItem::new(
-                                Status::Ok,
-                                format!("M_EPI{e}"),
-                                MenuAction::SelectEpisode,
-                                char::from_digit(e, 10).unwrap(),
-                            );
-                            item.cached_value = e as i32;
-                            Some(item)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
+                episode_items,
             ),
             MenuSet::new(
                 MenuIndex::Skill,
-                if mode == GameMode::Commercial {
-                    MenuIndex::TopLevel
-                } else {
-                    MenuIndex::Episodes
-                },
+                skill_prev,
                 vec![
                     Title::new("M_NEWG", x_pos(96), 14),
                     Title::new("M_SKILL", x_pos(54), 38),
@@ -488,6 +670,7 @@ impl GameMenu {
                     MenuItem::slider(lang::OPT_SFX_VOL, ConfigKey::SfxVolume, 0, 100, 5),
                     MenuItem::slider(lang::OPT_MUS_VOL, ConfigKey::MusVolume, 0, 100, 5),
                     MenuItem::cycle(lang::OPT_MUS_TYPE, ConfigKey::MusicType, MUSIC_TYPE_OPTIONS),
+                    MenuItem::toggle(lang::OPT_PITCH_SHIFT, ConfigKey::PitchShift),
                 ],
             ),
             MenuSet::new(
@@ -552,8 +735,25 @@ impl GameMenu {
                 vec![
                     MenuItem::slider(lang::OPT_MOUSE_SENS, ConfigKey::MouseSensitivity, 0, 15, 1),
                     MenuItem::toggle(lang::OPT_INVERT_Y, ConfigKey::InvertY),
+                    MenuItem::label(lang::OPT_CONTROLS, MenuAction::OpenOptControls, 'C'),
                 ],
             ),
+            MenuSet::new(
+                MenuIndex::OptControls,
+                MenuIndex::OptInput,
+                vec![],
+                32,
+                24,
+                Action::ALL
+                    .into_iter()
+                    .map(MenuItem::binding)
+                    .chain([MenuItem::label(
+                        lang::OPT_BIND_RESET,
+                        MenuAction::ResetBindings,
+                        '\0',
+                    )])
+                    .collect(),
+            ),
         ];
 
         let mut patches = HashMap::new();
@@ -607,12 +807,16 @@ impl GameMenu {
             current_menu: MenuIndex::TopLevel,
             patches,
             palette,
-            episode: 0,
+            episode_starts,
+            episode: (1, 1),
             which_skull: 0,
             skull_anim_counter: 10,
             restart_needed: false,
             dim_background: true,
             video_snapshot: None,
+            bind_action: None,
+            bind_mod_key: None,
+            bindings: Bindings::default(),
         }
     }
 
@@ -628,6 +832,43 @@ impl GameMenu {
         }
     }
 
+    /// Save to the quicksave slot, asking for the slot the first time.
+    fn quick_save<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) {
+        if game.game_state() != GameState::Level {
+            game.start_sound(SfxName::Oof);
+            return;
+        }
+        if self.quicksave_slot < 0 {
+            // First quicksave: open save menu to pick a slot
+            self.quicksave_slot = QS_PICKING;
+            self.active = true;
+            self.open_save_menu(game);
+        } else {
+            // Re-save to previously chosen slot
+            let slot = self.quicksave_slot as usize;
+            self.read_save_strings(game);
+            let desc = self.save_strings[slot].clone();
+            let desc = if desc == EMPTY_STRING {
+                String::new()
+            } else {
+                desc
+            };
+            game.save_game(format!("slot{slot}"), desc);
+        }
+        game.start_sound(SfxName::Swtchn);
+    }
+
+    /// Load the quicksave slot, if one was chosen.
+    fn quick_load<T: GameTraits + ConfigTraits>(&self, game: &mut T) {
+        if self.quicksave_slot >= 0 {
+            let slot = self.quicksave_slot as usize;
+            game.load_game(format!("slot{slot}"));
+            game.start_sound(SfxName::Swtchn);
+        } else {
+            game.start_sound(SfxName::Oof);
+        }
+    }
+
     /// Sets menu state on exit
     fn exit_menu<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) {
         if self.current_menu == MenuIndex::OptVideo {
@@ -637,6 +878,8 @@ impl GameMenu {
         self.active = false;
         self.in_help = false;
         self.save_enter = false;
+        self.bind_action = None;
+        self.bind_mod_key = None;
         self.current_menu = MenuIndex::TopLevel;
         game.start_sound(SfxName::Swtchx);
     }
@@ -757,15 +1000,15 @@ impl GameMenu {
             MenuAction::None => {}
             MenuAction::GoTo(target) => self.current_menu = target,
             MenuAction::NewGame => {
-                self.current_menu = if game.get_mode() == GameMode::Commercial {
+                self.current_menu = if self.episode_starts.is_empty() {
+                    self.episode = (1, 1);
                     MenuIndex::Skill
                 } else {
                     MenuIndex::Episodes
                 };
             }
             MenuAction::SelectEpisode => {
-                self.episode =
-                    self.menus[MenuIndex::Episodes as usize].items[choice].cached_value as usize;
+                self.episode = self.episode_starts[choice];
                 self.current_menu = MenuIndex::Skill;
             }
             MenuAction::OpenLoadGame => self.open_load_menu(game),
@@ -802,9 +1045,17 @@ impl GameMenu {
                 self.refresh_options_cache(MenuIndex::OptInput, game);
                 self.current_menu = MenuIndex::OptInput;
             }
+            MenuAction::OpenOptControls => {
+                self.bindings = game.bindings().clone();
+                self.current_menu = MenuIndex::OptControls;
+            }
+            MenuAction::ResetBindings => {
+                self.set_bindings(Bindings::default(), game);
+            }
             MenuAction::StartGame => {
                 self.exit_menu(game);
-                game.defered_init_new(Skill::from(choice), self.episode, 1);
+                let (episode, map) = self.episode;
+                game.defered_init_new(Skill::from(choice), episode, map);
             }
             MenuAction::LoadSlot => {
                 game.load_game(format!("slot{choice}"));
@@ -838,6 +1089,48 @@ impl GameMenu {
         }
     }
 
+    /// Hand changed bindings to the game and save them.
+    fn set_bindings<T: GameTraits + ConfigTraits>(&mut self, bindings: Bindings, game: &mut T) {
+        self.bindings = bindings.clone();
+        game.set_bindings(bindings);
+        game.mark_config_changed();
+    }
+
+    /// Add `binding` to the action waiting for one and stop waiting.
+    fn finish_binding<T: GameTraits + ConfigTraits>(&mut self, binding: Binding, game: &mut T) {
+        self.bind_mod_key = None;
+        if let Some(action) = self.bind_action.take() {
+            let mut bindings = game.bindings().clone();
+            bindings.bind(action, binding);
+            self.set_bindings(bindings, game);
+            game.start_sound(SfxName::Pistol);
+        }
+    }
+
+    /// Input while the Controls page waits for a binding. Escape cancels.
+    fn bind_responder<T: GameTraits + ConfigTraits>(&mut self, sc: KeyCode, game: &mut T) -> bool {
+        if sc == KeyCode::Escape {
+            self.bind_action = None;
+            self.bind_mod_key = None;
+            game.start_sound(SfxName::Swtchx);
+            return true;
+        }
+        if sc.modifier().is_some() {
+            // Left held so the ticker can see when it is released.
+            self.bind_mod_key = Some(sc);
+            return false;
+        }
+        let modifier = self.bind_mod_key.and_then(KeyCode::modifier);
+        self.finish_binding(
+            Binding {
+                input: Input::Key(sc),
+                modifier,
+            },
+            game,
+        );
+        true
+    }
+
     /// Restore video config values to the snapshot taken on menu entry,
     /// discarding unapplied changes.
     fn revert_video_snapshot<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) {
@@ -896,6 +1189,7 @@ impl GameMenu {
                 | MenuIndex::OptGraphics
                 | MenuIndex::OptHud
                 | MenuIndex::OptInput
+                | MenuIndex::OptControls
         )
     }
 
@@ -907,6 +1201,7 @@ impl GameMenu {
                 | MenuIndex::OptGraphics
                 | MenuIndex::OptHud
                 | MenuIndex::OptInput
+                | MenuIndex::OptControls
         )
     }
 
@@ -1058,7 +1353,8 @@ impl GameMenu {
                 let center_x = x_ofs + 160.0 * draw_sx;
                 let gap = 4.0 * draw_sx;
                 let sel_idx = active.last_on;
-                for (item_i, item) in active.items.iter().enumerate() {
+                let rows = active.visible_rows();
+                for (item_i, item) in active.items.iter().enumerate().skip(active.top).take(rows) {
                     let label_tint = if item_i == sel_idx {
                         TINT_SELECTED
                     } else {
@@ -1125,10 +1421,49 @@ impl GameMenu {
                                 pixels,
                             );
                         }
-                        ItemKind::Label | ItemKind::Patch => {}
+                        ItemKind::Binding(action) => {
+                            let (text, tint) = if self.bind_action == Some(*action) {
+                                (lang::OPT_BIND_PROMPT.to_owned(), TINT_SELECTED)
+                            } else {
+                                let binds = self.bindings.get(*action);
+                                let text = if binds.is_empty() {
+                                    lang::OPT_BIND_NONE.to_owned()
+                                } else {
+                                    binds
+                                        .iter()
+                                        .map(binding_text)
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                };
+                                (text, TINT_VALUE)
+                            };
+                            draw_text_line_tinted(
+                                &text,
+                                center_x + gap,
+                                y,
+                                draw_sx,
+                                draw_sy,
+                                &self.palette,
+                                tint,
+                                pixels,
+                            );
+                        }
+                        ItemKind::Label | ItemKind::Patch | ItemKind::Text(_) => {}
                     }
                     y += LINEHEIGHT as f32 * draw_sy;
                 }
+                if self.current_menu == MenuIndex::OptControls {
+                    let note = lang::OPT_BIND_HELP;
+                    draw_text_line(
+                        note,
+                        center_x - measure_text_line(note, draw_sx) / 2.0,
+                        pixels.size().height_f32() - 16.0 * draw_sy,
+                        draw_sx,
+                        draw_sy,
+                        &self.palette,
+                        pixels,
+                    );
+                }
                 if self.restart_needed && self.in_options_submenu() {
                     let note_y = pixels.size().height_f32() - 16.0 * draw_sy;
                     draw_text_line(
@@ -1153,6 +1488,8 @@ impl GameMenu {
                             &self.palette,
                             pixels,
                         );
+                    } else if let ItemKind::Text(text) = &item.kind {
+                        draw_text_line(text, x, y, draw_sx, draw_sy, &self.palette, pixels);
                     }
                     y += LINEHEIGHT as f32 * draw_sy;
                 }
@@ -1224,9 +1561,22 @@ impl SubsystemTrait for GameMenu {
             }
         }
 
+        if self.bind_action.is_some() {
+            return self.bind_responder(sc, game);
+        }
+
         if !self.active {
-            // F-keys
+            // F-keys, quicksave and quickload as bound
+            let action = game.key_action(sc);
             match sc {
+                _ if action == Some(Action::QuickSave) => {
+                    self.quick_save(game);
+                    return true;
+                }
+                _ if action == Some(Action::QuickLoad) => {
+                    self.quick_load(game);
+                    return true;
+                }
                 KeyCode::F1 => {
                     // HELP
                     self.in_help = !self.in_help;
@@ -1256,43 +1606,6 @@ impl SubsystemTrait for GameMenu {
                     game.start_sound(SfxName::Swtchn);
                     return true;
                 }
-                KeyCode::F6 => {
-                    // Quicksave
-                    if game.game_state() != GameState::Level {
-                        game.start_sound(SfxName::Oof);
-                        return true;
-                    }
-                    if self.quicksave_slot < 0 {
-                        // First quicksave: open save menu to pick a slot
-                        self.quicksave_slot = QS_PICKING;
-                        self.active = true;
-                        self.open_save_menu(game);
-                    } else {
-                        // Re-save to previously chosen slot
-                        let slot = self.quicksave_slot as usize;
-                        self.read_save_strings(game);
-                        let desc = self.save_strings[slot].clone();
-                        let desc = if desc == EMPTY_STRING {
-                            String::new()
-                        } else {
-                            desc
-                        };
-                        game.save_game(format!("slot{slot}"), desc);
-                    }
-                    game.start_sound(SfxName::Swtchn);
-                    return true;
-                }
-                KeyCode::F9 => {
-                    // Quickload
-                    if self.quicksave_slot >= 0 {
-                        let slot = self.quicksave_slot as usize;
-                        game.load_game(format!("slot{slot}"));
-                        game.start_sound(SfxName::Swtchn);
-                    } else {
-                        game.start_sound(SfxName::Oof);
-                    }
-                    return true;
-                }
                 KeyCode::Pause => {
                     game.toggle_pause_game();
                     return true;
@@ -1310,7 +1623,9 @@ impl SubsystemTrait for GameMenu {
                 let hk = hot_key.chars().next().unwrap();
                 for (i, item) in self.get_current_menu().items.iter().enumerate() {
                     if item.hotkey == hk {
-                        self.get_current_menu().last_on = i;
+                        let active = self.get_current_menu();
+                        active.last_on = i;
+                        active.scroll_to_cursor();
                         sc = KeyCode::Return;
                         break;
                     }
@@ -1342,6 +1657,7 @@ impl SubsystemTrait for GameMenu {
                     if active.last_on >= active.items.len() {
                         active.last_on = 0;
                     }
+                    active.scroll_to_cursor();
                     game.start_sound(SfxName::Pstop);
                     return true;
                 }
@@ -1355,6 +1671,7 @@ impl SubsystemTrait for GameMenu {
                     } else {
                         active.last_on -= 1;
                     }
+                    active.scroll_to_cursor();
                     game.start_sound(SfxName::Pstop);
                     return true;
                 }
@@ -1374,6 +1691,9 @@ impl SubsystemTrait for GameMenu {
                             } => {
                                 self.adjust_option_item(idx, last_on, 1, game);
                             }
+                            ItemKind::Binding(action) => {
+                                self.bind_action = Some(*action);
+                            }
                             _ => {
                                 self.execute_action(action, last_on, game);
                             }
@@ -1394,6 +1714,18 @@ impl SubsystemTrait for GameMenu {
                 }
 
                 KeyCode::Backspace => {
+                    let active = self.get_current_menu();
+                    let bound = match active.items.get(active.last_on).map(|i| &i.kind) {
+                        Some(ItemKind::Binding(action)) => Some(*action),
+                        _ => None,
+                    };
+                    if let Some(action) = bound {
+                        let mut bindings = game.bindings().clone();
+                        bindings.clear(action);
+                        self.set_bindings(bindings, game);
+                        game.start_sound(SfxName::Pstop);
+                        return true;
+                    }
                     if self.current_menu == MenuIndex::OptVideo {
                         self.revert_video_snapshot(game);
                     }
@@ -1419,6 +1751,13 @@ impl SubsystemTrait for GameMenu {
             self.which_skull ^= 1;
             self.skull_anim_counter = 8;
         }
+        if let Some(key) = self.bind_mod_key
+            && key
+                .modifier()
+                .is_some_and(|m| !game.modifiers().contains(m))
+        {
+            self.finish_binding(Binding::key(key), game);
+        }
         if self.active && self.is_options_menu() {
             let idx = self.current_menu as usize;
             for item in &mut self.menus[idx].items {
@@ -1431,7 +1770,120 @@ impl SubsystemTrait for GameMenu {
         self.active
     }
 
+    /// Mouse buttons and wheel steps only matter while the Controls page
+    /// waits for a binding. Pad buttons also drive an open menu, with Start
+    /// standing in for Escape.
+    fn device_responder<T: GameTraits + ConfigTraits>(
+        &mut self,
+        input: Input,
+        game: &mut T,
+    ) -> bool {
+        if self.bind_action.is_none() {
+            if !self.active && !self.in_help && !self.save_enter {
+                match game.bindings().action_for(input, game.modifiers()) {
+                    Some(Action::QuickSave) => {
+                        self.quick_save(game);
+                        return true;
+                    }
+                    Some(Action::QuickLoad) => {
+                        self.quick_load(game);
+                        return true;
+                    }
+                    _ => {}
+                }
+            }
+            let Input::Pad(b) = input else {
+                return false;
+            };
+            let sc = match b {
+                PadButton::Start => KeyCode::Escape,
+                _ if !self.active && !self.in_help => return false,
+                PadButton::DPadUp => KeyCode::Up,
+                PadButton::DPadDown => KeyCode::Down,
+                PadButton::DPadLeft => KeyCode::Left,
+                PadButton::DPadRight => KeyCode::Right,
+                PadButton::A => KeyCode::Return,
+                PadButton::B => KeyCode::Backspace,
+                _ => return false,
+            };
+            return self.responder(sc, game);
+        }
+        if input == Input::Pad(PadButton::Start) {
+            return self.bind_responder(KeyCode::Escape, game);
+        }
+        let modifier = self.bind_mod_key.and_then(KeyCode::modifier);
+        self.finish_binding(
+            Binding {
+                input,
+                modifier,
+            },
+            game,
+        );
+        true
+    }
+
     fn draw(&mut self, buffer: &mut impl DrawBuffer) {
         self.draw_pixels(buffer);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use game_config::GameMode;
+
+    use super::episode_choices;
+
+    fn starts(
+        mode: GameMode,
+        stock: usize,
+        umapinfo: Option<&str>,
+    ) -> Vec<(usize, usize, String, char)> {
+        let info = umapinfo.map(|u| wad::umapinfo::parse(u).unwrap());
+        let has_lump = |name: &str| {
+            name.strip_prefix("M_EPI")
+                .and_then(|e| e.parse::<usize>().ok())
+                .is_some_and(|e| e <= stock)
+        };
+        episode_choices(mode, has_lump, info.as_ref())
+            .into_iter()
+            .map(|c| (c.episode, c.map, c.patch, c.hotkey))
+            .collect()
+    }
+
+    #[test]
+    fn stock_episodes_follow_the_wad() {
+        let eps = starts(GameMode::Retail, 4, None);
+        assert_eq!(eps.len(), 4);
+        assert_eq!(eps[3], (4, 1, "M_EPI4".to_owned(), '4'));
+        assert!(starts(GameMode::Commercial, 0, None).is_empty());
+    }
+
+    #[test]
+    fn umapinfo_episodes_add_replace_and_clear() {
+        let sigil = r#"
+map E1M1 { episode = "M_EPI1", "Knee-Deep", "k" }
+map E5M1 { episode = "M_EPI5", "Sigil", "s" }
+"#;
+        let eps = starts(GameMode::Retail, 4, Some(sigil));
+        assert_eq!(eps.len(), 5);
+        assert_eq!(eps[0], (1, 1, "M_EPI1".to_owned(), 'K'));
+        assert_eq!(eps[4], (5, 1, "M_EPI5".to_owned(), 'S'));
+
+        let conversion = r#"
+map MAP01
+{
+    episode = clear
+    episode = "WAD1", "Part One", "o"
+}
+map MAP12 { episode = "WAD2", "Part Two", "t" }
+"#;
+        let eps = starts(GameMode::Commercial, 0, Some(conversion));
+        assert_eq!(
+            eps,
+            [
+                (1, 1, "WAD1".to_owned(), 'O'),
+                (1, 12, "WAD2".to_owned(), 'T')
+            ]
+        );
+    }
+}
@@ -6,7 +6,7 @@
 
 use faces::DoomguyFace;
 use game_config::{GameMode, WeaponType};
-use gameplay::{AmmoType, PlayerStatus, WEAPON_INFO};
+use gameplay::{AmmoType, MAXPLAYERS, PlayerStatus, weapon_info};
 use gamestate_traits::{ConfigKey, ConfigTraits, GameTraits, KeyCode, SubsystemTrait};
 use hud_util::{draw_num, draw_patch, hud_scale, load_key_sprites, load_num_sprites};
 use render_common::{DrawBuffer, STBAR_HEIGHT};
@@ -45,10 +45,19 @@ const ST_ARMSBGY: f32 = 168.0;
 const ST_ARMSXSPACE: f32 = 12.0;
 const ST_ARMSYSPACE: f32 = 10.0;
 
+// Frags, in place of the arms grid in deathmatch
+const ST_FRAGSX: f32 = 138.0;
+const ST_FRAGSY: f32 = 171.0;
+
 // Face
 const ST_FACESX: f32 = 143.0;
 const ST_FACESY: f32 = 170.0;
 
+// Face background in the player's colour, netgames only
+const ST_FX: f32 = 143.0;
+const ST_FY: f32 = 169.0;
+const FACE_BACKS: [&str; MAXPLAYERS] = ["STFB0", "STFB1", "STFB2", "STFB3"];
+
 // Armor (big nums)
 const ST_ARMORX: f32 = 221.0;
 const ST_ARMORY: f32 = 171.0;
@@ -88,6 +97,11 @@ pub struct Statusbar {
     /// Keys: blue yellow red. Skulls: blue yellow red
     keys: [WadPatch; 6],
     status: PlayerStatus,
+    netgame: bool,
+    deathmatch: bool,
+    console_player: usize,
+    /// Frags less suicides
+    frags: i32,
     faces: DoomguyFace,
     widescreen: bool,
     bar_mode: bool,
@@ -103,8 +117,13 @@ impl Statusbar {
 
         let mut patches = HashMap::new();
 
-        let lump = wad.get_lump("STFB1").unwrap();
-        patches.insert("STFB1", WadPatch::from_lump(lump));
+        for name in FACE_BACKS {
+            let lump = wad.get_lump(name).unwrap();
+            patches.insert(name, WadPatch::from_lump(lump));
+        }
+        if let Some(lump) = wad.get_lump("STTMINUS") {
+            patches.insert("STTMINUS", WadPatch::from_lump(lump));
+        }
 
         if let Some(lump) = wad.get_lump("STARMS") {
             patches.insert("STARMS", WadPatch::from_lump(lump));
@@ -139,6 +158,10 @@ impl Statusbar {
             yell_nums: load_num_sprites("STYSNUM", 0, wad),
             keys: load_key_sprites(wad),
             status: PlayerStatus::default(),
+            netgame: false,
+            deathmatch: false,
+            console_player: 0,
+            frags: 0,
             faces: DoomguyFace::new(wad),
             widescreen: false,
             bar_mode: false,
@@ -153,6 +176,15 @@ impl Statusbar {
             .unwrap_or_else(|| panic!("{name} not in cache"))
     }
 
+    /// The face background: the player's colour in a netgame, else `STFB1`.
+    fn face_back(&self) -> &WadPatch {
+        if self.netgame {
+            self.get_patch(FACE_BACKS[self.console_player])
+        } else {
+            self.get_patch(FACE_BACKS[1])
+        }
+    }
+
     // ========================================================================
     // Fullscreen HUD (no background, overlay on 3D view)
     // ========================================================================
@@ -165,10 +197,57 @@ impl Statusbar {
         self.draw_health_fullscreen(true, face, buffer);
         self.draw_armour_fullscreen(face, buffer);
         self.draw_ammo_big_fullscreen(buffer);
-        self.draw_weapons_fullscreen(buffer);
+        if self.deathmatch {
+            self.draw_frags_fullscreen(buffer);
+        } else {
+            self.draw_weapons_fullscreen(buffer);
+        }
         self.draw_keys_fullscreen(buffer);
     }
 
+    /// Draw the frag count right-aligned at `x` in big numbers, with a minus
+    /// if the player has killed themselves more than anyone else.
+    fn draw_frags(&self, x: f32, y: f32, sx: f32, sy: f32, buffer: &mut impl DrawBuffer) {
+        let x = draw_num(
+            self.frags.unsigned_abs(),
+            x,
+            y,
+            0,
+            &self.big_nums,
+            sx,
+            sy,
+            &self.palette,
+            buffer,
+        );
+        if self.frags < 0
+            && let Some(minus) = self.patches.get("STTMINUS")
+        {
+            draw_patch(
+                minus,
+                x - minus.width as f32 * sx,
+                y,
+                sx,
+                sy,
+                &self.palette,
+                buffer,
+            );
+        }
+    }
+
+    fn draw_frags_fullscreen(&self, pixels: &mut impl DrawBuffer) {
+        let (sx, sy) = hud_scale(pixels);
+        // Where the weapon numbers would end
+        let x = self.screen_width
+            - self.big_nums[0].width as f32 * sx * 2.0
+            - self.keys[0].width as f32 * sx
+            - 2.0;
+        let y = self.screen_height
+            - 2.0
+            - self.big_nums[0].height as f32 * sy
+            - self.grey_nums[0].height as f32 * sy;
+        self.draw_frags(x, y, sx, sy, pixels);
+    }
+
     fn draw_health_fullscreen(&self, big: bool, face: bool, pixels: &mut impl DrawBuffer) {
         let (sx, sy) = hud_scale(pixels);
         let nums = if big { &self.big_nums } else { &self.lil_nums };
@@ -251,7 +330,7 @@ impl Statusbar {
         {
             return;
         }
-        let ammo = WEAPON_INFO[self.status.readyweapon as usize].ammo;
+        let ammo = weapon_info()[self.status.readyweapon as usize].ammo;
         if ammo == AmmoType::NoAmmo {
             return;
         }
@@ -355,7 +434,7 @@ impl Statusbar {
         let mut x: f32;
         let mut y: f32;
         if big && !upper {
-            let patch = self.get_patch("STFB1");
+            let patch = self.face_back();
             y = self.screen_height - patch.height as f32 * sy;
             x = f32::midpoint(self.x_ofs, self.screen_width) - patch.width as f32 * sx / 2.0;
             draw_patch(patch, x, y, sx, sy, &self.palette, pixels);
@@ -461,8 +540,9 @@ impl Statusbar {
 
         self.draw_stbar_background(sx, sy, bar_y, x, buffer);
 
-        // ARMS background patch
+        // ARMS background patch, the bar underneath says FRAG
         if self.mode != GameMode::Commercial
+            && !self.deathmatch
             && let Some(patch) = self.patches.get("STARMS")
         {
             draw_patch(
@@ -476,6 +556,18 @@ impl Statusbar {
             );
         }
 
+        if self.netgame {
+            draw_patch(
+                self.face_back(),
+                x + ST_FX * sx,
+                ST_FY * sy,
+                sx,
+                sy,
+                &self.palette,
+                buffer,
+            );
+        }
+
         // Face
         let face = self.faces.get_face();
         draw_patch(
@@ -493,7 +585,7 @@ impl Statusbar {
             && (self.mode == GameMode::Commercial
                 || self.status.readyweapon != WeaponType::SuperShotgun)
         {
-            let ammo_type = WEAPON_INFO[self.status.readyweapon as usize].ammo;
+            let ammo_type = weapon_info()[self.status.readyweapon as usize].ammo;
             if ammo_type != AmmoType::NoAmmo {
                 draw_num(
                     self.status.ammo[ammo_type as usize],
@@ -545,8 +637,12 @@ impl Statusbar {
             buffer,
         );
 
+        if self.deathmatch {
+            self.draw_frags(x + ST_FRAGSX * sx, ST_FRAGSY * sy, sx, sy, buffer);
+        }
+
         // Arms (2 rows × 3 cols)
-        if self.mode != GameMode::Commercial {
+        if self.mode != GameMode::Commercial && !self.deathmatch {
             for i in 0..6 {
                 if !self.status.weaponowned[i + 1] {
                     continue;
@@ -629,6 +725,15 @@ impl SubsystemTrait for Statusbar {
 
     fn ticker<T: GameTraits + ConfigTraits>(&mut self, game: &mut T) -> bool {
         self.status = game.player_status();
+        self.netgame = game.netgame();
+        self.deathmatch = self.netgame && game.deathmatch() != 0;
+        self.console_player = game.console_player();
+        self.frags = game
+            .player_frags()
+            .iter()
+            .enumerate()
+            .map(|(i, &f)| if i == self.console_player { -f } else { f })
+            .sum();
         self.faces.tick(&self.status);
         self.widescreen = game.config_value(ConfigKey::HudWidth) != 0;
         self.bar_mode = game.config_value(ConfigKey::HudSize) == 1;
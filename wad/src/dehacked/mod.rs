@@ -0,0 +1,95 @@
+mod parse;
+
+pub use parse::ParseError;
+
+/// A parsed DEHACKED/BEX patch. Values are kept as written; resolving them
+/// against the info tables is up to the consumer.
+#[derive(Debug, Clone, Default)]
+pub struct DehPatch {
+    /// `Doom version = N` from the header (12, 16, 19, 21; 2021 for MBF21).
+    pub doom_version: Option<i32>,
+    /// `Patch format = N` from the header, 6 for text patches.
+    pub patch_format: Option<i32>,
+    /// `Thing N` blocks, 1-based as written.
+    pub things: Vec<DehBlock>,
+    /// `Frame N` blocks.
+    pub frames: Vec<DehBlock>,
+    /// `Weapon N` blocks.
+    pub weapons: Vec<DehBlock>,
+    /// `Ammo N` blocks.
+    pub ammo: Vec<DehBlock>,
+    /// `Sound N` blocks.
+    pub sounds: Vec<DehBlock>,
+    /// `Misc` block fields.
+    pub misc: Vec<(String, String)>,
+    /// `Cheat` block fields.
+    pub cheats: Vec<(String, String)>,
+    /// `Pointer N (Frame F)` blocks.
+    pub pointers: Vec<DehPointer>,
+    /// BEX `[CODEPTR]` entries: frame and action name.
+    pub code_pointers: Vec<(usize, String)>,
+    /// `Text` blocks: original and replacement.
+    pub texts: Vec<(String, String)>,
+    /// BEX `[STRINGS]` entries: mnemonic and replacement.
+    pub strings: Vec<(String, String)>,
+    /// BEX `[PARS]` entries.
+    pub pars: Vec<DehPar>,
+}
+
+/// A numbered block of `field = value` lines. Field names are lowercased.
+#[derive(Debug, Clone, Default)]
+pub struct DehBlock {
+    pub index: usize,
+    pub fields: Vec<(String, String)>,
+}
+
+impl DehBlock {
+    /// The last value written for `field` (case-insensitive).
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .rev()
+            .find(|(k, _)| k.eq_ignore_ascii_case(field))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// `Pointer` block: give `frame` the action `codep_frame` had originally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DehPointer {
+    pub frame: usize,
+    pub codep_frame: usize,
+}
+
+/// BEX par time. `episode` is 0 for Doom II `par <map> <seconds>` lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DehPar {
+    pub episode: usize,
+    pub map: usize,
+    pub seconds: i32,
+}
+
+pub fn parse(input: &str) -> Result<DehPatch, ParseError> {
+    parse::parse(input)
+}
+
+/// Parse a DEHACKED integer: decimal, negative, or `0x` hex.
+pub fn parse_int(value: &str) -> Option<i32> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let magnitude = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()? as i64,
+        None => digits.parse::<i64>().ok()?,
+    };
+    let signed = if negative { -magnitude } else { magnitude };
+    // Bits fields are written unsigned up to 0xFFFFFFFF
+    i32::try_from(signed)
+        .ok()
+        .or_else(|| u32::try_from(signed).ok().map(|v| v as i32))
+}
@@ -0,0 +1,501 @@
+use super::*;
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DEHACKED line {}: {}", self.line, self.message)
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// The block the following `field = value` lines belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Thing,
+    Frame,
+    Weapon,
+    Ammo,
+    Sound,
+    Misc,
+    Cheat,
+    Pointer(usize),
+    Strings,
+    CodePtr,
+    Pars,
+    /// Recognised but unsupported, or unknown: skip to the next header.
+    Skip,
+}
+
+impl Section {
+    /// Numbered DeHackEd blocks end at a blank line; BEX sections do not.
+    const fn ends_at_blank(self) -> bool {
+        !matches!(self, Self::Strings | Self::CodePtr | Self::Pars)
+    }
+}
+
+struct Lines<'a> {
+    src: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    const fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            pos: 0,
+            line: 0,
+        }
+    }
+
+    fn next_line(&mut self) -> Option<&'a str> {
+        if self.pos >= self.src.len() {
+            return None;
+        }
+        let rest = &self.src[self.pos..];
+        let end = rest.find('\n').unwrap_or(rest.len());
+        self.pos += (end + 1).min(rest.len());
+        self.line += 1;
+        Some(rest[..end].trim_end_matches('\r'))
+    }
+
+    /// Read `count` raw characters for a `Text` block. Newlines count as one
+    /// character and carriage returns are skipped.
+    fn read_chars(&mut self, count: usize) -> Option<String> {
+        let mut out = String::with_capacity(count);
+        let mut chars = self.src[self.pos..].char_indices();
+        let mut consumed = 0;
+        while out.chars().count() < count {
+            let (i, c) = chars.next()?;
+            consumed = i + c.len_utf8();
+            match c {
+                '\r' => {}
+                '\n' => {
+                    self.line += 1;
+                    out.push(c);
+                }
+                _ => out.push(c),
+            }
+        }
+        self.pos += consumed;
+        Some(out)
+    }
+}
+
+pub(super) fn parse(input: &str) -> Result<DehPatch, ParseError> {
+    let mut patch = DehPatch::default();
+    let mut lines = Lines::new(input);
+    let mut section = Section::None;
+
+    while let Some(raw) = lines.next_line() {
+        let line_no = lines.line;
+        let line = raw.trim();
+        if line.is_empty() {
+            if section.ends_at_blank() {
+                section = Section::None;
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if section == Section::Pars && starts_with_word(line, "par") {
+            patch.pars.push(parse_par(line, line_no)?);
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim();
+            let value = value.trim();
+            match section {
+                Section::None => header_field(&mut patch, key, value),
+                Section::Thing => push_field(&mut patch.things, key, value),
+                Section::Frame => push_field(&mut patch.frames, key, value),
+                Section::Weapon => push_field(&mut patch.weapons, key, value),
+                Section::Ammo => push_field(&mut patch.ammo, key, value),
+                Section::Sound => push_field(&mut patch.sounds, key, value),
+                Section::Misc => patch
+                    .misc
+                    .push((key.to_ascii_lowercase(), value.to_owned())),
+                Section::Cheat => patch
+                    .cheats
+                    .push((key.to_ascii_lowercase(), value.to_owned())),
+                Section::Pointer(frame) => {
+                    if key.eq_ignore_ascii_case("codep frame") {
+                        let codep_frame = parse_index(value, line_no)?;
+                        patch.pointers.push(DehPointer {
+                            frame,
+                            codep_frame,
+                        });
+                    }
+                }
+                Section::Strings => {
+                    let value = read_continued(&mut lines, value);
+                    patch
+                        .strings
+                        .push((key.to_ascii_uppercase(), unescape(&value)));
+                }
+                Section::CodePtr => {
+                    let frame = key
+                        .split_whitespace()
+                        .nth(1)
+                        .ok_or_else(|| error(line_no, "expected `FRAME <n> = <action>`"))?;
+                    patch
+                        .code_pointers
+                        .push((parse_index(frame, line_no)?, value.to_owned()));
+                }
+                Section::Pars | Section::Skip => {}
+            }
+            continue;
+        }
+
+        section = parse_header(&mut patch, &mut lines, line, line_no)?;
+    }
+
+    Ok(patch)
+}
+
+/// Start a new block from a header line such as `Thing 1 (Player)`.
+fn parse_header(
+    patch: &mut DehPatch,
+    lines: &mut Lines<'_>,
+    line: &str,
+    line_no: usize,
+) -> Result<Section, ParseError> {
+    if let Some(name) = line.strip_prefix('[') {
+        let name = name.trim_end_matches(']').trim();
+        return Ok(match name.to_ascii_uppercase().as_str() {
+            "STRINGS" => Section::Strings,
+            "CODEPTR" => Section::CodePtr,
+            "PARS" => Section::Pars,
+            other => {
+                log::warn!("DEHACKED line {line_no}: skipping unsupported [{other}]");
+                Section::Skip
+            }
+        });
+    }
+
+    let mut words = line.split_whitespace();
+    let keyword = words.next().unwrap_or_default().to_ascii_lowercase();
+    let number = words.next();
+    let block = |blocks: &mut Vec<DehBlock>| -> Result<(), ParseError> {
+        let index = parse_index(number.unwrap_or_default(), line_no)?;
+        blocks.push(DehBlock {
+            index,
+            fields: Vec::new(),
+        });
+        Ok(())
+    };
+
+    let section = match keyword.as_str() {
+        "thing" => {
+            block(&mut patch.things)?;
+            Section::Thing
+        }
+        "frame" => {
+            block(&mut patch.frames)?;
+            Section::Frame
+        }
+        "weapon" => {
+            block(&mut patch.weapons)?;
+            Section::Weapon
+        }
+        "ammo" => {
+            block(&mut patch.ammo)?;
+            Section::Ammo
+        }
+        "sound" => {
+            block(&mut patch.sounds)?;
+            Section::Sound
+        }
+        "misc" => Section::Misc,
+        "cheat" => Section::Cheat,
+        "pointer" => {
+            // `Pointer 12 (Frame 34)`: the frame in brackets is what changes
+            let frame = line
+                .split_once('(')
+                .and_then(|(_, rest)| rest.split_whitespace().nth(1))
+                .map(|n| n.trim_end_matches(')'))
+                .ok_or_else(|| error(line_no, "expected `Pointer <n> (Frame <f>)`"))?;
+            Section::Pointer(parse_index(frame, line_no)?)
+        }
+        "text" => {
+            let from_len = parse_index(number.unwrap_or_default(), line_no)?;
+            let to_len = parse_index(words.next().unwrap_or_default(), line_no)?;
+            let text = lines
+                .read_chars(from_len + to_len)
+                .ok_or_else(|| error(line_no, "Text block runs past the end of the patch"))?;
+            let split = text
+                .char_indices()
+                .nth(from_len)
+                .map_or(text.len(), |(i, _)| i);
+            patch
+                .texts
+                .push((text[..split].to_owned(), text[split..].to_owned()));
+            Section::None
+        }
+        "patch" | "doom" => Section::None,
+        "sprite" => {
+            log::debug!("DEHACKED line {line_no}: ignoring obsolete Sprite block");
+            Section::Skip
+        }
+        other => {
+            log::warn!("DEHACKED line {line_no}: skipping unknown block `{other}`");
+            Section::Skip
+        }
+    };
+    Ok(section)
+}
+
+fn header_field(patch: &mut DehPatch, key: &str, value: &str) {
+    if key.eq_ignore_ascii_case("doom version") {
+        patch.doom_version = parse_int(value);
+    } else if key.eq_ignore_ascii_case("patch format") {
+        patch.patch_format = parse_int(value);
+    }
+}
+
+fn push_field(blocks: &mut [DehBlock], key: &str, value: &str) {
+    if let Some(block) = blocks.last_mut() {
+        block
+            .fields
+            .push((key.to_ascii_lowercase(), value.to_owned()));
+    }
+}
+
+/// `par <episode> <map> <seconds>` or `par <map> <seconds>`.
+fn parse_par(line: &str, line_no: usize) -> Result<DehPar, ParseError> {
+    let nums = line
+        .split_whitespace()
+        .skip(1)
+        .map(|n| parse_int(n).ok_or_else(|| error(line_no, "bad par number")))
+        .collect::<Result<Vec<_>, _>>()?;
+    match *nums.as_slice() {
+        [episode, map, seconds] => Ok(DehPar {
+            episode: episode.max(0) as usize,
+            map: map.max(0) as usize,
+            seconds,
+        }),
+        [map, seconds] => Ok(DehPar {
+            episode: 0,
+            map: map.max(0) as usize,
+            seconds,
+        }),
+        _ => Err(error(line_no, "expected `par [episode] <map> <seconds>`")),
+    }
+}
+
+/// BEX string values continue onto the next line after a trailing `\`.
+fn read_continued(lines: &mut Lines<'_>, value: &str) -> String {
+    let mut value = value.to_owned();
+    while value.ends_with('\\') {
+        value.pop();
+        match lines.next_line() {
+            Some(next) => value.push_str(next.trim()),
+            None => break,
+        }
+    }
+    value
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+fn starts_with_word(line: &str, word: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .is_some_and(|w| w.eq_ignore_ascii_case(word))
+}
+
+fn parse_index(value: &str, line_no: usize) -> Result<usize, ParseError> {
+    parse_int(value)
+        .and_then(|n| usize::try_from(n).ok())
+        .ok_or_else(|| error(line_no, &format!("expected a number, found `{value}`")))
+}
+
+fn error(line: usize, message: &str) -> ParseError {
+    ParseError {
+        line,
+        message: message.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATCH: &str = "Patch File for DeHackEd v3.0
+# Changes made:
+Doom version = 21
+Patch format = 6
+
+Thing 1 (Player)
+Hit points = 200
+Bits = SOLID+SHOOTABLE
+
+Frame 174
+Duration = 0x10
+Next frame = 175
+
+Pointer 76 (Frame 176)
+Codep Frame = 180
+
+Weapon 3 (Chaingun)
+Ammo type = 1
+
+Misc 0
+Initial Health = 150
+
+Text 4 4
+BOSSBBRN
+Text 20 9
+Picked up the armor.New armor
+
+Cheat 0
+God mode = iddqd
+";
+
+    #[test]
+    fn parses_numbered_blocks() {
+        let patch = parse(PATCH).unwrap();
+        assert_eq!(patch.doom_version, Some(21));
+        assert_eq!(patch.patch_format, Some(6));
+        assert_eq!(patch.things.len(), 1);
+        assert_eq!(patch.things[0].index, 1);
+        assert_eq!(patch.things[0].get("Hit Points"), Some("200"));
+        assert_eq!(patch.things[0].get("bits"), Some("SOLID+SHOOTABLE"));
+        assert_eq!(patch.frames[0].index, 174);
+        assert_eq!(
+            patch.frames[0].get("duration").and_then(parse_int),
+            Some(16)
+        );
+        assert_eq!(patch.weapons[0].index, 3);
+        assert_eq!(
+            patch.misc,
+            vec![("initial health".to_owned(), "150".to_owned())]
+        );
+        assert_eq!(
+            patch.cheats,
+            vec![("god mode".to_owned(), "iddqd".to_owned())]
+        );
+    }
+
+    #[test]
+    fn pointer_uses_bracketed_frame() {
+        let patch = parse(PATCH).unwrap();
+        assert_eq!(
+            patch.pointers,
+            vec![DehPointer {
+                frame: 176,
+                codep_frame: 180,
+            }]
+        );
+    }
+
+    #[test]
+    fn text_blocks_split_by_length() {
+        let patch = parse(PATCH).unwrap();
+        assert_eq!(patch.texts[0], ("BOSS".to_owned(), "BBRN".to_owned()));
+        assert_eq!(
+            patch.texts[1],
+            ("Picked up the armor.".to_owned(), "New armor".to_owned())
+        );
+    }
+
+    #[test]
+    fn text_block_spans_lines() {
+        let patch = parse("Text 3 7\r\na\nbline1\nx\nThing 2\nSpeed = 3\n").unwrap();
+        assert_eq!(patch.texts[0], ("a\nb".to_owned(), "line1\nx".to_owned()));
+        assert_eq!(patch.things[0].get("speed"), Some("3"));
+    }
+
+    #[test]
+    fn truncated_text_is_an_error() {
+        let err = parse("Text 10 10\nshort\n").unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+
+    #[test]
+    fn bex_sections() {
+        let patch = parse(
+            "[STRINGS]
+GOTARMOR = You got \\
+ armor!
+HUSTR_1 = Level\\none
+
+[CODEPTR]
+FRAME 1 = Chase
+Frame 2 = A_Look
+[PARS]
+par 1 1 45
+par 7 120
+",
+        )
+        .unwrap();
+        assert_eq!(
+            patch.strings,
+            vec![
+                ("GOTARMOR".to_owned(), "You got armor!".to_owned()),
+                ("HUSTR_1".to_owned(), "Level\none".to_owned()),
+            ]
+        );
+        assert_eq!(
+            patch.code_pointers,
+            vec![(1, "Chase".to_owned()), (2, "A_Look".to_owned())]
+        );
+        assert_eq!(
+            patch.pars,
+            vec![
+                DehPar {
+                    episode: 1,
+                    map: 1,
+                    seconds: 45,
+                },
+                DehPar {
+                    episode: 0,
+                    map: 7,
+                    seconds: 120,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_sections_are_skipped() {
+        let patch =
+            parse("[SPRITES]\n1 = ABCD\n\nSprite 3\nOffset = 5\n\nThing 4\nMass = 9\n").unwrap();
+        assert_eq!(patch.things.len(), 1);
+        assert_eq!(patch.things[0].get("mass"), Some("9"));
+    }
+
+    #[test]
+    fn parse_int_forms() {
+        assert_eq!(parse_int("42"), Some(42));
+        assert_eq!(parse_int("-1"), Some(-1));
+        assert_eq!(parse_int("0x8000"), Some(0x8000));
+        assert_eq!(parse_int("4294967295"), Some(-1));
+        assert_eq!(parse_int("SOLID"), None);
+    }
+}
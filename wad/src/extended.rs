@@ -36,6 +36,9 @@ pub enum NodeLumpType {
     /// the table is compressed (with zlib) or uncompressed, and further check
     /// if GL or GL2 style
     Extended(ExtendedNodeType),
+    /// A ZDoom style signature this parser does not know, such as `ZGL3`.
+    /// Neither layout can read it, so the BSP has to be built
+    Unknown([u8; 4]),
 }
 
 impl NodeLumpType {
@@ -49,7 +52,8 @@ impl NodeLumpType {
             } else if bytes[1..] == [b'G', b'L', b'2'] {
                 Self::Extended(ExtendedNodeType::ZGL2)
             } else {
-                panic!("Unknown Z<node> type")
+                warn!("Unknown Z node type {:?}", String::from_utf8_lossy(bytes));
+                Self::Unknown(*bytes)
             };
         } else if bytes[0] == b'X' {
             warn!("NODES is an uncompressed zdoom style");
@@ -60,8 +64,8 @@ impl NodeLumpType {
             } else if bytes[1..] == [b'G', b'L', b'2'] {
                 Self::Extended(ExtendedNodeType::XGL2)
             } else {
-                warn!("Unknown X node type, using default");
-                Self::OGDoom
+                warn!("Unknown X node type {:?}", String::from_utf8_lossy(bytes));
+                Self::Unknown(*bytes)
             };
         }
         Self::OGDoom
@@ -85,6 +89,12 @@ impl NodeLumpType {
 ///
 /// Note: a 16:16 fixed point number is stored in 4 bytes.
 ///
+/// The GL variants differ only in the seg record: `XGLN`/`ZGLN` store `u32`:Vertex 1,
+/// `u32`:Partner seg, `u16`:Line, `u8`:Side (11 bytes), and `XGL2`/`ZGL2` widen
+/// Line to `u32` (13 bytes). A GL seg's second vertex is the first vertex of the
+/// next seg in its subsector, and a Line of all ones marks a miniseg. These are
+/// parsed into `gl_segments`.
+///
 /// Note: the OG Doom segs and subsectors lumps are empty if an extended format
 /// is used. From the OG format you will require: `WadSector`, `WadLinedef`,
 /// `WadSidedef`, and `WadThing`.
@@ -99,15 +109,65 @@ pub struct WadExtendedMap {
     pub vertexes: Vec<WadVertex>,
     /// The start seg for a subsector inferred from being first in the count
     pub subsectors: Vec<WadSubSector>,
-    /// The angle and other parts can be recalculated using the new data layout
+    /// The angle and other parts can be recalculated using the new data layout.
+    /// Empty for the GL variants
     pub segments: Vec<WadSegment>,
+    /// The closed subsector outlines of the GL variants, minisegs included.
+    /// Empty for `XNOD`/`ZNOD`
+    pub gl_segments: Vec<WadGlSegment>,
     pub nodes: Vec<WadNode>,
 }
 
+/// A seg from a GL node lump. The segs of one subsector run head to tail
+/// around its convex outline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WadGlSegment {
+    pub start_vertex: u32,
+    pub end_vertex: u32,
+    /// `None` for a miniseg, which lies along a partition line rather than a
+    /// linedef
+    pub linedef: Option<u32>,
+    /// The `side`, 0 = front/right, 1 = back/left
+    pub side: u8,
+}
+
 impl WadExtendedMap {
     pub fn parse(wad_data: &WadData, map_name: &str) -> Option<Self> {
         let lump = wad_data.find_lump_for_map_or_panic(map_name, MapLump::Nodes);
+        Self::from_lump(lump)
+    }
+
+    /// Parse the map's GL nodes, if it has any. ZDBSP stores them in
+    /// `SSECTORS`, leaving `NODES` and `SEGS` empty, some builders in `NODES`,
+    /// and a UDMF map keeps them in `ZNODES`. Unlike [`Self::parse`] this
+    /// tolerates missing or empty lumps.
+    pub fn parse_gl(wad_data: &WadData, map_name: &str) -> Option<Self> {
+        if wad_data.read_textmap(map_name).is_some() {
+            return wad_data
+                .find_udmf_lump(map_name, "ZNODES")
+                .and_then(Self::from_gl_lump);
+        }
+        [(MapLump::SubSectors, "SSECTORS"), (MapLump::Nodes, "NODES")]
+            .into_iter()
+            .filter_map(|(kind, name)| {
+                wad_data
+                    .find_lump_for_map(map_name, kind)
+                    .filter(|lump| lump.name == name)
+            })
+            .find_map(Self::from_gl_lump)
+    }
+
+    /// Parse `lump` only when its signature is one of the GL variants.
+    fn from_gl_lump(lump: &Lump) -> Option<Self> {
+        match NodeLumpType::from_bytes(lump.data.first_chunk()?) {
+            NodeLumpType::Extended(t) if t.is_gl() => Self::from_lump(lump),
+            _ => None,
+        }
+    }
 
+    /// Parse an extended `NODES` lump, `None` for the original Doom layout or
+    /// an unknown one.
+    pub fn from_lump(lump: &Lump) -> Option<Self> {
         let bytes = [lump.data[0], lump.data[1], lump.data[2], lump.data[3]];
         let node_type = NodeLumpType::from_bytes(&bytes);
 
@@ -152,7 +212,7 @@ impl WadExtendedMap {
 
         let mut vertexes = Vec::with_capacity(num_new_vertices);
         let end = ofs + num_new_vertices * 8;
-        // The vertices are in fixed-point format and will require conversion later
+        // The vertices are in 16:16 fixed-point format, converted to map units
         // Each vert is x,y, where x and y are 4 bytes each
         while ofs < end {
             let v1 = lump.read_u32_to_f32(ofs);
@@ -181,18 +241,50 @@ impl WadExtendedMap {
 
         let num_segs = lump.read_u32(ofs) as usize;
         ofs += 4;
-        let mut segments = Vec::with_capacity(num_segs);
-        let end = ofs + num_segs * 11;
-        while ofs < end {
-            segments.push(WadSegment::new_z(
-                lump.read_u32(ofs),
-                lump.read_u32(ofs + 4),
-                lump.read_u16(ofs + 8),
-                lump.data[ofs + 10] as u16,
-            ));
-            ofs += 11;
+        let mut segments = Vec::new();
+        let mut gl_segments = Vec::new();
+        if etype.is_gl() {
+            let wide = matches!(etype, ExtendedNodeType::XGL2 | ExtendedNodeType::ZGL2);
+            let size = if wide { 13 } else { 11 };
+            gl_segments.reserve(num_segs);
+            let end = ofs + num_segs * size;
+            while ofs < end {
+                // The partner seg at +4 is not needed, the polygons are closed
+                let (linedef, side) = if wide {
+                    (lump.read_u32(ofs + 8), lump.data[ofs + 12])
+                } else {
+                    let line = lump.read_u16(ofs + 8);
+                    let line = if line == u16::MAX {
+                        u32::MAX
+                    } else {
+                        line as u32
+                    };
+                    (line, lump.data[ofs + 10])
+                };
+                gl_segments.push(WadGlSegment {
+                    start_vertex: lump.read_u32(ofs),
+                    end_vertex: 0,
+                    linedef: (linedef != u32::MAX).then_some(linedef),
+                    side,
+                });
+                ofs += size;
+            }
+            debug_assert_eq!(gl_segments.len(), num_segs, "segment count mismatch");
+            close_gl_subsectors(&subsectors, &mut gl_segments);
+        } else {
+            segments.reserve(num_segs);
+            let end = ofs + num_segs * 11;
+            while ofs < end {
+                segments.push(WadSegment::new_z(
+                    lump.read_u32(ofs),
+                    lump.read_u32(ofs + 4),
+                    lump.read_u16(ofs + 8),
+                    lump.data[ofs + 10] as u16,
+                ));
+                ofs += 11;
+            }
+            debug_assert_eq!(segments.len(), num_segs, "segment count mismatch");
         }
-        debug_assert_eq!(segments.len(), num_segs, "segment count mismatch");
 
         let num_nodes = lump.read_u32(ofs) as usize;
         ofs += 4;
@@ -232,7 +324,107 @@ impl WadExtendedMap {
             vertexes,
             subsectors,
             segments,
+            gl_segments,
             nodes,
         }
     }
 }
+
+/// Give each GL seg its end vertex: the start of the next seg around the
+/// subsector, wrapping to the first.
+fn close_gl_subsectors(subsectors: &[WadSubSector], segs: &mut [WadGlSegment]) {
+    for ss in subsectors {
+        let start = ss.start_seg as usize;
+        let end = start + ss.seg_count as usize;
+        let Some(ring) = segs.get_mut(start..end) else {
+            warn!("GL subsector segs {start}..{end} out of range");
+            continue;
+        };
+        for i in 0..ring.len() {
+            ring[i].end_vertex = ring[(i + 1) % ring.len()].start_vertex;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+
+    use super::*;
+
+    fn lump(magic: &[u8; 4], body: &[u8]) -> Lump {
+        let mut data = magic.to_vec();
+        data.extend_from_slice(body);
+        Lump {
+            name: "NODES".to_owned(),
+            data,
+        }
+    }
+
+    fn assert_room(map: &WadExtendedMap) {
+        assert_eq!(map.num_org_vertices, 4);
+        assert_eq!(map.vertexes.len(), 2);
+        assert_eq!((map.vertexes[1].x, map.vertexes[1].y), (128.0, 128.0));
+        assert_eq!(map.subsectors.len(), 2);
+        assert!(map.segments.is_empty());
+        assert_eq!(map.gl_segments.len(), 8);
+        assert_eq!(
+            map.gl_segments[0],
+            WadGlSegment {
+                start_vertex: 4,
+                end_vertex: 5,
+                linedef: None,
+                side: 0,
+            }
+        );
+        // The last seg of a subsector closes back to its first vertex.
+        assert_eq!(map.gl_segments[3].linedef, Some(3));
+        assert_eq!(map.gl_segments[3].end_vertex, 4);
+        assert_eq!(map.gl_segments[7].end_vertex, 0);
+        assert_eq!(map.nodes.len(), 1);
+        assert_eq!(map.nodes[0].children, [0x8000_0000, 0x8000_0001]);
+    }
+
+    #[test]
+    fn xgln_segs_close_each_subsector() {
+        let map =
+            WadExtendedMap::from_lump(&lump(b"XGLN", &test_utils::gl_room_nodes(false))).unwrap();
+        assert_eq!(map.node_type, ExtendedNodeType::XGLN);
+        assert_room(&map);
+    }
+
+    #[test]
+    fn xgl2_reads_wide_linedefs() {
+        let map =
+            WadExtendedMap::from_lump(&lump(b"XGL2", &test_utils::gl_room_nodes(true))).unwrap();
+        assert_eq!(map.node_type, ExtendedNodeType::XGL2);
+        assert_room(&map);
+    }
+
+    #[test]
+    fn zgln_decompresses_to_the_same_nodes() {
+        let mut enc = ZlibEncoder::new(Vec::new(), Compression::default());
+        enc.write_all(&test_utils::gl_room_nodes(false)).unwrap();
+        let map = WadExtendedMap::from_lump(&lump(b"ZGLN", &enc.finish().unwrap())).unwrap();
+        assert_eq!(map.node_type, ExtendedNodeType::ZGLN);
+        assert_room(&map);
+    }
+
+    #[test]
+    fn unknown_types_are_neither_layout() {
+        assert_eq!(
+            NodeLumpType::from_bytes(b"ZGL3"),
+            NodeLumpType::Unknown(*b"ZGL3")
+        );
+        assert_eq!(
+            NodeLumpType::from_bytes(b"XGL3"),
+            NodeLumpType::Unknown(*b"XGL3")
+        );
+        assert!(
+            WadExtendedMap::from_lump(&lump(b"XGL3", &test_utils::gl_room_nodes(true))).is_none()
+        );
+    }
+}
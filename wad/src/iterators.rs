@@ -255,6 +255,35 @@ impl WadData {
         }
     }
 
+    /// Extra colourmaps between `C_START`/`C_END` (BOOM), last loaded wad
+    /// first. The markers are optional, so this may yield nothing.
+    pub fn colourmaps_iter(&'_ self) -> LumpIter<'_, WadColourmap, impl Fn(&Lump) -> WadColourmap> {
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+        for (i, info) in self.lumps.iter().enumerate().rev() {
+            if info.name == "C_START" {
+                starts.push(i);
+            } else if info.name == "C_END" {
+                ends.push(i);
+            }
+        }
+        // A wad missing one marker of the pair contributes nothing usable
+        let pairs = starts.len().min(ends.len());
+        starts.truncate(pairs);
+        ends.truncate(pairs);
+
+        LumpIter {
+            end_lumps: ends,
+            lumps: &self.lumps,
+            start_lumps: starts,
+            current_start: 0,
+            transformer: move |lump| WadColourmap {
+                name: lump.name.clone(),
+                data: lump.data.clone(),
+            },
+        }
+    }
+
     pub fn pnames_iter(&self) -> OffsetIter<String, impl Fn(usize) -> String + '_> {
         let info = self.find_lump_or_panic("PNAMES");
         let item_size = 8;
@@ -49,8 +49,14 @@ pub mod write;
 /// ZDoom BSP support (and maybe others in future)
 pub mod extended;
 
+/// PK3 (zip) archives mounted as virtual lumps
+pub mod pk3;
+
 /// UMAPINFO lump parser (per-map metadata override)
 pub mod umapinfo;
 
+/// DEHACKED/BEX patch parser (info table and string overrides)
+pub mod dehacked;
+
 /// BOOM binary lump parsers (SWITCHES, ANIMATED)
 pub mod boom;
@@ -0,0 +1,225 @@
+//! PK3 (zip) archives, mounted as if they were a PWAD.
+//!
+//! Every file becomes a lump named after its upper-cased stem (the file name up
+//! to the first `.`), so `sounds/dspistol.lmp` is `DSPISTOL`. Files in the
+//! namespace directories are wrapped in the PWAD marker pair the iterators
+//! already look for, which is why `flats/` mounts between `FF_START`/`FF_END`.
+//! A WAD under `maps/` is mounted whole, its map marker renamed after the file
+//! as ZDoom does. Subdirectories within a namespace are flattened.
+//!
+//! Lumps are mounted in path order: root files, embedded maps, then each
+//! namespace. Files elsewhere (`filter/`, `voxels/`, ...) are skipped.
+
+use std::io::{self, Cursor, Read as _};
+
+use log::{debug, warn};
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+use crate::{Lump, WadData};
+
+/// Directory → marker pair its lumps are wrapped in. `None` for directories
+/// whose lumps are found by name alone.
+const NAMESPACES: [(&str, Option<(&str, &str)>); 8] = [
+    ("flats", Some(("FF_START", "FF_END"))),
+    ("sprites", Some(("SS_START", "SS_END"))),
+    ("patches", Some(("PP_START", "PP_END"))),
+    ("textures", Some(("TX_START", "TX_END"))),
+    ("colormaps", Some(("C_START", "C_END"))),
+    ("music", None),
+    ("sounds", None),
+    ("graphics", None),
+];
+
+/// True when `data` starts with a zip signature (a local file header, or the
+/// end-of-directory record of an empty archive).
+pub fn is_pk3(data: &[u8]) -> bool {
+    data.starts_with(b"PK\x03\x04") || data.starts_with(b"PK\x05\x06")
+}
+
+/// All lumps of a PK3 archive image in mount order.
+pub fn read_lumps(data: &[u8]) -> io::Result<Vec<Lump>> {
+    let mut archive = ZipArchive::new(Cursor::new(data)).map_err(invalid)?;
+    let mut files = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(invalid)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let path = entry.name().replace('\\', "/").to_ascii_lowercase();
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut bytes)?;
+        files.push((path, bytes));
+    }
+    // Zip order is whatever the packer chose, path order is stable.
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut root = Vec::new();
+    let mut maps = Vec::new();
+    let mut spaces: Vec<Vec<Lump>> = NAMESPACES.iter().map(|_| Vec::new()).collect();
+    for (path, data) in files {
+        let (dir, file) = match path.split_once('/') {
+            Some((dir, rest)) => (dir, rest.rsplit('/').next().unwrap_or(rest)),
+            None => ("", path.as_str()),
+        };
+        let Some(name) = lump_name(file) else {
+            debug!("PK3: skipping {path}, no short lump name");
+            continue;
+        };
+
+        if dir.is_empty() {
+            root.push(Lump {
+                name,
+                data,
+            });
+        } else if dir == "maps" {
+            if !file.ends_with(".wad") {
+                debug!("PK3: skipping {path}, not a WAD");
+            } else if WadData::is_well_formed(&data) {
+                let mut lumps = WadData::read_lumps(&data);
+                if let Some(marker) = lumps.first_mut()
+                    && marker.data.is_empty()
+                {
+                    marker.name = name;
+                }
+                maps.extend(lumps);
+            } else {
+                warn!("PK3: {path} is not a valid WAD");
+            }
+        } else if let Some(i) = NAMESPACES.iter().position(|(ns, _)| *ns == dir) {
+            spaces[i].push(Lump {
+                name,
+                data,
+            });
+        } else {
+            debug!("PK3: skipping {path}, unknown directory");
+        }
+    }
+
+    let mut lumps = root;
+    lumps.append(&mut maps);
+    for ((_, markers), space) in NAMESPACES.iter().zip(spaces) {
+        if space.is_empty() {
+            continue;
+        }
+        match markers {
+            Some((start, end)) => {
+                lumps.push(marker(start));
+                lumps.extend(space);
+                lumps.push(marker(end));
+            }
+            None => lumps.extend(space),
+        }
+    }
+    Ok(lumps)
+}
+
+/// The 8-character lump name for a file: its stem, upper-cased. `None` when
+/// the stem is empty or too long to be looked up by a WAD name.
+fn lump_name(file: &str) -> Option<String> {
+    let stem = file.split('.').next()?;
+    (!stem.is_empty() && stem.len() <= 8).then(|| stem.to_ascii_uppercase())
+}
+
+fn marker(name: &str) -> Lump {
+    Lump {
+        name: name.to_owned(),
+        data: Vec::new(),
+    }
+}
+
+fn invalid(e: ZipError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+    use crate::write::write_pwad;
+
+    fn pk3(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, data) in files {
+            zip.start_file(*path, SimpleFileOptions::default()).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn names(lumps: &[Lump]) -> Vec<&str> {
+        lumps.iter().map(|l| l.name.as_str()).collect()
+    }
+
+    #[test]
+    fn directories_map_to_namespaces() {
+        let data = pk3(&[
+            ("sprites/monsters/TROOA1.lmp", &[1]),
+            ("flats/floor0_1.lmp", &[2]),
+            ("UMAPINFO.txt", b"map MAP01 {}"),
+            ("music/d_runnin.mid", &[3]),
+            ("patches/wall00_1.lmp", &[4]),
+            ("sounds/DSPISTOL.wav", &[5]),
+            ("filter/doom.id.doom2/dehacked.deh", &[6]),
+            ("textures/longtexturename.png", &[7]),
+        ]);
+        assert!(is_pk3(&data));
+        let lumps = read_lumps(&data).unwrap();
+        assert_eq!(
+            names(&lumps),
+            [
+                "UMAPINFO", "FF_START", "FLOOR0_1", "FF_END", "SS_START", "TROOA1", "SS_END",
+                "PP_START", "WALL00_1", "PP_END", "D_RUNNIN", "DSPISTOL",
+            ]
+        );
+        assert_eq!(lumps[0].data, b"map MAP01 {}");
+        assert_eq!(lumps[5].data, [1]);
+    }
+
+    #[test]
+    fn embedded_map_wad_takes_the_file_name() {
+        let lump = |name: &str, data: &[u8]| Lump {
+            name: name.to_owned(),
+            data: data.to_vec(),
+        };
+        let wad = write_pwad(&[
+            lump("MAP01", &[]),
+            lump("TEXTMAP", b"namespace = \"doom\";"),
+        ])
+        .unwrap();
+        let data = pk3(&[("maps/map07.wad", &wad), ("maps/broken.wad", b"PWAD")]);
+        let lumps = read_lumps(&data).unwrap();
+        assert_eq!(names(&lumps), ["MAP07", "TEXTMAP"]);
+        assert_eq!(lumps[1].data, b"namespace = \"doom\";");
+    }
+
+    #[test]
+    fn mounted_pk3_overrides_earlier_lumps() {
+        let base = write_pwad(&[Lump {
+            name: "DEMO1".to_owned(),
+            data: vec![0],
+        }])
+        .unwrap();
+        let id = std::process::id();
+        let base_path = std::env::temp_dir().join(format!("r4d-pk3-base-{id}.wad"));
+        let pk3_path = std::env::temp_dir().join(format!("r4d-pk3-{id}.pk3"));
+        std::fs::write(&base_path, base).unwrap();
+        std::fs::write(&pk3_path, pk3(&[("demo1.lmp", &[9])])).unwrap();
+
+        let mut wad = WadData::new(&base_path);
+        wad.try_add_file(&pk3_path).unwrap();
+        std::fs::remove_file(&base_path).ok();
+        std::fs::remove_file(&pk3_path).ok();
+        assert_eq!(wad.get_lump("DEMO1").unwrap().data, [9]);
+    }
+
+    #[test]
+    fn corrupt_pk3_is_an_error() {
+        let err = read_lumps(b"PK\x03\x04 not really a zip").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -43,6 +43,13 @@ pub struct WadFlat {
     pub data: Vec<u8>,
 }
 
+/// A BOOM colourmap lump from between `C_START`/`C_END`. Laid out the same as
+/// `COLORMAP`: 256 byte light levels.
+pub struct WadColourmap {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
 /// Packed pixel colour in `0xFFRRGGBB` format (ARGB, always fully opaque).
 pub type WadColour = u32;
 
@@ -331,6 +338,9 @@ pub struct WadThing {
     pub angle: i16,
     pub kind: i16,
     pub flags: i16,
+    /// UDMF `alpha` below 1: the thing is drawn translucent. Not part of the
+    /// WAD record.
+    pub translucent: bool,
 }
 
 impl WadThing {
@@ -341,25 +351,76 @@ impl WadThing {
             angle,
             kind,
             flags,
+            translucent: false,
         }
     }
 }
 
 impl From<&[u8]> for WadThing {
     fn from(d: &[u8]) -> Self {
-        Self {
-            x: rd_i16(d, 0),
-            y: rd_i16(d, 2),
-            angle: rd_i16(d, 4),
-            kind: rd_i16(d, 6),
-            flags: rd_i16(d, 8),
-        }
+        Self::new(
+            rd_i16(d, 0),
+            rd_i16(d, 2),
+            rd_i16(d, 4),
+            rd_i16(d, 6),
+            rd_i16(d, 8),
+        )
     }
 }
 impl WadRecord for WadThing {
     const SIZE: usize = 10;
 }
 
+/// A Hexen-format `Thing`: the Doom fields plus a thing ID, spawn height and
+/// an action special.
+///
+/// The data in the WAD lump is structured as follows:
+///
+/// | Field Size | Data Type | Content        |
+/// |------------|-----------|----------------|
+/// |  0x00-0x01 |    i16    | Thing ID (TID) |
+/// |  0x02-0x03 |    i16    | X Position     |
+/// |  0x04-0x05 |    i16    | Y Position     |
+/// |  0x06-0x07 |    i16    | Z (height)     |
+/// |  0x08-0x09 |    i16    | Angle          |
+/// |  0x0A-0x0B |    i16    | Type           |
+/// |  0x0C-0x0D |    i16    | Flags          |
+/// |  0x0E      |    u8     | Special        |
+/// |  0x0F-0x13 |   5 u8    | Special args   |
+///
+/// Each `Thing` record is 20 bytes
+#[derive(Debug, Default, Copy, Clone)]
+pub struct WadHexenThing {
+    pub tid: i16,
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub angle: i16,
+    pub kind: i16,
+    pub flags: i16,
+    pub special: u8,
+    pub args: [u8; 5],
+}
+
+impl From<&[u8]> for WadHexenThing {
+    fn from(d: &[u8]) -> Self {
+        Self {
+            tid: rd_i16(d, 0),
+            x: rd_i16(d, 2),
+            y: rd_i16(d, 4),
+            z: rd_i16(d, 6),
+            angle: rd_i16(d, 8),
+            kind: rd_i16(d, 10),
+            flags: rd_i16(d, 12),
+            special: d[14],
+            args: [d[15], d[16], d[17], d[18], d[19]],
+        }
+    }
+}
+impl WadRecord for WadHexenThing {
+    const SIZE: usize = 20;
+}
+
 /// A `Vertex` is the basic struct used for any type of coordinate
 /// in the game-exe
 ///
@@ -485,6 +546,51 @@ impl WadRecord for WadLineDef {
     const SIZE: usize = 14;
 }
 
+/// A Hexen-format linedef. The sector tag is gone: the special takes five
+/// byte arguments instead, and the activation type lives in the flags.
+///
+///| Field Size | Data Type      | Content                                   |
+///|------------|----------------|-------------------------------------------|
+///|  0x00-0x01 | Unsigned short | Start vertex                              |
+///|  0x02-0x03 | Unsigned short | End vertex                                |
+///|  0x04-0x05 | Unsigned short | Flags (activation in bits 10-12)          |
+///|  0x06      | Unsigned byte  | Special                                   |
+///|  0x07-0x0B | 5 bytes        | Special args                              |
+///|  0x0C-0x0D | Unsigned short | Front sidedef ( 0xFFFF side not present ) |
+///|  0x0E-0x0F | Unsigned short | Back sidedef  ( 0xFFFF side not present ) |
+///
+/// Each linedef's record is 16 bytes
+#[derive(Debug, Clone)]
+pub struct WadHexenLineDef {
+    pub start_vertex: u16,
+    pub end_vertex: u16,
+    /// Doom flags in bits 0-8, then repeatable (bit 9) and activation (10-12)
+    pub flags: u16,
+    pub special: u8,
+    pub args: [u8; 5],
+    pub front_sidedef: u16,
+    /// If the parsed value == `0xFFFF` means there is no sidedef
+    pub back_sidedef: Option<u16>,
+}
+
+impl From<&[u8]> for WadHexenLineDef {
+    fn from(d: &[u8]) -> Self {
+        let back = rd_u16(d, 14);
+        Self {
+            start_vertex: rd_u16(d, 0),
+            end_vertex: rd_u16(d, 2),
+            flags: rd_u16(d, 4),
+            special: d[6],
+            args: [d[7], d[8], d[9], d[10], d[11]],
+            front_sidedef: rd_u16(d, 12),
+            back_sidedef: if back < u16::MAX { Some(back) } else { None },
+        }
+    }
+}
+impl WadRecord for WadHexenLineDef {
+    const SIZE: usize = 16;
+}
+
 /// The Segments (SEGS) are in a sequential order determined by the `SubSector`
 /// (SSECTOR), which are part of the NODES recursive tree
 ///
@@ -1084,4 +1190,25 @@ mod tests {
             .to_owned();
         assert_eq!(name.as_str(), "WOODSKUL");
     }
+
+    #[test]
+    fn hexen_records() {
+        use super::{WadHexenLineDef, WadHexenThing};
+
+        let thing: [u8; 20] = [
+            7, 0, 0x40, 0, 0xC0, 0xFF, 16, 0, 90, 0, 1, 0, 0x07, 0x01, 12, 5, 16, 150, 0, 0,
+        ];
+        let t = WadHexenThing::from(&thing[..]);
+        assert_eq!((t.tid, t.x, t.y, t.z, t.angle), (7, 64, -64, 16, 90));
+        assert_eq!((t.kind, t.flags), (1, 0x107));
+        assert_eq!((t.special, t.args), (12, [5, 16, 150, 0, 0]));
+
+        let line: [u8; 16] = [
+            1, 0, 2, 0, 0x04, 0x06, 12, 0, 16, 150, 0, 0, 3, 0, 0xFF, 0xFF,
+        ];
+        let l = WadHexenLineDef::from(&line[..]);
+        assert_eq!((l.start_vertex, l.end_vertex, l.flags), (1, 2, 0x0604));
+        assert_eq!((l.special, l.args), (12, [0, 16, 150, 0, 0]));
+        assert_eq!((l.front_sidedef, l.back_sidedef), (3, None));
+    }
 }
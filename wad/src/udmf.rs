@@ -1,6 +1,10 @@
-//! UDMF `TEXTMAP` parser (spec v1.1 grammar + the ZDoom plane-equation
-//! sector fields). Unknown identifiers are ignored per the spec; fields the
-//! spec marks "no valid default" are required.
+//! UDMF `TEXTMAP` parser (spec v1.1 grammar + ZDoom extensions).
+//!
+//! Beyond the spec: the ZDoom plane-equation sector fields and the common
+//! ZDoom render fields (per-part sidedef offset/scale, wall and plane light,
+//! flat panning/rotation, line and thing alpha). Unknown identifiers are
+//! ignored per the spec; fields the spec marks "no valid default" are
+//! required.
 
 use std::collections::HashMap;
 use std::fmt;
@@ -48,6 +52,32 @@ pub struct UdmfLineDef {
     pub blocksound: bool,
     pub dontdraw: bool,
     pub mapped: bool,
+    /// ZDoom `alpha`: opacity of the middle texture, 1.0 = opaque.
+    pub alpha: f64,
+    /// ZDoom `renderstyle`, lowercased (`"translucent"` or `"add"`); `None`
+    /// when absent.
+    pub renderstyle: Option<String>,
+}
+
+/// ZDoom per-part sidedef texture offset and scale (`offsetx_top`,
+/// `scaley_mid`, ...), added to and multiplied over the sidedef's own offset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UdmfSidePart {
+    pub offsetx: f64,
+    pub offsety: f64,
+    pub scalex: f64,
+    pub scaley: f64,
+}
+
+impl Default for UdmfSidePart {
+    fn default() -> Self {
+        Self {
+            offsetx: 0.0,
+            offsety: 0.0,
+            scalex: 1.0,
+            scaley: 1.0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -59,6 +89,13 @@ pub struct UdmfSideDef {
     pub texturebottom: Option<String>,
     pub texturemiddle: Option<String>,
     pub sector: usize,
+    pub top: UdmfSidePart,
+    pub mid: UdmfSidePart,
+    pub bottom: UdmfSidePart,
+    /// ZDoom `light`: added to the sector light, or replacing it when
+    /// `lightabsolute` is set.
+    pub light: i32,
+    pub lightabsolute: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -75,6 +112,19 @@ pub struct UdmfSector {
     pub floor_plane: Option<[f64; 4]>,
     /// ZDoom `ceilingplane_a..d`, normal down.
     pub ceiling_plane: Option<[f64; 4]>,
+    /// ZDoom `xpanningfloor`/`ypanningfloor`: flat offset in map units.
+    pub panning_floor: [f64; 2],
+    pub panning_ceiling: [f64; 2],
+    /// ZDoom `rotationfloor`/`rotationceiling`: flat rotation in degrees,
+    /// counter-clockwise.
+    pub rotation_floor: f64,
+    pub rotation_ceiling: f64,
+    /// ZDoom `lightfloor`/`lightceiling`: added to the sector light, or
+    /// replacing it when the matching `light*absolute` is set.
+    pub lightfloor: i32,
+    pub lightceiling: i32,
+    pub lightfloorabsolute: bool,
+    pub lightceilingabsolute: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -93,6 +143,8 @@ pub struct UdmfThing {
     pub single: bool,
     pub dm: bool,
     pub coop: bool,
+    /// ZDoom `alpha`: opacity, 1.0 = opaque.
+    pub alpha: f64,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -202,6 +254,17 @@ impl Block {
             .unwrap_or(false)
     }
 
+    /// The `offsetx_<part>`, `offsety_<part>`, `scalex_<part>` and
+    /// `scaley_<part>` fields of a sidedef.
+    fn side_part(&self, part: &str) -> UdmfSidePart {
+        UdmfSidePart {
+            offsetx: self.f64_or(&format!("offsetx_{part}"), 0.0),
+            offsety: self.f64_or(&format!("offsety_{part}"), 0.0),
+            scalex: self.f64_or(&format!("scalex_{part}"), 1.0),
+            scaley: self.f64_or(&format!("scaley_{part}"), 1.0),
+        }
+    }
+
     fn texture(&self, key: &str) -> Option<String> {
         let tex = self.fields.get(key).and_then(Value::as_str)?;
         (tex != NO_TEXTURE).then(|| tex.to_owned())
@@ -280,6 +343,12 @@ pub fn parse_textmap(text: &str) -> Result<UdmfMap, UdmfError> {
                         blocksound: block.bool_or("blocksound"),
                         dontdraw: block.bool_or("dontdraw"),
                         mapped: block.bool_or("mapped"),
+                        alpha: block.f64_or("alpha", 1.0),
+                        renderstyle: block
+                            .fields
+                            .get("renderstyle")
+                            .and_then(Value::as_str)
+                            .map(str::to_ascii_lowercase),
                     }),
                     "sidedef" => map.sidedefs.push(UdmfSideDef {
                         offsetx: block.i32_or("offsetx", 0),
@@ -288,6 +357,11 @@ pub fn parse_textmap(text: &str) -> Result<UdmfMap, UdmfError> {
                         texturebottom: block.texture("texturebottom"),
                         texturemiddle: block.texture("texturemiddle"),
                         sector: block.usize_req("sector")?,
+                        top: block.side_part("top"),
+                        mid: block.side_part("mid"),
+                        bottom: block.side_part("bottom"),
+                        light: block.i32_or("light", 0),
+                        lightabsolute: block.bool_or("lightabsolute"),
                     }),
                     "sector" => map.sectors.push(UdmfSector {
                         heightfloor: block.i32_or("heightfloor", 0),
@@ -299,6 +373,20 @@ pub fn parse_textmap(text: &str) -> Result<UdmfMap, UdmfError> {
                         id: block.i32_or("id", 0),
                         floor_plane: block.plane("floorplane"),
                         ceiling_plane: block.plane("ceilingplane"),
+                        panning_floor: [
+                            block.f64_or("xpanningfloor", 0.0),
+                            block.f64_or("ypanningfloor", 0.0),
+                        ],
+                        panning_ceiling: [
+                            block.f64_or("xpanningceiling", 0.0),
+                            block.f64_or("ypanningceiling", 0.0),
+                        ],
+                        rotation_floor: block.f64_or("rotationfloor", 0.0),
+                        rotation_ceiling: block.f64_or("rotationceiling", 0.0),
+                        lightfloor: block.i32_or("lightfloor", 0),
+                        lightceiling: block.i32_or("lightceiling", 0),
+                        lightfloorabsolute: block.bool_or("lightfloorabsolute"),
+                        lightceilingabsolute: block.bool_or("lightceilingabsolute"),
                     }),
                     "thing" => map.things.push(UdmfThing {
                         x: block.f64_req("x")?,
@@ -319,6 +407,7 @@ pub fn parse_textmap(text: &str) -> Result<UdmfMap, UdmfError> {
                         single: block.bool_or("single"),
                         dm: block.bool_or("dm"),
                         coop: block.bool_or("coop"),
+                        alpha: block.f64_or("alpha", 1.0),
                     }),
                     _ => {} // unknown block kinds are ignored per spec
                 }
@@ -548,6 +637,8 @@ mod tests {
     const FLAT_ROOM: &str = include_str!("../../data/test_files/udmf/flat_room.textmap");
     const SLOPED_FLOOR: &str = include_str!("../../data/test_files/udmf/sloped_floor.textmap");
     const SLOPED_CEILING: &str = include_str!("../../data/test_files/udmf/sloped_ceiling.textmap");
+    const ZDOOM_EXTENSIONS: &str =
+        include_str!("../../data/test_files/udmf/zdoom_extensions.textmap");
 
     #[test]
     fn flat_room_parses() {
@@ -596,6 +687,41 @@ mod tests {
         assert_eq!(plane, [0.25, 0.0, -1.0, 128.0]);
     }
 
+    #[test]
+    fn zdoom_render_extensions() {
+        let map = parse_textmap(ZDOOM_EXTENSIONS).expect("zdoom extensions");
+
+        let side = &map.sidedefs[1];
+        assert_eq!(side.mid.offsetx, 16.0);
+        assert_eq!(side.mid.offsety, -8.0);
+        assert_eq!(side.mid.scalex, 2.0);
+        assert_eq!(side.mid.scaley, 0.5);
+        assert_eq!(side.top, UdmfSidePart::default(), "untouched part");
+        assert_eq!((side.light, side.lightabsolute), (32, false));
+        assert_eq!(
+            (map.sidedefs[2].light, map.sidedefs[2].lightabsolute),
+            (200, true)
+        );
+
+        let sector = &map.sectors[1];
+        assert_eq!(sector.panning_floor, [8.0, 4.0]);
+        assert_eq!(sector.rotation_floor, 45.0);
+        assert_eq!((sector.lightfloor, sector.lightfloorabsolute), (-16, false));
+        assert_eq!(
+            (sector.lightceiling, sector.lightceilingabsolute),
+            (96, true)
+        );
+        assert_eq!(map.sectors[0].rotation_floor, 0.0, "default");
+
+        let line = &map.linedefs[3];
+        assert_eq!(line.alpha, 0.5);
+        assert_eq!(line.renderstyle.as_deref(), Some("add"));
+        assert_eq!(map.linedefs[0].alpha, 1.0, "default opaque");
+
+        assert_eq!(map.things[1].alpha, 0.25);
+        assert_eq!(map.things[0].alpha, 1.0, "default opaque");
+    }
+
     #[test]
     fn partial_plane_fields_are_ignored() {
         let text = r#"
@@ -10,6 +10,8 @@ pub use parse::ParseError;
 pub struct UMapInfo {
     pub(crate) entries: Vec<MapEntry>,
     pub(crate) index: HashMap<String, usize>,
+    /// The stock episode menu is replaced by `episodes()` rather than
+    /// added to.
     pub clear_episodes: bool,
 }
 
@@ -32,6 +34,14 @@ impl UMapInfo {
             .collect()
     }
 
+    /// Each episode definition with the map entry it starts on.
+    pub fn episode_starts(&self) -> Vec<(&MapEntry, &EpisodeDef)> {
+        self.entries
+            .iter()
+            .filter_map(|e| e.episode_def.as_ref().map(|d| (e, d)))
+            .collect()
+    }
+
     pub fn entries(&self) -> &[MapEntry] {
         &self.entries
     }
@@ -158,6 +158,8 @@ impl<'a> Tokenizer<'a> {
 struct Parser {
     tokens: Vec<(Token, usize)>,
     pos: usize,
+    /// Set by an `episode = clear` in the entry being parsed.
+    cleared_episodes: bool,
 }
 
 impl Parser {
@@ -170,6 +172,7 @@ impl Parser {
         Self {
             tokens,
             pos: 0,
+            cleared_episodes: false,
         }
     }
 
@@ -274,6 +277,7 @@ impl Parser {
     fn parse_episode_def(&mut self) -> Result<Option<EpisodeDef>, ParseError> {
         if matches!(self.peek(), Some(Token::Ident(s)) if s.eq_ignore_ascii_case("clear")) {
             self.advance();
+            self.cleared_episodes = true;
             return Ok(None);
         }
         let patch = self.expect_string()?;
@@ -525,7 +529,7 @@ struct ClusterDef {
 
 pub fn parse(input: &str) -> Result<UMapInfo, ParseError> {
     let mut parser = Parser::new(input);
-    let mut entries = Vec::new();
+    let mut entries: Vec<MapEntry> = Vec::new();
     let mut index = HashMap::new();
     let mut clusters: HashMap<i32, ClusterDef> = HashMap::new();
     let mut episode_defs: Vec<(String, EpisodeDef)> = Vec::new();
@@ -536,6 +540,14 @@ pub fn parse(input: &str) -> Result<UMapInfo, ParseError> {
             Token::Ident(s) if s.eq_ignore_ascii_case("map") => {
                 parser.advance();
                 let entry = parser.parse_entry()?;
+                // `episode = clear` drops the stock episodes and any defined
+                // by earlier maps
+                if std::mem::take(&mut parser.cleared_episodes) {
+                    clear_episodes = true;
+                    for e in &mut entries {
+                        e.episode_def = None;
+                    }
+                }
                 let key = entry.map_name.clone();
                 let idx = entries.len();
                 entries.push(entry);
@@ -846,6 +858,33 @@ map MAP06
         assert!(matches!(&entry.inter_text, Some(TextOrClear::Clear)));
     }
 
+    #[test]
+    fn test_episode_clear() {
+        let input = r#"
+map E1M1
+{
+    episode = "M_EPI1", "Stock", "k"
+}
+map E5M1
+{
+    episode = clear
+}
+map E5M2
+{
+    episode = "M_EPI5", "Fifth", "f"
+}
+map E6M1
+{
+    episode = "M_EPI6", "Sixth", "s"
+}
+"#;
+        let info = parse(input).expect("parse failed");
+        assert!(info.clear_episodes);
+        let names: Vec<_> = info.episodes().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Fifth", "Sixth"]);
+        assert!(!parse("map E1M1 { }").unwrap().clear_episodes);
+    }
+
     #[test]
     fn test_boss_actions() {
         let input = r"
@@ -4,6 +4,7 @@ use std::hash::{Hash as _, Hasher as _};
 use std::path::{Path, PathBuf};
 use std::{fmt, fs, io, str};
 
+use crate::pk3;
 use crate::types::{GameMission, GameMode, WadBlockMap};
 
 const FRACUNIT: f32 = (1 << 16) as f32;
@@ -48,6 +49,9 @@ pub enum MapLump {
     /// 128x128 grid partition of the level LINEDEFS to accelerate collision
     /// detection
     Blockmap,
+    /// Compiled ACS bytecode. Only Hexen-format maps carry it, so its presence
+    /// is what marks the THINGS and LINEDEFS lumps as the Hexen layout
+    Behavior,
     Count,
 }
 
@@ -64,6 +68,7 @@ impl Display for MapLump {
             Self::Sectors => write!(f, "SECTORS"),
             Self::Reject => write!(f, "REJECT"),
             Self::Blockmap => write!(f, "BLOCKMAP"),
+            Self::Behavior => write!(f, "BEHAVIOR"),
             Self::Count => write!(f, "COUNT"),
         }
     }
@@ -231,9 +236,16 @@ impl WadData {
     }
 
     /// Fallible [`WadData::add_file`]: errors instead of panicking on an unreadable file.
+    /// A PK3 (zip) archive is mounted as virtual lumps, see [`crate::pk3`].
     pub fn try_add_file(&mut self, file_path: &Path) -> io::Result<()> {
         let file_data = fs::read(file_path)?;
-        self.cache_lumps(&file_data);
+        if pk3::is_pk3(&file_data) {
+            let lumps = pk3::read_lumps(&file_data)?;
+            log::info!("{}: mounted {} PK3 lumps", file_path.display(), lumps.len());
+            self.lumps.extend(lumps);
+        } else {
+            self.cache_lumps(&file_data);
+        }
         Ok(())
     }
 
@@ -278,13 +290,43 @@ impl WadData {
 
     /// Read the directory and cache every lump's data into `self.lumps`.
     fn cache_lumps(&mut self, file: &[u8]) {
+        let lumps = Self::read_lumps(file);
+        self.lumps.extend(lumps);
+    }
+
+    /// Every lump of a WAD file image, in directory order.
+    pub(crate) fn read_lumps(file: &[u8]) -> Vec<Lump> {
         let header = Self::read_header(file);
-        self.lumps.reserve_exact(header.dir_count as usize);
+        (0..header.dir_count)
+            .map(|i| Self::read_dir_data((header.dir_offset + i * 16) as usize, file))
+            .collect()
+    }
 
-        for i in 0..(header.dir_count) {
-            let dir = Self::read_dir_data((header.dir_offset + i * 16) as usize, file);
-            self.lumps.push(dir);
+    /// True when `file` is a WAD image whose directory and lumps all lie
+    /// within it, so [`Self::read_lumps`] cannot index out of bounds.
+    pub(crate) fn is_well_formed(file: &[u8]) -> bool {
+        if file.len() < 12 || !matches!(&file[0..4], b"IWAD" | b"PWAD") {
+            return false;
         }
+        let header = Self::read_header(file);
+        let dir = header.dir_offset as usize;
+        let Some(dir_end) = (header.dir_count as usize)
+            .checked_mul(16)
+            .and_then(|len| len.checked_add(dir))
+        else {
+            return false;
+        };
+        if dir_end > file.len() {
+            return false;
+        }
+        file[dir..dir_end].chunks_exact(16).all(|entry| {
+            let offset = i32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]);
+            let size = i32::from_le_bytes([entry[4], entry[5], entry[6], entry[7]]);
+            offset >= 0
+                && size >= 0
+                && (offset as usize + size as usize) <= file.len()
+                && str::from_utf8(&entry[8..16]).is_ok()
+        })
     }
 
     /// Find a general lump by name
@@ -366,6 +408,24 @@ impl WadData {
         (lump.name == "TEXTMAP").then_some(lump.data.as_slice())
     }
 
+    /// A lump of a UDMF map by name, looked for between the map marker and
+    /// `ENDMAP`.
+    pub(super) fn find_udmf_lump(&self, map_name: &str, name: &str) -> Option<&Lump> {
+        let map_name = map_name.to_ascii_uppercase();
+        let marker = self.lumps.iter().rposition(|l| l.name == map_name)?;
+        self.lumps[marker + 1..]
+            .iter()
+            .take_while(|l| l.name != "ENDMAP")
+            .find(|l| l.name == name)
+    }
+
+    /// True for a Hexen-format map: a `BEHAVIOR` lump follows `BLOCKMAP`.
+    /// Its THINGS and LINEDEFS use the wider Hexen records.
+    pub fn is_hexen_map(&self, map_name: &str) -> bool {
+        self.find_lump_for_map(map_name, MapLump::Behavior)
+            .is_some_and(|l| l.name == "BEHAVIOR")
+    }
+
     /// Parse the UMAPINFO lump if present.
     pub fn umapinfo(&self) -> Option<crate::umapinfo::UMapInfo> {
         let lump = self.get_lump("UMAPINFO")?;
@@ -405,6 +465,24 @@ impl WadData {
         }
     }
 
+    /// Parse every DEHACKED lump, IWAD first so later PWADs win.
+    pub fn dehacked(&self) -> Vec<crate::dehacked::DehPatch> {
+        self.lumps
+            .iter()
+            .filter(|lump| lump.name == "DEHACKED")
+            .filter_map(|lump| {
+                let text = String::from_utf8_lossy(&lump.data);
+                match crate::dehacked::parse(&text) {
+                    Ok(patch) => Some(patch),
+                    Err(e) => {
+                        log::warn!("Failed to parse DEHACKED: {e}");
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Returns UMAPINFO if present, then ZMAPINFO, then MAPINFO.
     pub fn map_info(&self) -> Option<crate::umapinfo::UMapInfo> {
         self.umapinfo()